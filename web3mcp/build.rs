@@ -5,61 +5,60 @@ fn main() {
     let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR not set");
     let out_path = Path::new(&out_dir).join("router_impl.rs");
 
-    let expose_advanced = std::env::var("CARGO_FEATURE_EXPOSE_ADVANCED_TOOLS").is_ok();
+    // Every tool is compiled in; which ones are listed/callable is decided at runtime by the
+    // active tool profile (see src/utils/tool_profiles.rs).
+    let helper_sections: Vec<&str> = vec![
+        "src/tools/move/automation/move_auto_helpers.rs",
+        "src/tools/move/resolution/move_resolve_helpers.rs",
+        "src/tools/intent/intent_helpers.rs",
+        "src/tools/intent/intent_parse_only.rs",
+        "src/tools/system/workflow/workflow_run.rs",
+        "src/tools/system/workflow/workflow_plan.rs",
+        "src/tools/system/workflow/workflow_solana.rs",
+        "src/tools/system/workflow/workflow_evm.rs",
+        "src/tools/system/workflow/workflow_sui.rs",
+    ];
 
-    let helper_sections: Vec<&str> = if expose_advanced {
-        vec![
-            "src/tools/move/automation/move_auto_helpers.rs",
-            "src/tools/move/resolution/move_resolve_helpers.rs",
-            "src/tools/intent/intent_helpers.rs",
-        ]
-    } else {
-        // Minimal build: keep helpers empty to avoid pulling in non-Solana dependencies.
-        vec![]
-    };
-
-    // Minimal public surface (Solana-first): only expose the workflow entrypoint + Solana confirmation.
-    // Advanced surface: expose the full toolbox.
-    let sections: Vec<&str> = if expose_advanced {
-        vec![
-            "src/tools/read/balances/read_balances.rs",
-            "src/tools/read/objects/read_objects.rs",
-            "src/tools/read/transactions/read_transactions.rs",
-            "src/tools/intent/nl_intent.rs",
-            "src/tools/intent/templates/transaction_templates.rs",
-            "src/tools/auth/zklogin/zklogin.rs",
-            "src/tools/auth/keystore/keystore.rs",
-            "src/tools/tx/pay/tx_build.rs",
-            "src/tools/tx/inspections/tx_sim.rs",
-            "src/tools/tx/staking/tx_stakes.rs",
-            "src/tools/tx/queries/checkpoints.rs",
-            "src/tools/tx/queries/tx_queries.rs",
-            "src/tools/tx/queries/coin_queries.rs",
-            "src/tools/move/schema/move_schema.rs",
-            "src/tools/move/schema/move_suggest.rs",
-            "src/tools/move/resolution/move_resolve.rs",
-            "src/tools/move/dynamic/dapp_manifest.rs",
-            "src/tools/move/automation/move_auto.rs",
-            "src/tools/move/dynamic/move_dynamic.rs",
-            "src/tools/graphql/core/graphql_tools.rs",
-            "src/tools/rpc/service/rpc_tools.rs",
-            "src/tools/crypto/signature/crypto_tools.rs",
-            "src/tools/system/gas/system_gas.rs",
-            "src/tools/system/events/system_events.rs",
-            "src/tools/system/stats/system_stats.rs",
-            "src/tools/system/coins/coin_read.rs",
-            "src/tools/system/chain/chain_info.rs",
-            "src/tools/system/workflow/system_workflow.rs",
-            "src/tools/evm/evm_tools.rs",
-            "src/tools/solana/solana_tools.rs",
-        ]
-    } else {
-        vec![
-            "src/tools/intent/intent_parse_only.rs",
-            "src/tools/system/workflow/system_workflow.rs",
-            "src/tools/solana/solana_public.rs",
-        ]
-    };
+    let sections: Vec<&str> = vec![
+        "src/tools/read/balances/read_balances.rs",
+        "src/tools/read/objects/read_objects.rs",
+        "src/tools/read/transactions/read_transactions.rs",
+        "src/tools/intent/nl_intent.rs",
+        "src/tools/intent/templates/transaction_templates.rs",
+        "src/tools/auth/zklogin/zklogin.rs",
+        "src/tools/auth/keystore/keystore.rs",
+        "src/tools/auth/keystore/mnemonic.rs",
+        "src/tools/tx/pay/tx_build.rs",
+        "src/tools/tx/sponsor/sui_sponsor.rs",
+        "src/tools/tx/inspections/tx_sim.rs",
+        "src/tools/tx/staking/tx_stakes.rs",
+        "src/tools/tx/queries/checkpoints.rs",
+        "src/tools/tx/queries/tx_queries.rs",
+        "src/tools/tx/queries/coin_queries.rs",
+        "src/tools/move/schema/move_schema.rs",
+        "src/tools/move/schema/move_suggest.rs",
+        "src/tools/move/resolution/move_resolve.rs",
+        "src/tools/move/dynamic/dapp_manifest.rs",
+        "src/tools/move/automation/move_auto.rs",
+        "src/tools/move/dynamic/move_dynamic.rs",
+        "src/tools/graphql/core/graphql_tools.rs",
+        "src/tools/rpc/service/rpc_tools.rs",
+        "src/tools/crypto/signature/crypto_tools.rs",
+        "src/tools/crypto/multisig/multisig_tools.rs",
+        "src/tools/system/gas/system_gas.rs",
+        "src/tools/system/events/system_events.rs",
+        "src/tools/system/stats/system_stats.rs",
+        "src/tools/system/coins/coin_read.rs",
+        "src/tools/system/chain/chain_info.rs",
+        "src/tools/system/config/system_config.rs",
+        "src/tools/system/pending/system_pending.rs",
+        "src/tools/system/audit/system_audit.rs",
+        "src/tools/system/metrics/system_metrics.rs",
+        "src/tools/system/cache/system_cache.rs",
+        "src/tools/system/workflow/system_workflow.rs",
+        "src/tools/evm/evm_tools.rs",
+        "src/tools/solana/solana_tools.rs",
+    ];
 
     let mut content = String::new();
 
@@ -75,8 +74,9 @@ fn main() {
     content.push_str("}\n\n");
 
     // 2) Tool router impl block (tools only)
-    // Note: some tools are behind optional cargo features. When those features are OFF,
-    // the generated methods may not be referenced by the router, triggering dead_code under -D warnings.
+    // Note: some tools are behind optional cargo features (e.g. solana-extended-tools). When those
+    // features are OFF, the generated methods may not be referenced by the router, triggering
+    // dead_code under -D warnings.
     content.push_str("#[allow(dead_code)]\n");
     content.push_str("#[allow(clippy::empty_line_after_outer_attr, clippy::manual_find, clippy::bool_comparison, clippy::get_first, clippy::too_many_arguments, clippy::unnecessary_to_owned, clippy::type_complexity, clippy::redundant_locals, clippy::bind_instead_of_map, clippy::unwrap_or_default)]\n");
     content.push_str("#[tool_router]\nimpl Web3McpServer {\n");
@@ -96,7 +96,4 @@ fn main() {
     for section in sections.iter() {
         println!("cargo:rerun-if-changed={}", section);
     }
-
-    // Re-run if feature flags change.
-    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_EXPOSE_ADVANCED_TOOLS");
 }
@@ -0,0 +1,131 @@
+//! MCP streamable-HTTP transport (`web3mcp --http`).
+//!
+//! One endpoint (`/mcp` by default) serves every session. Requests must carry a configured
+//! token (see `utils/http_auth.rs`), browsers are limited to the CORS origin allowlist, and
+//! each session gets its own `Web3McpServer::for_session` copy, so pending confirmations and
+//! resource subscriptions never leak between clients. Tool-call metrics are served in the
+//! Prometheus text format on `/metrics` (`WEB3MCP_METRICS_PATH`), behind the same auth.
+
+use crate::utils::http_auth::{self, HttpAuth};
+use crate::Web3McpServer;
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::{info, warn};
+
+pub const DEFAULT_BIND: &str = "127.0.0.1:8080";
+pub const DEFAULT_PATH: &str = "/mcp";
+pub const DEFAULT_METRICS_PATH: &str = "/metrics";
+
+async fn metrics() -> Response {
+    (
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        crate::utils::metrics::render_prometheus(),
+    )
+        .into_response()
+}
+
+async fn require_auth(State(auth): State<Arc<HttpAuth>>, request: Request, next: Next) -> Response {
+    let header_str = |name: &str| request.headers().get(name).and_then(|v| v.to_str().ok());
+    let authorized = auth.authorize(
+        header_str(header::AUTHORIZATION.as_str()),
+        header_str(http_auth::API_KEY_HEADER),
+    );
+    if authorized {
+        return next.run(request).await;
+    }
+    warn!(
+        method = %request.method(),
+        uri = %request.uri(),
+        "Rejected unauthenticated MCP request"
+    );
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+        "missing or invalid token (Authorization: Bearer <token> or X-API-Key: <token>)",
+    )
+        .into_response()
+}
+
+fn cors_layer(origins: &[String]) -> CorsLayer {
+    let origins: Vec<HeaderValue> = origins
+        .iter()
+        .filter_map(|o| HeaderValue::from_str(o).ok())
+        .collect();
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods([Method::GET, Method::POST, Method::DELETE, Method::OPTIONS])
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            header::ACCEPT,
+            HeaderName::from_static(http_auth::API_KEY_HEADER),
+            HeaderName::from_static("mcp-session-id"),
+            HeaderName::from_static("mcp-protocol-version"),
+            HeaderName::from_static("last-event-id"),
+        ])
+        .expose_headers([HeaderName::from_static("mcp-session-id")])
+}
+
+/// Serve `server` on `bind` until Ctrl-C.
+pub async fn serve(server: Web3McpServer, bind: SocketAddr, path: &str) -> anyhow::Result<()> {
+    let auth = HttpAuth::from_config();
+    auth.check_bind(&bind).map_err(anyhow::Error::msg)?;
+    let origins = http_auth::cors_origins();
+
+    let service = StreamableHttpService::new(
+        move || Ok(server.for_session()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig::default(),
+    );
+    let metrics_path = crate::utils::config::var("WEB3MCP_METRICS_PATH")
+        .unwrap_or_else(|_| DEFAULT_METRICS_PATH.to_string());
+    if metrics_path == path {
+        anyhow::bail!(
+            "WEB3MCP_METRICS_PATH must differ from the MCP path {}",
+            path
+        );
+    }
+    let auth_layer = middleware::from_fn_with_state(Arc::new(auth.clone()), require_auth);
+    let mut router = axum::Router::new()
+        .route(&metrics_path, axum::routing::get(metrics))
+        .nest_service(path, service)
+        .layer(auth_layer);
+    // Outermost, so preflight requests (which carry no token) are answered before auth.
+    if !origins.is_empty() {
+        router = router.layer(cors_layer(&origins));
+    }
+
+    let listener = tokio::net::TcpListener::bind(bind).await?;
+    info!(
+        "Web3MCP streamable HTTP server running on http://{}{} (metrics: {}, auth: {}, CORS origins: {})",
+        bind,
+        path,
+        metrics_path,
+        if auth.is_enabled() {
+            "token"
+        } else {
+            "none, loopback only"
+        },
+        if origins.is_empty() {
+            "none".to_string()
+        } else {
+            origins.join(", ")
+        }
+    );
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
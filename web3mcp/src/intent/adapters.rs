@@ -1,22 +1,675 @@
-#![allow(dead_code)]
+//! Chain adapters: the per-chain half of the intent pipeline.
+//!
+//! `w3rt_run_workflow_v0` and `execute_intent` only orchestrate stages (artifacts, guards,
+//! overrides). Everything chain-specific goes through a [`ChainAdapter`] looked up in the
+//! registry below, so adding a chain or an action means implementing (or extending) an adapter
+//! rather than editing the workflow tool.
 
-pub trait ChainAdapter {
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+
+use rmcp::model::{ErrorCode, ErrorData};
+use serde_json::{json, Value};
+
+use crate::Web3McpServer;
+
+/// Boxed future returned by adapter stages (no `async fn` in object-safe traits).
+pub type AdapterFuture<'a> = Pin<Box<dyn Future<Output = Result<Value, ErrorData>> + Send + 'a>>;
+
+pub trait ChainAdapter: Send + Sync {
+    /// Chain key as used in `intent.chain` ("solana" | "evm" | "sui").
     fn name(&self) -> &'static str;
-    fn supports(&self, action: &str) -> bool;
+
+    /// Normalized workflow actions this adapter can simulate/execute.
+    fn actions(&self) -> &'static [&'static str];
+
+    fn supports(&self, action: &str) -> bool {
+        self.actions().contains(&action)
+    }
+
+    /// Chain-specific aliasing (action names, sender fields, resolved_network).
+    /// Runs after the chain-agnostic aliasing in [`normalize_intent`].
+    fn normalize(&self, intent: &mut Value);
+
+    /// Build + dry-run an intent; returns the `simulate` stage artifact (the built transaction
+    /// is part of it: there is no separate build stage).
+    fn simulate<'a>(&'a self, server: &'a Web3McpServer, intent: &'a Value) -> AdapterFuture<'a>;
+
+    /// Summarize a simulate artifact into the `approval` stage artifact (warnings + status).
+    fn summarize<'a>(&'a self, server: &'a Web3McpServer, simulate: &'a Value)
+        -> AdapterFuture<'a>;
+
+    /// Approval stage: [`ChainAdapter::summarize`], then the declarative approval policy
+    /// (`src/utils/approval_policy.rs`) over the intent and simulation.
+    fn approve<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        intent: &'a Value,
+        simulate: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move {
+            let approval = self.summarize(server, simulate).await?;
+            Ok(crate::utils::approval_policy::apply(
+                self.name(),
+                intent,
+                simulate,
+                approval,
+            ))
+        })
+    }
+
+    /// Bindable outputs of a simulated step (workflow v1 `{{steps.<id>.outputs.<field>}}`).
+    ///
+    /// Swaps expose `output_token`, `amount_out_raw` and `min_amount_out_raw`, plus UI amounts
+    /// (`amount_out`, `min_amount_out`) when the output decimals are known.
+    fn outputs(&self, simulate: &Value) -> Value;
+
+    /// Outputs of an executed step read back from its landed transaction, so dependent steps
+    /// bind to what was actually received rather than to the quote. `Null` while the step's
+    /// pending confirmation has not been broadcast or the transaction is not found yet; steps
+    /// that created no pending confirmation keep their simulated outputs.
+    fn landed_outputs<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        simulate: &'a Value,
+        execute: &'a Value,
+    ) -> AdapterFuture<'a>;
+
+    /// Create the pending confirmation for an approved intent; returns the `execute` artifact.
+    /// Guards (no-sim-no-send, approval/override) are enforced by the caller.
+    fn execute<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        run_id: &'a str,
+        intent: &'a Value,
+        simulate: &'a Value,
+        approval: &'a Value,
+    ) -> AdapterFuture<'a>;
 }
 
-pub struct SuiAdapter;
+static ADAPTERS: &[&dyn ChainAdapter] = &[&SolanaAdapter, &EvmAdapter, &SuiAdapter];
 
-impl ChainAdapter for SuiAdapter {
+/// All registered adapters, in lookup order.
+pub fn adapters() -> &'static [&'static dyn ChainAdapter] {
+    ADAPTERS
+}
+
+pub fn adapter_for(chain: &str) -> Option<&'static dyn ChainAdapter> {
+    ADAPTERS.iter().copied().find(|a| a.name() == chain)
+}
+
+/// Adapter for a normalized intent, if its chain is registered and supports the action.
+pub fn adapter_for_intent(intent: &Value) -> Option<&'static dyn ChainAdapter> {
+    let chain = intent.get("chain").and_then(Value::as_str)?;
+    let action = intent.get("action").and_then(Value::as_str)?;
+    adapter_for(chain).filter(|a| a.supports(action))
+}
+
+/// Placeholder artifact for stages an adapter does not implement (yet).
+pub fn stage_todo(stage: &str, note: &str) -> Value {
+    json!({
+        "stage": stage,
+        "status": "todo",
+        "note": note,
+    })
+}
+
+/// Normalize common aliases so NL / external clients reliably hit the implemented code paths.
+pub fn normalize_intent(
+    intent: &mut Value,
+    request_sender: Option<&str>,
+    request_network: Option<&str>,
+) {
+    if !intent.is_object() {
+        return;
+    }
+
+    // EVM chain names ("base", "ethereum", ...) are accepted as chain and mapped to chain="evm".
+    if let Some(c) = intent
+        .get("chain")
+        .and_then(Value::as_str)
+        .map(|s| s.to_lowercase())
+    {
+        if adapter_for(&c).is_none() {
+            if let Some(id) = Web3McpServer::w3rt_evm_chain_id_for_name(&c) {
+                intent["chain"] = Value::String("evm".to_string());
+                if intent.get("chain_id").is_none() {
+                    intent["chain_id"] = json!(id);
+                }
+            }
+        }
+    }
+
+    // Infer chain from explicit chain hints before falling back to the Solana-first heuristic.
+    if intent.get("chain").is_none() {
+        let family = intent
+            .get("resolved_network")
+            .and_then(|v| v.get("family"))
+            .and_then(Value::as_str)
+            .map(|s| s.to_string());
+        if intent.get("chain_id").is_some() || family.as_deref() == Some("evm") {
+            intent["chain"] = Value::String("evm".to_string());
+        } else if let Some(f) = family.filter(|f| f == "solana" || f == "sui") {
+            intent["chain"] = Value::String(f);
+        }
+    }
+
+    // Ensure chain is present for Solana actions.
+    if intent.get("chain").is_none() {
+        // Heuristic: without any chain hint, swap/quote/transfer actions are Solana-first.
+        if let Some(a) = intent.get("action").and_then(Value::as_str) {
+            if a.starts_with("swap")
+                || a.contains("quote")
+                || a.starts_with("transfer")
+                || a.contains("jupiter")
+            {
+                intent["chain"] = Value::String("solana".to_string());
+            }
+        }
+    }
+
+    // Ensure user_pubkey is present (use request.sender if caller omitted it).
+    if intent.get("user_pubkey").is_none() {
+        if let Some(s) = intent.get("sender").and_then(Value::as_str) {
+            // tolerate alternate field name
+            intent["user_pubkey"] = Value::String(s.to_string());
+        } else if let Some(s) = request_sender {
+            intent["user_pubkey"] = Value::String(s.to_string());
+        }
+    }
+
+    // Ensure network is present (use request.network if caller omitted it).
+    if intent.get("network").is_none() {
+        if let Some(n) = request_network {
+            intent["network"] = Value::String(n.to_string());
+        }
+    }
+
+    // Token aliases.
+    if intent.get("input_token").is_none() {
+        if let Some(v) = intent.get("from_token").cloned() {
+            intent["input_token"] = v;
+        } else if let Some(v) = intent.get("input_mint").cloned() {
+            // Some clients emit mint fields; treat them as tokens (we resolve mint vs symbol later).
+            intent["input_token"] = v;
+        }
+    }
+    if intent.get("output_token").is_none() {
+        if let Some(v) = intent.get("to_token").cloned() {
+            intent["output_token"] = v;
+        } else if let Some(v) = intent.get("output_mint").cloned() {
+            intent["output_token"] = v;
+        }
+    }
+
+    // Chain-specific aliasing. Unknown/missing chains keep the Solana-first defaults.
+    let chain = intent
+        .get("chain")
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .to_string();
+    adapter_for(&chain)
+        .unwrap_or(&SolanaAdapter)
+        .normalize(intent);
+
+    // Amount aliases.
+    let action = intent
+        .get("action")
+        .and_then(Value::as_str)
+        .unwrap_or("")
+        .to_string();
+    if action == "swap_exact_in" && intent.get("amount_in").is_none() {
+        if let Some(v) = intent.get("amount_in_ui").cloned() {
+            intent["amount_in"] = v;
+        } else if let Some(v) = intent.get("amount").cloned() {
+            intent["amount_in"] = v;
+        }
+    }
+    if action == "swap_exact_out" && intent.get("amount_out").is_none() {
+        if let Some(v) = intent.get("amount_out_ui").cloned() {
+            intent["amount_out"] = v;
+        } else if let Some(v) = intent.get("amount").cloned() {
+            intent["amount_out"] = v;
+        }
+    }
+}
+
+/// Network name from `intent.network` or `intent.resolved_network.network_name`.
+fn intent_network_name(intent: &Value) -> Option<String> {
+    intent
+        .get("network")
+        .and_then(Value::as_str)
+        .or_else(|| {
+            intent
+                .get("resolved_network")
+                .and_then(|v| v.get("network_name"))
+                .and_then(Value::as_str)
+        })
+        .map(|s| s.to_string())
+}
+
+/// Solana and Sui share the mainnet/testnet/devnet naming.
+/// Accept network strings like "solana-mainnet" and normalize to resolved_network.network_name.
+fn normalize_cluster_network(intent: &mut Value, family: &str) {
+    let Some(n) = intent_network_name(intent) else {
+        return;
+    };
+    let lower = n.to_lowercase();
+    let canonical = if lower.contains("devnet") {
+        "devnet"
+    } else if lower.contains("testnet") {
+        "testnet"
+    } else if lower.contains("main") {
+        "mainnet"
+    } else {
+        n.as_str()
+    };
+
+    if intent.get("resolved_network").is_none() {
+        intent["resolved_network"] = json!({
+            "family": family,
+            "network_name": canonical
+        });
+    } else if intent
+        .get("resolved_network")
+        .and_then(|v| v.get("network_name"))
+        .is_none()
+    {
+        intent["resolved_network"]["network_name"] = Value::String(canonical.to_string());
+        if intent["resolved_network"]["family"].is_null() {
+            intent["resolved_network"]["family"] = Value::String(family.to_string());
+        }
+    } else {
+        intent["resolved_network"]["network_name"] = Value::String(canonical.to_string());
+    }
+}
+
+/// EVM/Sui: a bare "swap" is a sell-exact-in (0x / 7K); transfers default `from` to the sender.
+fn normalize_swap_and_sender(intent: &mut Value) {
+    if intent.get("action").and_then(Value::as_str) == Some("swap") {
+        intent["action"] = Value::String("swap_exact_in".to_string());
+    }
+    if intent.get("from").is_none() {
+        if let Some(v) = intent.get("user_pubkey").cloned() {
+            intent["from"] = v;
+        }
+    }
+}
+
+fn simulate_ok(simulate: &Value) -> bool {
+    simulate.get("status").and_then(Value::as_str) == Some("ok")
+}
+
+/// Raw integer amount from a quote field (aggregators return strings or numbers).
+fn raw_amount(v: Option<&Value>) -> Option<String> {
+    match v? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn swap_outputs(
+    output_token: Option<&Value>,
+    amount_out_raw: Option<String>,
+    min_amount_out_raw: Option<String>,
+    decimals: Option<u8>,
+) -> Value {
+    use crate::utils::solana_workflow::format_base_units_ui;
+
+    let ui = |raw: &Option<String>| match (raw, decimals) {
+        (Some(r), Some(d)) => json!(format_base_units_ui(r, d)),
+        _ => Value::Null,
+    };
+    json!({
+        "output_token": output_token,
+        "output_decimals": decimals,
+        "amount_out_raw": amount_out_raw,
+        "min_amount_out_raw": min_amount_out_raw,
+        "amount_out": ui(&amount_out_raw),
+        "min_amount_out": ui(&min_amount_out_raw),
+        "source": "quote",
+    })
+}
+
+/// Quoted swap outputs with the amount actually received: `amount_out_raw` (and `amount_out`
+/// when the decimals are known) become the landed amount, the quote moves to
+/// `quoted_amount_out_raw`.
+pub fn with_received_amount(quoted: &Value, received_raw: &str) -> Value {
+    use crate::utils::solana_workflow::format_base_units_ui;
+
+    let mut out = quoted.clone();
+    out["quoted_amount_out_raw"] = quoted.get("amount_out_raw").cloned().unwrap_or(Value::Null);
+    out["amount_out_raw"] = json!(received_raw);
+    if let Some(d) = quoted.get("output_decimals").and_then(Value::as_u64) {
+        out["amount_out"] = json!(format_base_units_ui(received_raw, d as u8));
+    }
+    out["source"] = json!("landed");
+    out
+}
+
+/// Outputs that do not depend on execution (transfers, steps without a broadcast), marked landed.
+fn landed_as_quoted(quoted: Value) -> Value {
+    let mut out = quoted;
+    if out.is_object() {
+        out["source"] = json!("landed");
+    }
+    out
+}
+
+/// Broadcast state of the pending confirmation an execute artifact points at.
+enum StepTx {
+    /// The step created no pending confirmation (read-only, or nothing to send).
+    NoPending,
+    /// Not broadcast yet.
+    Unsent,
+    /// Broadcast with this signature / digest / tx hash.
+    Sent(String),
+}
+
+fn step_tx(execute: &Value, pointer: &str) -> Result<StepTx, ErrorData> {
+    use crate::utils::pending_store::{self, PendingStatus};
+
+    let Some(id) = execute.pointer(pointer).and_then(Value::as_str) else {
+        return Ok(StepTx::NoPending);
+    };
+    let conn = pending_store::connect()?;
+    let tx_ref = pending_store::get(&conn, id)?
+        .filter(|r| matches!(r.status, PendingStatus::Sent | PendingStatus::Confirmed))
+        .and_then(|r| r.tx_ref);
+    Ok(tx_ref.map_or(StepTx::Unsent, StepTx::Sent))
+}
+
+fn landed_err(context: &str, reason: String) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("{context}: {reason}")),
+        data: None,
+    }
+}
+
+/// Integer amount from an RPC field (strings or numbers).
+fn int_amount(v: Option<&Value>) -> Option<i128> {
+    match v? {
+        Value::String(s) => s.parse().ok(),
+        Value::Number(n) => n.as_i64().map(i128::from),
+        _ => None,
+    }
+}
+
+fn non_negative(delta: i128) -> Result<String, String> {
+    if delta < 0 {
+        return Err(format!("balance decreased by {}", delta.unsigned_abs()));
+    }
+    Ok(delta.to_string())
+}
+
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Amount of `output_mint` the fee payer received in a landed Solana transaction
+/// (`getTransaction` JSON). Native SOL (unwrapped wSOL) is the lamport change plus the fee.
+pub fn solana_received_raw(tx: &Value, output_mint: &str) -> Result<String, String> {
+    let meta = tx.get("meta").ok_or("transaction has no meta")?;
+    if meta.get("err").is_some_and(|e| !e.is_null()) {
+        return Err(format!("transaction failed: {}", meta["err"]));
+    }
+    let key = tx
+        .pointer("/transaction/message/accountKeys/0")
+        .ok_or("transaction has no account keys")?;
+    let owner = key
+        .get("pubkey")
+        .unwrap_or(key)
+        .as_str()
+        .ok_or("invalid fee payer")?;
+
+    let token_total = |field: &str| -> i128 {
+        meta.get(field)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|b| {
+                b.get("mint").and_then(Value::as_str) == Some(output_mint)
+                    && b.get("owner").and_then(Value::as_str) == Some(owner)
+            })
+            .filter_map(|b| int_amount(b.pointer("/uiTokenAmount/amount")))
+            .sum()
+    };
+    let mut delta = token_total("postTokenBalances") - token_total("preTokenBalances");
+    if output_mint == WSOL_MINT {
+        let lamports = |field: &str| int_amount(meta.get(field).and_then(|v| v.get(0)));
+        let (Some(pre), Some(post)) = (lamports("preBalances"), lamports("postBalances")) else {
+            return Err("transaction has no lamport balances".to_string());
+        };
+        delta += post - pre + int_amount(meta.get("fee")).unwrap_or(0);
+    }
+    non_negative(delta)
+}
+
+/// Amount of `coin_type` `owner` received in a landed Sui transaction (effects + balance
+/// changes JSON). SUI outputs add back the net gas the sender paid.
+pub fn sui_received_raw(tx: &Value, owner: &str, coin_type: &str) -> Result<String, String> {
+    use crate::utils::spend_limits::normalize_sui_coin_type;
+
+    let status = tx.pointer("/effects/status/status").and_then(Value::as_str);
+    if status != Some("success") {
+        return Err(format!(
+            "transaction failed: {}",
+            tx.pointer("/effects/status")
+                .cloned()
+                .unwrap_or(Value::Null)
+        ));
+    }
+    let owner = owner.to_lowercase();
+    let coin_type = normalize_sui_coin_type(coin_type);
+    let mut delta: i128 = tx
+        .get("balanceChanges")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|c| {
+            c.pointer("/owner/AddressOwner")
+                .and_then(Value::as_str)
+                .map(str::to_lowercase)
+                .as_deref()
+                == Some(owner.as_str())
+                && c.get("coinType")
+                    .and_then(Value::as_str)
+                    .map(normalize_sui_coin_type)
+                    .as_deref()
+                    == Some(coin_type.as_str())
+        })
+        .filter_map(|c| int_amount(c.get("amount")))
+        .sum();
+    if coin_type == normalize_sui_coin_type("0x2::sui::SUI") {
+        let gas = |field: &str| int_amount(tx.pointer(&format!("/effects/gasUsed/{field}")));
+        delta += gas("computationCost").unwrap_or(0) + gas("storageCost").unwrap_or(0)
+            - gas("storageRebate").unwrap_or(0);
+    }
+    non_negative(delta)
+}
+
+/// keccak256("Transfer(address,address,uint256)")
+const ERC20_TRANSFER_TOPIC: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+/// Sum of ERC-20 `token` Transfer logs to `recipient` in a successful EVM receipt (JSON).
+pub fn evm_erc20_received_raw(
+    receipt: &Value,
+    token: &str,
+    recipient: &str,
+) -> Result<String, String> {
+    let recipient = recipient.trim_start_matches("0x").to_lowercase();
+    let mut total = ethers::types::U256::zero();
+    for log in receipt
+        .get("logs")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let topics = log.get("topics").and_then(Value::as_array);
+        let topic = |i: usize| {
+            topics
+                .and_then(|t| t.get(i))
+                .and_then(Value::as_str)
+                .map(str::to_lowercase)
+        };
+        let to_matches = topic(2).is_some_and(|t| t.ends_with(&recipient));
+        let address = log.get("address").and_then(Value::as_str).unwrap_or("");
+        if !address.eq_ignore_ascii_case(token)
+            || topic(0).as_deref() != Some(ERC20_TRANSFER_TOPIC)
+            || !to_matches
+        {
+            continue;
+        }
+        let data = log.get("data").and_then(Value::as_str).unwrap_or("0x");
+        let amount = ethers::types::U256::from_str_radix(data.trim_start_matches("0x"), 16)
+            .map_err(|e| format!("invalid Transfer data: {e}"))?;
+        total = total.saturating_add(amount);
+    }
+    Ok(total.to_string())
+}
+
+/// Native amount received by the sender of a landed EVM transaction from its balance before and
+/// after the block, adding back the gas fee and the value it sent.
+pub fn evm_native_received_raw(
+    before: ethers::types::U256,
+    after: ethers::types::U256,
+    fee: ethers::types::U256,
+    value: ethers::types::U256,
+) -> Result<String, String> {
+    let spent = fee.saturating_add(value);
+    after
+        .saturating_add(spent)
+        .checked_sub(before)
+        .map(|v| v.to_string())
+        .ok_or_else(|| "balance decreased by more than the fee and value".to_string())
+}
+
+/// Fields shared by transfer artifacts on every chain.
+fn transfer_outputs(simulate: &Value) -> Value {
+    let mut out = json!({"source": "quote"});
+    for key in [
+        "to",
+        "amount_ui",
+        "amount_raw",
+        "amount_base",
+        "mint",
+        "token_address",
+    ] {
+        if let Some(v) = simulate.get(key).filter(|v| !v.is_null()) {
+            out[key] = v.clone();
+        }
+    }
+    out
+}
+
+pub struct SolanaAdapter;
+
+impl ChainAdapter for SolanaAdapter {
     fn name(&self) -> &'static str {
-        "sui"
+        "solana"
     }
 
-    fn supports(&self, action: &str) -> bool {
-        matches!(
-            action,
-            "transfer" | "swap" | "stake" | "unstake" | "pay" | "query"
-        )
+    fn actions(&self) -> &'static [&'static str] {
+        &[
+            "transfer_native",
+            "transfer_spl",
+            "swap_exact_in",
+            "swap_exact_out",
+            "quote",
+            "jupiter_quote",
+            "tx_preview",
+            "get_token_balance",
+            "get_portfolio",
+        ]
+    }
+
+    fn normalize(&self, intent: &mut Value) {
+        normalize_cluster_network(intent, "solana");
+    }
+
+    fn simulate<'a>(&'a self, server: &'a Web3McpServer, intent: &'a Value) -> AdapterFuture<'a> {
+        Box::pin(server.w3rt_solana_simulate(intent))
+    }
+
+    fn summarize<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        simulate: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(server.w3rt_solana_approval(simulate))
+    }
+
+    fn outputs(&self, simulate: &Value) -> Value {
+        let Some(quote) = simulate.get("quote") else {
+            return transfer_outputs(simulate);
+        };
+        let output_mint = simulate
+            .get("output_mint")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        let decimals = simulate
+            .get("output_decimals")
+            .or_else(|| {
+                simulate
+                    .get("output_token_info")
+                    .and_then(|t| t.get("decimals"))
+            })
+            .and_then(Value::as_u64)
+            .map(|d| d as u8)
+            .or(match output_mint {
+                "So11111111111111111111111111111111111111112" => Some(9),
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" => Some(6),
+                _ => None,
+            });
+        let mut out = swap_outputs(
+            simulate.get("output_token"),
+            raw_amount(quote.get("outAmount")),
+            // ExactIn: otherAmountThreshold is the minimum out after slippage.
+            if simulate.get("swap_mode").and_then(Value::as_str) == Some("ExactOut") {
+                raw_amount(quote.get("outAmount"))
+            } else {
+                raw_amount(quote.get("otherAmountThreshold"))
+            },
+            decimals,
+        );
+        out["output_mint"] = json!(output_mint);
+        out
+    }
+
+    fn landed_outputs<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        simulate: &'a Value,
+        execute: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move {
+            let quoted = self.outputs(simulate);
+            let signature = match step_tx(execute, "/result/pending_confirmation_id")? {
+                StepTx::NoPending => return Ok(landed_as_quoted(quoted)),
+                StepTx::Unsent => return Ok(Value::Null),
+                StepTx::Sent(sig) => sig,
+            };
+            if simulate.get("quote").is_none() {
+                return Ok(landed_as_quoted(quoted));
+            }
+            Ok(server
+                .w3rt_solana_received_raw(simulate, &signature)
+                .await?
+                .map_or(Value::Null, |raw| with_received_amount(&quoted, &raw)))
+        })
+    }
+
+    fn execute<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        run_id: &'a str,
+        intent: &'a Value,
+        simulate: &'a Value,
+        approval: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(server.w3rt_solana_execute(run_id, intent, simulate, approval))
     }
 }
 
@@ -27,19 +680,437 @@ impl ChainAdapter for EvmAdapter {
         "evm"
     }
 
-    fn supports(&self, _action: &str) -> bool {
-        false
+    fn actions(&self) -> &'static [&'static str] {
+        &["transfer_native", "transfer_erc20", "swap_exact_in"]
+    }
+
+    // EVM networks are identified by chain_id; keep the given name and record the id.
+    fn normalize(&self, intent: &mut Value) {
+        normalize_swap_and_sender(intent);
+
+        let chain_id = Web3McpServer::w3rt_evm_chain_id(intent);
+        if intent.get("chain_id").is_none() {
+            if let Some(id) = chain_id {
+                intent["chain_id"] = json!(id);
+            }
+        }
+        let net = intent_network_name(intent);
+        if !intent.get("resolved_network").is_some_and(Value::is_object) {
+            intent["resolved_network"] = json!({});
+        }
+        intent["resolved_network"]["family"] = Value::String("evm".to_string());
+        intent["resolved_network"]["chain_id"] = json!(chain_id);
+        if let Some(n) = net {
+            intent["resolved_network"]["network_name"] = Value::String(n);
+        }
+    }
+
+    fn simulate<'a>(&'a self, server: &'a Web3McpServer, intent: &'a Value) -> AdapterFuture<'a> {
+        Box::pin(server.w3rt_evm_simulate(intent))
+    }
+
+    fn summarize<'a>(
+        &'a self,
+        _server: &'a Web3McpServer,
+        simulate: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move {
+            if !simulate_ok(simulate) {
+                return Ok(stage_todo(
+                    "approval",
+                    "Approval not implemented for this intent yet.",
+                ));
+            }
+            Ok(Web3McpServer::w3rt_evm_approval(simulate))
+        })
+    }
+
+    fn outputs(&self, simulate: &Value) -> Value {
+        if simulate.get("buy_amount_raw").is_none() {
+            return transfer_outputs(simulate);
+        }
+        let mut out = swap_outputs(
+            simulate.get("output_token"),
+            raw_amount(simulate.get("buy_amount_raw")),
+            raw_amount(simulate.get("min_buy_amount_raw")),
+            None,
+        );
+        out["output_token_address"] = simulate
+            .get("buy_token_address")
+            .cloned()
+            .unwrap_or(Value::Null);
+        out
+    }
+
+    fn landed_outputs<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        simulate: &'a Value,
+        execute: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move {
+            let quoted = self.outputs(simulate);
+            let tx_hash = match step_tx(execute, "/result/tx/confirmation_id")? {
+                StepTx::NoPending => return Ok(landed_as_quoted(quoted)),
+                StepTx::Unsent => return Ok(Value::Null),
+                StepTx::Sent(hash) => hash,
+            };
+            if simulate.get("buy_amount_raw").is_none() {
+                return Ok(landed_as_quoted(quoted));
+            }
+            Ok(server
+                .w3rt_evm_received_raw(simulate, &tx_hash)
+                .await?
+                .map_or(Value::Null, |raw| with_received_amount(&quoted, &raw)))
+        })
+    }
+
+    // Safe default: store evm_confirm_store pending rows (approve + swap are linked).
+    fn execute<'a>(
+        &'a self,
+        _server: &'a Web3McpServer,
+        run_id: &'a str,
+        _intent: &'a Value,
+        simulate: &'a Value,
+        approval: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move { Web3McpServer::w3rt_evm_execute(run_id, simulate, approval) })
     }
 }
 
-pub struct SolanaAdapter;
+pub struct SuiAdapter;
 
-impl ChainAdapter for SolanaAdapter {
+impl ChainAdapter for SuiAdapter {
     fn name(&self) -> &'static str {
-        "solana"
+        "sui"
+    }
+
+    fn actions(&self) -> &'static [&'static str] {
+        &[
+            "transfer_native",
+            "transfer_sui",
+            "pay",
+            "pay_sui",
+            "swap_exact_in",
+        ]
+    }
+
+    fn normalize(&self, intent: &mut Value) {
+        normalize_swap_and_sender(intent);
+        normalize_cluster_network(intent, "sui");
+    }
+
+    fn simulate<'a>(&'a self, server: &'a Web3McpServer, intent: &'a Value) -> AdapterFuture<'a> {
+        Box::pin(server.w3rt_sui_simulate(intent))
+    }
+
+    fn summarize<'a>(
+        &'a self,
+        _server: &'a Web3McpServer,
+        simulate: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move {
+            if !simulate_ok(simulate) {
+                return Ok(stage_todo(
+                    "approval",
+                    "Approval not implemented for this intent yet.",
+                ));
+            }
+            Ok(Web3McpServer::w3rt_sui_approval(simulate))
+        })
+    }
+
+    fn outputs(&self, simulate: &Value) -> Value {
+        if simulate.get("expected_return").is_none() {
+            return transfer_outputs(simulate);
+        }
+        let mut out = swap_outputs(
+            simulate.get("output_token"),
+            raw_amount(simulate.get("expected_return")),
+            raw_amount(simulate.get("min_received")),
+            None,
+        );
+        out["output_coin_type"] = simulate
+            .get("output_coin_type")
+            .cloned()
+            .unwrap_or(Value::Null);
+        out
+    }
+
+    fn landed_outputs<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        simulate: &'a Value,
+        execute: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move {
+            let quoted = self.outputs(simulate);
+            let digest = match step_tx(execute, "/result/confirmation_id")? {
+                StepTx::NoPending => return Ok(landed_as_quoted(quoted)),
+                StepTx::Unsent => return Ok(Value::Null),
+                StepTx::Sent(digest) => digest,
+            };
+            if simulate.get("expected_return").is_none() {
+                return Ok(landed_as_quoted(quoted));
+            }
+            let raw = server.w3rt_sui_received_raw(simulate, &digest).await?;
+            Ok(with_received_amount(&quoted, &raw))
+        })
+    }
+
+    // Safe default: store a sui_confirm_store pending row (sui_confirm_execution broadcasts).
+    fn execute<'a>(
+        &'a self,
+        server: &'a Web3McpServer,
+        run_id: &'a str,
+        _intent: &'a Value,
+        simulate: &'a Value,
+        approval: &'a Value,
+    ) -> AdapterFuture<'a> {
+        Box::pin(async move { server.w3rt_sui_execute(run_id, simulate, approval) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUI_SENDER: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+    const SUI_RECIPIENT: &str =
+        "0x2222222222222222222222222222222222222222222222222222222222222222";
+
+    fn normalized(mut intent: Value) -> Value {
+        normalize_intent(&mut intent, Some(SUI_SENDER), None);
+        intent
+    }
+
+    #[test]
+    fn sui_transfer_pay_and_7k_intents_route_to_the_sui_adapter() {
+        let transfer = normalized(json!({
+            "chain": "sui", "action": "transfer_sui", "to": SUI_RECIPIENT, "amount": "1.5",
+            "network": "sui-testnet"
+        }));
+        let pay = normalized(json!({
+            "chain": "sui", "action": "pay_sui", "recipients": [SUI_RECIPIENT], "amounts": ["1"]
+        }));
+        let swap = normalized(json!({
+            "resolved_network": {"family": "sui", "network_name": "mainnet"},
+            "action": "swap", "from_token": "SUI", "to_token": "USDC", "amount": "2"
+        }));
+
+        for intent in [&transfer, &pay, &swap] {
+            let adapter = adapter_for_intent(intent).expect("sui adapter");
+            assert_eq!(adapter.name(), "sui");
+            // Transfers and pays sign as the request sender.
+            assert_eq!(intent["from"], SUI_SENDER);
+        }
+        assert_eq!(transfer["resolved_network"]["network_name"], "testnet");
+        assert_eq!(swap["chain"], "sui");
+        assert_eq!(swap["action"], "swap_exact_in");
+        assert_eq!(swap["input_token"], "SUI");
+        assert_eq!(swap["output_token"], "USDC");
+        assert_eq!(swap["amount_in"], "2");
+
+        // Sui has no exact-out route; such intents are not claimed by any adapter.
+        let exact_out = normalized(json!({"chain": "sui", "action": "swap_exact_out"}));
+        assert!(adapter_for_intent(&exact_out).is_none());
+    }
+
+    #[test]
+    fn sui_approval_flags_self_pays_and_zero_min_received() {
+        let transfer = json!({
+            "status": "ok", "adapter": "sui_transfer", "network": "testnet",
+            "from": SUI_SENDER, "to": SUI_RECIPIENT, "total_mist": "1500000000"
+        });
+        let approval = Web3McpServer::w3rt_sui_approval(&transfer);
+        assert_eq!(approval["status"], "ok");
+        assert_eq!(approval["summary"]["total_mist"], "1500000000");
+
+        let self_pay = json!({
+            "status": "ok", "adapter": "sui_pay", "network": "testnet",
+            "from": SUI_SENDER, "recipients": [SUI_RECIPIENT, SUI_SENDER]
+        });
+        let approval = Web3McpServer::w3rt_sui_approval(&self_pay);
+        assert_eq!(approval["status"], "needs_review");
+        assert_eq!(approval["warnings"][0]["kind"], "self_transfer");
+
+        let swap = json!({
+            "status": "ok", "adapter": "sui_7k", "network": "mainnet",
+            "user_pubkey": SUI_SENDER, "output_token": "USDC",
+            "output_coin_type": "0xdba3::usdc::USDC",
+            "expected_return": "1000", "min_received": "0"
+        });
+        let approval = Web3McpServer::w3rt_sui_approval(&swap);
+        assert_eq!(approval["warnings"][0]["kind"], "min_out_zero");
+        let outputs = SuiAdapter.outputs(&swap);
+        assert_eq!(outputs["amount_out_raw"], "1000");
+        assert_eq!(outputs["output_coin_type"], "0xdba3::usdc::USDC");
+    }
+
+    #[test]
+    fn intents_dispatch_to_the_adapter_for_their_chain_and_action() {
+        // EVM chain names become chain="evm" with the registry chain id.
+        let evm = normalized(json!({
+            "chain": "base", "action": "transfer_native", "to": "0xabc", "amount": "0.1"
+        }));
+        assert_eq!(evm["chain"], "evm");
+        assert_eq!(evm["chain_id"], 8453);
+        assert_eq!(evm["resolved_network"]["family"], "evm");
+        assert_eq!(adapter_for_intent(&evm).map(|a| a.name()), Some("evm"));
+
+        // Without a chain hint swaps and transfers are Solana-first.
+        let solana = normalized(json!({"action": "swap_exact_in", "input_token": "SOL"}));
+        assert_eq!(solana["chain"], "solana");
+        assert_eq!(
+            adapter_for_intent(&solana).map(|a| a.name()),
+            Some("solana")
+        );
+
+        // A chain_id is an EVM hint even without a chain.
+        let by_id = normalized(json!({"chain_id": 1, "action": "swap_exact_in"}));
+        assert_eq!(adapter_for_intent(&by_id).map(|a| a.name()), Some("evm"));
+
+        // Registered chain, unsupported action; unknown chain.
+        let quote = normalized(json!({"chain": "evm", "chain_id": 1, "action": "jupiter_quote"}));
+        assert!(adapter_for_intent(&quote).is_none());
+        assert!(adapter_for("bitcoin").is_none());
+        assert!(
+            adapter_for_intent(&json!({"chain": "bitcoin", "action": "transfer_native"})).is_none()
+        );
+
+        let names: Vec<_> = adapters().iter().map(|a| a.name()).collect();
+        assert_eq!(names, ["solana", "evm", "sui"]);
+    }
+
+    #[test]
+    fn execute_intent_keeps_the_evm_swap_fields() {
+        let simulate = json!({
+            "status": "ok", "adapter": "evm_0x", "sell_token_address": "0xsell",
+            "simulation": {"gas_used": 21000},
+            "approve": {
+                "needed": true, "spender": "0xspender", "allowance_raw": "0",
+                "required_raw": "100", "tx": {"to": "0xsell"}
+            }
+        });
+        let execute = json!({
+            "status": "pending_confirmation_created",
+            "result": {
+                "tx": {"confirmation_id": "swap_1", "tx_summary_hash": "h1"},
+                "approve": {"confirmation_id": "approve_1", "tx_summary_hash": "h2"}
+            }
+        });
+        let fields = Web3McpServer::intent_evm_swap_fields(&simulate, &execute);
+        assert_eq!(fields["provider"], "0x");
+        assert_eq!(
+            fields["swap"]["next"]["how_to_confirm"],
+            "confirm swap_1 hash:h1 (swap) (and include same network)"
+        );
+        assert_eq!(fields["swap"]["preflight"]["gas_used"], 21000);
+        assert_eq!(fields["approve"]["needed"], true);
+        assert_eq!(fields["approve"]["confirmation_id"], "approve_1");
+        assert_eq!(fields["approve"]["required_raw"], "100");
+        assert_eq!(fields["allowance"]["allowance_target"], "0xspender");
+        assert_eq!(fields["allowance"]["current"]["allowance_raw"], "0");
+        assert_eq!(fields["allowance"]["suggested_approve_tx"]["to"], "0xsell");
+
+        // Enough allowance: no approve step, and nothing to confirm when execute was blocked.
+        let mut simulate = simulate;
+        simulate["approve"]["needed"] = json!(false);
+        simulate["approve"]["tx"] = Value::Null;
+        let blocked = json!({"stage": "execute", "status": "blocked"});
+        let fields = Web3McpServer::intent_evm_swap_fields(&simulate, &blocked);
+        assert_eq!(fields["approve"]["needed"], false);
+        assert!(fields["approve"].get("confirmation_id").is_none());
+        assert!(fields["swap"].is_null());
+        assert!(fields["allowance"]["suggested_approve_tx"].is_null());
+    }
+
+    #[test]
+    fn dependent_bindings_use_the_landed_output_not_the_quote() {
+        const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        const PAYER: &str = "Payer11111111111111111111111111111111111111";
+        let simulate = json!({
+            "status": "ok", "output_token": "USDC", "output_mint": USDC, "swap_mode": "ExactIn",
+            "quote": {"outAmount": "12500000", "otherAmountThreshold": "12437500"}
+        });
+        let quoted = SolanaAdapter.outputs(&simulate);
+        assert_eq!(quoted["amount_out"], "12.500000");
+        assert_eq!(quoted["source"], "quote");
+
+        // The swap landed with less than quoted (price moved within slippage).
+        let balance = |amount: &str| json!([{"accountIndex": 3, "mint": USDC, "owner": PAYER, "uiTokenAmount": {"amount": amount}}]);
+        let tx = json!({
+            "transaction": {"message": {"accountKeys": [{"pubkey": PAYER, "signer": true}]}},
+            "meta": {
+                "err": null, "fee": 5000, "preBalances": [1_000_000], "postBalances": [995_000],
+                "preTokenBalances": balance("1000000"), "postTokenBalances": balance("13450000")
+            }
+        });
+        let raw = solana_received_raw(&tx, USDC).unwrap();
+        assert_eq!(raw, "12450000");
+        let landed = with_received_amount(&quoted, &raw);
+        assert_eq!(landed["source"], "landed");
+        assert_eq!(landed["quoted_amount_out_raw"], "12500000");
+
+        let ctx = json!({"steps": {"swap": {"outputs": landed}}});
+        let send = json!({"action": "transfer_spl", "amount": "{{steps.swap.outputs.amount_out}}"});
+        let resolved = crate::intent_plan::resolve_bindings(&send, &ctx).unwrap();
+        assert_eq!(resolved["amount"], "12.450000");
+
+        // Native SOL out: lamport change plus the fee.
+        assert_eq!(solana_received_raw(&tx, WSOL_MINT).unwrap(), "0");
+        let mut failed = tx.clone();
+        failed["meta"]["err"] = json!({"InstructionError": [0, "Custom"]});
+        assert!(solana_received_raw(&failed, USDC).is_err());
+
+        // Sui: the sender's balance change; SUI adds back the net gas.
+        let sui_tx = json!({
+            "effects": {
+                "status": {"status": "success"},
+                "gasUsed": {"computationCost": "1000", "storageCost": "500", "storageRebate": "300"}
+            },
+            "balanceChanges": [
+                {"owner": {"AddressOwner": SUI_SENDER}, "coinType": "0x2::sui::SUI", "amount": "48800"},
+                {"owner": {"AddressOwner": SUI_RECIPIENT}, "coinType": "0x2::sui::SUI", "amount": "7"}
+            ]
+        });
+        assert_eq!(
+            sui_received_raw(
+                &sui_tx,
+                SUI_SENDER,
+                "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI"
+            )
+            .unwrap(),
+            "50000"
+        );
+
+        // EVM: Transfer logs of the bought token to the sender.
+        let to = format!("0x{:0>64}", "abc0000000000000000000000000000000000001");
+        let receipt = json!({"status": "0x1", "logs": [
+            {"address": "0xTOKEN", "topics": [ERC20_TRANSFER_TOPIC, "0x00", to], "data": "0x64"},
+            {"address": "0xother", "topics": [ERC20_TRANSFER_TOPIC, "0x00", to], "data": "0x64"}
+        ]});
+        let raw = evm_erc20_received_raw(
+            &receipt,
+            "0xtoken",
+            "0xabc0000000000000000000000000000000000001",
+        );
+        assert_eq!(raw.unwrap(), "100");
+        let wei = ethers::types::U256::from;
+        assert_eq!(
+            evm_native_received_raw(wei(1000), wei(1900), wei(100), wei(0)).unwrap(),
+            "1000"
+        );
     }
 
-    fn supports(&self, _action: &str) -> bool {
-        false
+    #[test]
+    fn evm_swap_with_zero_min_out_needs_review() {
+        let swap = json!({
+            "status": "ok", "adapter": "evm_0x", "chain_id": 1, "network": "ethereum",
+            "user_pubkey": "0xabc", "min_buy_amount_raw": "0"
+        });
+        let approval = Web3McpServer::w3rt_evm_approval(&swap);
+        assert_eq!(approval["status"], "needs_review");
+        assert_eq!(approval["warnings"][0]["kind"], "min_out_zero");
     }
 }
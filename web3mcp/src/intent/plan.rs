@@ -0,0 +1,507 @@
+//! Workflow v1 plans: an ordered list or DAG of intents executed inside one W3RT run.
+//!
+//! Steps may bind outputs of earlier steps into their intent with `{{steps.<id>.<path>}}`
+//! (e.g. `{{steps.swap.outputs.amount_out}}`). A string that is exactly one binding is
+//! replaced by the bound JSON value; bindings embedded in longer strings are interpolated.
+//! `outputs` are read from the predecessor's landed transaction (see
+//! `ChainAdapter::landed_outputs`), so a step binding them is simulated only after it landed.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanStep {
+    pub id: String,
+    pub intent: Value,
+    pub depends_on: Vec<String>,
+}
+
+fn valid_step_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 64
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Parse `steps` (either `{id?, intent, depends_on?}` objects or bare intent objects).
+///
+/// Without any explicit `depends_on`, the list is ordered: each step depends on the previous one.
+/// Step ids referenced by bindings are always added as dependencies.
+pub fn parse_steps(steps: &[Value]) -> Result<Vec<PlanStep>, String> {
+    if steps.is_empty() {
+        return Err("plan must contain at least one step".to_string());
+    }
+
+    let explicit_deps = steps.iter().any(|s| s.get("depends_on").is_some());
+    let mut out: Vec<PlanStep> = Vec::with_capacity(steps.len());
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for (i, raw) in steps.iter().enumerate() {
+        if !raw.is_object() {
+            return Err(format!("step {} must be an object", i + 1));
+        }
+        let id = raw
+            .get("id")
+            .and_then(Value::as_str)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|| format!("step_{}", i + 1));
+        if !valid_step_id(&id) {
+            return Err(format!(
+                "invalid step id '{}' (use [A-Za-z0-9_-], max 64 chars)",
+                id
+            ));
+        }
+        if !seen.insert(id.clone()) {
+            return Err(format!("duplicate step id '{}'", id));
+        }
+
+        let intent = match raw.get("intent") {
+            Some(v) if v.is_object() => v.clone(),
+            Some(_) => return Err(format!("step '{}': intent must be an object", id)),
+            None => raw.clone(),
+        };
+
+        let mut depends_on: Vec<String> = if explicit_deps {
+            match raw.get("depends_on") {
+                None | Some(Value::Null) => vec![],
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .map(|v| {
+                        v.as_str().map(|s| s.to_string()).ok_or_else(|| {
+                            format!("step '{}': depends_on must be a list of step ids", id)
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+                Some(_) => {
+                    return Err(format!(
+                        "step '{}': depends_on must be a list of step ids",
+                        id
+                    ))
+                }
+            }
+        } else if i > 0 {
+            vec![out[i - 1].id.clone()]
+        } else {
+            vec![]
+        };
+
+        for r in binding_refs(&intent) {
+            if !depends_on.contains(&r) {
+                depends_on.push(r);
+            }
+        }
+
+        out.push(PlanStep {
+            id,
+            intent,
+            depends_on,
+        });
+    }
+
+    for step in &out {
+        for dep in &step.depends_on {
+            if dep == &step.id {
+                return Err(format!("step '{}' depends on itself", step.id));
+            }
+            if !seen.contains(dep) {
+                return Err(format!(
+                    "step '{}' depends on unknown step '{}'",
+                    step.id, dep
+                ));
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// Execution order (Kahn's algorithm, ties broken by declaration order). Errors on cycles.
+pub fn topo_order(steps: &[PlanStep]) -> Result<Vec<usize>, String> {
+    let index: BTreeMap<&str, usize> = steps
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.id.as_str(), i))
+        .collect();
+    let mut remaining: Vec<usize> = steps.iter().map(|s| s.depends_on.len()).collect();
+    let mut done = vec![false; steps.len()];
+    let mut order = Vec::with_capacity(steps.len());
+
+    while order.len() < steps.len() {
+        let Some(next) = (0..steps.len()).find(|i| !done[*i] && remaining[*i] == 0) else {
+            let stuck: Vec<&str> = (0..steps.len())
+                .filter(|i| !done[*i])
+                .map(|i| steps[i].id.as_str())
+                .collect();
+            return Err(format!(
+                "plan has a dependency cycle among: {}",
+                stuck.join(", ")
+            ));
+        };
+        done[next] = true;
+        order.push(next);
+        for (i, step) in steps.iter().enumerate() {
+            if step
+                .depends_on
+                .iter()
+                .any(|d| index.get(d.as_str()) == Some(&next))
+            {
+                remaining[i] -= 1;
+            }
+        }
+    }
+    Ok(order)
+}
+
+/// Inner expressions of every `{{ ... }}` in `s`.
+fn binding_exprs(s: &str) -> Vec<&str> {
+    let mut out = vec![];
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        out.push(after[..end].trim());
+        rest = &after[end + 2..];
+    }
+    out
+}
+
+/// Step ids referenced by bindings anywhere inside `v`.
+pub fn binding_refs(v: &Value) -> Vec<String> {
+    let mut out = vec![];
+    collect_refs(v, false, &mut out);
+    out
+}
+
+/// Step ids whose `outputs` are bound inside `v`. Outputs bind to what the step's transaction
+/// actually produced, so such a step waits until those predecessors have landed.
+pub fn output_refs(v: &Value) -> Vec<String> {
+    let mut out = vec![];
+    collect_refs(v, true, &mut out);
+    out
+}
+
+fn collect_refs(v: &Value, outputs_only: bool, out: &mut Vec<String>) {
+    match v {
+        Value::String(s) => {
+            for expr in binding_exprs(s) {
+                let Some(path) = expr.strip_prefix("steps.") else {
+                    continue;
+                };
+                let mut segs = path.split('.');
+                let id = segs.next().unwrap_or("");
+                if outputs_only && segs.next() != Some("outputs") {
+                    continue;
+                }
+                if !out.iter().any(|x| x == id) {
+                    out.push(id.to_string());
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|i| collect_refs(i, outputs_only, out)),
+        Value::Object(map) => map
+            .values()
+            .for_each(|i| collect_refs(i, outputs_only, out)),
+        _ => {}
+    }
+}
+
+fn lookup<'a>(ctx: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut cur = ctx;
+    for seg in path.split('.') {
+        cur = match cur {
+            Value::Array(items) => items.get(seg.parse::<usize>().ok()?)?,
+            _ => cur.get(seg)?,
+        };
+    }
+    (!cur.is_null()).then_some(cur)
+}
+
+/// Resolve `{{steps.<id>.<path>}}` bindings in `v` against `ctx` (`{"steps": {<id>: {...}}}`).
+pub fn resolve_bindings(v: &Value, ctx: &Value) -> Result<Value, String> {
+    match v {
+        Value::String(s) => {
+            let exprs = binding_exprs(s);
+            if exprs.is_empty() {
+                return Ok(v.clone());
+            }
+            let resolve = |expr: &str| -> Result<&Value, String> {
+                if !expr.starts_with("steps.") {
+                    return Err(format!("binding '{}' must start with steps.<id>", expr));
+                }
+                lookup(ctx, expr).ok_or_else(|| format!("binding '{}' is not available", expr))
+            };
+
+            let trimmed = s.trim();
+            if exprs.len() == 1 && trimmed.starts_with("{{") && trimmed.ends_with("}}") {
+                return resolve(exprs[0]).cloned();
+            }
+
+            let mut out = s.clone();
+            for expr in exprs {
+                let value = resolve(expr)?;
+                let text = value
+                    .as_str()
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| value.to_string());
+                out = out.replacen(&format!("{{{{{}}}}}", expr), &text, 1);
+                out = out.replacen(&format!("{{{{ {} }}}}", expr), &text, 1);
+            }
+            Ok(Value::String(out))
+        }
+        Value::Array(items) => Ok(Value::Array(
+            items
+                .iter()
+                .map(|i| resolve_bindings(i, ctx))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::Object(map) => {
+            let mut out = serde_json::Map::new();
+            for (k, item) in map {
+                out.insert(k.clone(), resolve_bindings(item, ctx)?);
+            }
+            Ok(Value::Object(out))
+        }
+        _ => Ok(v.clone()),
+    }
+}
+
+/// Single approval over every simulated step: `ok` only if each step approval is ok and no step
+/// failed; any policy `blocked` step blocks the whole plan.
+pub fn aggregate_approval(
+    step_approvals: &[(String, Value)],
+    deferred: &[String],
+    failed: &[String],
+) -> Value {
+    let mut warnings: Vec<Value> = vec![];
+    let mut any_review = false;
+    let mut any_blocked = false;
+    let mut all_ok = failed.is_empty();
+    let mut steps: Vec<Value> = vec![];
+    let mut matched_rule_ids: Vec<Value> = vec![];
+
+    for (id, approval) in step_approvals {
+        let status = approval
+            .get("status")
+            .and_then(Value::as_str)
+            .unwrap_or("todo");
+        all_ok &= status == "ok";
+        any_review |= status == "needs_review";
+        any_blocked |= status == "blocked";
+        if let Some(ws) = approval.get("warnings").and_then(Value::as_array) {
+            for w in ws {
+                let mut w = w.clone();
+                if w.is_object() {
+                    w["step"] = Value::String(id.clone());
+                }
+                warnings.push(w);
+            }
+        }
+        if let Some(ids) = approval
+            .get("policy")
+            .and_then(|p| p.get("matched_rule_ids"))
+            .and_then(Value::as_array)
+        {
+            for rule_id in ids {
+                matched_rule_ids.push(json!({"step": id, "rule_id": rule_id}));
+            }
+        }
+        steps.push(json!({"id": id, "status": status}));
+    }
+
+    let status = if any_blocked {
+        "blocked"
+    } else if all_ok {
+        "ok"
+    } else if any_review || !failed.is_empty() {
+        "needs_review"
+    } else {
+        "todo"
+    };
+
+    json!({
+        "stage": "approval",
+        "status": status,
+        "steps": steps,
+        "warnings": warnings,
+        "policy": {"matched_rule_ids": matched_rule_ids},
+        "deferred": deferred,
+        "failed": failed,
+        "note": if deferred.is_empty() {
+            "Aggregated approval over all plan steps."
+        } else {
+            "Aggregated approval over simulated steps; deferred steps are simulated and re-approved on w3rt_resume_run."
+        }
+    })
+}
+
+/// Next free nonce per `(chain_id, lowercase sender)` across the EVM steps of one plan.
+pub type EvmNonces = BTreeMap<(u64, String), u64>;
+
+/// Put the EVM transactions of a simulated step (its approve, then its main tx) after those of
+/// every earlier step from the same sender. All steps are preflighted before any is sent, so
+/// they all see the same on-chain nonce. With `reserve_only` (pending rows already exist) the
+/// nonces are only recorded. Returns whether `simulate` changed.
+pub fn sequence_evm_nonces(simulate: &mut Value, next: &mut EvmNonces, reserve_only: bool) -> bool {
+    if simulate.get("status").and_then(Value::as_str) != Some("ok") {
+        return false;
+    }
+    let Some(chain_id) = simulate.get("chain_id").and_then(Value::as_u64) else {
+        return false;
+    };
+    let approve_needed = simulate
+        .pointer("/approve/needed")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let paths: &[&str] = if approve_needed {
+        &["/approve/tx", "/tx"]
+    } else {
+        &["/tx"]
+    };
+
+    let mut changed = false;
+    for path in paths {
+        let Some(tx) = simulate.pointer_mut(path) else {
+            continue;
+        };
+        let (Some(current), Some(from)) = (
+            tx.get("nonce").and_then(Value::as_u64),
+            tx.get("from").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        let key = (chain_id, from.to_ascii_lowercase());
+        let free = next.get(&key).copied().unwrap_or(current);
+        let assigned = if reserve_only {
+            current
+        } else {
+            current.max(free)
+        };
+        if assigned != current {
+            tx["nonce"] = json!(assigned);
+            changed = true;
+        }
+        next.insert(key, free.max(assigned + 1));
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered_list_chains_steps_and_bindings_add_deps() {
+        let steps = parse_steps(&[
+            json!({"id": "swap", "intent": {"action": "swap_exact_in"}}),
+            json!({"action": "transfer_spl", "amount": "{{steps.swap.outputs.min_amount_out}}"}),
+        ])
+        .unwrap();
+        assert_eq!(steps[1].id, "step_2");
+        assert_eq!(steps[1].depends_on, vec!["swap".to_string()]);
+        assert_eq!(topo_order(&steps).unwrap(), vec![0, 1]);
+
+        // Only `outputs` bindings wait for the predecessor to land.
+        let intent =
+            json!({"a": "{{steps.swap.outputs.amount_out}}", "b": "{{steps.q.intent.to}}"});
+        assert_eq!(binding_refs(&intent), vec!["swap", "q"]);
+        assert_eq!(output_refs(&intent), vec!["swap"]);
+    }
+
+    #[test]
+    fn dag_order_and_cycle_detection() {
+        let steps = parse_steps(&[
+            json!({"id": "b", "intent": {}, "depends_on": ["a"]}),
+            json!({"id": "a", "intent": {}, "depends_on": []}),
+        ])
+        .unwrap();
+        assert_eq!(topo_order(&steps).unwrap(), vec![1, 0]);
+
+        let cyclic = parse_steps(&[
+            json!({"id": "a", "intent": {}, "depends_on": ["b"]}),
+            json!({"id": "b", "intent": {}, "depends_on": ["a"]}),
+        ])
+        .unwrap();
+        assert!(topo_order(&cyclic).is_err());
+        assert!(parse_steps(&[json!({"id": "a", "intent": {}, "depends_on": ["zzz"]})]).is_err());
+    }
+
+    #[test]
+    fn bindings_replace_whole_values_and_interpolate() {
+        let ctx = json!({"steps": {"swap": {"outputs": {"amount_out": "12.5", "raw": 125}}}});
+        let resolved = resolve_bindings(
+            &json!({
+                "amount": "{{steps.swap.outputs.amount_out}}",
+                "raw": "{{ steps.swap.outputs.raw }}",
+                "memo": "got {{steps.swap.outputs.amount_out}} usdc"
+            }),
+            &ctx,
+        )
+        .unwrap();
+        assert_eq!(resolved["amount"], "12.5");
+        assert_eq!(resolved["raw"], 125);
+        assert_eq!(resolved["memo"], "got 12.5 usdc");
+        assert!(resolve_bindings(&json!("{{steps.swap.outputs.missing}}"), &ctx).is_err());
+    }
+
+    #[test]
+    fn aggregated_approval_requires_every_step_ok() {
+        let ok = json!({"status": "ok", "warnings": []});
+        let review = json!({"status": "needs_review", "warnings": [{"kind": "high_slippage"}]});
+        let agg = aggregate_approval(&[("a".into(), ok.clone()), ("b".into(), review)], &[], &[]);
+        assert_eq!(agg["status"], "needs_review");
+        assert_eq!(agg["warnings"][0]["step"], "b");
+        let agg = aggregate_approval(&[("a".into(), ok.clone())], &["b".into()], &[]);
+        assert_eq!(agg["status"], "ok");
+        let blocked = json!({"status": "blocked", "policy": {"matched_rule_ids": ["deny"]}});
+        let agg = aggregate_approval(&[("a".into(), ok), ("b".into(), blocked)], &[], &[]);
+        assert_eq!(agg["status"], "blocked");
+        assert_eq!(agg["policy"]["matched_rule_ids"][0]["rule_id"], "deny");
+    }
+
+    #[test]
+    fn evm_steps_of_one_plan_get_sequential_nonces() {
+        let from = "0x1111111111111111111111111111111111111111";
+        // approve + swap step, then a transfer step: all preflighted at on-chain nonce 7.
+        let mut swap = json!({
+            "status": "ok",
+            "chain_id": 8453,
+            "approve": {"needed": true, "tx": {"from": from, "nonce": 7}},
+            "tx": {"from": from, "nonce": 8}
+        });
+        let mut transfer =
+            json!({"status": "ok", "chain_id": 8453, "tx": {"from": from, "nonce": 7}});
+        let mut other = json!({
+            "status": "ok",
+            "chain_id": 8453,
+            "tx": {"from": "0x2222222222222222222222222222222222222222", "nonce": 3}
+        });
+
+        let mut next = EvmNonces::new();
+        assert!(!sequence_evm_nonces(&mut swap, &mut next, false));
+        assert!(sequence_evm_nonces(&mut transfer, &mut next, false));
+        assert!(!sequence_evm_nonces(&mut other, &mut next, false));
+        assert_eq!(swap["approve"]["tx"]["nonce"], 7);
+        assert_eq!(swap["tx"]["nonce"], 8);
+        assert_eq!(transfer["tx"]["nonce"], 9);
+        assert_eq!(other["tx"]["nonce"], 3);
+
+        // On resume the first step's pending rows exist (reserve only) and the chain has moved
+        // on by one: the re-simulated transfer still lands after the swap.
+        let mut next = EvmNonces::new();
+        let mut transfer =
+            json!({"status": "ok", "chain_id": 8453, "tx": {"from": from, "nonce": 8}});
+        assert!(!sequence_evm_nonces(&mut swap, &mut next, true));
+        assert!(sequence_evm_nonces(&mut transfer, &mut next, false));
+        assert_eq!(transfer["tx"]["nonce"], 9);
+
+        let mut deferred =
+            json!({"status": "deferred", "chain_id": 8453, "tx": {"from": from, "nonce": 1}});
+        assert!(!sequence_evm_nonces(&mut deferred, &mut next, false));
+    }
+}
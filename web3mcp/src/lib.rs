@@ -6,6 +6,24 @@ pub mod types;
 
 // Keep the library surface minimal: many utils are implemented as `impl Web3McpServer` methods
 // and therefore only compile in the binary crate.
+#[path = "utils/audit_log.rs"]
+pub mod audit_log;
+
+#[path = "utils/config.rs"]
+pub mod config;
+
+#[path = "utils/data_dir.rs"]
+pub mod data_dir;
+
+#[path = "utils/metrics.rs"]
+pub mod metrics;
+
+#[path = "utils/mnemonic.rs"]
+pub mod mnemonic;
+
+#[path = "utils/pending_store.rs"]
+pub mod pending_store;
+
 #[path = "utils/evm_confirm_store.rs"]
 pub mod evm_confirm_store;
 
@@ -15,16 +33,62 @@ pub mod evm_calldata;
 #[path = "utils/evm_confirm_ux.rs"]
 pub mod evm_confirm_ux;
 
+#[path = "utils/evm_chain_registry.rs"]
+pub mod evm_chain_registry;
+
 #[path = "utils/sui_confirm_store.rs"]
 pub mod sui_confirm_store;
 
 #[path = "utils/evm_tx_replace.rs"]
 pub mod evm_tx_replace;
 
+#[path = "utils/http_auth.rs"]
+pub mod http_auth;
+
+#[path = "utils/response_cache.rs"]
+pub mod response_cache;
+
+#[path = "utils/rpc_endpoints.rs"]
+pub mod rpc_endpoints;
+
+#[path = "utils/resource_uri.rs"]
+pub mod resource_uri;
+
+#[path = "utils/session_scope.rs"]
+pub mod session_scope;
+
+#[path = "utils/signer.rs"]
+pub mod signer;
+
+#[path = "utils/solana_keystore.rs"]
+pub mod solana_keystore;
+
+#[path = "utils/sui_sponsor.rs"]
+pub mod sui_sponsor;
+
+#[path = "utils/tool_profiles.rs"]
+pub mod tool_profiles;
+
 pub mod utils {
+    pub use crate::audit_log;
+    pub use crate::config;
+    pub use crate::data_dir;
     pub use crate::evm_calldata;
+    pub use crate::evm_chain_registry;
     pub use crate::evm_confirm_store;
     pub use crate::evm_confirm_ux;
     pub use crate::evm_tx_replace;
+    pub use crate::http_auth;
+    pub use crate::metrics;
+    pub use crate::mnemonic;
+    pub use crate::pending_store;
+    pub use crate::resource_uri;
+    pub use crate::response_cache;
+    pub use crate::rpc_endpoints;
+    pub use crate::session_scope;
+    pub use crate::signer;
+    pub use crate::solana_keystore;
     pub use crate::sui_confirm_store;
+    pub use crate::sui_sponsor;
+    pub use crate::tool_profiles;
 }
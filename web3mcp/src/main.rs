@@ -1,15 +1,17 @@
 #![allow(dead_code)]
 
 use anyhow::Result;
-use rmcp::service::RequestContext;
+use rmcp::service::{NotificationContext, RequestContext};
 use rmcp::RoleServer;
 
 #[path = "intent/adapters.rs"]
 mod intent_adapters;
+#[path = "intent/plan.rs"]
+mod intent_plan;
 // (moved) Base64Engine/Engine + various chain/tool types imported via router_prelude
 use rmcp::{
-    handler::server::wrapper::Parameters, model::*, prompt_handler, tool, tool_handler,
-    tool_router, ServerHandler, ServiceExt,
+    handler::server::wrapper::Parameters, model::*, prompt_handler, tool, tool_router,
+    ServerHandler, ServiceExt,
 };
 // (moved) tool request schemas live in src/types.rs
 // (moved) Future used in src/sui/dynamic_fields.rs
@@ -35,11 +37,13 @@ impl Web3McpServer {
         &self,
         tx_data: &TransactionData,
     ) -> Result<DryRunTransactionBlockResponse, ErrorData> {
-        self.client
-            .read_api()
-            .dry_run_transaction_block(tx_data.clone())
-            .await
-            .map_err(|e| Self::sdk_error("preflight_tx", e))
+        self.sui_read("preflight_tx", |client| async move {
+            client
+                .read_api()
+                .dry_run_transaction_block(tx_data.clone())
+                .await
+        })
+        .await
     }
 
     // Utilities moved to src/utils/errors.rs
@@ -73,15 +77,17 @@ impl Web3McpServer {
     // Dynamic field helpers moved to src/sui/dynamic_fields.rs
 }
 
+mod http_server;
 mod move_auto_fill;
 mod move_schema;
 mod move_type_args;
 mod prompts;
+mod resources;
 mod router_prelude;
 mod server;
 mod sui;
 
-#[cfg(all(test, feature = "expose-advanced-tools"))]
+#[cfg(test)]
 mod tx_build_tests;
 mod types;
 mod types_solana_idl_dynamic;
@@ -100,7 +106,6 @@ pub use types_solana_idl_dynamic::*;
 
 include!(concat!(env!("OUT_DIR"), "/router_impl.rs"));
 
-#[tool_handler]
 #[prompt_handler(router = self.prompt_router)]
 impl ServerHandler for Web3McpServer {
     fn get_info(&self) -> ServerInfo {
@@ -109,6 +114,9 @@ impl ServerHandler for Web3McpServer {
             capabilities: ServerCapabilities::builder()
                 .enable_tools()
                 .enable_prompts()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
                 .build(),
             server_info: Implementation::from_build_env(),
             instructions: Some(
@@ -116,6 +124,82 @@ impl ServerHandler for Web3McpServer {
             ),
         }
     }
+
+    // Tools and resources run in the session's scope (see `utils/session_scope.rs`).
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        // Span + metrics per call: src/utils/tool_telemetry.rs.
+        let call = self.call_tool_instrumented(request, context);
+        utils::session_scope::scope(self.session_id.clone(), call).await
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
+    }
+
+    // Resources are served from src/resources.rs.
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        let session = self.session_id.clone();
+        utils::session_scope::sync_scope(session, || {
+            Ok(ListResourcesResult::with_all_items(self.resource_list()))
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, ErrorData> {
+        Ok(ListResourceTemplatesResult::with_all_items(
+            Self::resource_templates(),
+        ))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        utils::session_scope::sync_scope(self.session_id.clone(), || {
+            self.read_resource_uri(&request.uri)
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        utils::session_scope::sync_scope(self.session_id.clone(), || {
+            self.subscribe_resource(&request.uri, context.peer)
+        })
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        self.unsubscribe_resource(&request.uri);
+        Ok(())
+    }
+
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        info!("client initialized");
+        // Also watches the resource list, so clients get list_changed without subscribing.
+        self.start_resource_watch(context.peer);
+    }
 }
 
 #[tokio::main]
@@ -128,22 +212,38 @@ async fn main() -> Result<()> {
         .with_ansi(false)
         .init();
 
-    // Get RPC URL or network from environment variable if provided
-    let rpc_url = std::env::var("SUI_RPC_URL").ok();
-    let network = std::env::var("SUI_NETWORK").ok();
+    // web3mcp.toml + env overrides; refuse to start on an invalid config.
+    match utils::config::init() {
+        Ok(Some(path)) => info!("Loaded config from {}", path.display()),
+        Ok(None) => {}
+        Err(e) => anyhow::bail!(e),
+    }
+    utils::metrics::init();
+
+    // Get RPC URL or network from config / environment if provided
+    let rpc_url = crate::utils::config::var("SUI_RPC_URL").ok();
+    let network = crate::utils::config::var("SUI_NETWORK").ok();
 
     // Create Web3MCP server
-    let server = Web3McpServer::new(rpc_url, network).await?;
+    let mut server = Web3McpServer::new(rpc_url, network).await?;
 
     info!("Starting Web3MCP Server");
-    info!("Using RPC URL: {}", server.rpc_url);
+    info!(
+        "Default Sui network: {} ({})",
+        server.resolve_network_kind(),
+        crate::utils::config::redact_url(&server.rpc_url)
+    );
 
     // Transport selection
     let mut args = std::env::args().skip(1);
     let mut use_sse = false;
+    let mut use_http = false;
+    let mut http_bind: Option<String> = None;
+    let mut http_path: Option<String> = None;
     let mut sse_bind: Option<String> = None;
     let mut sse_path: Option<String> = None;
     let mut post_path: Option<String> = None;
+    let mut profile: Option<String> = None;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -151,16 +251,33 @@ async fn main() -> Result<()> {
             "--sse-bind" => sse_bind = args.next(),
             "--sse-path" => sse_path = args.next(),
             "--post-path" => post_path = args.next(),
+            "--http" => use_http = true,
+            "--http-bind" => http_bind = args.next(),
+            "--http-path" => http_path = args.next(),
+            "--profile" => profile = args.next(),
             "-h" | "--help" => {
                 println!(concat!(
                     "web3mcp\n\n",
                     "USAGE:\n",
                     "  web3mcp                # stdio (default)\n",
-                    "  web3mcp --sse           # SSE server (HTTP)\n\n",
+                    "  web3mcp --http          # streamable HTTP server (MCP 2025-03-26)\n",
+                    "  web3mcp --sse           # legacy SSE server (no auth; keep on localhost)\n\n",
+                    "HTTP OPTIONS:\n",
+                    "  --http-bind <addr>      # default: 127.0.0.1:8080\n",
+                    "  --http-path <path>      # default: /mcp\n",
+                    "  WEB3MCP_AUTH_TOKENS     # Bearer / X-API-Key tokens (required off loopback)\n",
+                    "  WEB3MCP_CORS_ORIGINS    # browser origins allowed by CORS\n\n",
                     "SSE OPTIONS:\n",
                     "  --sse-bind <addr>       # default: 127.0.0.1:8000\n",
                     "  --sse-path <path>       # default: /sse\n",
                     "  --post-path <path>      # default: /message\n\n",
+                    "TOOLS:\n",
+                    "  --profile <name>        # public (default) | solana | evm-trader | sui-dev | full\n",
+                    "                          # or a [tools.profiles.<name>] from web3mcp.toml\n",
+                    "  WEB3MCP_TOOL_PROFILE, WEB3MCP_TOOLS_ALLOW / WEB3MCP_TOOLS_DENY (globs)\n\n",
+                    "CONFIG:\n",
+                    "  web3mcp.toml (./ or ~/.web3mcp/), or WEB3MCP_CONFIG=<path>\n",
+                    "  Env vars override file values; see web3mcp.example.toml\n\n",
                     "ENV (Sui):\n",
                     "  SUI_RPC_URL / SUI_NETWORK\n"
                 ));
@@ -173,15 +290,35 @@ async fn main() -> Result<()> {
         }
     }
 
-    if use_sse {
+    // Tool surface: which compiled-in tools are listed / callable.
+    let profile = utils::tool_profiles::resolve(profile.as_deref()).map_err(anyhow::Error::msg)?;
+    let profile_name = profile.name.clone();
+    let exposed = server.apply_tool_profile(profile);
+    info!("Tool profile: {} ({} tools)", profile_name, exposed.len());
+
+    if use_http {
+        let bind = http_bind
+            .or_else(|| crate::utils::config::var("WEB3MCP_HTTP_BIND").ok())
+            .unwrap_or_else(|| http_server::DEFAULT_BIND.to_string());
+        let path = http_path
+            .or_else(|| crate::utils::config::var("WEB3MCP_HTTP_PATH").ok())
+            .unwrap_or_else(|| http_server::DEFAULT_PATH.to_string());
+        http_server::serve(server, bind.parse()?, &path).await
+    } else if use_sse {
         use rmcp::transport::sse_server::SseServerConfig;
         use rmcp::transport::SseServer;
         use tokio_util::sync::CancellationToken;
 
         let bind = sse_bind
-            .or_else(|| std::env::var("WEB3MCP_SSE_BIND").ok())
+            .or_else(|| crate::utils::config::var("WEB3MCP_SSE_BIND").ok())
             .unwrap_or_else(|| "127.0.0.1:8000".to_string());
-        let bind = bind.parse()?;
+        let bind: std::net::SocketAddr = bind.parse()?;
+        if !bind.ip().is_loopback() {
+            tracing::warn!(
+                "SSE transport has no authentication and is bound to {}; prefer --http",
+                bind
+            );
+        }
         let sse_path = sse_path.unwrap_or_else(|| "/sse".to_string());
         let post_path = post_path.unwrap_or_else(|| "/message".to_string());
 
@@ -195,7 +332,7 @@ async fn main() -> Result<()> {
         .await?;
 
         let server_clone = server.clone();
-        let ct = sse.with_service(move || server_clone.clone());
+        let ct = sse.with_service(move || server_clone.for_session());
 
         info!(
             "Web3MCP SSE server running on http://{}{} (POST to {}?sessionId=...)",
@@ -29,11 +29,8 @@ impl Web3McpServer {
         let limit = 200usize;
 
         let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error("auto_fill_move_call", e))?;
+            .load_normalized_move_modules(package, "auto_fill_move_call")
+            .await?;
         let module = modules.get(&request.module).ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
             message: Cow::from(format!("Module not found: {}", request.module)),
@@ -51,11 +48,16 @@ impl Web3McpServer {
         let options = SuiObjectDataOptions::new().with_type().with_owner();
         let query = SuiObjectResponseQuery::new(None, Some(options));
         let owned = self
-            .client
-            .read_api()
-            .get_owned_objects(sender, Some(query), None, Some(limit))
-            .await
-            .map_err(|e| Self::sdk_error("auto_fill_move_call", e))?;
+            .sui_read("auto_fill_move_call", |client| {
+                let query = query.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_owned_objects(sender, Some(query), None, Some(limit))
+                        .await
+                }
+            })
+            .await?;
 
         let mut owned_metadata: Vec<(ObjectID, StructTag, Owner)> = Vec::new();
         for item in owned.data {
@@ -128,11 +130,10 @@ impl Web3McpServer {
 
         let gas = if let Some(gas_budget) = request.gas_budget {
             let gas_price = request.gas_price.unwrap_or(
-                self.client
-                    .read_api()
-                    .get_reference_gas_price()
-                    .await
-                    .map_err(|e| Self::sdk_error("auto_fill_move_call", e))?,
+                self.sui_read("auto_fill_move_call", |client| async move {
+                    client.read_api().get_reference_gas_price().await
+                })
+                .await?,
             );
             let gas_object = if let Some(gas_object_id) = request.gas_object_id.clone() {
                 Some(json!({"object_id": gas_object_id}))
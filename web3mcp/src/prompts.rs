@@ -2,6 +2,7 @@ use crate::types::*;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{PromptMessage, PromptMessageRole};
 use rmcp::{prompt, prompt_router};
+use serde_json::json;
 
 /// Prompt router (MCP Prompts) - reusable templates for LLM clients.
 #[prompt_router]
@@ -92,6 +93,56 @@ impl crate::Web3McpServer {
             ),
         )]
     }
+
+    #[prompt(
+        description = "Review a Solana wallet: SOL + SPL holdings, concentration and approval risk, empty / dust token accounts"
+    )]
+    pub async fn solana_portfolio_review(
+        &self,
+        Parameters(req): Parameters<PromptSolanaPortfolioReviewRequest>,
+    ) -> Vec<PromptMessage> {
+        Self::solana_portfolio_review_messages(req)
+    }
+
+    #[prompt(
+        description = "Post-mortem of a Solana transaction: what it did, why it failed (or differed from the expected outcome) and how to retry"
+    )]
+    pub async fn solana_tx_postmortem(
+        &self,
+        Parameters(req): Parameters<PromptSolanaTxPostmortemRequest>,
+    ) -> Vec<PromptMessage> {
+        Self::solana_tx_postmortem_messages(req)
+    }
+
+    #[prompt(
+        description = "Explore a Sui Move package: modules, entry functions, argument schema and a dry-run-ready move_call template"
+    )]
+    pub async fn sui_move_explore(
+        &self,
+        Parameters(req): Parameters<PromptSuiMoveExploreRequest>,
+    ) -> Vec<PromptMessage> {
+        Self::sui_move_explore_messages(req)
+    }
+
+    #[prompt(
+        description = "Walk through a swap with W3RT: simulate, review approval warnings, create a pending confirmation, confirm only on explicit user approval"
+    )]
+    pub async fn w3rt_safe_swap(
+        &self,
+        Parameters(req): Parameters<PromptW3rtSafeSwapRequest>,
+    ) -> Vec<PromptMessage> {
+        Self::w3rt_safe_swap_messages(req)
+    }
+
+    #[prompt(
+        description = "Triage pending confirmations: what each one would do, which to confirm, retry or clean up"
+    )]
+    pub async fn pending_confirmation_triage(
+        &self,
+        Parameters(req): Parameters<PromptPendingTriageRequest>,
+    ) -> Vec<PromptMessage> {
+        Self::pending_confirmation_triage_messages(req)
+    }
 }
 
 impl crate::Web3McpServer {
@@ -101,3 +152,321 @@ impl crate::Web3McpServer {
         Self::prompt_router()
     }
 }
+
+// Bodies of the typed prompts above: they only depend on the arguments, so they are plain
+// functions that tests can render without a server.
+impl crate::Web3McpServer {
+    fn solana_portfolio_review_messages(
+        req: PromptSolanaPortfolioReviewRequest,
+    ) -> Vec<PromptMessage> {
+        let network = req.network.unwrap_or_default().as_str();
+        let focus = match req.focus.unwrap_or_default() {
+            PromptPortfolioFocus::Overview => {
+                "Focus: holdings overview. Group by token, largest positions first."
+            }
+            PromptPortfolioFocus::Risk => concat!(
+                "Focus: risk. Flag concentration (>50% in one token), accounts with a delegate ",
+                "(delegatedAmount > 0: suggest solana_spl_revoke), frozen accounts and mints ",
+                "you cannot identify."
+            ),
+            PromptPortfolioFocus::Cleanup => concat!(
+                "Focus: cleanup. List token accounts with a zero balance (closable with ",
+                "solana_spl_close_account to reclaim rent) and dust balances. Do not build ",
+                "close transactions until the user picks the accounts."
+            ),
+        };
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            format!(
+                concat!(
+                    "Review the Solana wallet {} on {}.\n\n",
+                    "1) Call solana_get_balance(address, network) for SOL.\n",
+                    "2) Call solana_get_token_accounts(owner, network, encoding=jsonParsed) for SPL and Token-2022 holdings.\n",
+                    "3) For a token you need details on, call solana_get_token_balance(owner, mint).\n",
+                    "4) Report a table: token (symbol if known, else mint), UI amount, decimals, token account, delegate/state.\n",
+                    "5) {}\n\n",
+                    "Read-only review: do not create or confirm any transaction."
+                ),
+                req.owner, network, focus
+            ),
+        )]
+    }
+
+    fn solana_tx_postmortem_messages(req: PromptSolanaTxPostmortemRequest) -> Vec<PromptMessage> {
+        let network = req.network.unwrap_or_default().as_str();
+        let expected = req
+            .expected_outcome
+            .map(|e| format!("Expected outcome (compare against it): {}\n\n", e))
+            .unwrap_or_default();
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            format!(
+                concat!(
+                    "Write a post-mortem for the Solana transaction {} on {}.\n\n",
+                    "{}",
+                    "1) Call solana_get_signature_status(signature, network) for confirmation status and err.\n",
+                    "2) Call solana_get_transaction(signature, network, encoding=base64, max_supported_transaction_version=0). ",
+                    "Keep meta (err, logMessages, fee, pre/post balances, computeUnitsConsumed).\n",
+                    "3) Call solana_tx_analyze(transaction_base64=<transaction[0] from step 2>, network) to list the programs ",
+                    "and decoded actions (build with solana-extended-tools).\n",
+                    "4) Explain what the transaction did: signer/fee payer, programs, token movements from pre/post token balances.\n",
+                    "5) If it failed, find the failing instruction in the logs and classify it: BLOCKHASH_EXPIRED, ",
+                    "INSUFFICIENT_FUNDS, compute budget exceeded, slippage (e.g. Jupiter 0x1771), account in use, ",
+                    "or a program-specific custom error.\n",
+                    "6) Finish with root cause, impact (fees paid, funds moved) and concrete next steps ",
+                    "(rebuild with a fresh blockhash, raise slippage or compute units, fund the fee payer, ...).\n\n",
+                    "Read-only: do not resend or build a replacement transaction unless the user asks."
+                ),
+                req.signature, network, expected
+            ),
+        )]
+    }
+
+    fn sui_move_explore_messages(req: PromptSuiMoveExploreRequest) -> Vec<PromptMessage> {
+        let network = req
+            .network
+            .map(|n| format!(", network={}", n))
+            .unwrap_or_default();
+        let target = match (&req.module, &req.function) {
+            (Some(m), Some(f)) => format!("{}::{}::{}", req.package, m, f),
+            (Some(m), None) => format!("{}::{}", req.package, m),
+            _ => req.package.clone(),
+        };
+        let discover = match (&req.module, &req.function) {
+            (Some(_), Some(_)) => String::new(),
+            (module, _) => format!(
+                concat!(
+                    "0) Call generate_module_templates(package{}{}, entry_only=true) to list the entry functions; ",
+                    "summarize what each one appears to do and ask which to explore (or pick the most relevant).\n"
+                ),
+                module
+                    .as_ref()
+                    .map(|m| format!(", module={}", m))
+                    .unwrap_or_default(),
+                network
+            ),
+        };
+        let inputs = match &req.sender {
+            Some(sender) => format!(
+                "3) Call suggest_move_call_inputs(sender={}, package, module, function{}) to match owned objects to the object parameters.\n",
+                sender, network
+            ),
+            None => "3) Without a sender, list which object parameters the caller must own or share.\n".to_string(),
+        };
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            format!(
+                concat!(
+                    "Explore the Sui Move target {}.\n\n",
+                    "{}",
+                    "1) Call describe_move_function(package, module, function{}) for the signature, type parameters ",
+                    "and the move_call template.\n",
+                    "2) Call generate_move_call_form_schema(package, module, function{}) and explain each argument: ",
+                    "pure values vs objects, owned vs shared, &mut vs by-value, and type arguments.\n",
+                    "{}",
+                    "4) If more context is needed, call get_normalized_move_modules(package{}) and describe the structs the function touches ",
+                    "(abilities: key/store/drop, who can create them).\n",
+                    "5) Return a filled move_call template and the risks of calling it (objects consumed or transferred, coins spent).\n\n",
+                    "Stop at build_move_call / dry_run_transaction: do not sign or execute."
+                ),
+                target, discover, network, network, inputs, network
+            ),
+        )]
+    }
+
+    fn w3rt_safe_swap_messages(req: PromptW3rtSafeSwapRequest) -> Vec<PromptMessage> {
+        let mut intent = json!({
+            "chain": req.chain.as_str(),
+            "action": "swap_exact_in",
+            "user_pubkey": req.sender.clone(),
+            "input_token": req.input_token,
+            "output_token": req.output_token,
+            "amount_in": req.amount_in,
+        });
+        if let Some(bps) = req.slippage_bps {
+            intent["slippage_bps"] = json!(bps);
+        }
+        let mut call = json!({"intent": intent, "sender": req.sender, "label": "safe_swap"});
+        if let Some(network) = req.network {
+            call["network"] = json!(network);
+        }
+        let confirm = match req.chain {
+            PromptChain::Solana => "solana_confirm_transaction (with confirm_token on mainnet)",
+            PromptChain::Sui => "sui_confirm_execution",
+            PromptChain::Evm => {
+                "evm_retry_pending_confirmation, following next.steps in order (approve first, then swap)"
+            }
+        };
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            format!(
+                concat!(
+                    "Swap {} {} to {} on {} with the W3RT workflow, safely.\n\n",
+                    "1) Call w3rt_run_workflow_v0 with:\n{}\n",
+                    "2) Read artifacts.simulate: status must be ok. Report expected output, minimum output after slippage, ",
+                    "price impact and route. If simulation failed, explain the error and stop.\n",
+                    "3) Read artifacts.approval: list every warning and fired policy rule (rule_id, decision). ",
+                    "blocked cannot be overridden: stop. needs_review: explain the risk and ask the user; only if they ",
+                    "explicitly accept, call w3rt_request_override(run_id) and w3rt_resume_run(run_id, override_token).\n",
+                    "4) Read artifacts.execute: on pending_confirmation_created, show the pending id, what will be signed ",
+                    "and the next.confirm template. Spend limits are checked at confirmation time.\n",
+                    "5) Ask the user to confirm. Only after an explicit yes, call {}. Never confirm on your own.\n",
+                    "6) Afterwards call w3rt_get_run(run_id) and report the final status and transaction id."
+                ),
+                intent["amount_in"].as_str().unwrap_or_default(),
+                intent["input_token"].as_str().unwrap_or_default(),
+                intent["output_token"].as_str().unwrap_or_default(),
+                req.chain.as_str(),
+                serde_json::to_string_pretty(&call).unwrap_or_default(),
+                confirm
+            ),
+        )]
+    }
+
+    fn pending_confirmation_triage_messages(req: PromptPendingTriageRequest) -> Vec<PromptMessage> {
+        let list = match &req.id {
+            Some(id) => format!(
+                "1) Call system_get_pending_confirmation(id={}, include_payload=true).\n",
+                id
+            ),
+            None => {
+                let mut filter = String::new();
+                if let Some(chain) = req.chain {
+                    filter.push_str(&format!(", chain={}", chain.as_str()));
+                }
+                if let Some(network) = &req.network {
+                    filter.push_str(&format!(", network={}", network));
+                }
+                format!(
+                    concat!(
+                        "1) Call system_list_pending_confirmations(limit=50{}). For rows still pending or signed, ",
+                        "call system_get_pending_confirmation(id, include_payload=true).\n"
+                    ),
+                    filter
+                )
+            }
+        };
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            format!(
+                concat!(
+                    "Triage the pending confirmations of this server.\n\n",
+                    "{}",
+                    "2) For each confirmation report: chain, network, age, status, summary (action, amounts, recipient) ",
+                    "and time left before expiry.\n",
+                    "3) Sort them into:\n",
+                    "   - confirm: still valid and matches what the user asked for;\n",
+                    "   - retry / rebuild: failed, or a Solana blockhash / EVM nonce that is now stale ",
+                    "(solana: rebuild through w3rt_resume_run or the original tool; evm: evm_retry_pending_confirmation; ",
+                    "sui: sui_retry_pending_confirmation);\n",
+                    "   - discard: expired, duplicated or unrecognized.\n",
+                    "4) Give the exact confirm call for each confirm candidate (solana_confirm_transaction, ",
+                    "sui_confirm_execution or evm_retry_pending_confirmation) but do not run it: the user confirms each one explicitly.\n",
+                    "5) Propose system_cleanup_pending_confirmations(status=expired) for the discard list, and run it only ",
+                    "after the user agrees."
+                ),
+                list
+            ),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Web3McpServer;
+    use rmcp::model::PromptMessageContent;
+    use serde::de::DeserializeOwned;
+
+    /// Prompt arguments as a client sends them.
+    fn args<T: DeserializeOwned>(value: serde_json::Value) -> T {
+        serde_json::from_value(value).expect("valid prompt arguments")
+    }
+
+    fn text(messages: Vec<PromptMessage>) -> String {
+        assert_eq!(messages.len(), 1);
+        match &messages[0].content {
+            PromptMessageContent::Text { text } => text.clone(),
+            other => panic!("expected a text prompt, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn solana_portfolio_review_renders_defaults_and_focus() {
+        let out = text(Web3McpServer::solana_portfolio_review_messages(args(
+            json!({"owner": "WalletAbc"}),
+        )));
+        assert!(out.starts_with("Review the Solana wallet WalletAbc on mainnet."));
+        assert!(out.contains("Focus: holdings overview."));
+
+        let out = text(Web3McpServer::solana_portfolio_review_messages(args(
+            json!({"owner": "WalletAbc", "network": "devnet", "focus": "cleanup"}),
+        )));
+        assert!(out.contains("on devnet."));
+        assert!(out.contains("solana_spl_close_account"));
+
+        let bad = serde_json::from_value::<PromptSolanaPortfolioReviewRequest>(
+            json!({"owner": "WalletAbc", "network": "moonnet"}),
+        );
+        assert!(bad.is_err());
+    }
+
+    #[test]
+    fn w3rt_safe_swap_embeds_the_workflow_call_for_the_chain() {
+        let out = text(Web3McpServer::w3rt_safe_swap_messages(args(json!({
+            "chain": "sui",
+            "input_token": "SUI",
+            "output_token": "USDC",
+            "amount_in": "1.5",
+            "sender": "0xabc",
+            "slippage_bps": 50
+        }))));
+        assert!(out.starts_with("Swap 1.5 SUI to USDC on sui with the W3RT workflow"));
+        assert!(out.contains("\"slippage_bps\": 50"));
+        assert!(out.contains("\"user_pubkey\": \"0xabc\""));
+        assert!(!out.contains("\"network\""));
+        assert!(out.contains("call sui_confirm_execution."));
+    }
+
+    #[test]
+    fn sui_move_explore_lists_functions_until_one_is_given() {
+        let out = text(Web3McpServer::sui_move_explore_messages(args(json!({
+            "package": "0x2",
+            "module": "coin",
+            "network": "testnet"
+        }))));
+        assert!(out.starts_with("Explore the Sui Move target 0x2::coin."));
+        assert!(out.contains(
+            "generate_module_templates(package, module=coin, network=testnet, entry_only=true)"
+        ));
+        assert!(out.contains("Without a sender"));
+
+        let out = text(Web3McpServer::sui_move_explore_messages(args(json!({
+            "package": "0x2",
+            "module": "coin",
+            "function": "split",
+            "sender": "0xabc"
+        }))));
+        assert!(out.starts_with("Explore the Sui Move target 0x2::coin::split."));
+        assert!(!out.contains("generate_module_templates"));
+        assert!(out.contains("suggest_move_call_inputs(sender=0xabc, package, module, function)"));
+    }
+
+    #[test]
+    fn pending_triage_targets_one_id_or_filters_the_list() {
+        let out = text(Web3McpServer::pending_confirmation_triage_messages(args(
+            json!({"id": "sui_confirm_1"}),
+        )));
+        assert!(
+            out.contains("system_get_pending_confirmation(id=sui_confirm_1, include_payload=true)")
+        );
+        assert!(!out.contains("system_list_pending_confirmations"));
+
+        let out = text(Web3McpServer::pending_confirmation_triage_messages(args(
+            json!({"chain": "evm", "network": "8453"}),
+        )));
+        assert!(
+            out.contains("system_list_pending_confirmations(limit=50, chain=evm, network=8453)")
+        );
+    }
+}
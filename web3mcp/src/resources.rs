@@ -0,0 +1,453 @@
+//! MCP resources: W3RT runs, pending confirmations, the Solana IDL / EVM ABI registries and the
+//! Sui dapp manifest, served as read-only JSON documents under `web3mcp://` (see
+//! `utils/resource_uri.rs`).
+//!
+//! Subscriptions are per session. A watcher task polls the subscribed documents and the resource
+//! list every `WEB3MCP_RESOURCE_POLL_MS` and sends `notifications/resources/updated` /
+//! `notifications/resources/list_changed` when they change. The stores are polled rather than
+//! hooked because other processes (and users dropping ABI / IDL files) write them too.
+
+use crate::utils::resource_uri::{ResourceUri, TEMPLATES};
+use rmcp::model::{
+    AnnotateAble, ErrorCode, ErrorData, RawResource, RawResourceTemplate, ReadResourceResult,
+    Resource, ResourceContents, ResourceTemplate, ResourceUpdatedNotificationParam,
+};
+use rmcp::{Peer, RoleServer};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Items per listing (recent runs, pending confirmations).
+const LIST_LIMIT: usize = 50;
+const MIME_JSON: &str = "application/json";
+const DEFAULT_POLL_MS: u64 = 2_000;
+
+/// Subscriptions of one MCP session.
+#[derive(Debug, Default)]
+pub struct ResourceWatch {
+    /// Subscribed URI -> fingerprint of the content last seen.
+    subscriptions: Mutex<HashMap<String, u64>>,
+    started: AtomicBool,
+}
+
+impl ResourceWatch {
+    fn subscriptions(&self) -> std::sync::MutexGuard<'_, HashMap<String, u64>> {
+        self.subscriptions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// `None` when change notifications are off (`WEB3MCP_RESOURCE_POLL_MS=0`).
+fn poll_interval() -> Option<Duration> {
+    let ms = crate::utils::config::var("WEB3MCP_RESOURCE_POLL_MS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_POLL_MS);
+    (ms > 0).then(|| Duration::from_millis(ms))
+}
+
+fn fingerprint<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut h = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut h);
+    h.finish()
+}
+
+fn not_found(uri: &ResourceUri, what: &str) -> ErrorData {
+    ErrorData {
+        code: ErrorCode::RESOURCE_NOT_FOUND,
+        message: Cow::from(format!("{} not found", what)),
+        data: Some(json!({"uri": uri.uri()})),
+    }
+}
+
+fn resource(uri: &ResourceUri, name: String, description: &str) -> Resource {
+    let mut raw = RawResource::new(uri.uri(), name);
+    raw.description = Some(description.to_string());
+    raw.mime_type = Some(MIME_JSON.to_string());
+    raw.no_annotation()
+}
+
+impl crate::Web3McpServer {
+    /// Index documents plus one entry per recent run, recent pending confirmation and registry file.
+    pub fn resource_list(&self) -> Vec<Resource> {
+        let mut out = vec![
+            resource(
+                &ResourceUri::Runs,
+                "W3RT runs".to_string(),
+                "Recent workflow runs (run index)",
+            ),
+            resource(
+                &ResourceUri::Pending,
+                "Pending confirmations".to_string(),
+                "Recent pending confirmations of every chain (Solana / Sui / EVM)",
+            ),
+            resource(
+                &ResourceUri::SolanaIdls,
+                "Solana IDL registry".to_string(),
+                "Programs and IDL names in the Solana IDL registry",
+            ),
+            resource(
+                &ResourceUri::EvmAbis,
+                "EVM ABI registry".to_string(),
+                "Contracts registered in the EVM ABI registry",
+            ),
+            resource(
+                &ResourceUri::Dapps,
+                "Sui dapp manifest".to_string(),
+                "The dapp manifest (SUI_DAPP_MANIFEST or ./dapps.json)",
+            ),
+        ];
+
+        for run in Self::resource_recent_runs() {
+            let run_id = run.run_id.clone();
+            let name = format!(
+                "Run {} ({} {})",
+                run_id,
+                run.action.as_deref().unwrap_or("-"),
+                run.status
+            );
+            out.push(resource(
+                &ResourceUri::Run { run_id },
+                name,
+                "W3RT run state and stage artifacts",
+            ));
+        }
+        for r in Self::resource_recent_pending() {
+            let name = format!(
+                "Pending {} ({} {})",
+                r.id,
+                r.chain.as_str(),
+                r.status.as_str()
+            );
+            out.push(resource(
+                &ResourceUri::PendingItem { id: r.id },
+                name,
+                "Pending confirmation",
+            ));
+        }
+        for (program_id, names) in
+            crate::utils::solana_idl_registry::list_programs().unwrap_or_default()
+        {
+            for name in names {
+                let title = format!("Solana IDL {} ({})", name, program_id);
+                let uri = ResourceUri::SolanaIdl {
+                    program_id: program_id.clone(),
+                    name,
+                };
+                out.push(resource(&uri, title, "Solana IDL"));
+            }
+        }
+        for entry in Self::evm_registry_entries(None) {
+            let (Some(chain_id), Some(address)) = (
+                entry.get("chain_id").and_then(Value::as_u64),
+                entry.get("address").and_then(Value::as_str),
+            ) else {
+                continue;
+            };
+            let title = format!(
+                "EVM ABI {} ({} on {})",
+                entry
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("contract"),
+                address,
+                chain_id
+            );
+            let uri = ResourceUri::EvmAbi {
+                chain_id,
+                address: address.to_lowercase(),
+            };
+            out.push(resource(&uri, title, "Registered EVM contract ABI"));
+        }
+        out
+    }
+
+    pub fn resource_templates() -> Vec<ResourceTemplate> {
+        TEMPLATES
+            .iter()
+            .map(|(uri_template, name, description)| {
+                RawResourceTemplate {
+                    uri_template: uri_template.to_string(),
+                    name: name.to_string(),
+                    title: None,
+                    description: Some(description.to_string()),
+                    mime_type: Some(MIME_JSON.to_string()),
+                }
+                .no_annotation()
+            })
+            .collect()
+    }
+
+    fn resource_recent_runs() -> Vec<crate::utils::run_index::RunRow> {
+        let store = crate::utils::run_store::RunStore::new();
+        let filter = crate::utils::run_index::RunFilter {
+            limit: Some(LIST_LIMIT as u32),
+            ..Default::default()
+        };
+        crate::utils::run_index::list(&store, &filter)
+            .map(|(rows, _)| rows)
+            .unwrap_or_default()
+    }
+
+    fn resource_recent_pending() -> Vec<crate::utils::pending_store::PendingRecord> {
+        let filter = crate::utils::pending_store::ListFilter {
+            limit: LIST_LIMIT,
+            ..Default::default()
+        };
+        crate::utils::pending_store::connect()
+            .and_then(|conn| crate::utils::pending_store::list(&conn, &filter))
+            .unwrap_or_default()
+    }
+
+    /// The JSON document behind `uri`.
+    pub fn resource_json(&self, uri: &ResourceUri) -> Result<Value, ErrorData> {
+        let now = crate::utils::pending_store::now_ms();
+        let doc = match uri {
+            ResourceUri::Runs => {
+                let store = crate::utils::run_store::RunStore::new();
+                let filter = crate::utils::run_index::RunFilter {
+                    limit: Some(LIST_LIMIT as u32),
+                    ..Default::default()
+                };
+                let (rows, total) = crate::utils::run_index::list(&store, &filter)?;
+                let runs: Vec<Value> = rows
+                    .iter()
+                    .map(|r| {
+                        let mut v = json!(r);
+                        v["uri"] = json!(ResourceUri::Run {
+                            run_id: r.run_id.clone()
+                        }
+                        .uri());
+                        v
+                    })
+                    .collect();
+                json!({"runs_dir": store.root(), "total": total, "runs": runs})
+            }
+            ResourceUri::Run { run_id } => {
+                let store = crate::utils::run_store::RunStore::new();
+                if !store.run_dir(run_id).is_dir() || !store.visible_in_session(run_id) {
+                    return Err(not_found(uri, "run"));
+                }
+                Self::w3rt_run_view(run_id, true)
+            }
+            ResourceUri::Pending => {
+                let conn = crate::utils::pending_store::connect()?;
+                let counts: Vec<Value> = crate::utils::pending_store::counts(&conn)?
+                    .into_iter()
+                    .map(|(chain, status, n)| json!({"chain": chain, "status": status, "count": n}))
+                    .collect();
+                let items: Vec<Value> = Self::resource_recent_pending()
+                    .iter()
+                    .map(|r| {
+                        let mut v = Self::pending_item_json(r, now, false);
+                        v["uri"] = json!(ResourceUri::PendingItem { id: r.id.clone() }.uri());
+                        v
+                    })
+                    .collect();
+                json!({"counts": counts, "items": items})
+            }
+            ResourceUri::PendingItem { id } => {
+                let conn = crate::utils::pending_store::connect()?;
+                let r = crate::utils::pending_store::get(&conn, id)?
+                    .ok_or_else(|| not_found(uri, "pending confirmation"))?;
+                Self::pending_item_json(&r, now, true)
+            }
+            ResourceUri::SolanaIdls => {
+                let programs: Vec<Value> = crate::utils::solana_idl_registry::list_programs()?
+                    .into_iter()
+                    .map(|(program_id, names)| {
+                        let uris: Vec<String> = names
+                            .iter()
+                            .map(|name| {
+                                ResourceUri::SolanaIdl {
+                                    program_id: program_id.clone(),
+                                    name: name.clone(),
+                                }
+                                .uri()
+                            })
+                            .collect();
+                        json!({"program_id": program_id, "names": names, "uris": uris})
+                    })
+                    .collect();
+                json!({
+                    "root": crate::utils::solana_idl_registry::registry_root(),
+                    "programs": programs
+                })
+            }
+            ResourceUri::SolanaIdl { program_id, name } => {
+                if !crate::utils::solana_idl_registry::idl_path(program_id, name).is_file() {
+                    return Err(not_found(uri, "IDL"));
+                }
+                crate::utils::solana_idl_registry::read_idl(program_id, name)?
+            }
+            ResourceUri::EvmAbis => {
+                let items: Vec<Value> = Self::evm_registry_entries(None)
+                    .into_iter()
+                    .map(|mut v| {
+                        if let (Some(chain_id), Some(address)) = (
+                            v.get("chain_id").and_then(Value::as_u64),
+                            v.get("address")
+                                .and_then(Value::as_str)
+                                .map(str::to_lowercase),
+                        ) {
+                            v["uri"] = json!(ResourceUri::EvmAbi { chain_id, address }.uri());
+                        }
+                        v
+                    })
+                    .collect();
+                json!({"root": Self::evm_abi_registry_dir(), "items": items})
+            }
+            ResourceUri::EvmAbi { chain_id, address } => {
+                let path = Self::evm_abi_path(*chain_id, address)
+                    .map_err(|_| not_found(uri, "contract"))?;
+                let bytes = std::fs::read(&path).map_err(|_| not_found(uri, "contract"))?;
+                serde_json::from_slice::<Value>(&bytes).map_err(|e| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("Invalid ABI registry file: {}", e)),
+                    data: Some(json!({"path": path})),
+                })?
+            }
+            ResourceUri::Dapps => {
+                let path = Self::dapp_manifest_path(None);
+                match std::fs::read(&path) {
+                    Ok(bytes) => {
+                        let manifest =
+                            serde_json::from_slice::<Value>(&bytes).map_err(|e| ErrorData {
+                                code: ErrorCode(-32603),
+                                message: Cow::from(format!("Invalid dapp manifest: {}", e)),
+                                data: Some(json!({"path": path})),
+                            })?;
+                        json!({"path": path, "exists": true, "manifest": manifest})
+                    }
+                    Err(_) => json!({
+                        "path": path,
+                        "exists": false,
+                        "manifest": null,
+                        "note": "Set SUI_DAPP_MANIFEST (or [sui] dapp_manifest) or create ./dapps.json"
+                    }),
+                }
+            }
+        };
+        Ok(doc)
+    }
+
+    pub fn read_resource_uri(&self, uri: &str) -> Result<ReadResourceResult, ErrorData> {
+        let parsed = ResourceUri::parse(uri).map_err(|e| ErrorData {
+            code: ErrorCode::RESOURCE_NOT_FOUND,
+            message: Cow::from(e),
+            data: Some(json!({"uri": uri})),
+        })?;
+        let text = Self::pretty_json(&self.resource_json(&parsed)?)?;
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: parsed.uri(),
+                mime_type: Some(MIME_JSON.to_string()),
+                text,
+                meta: None,
+            }],
+        })
+    }
+
+    /// Fingerprint of a document (missing documents hash as `null`, so creation is a change).
+    fn resource_fingerprint(&self, uri: &ResourceUri) -> u64 {
+        let doc = self.resource_json(uri).unwrap_or(Value::Null);
+        fingerprint(&doc.to_string())
+    }
+
+    fn resource_list_fingerprint(&self) -> u64 {
+        let uris: Vec<String> = self
+            .resource_list()
+            .into_iter()
+            .map(|r| r.raw.uri)
+            .collect();
+        fingerprint(&uris)
+    }
+
+    pub fn subscribe_resource(&self, uri: &str, peer: Peer<RoleServer>) -> Result<(), ErrorData> {
+        let parsed = ResourceUri::parse(uri).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(e),
+            data: Some(json!({"uri": uri})),
+        })?;
+        let fp = self.resource_fingerprint(&parsed);
+        self.resource_watch.subscriptions().insert(parsed.uri(), fp);
+        self.start_resource_watch(peer);
+        Ok(())
+    }
+
+    pub fn unsubscribe_resource(&self, uri: &str) {
+        let key = ResourceUri::parse(uri)
+            .map(|u| u.uri())
+            .unwrap_or_else(|_| uri.to_string());
+        self.resource_watch.subscriptions().remove(&key);
+    }
+
+    /// Subscribed URIs whose document changed since the last check.
+    fn changed_resources(&self) -> Vec<String> {
+        let subscribed: Vec<String> = self
+            .resource_watch
+            .subscriptions()
+            .keys()
+            .cloned()
+            .collect();
+        let mut changed = Vec::new();
+        for uri in subscribed {
+            let Ok(parsed) = ResourceUri::parse(&uri) else {
+                continue;
+            };
+            let fp = self.resource_fingerprint(&parsed);
+            let mut subs = self.resource_watch.subscriptions();
+            // Skip URIs unsubscribed while we were reading.
+            if let Some(seen) = subs.get_mut(&uri) {
+                if *seen != fp {
+                    *seen = fp;
+                    changed.push(uri);
+                }
+            }
+        }
+        changed
+    }
+
+    /// Start this session's watcher (once). It stops when the client disconnects.
+    pub fn start_resource_watch(&self, peer: Peer<RoleServer>) {
+        let Some(interval) = poll_interval() else {
+            return;
+        };
+        if self.resource_watch.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let server = self.clone();
+        let session = self.session_id.clone();
+        tokio::spawn(crate::utils::session_scope::scope(session, async move {
+            let mut list_fp = server.resource_list_fingerprint();
+            let mut tick = tokio::time::interval(interval);
+            tick.tick().await;
+            loop {
+                tick.tick().await;
+                if peer.is_transport_closed() {
+                    break;
+                }
+                for uri in server.changed_resources() {
+                    if peer
+                        .notify_resource_updated(ResourceUpdatedNotificationParam { uri })
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                let fp = server.resource_list_fingerprint();
+                if fp != list_fp {
+                    list_fp = fp;
+                    if peer.notify_resource_list_changed().await.is_err() {
+                        return;
+                    }
+                }
+            }
+            tracing::debug!("resource watcher stopped (client disconnected)");
+        }));
+    }
+}
@@ -1,65 +1,185 @@
-use anyhow::{bail, Result};
+use crate::sui::client_pool::{SuiClientPool, SuiEndpoint, SuiNetwork};
+use anyhow::{anyhow, Result};
 use rmcp::handler::server::router::prompt::PromptRouter;
 use rmcp::handler::server::router::tool::ToolRouter;
+use rmcp::model::{ErrorCode, ErrorData};
+use std::borrow::Cow;
 use std::sync::Arc;
-use sui_sdk::{SuiClient, SuiClientBuilder};
+use sui_sdk::SuiClient;
 
 /// Web3MCP Server - provides tools for interacting with chains (Sui, Solana, EVM) via RPC
 #[derive(Clone)]
 pub struct Web3McpServer {
+    /// Sui RPC URL / client / network kind of this instance (the default network unless it came
+    /// from `for_sui_network`).
     pub rpc_url: String,
     pub client: SuiClient,
+    pub sui_network: SuiNetwork,
+    pub sui_network_kind: String,
+    pub sui_clients: Arc<SuiClientPool>,
     pub tool_router: ToolRouter<Self>,
     pub prompt_router: PromptRouter<Self>,
 
     // In-memory caches
     pub solana_idl_cache: Arc<crate::utils::solana_idl_cache::SolanaIdlCache>,
+
+    /// MCP session this instance serves (network transports; `None` over stdio). Requests run
+    /// inside its `session_scope`, which isolates pending confirmations per session.
+    pub session_id: Option<String>,
+    /// Resource subscriptions of the MCP session this instance serves.
+    pub resource_watch: Arc<crate::resources::ResourceWatch>,
 }
 
 impl Web3McpServer {
     pub async fn new(rpc_url: Option<String>, network: Option<String>) -> Result<Self> {
-        let url = Self::resolve_rpc_url(rpc_url, network)?;
-        let client = SuiClientBuilder::default().build(&url).await?;
+        let network = Self::resolve_sui_network(rpc_url, network)?;
+        let endpoint = SuiEndpoint::connect(network).await?;
         Ok(Self {
-            rpc_url: url,
-            client,
+            rpc_url: endpoint.rpc_url.clone(),
+            client: endpoint.client.clone(),
+            sui_network: endpoint.network.clone(),
+            sui_network_kind: endpoint.kind.clone(),
+            sui_clients: Arc::new(SuiClientPool::new(endpoint)),
             tool_router: Self::tool_router(),
             prompt_router: Self::build_prompt_router(),
             solana_idl_cache: Arc::new(crate::utils::solana_idl_cache::SolanaIdlCache::new()),
+            session_id: None,
+            resource_watch: Arc::new(crate::resources::ResourceWatch::default()),
         })
     }
 
-    /// Best-effort network kind, used for safety gates (e.g., require pending confirmation on mainnet).
-    ///
-    /// If the server was constructed with `network`, `rpc_url` will usually contain it.
-    pub fn resolve_network_kind(&self) -> String {
-        let u = self.rpc_url.to_lowercase();
-        if u.contains("testnet") {
-            return "testnet".to_string();
-        }
-        if u.contains("devnet") {
-            return "devnet".to_string();
+    /// A copy for a new MCP session: shares clients and caches, but gets its own session id and
+    /// resource subscriptions.
+    pub fn for_session(&self) -> Self {
+        Self {
+            session_id: Some(crate::utils::session_scope::new_id()),
+            resource_watch: Arc::new(crate::resources::ResourceWatch::default()),
+            ..self.clone()
         }
-        if u.contains("local") || u.contains("127.0.0.1") || u.contains("localhost") {
-            return "localnet".to_string();
+    }
+
+    /// This server bound to another Sui network for one call (`None`/empty keeps this
+    /// instance's network). Clients come from the shared pool and are built on first use; a
+    /// network with several RPC URLs gets its client re-checked so calls move off a dead node.
+    pub async fn for_sui_network(&self, network: Option<&str>) -> Result<Self, ErrorData> {
+        let network = match network.map(str::trim).filter(|s| !s.is_empty()) {
+            Some(selector) => SuiNetwork::parse(selector).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(e),
+                data: None,
+            })?,
+            None => self.sui_network.clone(),
+        };
+        if network == self.sui_network && self.sui_clients.single_endpoint(&network) {
+            return Ok(self.clone());
         }
-        "mainnet".to_string()
+        let endpoint = self
+            .sui_clients
+            .get(&network)
+            .await
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(e.to_string()),
+                data: None,
+            })?;
+        Ok(Self {
+            rpc_url: endpoint.rpc_url,
+            client: endpoint.client,
+            sui_network: endpoint.network,
+            sui_network_kind: endpoint.kind,
+            ..self.clone()
+        })
+    }
+
+    /// Run a Sui read through [`rpc_endpoints::call`](crate::utils::rpc_endpoints::call):
+    /// best endpoint of this instance's network first, and on timeouts, rate limits and
+    /// connection errors the endpoint is marked down and the read retried on the next one.
+    /// `op` may run several times, so it must not submit transactions.
+    pub async fn sui_read<T, E, F, Fut>(&self, context: &str, op: F) -> Result<T, ErrorData>
+    where
+        E: std::fmt::Display,
+        F: Fn(SuiClient) -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+    {
+        use crate::utils::rpc_endpoints::{self, RetryPolicy};
+        let endpoints = crate::sui::client_pool::endpoint_set(&self.sui_network);
+        let policy = RetryPolicy::from_config();
+        let op = &op;
+        rpc_endpoints::call(
+            &endpoints,
+            &policy,
+            true,
+            |e: &ErrorData| rpc_endpoints::is_transient_error(&e.message),
+            |rpc_url| {
+                Self::sdk_error(
+                    context,
+                    format!(
+                        "request to {} timed out after {}ms",
+                        crate::utils::config::redact_url(rpc_url),
+                        policy.timeout.as_millis()
+                    ),
+                )
+            },
+            |idx| {
+                let rpc_url = endpoints.url(idx).to_string();
+                async move {
+                    let client = if rpc_url == self.rpc_url {
+                        self.client.clone()
+                    } else {
+                        self.sui_clients
+                            .client_for_url(&rpc_url)
+                            .await
+                            .map_err(|e| Self::sdk_error(context, e))?
+                    };
+                    op(client).await.map_err(|e| Self::sdk_error(context, e))
+                }
+            },
+        )
+        .await
     }
 
-    pub fn resolve_rpc_url(rpc_url: Option<String>, network: Option<String>) -> Result<String> {
-        if let Some(url) = rpc_url {
-            return Ok(url);
+    /// Drop every tool `profile` does not allow from the router, so `tools/list` and
+    /// `tools/call` only see the profile's surface. Returns the exposed tool names.
+    pub fn apply_tool_profile(
+        &mut self,
+        profile: crate::utils::tool_profiles::ToolProfile,
+    ) -> Vec<String> {
+        let mut exposed = Vec::new();
+        for tool in self.tool_router.list_all() {
+            if profile.allows(&tool.name) {
+                exposed.push(tool.name.to_string());
+            } else {
+                self.tool_router.remove_route(&tool.name);
+            }
         }
+        exposed.sort();
+        crate::utils::tool_profiles::set_active(profile, exposed.clone());
+        exposed
+    }
 
-        let network = network.unwrap_or_else(|| "mainnet".to_string());
-        let url = match network.as_str() {
-            "mainnet" => "https://fullnode.mainnet.sui.io:443",
-            "testnet" => "https://fullnode.testnet.sui.io:443",
-            "devnet" => "https://fullnode.devnet.sui.io:443",
-            "localnet" => "http://127.0.0.1:9000",
-            other => bail!("Unsupported network: {}", other),
-        };
+    /// Network kind of this instance's Sui client (mainnet|testnet|devnet|localnet|custom), used
+    /// for safety gates. Custom URLs are classified by chain identifier when connecting.
+    pub fn resolve_network_kind(&self) -> String {
+        self.sui_network_kind.clone()
+    }
 
-        Ok(url.to_string())
+    pub fn sui_is_mainnet(&self) -> bool {
+        self.sui_network_kind == "mainnet"
+    }
+
+    /// `SUI_RPC_URL` wins over `SUI_NETWORK` (default mainnet). With a list, the first URL
+    /// names the network and the rest are its fallbacks.
+    pub fn resolve_sui_network(
+        rpc_url: Option<String>,
+        network: Option<String>,
+    ) -> Result<SuiNetwork> {
+        let urls = rpc_url
+            .map(|u| crate::utils::rpc_endpoints::parse_url_list(&u))
+            .unwrap_or_default();
+        if let Some(url) = urls.first() {
+            return Ok(SuiNetwork::from_url(url));
+        }
+        let network = network.unwrap_or_else(|| "mainnet".to_string());
+        SuiNetwork::parse(&network).map_err(|e| anyhow!(e))
     }
 }
@@ -0,0 +1,460 @@
+//! Lazily built `SuiClient`s, one per network (mainnet|testnet|devnet|localnet) or custom RPC URL.
+//!
+//! The network *kind* that drives safety gates (confirm tokens on mainnet) comes from the
+//! selector itself, or for a custom URL from the node's chain identifier. It is never guessed
+//! from the URL text.
+//!
+//! A network may have several fullnode URLs (`SUI_RPC_URL_<NET>`, comma-separated). The client
+//! is bound to the healthiest one: connecting falls through the list, and a client whose
+//! endpoint has not been seen working for `WEB3MCP_RPC_PROBE_INTERVAL_SECS` is re-probed
+//! before use and replaced when the probe fails. Reads go through
+//! [`Web3McpServer::sui_read`](crate::Web3McpServer::sui_read), which retries them on the next
+//! URL and records each failure, so the next `get` moves the client off a failing node.
+
+use crate::utils::rpc_endpoints::{self, EndpointSet, RetryPolicy};
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use sui_sdk::{SuiClient, SuiClientBuilder};
+
+/// `sui_getChainIdentifier` of the public networks (devnet/localnet change on every reset).
+pub const MAINNET_CHAIN_IDENTIFIER: &str = "35834a8a";
+pub const TESTNET_CHAIN_IDENTIFIER: &str = "4c78adac";
+
+/// Custom URLs kept in the pool; further ones get a fresh client per call.
+const MAX_CUSTOM_CLIENTS: usize = 16;
+
+/// Per-URL clients kept for read failover; further ones get a fresh client per call.
+const MAX_URL_CLIENTS: usize = 64;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SuiNetwork {
+    Mainnet,
+    Testnet,
+    Devnet,
+    Localnet,
+    Custom(String),
+}
+
+impl SuiNetwork {
+    const NAMED: [SuiNetwork; 4] = [
+        SuiNetwork::Mainnet,
+        SuiNetwork::Testnet,
+        SuiNetwork::Devnet,
+        SuiNetwork::Localnet,
+    ];
+
+    /// `mainnet|testnet|devnet|localnet` or an `http(s)://` RPC URL.
+    pub fn parse(selector: &str) -> Result<Self, String> {
+        let s = selector.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" => Ok(Self::Mainnet),
+            "testnet" => Ok(Self::Testnet),
+            "devnet" => Ok(Self::Devnet),
+            "localnet" | "local" => Ok(Self::Localnet),
+            lower if lower.starts_with("http://") || lower.starts_with("https://") => {
+                Ok(Self::from_url(s))
+            }
+            _ => Err(format!(
+                "Unsupported Sui network: {} (expected mainnet|testnet|devnet|localnet or an http(s) RPC URL)",
+                s
+            )),
+        }
+    }
+
+    /// The public fullnode URLs map to their network (exact match); anything else is `Custom`.
+    pub fn from_url(url: &str) -> Self {
+        let url = url.trim().trim_end_matches('/');
+        Self::NAMED
+            .into_iter()
+            .find(|n| {
+                let known = n.default_rpc_url();
+                url.eq_ignore_ascii_case(known)
+                    || known
+                        .strip_suffix(":443")
+                        .is_some_and(|k| url.eq_ignore_ascii_case(k))
+            })
+            .unwrap_or_else(|| Self::Custom(url.to_string()))
+    }
+
+    fn default_rpc_url(&self) -> &str {
+        match self {
+            Self::Mainnet => "https://fullnode.mainnet.sui.io:443",
+            Self::Testnet => "https://fullnode.testnet.sui.io:443",
+            Self::Devnet => "https://fullnode.devnet.sui.io:443",
+            Self::Localnet => "http://127.0.0.1:9000",
+            Self::Custom(url) => url,
+        }
+    }
+
+    pub fn rpc_url(&self) -> String {
+        self.default_rpc_url().to_string()
+    }
+
+    /// Fullnode URLs, primary first: `SUI_RPC_URL_<NET>`, else `SUI_RPC_URL` when its first
+    /// URL is this network, else the public fullnode. Lists are comma-separated.
+    pub fn rpc_urls(&self) -> Vec<String> {
+        if !matches!(self, Self::Custom(_)) {
+            let key = format!("SUI_RPC_URL_{}", self.key().to_uppercase());
+            if let Ok(value) = crate::utils::config::var(&key) {
+                let urls = rpc_endpoints::parse_url_list(&value);
+                if !urls.is_empty() {
+                    return urls;
+                }
+            }
+        }
+        if let Ok(value) = crate::utils::config::var("SUI_RPC_URL") {
+            let urls = rpc_endpoints::parse_url_list(&value);
+            if urls.first().is_some_and(|u| Self::from_url(u) == *self) {
+                return urls;
+            }
+        }
+        vec![self.rpc_url()]
+    }
+
+    /// Selector that resolves back to this network (stored with pending confirmations).
+    pub fn key(&self) -> String {
+        match self {
+            Self::Mainnet => "mainnet".to_string(),
+            Self::Testnet => "testnet".to_string(),
+            Self::Devnet => "devnet".to_string(),
+            Self::Localnet => "localnet".to_string(),
+            Self::Custom(url) => url.clone(),
+        }
+    }
+}
+
+/// Network kind of a custom endpoint. An unreachable node keeps the mainnet safety gates.
+fn kind_from_chain_identifier(chain_identifier: Option<&str>) -> &'static str {
+    match chain_identifier {
+        Some(MAINNET_CHAIN_IDENTIFIER) => "mainnet",
+        Some(TESTNET_CHAIN_IDENTIFIER) => "testnet",
+        Some(_) => "custom",
+        None => "mainnet",
+    }
+}
+
+#[derive(Clone)]
+pub struct SuiEndpoint {
+    pub network: SuiNetwork,
+    /// mainnet|testnet|devnet|localnet|custom
+    pub kind: String,
+    /// The URL `client` talks to (one of `endpoints`).
+    pub rpc_url: String,
+    pub client: SuiClient,
+    pub endpoints: Arc<EndpointSet>,
+}
+
+impl SuiEndpoint {
+    /// Connect to the healthiest of the network's URLs, falling through the rest.
+    pub async fn connect(network: SuiNetwork) -> Result<Self> {
+        let endpoints = endpoint_set(&network);
+        let policy = RetryPolicy::from_config();
+        let mut last_error = None;
+        for idx in endpoints.order() {
+            let rpc_url = endpoints.url(idx).to_string();
+            let started = Instant::now();
+            let client = match SuiClientBuilder::default()
+                .request_timeout(policy.timeout)
+                .build(&rpc_url)
+                .await
+            {
+                Ok(client) => client,
+                Err(e) => {
+                    endpoints.record_failure(idx, &e.to_string());
+                    last_error = Some(anyhow!("failed to connect to Sui RPC {}: {}", rpc_url, e));
+                    continue;
+                }
+            };
+            endpoints.record_success(idx, started.elapsed());
+            let kind = match &network {
+                SuiNetwork::Custom(_) => {
+                    let chain_identifier = client.read_api().get_chain_identifier().await.ok();
+                    kind_from_chain_identifier(chain_identifier.as_deref()).to_string()
+                }
+                named => named.key(),
+            };
+            return Ok(Self {
+                network,
+                kind,
+                rpc_url,
+                client,
+                endpoints,
+            });
+        }
+        Err(last_error
+            .unwrap_or_else(|| anyhow!("no Sui RPC URL configured for {}", network.key())))
+    }
+
+    fn endpoint_index(&self) -> usize {
+        self.endpoints
+            .urls()
+            .iter()
+            .position(|u| *u == self.rpc_url)
+            .unwrap_or(0)
+    }
+
+    /// Whether the client can keep being used: it is on the best endpoint and that endpoint
+    /// answered `sui_getChainIdentifier` recently (probing it now if not).
+    async fn still_preferred(&self) -> bool {
+        let idx = self.endpoint_index();
+        if self.endpoints.best() != idx {
+            return false;
+        }
+        if !self
+            .endpoints
+            .is_stale(idx, rpc_endpoints::probe_interval())
+        {
+            return true;
+        }
+        probe(&self.client, &self.endpoints, idx).await
+    }
+}
+
+/// Health-tracked URL list of `network` (shared with `system_rpc_health`).
+pub fn endpoint_set(network: &SuiNetwork) -> Arc<EndpointSet> {
+    let label = match network {
+        SuiNetwork::Custom(url) => crate::utils::config::redact_url(url),
+        named => named.key(),
+    };
+    rpc_endpoints::endpoint_set(&format!("sui:{}", label), network.rpc_urls())
+}
+
+/// `sui_getChainIdentifier` against endpoint `idx`, recorded in its health.
+async fn probe(client: &SuiClient, endpoints: &EndpointSet, idx: usize) -> bool {
+    // Runs in front of a tool call, so a hanging node costs at most a few seconds.
+    let timeout = RetryPolicy::from_config().timeout.min(PROBE_TIMEOUT);
+    let started = Instant::now();
+    match tokio::time::timeout(timeout, client.read_api().get_chain_identifier()).await {
+        Ok(Ok(_)) => {
+            endpoints.record_success(idx, started.elapsed());
+            true
+        }
+        Ok(Err(e)) => {
+            endpoints.record_failure(idx, &e.to_string());
+            false
+        }
+        Err(_) => {
+            endpoints.record_failure(idx, &format!("timed out after {}ms", timeout.as_millis()));
+            false
+        }
+    }
+}
+
+/// Probe every URL of `network` (fresh client each) and return its health snapshot.
+pub async fn probe_endpoints(network: &SuiNetwork) -> serde_json::Value {
+    let endpoints = endpoint_set(network);
+    let timeout = RetryPolicy::from_config().timeout;
+    for idx in 0..endpoints.urls().len() {
+        let url = endpoints.url(idx).to_string();
+        match SuiClientBuilder::default()
+            .request_timeout(timeout)
+            .build(&url)
+            .await
+        {
+            Ok(client) => {
+                probe(&client, &endpoints, idx).await;
+            }
+            Err(e) => endpoints.record_failure(idx, &e.to_string()),
+        }
+    }
+    endpoints.snapshot()
+}
+
+pub struct SuiClientPool {
+    clients: Mutex<HashMap<SuiNetwork, SuiEndpoint>>,
+    /// Clients by fullnode URL, for reads retried on another endpoint of their network.
+    by_url: Mutex<HashMap<String, SuiClient>>,
+}
+
+impl SuiClientPool {
+    pub fn new(default: SuiEndpoint) -> Self {
+        let mut by_url = HashMap::new();
+        by_url.insert(default.rpc_url.clone(), default.client.clone());
+        let mut clients = HashMap::new();
+        clients.insert(default.network.clone(), default);
+        Self {
+            clients: Mutex::new(clients),
+            by_url: Mutex::new(by_url),
+        }
+    }
+
+    /// Client talking to `rpc_url` (one URL of a network's endpoint set), built on first use.
+    pub async fn client_for_url(&self, rpc_url: &str) -> Result<SuiClient> {
+        if let Some(client) = self.lock_urls().get(rpc_url).cloned() {
+            return Ok(client);
+        }
+        let client = SuiClientBuilder::default()
+            .request_timeout(RetryPolicy::from_config().timeout)
+            .build(rpc_url)
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "failed to connect to Sui RPC {}: {}",
+                    crate::utils::config::redact_url(rpc_url),
+                    e
+                )
+            })?;
+        self.remember_url(rpc_url, &client);
+        Ok(client)
+    }
+
+    fn remember_url(&self, rpc_url: &str, client: &SuiClient) {
+        let mut by_url = self.lock_urls();
+        if by_url.len() < MAX_URL_CLIENTS || by_url.contains_key(rpc_url) {
+            by_url.insert(rpc_url.to_string(), client.clone());
+        }
+    }
+
+    /// Whether `network` is connected through its only URL, so its client never needs
+    /// replacing.
+    pub fn single_endpoint(&self, network: &SuiNetwork) -> bool {
+        self.lock()
+            .get(network)
+            .is_some_and(|e| e.endpoints.urls().len() <= 1)
+    }
+
+    /// Cached endpoint for `network`, connecting on first use (and again when the cached
+    /// client's endpoint stopped being the healthiest one).
+    pub async fn get(&self, network: &SuiNetwork) -> Result<SuiEndpoint> {
+        let cached = self.lock().get(network).cloned();
+        if let Some(endpoint) = cached {
+            if endpoint.endpoints.urls().len() <= 1 || endpoint.still_preferred().await {
+                return Ok(endpoint);
+            }
+            tracing::warn!(
+                "Sui RPC {} unhealthy, switching endpoint for {}",
+                crate::utils::config::redact_url(&endpoint.rpc_url),
+                endpoint.endpoints.key()
+            );
+        }
+        // Connect without holding the lock; a concurrent first use may connect twice.
+        let endpoint = SuiEndpoint::connect(network.clone()).await?;
+        let mut clients = self.lock();
+        let custom = clients
+            .keys()
+            .filter(|n| matches!(n, SuiNetwork::Custom(_)))
+            .count();
+        if matches!(network, SuiNetwork::Custom(_))
+            && custom >= MAX_CUSTOM_CLIENTS
+            && !clients.contains_key(network)
+        {
+            return Ok(endpoint);
+        }
+        clients.insert(network.clone(), endpoint.clone());
+        drop(clients);
+        self.remember_url(&endpoint.rpc_url, &endpoint.client);
+        Ok(endpoint)
+    }
+
+    /// Connected endpoints, for diagnostics.
+    pub fn describe(&self) -> Vec<serde_json::Value> {
+        let mut items: Vec<_> = self
+            .lock()
+            .values()
+            .map(|e| {
+                serde_json::json!({
+                    "network": match &e.network {
+                        SuiNetwork::Custom(_) => "custom".to_string(),
+                        named => named.key(),
+                    },
+                    "kind": e.kind,
+                    "rpc_url": crate::utils::config::redact_url(&e.rpc_url),
+                })
+            })
+            .collect();
+        items.sort_by_key(|v| v["rpc_url"].as_str().unwrap_or_default().to_string());
+        items
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<SuiNetwork, SuiEndpoint>> {
+        self.clients.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_urls(&self) -> std::sync::MutexGuard<'_, HashMap<String, SuiClient>> {
+        self.by_url.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selectors_resolve_to_pool_keys_without_guessing_from_the_url() {
+        assert_eq!(SuiNetwork::parse(" Testnet "), Ok(SuiNetwork::Testnet));
+        assert_eq!(SuiNetwork::parse("local"), Ok(SuiNetwork::Localnet));
+        assert!(SuiNetwork::parse("sui-mainnet").is_err());
+
+        // Public fullnodes map to their network; any other URL is its own pool entry, even if
+        // its host mentions a network name.
+        assert_eq!(
+            SuiNetwork::parse("https://fullnode.testnet.sui.io/").unwrap(),
+            SuiNetwork::Testnet
+        );
+        assert_eq!(
+            SuiNetwork::from_url("https://FULLNODE.MAINNET.SUI.IO:443"),
+            SuiNetwork::Mainnet
+        );
+        let custom = SuiNetwork::parse("https://mainnet.example.com/rpc/").unwrap();
+        assert_eq!(
+            custom,
+            SuiNetwork::Custom("https://mainnet.example.com/rpc".to_string())
+        );
+
+        // Keys stored with pending confirmations select the same client again.
+        for network in [SuiNetwork::Devnet, SuiNetwork::Localnet, custom] {
+            assert_eq!(SuiNetwork::parse(&network.key()), Ok(network));
+        }
+    }
+
+    #[test]
+    fn custom_endpoints_take_their_kind_from_the_chain_identifier() {
+        assert_eq!(
+            kind_from_chain_identifier(Some(MAINNET_CHAIN_IDENTIFIER)),
+            "mainnet"
+        );
+        assert_eq!(
+            kind_from_chain_identifier(Some(TESTNET_CHAIN_IDENTIFIER)),
+            "testnet"
+        );
+        assert_eq!(kind_from_chain_identifier(Some("deadbeef")), "custom");
+        // Unreachable nodes keep the mainnet confirm-token gate.
+        assert_eq!(kind_from_chain_identifier(None), "mainnet");
+    }
+
+    #[test]
+    fn per_network_url_lists_override_the_public_fullnode() {
+        let _guard = crate::utils::signer::ENV_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        std::env::set_var(
+            "SUI_RPC_URL_LOCALNET",
+            "http://127.0.0.1:9100, http://127.0.0.1:9200",
+        );
+        assert_eq!(
+            SuiNetwork::Localnet.rpc_urls(),
+            vec!["http://127.0.0.1:9100", "http://127.0.0.1:9200"]
+        );
+        std::env::remove_var("SUI_RPC_URL_LOCALNET");
+
+        let custom = SuiNetwork::Custom("https://rpc.example.com".to_string());
+        assert_eq!(custom.rpc_urls(), vec!["https://rpc.example.com"]);
+    }
+
+    #[test]
+    fn server_default_network_prefers_the_rpc_url() {
+        let network = crate::Web3McpServer::resolve_sui_network(
+            Some("https://fullnode.devnet.sui.io:443,https://backup.example.com".to_string()),
+            Some("testnet".to_string()),
+        )
+        .unwrap();
+        assert_eq!(network, SuiNetwork::Devnet);
+        let network = crate::Web3McpServer::resolve_sui_network(None, None).unwrap();
+        assert_eq!(network, SuiNetwork::Mainnet);
+        assert!(crate::Web3McpServer::resolve_sui_network(None, Some("moon".into())).is_err());
+    }
+}
@@ -36,11 +36,13 @@ impl Web3McpServer {
     ) -> Pin<Box<dyn Future<Output = Result<Value, ErrorData>> + Send + 'a>> {
         Box::pin(async move {
             let page = self
-                .client
-                .read_api()
-                .get_dynamic_fields(object_id, None, Some(limit))
-                .await
-                .map_err(|e| Self::sdk_error("get_dynamic_fields", e))?;
+                .sui_read("get_dynamic_fields", |client| async move {
+                    client
+                        .read_api()
+                        .get_dynamic_fields(object_id, None, Some(limit))
+                        .await
+                })
+                .await?;
 
             let mut fields = Vec::new();
             for field in page.data {
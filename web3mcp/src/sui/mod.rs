@@ -1,2 +1,3 @@
+pub mod client_pool;
 pub mod dynamic_fields;
 pub mod tx;
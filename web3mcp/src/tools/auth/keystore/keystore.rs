@@ -23,14 +23,16 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    /// Sign transaction bytes using a local Sui keystore
-    #[tool(description = "Sign transaction bytes using the local Sui keystore")]
+    /// Sign transaction bytes with the configured Sui signer (local keystore by default)
+    #[tool(description = "Sign transaction bytes with the configured Sui signer (local keystore by default)")]
     async fn sign_transaction_with_keystore(
         &self,
         Parameters(request): Parameters<KeystoreSignTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = self.resolve_keystore_signer(&keystore, request.signer.as_deref())?;
+        let sui_signer = self
+            .sui_signer(request.keystore_path.as_deref(), request.signer.as_deref(), None)
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
         let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
         let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
@@ -51,14 +53,7 @@
             });
         }
 
-        let signature = keystore
-            .sign_secure(&signer, &tx_data, shared_crypto::intent::Intent::sui_transaction())
-            .await
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to sign transaction: {}", e)),
-                data: None,
-            })?;
+        let signature = Self::sui_sign_tx_data(sui_signer.as_ref(), &tx_data).await?;
 
         let signature_base64 = Base64Engine.encode(signature.as_ref());
         let response = Self::pretty_json(&json!({
@@ -68,14 +63,17 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    /// Execute a transaction using a local Sui keystore
-    #[tool(description = "Execute a transaction using the local Sui keystore")]
+    /// Execute a transaction with the configured Sui signer (local keystore by default)
+    #[tool(description = "Execute a transaction with the configured Sui signer (local keystore by default)")]
     async fn execute_transaction_with_keystore(
         &self,
         Parameters(request): Parameters<KeystoreExecuteTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = self.resolve_keystore_signer(&keystore, request.signer.as_deref())?;
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let sui_signer = this
+            .sui_signer(request.keystore_path.as_deref(), request.signer.as_deref(), None)
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
         let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
         let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
@@ -97,7 +95,7 @@
         }
 
         let preflight = if request.preflight.unwrap_or(false) {
-            Some(self.preflight_tx_data(&tx_data).await?)
+            Some(this.preflight_tx_data(&tx_data).await?)
         } else {
             None
         };
@@ -110,7 +108,7 @@
                     .execution_error_source
                     .as_deref()
                     .unwrap_or("dry-run failed (missing execution_error_source)");
-                self.write_audit_log(
+                this.write_audit_log(
                     "sui_sign_and_execute_from_keystore",
                     json!({
                         "event": "dry_run_failed",
@@ -130,14 +128,7 @@
             }
         }
 
-        let signature = keystore
-            .sign_secure(&signer, &tx_data, shared_crypto::intent::Intent::sui_transaction())
-            .await
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to sign transaction: {}", e)),
-                data: None,
-            })?;
+        let signature = Self::sui_sign_tx_data(sui_signer.as_ref(), &tx_data).await?;
 
         let tx = Transaction::from_generic_sig_data(
             tx_data,
@@ -151,7 +142,7 @@
             .with_object_changes()
             .with_balance_changes();
 
-        let result = self
+        let result = this
             .client
             .quorum_driver_api()
             .execute_transaction_block(tx, options, None)
@@ -165,7 +156,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_transaction_with_keystore",
             json!({
                 "signer": signer.to_string(),
@@ -182,7 +173,7 @@
     ) -> Result<sui_keys::keystore::FileBasedKeystore, ErrorData> {
         let path = if let Some(path) = keystore_path {
             std::path::PathBuf::from(path)
-        } else if let Ok(path) = std::env::var("SUI_KEYSTORE_PATH") {
+        } else if let Ok(path) = crate::utils::config::var("SUI_KEYSTORE_PATH") {
             std::path::PathBuf::from(path)
         } else {
             let home = std::env::var("HOME").map_err(|_| ErrorData {
@@ -231,7 +222,7 @@
             });
         }
 
-        if let Ok(default_signer) = std::env::var("SUI_DEFAULT_SIGNER") {
+        if let Ok(default_signer) = crate::utils::config::var("SUI_DEFAULT_SIGNER") {
             let identity = default_signer
                 .parse::<sui_keys::key_identity::KeyIdentity>()
                 .map_err(|e| ErrorData {
@@ -0,0 +1,202 @@
+    fn mnemonic_chain(value: &str) -> Result<crate::utils::signer::SignerChain, ErrorData> {
+        crate::utils::signer::SignerChain::parse(value).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Unknown chain '{}'; use sui, solana or evm", value)),
+            data: None,
+        })
+    }
+
+    /// Import a BIP-39 mnemonic into the encrypted mnemonic store
+    #[tool(description = "Import a BIP-39 mnemonic (stored encrypted under the signer keystore dir); returns the index-0 address per chain. The phrase is never logged or echoed")]
+    async fn mnemonic_import(
+        &self,
+        Parameters(request): Parameters<MnemonicImportRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let name = crate::utils::mnemonic::validate_name(
+            request
+                .name
+                .as_deref()
+                .unwrap_or(crate::utils::mnemonic::DEFAULT_NAME),
+        )?;
+        let phrase = crate::utils::mnemonic::normalize_phrase(&request.mnemonic)?;
+        let password = crate::utils::signer::keystore_password(request.password.as_deref())?;
+        let overwrite = request.overwrite.unwrap_or(false);
+        let path = crate::utils::mnemonic::store(&name, &phrase, &password, overwrite)?;
+
+        let mut addresses = serde_json::Map::new();
+        for chain in crate::utils::signer::SignerChain::ALL {
+            let derivation_path = crate::utils::mnemonic::default_path(chain, 0);
+            let address =
+                crate::utils::signer_backends::mnemonic_address(chain, &phrase, &derivation_path)?;
+            addresses.insert(
+                chain.as_str().to_string(),
+                json!({ "derivation_path": derivation_path, "address": address }),
+            );
+        }
+
+        self.write_audit_log(
+            "mnemonic_import",
+            json!({
+                "name": name,
+                "words": phrase.split(' ').count(),
+                "path": path,
+                "overwrite": overwrite
+            }),
+        );
+
+        let response = Self::pretty_json(&json!({
+            "name": name,
+            "words": phrase.split(' ').count(),
+            "keystore_path": path,
+            "addresses": addresses
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// List stored mnemonics and the account each chain signs with
+    #[tool(description = "List stored mnemonics (names only) and the mnemonic account selected per chain")]
+    async fn mnemonic_list(&self) -> Result<CallToolResult, ErrorData> {
+        let stored = crate::utils::mnemonic::list()?;
+        let mut chains = serde_json::Map::new();
+        for chain in crate::utils::signer::SignerChain::ALL {
+            let active = crate::utils::mnemonic::active(chain);
+            let backend = crate::utils::signer::backend_for(chain)?;
+            chains.insert(
+                chain.as_str().to_string(),
+                json!({
+                    "mnemonic": active.mnemonic,
+                    "derivation_path": active.derivation_path,
+                    "signer_backend": backend.as_str()
+                }),
+            );
+        }
+
+        let response = Self::pretty_json(&json!({
+            "dir": crate::utils::mnemonic::dir(),
+            "count": stored.len(),
+            "mnemonics": stored,
+            "chains": chains
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Derive addresses from a stored mnemonic
+    #[tool(description = "Derive addresses from a stored mnemonic: an index range on the chain's standard path (Sui m/44'/784'/i'/0'/0', Solana m/44'/501'/i'/0', EVM m/44'/60'/0'/0/i) or one explicit derivation_path")]
+    async fn mnemonic_derive(
+        &self,
+        Parameters(request): Parameters<MnemonicDeriveRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let chain = Self::mnemonic_chain(&request.chain)?;
+        let name = crate::utils::mnemonic::validate_name(
+            request
+                .name
+                .as_deref()
+                .unwrap_or(crate::utils::mnemonic::DEFAULT_NAME),
+        )?;
+        let password = crate::utils::signer::keystore_password(request.password.as_deref())?;
+        let phrase = crate::utils::mnemonic::load(&name, &password)?;
+
+        let paths: Vec<(Option<u32>, String)> = match request.derivation_path.as_deref() {
+            Some(path) => {
+                crate::utils::mnemonic::validate_path(chain, path)?;
+                vec![(None, path.trim().to_string())]
+            }
+            None => {
+                let start = request.start_index.unwrap_or(0);
+                let count = request.count.unwrap_or(5);
+                if count == 0 || count > 100 {
+                    return Err(ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("count must be between 1 and 100"),
+                        data: Some(json!({ "count": count })),
+                    });
+                }
+                (0..count)
+                    .filter_map(|i| start.checked_add(i))
+                    .map(|i| (Some(i), crate::utils::mnemonic::default_path(chain, i)))
+                    .collect()
+            }
+        };
+
+        let mut accounts = Vec::with_capacity(paths.len());
+        for (index, path) in paths {
+            let address = crate::utils::signer_backends::mnemonic_address(chain, &phrase, &path)?;
+            accounts.push(json!({
+                "index": index,
+                "derivation_path": path,
+                "address": address
+            }));
+        }
+
+        let response = Self::pretty_json(&json!({
+            "name": name,
+            "chain": chain.as_str(),
+            "count": accounts.len(),
+            "accounts": accounts
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Select the mnemonic account a chain signs with
+    #[tool(description = "Select the mnemonic account (name + index or derivation_path) the mnemonic signer backend uses for a chain (sui|solana|evm)")]
+    async fn mnemonic_select(
+        &self,
+        Parameters(request): Parameters<MnemonicSelectRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let chain = Self::mnemonic_chain(&request.chain)?;
+        let name = crate::utils::mnemonic::validate_name(
+            request
+                .name
+                .as_deref()
+                .unwrap_or(crate::utils::mnemonic::DEFAULT_NAME),
+        )?;
+        let derivation_path = match (request.derivation_path.as_deref(), request.index) {
+            (Some(_), Some(_)) => {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("Provide at most one of index or derivation_path"),
+                    data: None,
+                })
+            }
+            (Some(path), None) => path.trim().to_string(),
+            (None, index) => crate::utils::mnemonic::default_path(chain, index.unwrap_or(0)),
+        };
+        let previous = crate::utils::mnemonic::select(chain, &name, &derivation_path)?;
+        let backend = crate::utils::signer::backend_for(chain)?;
+
+        self.write_audit_log(
+            "mnemonic_select",
+            json!({
+                "chain": chain.as_str(),
+                "name": name,
+                "derivation_path": derivation_path,
+                "previous": previous
+            }),
+        );
+
+        let note = (backend != crate::utils::signer::SignerBackend::Mnemonic).then(|| {
+            format!(
+                "{} signer backend is {}; set {}=mnemonic to sign with this account",
+                chain.as_str(),
+                backend.as_str(),
+                chain.backend_env()
+            )
+        });
+        let overridden = [
+            crate::utils::mnemonic::MNEMONIC_ENV,
+            crate::utils::mnemonic::derivation_path_env(chain),
+        ]
+        .into_iter()
+        .filter(|key| crate::utils::config::var(key).is_ok())
+        .collect::<Vec<_>>();
+        let response = Self::pretty_json(&json!({
+            "chain": chain.as_str(),
+            "name": name,
+            "derivation_path": derivation_path,
+            "previous": previous,
+            "signer_backend": backend.as_str(),
+            "overridden_by": overridden,
+            "note": note
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
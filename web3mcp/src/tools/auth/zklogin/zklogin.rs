@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<ZkLoginExecuteTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
         let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
             code: ErrorCode(-32602),
@@ -40,7 +41,7 @@
             .with_object_changes()
             .with_balance_changes();
 
-        let result = self
+        let result = this
             .client
             .quorum_driver_api()
             .execute_transaction_block(tx, options, None)
@@ -62,6 +63,7 @@
         &self,
         Parameters(request): Parameters<VerifyZkLoginSignatureRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let address = Self::parse_address(&request.address)?;
         let intent_scope = match request
             .intent_scope
@@ -84,17 +86,19 @@
             }
         };
 
-        let result = self
-            .client
-            .read_api()
-            .verify_zklogin_signature(
-                request.bytes,
-                request.signature,
-                intent_scope,
-                address,
-            )
-            .await
-            .map_err(|e| Self::sdk_error("verify_zklogin_signature", e))?;
+        let result = this
+            .sui_read("verify_zklogin_signature", |client| {
+                let bytes = request.bytes.clone();
+                let signature = request.signature.clone();
+                let intent_scope = intent_scope.clone();
+                async move {
+                    client
+                        .read_api()
+                        .verify_zklogin_signature(bytes, signature, intent_scope, address)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -0,0 +1,142 @@
+    fn sui_multisig_public_key(
+        members: &[SuiMultisigMember],
+        threshold: u16,
+    ) -> Result<sui_types::multisig::MultiSigPublicKey, ErrorData> {
+        let members = members
+            .iter()
+            .map(|m| (m.public_key.clone(), m.weight))
+            .collect::<Vec<_>>();
+        crate::utils::sui_multisig::public_key(&members, threshold)
+    }
+
+    /// Derive a Sui multisig address
+    #[tool(description = "Sui multisig: derive the multisig address from member public keys (base64 flag||pk), weights and a threshold")]
+    async fn sui_multisig_address(
+        &self,
+        Parameters(request): Parameters<SuiMultisigAddressRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let multisig_pk = Self::sui_multisig_public_key(&request.members, request.threshold)?;
+
+        let response = Self::pretty_json(&json!({
+            "multisig_address": SuiAddress::from(&multisig_pk).to_string(),
+            "threshold": request.threshold,
+            "members": crate::utils::sui_multisig::members_json(&multisig_pk)
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Partially sign a multisig transaction with the configured Sui signer
+    #[tool(description = "Sui multisig: add this server's partial signature to multisig transaction bytes (the configured Sui signer must be a member)")]
+    async fn sui_multisig_sign(
+        &self,
+        Parameters(request): Parameters<SuiMultisigSignRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let multisig_pk = Self::sui_multisig_public_key(&request.members, request.threshold)?;
+        let multisig_address = SuiAddress::from(&multisig_pk);
+
+        let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid transaction bytes: {}", e)),
+            data: None,
+        })?;
+        if tx_data.sender() != multisig_address {
+            return Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!(
+                    "Transaction sender {} is not the multisig address {}",
+                    tx_data.sender(),
+                    multisig_address
+                )),
+                data: Some(json!({
+                    "sender": tx_data.sender().to_string(),
+                    "multisig_address": multisig_address.to_string()
+                })),
+            });
+        }
+
+        let sui_signer = self
+            .sui_signer(request.keystore_path.as_deref(), request.signer.as_deref(), None)
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
+        let signature = Self::sui_sign_tx_data(sui_signer.as_ref(), &tx_data).await?;
+        let signature_base64 = Base64Engine.encode(signature.as_ref());
+        let (index, _) = crate::utils::sui_multisig::check_partial(
+            &multisig_pk,
+            &tx_data,
+            &signature_base64,
+        )?;
+        let weight = multisig_pk.pubkeys()[index].1;
+
+        self.write_audit_log(
+            "sui_multisig_sign",
+            json!({
+                "multisig_address": multisig_address.to_string(),
+                "signer": signer.to_string(),
+                "member_index": index,
+                "tx_digest": tx_data.digest().to_string()
+            }),
+        );
+
+        let response = Self::pretty_json(&json!({
+            "multisig_address": multisig_address.to_string(),
+            "signer": signer.to_string(),
+            "member_index": index,
+            "weight": weight,
+            "threshold": request.threshold,
+            "signature_base64": signature_base64,
+            "next": "Collect partial signatures until their weight reaches the threshold, then call sui_multisig_combine"
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Combine partial signatures into a Sui multisig signature
+    #[tool(description = "Sui multisig: verify partial signatures over tx_bytes and combine them into a multisig signature; pass it to sui_confirm_execution as multisig_signature")]
+    async fn sui_multisig_combine(
+        &self,
+        Parameters(request): Parameters<SuiMultisigCombineRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let multisig_pk = Self::sui_multisig_public_key(&request.members, request.threshold)?;
+
+        let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid transaction bytes: {}", e)),
+            data: None,
+        })?;
+
+        let checked =
+            crate::utils::sui_multisig::combine(multisig_pk, &tx_data, &request.signatures)?;
+
+        let mut response = checked.to_json();
+        response["multisig_signature"] = json!(checked.signature_b64());
+        response["tx_digest"] = json!(tx_data.digest().to_string());
+        response["next"] = json!(
+            "Create a pending confirmation for tx_bytes (sui_create_pending_confirmation) and run sui_confirm_execution with multisig_signature"
+        );
+        let response = Self::pretty_json(&response)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Verify a Sui multisig signature against transaction bytes
+    #[tool(description = "Sui multisig: verify a combined multisig signature against tx_bytes (member signatures, threshold and sender)")]
+    async fn sui_multisig_verify(
+        &self,
+        Parameters(request): Parameters<SuiMultisigVerifyRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid transaction bytes: {}", e)),
+            data: None,
+        })?;
+
+        let multisig = crate::utils::sui_multisig::decode(&request.multisig_signature)?;
+        let checked = crate::utils::sui_multisig::verify(&multisig, &tx_data)?;
+
+        let mut response = checked.to_json();
+        response["valid"] = json!(true);
+        response["members"] = json!(crate::utils::sui_multisig::members_json(multisig.get_pk()));
+        let response = Self::pretty_json(&response)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
@@ -4,7 +4,7 @@
     /// expands into a multi-chain MCP server. These EVM tools are one step in that direction.
 
     fn evm_keystore_dir() -> String {
-        std::env::var("EVM_KEYSTORE_DIR")
+        crate::utils::config::var("EVM_KEYSTORE_DIR")
             .unwrap_or_else(|_| {
                 let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
                 format!("{}/.foundry/keystores", home)
@@ -83,7 +83,7 @@
     }
 
     fn evm_default_chain_id() -> Result<u64, ErrorData> {
-        if let Ok(v) = std::env::var("EVM_DEFAULT_CHAIN_ID") {
+        if let Ok(v) = crate::utils::config::var("EVM_DEFAULT_CHAIN_ID") {
             return v.parse::<u64>().map_err(|e| ErrorData {
                 code: ErrorCode(-32602),
                 message: Cow::from(format!("Invalid EVM_DEFAULT_CHAIN_ID: {}", e)),
@@ -93,10 +93,15 @@
         Ok(84532) // Base Sepolia
     }
 
-    fn evm_rpc_url(chain_id: u64) -> Result<String, ErrorData> {
+    /// RPC URLs for `chain_id`, primary first. `EVM_RPC_URL_<chain_id>` may list several
+    /// (comma-separated) and then replaces the built-in default and fallbacks.
+    fn evm_rpc_urls(chain_id: u64) -> Result<Vec<String>, ErrorData> {
         let key = format!("EVM_RPC_URL_{}", chain_id);
-        if let Ok(url) = std::env::var(&key) {
-            return Ok(url);
+        if let Ok(value) = crate::utils::config::var(&key) {
+            let urls = crate::utils::rpc_endpoints::parse_url_list(&value);
+            if !urls.is_empty() {
+                return Ok(urls);
+            }
         }
 
         // Built-in defaults for common chains (can always be overridden via env).
@@ -107,7 +112,10 @@
             .into_iter()
             .find(|c| c.chain_id == chain_id)
         {
-            return Ok(c.default_rpc_url.to_string());
+            return Ok(std::iter::once(c.default_rpc_url)
+                .chain(c.fallback_rpc_urls.iter().copied())
+                .map(str::to_string)
+                .collect());
         }
 
         Err(ErrorData {
@@ -120,18 +128,25 @@
         })
     }
 
+    fn evm_rpc_url(chain_id: u64) -> Result<String, ErrorData> {
+        Ok(Self::evm_rpc_urls(chain_id)?.remove(0))
+    }
+
+    /// Provider that fails over between the chain's RPC URLs (health is kept per chain).
     async fn evm_provider(
         &self,
         chain_id: u64,
-    ) -> Result<ethers::providers::Provider<ethers::providers::Http>, ErrorData> {
-        let url = Self::evm_rpc_url(chain_id)?;
-        ethers::providers::Provider::<ethers::providers::Http>::try_from(url.as_str()).map_err(
-            |e| ErrorData {
+    ) -> Result<crate::utils::evm_rpc::EvmProvider, ErrorData> {
+        let urls = Self::evm_rpc_urls(chain_id)?;
+        let set = crate::utils::rpc_endpoints::endpoint_set(&format!("evm:{}", chain_id), urls);
+        let policy = crate::utils::rpc_endpoints::RetryPolicy::from_config();
+        let transport =
+            crate::utils::evm_rpc::FailoverHttp::new(set, policy).map_err(|e| ErrorData {
                 code: ErrorCode(-32603),
                 message: Cow::from(format!("Failed to create EVM provider: {}", e)),
                 data: None,
-            },
-        )
+            })?;
+        Ok(ethers::providers::Provider::new(transport))
     }
 
     fn parse_evm_address(address: &str) -> Result<ethers::types::Address, ErrorData> {
@@ -290,148 +305,69 @@
         )?;
 
         let now_ms = crate::utils::evm_confirm_store::now_ms() as i64;
-        let limit = request.limit.unwrap_or(20).min(200) as i64;
+        let limit = request.limit.unwrap_or(20).min(200);
         let include_tx_summary = request.include_tx_summary.unwrap_or(true);
 
-        let status = request.status.as_deref().map(|s| s.trim().to_lowercase());
-        if let Some(st) = status.as_deref() {
-            let allowed = ["pending", "consumed", "sent", "failed", "skipped"];
-            if !allowed.contains(&st) {
-                return Err(ErrorData {
+        let status = match request.status.as_deref() {
+            Some(st) => Some(
+                crate::utils::pending_store::PendingStatus::parse(st).ok_or_else(|| ErrorData {
                     code: ErrorCode(-32602),
-                    message: Cow::from("status must be one of: pending|consumed|sent|failed|skipped"),
+                    message: Cow::from(format!(
+                        "status must be one of: {}",
+                        crate::utils::pending_store::PendingStatus::names()
+                    )),
                     data: None,
-                });
-            }
-        }
+                })?,
+            ),
+            None => None,
+        };
 
         let mut items: Vec<Value> = Vec::new();
 
-        let mut sql = "SELECT id, chain_id, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash, status, tx_hash, last_error, tx_json, raw_tx_prefix, signed_at_ms, second_confirm_token, second_confirmed, expected_spender, required_allowance_raw, expected_token, approve_confirmation_id, swap_confirmation_id FROM evm_pending_confirmations".to_string();
-        let mut params: Vec<rusqlite::types::Value> = Vec::new();
-        let mut where_clauses: Vec<String> = Vec::new();
-
-        if let Some(chain_id) = request.chain_id {
-            where_clauses.push(format!("chain_id = ?{}", params.len() + 1));
-            params.push(rusqlite::types::Value::Integer(chain_id as i64));
-        }
-        if let Some(st) = status {
-            where_clauses.push(format!("status = ?{}", params.len() + 1));
-            params.push(rusqlite::types::Value::Text(st));
-        }
-        if !where_clauses.is_empty() {
-            sql.push_str(" WHERE ");
-            sql.push_str(&where_clauses.join(" AND "));
-        }
-        sql.push_str(" ORDER BY created_at_ms DESC");
-        sql.push_str(&format!(" LIMIT ?{}", params.len() + 1));
-        params.push(rusqlite::types::Value::Integer(limit));
-
-        let mut stmt = conn.prepare(&sql).map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to prepare select: {}", e)),
-            data: None,
-        })?;
-
-        let rows = stmt
-            .query_map(rusqlite::params_from_iter(params), |row| {
-                let id: String = row.get(0)?;
-                let chain_id: i64 = row.get(1)?;
-                let created_at_ms: i64 = row.get(2)?;
-                let updated_at_ms: i64 = row.get(3)?;
-                let expires_at_ms: i64 = row.get(4)?;
-                let tx_summary_hash: String = row.get(5)?;
-                let status: String = row.get(6)?;
-                let tx_hash: Option<String> = row.get(7)?;
-                let last_error: Option<String> = row.get(8)?;
-                let tx_json: String = row.get(9)?;
-                let raw_tx_prefix: Option<String> = row.get(10)?;
-                let signed_at_ms: Option<i64> = row.get(11)?;
-                let second_confirm_token: Option<String> = row.get(12)?;
-                let second_confirmed: Option<i64> = row.get(13)?;
-                let expected_spender: Option<String> = row.get(14)?;
-                let required_allowance_raw: Option<String> = row.get(15)?;
-                let expected_token: Option<String> = row.get(16)?;
-                let approve_confirmation_id: Option<String> = row.get(17)?;
-                let swap_confirmation_id: Option<String> = row.get(18)?;
-                Ok((
-                    id,
-                    chain_id,
-                    created_at_ms,
-                    updated_at_ms,
-                    expires_at_ms,
-                    tx_summary_hash,
-                    status,
-                    tx_hash,
-                    last_error,
-                    tx_json,
-                    raw_tx_prefix,
-                    signed_at_ms,
-                    second_confirm_token,
-                    second_confirmed,
-                    expected_spender,
-                    required_allowance_raw,
-                    expected_token,
-                    approve_confirmation_id,
-                    swap_confirmation_id,
-                ))
-            })
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to query_map: {}", e)),
-                data: None,
-            })?;
-
-        for r in rows.flatten() {
-            let expires_in_ms = (r.4 - now_ms).max(0);
-            let tx: Option<EvmTxRequest> = if include_tx_summary {
-                serde_json::from_str::<EvmTxRequest>(&r.9).ok()
-            } else {
-                None
-            };
+        let rows = crate::utils::evm_confirm_store::list_rows(&conn, request.chain_id, status, limit)?;
+        for r in rows {
+            let expires_in_ms = (r.expires_at_ms as i64 - now_ms).max(0);
+            let tx: Option<&EvmTxRequest> = if include_tx_summary { Some(&r.tx) } else { None };
 
             let calldata = tx
-                .as_ref()
                 .and_then(|t| t.data_hex.as_deref())
                 .and_then(crate::utils::evm_selector::classify_calldata);
 
-            let summary = tx
-                .as_ref()
-                .map(crate::utils::evm_confirm_store::tx_summary_for_response);
+            let summary = tx.map(crate::utils::evm_confirm_store::tx_summary_for_response);
 
             items.push(json!({
-                "id": r.0,
-                "chain_id": r.1,
-                "created_at_ms": r.2,
-                "updated_at_ms": r.3,
-                "expires_at_ms": r.4,
+                "id": r.id,
+                "chain_id": r.chain_id,
+                "created_at_ms": r.created_at_ms,
+                "updated_at_ms": r.updated_at_ms,
+                "expires_at_ms": r.expires_at_ms,
                 "expires_in_ms": expires_in_ms,
-                "status": r.6,
-                "tx_hash": r.7,
-                "last_error": r.8,
-                "raw_tx_prefix": r.10,
-                "signed_at_ms": r.11,
-                "second_confirm_token": r.12,
-                "second_confirmed": r.13.map(|v| v == 1).unwrap_or(false),
-                "tx_summary_hash": r.5,
+                "status": r.status,
+                "tx_hash": r.tx_hash,
+                "last_error": r.last_error,
+                "raw_tx_prefix": r.raw_tx_prefix,
+                "signed_at_ms": r.signed_at_ms,
+                "second_confirm_token": r.second_confirm_token,
+                "second_confirmed": r.second_confirmed,
+                "tx_summary_hash": r.tx_summary_hash,
                 "summary": summary,
                 "tx_summary": summary,
                 "tool_context": json!({
-                    "expected_spender": r.14,
-                    "required_allowance_raw": r.15,
-                    "expected_token": r.16,
-                    "approve_confirmation_id": r.17,
-                    "swap_confirmation_id": r.18,
+                    "expected_spender": r.expected_spender,
+                    "required_allowance_raw": r.required_allowance_raw,
+                    "expected_token": r.expected_token,
+                    "approve_confirmation_id": r.approve_confirmation_id,
+                    "swap_confirmation_id": r.swap_confirmation_id,
                 }),
                 "calldata": calldata
             }));
         }
 
         let response = Self::pretty_json(&json!({
-            "db_path": crate::utils::evm_confirm_store::pending_db_path_from_cwd()?.to_string_lossy(),
+            "db_path": crate::utils::evm_confirm_store::pending_db_path()?.to_string_lossy(),
             "count": items.len(),
             "items": items,
-            "note": "Use evm_get_pending_confirmation for full record; use evm_retry_pending_confirmation to retry failed/consumed."
+            "note": "Use evm_get_pending_confirmation for full record; use evm_retry_pending_confirmation to retry failed/signed."
         }))?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
@@ -450,7 +386,7 @@
         let row = crate::utils::evm_confirm_store::get_row(&conn, request.id.trim())?;
 
         let response = Self::pretty_json(&json!({
-            "db_path": crate::utils::evm_confirm_store::pending_db_path_from_cwd()?.to_string_lossy(),
+            "db_path": crate::utils::evm_confirm_store::pending_db_path()?.to_string_lossy(),
             "item": row.map(|r| json!({
                 "id": r.id,
                 "chain_id": r.chain_id,
@@ -583,7 +519,7 @@
             }
         }
 
-        if row.status == "sent" {
+        if matches!(row.status.as_str(), "sent" | "confirmed") {
             let required_confirmations = crate::utils::evm_chain_registry::confirmations_for_chain(row.chain_id);
             let explorer_url = row
                 .tx_hash
@@ -591,7 +527,7 @@
                 .and_then(|h| Self::evm_explorer_tx_url(row.chain_id, h));
 
             let response = Self::pretty_json(&json!({
-                "status": "sent",
+                "status": row.status,
                 "confirmation_id": row.id,
                 "chain_id": row.chain_id,
                 "tx_hash": row.tx_hash,
@@ -660,8 +596,8 @@
             );
         }
 
-        // Only allow retry when status is pending/failed/consumed.
-        if !matches!(row.status.as_str(), "pending" | "failed" | "consumed") {
+        // Only allow retry when status is pending/failed/signed (claimed but never broadcast).
+        if !matches!(row.status.as_str(), "pending" | "failed" | "signed") {
             return Self::guard_result(
                 "evm_retry_pending_confirmation",
                 "UNSUPPORTED_STATUS",
@@ -780,6 +716,17 @@
             }
         }
 
+        // Rolling-window spend limits: book the spend before signing (released on error).
+        let spend = crate::utils::spend_limits::evm_entries(
+            &tx,
+            &Self::spend_known_evm_tokens(row.chain_id),
+            crate::utils::spend_limits::now_ms(),
+        );
+        let reservation = match Self::spend_limit_guard("evm_retry_pending_confirmation", &spend, id) {
+            crate::utils::spend_limits::SpendGuard::Reserved(r) => r,
+            crate::utils::spend_limits::SpendGuard::Blocked(blocked) => return blocked,
+        };
+
         // Consume.
         crate::utils::evm_confirm_store::mark_consumed(&conn, id)?;
 
@@ -823,6 +770,7 @@
 
         match sent {
             Ok(ok) => {
+                reservation.keep();
                 let mut tx_hash: Option<String> = None;
                 let send_result = Self::evm_extract_first_json(&ok);
                 if let Some(v) = send_result.as_ref() {
@@ -889,45 +837,29 @@
         // Optional: delete failed older than threshold.
         let mut deleted_failed: i64 = 0;
         if let Some(age) = request.delete_failed_older_than_ms {
-            let cutoff = (now as i128 - age as i128).max(0) as i64;
-            if let Some(chain_id) = request.chain_id {
-                deleted_failed = conn
-                    .execute(
-                        "DELETE FROM evm_pending_confirmations
-                         WHERE status='failed' AND updated_at_ms < ?1 AND chain_id = ?2",
-                        rusqlite::params![cutoff, chain_id as i64],
-                    )
-                    .map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to delete old failed: {}", e)),
-                        data: None,
-                    })? as i64;
-            } else {
-                deleted_failed = conn
-                    .execute(
-                        "DELETE FROM evm_pending_confirmations
-                         WHERE status='failed' AND updated_at_ms < ?1",
-                        rusqlite::params![cutoff],
-                    )
-                    .map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to delete old failed: {}", e)),
-                        data: None,
-                    })? as i64;
-            }
+            let filter = crate::utils::pending_store::ListFilter {
+                chain: Some(crate::utils::pending_store::PendingChain::Evm),
+                network: request.chain_id.map(|c| c.to_string()),
+                status: Some(crate::utils::pending_store::PendingStatus::Failed),
+                limit: 0,
+            };
+            deleted_failed = crate::utils::pending_store::purge(
+                &conn,
+                &filter,
+                (now as u64).saturating_sub(age),
+                now as u64,
+            )? as i64;
         }
 
         // Report counts.
-        let count: i64 = conn
-            .query_row(
-                "SELECT COUNT(*) FROM evm_pending_confirmations",
-                [],
-                |row| row.get(0),
-            )
-            .unwrap_or(0);
+        let count: i64 = crate::utils::pending_store::counts(&conn)?
+            .into_iter()
+            .filter(|(chain, _, _)| chain == "evm")
+            .map(|(_, _, n)| n)
+            .sum();
 
         let response = Self::pretty_json(&json!({
-            "db_path": crate::utils::evm_confirm_store::pending_db_path_from_cwd()?.to_string_lossy(),
+            "db_path": crate::utils::evm_confirm_store::pending_db_path()?.to_string_lossy(),
             "remaining": count,
             "deleted_failed": deleted_failed
         }))?;
@@ -1021,7 +953,7 @@
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call.into();
 
-        let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
@@ -1903,7 +1835,7 @@
         let pk = if let Some(p) = request.private_key.as_deref() {
             p.trim().to_string()
         } else {
-            std::env::var("EVM_PRIVATE_KEY").map_err(|_| ErrorData {
+            crate::utils::config::var("EVM_PRIVATE_KEY").map_err(|_| ErrorData {
                 code: ErrorCode(-32602),
                 message: Cow::from("Missing private key: provide private_key or set EVM_PRIVATE_KEY env"),
                 data: None,
@@ -1944,7 +1876,7 @@
             data: None,
         })?;
 
-        let code = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_code(
+        let code = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_code(
             &provider,
             addr,
             None,
@@ -1979,7 +1911,7 @@
             });
         }
 
-        let addr = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::resolve_name(
+        let addr = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::resolve_name(
             &provider,
             name,
         )
@@ -2052,7 +1984,7 @@
             ..Default::default()
         };
         let owner_typed: ethers::types::transaction::eip2718::TypedTransaction = owner_call.into();
-        let owner_raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let owner_raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &owner_typed,
             None,
@@ -2077,7 +2009,7 @@
             ..Default::default()
         };
         let uri_typed: ethers::types::transaction::eip2718::TypedTransaction = uri_call.into();
-        let uri_raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let uri_raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &uri_typed,
             None,
@@ -2187,7 +2119,7 @@
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call.into();
 
-        let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
@@ -2410,14 +2342,14 @@
                 ));
             }
 
-            let receipt = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_transaction_receipt(
+            let receipt = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction_receipt(
                 &provider,
                 tx_hash,
             )
             .await
             .map_err(|e| Self::sdk_error("evm_wait_for_confirmations:get_receipt", e))?;
 
-            let tip = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_block_number(&provider)
+            let tip = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_block_number(&provider)
                 .await
                 .map_err(|e| Self::sdk_error("evm_wait_for_confirmations:get_block_number", e))?;
 
@@ -2480,12 +2412,12 @@
             .unwrap_or(Self::evm_default_chain_id()?);
         let provider = self.evm_provider(chain_id).await?;
 
-        let gas_price = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_gas_price(&provider)
+        let gas_price = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_gas_price(&provider)
             .await
             .map_err(|e| Self::sdk_error("evm_get_gas_price:get_gas_price", e))?;
 
         let (max_fee_per_gas, max_priority_fee_per_gas, eip1559_ok) =
-            match <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::estimate_eip1559_fees(&provider, None)
+            match <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::estimate_eip1559_fees(&provider, None)
                 .await
             {
                 Ok((max_fee, max_prio)) => (Some(max_fee), Some(max_prio), true),
@@ -2514,7 +2446,7 @@
         let provider = self.evm_provider(chain_id).await?;
         let address = Self::parse_evm_address(&request.address)?;
 
-        let bal = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_balance(
+        let bal = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_balance(
             &provider,
             address,
             None,
@@ -2552,7 +2484,7 @@
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call.into();
 
-        let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
@@ -2608,7 +2540,7 @@
             };
             let typed: ethers::types::transaction::eip2718::TypedTransaction = call.clone().into();
 
-            let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+            let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
                 &provider,
                 &typed,
                 None,
@@ -2627,7 +2559,7 @@
             }
             ethers::types::U256::from_big_endian(&bytes[bytes.len() - 32..])
         } else {
-            <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_balance(
+            <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_balance(
                 &provider,
                 address,
                 None,
@@ -2657,12 +2589,26 @@
         let provider = self.evm_provider(chain_id).await?;
         let tx_hash = Self::parse_evm_h256(&request.tx_hash)?;
 
-        let receipt = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_transaction_receipt(&provider, tx_hash)
-            .await
-            .map_err(|e| Self::sdk_error("evm_get_transaction_receipt:get_transaction_receipt", e))?;
+        // Receipts past the chain's confirmation depth are final: cached without expiry.
+        let cache = crate::utils::response_cache::global();
+        let cache_key = format!("evm:{}:receipt:{:?}", chain_id, tx_hash);
+        let cached_receipt: Option<ethers::types::TransactionReceipt> = cache
+            .get(
+                crate::utils::response_cache::CacheCategory::FinalizedTx,
+                &cache_key,
+                crate::utils::pending_store::now_ms(),
+            )
+            .and_then(|v| serde_json::from_value(v).ok());
+        let from_cache = cached_receipt.is_some();
+        let receipt = match cached_receipt {
+            Some(r) => Some(r),
+            None => <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction_receipt(&provider, tx_hash)
+                .await
+                .map_err(|e| Self::sdk_error("evm_get_transaction_receipt:get_transaction_receipt", e))?,
+        };
 
         // Best-effort confirmations computation (adds one extra RPC call).
-        let tip = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_block_number(&provider)
+        let tip = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_block_number(&provider)
             .await
             .ok();
         let confirmations = receipt.as_ref().and_then(|r| {
@@ -2675,6 +2621,18 @@
             }
         });
         let required_confirmations = crate::utils::evm_chain_registry::confirmations_for_chain(chain_id);
+        if !from_cache
+            && confirmations.unwrap_or(0) >= required_confirmations.unwrap_or(64).max(1)
+        {
+            if let Ok(v) = serde_json::to_value(&receipt) {
+                cache.put(
+                    crate::utils::response_cache::CacheCategory::FinalizedTx,
+                    &cache_key,
+                    v,
+                    crate::utils::pending_store::now_ms(),
+                );
+            }
+        }
 
         let limit = request.decoded_logs_limit.unwrap_or(50);
         let (decoded_logs, decoded_logs_truncated, decoded_logs_total) = if let Some(receipt) = &receipt {
@@ -2940,14 +2898,14 @@
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call_req.clone().into();
 
-        let call_res = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let call_res = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
         )
         .await;
 
-        let estimate_res = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::estimate_gas(
+        let estimate_res = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::estimate_gas(
             &provider,
             &typed,
             None,
@@ -2965,12 +2923,12 @@
             Err(e) => (None, Some(e.to_string()), None),
         };
 
-        let gas_price = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_gas_price(&provider)
+        let gas_price = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_gas_price(&provider)
             .await
             .ok();
 
         // Best-effort EIP-1559 suggestion (some chains/RPCs might not support it)
-        let eip1559 = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::estimate_eip1559_fees(&provider, None)
+        let eip1559 = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::estimate_eip1559_fees(&provider, None)
             .await
             .ok();
 
@@ -3049,51 +3007,68 @@
         let provider = self.evm_provider(chain_id).await?;
         let token = Self::parse_evm_address(&request.token)?;
 
-        // name()
-        let name_data = Self::encode_erc20_call("name()", vec![]);
-        let name_call = ethers::types::TransactionRequest {
-            to: Some(ethers::types::NameOrAddress::Address(token)),
-            data: Some(name_data),
-            ..Default::default()
-        };
-        let name_typed: ethers::types::transaction::eip2718::TypedTransaction = name_call.into();
-        let name_raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
-            &provider,
-            &name_typed,
-            None,
-        )
-        .await;
+        // name / symbol / decimals are fixed once a token is deployed: cached with the
+        // token_metadata TTL. totalSupply is always read live.
+        let cache_key = format!("evm:{}:erc20_metadata:{:?}", chain_id, token);
+        let (name, symbol, decimals) = crate::utils::response_cache::cached_if(
+            crate::utils::response_cache::CacheCategory::TokenMetadata,
+            &cache_key,
+            || async {
+                // name()
+                let name_data = Self::encode_erc20_call("name()", vec![]);
+                let name_call = ethers::types::TransactionRequest {
+                    to: Some(ethers::types::NameOrAddress::Address(token)),
+                    data: Some(name_data),
+                    ..Default::default()
+                };
+                let name_typed: ethers::types::transaction::eip2718::TypedTransaction = name_call.into();
+                let name_raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
+                    &provider,
+                    &name_typed,
+                    None,
+                )
+                .await;
 
-        // symbol()
-        let symbol_data = Self::encode_erc20_call("symbol()", vec![]);
-        let symbol_call = ethers::types::TransactionRequest {
-            to: Some(ethers::types::NameOrAddress::Address(token)),
-            data: Some(symbol_data),
-            ..Default::default()
-        };
-        let symbol_typed: ethers::types::transaction::eip2718::TypedTransaction = symbol_call.into();
-        let symbol_raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
-            &provider,
-            &symbol_typed,
-            None,
-        )
-        .await;
+                // symbol()
+                let symbol_data = Self::encode_erc20_call("symbol()", vec![]);
+                let symbol_call = ethers::types::TransactionRequest {
+                    to: Some(ethers::types::NameOrAddress::Address(token)),
+                    data: Some(symbol_data),
+                    ..Default::default()
+                };
+                let symbol_typed: ethers::types::transaction::eip2718::TypedTransaction = symbol_call.into();
+                let symbol_raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
+                    &provider,
+                    &symbol_typed,
+                    None,
+                )
+                .await;
 
-        // decimals()
-        let decimals_data = Self::encode_erc20_call("decimals()", vec![]);
-        let decimals_call = ethers::types::TransactionRequest {
-            to: Some(ethers::types::NameOrAddress::Address(token)),
-            data: Some(decimals_data),
-            ..Default::default()
-        };
-        let decimals_typed: ethers::types::transaction::eip2718::TypedTransaction =
-            decimals_call.into();
-        let decimals_raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
-            &provider,
-            &decimals_typed,
-            None,
+                // decimals()
+                let decimals_data = Self::encode_erc20_call("decimals()", vec![]);
+                let decimals_call = ethers::types::TransactionRequest {
+                    to: Some(ethers::types::NameOrAddress::Address(token)),
+                    data: Some(decimals_data),
+                    ..Default::default()
+                };
+                let decimals_typed: ethers::types::transaction::eip2718::TypedTransaction =
+                    decimals_call.into();
+                let decimals_raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
+                    &provider,
+                    &decimals_typed,
+                    None,
+                )
+                .await;
+
+                Ok::<_, ErrorData>((
+                    name_raw.ok().and_then(|b| decode_string_ret(b.as_ref())),
+                    symbol_raw.ok().and_then(|b| decode_string_ret(b.as_ref())),
+                    decimals_raw.ok().and_then(|b| decode_u8_ret(b.as_ref())),
+                ))
+            },
+            |(_, _, decimals): &(Option<String>, Option<String>, Option<u8>)| decimals.is_some(),
         )
-        .await;
+        .await?;
 
         // totalSupply() (optional but common)
         let supply_data = Self::encode_erc20_call("totalSupply()", vec![]);
@@ -3103,7 +3078,7 @@
             ..Default::default()
         };
         let supply_typed: ethers::types::transaction::eip2718::TypedTransaction = supply_call.into();
-        let supply_raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let supply_raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &supply_typed,
             None,
@@ -3124,15 +3099,6 @@
             tokens.get(0)?.clone().into_uint()
         }
 
-        let name = name_raw
-            .ok()
-            .and_then(|b| decode_string_ret(b.as_ref()));
-        let symbol = symbol_raw
-            .ok()
-            .and_then(|b| decode_string_ret(b.as_ref()));
-        let decimals = decimals_raw
-            .ok()
-            .and_then(|b| decode_u8_ret(b.as_ref()));
         let total_supply_raw = supply_raw
             .ok()
             .and_then(|b| decode_u256_ret(b.as_ref()))
@@ -3175,7 +3141,7 @@
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call.clone().into();
 
-        let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
@@ -3232,7 +3198,7 @@
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call.clone().into();
 
-        let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
@@ -3392,16 +3358,45 @@
     }
 
     fn evm_abi_registry_dir() -> std::path::PathBuf {
-        if let Ok(dir) = std::env::var("EVM_ABI_REGISTRY_DIR") {
-            return std::path::PathBuf::from(dir);
-        }
-        if let Ok(home) = std::env::var("HOME") {
-            return std::path::PathBuf::from(home)
-                .join(".web3mcp")
-                .join("abi_registry")
-                .join("evm");
+        crate::utils::data_dir::evm_abi_registry_dir()
+    }
+
+    /// Registered contracts (`chain_id`, `address`, `name`, `path`), optionally for one chain.
+    fn evm_registry_entries(chain_id: Option<u64>) -> Vec<Value> {
+        let root = Self::evm_abi_registry_dir();
+        let mut out: Vec<Value> = Vec::new();
+
+        let chain_dirs: Vec<std::path::PathBuf> = if let Some(chain_id) = chain_id {
+            vec![root.join(chain_id.to_string())]
+        } else {
+            match std::fs::read_dir(&root) {
+                Ok(rd) => rd.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+                Err(_) => vec![],
+            }
+        };
+
+        for dir in chain_dirs {
+            let chain_id = dir
+                .file_name()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse::<u64>().ok());
+            let Ok(rd) = std::fs::read_dir(&dir) else { continue };
+            for e in rd.flatten() {
+                let p = e.path();
+                if p.extension().and_then(|s| s.to_str()) != Some("json") {
+                    continue;
+                }
+                let Ok(bytes) = std::fs::read(&p) else { continue };
+                let Ok(v) = serde_json::from_slice::<Value>(&bytes) else { continue };
+                out.push(json!({
+                    "chain_id": chain_id.or_else(|| v.get("chain_id").and_then(Value::as_u64)),
+                    "address": v.get("address"),
+                    "name": v.get("name"),
+                    "path": p.to_string_lossy()
+                }));
+            }
         }
-        std::path::PathBuf::from("./abi_registry/evm")
+        out
     }
 
     fn normalize_evm_address(address: &str) -> Result<String, ErrorData> {
@@ -3775,38 +3770,7 @@ fn abi_entry_json(
         Parameters(request): Parameters<EvmListContractsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let root = Self::evm_abi_registry_dir();
-        let mut out: Vec<Value> = Vec::new();
-
-        let chain_dirs: Vec<std::path::PathBuf> = if let Some(chain_id) = request.chain_id {
-            vec![root.join(chain_id.to_string())]
-        } else {
-            match std::fs::read_dir(&root) {
-                Ok(rd) => rd.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
-                Err(_) => vec![],
-            }
-        };
-
-        for dir in chain_dirs {
-            let chain_id = dir
-                .file_name()
-                .and_then(|s| s.to_str())
-                .and_then(|s| s.parse::<u64>().ok());
-            let Ok(rd) = std::fs::read_dir(&dir) else { continue };
-            for e in rd.flatten() {
-                let p = e.path();
-                if p.extension().and_then(|s| s.to_str()) != Some("json") {
-                    continue;
-                }
-                let Ok(bytes) = std::fs::read(&p) else { continue };
-                let Ok(v) = serde_json::from_slice::<Value>(&bytes) else { continue };
-                out.push(json!({
-                    "chain_id": chain_id.or_else(|| v.get("chain_id").and_then(Value::as_u64)),
-                    "address": v.get("address"),
-                    "name": v.get("name"),
-                    "path": p.to_string_lossy()
-                }));
-            }
-        }
+        let out = Self::evm_registry_entries(request.chain_id);
 
         let response = Self::pretty_json(&json!({
             "root": root.to_string_lossy(),
@@ -4593,7 +4557,7 @@ fn abi_entry_json(
         };
         let typed: ethers::types::transaction::eip2718::TypedTransaction = call.clone().into();
 
-        let raw = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::call(
+        let raw = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::call(
             &provider,
             &typed,
             None,
@@ -4825,7 +4789,7 @@ fn abi_entry_json(
         let provider = self.evm_provider(chain_id).await?;
         let hash = Self::parse_evm_h256(&request.tx_hash)?;
 
-        let tx = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_transaction(
+        let tx = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction(
             &provider,
             hash,
         )
@@ -4917,7 +4881,7 @@ fn abi_entry_json(
 
         // Nonce
         if tx.nonce.is_none() {
-            let nonce = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_transaction_count(
+            let nonce = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction_count(
                 &provider,
                 from,
                 None,
@@ -4930,7 +4894,7 @@ fn abi_entry_json(
         // Fee data (EIP-1559 where available)
         if tx.max_fee_per_gas_wei.is_none() || tx.max_priority_fee_per_gas_wei.is_none() {
             // Prefer EIP-1559 fee estimation.
-            let fees = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::estimate_eip1559_fees(
+            let fees = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::estimate_eip1559_fees(
                 &provider,
                 None,
             )
@@ -4947,7 +4911,7 @@ fn abi_entry_json(
                 }
                 Err(_) => {
                     // Fallback: legacy gas price.
-                    let gas_price = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_gas_price(
+                    let gas_price = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_gas_price(
                         &provider,
                     )
                     .await
@@ -4975,7 +4939,7 @@ fn abi_entry_json(
             let typed: ethers::types::transaction::eip2718::TypedTransaction =
                 estimate_req.clone().into();
 
-            let est = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::estimate_gas(
+            let est = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::estimate_gas(
                 &provider,
                 &typed,
                 None,
@@ -4998,23 +4962,13 @@ fn abi_entry_json(
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    #[tool(description = "EVM: sign a tx using a local private key (EVM_PRIVATE_KEY env). Returns raw_tx hex.")]
+    #[tool(description = "EVM: sign a tx with the configured signer (EVM_PRIVATE_KEY env by default; keystore or remote via signer.evm_backend). Returns raw_tx hex.")]
     async fn evm_sign_transaction_local(
         &self,
         Parameters(request): Parameters<EvmSignLocalRequest>,
     ) -> Result<CallToolResult, ErrorData> {
         let chain_id = request.tx.chain_id;
-        let pk = std::env::var("EVM_PRIVATE_KEY").map_err(|_| ErrorData {
-            code: ErrorCode(-32602),
-            message: Cow::from("Missing EVM_PRIVATE_KEY env var"),
-            data: None,
-        })?;
-        let wallet: ethers::signers::LocalWallet = pk.parse().map_err(|e| ErrorData {
-            code: ErrorCode(-32602),
-            message: Cow::from(format!("Invalid EVM_PRIVATE_KEY: {}", e)),
-            data: None,
-        })?;
-        let wallet = ethers::signers::Signer::with_chain_id(wallet, chain_id);
+        let signer = crate::utils::signer::evm_signer(None).await?;
 
         let from = Self::parse_evm_address(&request.tx.from)?;
         let to = Self::parse_evm_address(&request.tx.to)?;
@@ -5064,7 +5018,7 @@ fn abi_entry_json(
 
         // Basic sender mismatch protection.
         if !request.allow_sender_mismatch.unwrap_or(false) {
-            let wallet_addr = ethers::signers::Signer::address(&wallet);
+            let wallet_addr = Self::parse_evm_address(signer.address())?;
             if wallet_addr != from {
                 return Err(ErrorData {
                     code: ErrorCode(-32602),
@@ -5094,9 +5048,7 @@ fn abi_entry_json(
         // Ensure chain id is set
         typed.set_chain_id(chain_id);
 
-        let sig = ethers::signers::Signer::sign_transaction(&wallet, &typed)
-            .await
-            .map_err(|e| Self::sdk_error("evm_sign_transaction_local", e))?;
+        let sig = crate::utils::signer::evm_sign_typed(signer.as_ref(), &typed).await?;
 
         let raw = typed.rlp_signed(&sig);
         let raw_hex = format!("0x{}", hex::encode(raw.as_ref()));
@@ -5106,6 +5058,7 @@ fn abi_entry_json(
             "evm_sign_transaction_local",
             json!({
                 "chain_id": chain_id,
+                "signer_backend": signer.backend().as_str(),
                 "from": request.tx.from,
                 "to": request.tx.to,
                 "value_wei": request.tx.value_wei,
@@ -5162,7 +5115,7 @@ fn abi_entry_json(
             data: None,
         })?;
 
-        let pending = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::send_raw_transaction(
+        let pending = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::send_raw_transaction(
             &provider,
             ethers::types::Bytes::from(raw),
         )
@@ -5339,7 +5292,7 @@ fn abi_entry_json(
         let (from, nonce, to, value, data_hex, gas_limit_from_tx, old_max_fee, old_max_prio, original_tx_hash) =
             if let Some(tx_hash) = request.tx_hash.as_deref() {
                 let h = Self::parse_evm_h256(tx_hash)?;
-                let tx = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_transaction(&provider, h)
+                let tx = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction(&provider, h)
                     .await
                     .map_err(|e| Self::sdk_error("evm_speed_up_tx:get_transaction", e))?
                     .ok_or_else(|| ErrorData {
@@ -5404,7 +5357,7 @@ fn abi_entry_json(
             };
 
         // Fee suggestions (best-effort): use legacy gas_price and convert into EIP-1559 params.
-        let gas_price = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_gas_price(&provider)
+        let gas_price = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_gas_price(&provider)
             .await
             .unwrap_or_else(|_| ethers::types::U256::from(0));
         let suggested_max_fee = gas_price.checked_mul(ethers::types::U256::from(2)).unwrap_or(gas_price);
@@ -5518,7 +5471,7 @@ fn abi_entry_json(
 
         let (from, nonce, old_max_fee, old_max_prio, original_tx_hash) = if let Some(tx_hash) = request.tx_hash.as_deref() {
             let h = Self::parse_evm_h256(tx_hash)?;
-            let tx = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_transaction(&provider, h)
+            let tx = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction(&provider, h)
                 .await
                 .map_err(|e| Self::sdk_error("evm_cancel_tx:get_transaction", e))?
                 .ok_or_else(|| ErrorData {
@@ -5551,7 +5504,7 @@ fn abi_entry_json(
             (from, nonce, None, None, None)
         };
 
-        let gas_price = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_gas_price(&provider)
+        let gas_price = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_gas_price(&provider)
             .await
             .unwrap_or_else(|_| ethers::types::U256::from(0));
         let suggested_max_fee = gas_price
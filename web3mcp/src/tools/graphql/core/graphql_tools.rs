@@ -6,7 +6,7 @@
     ) -> Result<CallToolResult, ErrorData> {
         let endpoint = request
             .endpoint
-            .or_else(|| std::env::var("SUI_GRAPHQL_URL").ok())
+            .or_else(|| crate::utils::config::var("SUI_GRAPHQL_URL").ok())
             .unwrap_or_else(|| "https://graphql.mainnet.sui.io/graphql".to_string());
         let client = GraphqlClient::new(&endpoint).map_err(|e| ErrorData {
             code: ErrorCode(-32603),
@@ -154,7 +154,7 @@
     ) -> Result<CallToolResult, ErrorData> {
         let endpoint = request
             .endpoint
-            .or_else(|| std::env::var("SUI_GRAPHQL_URL").ok())
+            .or_else(|| crate::utils::config::var("SUI_GRAPHQL_URL").ok())
             .unwrap_or_else(|| "https://graphql.mainnet.sui.io/graphql".to_string());
         let client = GraphqlClient::new(&endpoint).map_err(|e| ErrorData {
             code: ErrorCode(-32603),
@@ -35,6 +35,24 @@
         Ok(())
     }
 
+    /// Sui network named by a Sui intent (`testnet`, `sui-devnet`, an RPC URL); `None` keeps the
+    /// server default.
+    fn intent_sui_network(resolved_network: &Value) -> Option<String> {
+        if resolved_network.get("family").and_then(Value::as_str) != Some("sui") {
+            return None;
+        }
+        let raw = resolved_network.get("raw").and_then(Value::as_str)?.trim();
+        let name = match raw.get(..3) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("sui") => {
+                raw[3..].trim_start_matches(['-', '_', ' '])
+            }
+            _ => raw,
+        };
+        crate::sui::client_pool::SuiNetwork::parse(name)
+            .ok()
+            .map(|n| n.key())
+    }
+
     async fn build_transfer_sui_intent_tx(
         &self,
         sender: String,
@@ -52,6 +70,7 @@
             auto_select_coins: Some(false),
             confirm_large_transfer: Some(false),
             large_transfer_threshold: None,
+            network: None,
         }))
         .await
     }
@@ -70,6 +89,7 @@
             recipient,
             gas_budget: Some(gas_budget),
             gas_object_id,
+            network: None,
         }))
         .await
     }
@@ -90,6 +110,7 @@
             amount,
             gas_budget: Some(gas_budget),
             gas_object_id,
+            network: None,
         }))
         .await
     }
@@ -106,6 +127,7 @@
             staked_sui,
             gas_budget: Some(gas_budget),
             gas_object_id,
+            network: None,
         }))
         .await
     }
@@ -140,6 +162,7 @@
                 message: Cow::from("user_signature required"),
                 data: None,
             })?,
+            network: None,
         }))
         .await
     }
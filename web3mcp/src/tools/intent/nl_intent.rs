@@ -1,5 +1,5 @@
     /// Interpret natural language intent into tool calls
-    #[cfg_attr(feature = "expose-advanced-tools", tool(description = "Interpret natural language intent into tool calls"))]
+    #[tool(description = "Interpret natural language intent into tool calls")]
     async fn interpret_intent(
         &self,
         Parameters(request): Parameters<IntentRequest>,
@@ -27,7 +27,7 @@
     }
 
     /// Execute an intent with optional zkLogin inputs
-    #[cfg_attr(feature = "expose-advanced-tools", tool(description = "Execute an intent using provided overrides (supports zkLogin)"))]
+    #[tool(description = "Execute an intent using provided overrides (supports zkLogin)")]
     async fn execute_intent(
         &self,
         Parameters(request): Parameters<IntentExecuteRequest>,
@@ -63,39 +63,53 @@
             .unwrap_or("sui");
         let chain_id = resolved_network.get("chain_id").and_then(Value::as_u64);
 
+        // Sui calls below run against the Sui network named in the intent (e.g. "on testnet").
+        let this = self
+            .for_sui_network(Self::intent_sui_network(&resolved_network).as_deref())
+            .await?;
+
         match intent.as_str() {
             "get_reference_gas_price" => {
                 Self::ensure_sui_intent_family(&resolved_network, "get_reference_gas_price")?;
-                let result = self.get_reference_gas_price().await?;
+                let result = this
+                    .get_reference_gas_price(Parameters(SuiNetworkRequest::default()))
+                    .await?;
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
             }
             "get_chain_identifier" => {
                 Self::ensure_sui_intent_family(&resolved_network, "get_chain_identifier")?;
-                let result = self.get_chain_identifier().await?;
+                let result = this
+                    .get_chain_identifier(Parameters(SuiNetworkRequest::default()))
+                    .await?;
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
             }
             "get_protocol_config" => {
                 Self::ensure_sui_intent_family(&resolved_network, "get_protocol_config")?;
-                let result = self.get_protocol_config().await?;
+                let result = this
+                    .get_protocol_config(Parameters(SuiNetworkRequest::default()))
+                    .await?;
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
             }
             "get_latest_checkpoint_sequence" => {
                 Self::ensure_sui_intent_family(&resolved_network, "get_latest_checkpoint_sequence")?;
-                let result = self.get_latest_checkpoint_sequence().await?;
+                let result = this
+                    .get_latest_checkpoint_sequence(Parameters(SuiNetworkRequest::default()))
+                    .await?;
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
             }
             "get_total_transactions" => {
                 Self::ensure_sui_intent_family(&resolved_network, "get_total_transactions")?;
-                let result = self.get_total_transactions().await?;
+                let result = this
+                    .get_total_transactions(Parameters(SuiNetworkRequest::default()))
+                    .await?;
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
             }
             "swap" => {
-                // MVP: support EVM (0x) + Solana (intent schema only for now).
-                if family != "evm" && family != "solana" {
+                if !crate::intent_adapters::adapter_for(family).is_some_and(|a| a.supports("swap_exact_in")) {
                     return Err(ErrorData {
                         code: ErrorCode(-32602),
-                        message: Cow::from("swap intent is currently supported on EVM (0x) and Solana (intent schema)"),
-                        data: None,
+                        message: Cow::from(format!("swap intent is not supported on {}", family)),
+                        data: Some(json!({ "resolved_network": resolved_network })),
                     });
                 }
 
@@ -148,278 +162,26 @@
                 // Slippage (tolerant default): 1%
                 let slippage = Self::extract_slippage_percent(&lower).or_else(|| Some("1%".to_string()));
 
-                // --- Solana swap (M1): intent schema only ---
-                if family == "solana" {
-                    // Convert percent string (e.g. "1%") to bps.
-                    let slippage_bps = slippage
-                        .as_deref()
-                        .and_then(|s| s.trim().strip_suffix('%'))
-                        .and_then(|p| p.parse::<f64>().ok())
-                        .map(|pct| (pct * 100.0).round() as u64)
-                        .unwrap_or(100);
-
-                    let response = Self::pretty_json(&json!({
-                        "resolved_network": resolved_network,
-                        "status": "ok",
-                        "kind": "intent_schema",
-                        "intent": {
-                            "chain": "solana",
-                            "action": "swap_exact_in",
-                            "input_token": sell,
-                            "output_token": buy,
-                            "amount_in": amount_for_swap,
-                            "slippage_bps": slippage_bps,
-                            "user_pubkey": sender,
-                        },
-                        "note": "MVP step (M1): Solana swap intent is supported as a validated schema. Tx building/execution via Jupiter comes in later milestones (M4/M5)."
-                    }))?;
-
-                    return Ok(CallToolResult::success(vec![Content::text(response)]));
-                }
-
-                // --- EVM swap (0x) ---
-                let chain_id = chain_id.ok_or_else(|| ErrorData {
-                    code: ErrorCode(-32602),
-                    message: Cow::from("chain_id is required for EVM swap"),
-                    data: None,
-                })?;
-
-                // 1) Build swap tx via 0x.
-                let built = self
-                    .evm_0x_build_swap_tx(Parameters(Evm0xBuildSwapTxRequest {
-                        chain_id,
-                        sender: sender.clone(),
-                        sell_token: sell,
-                        buy_token: buy,
-                        sell_amount: amount_for_swap,
-                        sell_amount_is_wei: Some(false),
-                        slippage,
-                        exact_approve: Some(true),
-                    }))
-                    .await?;
-
-                let built_json = Self::extract_first_json(&built).ok_or_else(|| ErrorData {
-                    code: ErrorCode(-32603),
-                    message: Cow::from("Failed to parse 0x build response"),
-                    data: None,
-                })?;
-                let tx: EvmTxRequest = serde_json::from_value(
-                    built_json.get("tx").cloned().unwrap_or(Value::Null),
-                )
-                .map_err(|e| ErrorData {
-                    code: ErrorCode(-32603),
-                    message: Cow::from(format!("Failed to decode built tx: {}", e)),
-                    data: None,
-                })?;
-
-                // 2) Preflight.
-                let preflight = self
-                    .evm_preflight(Parameters(EvmPreflightRequest { tx }))
-                    .await?;
-                let preflight_json = Self::extract_first_json(&preflight).ok_or_else(|| ErrorData {
-                    code: ErrorCode(-32603),
-                    message: Cow::from("Failed to parse evm_preflight response"),
-                    data: None,
-                })?;
-                let tx: EvmTxRequest = serde_json::from_value(
-                    preflight_json.get("tx").cloned().unwrap_or(Value::Null),
-                )
-                .map_err(|e| ErrorData {
-                    code: ErrorCode(-32603),
-                    message: Cow::from(format!("Failed to decode preflight tx: {}", e)),
-                    data: None,
-                })?;
-
-                // 3) Store pending confirmation.
-                let confirmation_id = Self::evm_next_confirmation_id();
-                let now_ms = crate::utils::evm_confirm_store::now_ms();
-                let ttl_ms = crate::utils::evm_confirm_store::default_ttl_ms();
-                let expires_at_ms = now_ms + ttl_ms;
-                let tx_summary_hash = crate::utils::evm_confirm_store::tx_summary_hash(&tx);
-
-                crate::utils::evm_confirm_store::insert_pending(
-                    &confirmation_id,
-                    &tx,
-                    now_ms,
-                    expires_at_ms,
-                    &tx_summary_hash,
-                )?;
-
-                // If 0x indicates an allowanceTarget, we likely need to approve before swapping.
-                let allowance_target = built_json
-                    .get("allowance_target")
-                    .and_then(Value::as_str)
-                    .map(|s| s.to_string());
-                let sell_token_address = built_json
-                    .get("sell_token_address")
-                    .and_then(Value::as_str)
-                    .map(|s| s.to_string());
-                let suggested_approve_tx = built_json.get("suggested_approve_tx").cloned();
-
-                // Check current allowance and only prompt approve if needed.
-                let mut allowance_info: Option<Value> = None;
-                let mut approve_flow: Option<Value> = None;
-
-                if let (Some(token_addr), Some(spender)) = (sell_token_address.clone(), allowance_target.clone()) {
-                    if token_addr != "ETH" {
-                        // Get current allowance
-                        let allowance_res = self
-                            .evm_erc20_allowance(Parameters(EvmErc20AllowanceRequest {
-                                token: token_addr.clone(),
-                                owner: sender.clone(),
-                                spender: spender.clone(),
-                                chain_id: Some(chain_id),
-                            }))
-                            .await?;
-                        let allowance_json = Self::extract_first_json(&allowance_res).unwrap_or(json!({}));
-                        allowance_info = Some(allowance_json.clone());
-
-                        let allowance_raw = allowance_json
-                            .get("allowance_raw")
-                            .and_then(Value::as_str)
-                            .and_then(|s| ethers::types::U256::from_dec_str(s).ok())
-                            .unwrap_or_else(|| ethers::types::U256::from(0));
-
-                        // Prefer 0x quote's exact sellAmount if present, otherwise fall back to our parser.
-                        let sell_amount_wei = if let Some(s) = built_json
-                            .get("quote")
-                            .and_then(|q| q.get("sellAmount"))
-                            .and_then(Value::as_str)
-                        {
-                            ethers::types::U256::from_dec_str(s)
-                                .ok()
-                                .unwrap_or_else(|| ethers::types::U256::from(0))
-                        } else {
-                            let sell_amount_res = self
-                                .evm_parse_amount(Parameters(EvmParseAmountRequest {
-                                    chain_id,
-                                    amount: amount.clone(),
-                                    symbol: None,
-                                    token_address: Some(token_addr.clone()),
-                                    decimals: None,
-                                }))
-                                .await;
-
-                            sell_amount_res
-                                .ok()
-                                .and_then(|r| Self::extract_first_json(&r))
-                                .and_then(|j| j.get("amount_wei").and_then(Value::as_str).map(|s| s.to_string()))
-                                .and_then(|s| ethers::types::U256::from_dec_str(&s).ok())
-                                .unwrap_or_else(|| ethers::types::U256::from(0))
-                        };
-
-                        // Store metadata so confirm-time can block swap if allowance is still insufficient.
-                        let _ = crate::utils::evm_confirm_store::set_expected_allowance(
-                            &confirmation_id,
-                            &token_addr,
-                            &spender,
-                            &sell_amount_wei.to_string(),
-                        );
-
-                        let needs_approve = sell_amount_wei > ethers::types::U256::from(0)
-                            && allowance_raw < sell_amount_wei;
-
-                        // If we have a suggested approve tx and approve is needed, preflight it and create its own confirmation.
-                        if needs_approve {
-                            if let Some(stx) = suggested_approve_tx.clone() {
-                                if let Ok(approve_tx) = serde_json::from_value::<EvmTxRequest>(stx.clone()) {
-                                    let approve_preflight = self
-                                        .evm_preflight(Parameters(EvmPreflightRequest { tx: approve_tx }))
-                                        .await?;
-                                    let approve_preflight_json = Self::extract_first_json(&approve_preflight)
-                                        .ok_or_else(|| ErrorData {
-                                            code: ErrorCode(-32603),
-                                            message: Cow::from("Failed to parse approve preflight"),
-                                            data: None,
-                                        })?;
-                                    let approve_tx: EvmTxRequest = serde_json::from_value(
-                                        approve_preflight_json.get("tx").cloned().unwrap_or(Value::Null),
-                                    )
-                                    .map_err(|e| ErrorData {
-                                        code: ErrorCode(-32603),
-                                        message: Cow::from(format!("Failed to decode approve tx: {}", e)),
-                                        data: None,
-                                    })?;
-
-                                    let approve_confirmation_id = Self::evm_next_confirmation_id();
-                                    let approve_hash = crate::utils::evm_confirm_store::tx_summary_hash(&approve_tx);
-                                    crate::utils::evm_confirm_store::insert_pending(
-                                        &approve_confirmation_id,
-                                        &approve_tx,
-                                        now_ms,
-                                        expires_at_ms,
-                                        &approve_hash,
-                                    )?;
-
-                                    // Store safety metadata for confirm-time checks.
-                                    let _ = crate::utils::evm_confirm_store::set_expected_allowance(
-                                        &approve_confirmation_id,
-                                        &token_addr,
-                                        &spender,
-                                        &sell_amount_wei.to_string(),
-                                    );
-
-                                    let _ = crate::utils::evm_confirm_store::set_approve_link(
-                                        &confirmation_id,
-                                        &approve_confirmation_id,
-                                    );
-                                    let _ = crate::utils::evm_confirm_store::set_swap_link(
-                                        &approve_confirmation_id,
-                                        &confirmation_id,
-                                    );
-
-                                    approve_flow = Some(json!({
-                                        "needed": true,
-                                        "allowance_raw": allowance_raw.to_string(),
-                                        "required_raw": sell_amount_wei.to_string(),
-                                        "confirmation_id": approve_confirmation_id,
-                                        "expires_in_ms": ttl_ms,
-                                        "tx_summary": crate::utils::evm_confirm_store::tx_summary_for_response(&approve_tx),
-                                        "tx_summary_hash": approve_hash,
-                                        "preflight": approve_preflight_json,
-                                        "next": {
-                                            "how_to_confirm": format!("confirm {} hash:{} (approve) (and include same network)", approve_confirmation_id, approve_hash)
-                                        }
-                                    }));
-                                }
-                            }
-                        } else {
-                            approve_flow = Some(json!({
-                                "needed": false,
-                                "allowance_raw": allowance_raw.to_string(),
-                                "required_raw": sell_amount_wei.to_string()
-                            }));
-                        }
-                    }
-                }
-
-                let response = Self::pretty_json(&json!({
-                    "resolved_network": resolved_network,
-                    "mode": "dry_run_only",
-                    "provider": "0x",
-                    "swap": {
-                        "confirmation_id": confirmation_id,
-                        "expires_in_ms": ttl_ms,
-                        "tx_summary": crate::utils::evm_confirm_store::tx_summary_for_response(&tx),
-                        "tx_summary_hash": tx_summary_hash,
-                        "preflight": preflight_json,
-                        "next": {
-                            "how_to_confirm": format!("confirm {} hash:{} (swap) (and include same network)", confirmation_id, tx_summary_hash)
-                        }
-                    },
-                    "approve": approve_flow,
-                    "build": built_json,
-                    "allowance": {
-                        "sell_token_address": sell_token_address,
-                        "allowance_target": allowance_target,
-                        "current": allowance_info,
-                        "suggested_approve_tx": suggested_approve_tx,
-                        "note": "If approve.needed=true, confirm approve first, then confirm swap. If approve.needed=false, you can confirm swap directly."
-                    },
-                    "note": "Safe default: not signed/broadcast. Confirm to execute." 
-                }))?;
-
-                return Ok(CallToolResult::success(vec![Content::text(response)]));
+                // Build/simulate/approval/execute are chain-specific: 0x on EVM, Jupiter on Solana, 7K on Sui.
+                let slippage_bps = slippage
+                    .as_deref()
+                    .and_then(|s| s.trim().strip_suffix('%'))
+                    .and_then(|p| p.parse::<f64>().ok())
+                    .map(|pct| (pct * 100.0).round() as u64)
+                    .unwrap_or(100);
+
+                let mut swap_intent = json!({
+                    "chain": family,
+                    "action": "swap_exact_in",
+                    "input_token": sell,
+                    "output_token": buy,
+                    "amount_in": amount_for_swap,
+                    "slippage_bps": slippage_bps,
+                    "user_pubkey": sender,
+                });
+                Self::intent_adapter_network(&mut swap_intent, &resolved_network);
+
+                return this.execute_intent_via_adapter(swap_intent, &resolved_network).await;
             }
             "get_coins" => {
                 if family == "evm" {
@@ -446,7 +208,7 @@
                         data: None,
                     })?;
 
-                    let result = self
+                    let result = this
                         .evm_get_balance(Parameters(EvmGetBalanceRequest {
                             address: sender,
                             chain_id: Some(chain_id),
@@ -464,11 +226,12 @@
                     .and_then(Value::as_str)
                     .map(|s| s.to_string());
 
-                let result = self
+                let result = this
                     .get_coins(Parameters(GetCoinsRequest {
                         address: sender,
                         coin_type,
                         limit: Some(50),
+                        network: None,
                     }))
                     .await?;
 
@@ -496,16 +259,17 @@
                     });
                 }
 
-                let result = self
+                let result = this
                     .query_transaction_events(Parameters(QueryEventsRequest {
                         digest: digest.to_string(),
+                        network: None,
                     }))
                     .await?;
 
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
             }
             "evm_get_gas_price" => {
-                let result = self
+                let result = this
                     .evm_get_gas_price(Parameters(EvmGetGasPriceRequest { chain_id }))
                     .await?;
                 return Self::wrap_resolved_network_result(&resolved_network, &result);
@@ -520,7 +284,7 @@
                         data: None,
                     })?;
 
-                let result = self
+                let result = this
                     .evm_get_transaction_receipt(Parameters(EvmGetTransactionReceiptRequest {
                         tx_hash: tx_hash.to_string(),
                         chain_id,
@@ -595,10 +359,10 @@
                 }
 
                 // Status handling.
-                if row.status == "sent" {
+                if matches!(row.status.as_str(), "sent" | "confirmed") {
                     let response = Self::pretty_json(&json!({
                         "resolved_network": resolved_network,
-                        "status": "sent",
+                        "status": row.status,
                         "confirmation_id": id,
                         "tx_hash": row.tx_hash,
                         "note": "Already broadcast (recorded in sqlite)"
@@ -620,7 +384,7 @@
                 let mut tx = row.tx;
 
                 // Re-preflight at confirm time (nonce/fees may have changed since dry-run).
-                let preflight = self
+                let preflight = this
                     .evm_preflight(Parameters(EvmPreflightRequest { tx }))
                     .await?;
                 let preflight_json = Self::extract_first_json(&preflight).ok_or_else(|| ErrorData {
@@ -693,7 +457,7 @@
                                 .unwrap_or_else(|_| ethers::types::U256::from(0));
 
                             if required > ethers::types::U256::from(0) {
-                                let allowance_res = self
+                                let allowance_res = this
                                     .evm_erc20_allowance(Parameters(EvmErc20AllowanceRequest {
                                         token: expected_token.to_string(),
                                         owner: tx.from.clone(),
@@ -779,7 +543,7 @@
                                         }),
                                     );
 
-                                    self.write_audit_log(
+                                    this.write_audit_log(
                                         "evm_confirm_execution",
                                         json!({
                                             "event": "blocked",
@@ -872,7 +636,7 @@
                                 );
 
                                 // Audit
-                                self.write_audit_log(
+                                this.write_audit_log(
                                     "evm_confirm_execution",
                                     json!({
                                         "event": "blocked",
@@ -924,7 +688,7 @@
                                         }),
                                     );
 
-                                    self.write_audit_log(
+                                    this.write_audit_log(
                                         "evm_confirm_execution",
                                         json!({
                                             "event": "blocked",
@@ -955,7 +719,7 @@
                             .and_then(|s| ethers::types::U256::from_dec_str(s).ok())
                             .unwrap_or(amount_u256);
 
-                        let allowance_res = self
+                        let allowance_res = this
                             .evm_erc20_allowance(Parameters(EvmErc20AllowanceRequest {
                                 token: token_addr.clone(),
                                 owner: tx.from.clone(),
@@ -998,7 +762,7 @@
                                 }),
                             );
 
-                            self.write_audit_log(
+                            this.write_audit_log(
                                 "evm_confirm_execution",
                                 json!({
                                     "event": "skipped",
@@ -1042,10 +806,21 @@
                     }
                 }
 
+                // Rolling-window spend limits: book the spend before signing (released on error).
+                let spend = crate::utils::spend_limits::evm_entries(
+                    &tx,
+                    &Self::spend_known_evm_tokens(chain_id),
+                    crate::utils::spend_limits::now_ms(),
+                );
+                let reservation = match Self::spend_limit_guard("evm_confirm_execution", &spend, &id) {
+                    crate::utils::spend_limits::SpendGuard::Reserved(r) => r,
+                    crate::utils::spend_limits::SpendGuard::Blocked(blocked) => return blocked,
+                };
+
                 // Mark as consumed (atomic-ish): we keep the row, but status changes.
                 crate::utils::evm_confirm_store::mark_consumed(&conn, &id)?;
 
-                let signed = self
+                let signed = this
                     .evm_sign_transaction_local(Parameters(EvmSignLocalRequest {
                         tx,
                         allow_sender_mismatch: Some(false),
@@ -1070,7 +845,7 @@
                 // Record signed prefix for observability (option C: no full raw_tx stored).
                 let _ = crate::utils::evm_confirm_store::mark_signed(&conn, &id, &raw_tx);
 
-                let sent = self
+                let sent = this
                     .evm_send_raw_transaction(Parameters(EvmSendRawTransactionRequest {
                         chain_id: Some(chain_id),
                         raw_tx,
@@ -1079,6 +854,7 @@
 
                 match sent {
                     Ok(ok) => {
+                        reservation.keep();
                         if let Some(v) = Self::extract_first_json(&ok) {
                             if let Some(tx_hash) = v.get("tx_hash").and_then(Value::as_str) {
                                 let _ = crate::utils::evm_confirm_store::mark_sent(&conn, &id, tx_hash);
@@ -1126,7 +902,7 @@
                     .map(|s| s.to_string());
 
                 // 1) Plan.
-                let planned = self
+                let planned = this
                     .evm_plan_contract_call(Parameters(EvmPlanContractCallRequest {
                         chain_id,
                         address: None,
@@ -1216,7 +992,7 @@
                             let s_trim = s.trim();
                             // only attempt if it looks like a number or a "<num> <sym>" pair
                             if s_trim.chars().any(|c| c.is_ascii_digit()) && !s_trim.starts_with("0x") {
-                                if let Ok(result) = self
+                                if let Ok(result) = this
                                     .evm_parse_amount(Parameters(EvmParseAmountRequest {
                                         chain_id,
                                         amount: s_trim.to_string(),
@@ -1265,7 +1041,7 @@
                 }
 
                 // 2) Build.
-                let built = self
+                let built = this
                     .evm_build_contract_tx(Parameters(EvmBuildContractTxRequest {
                         chain_id,
                         sender: sender.clone(),
@@ -1300,7 +1076,7 @@
                 })?;
 
                 // 3) Preflight.
-                let preflight = self
+                let preflight = this
                     .evm_preflight(Parameters(EvmPreflightRequest { tx }))
                     .await?;
                 let preflight_json = Self::extract_first_json(&preflight).ok_or_else(|| ErrorData {
@@ -1358,7 +1134,7 @@
             }
             "get_balance" => {
                 if family == "evm" {
-                    let result = self
+                    let result = this
                         .evm_get_balance(Parameters(EvmGetBalanceRequest {
                             address: sender,
                             chain_id,
@@ -1369,10 +1145,11 @@
                     return Self::wrap_resolved_network_result(&resolved_network, &result);
                 }
 
-                let result = self
+                let result = this
                     .get_balance(Parameters(GetBalanceRequest {
                         address: sender,
                         coin_type: None,
+                        network: None,
                     }))
                     .await?;
 
@@ -1400,7 +1177,7 @@
                         .to_string();
 
                     // Use the one-step tool to reduce duplication and keep execution stable.
-                    let result = self
+                    let result = this
                         .evm_execute_transfer_native(Parameters(EvmExecuteTransferNativeRequest {
                             sender: sender.clone(),
                             recipient: recipient.clone(),
@@ -1415,6 +1192,34 @@
                     return Self::wrap_resolved_network_result(&resolved_network, &result);
                 }
 
+                if family == "solana" {
+                    let recipient = recipient.ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("recipient is required for Solana transfer"),
+                        data: None,
+                    })?;
+                    let amount_str = entities
+                        .get("amount")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| ErrorData {
+                            code: ErrorCode(-32602),
+                            message: Cow::from("amount is required for Solana transfer (e.g. 'send 0.01 SOL to ... on solana devnet')"),
+                            data: None,
+                        })?
+                        .to_string();
+
+                    let mut transfer_intent = json!({
+                        "chain": "solana",
+                        "action": "transfer_native",
+                        "from": sender,
+                        "to": recipient,
+                        "amount": amount_str,
+                    });
+                    Self::intent_adapter_network(&mut transfer_intent, &resolved_network);
+
+                    return this.execute_intent_via_adapter(transfer_intent, &resolved_network).await;
+                }
+
                 // Sui default (zkLogin flow)
                 let recipient = recipient.ok_or_else(|| ErrorData {
                     code: ErrorCode(-32602),
@@ -1426,7 +1231,7 @@
                     message: Cow::from("input_coins is required for transfer"),
                     data: None,
                 })?;
-                let tx = self
+                let tx = this
                     .build_transfer_sui_intent_tx(
                         sender.clone(),
                         recipient,
@@ -1435,7 +1240,7 @@
                         gas_budget,
                     )
                     .await?;
-                let exec = self
+                let exec = this
                     .execute_zklogin_from_builder_result(
                         tx,
                         request.zk_login_inputs_json.clone(),
@@ -1459,7 +1264,7 @@
                     data: None,
                 })?;
 
-                let tx = self
+                let tx = this
                     .build_transfer_object_intent_tx(
                         sender.clone(),
                         object_id,
@@ -1468,7 +1273,7 @@
                         request.gas_object_id.clone(),
                     )
                     .await?;
-                let exec = self
+                let exec = this
                     .execute_zklogin_from_builder_result(
                         tx,
                         request.zk_login_inputs_json.clone(),
@@ -1492,7 +1297,7 @@
                     data: None,
                 })?;
 
-                let tx = self
+                let tx = this
                     .build_add_stake_intent_tx(
                         sender.clone(),
                         validator,
@@ -1502,7 +1307,7 @@
                         request.gas_object_id.clone(),
                     )
                     .await?;
-                let exec = self
+                let exec = this
                     .execute_zklogin_from_builder_result(
                         tx,
                         request.zk_login_inputs_json.clone(),
@@ -1522,7 +1327,7 @@
                     data: None,
                 })?;
 
-                let tx = self
+                let tx = this
                     .build_withdraw_stake_intent_tx(
                         sender.clone(),
                         staked_sui,
@@ -1530,7 +1335,7 @@
                         request.gas_object_id.clone(),
                     )
                     .await?;
-                let exec = self
+                let exec = this
                     .execute_zklogin_from_builder_result(
                         tx,
                         request.zk_login_inputs_json.clone(),
@@ -1562,7 +1367,7 @@
                 let type_args = request.type_args.unwrap_or_default();
                 let arguments = request.arguments.unwrap_or_default();
 
-                let exec = self
+                let exec = this
                     .auto_execute_move_call_filled(Parameters(AutoExecuteMoveCallRequest {
                         sender,
                         package,
@@ -1593,6 +1398,7 @@
                             message: Cow::from("user_signature required"),
                             data: None,
                         })?,
+                        network: None,
                     }))
                     .await?;
 
@@ -1608,6 +1414,162 @@
         }
     }
 
+    /// Carry the resolved network into an adapter intent (EVM by chain_id, Solana by cluster name).
+    fn intent_adapter_network(intent: &mut Value, resolved_network: &Value) {
+        if let Some(id) = resolved_network.get("chain_id").and_then(Value::as_u64) {
+            intent["chain_id"] = json!(id);
+        }
+        if resolved_network.get("family").and_then(Value::as_str) != Some("sui") {
+            if let Some(n) = resolved_network.get("normalized").and_then(Value::as_str) {
+                intent["network"] = Value::String(n.to_string());
+            }
+        } else if let Some(n) = Self::intent_sui_network(resolved_network) {
+            intent["network"] = Value::String(n);
+        }
+    }
+
+    /// Run an intent through its chain adapter (normalize → simulate → approve → execute).
+    /// Like `w3rt_run_workflow_v0` minus artifacts, except that approval warnings
+    /// (`needs_review`) do not need an override: execution only creates pending confirmations,
+    /// and the warnings are returned for review before confirming. Failed simulations and
+    /// policy blocks still stop before execute.
+    async fn execute_intent_via_adapter(
+        &self,
+        mut intent: Value,
+        resolved_network: &Value,
+    ) -> Result<CallToolResult, ErrorData> {
+        crate::intent_adapters::normalize_intent(&mut intent, None, None);
+
+        let adapter = crate::intent_adapters::adapter_for_intent(&intent).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("No chain adapter supports this intent"),
+            data: Some(json!({
+                "intent": intent,
+                "adapters": crate::intent_adapters::adapters()
+                    .iter()
+                    .map(|a| json!({ "chain": a.name(), "actions": a.actions() }))
+                    .collect::<Vec<_>>()
+            })),
+        })?;
+
+        let simulate = adapter.simulate(self, &intent).await?;
+        let approval = adapter.approve(self, &intent, &simulate).await?;
+
+        let simulation_ok = simulate
+            .get("simulation_performed")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+            && simulate.get("status").and_then(Value::as_str) == Some("ok");
+        let approval_ok = approval.get("status").and_then(Value::as_str) == Some("ok");
+
+        let run_id = format!("intent_{}", crate::utils::evm_confirm_store::now_ms());
+        let execute = if !simulation_ok {
+            json!({
+                "stage": "execute",
+                "status": "blocked",
+                "guard": { "guard_class": "no_sim_no_send" },
+                "note": "Execution blocked: simulation not OK (safety)."
+            })
+        } else if approval.get("status").and_then(Value::as_str) == Some("blocked") {
+            json!({
+                "stage": "execute",
+                "status": "blocked",
+                "guard": {
+                    "guard_class": "policy_blocked",
+                    "matched_rule_ids": approval.get("policy").and_then(|p| p.get("matched_rule_ids"))
+                },
+                "note": "Execution blocked: the approval policy blocks this intent."
+            })
+        } else {
+            adapter
+                .execute(self, &run_id, &intent, &simulate, &approval)
+                .await?
+        };
+
+        let mut out = json!({
+            "resolved_network": resolved_network,
+            "adapter": adapter.name(),
+            "mode": "dry_run_only",
+            "intent": intent,
+            "simulate": simulate,
+            "approval": approval,
+            "review_required": !approval_ok,
+            "execute": execute,
+            "note": if approval_ok {
+                "Safe default: not broadcast. Follow execute.next to confirm."
+            } else {
+                "Safe default: not broadcast. approval.status is not ok: review approval.warnings before following execute.next to confirm."
+            }
+        });
+        // EVM swaps keep the swap / approve / allowance fields execute_intent returned before
+        // the adapters existed.
+        if simulate.get("adapter").and_then(Value::as_str) == Some("evm_0x") {
+            if let (Some(map), Value::Object(extra)) =
+                (out.as_object_mut(), Self::intent_evm_swap_fields(&simulate, &execute))
+            {
+                map.extend(extra);
+            }
+        }
+
+        let response = Self::pretty_json(&out)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// `provider` / `swap` / `approve` / `allowance` of an EVM (0x) swap, from the adapter's
+    /// simulate and execute artifacts.
+    fn intent_evm_swap_fields(simulate: &Value, execute: &Value) -> Value {
+        let step = |key: &str, label: &str| -> Option<Value> {
+            let mut step = execute
+                .get("result")
+                .and_then(|r| r.get(key))
+                .filter(|v| v.is_object())
+                .cloned()?;
+            let id = step["confirmation_id"].as_str().unwrap_or_default().to_string();
+            let hash = step["tx_summary_hash"].as_str().unwrap_or_default().to_string();
+            step["next"] = json!({
+                "how_to_confirm": format!("confirm {} hash:{} ({}) (and include same network)", id, hash, label)
+            });
+            Some(step)
+        };
+
+        let approve_sim = simulate.get("approve").filter(|a| a.is_object());
+        let needed = approve_sim
+            .and_then(|a| a.get("needed"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let approve = match approve_sim {
+            None => Value::Null,
+            Some(a) => {
+                let mut approve = needed
+                    .then(|| step("approve", "approve"))
+                    .flatten()
+                    .unwrap_or_else(|| json!({}));
+                approve["needed"] = json!(needed);
+                approve["allowance_raw"] = a.get("allowance_raw").cloned().unwrap_or(Value::Null);
+                approve["required_raw"] = a.get("required_raw").cloned().unwrap_or(Value::Null);
+                approve
+            }
+        };
+
+        let swap = step("tx", "swap").map(|mut swap| {
+            swap["preflight"] = simulate.get("simulation").cloned().unwrap_or(Value::Null);
+            swap
+        });
+
+        json!({
+            "provider": "0x",
+            "swap": swap,
+            "approve": approve,
+            "allowance": {
+                "sell_token_address": simulate.get("sell_token_address"),
+                "allowance_target": approve_sim.and_then(|a| a.get("spender")),
+                "current": approve_sim.map(|a| json!({ "allowance_raw": a.get("allowance_raw") })),
+                "suggested_approve_tx": approve_sim.and_then(|a| a.get("tx")).filter(|t| !t.is_null()),
+                "note": "If approve.needed=true, confirm approve first, then confirm swap. If approve.needed=false, you can confirm swap directly."
+            }
+        })
+    }
+
     fn resolve_intent_network(network: Option<String>, lower: &str) -> Value {
         fn is_test(s: &str) -> bool {
             s.contains("test") || s.contains("testnet") || s.contains("sepolia") || s.contains("amoy") || s.contains("mumbai") || s.contains("alfajores") || s.contains("测试")
@@ -4,7 +4,8 @@
         &self,
         Parameters(request): Parameters<AutoFillMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let filled = self.auto_fill_move_call_internal(&request).await?;
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let filled = this.auto_fill_move_call_internal(&request).await?;
 
         let payload = json!({
             "sender": request.sender,
@@ -32,6 +33,7 @@
         &self,
         Parameters(request): Parameters<AutoPrepareMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let fill_request = AutoFillMoveCallRequest {
             sender: request.sender.clone(),
             package: request.package.clone(),
@@ -42,9 +44,10 @@
             gas_budget: request.gas_budget,
             gas_object_id: request.gas_object_id.clone(),
             gas_price: request.gas_price,
+            network: None,
         };
 
-        let filled = self.auto_fill_move_call_internal(&fill_request).await?;
+        let filled = this.auto_fill_move_call_internal(&fill_request).await?;
         let gas_budget = filled.gas_budget.ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
             message: Cow::from("gas_budget is required for auto_prepare_move_call"),
@@ -61,9 +64,10 @@
             gas_budget,
             gas_object_id: filled.gas_object_id,
             gas_price: filled.gas_price,
+            network: None,
         };
 
-        let response = self.prepare_move_call(Parameters(prepare_request)).await?;
+        let response = this.prepare_move_call(Parameters(prepare_request)).await?;
         Ok(response)
     }
 
@@ -73,6 +77,7 @@
         &self,
         Parameters(request): Parameters<AutoExecuteMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let package = Self::parse_object_id(&request.package)?;
         let gas = request
@@ -84,7 +89,7 @@
         let type_args = Self::parse_type_args(request.type_args)?;
         let call_args = Self::parse_json_args(&request.arguments)?;
 
-        let tx_data = self
+        let tx_data = this
             .build_move_call_tx_data(
                 sender,
                 package,
@@ -99,7 +104,7 @@
             )
             .await?;
 
-        let (tx_bytes, result) = self
+        let (tx_bytes, result) = this
             .execute_tx_with_zklogin(
                 tx_data,
                 &request.user_signature,
@@ -125,6 +130,7 @@
         &self,
         Parameters(request): Parameters<AutoExecuteMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let fill_request = AutoFillMoveCallRequest {
             sender: request.sender.clone(),
             package: request.package.clone(),
@@ -135,9 +141,10 @@
             gas_budget: Some(request.gas_budget),
             gas_object_id: request.gas_object_id.clone(),
             gas_price: request.gas_price,
+            network: None,
         };
 
-        let filled = self.auto_fill_move_call_internal(&fill_request).await?;
+        let filled = this.auto_fill_move_call_internal(&fill_request).await?;
         let payload = json!({
             "sender": request.sender,
             "package": request.package,
@@ -156,7 +163,7 @@
         let (type_args, call_args, gas_budget, gas, gas_price) =
             Self::parse_execute_payload(&payload, request.gas_budget)?;
 
-        let tx_data = self
+        let tx_data = this
             .build_move_call_tx_data(
                 sender,
                 package,
@@ -171,7 +178,7 @@
             )
             .await?;
 
-        let (tx_bytes, result) = self
+        let (tx_bytes, result) = this
             .execute_tx_with_zklogin(
                 tx_data,
                 &request.user_signature,
@@ -183,7 +190,7 @@
             .await?;
 
         let summary = Self::summarize_transaction(&result);
-        let form_schema = self
+        let form_schema = this
             .build_move_call_form_schema(
                 package,
                 &request.package,
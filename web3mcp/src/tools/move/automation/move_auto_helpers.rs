@@ -13,11 +13,8 @@
         context: &str,
     ) -> Result<Value, ErrorData> {
         let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package_id)
-            .await
-            .map_err(|e| Self::sdk_error(context, e))?;
+            .load_normalized_move_modules(package_id, context)
+            .await?;
 
         let module_def = modules.get(module).ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
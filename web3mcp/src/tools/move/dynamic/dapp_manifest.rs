@@ -25,7 +25,8 @@
         &self,
         Parameters(request): Parameters<DappMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let manifest = self.load_dapp_manifest(request.manifest_path.as_deref())?;
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let manifest = this.load_dapp_manifest(request.manifest_path.as_deref())?;
         let entry = manifest
             .dapps
             .iter()
@@ -46,9 +47,10 @@
             gas_budget: request.gas_budget,
             gas_object_id: request.gas_object_id.clone(),
             gas_price: request.gas_price,
+            network: None,
         };
 
-        let filled = self.auto_fill_move_call_internal(&fill_request).await?;
+        let filled = this.auto_fill_move_call_internal(&fill_request).await?;
         let payload = json!({
             "sender": fill_request.sender,
             "package": entry.package,
@@ -69,17 +71,22 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    fn load_dapp_manifest(
-        &self,
-        path: Option<&str>,
-    ) -> Result<DappManifest, ErrorData> {
-        let manifest_path = if let Some(path) = path {
+    /// `path`, else `SUI_DAPP_MANIFEST`, else `./dapps.json`.
+    fn dapp_manifest_path(path: Option<&str>) -> std::path::PathBuf {
+        if let Some(path) = path {
             std::path::PathBuf::from(path)
-        } else if let Ok(env_path) = std::env::var("SUI_DAPP_MANIFEST") {
+        } else if let Ok(env_path) = crate::utils::config::var("SUI_DAPP_MANIFEST") {
             std::path::PathBuf::from(env_path)
         } else {
             std::path::PathBuf::from("dapps.json")
-        };
+        }
+    }
+
+    fn load_dapp_manifest(
+        &self,
+        path: Option<&str>,
+    ) -> Result<DappManifest, ErrorData> {
+        let manifest_path = Self::dapp_manifest_path(path);
 
         let contents = std::fs::read_to_string(&manifest_path).map_err(|e| ErrorData {
             code: ErrorCode(-32602),
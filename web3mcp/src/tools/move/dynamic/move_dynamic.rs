@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<GetDynamicFieldsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let object_id = Self::parse_object_id(&request.object_id)?;
         let cursor = match request.cursor {
             Some(cursor) => Some(Self::parse_object_id(&cursor)?),
@@ -11,12 +12,14 @@
         };
         let limit = request.limit.map(|limit| Self::clamp_limit(Some(limit), limit, 50));
 
-        let result = self
-            .client
-            .read_api()
-            .get_dynamic_fields(object_id, cursor, limit)
-            .await
-            .map_err(|e| Self::sdk_error("get_dynamic_fields", e))?;
+        let result = this
+            .sui_read("get_dynamic_fields", |client| async move {
+                client
+                    .read_api()
+                    .get_dynamic_fields(object_id, cursor, limit)
+                    .await
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -28,11 +31,12 @@
         &self,
         Parameters(request): Parameters<GetDynamicFieldTreeRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let object_id = Self::parse_object_id(&request.object_id)?;
         let max_depth = request.max_depth.unwrap_or(2);
         let limit = request.limit.unwrap_or(50).min(50);
 
-        let tree = self
+        let tree = this
             .fetch_dynamic_field_tree(object_id, 0, max_depth, limit)
             .await?;
 
@@ -46,15 +50,21 @@
         &self,
         Parameters(request): Parameters<GetDynamicFieldObjectRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let parent_object_id = Self::parse_object_id(&request.parent_object_id)?;
         let name = Self::parse_dynamic_field_name(&request.name_type, request.name_value)?;
 
-        let result = self
-            .client
-            .read_api()
-            .get_dynamic_field_object(parent_object_id, name)
-            .await
-            .map_err(|e| Self::sdk_error("get_dynamic_field_object", e))?;
+        let result = this
+            .sui_read("get_dynamic_field_object", |client| {
+                let name = name.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_dynamic_field_object(parent_object_id, name)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -66,13 +76,13 @@
         &self,
         Parameters(request): Parameters<GetMoveObjectBcsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let object_id = Self::parse_object_id(&request.object_id)?;
-        let result = self
-            .client
-            .read_api()
-            .get_move_object_bcs(object_id)
-            .await
-            .map_err(|e| Self::sdk_error("get_move_object_bcs", e))?;
+        let result = this
+            .sui_read("get_move_object_bcs", |client| async move {
+                client.read_api().get_move_object_bcs(object_id).await
+            })
+            .await?;
 
         let response = json!({
             "bcs_base64": Base64Engine.encode(result),
@@ -87,16 +97,22 @@
         &self,
         Parameters(request): Parameters<GetPastObjectRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let object_id = Self::parse_object_id(&request.object_id)?;
         let version = SequenceNumber::from(request.version);
         let options = Self::object_options_from_request(request.options);
 
-        let result = self
-            .client
-            .read_api()
-            .try_get_parsed_past_object(object_id, version, options)
-            .await
-            .map_err(|e| Self::sdk_error("get_past_object", e))?;
+        let result = this
+            .sui_read("get_past_object", |client| {
+                let options = options.clone();
+                async move {
+                    client
+                        .read_api()
+                        .try_get_parsed_past_object(object_id, version, options)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -108,6 +124,7 @@
         &self,
         Parameters(request): Parameters<MultiGetPastObjectsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let options = Self::object_options_from_request(request.options);
         let objects = request
             .objects
@@ -119,12 +136,18 @@
             })
             .collect::<Result<Vec<_>, ErrorData>>()?;
 
-        let result = self
-            .client
-            .read_api()
-            .try_multi_get_parsed_past_object(objects, options)
-            .await
-            .map_err(|e| Self::sdk_error("multi_get_past_objects", e))?;
+        let result = this
+            .sui_read("multi_get_past_objects", |client| {
+                let objects = objects.clone();
+                let options = options.clone();
+                async move {
+                    client
+                        .read_api()
+                        .try_multi_get_parsed_past_object(objects, options)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -136,15 +159,21 @@
         &self,
         Parameters(request): Parameters<GetAllCoinsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let owner = Self::parse_address(&request.owner)?;
         let limit = request.limit.map(|limit| Self::clamp_limit(Some(limit), limit, 200));
 
-        let result = self
-            .client
-            .coin_read_api()
-            .get_all_coins(owner, request.cursor, limit)
-            .await
-            .map_err(|e| Self::sdk_error("get_all_coins", e))?;
+        let result = this
+            .sui_read("get_all_coins", |client| {
+                let cursor = request.cursor.clone();
+                async move {
+                    client
+                        .coin_read_api()
+                        .get_all_coins(owner, cursor, limit)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
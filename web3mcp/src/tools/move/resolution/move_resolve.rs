@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<ResolveMoveCallArgsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let package = Self::parse_object_id(&request.package)?;
         let module = Identifier::from_str(&request.module).map_err(|e| ErrorData {
             code: ErrorCode(-32602),
@@ -18,7 +19,7 @@
 
         let type_args = Self::parse_type_args_to_typetag(request.type_args)?;
 
-        let modules = self
+        let modules = this
             .load_normalized_move_modules(package, "resolve_move_call_args")
             .await?;
         let (_module_def, function_def) =
@@ -29,7 +30,7 @@
         let call_args = Self::parse_json_args(&request.arguments)?;
 
         let mut builder = ProgrammableTransactionBuilder::new();
-        self
+        this
             .client
             .transaction_builder()
             .resolve_and_checks_json_args(&mut builder, package, &module, &function, &type_args, call_args)
@@ -86,6 +87,7 @@
         &self,
         Parameters(request): Parameters<PrepareMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let package = Self::parse_object_id(&request.package)?;
         let gas = match request.gas_object_id {
@@ -96,7 +98,7 @@
         let type_args = Self::parse_type_args(request.type_args)?;
         let call_args = Self::parse_json_args(&request.arguments)?;
 
-        let tx_data = self
+        let tx_data = this
             .build_move_call_tx_data(
                 sender,
                 package,
@@ -127,8 +129,9 @@
         &self,
         Parameters(request): Parameters<GenerateMoveCallPayloadRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let package = Self::parse_object_id(&request.package)?;
-        let modules = self
+        let modules = this
             .load_normalized_move_modules(package, "generate_move_call_payload")
             .await?;
         let (_module_def, function_def) =
@@ -136,7 +139,7 @@
 
         let (type_args, arguments) = Self::build_move_call_payload_template(function_def);
 
-        let form_schema = self
+        let form_schema = this
             .build_move_call_form_schema(
                 package,
                 &request.package,
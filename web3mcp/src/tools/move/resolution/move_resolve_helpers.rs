@@ -8,11 +8,26 @@
         package: ObjectID,
         context: &str,
     ) -> Result<std::collections::BTreeMap<String, SuiMoveNormalizedModule>, ErrorData> {
-        self.client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error(context, e))
+        // Published packages never change (an upgrade gets a new id): cached without expiry.
+        let key = format!(
+            "sui:{}:{}",
+            crate::utils::config::redact_url(&self.rpc_url),
+            package
+        );
+        crate::utils::response_cache::cached(
+            crate::utils::response_cache::CacheCategory::MoveModules,
+            &key,
+            || async {
+                self.sui_read(context, |client| async move {
+                    client
+                        .read_api()
+                        .get_normalized_move_modules_by_package(package)
+                        .await
+                })
+                .await
+            },
+        )
+        .await
     }
 
     fn get_normalized_move_function_def<'a>(
@@ -4,13 +4,11 @@
         &self,
         Parameters(request): Parameters<GetNormalizedMoveModulesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let package = Self::parse_object_id(&request.package)?;
-        let result = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error("get_normalized_move_modules", e))?;
+        let result = this
+            .load_normalized_move_modules(package, "get_normalized_move_modules")
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -22,13 +20,11 @@
         &self,
         Parameters(request): Parameters<DescribeMoveFunctionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let package = Self::parse_object_id(&request.package)?;
-        let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error("describe_move_function", e))?;
+        let modules = this
+            .load_normalized_move_modules(package, "describe_move_function")
+            .await?;
 
         let module = modules.get(&request.module).ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
@@ -99,13 +95,11 @@
         &self,
         Parameters(request): Parameters<GenerateModuleTemplatesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let package = Self::parse_object_id(&request.package)?;
-        let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error("generate_module_templates", e))?;
+        let modules = this
+            .load_normalized_move_modules(package, "generate_module_templates")
+            .await?;
         let entry_only = request.entry_only.unwrap_or(true);
 
         let mut templates = Vec::new();
@@ -154,14 +148,20 @@
         &self,
         Parameters(request): Parameters<SuggestObjectMethodsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let object_id = Self::parse_object_id(&request.object_id)?;
         let options = SuiObjectDataOptions::new().with_type();
-        let object = self
-            .client
-            .read_api()
-            .get_object_with_options(object_id, options)
-            .await
-            .map_err(|e| Self::sdk_error("suggest_object_methods", e))?;
+        let object = this
+            .sui_read("suggest_object_methods", |client| {
+                let options = options.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_object_with_options(object_id, options)
+                        .await
+                }
+            })
+            .await?;
 
         let object_data = object.data.ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
@@ -187,12 +187,9 @@
             message: Cow::from(format!("Invalid package id from object type: {}", e)),
             data: None,
         })?;
-        let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package_id)
-            .await
-            .map_err(|e| Self::sdk_error("suggest_object_methods", e))?;
+        let modules = this
+            .load_normalized_move_modules(package_id, "suggest_object_methods")
+            .await?;
 
         let module_name = struct_tag.module.to_string();
         let module = modules.get(&module_name).ok_or_else(|| ErrorData {
@@ -241,13 +238,11 @@
         &self,
         Parameters(request): Parameters<GenerateMoveCallFormSchemaRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let package = Self::parse_object_id(&request.package)?;
-        let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error("generate_move_call_form_schema", e))?;
+        let modules = this
+            .load_normalized_move_modules(package, "generate_move_call_form_schema")
+            .await?;
         let module = modules.get(&request.module).ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
             message: Cow::from(format!("Module not found: {}", request.module)),
@@ -4,16 +4,14 @@
         &self,
         Parameters(request): Parameters<SuggestMoveCallInputsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let package = Self::parse_object_id(&request.package)?;
         let limit = request.limit.unwrap_or(200).min(200);
 
-        let modules = self
-            .client
-            .read_api()
-            .get_normalized_move_modules_by_package(package)
-            .await
-            .map_err(|e| Self::sdk_error("suggest_move_call_inputs", e))?;
+        let modules = this
+            .load_normalized_move_modules(package, "suggest_move_call_inputs")
+            .await?;
         let module = modules.get(&request.module).ok_or_else(|| ErrorData {
             code: ErrorCode(-32602),
             message: Cow::from(format!("Module not found: {}", request.module)),
@@ -30,12 +28,17 @@
 
         let options = SuiObjectDataOptions::new().with_type().with_owner();
         let query = SuiObjectResponseQuery::new(None, Some(options));
-        let owned = self
-            .client
-            .read_api()
-            .get_owned_objects(sender, Some(query), None, Some(limit))
-            .await
-            .map_err(|e| Self::sdk_error("suggest_move_call_inputs", e))?;
+        let owned = this
+            .sui_read("suggest_move_call_inputs", |client| {
+                let query = query.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_owned_objects(sender, Some(query), None, Some(limit))
+                        .await
+                }
+            })
+            .await?;
 
         let mut owned_metadata: Vec<(ObjectID, StructTag, Owner)> = Vec::new();
         for item in owned.data {
@@ -132,13 +135,12 @@
 
         let type_args = Self::type_args_from_mapping(&type_mapping, function_def.type_parameters.len());
         let gas = if let Some(gas_budget) = request.gas_budget {
-            let gas_price = self
-                .client
-                .read_api()
-                .get_reference_gas_price()
-                .await
-                .map_err(|e| Self::sdk_error("suggest_move_call_inputs", e))?;
-            let gas_object = self
+            let gas_price = this
+                .sui_read("suggest_move_call_inputs", |client| async move {
+                    client.read_api().get_reference_gas_price().await
+                })
+                .await?;
+            let gas_object = this
                 .client
                 .transaction_builder()
                 .select_gas(sender, None, gas_budget, vec![], gas_price)
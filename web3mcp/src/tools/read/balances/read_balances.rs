@@ -4,14 +4,32 @@
         &self,
         Parameters(request): Parameters<GetBalanceRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let address = Self::parse_address(&request.address)?;
         let coin_type = request.coin_type.unwrap_or_else(|| "0x2::sui::SUI".to_string());
-        let result = self
-            .client
-            .coin_read_api()
-            .get_balance(address, Some(coin_type))
-            .await
-            .map_err(|e| Self::sdk_error("suix_getBalance", e))?;
+        let cache_key = format!(
+            "sui:{}:balance:{}:{}",
+            crate::utils::config::redact_url(&this.rpc_url),
+            address,
+            coin_type
+        );
+        let result: sui_json_rpc_types::Balance = crate::utils::response_cache::cached(
+            crate::utils::response_cache::CacheCategory::Balance,
+            &cache_key,
+            || async {
+                this.sui_read("suix_getBalance", |client| {
+                    let coin_type = coin_type.clone();
+                    async move {
+                        client
+                            .coin_read_api()
+                            .get_balance(address, Some(coin_type))
+                            .await
+                    }
+                })
+                .await
+            },
+        )
+        .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -24,13 +42,13 @@
         &self,
         Parameters(request): Parameters<GetAllBalancesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let address = Self::parse_address(&request.address)?;
-        let result = self
-            .client
-            .coin_read_api()
-            .get_all_balances(address)
-            .await
-            .map_err(|e| Self::sdk_error("suix_getAllBalances", e))?;
+        let result = this
+            .sui_read("suix_getAllBalances", |client| async move {
+                client.coin_read_api().get_all_balances(address).await
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -43,38 +61,50 @@
         &self,
         Parameters(request): Parameters<WalletOverviewRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let address = if let Some(address) = request.address.as_deref() {
             Self::parse_address(address)?
         } else {
-            let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-            self.resolve_keystore_signer(&keystore, request.signer.as_deref())?
+            let signer = this
+                .sui_signer(request.keystore_path.as_deref(), request.signer.as_deref(), None)
+                .await?;
+            Self::sui_signer_address(signer.as_ref())?
         };
 
         let coin_type = request
             .coin_type
             .unwrap_or_else(|| "0x2::sui::SUI".to_string());
-        let sui_balance = self
-            .client
-            .coin_read_api()
-            .get_balance(address, Some(coin_type.clone()))
-            .await
-            .map_err(|e| Self::sdk_error("get_wallet_overview", e))?;
-        let all_balances = self
-            .client
-            .coin_read_api()
-            .get_all_balances(address)
-            .await
-            .map_err(|e| Self::sdk_error("get_wallet_overview", e))?;
+        let sui_balance = this
+            .sui_read("get_wallet_overview", |client| {
+                let coin_type = coin_type.clone();
+                async move {
+                    client
+                        .coin_read_api()
+                        .get_balance(address, Some(coin_type))
+                        .await
+                }
+            })
+            .await?;
+        let all_balances = this
+            .sui_read("get_wallet_overview", |client| async move {
+                client.coin_read_api().get_all_balances(address).await
+            })
+            .await?;
 
         let include_coins = request.include_coins.unwrap_or(false);
         let coins = if include_coins {
             let limit = Self::clamp_limit(request.coins_limit, 20, 50);
             Some(
-                self.client
-                    .coin_read_api()
-                    .get_coins(address, Some(coin_type.clone()), None, Some(limit))
-                    .await
-                    .map_err(|e| Self::sdk_error("get_wallet_overview", e))?,
+                this.sui_read("get_wallet_overview", |client| {
+                    let coin_type = coin_type.clone();
+                    async move {
+                        client
+                            .coin_read_api()
+                            .get_coins(address, Some(coin_type), None, Some(limit))
+                            .await
+                    }
+                })
+                .await?,
             )
         } else {
             None
@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<GetObjectRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let object_id = Self::parse_object_id(&request.object_id)?;
         let mut options = SuiObjectDataOptions::new()
             .with_type()
@@ -16,12 +17,17 @@
             options = options.with_content();
         }
 
-        let result = self
-            .client
-            .read_api()
-            .get_object_with_options(object_id, options)
-            .await
-            .map_err(|e| Self::sdk_error("sui_getObject", e))?;
+        let result = this
+            .sui_read("sui_getObject", |client| {
+                let options = options.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_object_with_options(object_id, options)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -34,17 +40,23 @@
         &self,
         Parameters(request): Parameters<GetOwnedObjectsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let address = Self::parse_address(&request.address)?;
         let options = SuiObjectDataOptions::new().with_type().with_owner();
         let query = SuiObjectResponseQuery::new(None, Some(options));
         let limit = Self::clamp_limit(request.limit, 50, 50);
 
-        let result = self
-            .client
-            .read_api()
-            .get_owned_objects(address, Some(query), None, Some(limit))
-            .await
-            .map_err(|e| Self::sdk_error("suix_getOwnedObjects", e))?;
+        let result = this
+            .sui_read("suix_getOwnedObjects", |client| {
+                let query = query.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_owned_objects(address, Some(query), None, Some(limit))
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<GetTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let digest = Self::parse_digest(&request.digest)?;
         let options = SuiTransactionBlockResponseOptions::new()
             .with_input()
@@ -12,12 +13,30 @@
             .with_object_changes()
             .with_balance_changes();
 
-        let result = self
-            .client
-            .read_api()
-            .get_transaction_with_options(digest, options)
-            .await
-            .map_err(|e| Self::sdk_error("sui_getTransactionBlock", e))?;
+        // Checkpointed transactions are final: cached without expiry.
+        let cache_key = format!(
+            "sui:{}:tx:{}",
+            crate::utils::config::redact_url(&this.rpc_url),
+            digest
+        );
+        let result = crate::utils::response_cache::cached_if(
+            crate::utils::response_cache::CacheCategory::FinalizedTx,
+            &cache_key,
+            || async {
+                this.sui_read("sui_getTransactionBlock", |client| {
+                    let options = options.clone();
+                    async move {
+                        client
+                            .read_api()
+                            .get_transaction_with_options(digest, options)
+                            .await
+                    }
+                })
+                .await
+            },
+            |tx: &SuiTransactionBlockResponse| tx.checkpoint.is_some(),
+        )
+        .await?;
 
         let response = Self::pretty_json(&result)?;
 
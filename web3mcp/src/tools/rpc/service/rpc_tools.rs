@@ -6,7 +6,7 @@
     ) -> Result<CallToolResult, ErrorData> {
         let endpoint = request
             .endpoint
-            .or_else(|| std::env::var("SUI_GRPC_URL").ok())
+            .or_else(|| crate::utils::config::var("SUI_GRPC_URL").ok())
             .unwrap_or_else(|| RpcClient::MAINNET_FULLNODE.to_string());
         let mut client = RpcClient::new(endpoint.clone()).map_err(|e| ErrorData {
             code: ErrorCode(-32603),
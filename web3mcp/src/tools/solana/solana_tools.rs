@@ -237,13 +237,18 @@
         }
     }
 
-    fn solana_rpc_url_for_network(network: Option<&str>) -> Result<String, ErrorData> {
+    /// RPC URLs for `network`, primary first. Each setting may list several URLs
+    /// (comma-separated); `solana_rpc` fails over between them.
+    fn solana_rpc_urls_for_network(network: Option<&str>) -> Result<Vec<String>, ErrorData> {
         // Priority:
         // 1) SOLANA_RPC_URL (explicit override)
         // 2) SOLANA_RPC_URL_MAINNET / _DEVNET / _TESTNET
         // 3) well-known public endpoints
-        if let Ok(url) = std::env::var("SOLANA_RPC_URL") {
-            return Ok(url);
+        if let Ok(url) = crate::utils::config::var("SOLANA_RPC_URL") {
+            let urls = crate::utils::rpc_endpoints::parse_url_list(&url);
+            if !urls.is_empty() {
+                return Ok(urls);
+            }
         }
 
         let net = network.unwrap_or("mainnet").trim().to_lowercase();
@@ -263,7 +268,17 @@
             }
         };
 
-        Ok(std::env::var(env_key).unwrap_or_else(|_| default_url.to_string()))
+        let urls = crate::utils::config::var(env_key)
+            .map(|v| crate::utils::rpc_endpoints::parse_url_list(&v))
+            .unwrap_or_default();
+        if urls.is_empty() {
+            return Ok(vec![default_url.to_string()]);
+        }
+        Ok(urls)
+    }
+
+    fn solana_rpc_url_for_network(network: Option<&str>) -> Result<String, ErrorData> {
+        Ok(Self::solana_rpc_urls_for_network(network)?.remove(0))
     }
 
     fn solana_rpc_url_default() -> String {
@@ -272,7 +287,7 @@
     }
 
     fn solana_keypair_path() -> Result<String, ErrorData> {
-        std::env::var("SOLANA_KEYPAIR_PATH").map_err(|_| ErrorData {
+        crate::utils::config::var("SOLANA_KEYPAIR_PATH").map_err(|_| ErrorData {
             code: ErrorCode(-32602),
             message: Cow::from(
                 "Missing SOLANA_KEYPAIR_PATH env var (path to Solana JSON keypair file)",
@@ -334,42 +349,128 @@
         Ok(c)
     }
 
+    /// Stores `tx_base64` as a pending confirmation (workflow stages hand these to
+    /// `solana_confirm_transaction`).
+    fn solana_create_pending_confirmation(
+        network: Option<&str>,
+        tx_base64: &str,
+        source: &str,
+        summary: Option<Value>,
+    ) -> Result<Value, ErrorData> {
+        let rpc_url = Self::solana_rpc_url_for_network(network)?;
+
+        let tx_bytes = base64::engine::general_purpose::STANDARD
+            .decode(tx_base64.trim())
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("Invalid transaction_base64: {}", e)),
+                data: None,
+            })?;
+
+        let hash = crate::utils::solana_confirm_store::tx_summary_hash(&tx_bytes);
+
+        let created = crate::utils::solana_confirm_store::now_ms();
+        let ttl = crate::utils::solana_confirm_store::default_ttl_ms();
+        let expires = created + ttl;
+
+        let id_seed = format!("{}:{}", created, hash);
+        let id_suffix = crate::utils::solana_confirm_store::tx_summary_hash(id_seed.as_bytes());
+        let confirmation_id = format!("solana_confirm_{}", &id_suffix[..16]);
+
+        crate::utils::solana_confirm_store::insert_pending(
+            &confirmation_id,
+            tx_base64.trim(),
+            created,
+            expires,
+            &hash,
+            source,
+            summary,
+        )?;
+
+        // Mainnet safety: require confirm_token.
+        let token = if Self::solana_is_mainnet_network(network) {
+            Some(crate::utils::solana_confirm_store::make_confirm_token(
+                &confirmation_id,
+                &hash,
+            ))
+        } else {
+            None
+        };
+
+        Ok(json!({
+            "ok": true,
+            "status": "pending",
+            "rpc_url": rpc_url,
+            "network": network.unwrap_or("mainnet").to_string(),
+            "pending_confirmation_id": confirmation_id,
+            "tx_summary_hash": hash,
+            "confirm_token": token,
+            "expires_in_ms": ttl
+        }))
+    }
+
+    /// Client that fails over between the network's RPC URLs (health is kept per network).
     fn solana_rpc(
         network: Option<&str>,
     ) -> Result<solana_client::nonblocking::rpc_client::RpcClient, ErrorData> {
-        let url = Self::solana_rpc_url_for_network(network)?;
-        Ok(solana_client::nonblocking::rpc_client::RpcClient::new(url))
+        let urls = Self::solana_rpc_urls_for_network(network)?;
+        let set = crate::utils::rpc_endpoints::endpoint_set(
+            &Self::solana_rpc_endpoint_key(network),
+            urls,
+        );
+        Ok(crate::utils::solana_rpc::failover_client(set))
+    }
+
+    fn solana_rpc_endpoint_key(network: Option<&str>) -> String {
+        let n = network.unwrap_or("mainnet").trim().to_lowercase();
+        let n = match n.as_str() {
+            "mainnet-beta" | "mainnet_beta" => "mainnet".to_string(),
+            _ => n,
+        };
+        format!("solana:{}", n)
     }
 
     fn solana_keypair_path_with_default(path: Option<&str>) -> Result<String, ErrorData> {
         if let Some(p) = path {
             return Ok(p.to_string());
         }
-        if let Ok(p) = std::env::var("SOLANA_KEYPAIR_PATH") {
+        if let Ok(p) = crate::utils::config::var("SOLANA_KEYPAIR_PATH") {
             return Ok(p);
         }
         let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
         Ok(format!("{}/.config/solana/id.json", home))
     }
 
-    #[tool(description = "Solana: get wallet address from SOLANA_KEYPAIR_PATH JSON")]
-    async fn solana_get_wallet_address(&self) -> Result<CallToolResult, ErrorData> {
-        let kp_path = Self::solana_keypair_path()?;
-        let kp = Self::solana_read_keypair_from_json_file(&kp_path)?;
-        let addr = solana_sdk::signature::Signer::pubkey(&kp).to_string();
+    #[tool(description = "Solana: get the configured signer's wallet address for a network (SOLANA_KEYPAIR_PATH by default)")]
+    async fn solana_get_wallet_address(
+        &self,
+        Parameters(request): Parameters<SolanaGetWalletAddressRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let network = request.network.as_deref();
+        let signer = Self::solana_signer(network).await?;
+        let addr = Self::solana_signer_pubkey(signer.as_ref())?.to_string();
         let response = Self::pretty_json(&json!({
-            "rpc_url": Self::solana_rpc_url_default(),
-            "keypair_path": kp_path,
+            "rpc_url": Self::solana_rpc_url_for_network(network)?,
+            "keypair_path": signer.source(),
+            "signer_backend": signer.backend().as_str(),
             "address": addr
         }))?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    #[tool(description = "Solana: get keypair info (address) from a custom path or default")]
+    #[tool(description = "Solana: get keypair info (address) from a keystore account, a custom path or default")]
     async fn solana_keypair_info(
         &self,
         Parameters(request): Parameters<SolanaKeypairInfoRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        if let Some(account) = request.account.as_deref() {
+            let response = Self::pretty_json(&json!({
+                "account": crate::utils::solana_keystore::validate_name(account)?,
+                "keystore_path": crate::utils::solana_keystore::account_path(account)?,
+                "address": crate::utils::solana_keystore::read_pubkey(account)?
+            }))?;
+            return Ok(CallToolResult::success(vec![Content::text(response)]));
+        }
         let kp_path = Self::solana_keypair_path_with_default(request.keypair_path.as_deref())?;
         let kp = Self::solana_read_keypair_from_json_file(&kp_path)?;
         let addr = solana_sdk::signature::Signer::pubkey(&kp).to_string();
@@ -380,10 +481,161 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
+    #[tool(description = "Solana: list encrypted keystore accounts with pubkeys and the account selected per network (no password needed)")]
+    async fn solana_keystore_list(&self) -> Result<CallToolResult, ErrorData> {
+        let accounts = crate::utils::solana_keystore::list()?;
+        let backend = crate::utils::signer::backend_for(crate::utils::signer::SignerChain::Solana)?;
+        let response = Self::pretty_json(&json!({
+            "keystore_dir": crate::utils::solana_keystore::dir(),
+            "signer_backend": backend.as_str(),
+            "defaults": crate::utils::solana_keystore::defaults(),
+            "count": accounts.len(),
+            "accounts": accounts
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Solana: import a keypair (JSON keypair file or base58 / byte-array secret) into the encrypted keystore under an account name")]
+    async fn solana_keystore_import(
+        &self,
+        Parameters(request): Parameters<SolanaKeystoreImportRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let (secret, from) = match (request.keypair_path.as_deref(), request.secret_key.as_deref()) {
+            (Some(path), None) => {
+                let raw = std::fs::read_to_string(path).map_err(|e| ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from(format!("Failed to read keypair file {}: {}", path, e)),
+                    data: None,
+                })?;
+                (crate::utils::solana_keystore::parse_secret(&raw)?, path.to_string())
+            }
+            (None, Some(secret)) => (
+                crate::utils::solana_keystore::parse_secret(secret)?,
+                "secret_key".to_string(),
+            ),
+            _ => {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("Provide exactly one of keypair_path or secret_key"),
+                    data: None,
+                })
+            }
+        };
+        let keypair = crate::utils::signer_backends::solana_keypair_from_secret(&secret, &from)?;
+        let pubkey = solana_sdk::signature::Signer::pubkey(&keypair).to_string();
+        let password = crate::utils::signer::keystore_password(request.password.as_deref())?;
+        let path = crate::utils::solana_keystore::import(
+            &request.account,
+            &keypair.to_bytes(),
+            &pubkey,
+            &password,
+            request.overwrite.unwrap_or(false),
+        )?;
+
+        self.write_audit_log(
+            "solana_keystore_import",
+            json!({
+                "account": request.account.trim(),
+                "pubkey": pubkey,
+                "path": path,
+                "from": from,
+                "overwrite": request.overwrite.unwrap_or(false)
+            }),
+        );
+
+        let response = Self::pretty_json(&json!({
+            "account": crate::utils::solana_keystore::validate_name(&request.account)?,
+            "pubkey": pubkey,
+            "keystore_path": path
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Solana: export the public key of an encrypted keystore account (base58 and hex); with password, also verifies the key decrypts to it")]
+    async fn solana_keystore_export_public(
+        &self,
+        Parameters(request): Parameters<SolanaKeystoreExportPublicRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let stored = crate::utils::solana_keystore::read_pubkey(&request.account)?;
+        let pubkey = solana_sdk::pubkey::Pubkey::from_str(&stored).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Keystore pubkey is not valid base58: {}", e)),
+            data: Some(json!({"account": request.account, "pubkey": stored})),
+        })?;
+
+        let verified = match request.password.as_deref() {
+            Some(password) => {
+                let secret = crate::utils::solana_keystore::decrypt(&request.account, password)?;
+                let keypair = crate::utils::signer_backends::solana_keypair_from_secret(
+                    &secret,
+                    &request.account,
+                )?;
+                if solana_sdk::signature::Signer::pubkey(&keypair) != pubkey {
+                    return Err(ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Keystore secret does not match its stored pubkey"),
+                        data: Some(json!({"account": request.account, "pubkey": stored})),
+                    });
+                }
+                Some(true)
+            }
+            None => None,
+        };
+
+        let response = Self::pretty_json(&json!({
+            "account": crate::utils::solana_keystore::validate_name(&request.account)?,
+            "pubkey": stored,
+            "pubkey_hex": hex::encode(pubkey.to_bytes()),
+            "keystore_path": crate::utils::solana_keystore::account_path(&request.account)?,
+            "password_verified": verified
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Solana: select the keystore account the keystore signer uses on a network (mainnet|devnet|testnet)")]
+    async fn solana_keystore_select(
+        &self,
+        Parameters(request): Parameters<SolanaKeystoreSelectRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let network =
+            crate::utils::solana_keystore::normalize_network(request.network.as_deref())?;
+        let previous =
+            crate::utils::solana_keystore::set_default(Some(&network), &request.account)?;
+        let account = crate::utils::solana_keystore::validate_name(&request.account)?;
+        let pubkey = crate::utils::solana_keystore::read_pubkey(&account).ok();
+        let backend = crate::utils::signer::backend_for(crate::utils::signer::SignerChain::Solana)?;
+
+        self.write_audit_log(
+            "solana_keystore_select",
+            json!({
+                "network": network,
+                "account": account,
+                "pubkey": pubkey,
+                "previous": previous
+            }),
+        );
+
+        let note = (backend != crate::utils::signer::SignerBackend::Keystore).then(|| {
+            format!(
+                "Solana signer backend is {}; set WEB3MCP_SIGNER_SOLANA_BACKEND=keystore (signer.solana_backend) to sign with this account",
+                backend.as_str()
+            )
+        });
+        let response = Self::pretty_json(&json!({
+            "network": network,
+            "account": account,
+            "pubkey": pubkey,
+            "previous": previous,
+            "signer_backend": backend.as_str(),
+            "note": note
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
     // ---------------- Solana DeFi APIs (off-chain) ----------------
 
     fn solana_meteora_dlmm_api_base_url() -> String {
-        std::env::var("SOLANA_METEORA_DLMM_API_BASE_URL")
+        crate::utils::config::var("SOLANA_METEORA_DLMM_API_BASE_URL")
             .unwrap_or_else(|_| "https://dlmm-api.meteora.ag".to_string())
     }
 
@@ -1013,23 +1265,17 @@
 
             // Build transaction (optionally signed)
             let sign = request.sign.unwrap_or(true);
-            let kp_path = if sign {
-                Some(Self::solana_keypair_path()?)
-            } else {
-                None
-            };
             let kp = if sign {
-                Some(Self::solana_read_keypair_from_json_file(
-                    kp_path.as_ref().unwrap(),
-                )?)
+                Some(Self::solana_signer(network).await?)
             } else {
                 None
             };
+            let kp_path = kp.as_ref().map(|k| k.source());
 
             let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
                 Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
             } else if let Some(ref k) = kp {
-                solana_sdk::signature::Signer::pubkey(k)
+                Self::solana_signer_pubkey(k.as_ref())?
             } else {
                 return Err(ErrorData {
                     code: ErrorCode(-32602),
@@ -1080,8 +1326,8 @@
             let mut tx = solana_sdk::transaction::Transaction::new_unsigned(message);
             tx.message.recent_blockhash = recent_blockhash;
 
-            if let Some(ref k) = kp {
-                tx.sign(&[k], recent_blockhash);
+            if let Some(k) = kp.as_deref() {
+                Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
             }
 
             let tx_bytes = bincode::serialize(&tx)
@@ -1568,10 +1814,22 @@
     ) -> Result<CallToolResult, ErrorData> {
         let addr = Self::solana_parse_pubkey(request.address.trim(), "address")?;
         let client = Self::solana_rpc(request.network.as_deref())?;
-        let lamports = client
-            .get_balance(&addr)
-            .await
-            .map_err(|e| Self::sdk_error("solana_get_balance", e))?;
+        let cache_key = format!(
+            "solana:{}:balance:{}",
+            crate::utils::config::redact_url(&client.url()),
+            addr
+        );
+        let lamports: u64 = crate::utils::response_cache::cached(
+            crate::utils::response_cache::CacheCategory::Balance,
+            &cache_key,
+            || async {
+                client
+                    .get_balance(&addr)
+                    .await
+                    .map_err(|e| Self::sdk_error("solana_get_balance", e))
+            },
+        )
+        .await?;
 
         let response = Self::pretty_json(&json!({
             "rpc_url": Self::solana_rpc_url_for_network(request.network.as_deref())?,
@@ -1720,10 +1978,36 @@
             max_supported_transaction_version: Some(request.max_supported_transaction_version.unwrap_or(0)),
         };
 
-        let tx = client
-            .get_transaction_with_config(&sig, cfg)
-            .await
-            .map_err(|e| Self::sdk_error("solana_get_transaction", e))?;
+        // Finalized transactions never change: cached without expiry. A transaction that is
+        // only confirmed (its slot not finalized yet) is returned uncached.
+        let cache_key = format!(
+            "solana:{}:tx:{}:{}:v{}",
+            crate::utils::config::redact_url(&client.url()),
+            sig,
+            encoding,
+            cfg.max_supported_transaction_version.unwrap_or(0)
+        );
+        let (tx, _finalized) = crate::utils::response_cache::cached_if(
+            crate::utils::response_cache::CacheCategory::FinalizedTx,
+            &cache_key,
+            || async {
+                let tx = client
+                    .get_transaction_with_config(&sig, cfg)
+                    .await
+                    .map_err(|e| Self::sdk_error("solana_get_transaction", e))?;
+                let finalized = client
+                    .get_slot_with_commitment(solana_commitment_config::CommitmentConfig::finalized())
+                    .await
+                    .map(|slot| slot >= tx.slot)
+                    .unwrap_or(false);
+                Ok((tx, finalized))
+            },
+            |(_, finalized): &(
+                solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta,
+                bool,
+            )| *finalized,
+        )
+        .await?;
 
         let response = Self::pretty_json(&json!({
             "rpc_url": Self::solana_rpc_url_for_network(request.network.as_deref())?,
@@ -1957,17 +2241,13 @@
         let create_ata_if_missing = request.create_ata_if_missing.unwrap_or(false);
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -2095,8 +2375,8 @@
         tx.message.recent_blockhash = recent_blockhash;
 
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -2172,7 +2452,7 @@
 
         // If tx isn't signed, attempt to sign if SOLANA_KEYPAIR_PATH exists.
         let mut tx2 = tx;
-        Self::solana_try_sign_if_needed(&mut tx2, kp.as_ref());
+        Self::solana_try_sign_if_needed(&mut tx2, kp.as_deref()).await?;
         if tx2.signatures.is_empty()
             || tx2
                 .signatures
@@ -2398,17 +2678,13 @@
         }
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -2462,8 +2738,8 @@
         tx.message.recent_blockhash = recent_blockhash;
 
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -2532,7 +2808,7 @@
         let timeout_ms = request.timeout_ms.unwrap_or(60_000);
 
         let mut tx2 = tx;
-        Self::solana_try_sign_if_needed(&mut tx2, kp.as_ref());
+        Self::solana_try_sign_if_needed(&mut tx2, kp.as_deref()).await?;
         if tx2.signatures.is_empty()
             || tx2
                 .signatures
@@ -2653,17 +2929,13 @@
         }
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -2721,8 +2993,8 @@
         tx.message.recent_blockhash = recent_blockhash;
 
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -2791,7 +3063,7 @@
         let timeout_ms = request.timeout_ms.unwrap_or(60_000);
 
         let mut tx2 = tx;
-        Self::solana_try_sign_if_needed(&mut tx2, kp.as_ref());
+        Self::solana_try_sign_if_needed(&mut tx2, kp.as_deref()).await?;
         if tx2.signatures.is_empty()
             || tx2
                 .signatures
@@ -2923,17 +3195,13 @@
         }
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -2992,8 +3260,8 @@
         tx.message.recent_blockhash = recent_blockhash;
 
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -3063,7 +3331,7 @@
         let timeout_ms = request.timeout_ms.unwrap_or(60_000);
 
         let mut tx2 = tx;
-        Self::solana_try_sign_if_needed(&mut tx2, kp.as_ref());
+        Self::solana_try_sign_if_needed(&mut tx2, kp.as_deref()).await?;
         if tx2.signatures.is_empty()
             || tx2
                 .signatures
@@ -3332,17 +3600,13 @@
         }
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -3402,8 +3666,8 @@
         tx.message.recent_blockhash = recent_blockhash;
 
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -3475,7 +3739,7 @@
         let timeout_ms = request.timeout_ms.unwrap_or(60_000);
 
         let mut tx2 = tx;
-        Self::solana_try_sign_if_needed(&mut tx2, kp.as_ref());
+        Self::solana_try_sign_if_needed(&mut tx2, kp.as_deref()).await?;
         if tx2.signatures.is_empty()
             || tx2
                 .signatures
@@ -3600,10 +3864,10 @@
         }
     }
 
-    fn solana_try_sign_if_needed(
+    async fn solana_try_sign_if_needed(
         tx: &mut solana_sdk::transaction::Transaction,
-        kp: Option<&solana_sdk::signature::Keypair>,
-    ) {
+        kp: Option<&dyn crate::utils::signer::Signer>,
+    ) -> Result<(), ErrorData> {
         if let Some(k) = kp {
             // If signatures are missing or default, attempt to sign.
             let all_default = tx
@@ -3612,9 +3876,10 @@
                 .all(|s| *s == solana_sdk::signature::Signature::default());
             if tx.signatures.is_empty() || all_default {
                 let bh = tx.message.recent_blockhash;
-                tx.sign(&[k], bh);
+                Self::solana_sign_legacy(tx, k, bh).await?;
             }
         }
+        Ok(())
     }
 
     #[cfg(feature = "solana-extended-tools")]
@@ -3702,17 +3967,13 @@
         let client = Self::solana_rpc(network)?;
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -3809,8 +4070,8 @@
         tx.message.recent_blockhash = recent_blockhash;
 
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -3895,9 +4156,7 @@
                     .iter()
                     .all(|s| *s == solana_sdk::signature::Signature::default());
 
-            let kp = Self::solana_keypair_path()
-                .ok()
-                .and_then(|p| Self::solana_read_keypair_from_json_file(&p).ok());
+            let kp = Self::solana_signer(network).await.ok();
 
             if strict_sig_verify && need_sign && kp.is_none() {
                 return Err(ErrorData {
@@ -3910,7 +4169,7 @@
             }
 
             // Best-effort sign if signatures are missing and a keypair is available.
-            Self::solana_try_sign_if_needed(&mut tx, kp.as_ref());
+            Self::solana_try_sign_if_needed(&mut tx, kp.as_deref()).await?;
         }
 
         let commitment = cfg
@@ -4109,9 +4368,7 @@
                     .iter()
                     .all(|s| *s == solana_sdk::signature::Signature::default());
 
-            let kp = Self::solana_keypair_path()
-                .ok()
-                .and_then(|p| Self::solana_read_keypair_from_json_file(&p).ok());
+            let kp = Self::solana_signer(network).await.ok();
 
             if strict_sig_verify && need_sign && kp.is_none() {
                 return Err(ErrorData {
@@ -4123,7 +4380,7 @@
                 });
             }
 
-            Self::solana_try_sign_if_needed(&mut tx, kp.as_ref());
+            Self::solana_try_sign_if_needed(&mut tx, kp.as_deref()).await?;
         }
 
         let commitment = cfg
@@ -4782,9 +5039,7 @@
                         .iter()
                         .all(|s| *s == solana_sdk::signature::Signature::default());
 
-                let kp = Self::solana_keypair_path()
-                    .ok()
-                    .and_then(|p| Self::solana_read_keypair_from_json_file(&p).ok());
+                let kp = Self::solana_signer(network).await.ok();
 
                 if strict_sig_verify && need_sign && kp.is_none() {
                     return Err(ErrorData {
@@ -4797,7 +5052,7 @@
                 }
 
                 // Best-effort sign if signatures are missing and a keypair is available.
-                Self::solana_try_sign_if_needed(legacy_tx, kp.as_ref());
+                Self::solana_try_sign_if_needed(legacy_tx, kp.as_deref()).await?;
             }
         }
 
@@ -6648,12 +6903,8 @@
         })?;
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(network).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             solana_sdk::pubkey::Pubkey::from_str(fp.trim()).map_err(|e| ErrorData {
@@ -6662,7 +6913,7 @@
                 data: Some(json!({"fee_payer": fp})),
             })?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             sender
         };
@@ -6685,12 +6936,8 @@
         let mut tx = solana_sdk::transaction::Transaction::new_unsigned(msg);
         tx.message.recent_blockhash = recent_blockhash;
 
-        if let Some(ref k) = kp {
-            tx.try_sign(&[k], recent_blockhash).map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to sign tx: {}", e)),
-                data: None,
-            })?;
+        if let Some(k) = kp.as_deref() {
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -6884,10 +7131,8 @@
             })?;
 
         // Try to sign if needed and keypair is available.
-        let kp = Self::solana_keypair_path()
-            .ok()
-            .and_then(|p| Self::solana_read_keypair_from_json_file(&p).ok());
-        Self::solana_try_sign_if_needed(&mut tx, kp.as_ref());
+        let kp = Self::solana_signer(network).await.ok();
+        Self::solana_try_sign_if_needed(&mut tx, kp.as_deref()).await?;
 
         
         let skip_preflight = request.skip_preflight.unwrap_or(false);
@@ -7124,11 +7369,11 @@
                     "APPROVAL_BLOCKED",
                     "Confirmation is blocked by approval policy",
                     false,
-                    Some("This pending transaction was marked blocked. Provide admin_pubkey that is in policies/solana_confirm_policy.json admin_override.blocked_confirm_admin_pubkeys."),
+                    Some("This pending transaction was marked blocked. Provide admin_pubkey that is in the Solana confirm policy (policy_path) admin_override.blocked_confirm_admin_pubkeys."),
                     None,
                     Some(json!({
                         "approval_status": approval_status,
-                        "policy_path": "policies/solana_confirm_policy.json"
+                        "policy_path": crate::utils::solana_policy::policy_path().to_string_lossy()
                     })),
                 );
             }
@@ -7497,7 +7742,7 @@
                                 "Transaction touches a denied program id",
                                 json!({
                                     "denied_programs": denied,
-                                    "policy_path": "policies/solana_confirm_policy.json"
+                                    "policy_path": crate::utils::solana_policy::policy_path().to_string_lossy()
                                 }),
                             )?;
                         }
@@ -7518,7 +7763,7 @@
                                     "Transaction touches a program id not in allowlist",
                                     json!({
                                         "not_allowed_programs": not_allowed,
-                                        "policy_path": "policies/solana_confirm_policy.json"
+                                        "policy_path": crate::utils::solana_policy::policy_path().to_string_lossy()
                                     }),
                                 )?;
                             }
@@ -7716,11 +7961,22 @@
             }
         }
 
+        // Rolling-window spend limits: book the spend before broadcast (released on error).
+        let spend = crate::utils::spend_limits::solana_entries(
+            &network,
+            pending.summary.as_ref(),
+            &tx_bytes,
+            crate::utils::spend_limits::now_ms(),
+        );
+        let reservation =
+            match Self::spend_limit_guard("solana_confirm_transaction", &spend, &request.id) {
+                crate::utils::spend_limits::SpendGuard::Reserved(r) => r,
+                crate::utils::spend_limits::SpendGuard::Blocked(blocked) => return blocked,
+            };
+        let spend_json = crate::utils::spend_limits::spend_json(&spend);
+
         // Sign if needed.
-        let kp_path = Self::solana_keypair_path().ok();
-        let kp = kp_path
-            .as_deref()
-            .and_then(|p| Self::solana_read_keypair_from_json_file(p).ok());
+        let kp = Self::solana_signer(Some(&network)).await.ok();
 
         let skip_preflight = request.skip_preflight.unwrap_or(false);
         let send_cfg = solana_client::rpc_config::RpcSendTransactionConfig {
@@ -7735,22 +7991,14 @@
 
         // Broadcast: support VersionedTransaction (v0+LUT) as well as legacy Transaction.
         let sig = if let Some(mut vt) = vtx {
-            if let Some(ref k) = kp {
+            if let Some(k) = kp.as_deref() {
                 // If signatures are missing/default, attempt to sign.
                 let all_default = vt
                     .signatures
                     .iter()
                     .all(|s| *s == solana_sdk::signature::Signature::default());
                 if vt.signatures.is_empty() || all_default {
-                    let recent_blockhash = match &vt.message {
-                        solana_message::VersionedMessage::Legacy(m) => m.recent_blockhash,
-                        solana_message::VersionedMessage::V0(m) => m.recent_blockhash,
-                    };
-                    vt.try_sign(&[k], recent_blockhash).map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to sign versioned tx: {e}")),
-                        data: None,
-                    })?;
+                    Self::solana_sign_versioned(&mut vt, k).await?;
                 }
             }
 
@@ -7779,7 +8027,7 @@
                     data: None,
                 })?;
 
-            Self::solana_try_sign_if_needed(&mut tx, kp.as_ref());
+            Self::solana_try_sign_if_needed(&mut tx, kp.as_deref()).await?;
 
             client
                 .send_transaction_with_config(&tx, send_cfg)
@@ -7800,6 +8048,10 @@
                 })?
         };
 
+        // Broadcast succeeded: the booked spend counts even if the wait below times out and the
+        // row stays pending for a retry.
+        reservation.keep();
+
         let timeout_ms = request.timeout_ms.unwrap_or(60_000);
         let commitment = request.commitment.clone().unwrap_or("confirmed".to_string());
         let waited = Self::solana_wait_for_signature(&client, &sig, &commitment, timeout_ms)
@@ -7818,9 +8070,13 @@
                 }
             })?;
 
-        // If we got ok, remove pending; if timeout, keep it for retry.
+        // If we got ok, mark confirmed (kept for the spend ledger); if timeout, keep it for retry.
         if waited.get("status").and_then(|v| v.as_str()) == Some("ok") {
-            let _ = crate::utils::solana_confirm_store::remove_pending(&request.id);
+            let _ = crate::utils::solana_confirm_store::mark_confirmed(
+                &request.id,
+                &sig.to_string(),
+                spend_json.as_deref(),
+            );
         }
 
         let status = waited
@@ -8141,7 +8397,7 @@
     // ---------------- Solana IDL Registry ----------------
 
     #[cfg(feature = "solana-extended-tools")]
-    #[cfg_attr(feature = "solana-extended-tools", tool(description = "Solana IDL Registry: register an IDL JSON under abi_registry/solana/<program_id>/<name>.json"))]
+    #[cfg_attr(feature = "solana-extended-tools", tool(description = "Solana IDL Registry: register an IDL JSON under <data_dir>/abi_registry/solana/<program_id>/<name>.json"))]
     async fn solana_idl_register(
         &self,
         Parameters(request): Parameters<SolanaIdlRegisterRequest>,
@@ -8977,9 +9233,7 @@
                     .iter()
                     .all(|s| *s == solana_sdk::signature::Signature::default());
 
-            let kp = Self::solana_keypair_path()
-                .ok()
-                .and_then(|p| Self::solana_read_keypair_from_json_file(&p).ok());
+            let kp = Self::solana_signer(Some(&network_str)).await.ok();
 
             if strict_sig_verify && need_sign && kp.is_none() {
                 return Err(ErrorData {
@@ -8991,7 +9245,7 @@
                 });
             }
 
-            Self::solana_try_sign_if_needed(&mut tx, kp.as_ref());
+            Self::solana_try_sign_if_needed(&mut tx, kp.as_deref()).await?;
         }
 
         let commitment = cfg
@@ -9242,17 +9496,13 @@
         let client = Self::solana_rpc(Some(&network_str))?;
 
         let sign = request.sign.unwrap_or(false);
-        let kp_path = if sign { Some(Self::solana_keypair_path()?) } else { None };
-        let kp = if sign {
-            Some(Self::solana_read_keypair_from_json_file(kp_path.as_ref().unwrap())?)
-        } else {
-            None
-        };
+        let kp = if sign { Some(Self::solana_signer(Some(&network_str)).await?) } else { None };
+        let kp_path = kp.as_ref().map(|k| k.source());
 
         let fee_payer = if let Some(fp) = request.fee_payer.as_deref() {
             Self::solana_parse_pubkey(fp.trim(), "fee_payer")?
         } else if let Some(ref k) = kp {
-            solana_sdk::signature::Signer::pubkey(k)
+            Self::solana_signer_pubkey(k.as_ref())?
         } else {
             return Err(ErrorData {
                 code: ErrorCode(-32602),
@@ -9285,8 +9535,8 @@
         let mut tx = solana_sdk::transaction::Transaction::new_unsigned(message);
         tx.message.recent_blockhash = recent_blockhash;
         if sign {
-            let k = kp.as_ref().unwrap();
-            tx.sign(&[k], recent_blockhash);
+            let k = kp.as_deref().unwrap();
+            Self::solana_sign_legacy(&mut tx, k, recent_blockhash).await?;
         }
 
         let tx_bytes = bincode::serialize(&tx).map_err(|e| ErrorData {
@@ -9376,7 +9626,7 @@
 
         // If tx isn't signed, attempt to sign if SOLANA_KEYPAIR_PATH exists.
         let mut tx2 = tx;
-        Self::solana_try_sign_if_needed(&mut tx2, kp.as_ref());
+        Self::solana_try_sign_if_needed(&mut tx2, kp.as_deref()).await?;
 
         
         let sig = client
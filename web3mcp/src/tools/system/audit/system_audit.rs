@@ -0,0 +1,56 @@
+    /// Walk the hash-chained audit log and report tampering.
+    #[tool(description = "Verify the hash-chained audit log: recompute every record hash, check the prev_hash links and sequence numbers across rotated files, and optionally compare the head against an expected_head recorded earlier. Reports the first problems found.")]
+    async fn system_audit_verify(
+        &self,
+        Parameters(request): Parameters<SystemAuditVerifyRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let log = crate::utils::audit_log::AuditLog::open_default();
+        let mut report = log.verify(request.expected_head.as_deref())?;
+        if let Value::Object(ref mut m) = report {
+            m.insert("path".to_string(), json!(log.path().display().to_string()));
+            m.insert(
+                "note".to_string(),
+                json!("Keep head.hash somewhere else (ticket, WORM storage) and pass it as expected_head later: a chain rewritten from scratch only shows up against an external head."),
+            );
+        }
+        let response = Self::pretty_json(&report)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Query the audit log (newest first) by tool (name or glob), chain, address, tx hash / digest and time range")]
+    async fn system_audit_query(
+        &self,
+        Parameters(request): Parameters<SystemAuditQueryRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let opt = |v: Option<String>| v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        let filter = crate::utils::audit_log::AuditFilter {
+            tool: opt(request.tool),
+            chain: opt(request.chain).map(|c| c.to_lowercase()),
+            address: opt(request.address),
+            tx_hash: opt(request.tx_hash),
+            since_ms: request.since_ms,
+            until_ms: request.until_ms,
+            // Same scoping as the run and pending listings: a session sees its own records.
+            session_id: crate::utils::session_scope::current(),
+            limit: request.limit.unwrap_or(50).clamp(1, 1000),
+        };
+        if let Some(c) = filter.chain.as_deref() {
+            if !["sui", "solana", "evm"].contains(&c) {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("chain must be one of: sui|solana|evm"),
+                    data: Some(json!({"chain": c})),
+                });
+            }
+        }
+
+        let log = crate::utils::audit_log::AuditLog::open_default();
+        let records = log.query(&filter)?;
+        let response = Self::pretty_json(&json!({
+            "path": log.path().display().to_string(),
+            "count": records.len(),
+            "limit": filter.limit,
+            "records": records,
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
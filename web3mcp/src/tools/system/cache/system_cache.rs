@@ -0,0 +1,42 @@
+    #[tool(description = "Shared RPC response cache: mode, TTL per category (finalized_tx, move_modules, token_metadata, token_list, balance), live entries, hits/misses and evictions since server start")]
+    async fn system_cache_stats(&self) -> Result<CallToolResult, ErrorData> {
+        let now = crate::utils::pending_store::now_ms();
+        let stats = crate::utils::response_cache::global().stats(now);
+        let response = Self::pretty_json(&stats)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Clear the shared RPC response cache (memory and sqlite), optionally one category and/or a key prefix")]
+    async fn system_cache_clear(
+        &self,
+        Parameters(request): Parameters<SystemCacheClearRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        use crate::utils::response_cache::CacheCategory;
+
+        let category = match request
+            .category
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            Some(raw) => Some(CacheCategory::parse(raw).ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("category must be one of: {}", CacheCategory::names())),
+                data: Some(json!({"category": raw})),
+            })?),
+            None => None,
+        };
+        let key_prefix = request
+            .key_prefix
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty());
+
+        let removed =
+            crate::utils::response_cache::global().clear(category, key_prefix.as_deref());
+        let response = Self::pretty_json(&json!({
+            "removed": removed,
+            "category": category.map(CacheCategory::as_str),
+            "key_prefix": key_prefix,
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
@@ -1,12 +1,15 @@
     /// Auto-generated tool: get chain identifier
     #[tool(description = "Auto-generated tool: get chain identifier")]
-    async fn get_chain_identifier(&self) -> Result<CallToolResult, ErrorData> {
-        let chain_id = self
-            .client
-            .read_api()
-            .get_chain_identifier()
-            .await
-            .map_err(|e| Self::sdk_error("sui_getChainIdentifier", e))?;
+    async fn get_chain_identifier(
+        &self,
+        Parameters(request): Parameters<SuiNetworkRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let chain_id = this
+            .sui_read("sui_getChainIdentifier", |client| async move {
+                client.read_api().get_chain_identifier().await
+            })
+            .await?;
 
         let response = format!("Chain identifier: {}", chain_id);
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -14,13 +17,16 @@
 
     /// Get protocol configuration
     #[tool(description = "Get the protocol configuration for the Sui network")]
-    async fn get_protocol_config(&self) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .read_api()
-            .get_protocol_config(None)
-            .await
-            .map_err(|e| Self::sdk_error("sui_getProtocolConfig", e))?;
+    async fn get_protocol_config(
+        &self,
+        Parameters(request): Parameters<SuiNetworkRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("sui_getProtocolConfig", |client| async move {
+                client.read_api().get_protocol_config(None).await
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -95,29 +101,34 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    #[tool(description = "List supported Sui networks and their default RPC URLs.")]
+    #[tool(description = "List supported Sui networks and their default RPC URLs (Sui tools take `network` per call).")]
     async fn sui_list_networks(&self) -> Result<CallToolResult, ErrorData> {
+        use crate::sui::client_pool::SuiNetwork;
+
+        let mut networks = serde_json::Map::new();
+        for network in [
+            SuiNetwork::Mainnet,
+            SuiNetwork::Testnet,
+            SuiNetwork::Devnet,
+            SuiNetwork::Localnet,
+        ] {
+            networks.insert(
+                network.key(),
+                serde_json::json!({
+                    "network": network.key(),
+                    "rpc_url": network.rpc_url(),
+                    "mainnet": network == SuiNetwork::Mainnet
+                }),
+            );
+        }
         let response = Self::pretty_json(&serde_json::json!({
-            "mainnet": {
-                "network": "mainnet",
-                "rpc_url": "https://fullnode.mainnet.sui.io:443",
-                "mainnet": true
-            },
-            "testnet": {
-                "network": "testnet",
-                "rpc_url": "https://fullnode.testnet.sui.io:443",
-                "mainnet": false
-            },
-            "devnet": {
-                "network": "devnet",
-                "rpc_url": "https://fullnode.devnet.sui.io:443",
-                "mainnet": false
+            "networks": networks,
+            "default": {
+                "network": self.resolve_network_kind(),
+                "rpc_url": crate::utils::config::redact_url(&self.rpc_url)
             },
-            "localnet": {
-                "network": "localnet",
-                "rpc_url": "http://127.0.0.1:9000",
-                "mainnet": false
-            }
+            "connected": self.sui_clients.describe(),
+            "note": "Pass network=mainnet|testnet|devnet|localnet or an http(s) RPC URL to Sui tools; clients are created on first use. A custom URL is classified by its chain identifier (treated as mainnet if that cannot be read)."
         }))?;
 
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -128,15 +139,19 @@
         &self,
         Parameters(request): Parameters<SystemHealthcheckRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        // Every configured RPC endpoint is probed (same probes as `system_rpc_health`); a
+        // chain is ok when at least one of its endpoints answers.
+        let endpoints_ok = |snapshot: &Value| {
+            snapshot["endpoints"]
+                .as_array()
+                .is_some_and(|eps| eps.iter().any(|e| e["available"] == true))
+        };
+
         // ---- Sui ----
-        let sui_rpc_url = self.rpc_url.clone();
-        let sui_ok = self
-            .client
-            .read_api()
-            .get_chain_identifier()
-            .await
-            .map(|_| true)
-            .unwrap_or(false);
+        let sui = self.for_sui_network(request.sui_network.as_deref()).await?;
+        let sui_rpc_url = sui.rpc_url.clone();
+        let sui_endpoints = crate::sui::client_pool::probe_endpoints(&sui.sui_network).await;
+        let sui_ok = endpoints_ok(&sui_endpoints);
 
         // ---- Solana ----
         let solana_network = request
@@ -145,28 +160,14 @@
             .unwrap_or("mainnet")
             .to_string();
         let solana_rpc_url = Self::solana_rpc_url_for_network(Some(&solana_network)).unwrap_or_else(|_| "".to_string());
-        let solana_ok = if solana_rpc_url.is_empty() {
-            false
-        } else {
-            let client = Self::solana_rpc(Some(&solana_network))?;
-            // getHealth returns "ok" when healthy.
-            client.get_health().await.is_ok()
-        };
+        let solana_endpoints = Self::rpc_probe_solana(&solana_network).await;
+        let solana_ok = endpoints_ok(&solana_endpoints);
 
         // ---- EVM ----
         let evm_chain_id = request.evm_chain_id.unwrap_or(Self::evm_default_chain_id()?);
         let evm_rpc_url = Self::evm_rpc_url(evm_chain_id).unwrap_or_else(|_| "".to_string());
-        let evm_ok = if evm_rpc_url.is_empty() {
-            false
-        } else {
-            match self.evm_provider(evm_chain_id).await {
-                Ok(provider) => {
-                    let bn = <ethers::providers::Provider<ethers::providers::Http> as ethers::providers::Middleware>::get_block_number(&provider).await;
-                    bn.is_ok()
-                }
-                Err(_) => false,
-            }
-        };
+        let evm_endpoints = Self::rpc_probe_evm(evm_chain_id).await;
+        let evm_ok = endpoints_ok(&evm_endpoints);
 
         // ---- Stores writability ----
         let solana_store_ok = crate::utils::solana_confirm_store::cleanup_expired().is_ok();
@@ -174,9 +175,24 @@
         let sui_store_ok = crate::utils::sui_confirm_store::connect().is_ok();
 
         let response = Self::pretty_json(&serde_json::json!({
-            "sui": { "rpc_url": sui_rpc_url, "ok": sui_ok },
-            "solana": { "network": solana_network, "rpc_url": solana_rpc_url, "ok": solana_ok },
-            "evm": { "chain_id": evm_chain_id, "rpc_url": evm_rpc_url, "ok": evm_ok },
+            "sui": {
+                "network": sui.resolve_network_kind(),
+                "rpc_url": crate::utils::config::redact_url(&sui_rpc_url),
+                "ok": sui_ok,
+                "endpoints": sui_endpoints["endpoints"]
+            },
+            "solana": {
+                "network": solana_network,
+                "rpc_url": crate::utils::config::redact_url(&solana_rpc_url),
+                "ok": solana_ok,
+                "endpoints": solana_endpoints["endpoints"]
+            },
+            "evm": {
+                "chain_id": evm_chain_id,
+                "rpc_url": crate::utils::config::redact_url(&evm_rpc_url),
+                "ok": evm_ok,
+                "endpoints": evm_endpoints["endpoints"]
+            },
             "stores": {
                 "solana_store_ok": solana_store_ok,
                 "evm_store_ok": evm_store_ok,
@@ -190,6 +206,93 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
+    /// Probe every RPC URL of a Solana network; unknown networks report the error instead.
+    async fn rpc_probe_solana(network: &str) -> Value {
+        match Self::solana_rpc_urls_for_network(Some(network)) {
+            Ok(urls) => {
+                let set = crate::utils::rpc_endpoints::endpoint_set(
+                    &Self::solana_rpc_endpoint_key(Some(network)),
+                    urls,
+                );
+                let timeout = crate::utils::rpc_endpoints::RetryPolicy::from_config().timeout;
+                crate::utils::solana_rpc::probe_endpoints(&set, timeout).await
+            }
+            Err(e) => serde_json::json!({
+                "key": format!("solana:{}", network),
+                "error": e.message
+            }),
+        }
+    }
+
+    /// Probe every RPC URL of an EVM chain; chains without a URL report the error instead.
+    async fn rpc_probe_evm(chain_id: u64) -> Value {
+        match Self::evm_rpc_urls(chain_id) {
+            Ok(urls) => {
+                let set =
+                    crate::utils::rpc_endpoints::endpoint_set(&format!("evm:{}", chain_id), urls);
+                let timeout = crate::utils::rpc_endpoints::RetryPolicy::from_config().timeout;
+                crate::utils::evm_rpc::probe_endpoints(&set, timeout).await
+            }
+            Err(e) => serde_json::json!({
+                "key": format!("evm:{}", chain_id),
+                "error": e.message
+            }),
+        }
+    }
+
+    #[tool(description = "RPC endpoint health per chain: probes every configured URL (failover order, latency, failures, cooldowns). Reads fail over between URLs automatically; set several comma-separated URLs in EVM_RPC_URL_<chain_id> / SOLANA_RPC_URL[_<NET>] / SUI_RPC_URL[_<NET>].")]
+    async fn system_rpc_health(
+        &self,
+        Parameters(request): Parameters<SystemRpcHealthRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let probe = request.probe.unwrap_or(true);
+        let mut probed = Vec::new();
+        if probe {
+            let evm_chain_ids = match request.evm_chain_ids {
+                Some(ids) => ids,
+                None => vec![Self::evm_default_chain_id()?],
+            };
+            for chain_id in evm_chain_ids {
+                probed.push(Self::rpc_probe_evm(chain_id).await);
+            }
+            let solana_networks = request
+                .solana_networks
+                .unwrap_or_else(|| vec!["mainnet".to_string()]);
+            for network in solana_networks {
+                probed.push(Self::rpc_probe_solana(network.trim()).await);
+            }
+            let sui_networks = match request.sui_networks {
+                Some(networks) => networks
+                    .iter()
+                    .map(|n| crate::sui::client_pool::SuiNetwork::parse(n))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(e),
+                        data: None,
+                    })?,
+                None => vec![self.sui_network.clone()],
+            };
+            for network in sui_networks {
+                probed.push(crate::sui::client_pool::probe_endpoints(&network).await);
+            }
+        }
+
+        let policy = crate::utils::rpc_endpoints::RetryPolicy::from_config();
+        let response = Self::pretty_json(&serde_json::json!({
+            "probed": probe,
+            "chains": if probe { probed } else { crate::utils::rpc_endpoints::snapshot() },
+            "policy": {
+                "max_attempts": policy.max_attempts,
+                "timeout_ms": policy.timeout.as_millis() as u64,
+                "probe_interval_secs": crate::utils::rpc_endpoints::probe_interval().as_secs()
+            },
+            "note": "Endpoints are tried best-first; timeouts, 429s and connection errors put an endpoint in a growing cooldown. Reads retry on the next endpoint with backoff; transaction sends are never retried. Sui clients switch endpoint when the current one fails a probe."
+        }))?;
+
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
     #[tool(description = "Show a safe, mainnet-oriented demo flow (2-phase + confirm_token). Does not broadcast.")]
     async fn system_demo_safe_mainnet_flow(
         &self,
@@ -242,18 +345,7 @@
 
         // ---- Network context ----
         let sui_rpc_url = self.rpc_url.clone();
-        let sui_rpc_lc = sui_rpc_url.to_lowercase();
-        let sui_network = if sui_rpc_lc.contains("testnet") {
-            "testnet"
-        } else if sui_rpc_lc.contains("devnet") {
-            "devnet"
-        } else if sui_rpc_lc.contains("mainnet") {
-            "mainnet"
-        } else if sui_rpc_lc.contains("127.0.0.1") || sui_rpc_lc.contains("localhost") {
-            "local"
-        } else {
-            "unknown"
-        };
+        let sui_network = self.resolve_network_kind();
 
         // Solana defaults
         let solana_networks = serde_json::json!({
@@ -263,7 +355,7 @@
         });
 
         // ---- Pending stores ----
-        // Solana (json file)
+        // Solana (pending rows of the shared store)
         let solana_pending = crate::utils::solana_confirm_store::list_pending().unwrap_or_default();
         let solana_pending_count = solana_pending.len();
         let solana_pending_sample = if include_pending_samples {
@@ -282,105 +374,48 @@
             vec![]
         };
 
-        // EVM (sqlite)
-        let evm_db_path = crate::utils::evm_confirm_store::pending_db_path_from_cwd()
+        // EVM + Sui (unified sqlite pending store)
+        let pending_db_path = crate::utils::pending_store::db_path()
             .ok()
             .map(|p| p.to_string_lossy().to_string());
-        let (evm_pending_count, evm_pending_sample) = (|| -> Result<(i64, Vec<serde_json::Value>), ErrorData> {
-            let conn = crate::utils::evm_confirm_store::connect()?;
-            let count: i64 = conn
-                .query_row("SELECT COUNT(*) FROM evm_pending_confirmations", [], |row| row.get(0))
-                .unwrap_or(0);
-
-            let mut sample: Vec<serde_json::Value> = Vec::new();
-            if include_pending_samples {
-                let mut stmt = conn
-                    .prepare(
-                        "SELECT id, chain_id, status, updated_at_ms, tx_hash FROM evm_pending_confirmations ORDER BY updated_at_ms DESC LIMIT 5",
-                    )
-                    .map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to prepare EVM pending sample query: {}", e)),
-                        data: None,
-                    })?;
-
-                let rows = stmt
-                    .query_map([], |row| {
-                        Ok((
-                            row.get::<_, String>(0)?,
-                            row.get::<_, i64>(1)?,
-                            row.get::<_, Option<String>>(2)?,
-                            row.get::<_, Option<i64>>(3)?,
-                            row.get::<_, Option<String>>(4)?,
-                        ))
-                    })
-                    .map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to query EVM pending sample: {}", e)),
-                        data: None,
-                    })?;
-                for r in rows.flatten() {
-                    sample.push(serde_json::json!({
-                        "id": r.0,
-                        "chain_id": r.1,
-                        "status": r.2,
-                        "updated_at_ms": r.3,
-                        "tx_hash": r.4,
-                    }));
-                }
-            }
-
-            Ok((count, sample))
-        })().unwrap_or((0, vec![]));
-
-        // Sui (sqlite). DB lives at cwd/.data/pending.sqlite
-        let sui_db_path = std::env::current_dir()
-            .ok()
-            .map(|cwd| cwd.join(".data").join("pending.sqlite").to_string_lossy().to_string());
-        let (sui_pending_count, sui_pending_sample) = (|| -> Result<(i64, Vec<serde_json::Value>), ErrorData> {
-            let conn = crate::utils::sui_confirm_store::connect()?;
-            let count: i64 = conn
-                .query_row("SELECT COUNT(*) FROM sui_pending_confirmations", [], |row| row.get(0))
-                .unwrap_or(0);
-
-            let mut sample: Vec<serde_json::Value> = Vec::new();
-            if include_pending_samples {
-                let mut stmt = conn
-                    .prepare(
-                        "SELECT id, status, updated_at_ms, digest FROM sui_pending_confirmations ORDER BY updated_at_ms DESC LIMIT 5",
-                    )
-                    .map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to prepare Sui pending sample query: {}", e)),
-                        data: None,
-                    })?;
-
-                let rows = stmt
-                    .query_map([], |row| {
-                        Ok((
-                            row.get::<_, String>(0)?,
-                            row.get::<_, Option<String>>(1)?,
-                            row.get::<_, Option<i64>>(2)?,
-                            row.get::<_, Option<String>>(3)?,
-                        ))
-                    })
-                    .map_err(|e| ErrorData {
-                        code: ErrorCode(-32603),
-                        message: Cow::from(format!("Failed to query Sui pending sample: {}", e)),
-                        data: None,
-                    })?;
-                for r in rows.flatten() {
-                    sample.push(serde_json::json!({
-                        "id": r.0,
-                        "status": r.1,
-                        "updated_at_ms": r.2,
-                        "digest": r.3,
-                    }));
+        let pending_summary = |chain: crate::utils::pending_store::PendingChain| {
+            (|| -> Result<(i64, serde_json::Value, Vec<serde_json::Value>), ErrorData> {
+                let conn = crate::utils::pending_store::connect()?;
+                let mut pending_count = 0;
+                let mut by_status = serde_json::Map::new();
+                for (c, status, n) in crate::utils::pending_store::counts(&conn)? {
+                    if c != chain.as_str() {
+                        continue;
+                    }
+                    if status == "pending" {
+                        pending_count = n;
+                    }
+                    by_status.insert(status, serde_json::json!(n));
                 }
-            }
 
-            Ok((count, sample))
-        })().unwrap_or((0, vec![]));
+                let sample = if include_pending_samples {
+                    let filter = crate::utils::pending_store::ListFilter {
+                        chain: Some(chain),
+                        limit: 5,
+                        ..Default::default()
+                    };
+                    let now = crate::utils::pending_store::now_ms();
+                    crate::utils::pending_store::list(&conn, &filter)?
+                        .iter()
+                        .map(|r| r.to_json(now, false))
+                        .collect()
+                } else {
+                    vec![]
+                };
+
+                Ok((pending_count, serde_json::Value::Object(by_status), sample))
+            })()
+            .unwrap_or((0, serde_json::json!({}), vec![]))
+        };
+        let (evm_pending_count, evm_by_status, evm_pending_sample) =
+            pending_summary(crate::utils::pending_store::PendingChain::Evm);
+        let (sui_pending_count, sui_by_status, sui_pending_sample) =
+            pending_summary(crate::utils::pending_store::PendingChain::Sui);
 
         // EVM RPC defaults (optional)
         let evm_rpc_defaults = if include_evm_rpc_defaults {
@@ -403,9 +438,16 @@
         let bundle = serde_json::json!({
             "generated_at_ms": crate::utils::solana_confirm_store::now_ms(),
             "network": {
-                "sui": { "rpc_url": sui_rpc_url, "network": sui_network, "mainnet": sui_network == "mainnet" },
+                "sui": {
+                    "rpc_url": sui_rpc_url,
+                    "network": sui_network,
+                    "mainnet": self.sui_is_mainnet(),
+                    "connected": self.sui_clients.describe()
+                },
                 "solana": { "supported": solana_networks },
+                "rpc_endpoints": crate::utils::rpc_endpoints::snapshot(),
             },
+            "data_dir": crate::utils::data_dir::describe(),
             "stores": {
                 "solana": {
                     "store_path": crate::utils::solana_confirm_store::store_path().to_string_lossy(),
@@ -413,13 +455,15 @@
                     "pending_sample": solana_pending_sample,
                 },
                 "evm": {
-                    "db_path": evm_db_path,
+                    "db_path": pending_db_path,
                     "pending_count": evm_pending_count,
+                    "by_status": evm_by_status,
                     "pending_sample": evm_pending_sample,
                 },
                 "sui": {
-                    "db_path": sui_db_path,
+                    "db_path": pending_db_path,
                     "pending_count": sui_pending_count,
+                    "by_status": sui_by_status,
                     "pending_sample": sui_pending_sample,
                 }
             },
@@ -444,18 +488,7 @@
     #[tool(description = "Get server network context (Sui rpc_url and inferred network; plus Solana network notes).")]
     async fn system_network_context(&self) -> Result<CallToolResult, ErrorData> {
         let sui_rpc_url = self.rpc_url.clone();
-        let sui_rpc_lc = sui_rpc_url.to_lowercase();
-        let sui_network = if sui_rpc_lc.contains("testnet") {
-            "testnet"
-        } else if sui_rpc_lc.contains("devnet") {
-            "devnet"
-        } else if sui_rpc_lc.contains("mainnet") {
-            "mainnet"
-        } else if sui_rpc_lc.contains("127.0.0.1") || sui_rpc_lc.contains("localhost") {
-            "local"
-        } else {
-            "unknown"
-        };
+        let sui_network = self.resolve_network_kind();
 
         // Solana tools accept a `network` param on each call; default is mainnet.
         let solana_default_network = "mainnet";
@@ -464,7 +497,9 @@
             "sui": {
                 "rpc_url": sui_rpc_url,
                 "network": sui_network,
-                "mainnet": sui_network == "mainnet"
+                "mainnet": self.sui_is_mainnet(),
+                "connected": self.sui_clients.describe(),
+                "note": "Sui tools accept network=mainnet|testnet|devnet|localnet or an RPC URL per call; default is the network above. Mainnet broadcasts require confirm_token."
             },
             "solana": {
                 "default_network": solana_default_network,
@@ -4,16 +4,22 @@
         &self,
         Parameters(request): Parameters<GetCoinsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let address = Self::parse_address(&request.address)?;
         let coin_type = request.coin_type.unwrap_or_else(|| "0x2::sui::SUI".to_string());
         let limit = Self::clamp_limit(request.limit, 50, 50);
 
-        let result = self
-            .client
-            .coin_read_api()
-            .get_coins(address, Some(coin_type), None, Some(limit))
-            .await
-            .map_err(|e| Self::sdk_error("suix_getCoins", e))?;
+        let result = this
+            .sui_read("suix_getCoins", |client| {
+                let coin_type = coin_type.clone();
+                async move {
+                    client
+                        .coin_read_api()
+                        .get_coins(address, Some(coin_type), None, Some(limit))
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -0,0 +1,14 @@
+    /// Effective server configuration (`web3mcp.toml` + env overrides).
+    #[tool(description = "Show the effective server config (web3mcp.toml + env overrides) with secrets redacted, the source of each value, validation problems and the active tool profile.")]
+    async fn system_config_show(&self) -> Result<CallToolResult, ErrorData> {
+        let mut out = crate::utils::config::effective_json(crate::utils::config::loaded());
+        if let Value::Object(ref mut m) = out {
+            m.insert("data_dir".to_string(), crate::utils::data_dir::describe());
+            m.insert(
+                "tool_profile".to_string(),
+                crate::utils::tool_profiles::active_json(),
+            );
+        }
+        let response = Self::pretty_json(&out)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
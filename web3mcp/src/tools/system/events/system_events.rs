@@ -4,15 +4,21 @@
         &self,
         Parameters(request): Parameters<QueryEventsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let digest = Self::parse_digest(&request.digest)?;
         let filter = EventFilter::Transaction(digest);
 
-        let result = self
-            .client
-            .event_api()
-            .query_events(filter, None, None, false)
-            .await
-            .map_err(|e| Self::sdk_error("suix_queryEvents", e))?;
+        let result = this
+            .sui_read("suix_queryEvents", |client| {
+                let filter = filter.clone();
+                async move {
+                    client
+                        .event_api()
+                        .query_events(filter, None, None, false)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
 
@@ -1,12 +1,15 @@
     /// Auto-generated tool: get reference gas price
     #[tool(description = "Auto-generated tool: get reference gas price")]
-    async fn get_reference_gas_price(&self) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .read_api()
-            .get_reference_gas_price()
-            .await
-            .map_err(|e| Self::sdk_error("suix_getReferenceGasPrice", e))?;
+    async fn get_reference_gas_price(
+        &self,
+        Parameters(request): Parameters<SuiNetworkRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("suix_getReferenceGasPrice", |client| async move {
+                client.read_api().get_reference_gas_price().await
+            })
+            .await?;
 
         let response = format!("Reference gas price: {}", result);
         Ok(CallToolResult::success(vec![Content::text(response)]))
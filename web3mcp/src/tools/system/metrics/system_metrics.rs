@@ -0,0 +1,30 @@
+    /// Tool-call metrics for deployments without the HTTP `/metrics` endpoint (stdio).
+    #[tool(description = "Tool-call metrics since server start: calls, errors by error_class, calls per network and latency (avg/max, p50/p95 bucket bounds) per tool. format=prometheus returns the same text as the HTTP /metrics endpoint.")]
+    async fn system_metrics(
+        &self,
+        Parameters(request): Parameters<SystemMetricsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let tool = request
+            .tool
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        let format = request
+            .format
+            .map(|f| f.trim().to_lowercase())
+            .unwrap_or_else(|| "json".to_string());
+        match format.as_str() {
+            "json" => {
+                let snapshot = crate::utils::metrics::snapshot_json(tool.as_deref());
+                let response = Self::pretty_json(&snapshot)?;
+                Ok(CallToolResult::success(vec![Content::text(response)]))
+            }
+            "prometheus" => Ok(CallToolResult::success(vec![Content::text(
+                crate::utils::metrics::render_prometheus(),
+            )])),
+            other => Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("format must be one of: json|prometheus"),
+                data: Some(json!({"format": other})),
+            }),
+        }
+    }
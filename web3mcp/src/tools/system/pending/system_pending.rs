@@ -0,0 +1,165 @@
+    fn pending_parse_filter(
+        chain: Option<&str>,
+        network: Option<&str>,
+        status: Option<&str>,
+    ) -> Result<crate::utils::pending_store::ListFilter, ErrorData> {
+        use crate::utils::pending_store::{PendingChain, PendingStatus};
+
+        let chain = match chain.map(str::trim).filter(|s| !s.is_empty()) {
+            Some(c) => Some(PendingChain::parse(c).ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("chain must be one of: solana|sui|evm"),
+                data: Some(json!({"chain": c})),
+            })?),
+            None => None,
+        };
+        let status = match status.map(str::trim).filter(|s| !s.is_empty()) {
+            Some(st) => Some(PendingStatus::parse(st).ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("status must be one of: {}", PendingStatus::names())),
+                data: Some(json!({"status": st})),
+            })?),
+            None => None,
+        };
+        let network = network
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+
+        Ok(crate::utils::pending_store::ListFilter {
+            chain,
+            network,
+            status,
+            limit: 0,
+        })
+    }
+
+    /// The chain-specific tool that moves a row forward, if it can still be broadcast.
+    fn pending_next_step(r: &crate::utils::pending_store::PendingRecord) -> Option<String> {
+        use crate::utils::pending_store::{PendingChain, PendingStatus};
+
+        match (r.chain, r.status) {
+            (PendingChain::Solana, PendingStatus::Pending) => Some(format!(
+                "Call solana_confirm_transaction id:{} hash:{}",
+                r.id, r.tx_summary_hash
+            )),
+            (PendingChain::Sui, PendingStatus::Pending) => Some(format!(
+                "Call sui_confirm_execution id:{} tx_summary_hash:{}",
+                r.id, r.tx_summary_hash
+            )),
+            (PendingChain::Sui, PendingStatus::Failed | PendingStatus::Signed) => Some(format!(
+                "Call sui_retry_pending_confirmation id:{} tx_summary_hash:{}",
+                r.id, r.tx_summary_hash
+            )),
+            (
+                PendingChain::Evm,
+                PendingStatus::Pending | PendingStatus::Failed | PendingStatus::Signed,
+            ) => Some(format!(
+                "Call evm_retry_pending_confirmation id:{} tx_summary_hash:{}",
+                r.id, r.tx_summary_hash
+            )),
+            _ => None,
+        }
+    }
+
+    fn pending_item_json(
+        r: &crate::utils::pending_store::PendingRecord,
+        now_ms: u64,
+        include_payload: bool,
+    ) -> Value {
+        let mut item = r.to_json(now_ms, include_payload);
+        if let Value::Object(ref mut m) = item {
+            m.insert("next".to_string(), json!(Self::pending_next_step(r)));
+        }
+        item
+    }
+
+    #[tool(description = "List pending confirmations across Solana/Sui/EVM from the shared store (filter by chain/network/status)")]
+    async fn system_list_pending_confirmations(
+        &self,
+        Parameters(request): Parameters<SystemListPendingConfirmationsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let mut filter = Self::pending_parse_filter(
+            request.chain.as_deref(),
+            request.network.as_deref(),
+            request.status.as_deref(),
+        )?;
+        filter.limit = request.limit.unwrap_or(20).min(200);
+        let include_payload = request.include_payload.unwrap_or(false);
+
+        let conn = crate::utils::pending_store::connect()?;
+        let now = crate::utils::pending_store::now_ms();
+        crate::utils::pending_store::sweep(&conn, now)?;
+
+        let items: Vec<Value> = crate::utils::pending_store::list(&conn, &filter)?
+            .iter()
+            .map(|r| Self::pending_item_json(r, now, include_payload))
+            .collect();
+        let counts: Vec<Value> = crate::utils::pending_store::counts(&conn)?
+            .into_iter()
+            .map(|(chain, status, count)| json!({"chain": chain, "status": status, "count": count}))
+            .collect();
+
+        let response = Self::pretty_json(&json!({
+            "db_path": crate::utils::pending_store::db_path()?.to_string_lossy(),
+            "count": items.len(),
+            "items": items,
+            "counts": counts,
+            "note": "Expired rows are kept for 24h; rows that recorded a spend are kept for the spend-limit window."
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Get one pending confirmation (any chain) by id from the shared store")]
+    async fn system_get_pending_confirmation(
+        &self,
+        Parameters(request): Parameters<SystemGetPendingConfirmationRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let id = request.id.trim();
+        let conn = crate::utils::pending_store::connect()?;
+        let now = crate::utils::pending_store::now_ms();
+        crate::utils::pending_store::sweep(&conn, now)?;
+
+        let row = crate::utils::pending_store::get(&conn, id)?;
+        let response = Self::pretty_json(&json!({
+            "db_path": crate::utils::pending_store::db_path()?.to_string_lossy(),
+            "found": row.is_some(),
+            "item": row.map(|r| Self::pending_item_json(&r, now, request.include_payload.unwrap_or(true))),
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    #[tool(description = "Cleanup the shared pending store: expire stale rows, drop old history, optionally delete matching rows older than older_than_ms")]
+    async fn system_cleanup_pending_confirmations(
+        &self,
+        Parameters(request): Parameters<SystemCleanupPendingConfirmationsRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let filter = Self::pending_parse_filter(
+            request.chain.as_deref(),
+            request.network.as_deref(),
+            request.status.as_deref(),
+        )?;
+
+        let conn = crate::utils::pending_store::connect()?;
+        let now = crate::utils::pending_store::now_ms();
+        let swept = crate::utils::pending_store::sweep(&conn, now)?;
+
+        let purged = match request.older_than_ms {
+            Some(age) => crate::utils::pending_store::purge(&conn, &filter, now.saturating_sub(age), now)?,
+            None => 0,
+        };
+
+        let remaining: i64 = crate::utils::pending_store::counts(&conn)?
+            .into_iter()
+            .map(|(_, _, n)| n)
+            .sum();
+
+        let response = Self::pretty_json(&json!({
+            "db_path": crate::utils::pending_store::db_path()?.to_string_lossy(),
+            "expired": swept.expired,
+            "deleted_history": swept.deleted,
+            "deleted_matching": purged,
+            "remaining": remaining
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
@@ -1,12 +1,21 @@
     /// Auto-generated tool: get latest checkpoint sequence
     #[tool(description = "Auto-generated tool: get latest checkpoint sequence")]
-    async fn get_latest_checkpoint_sequence(&self) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .read_api()
-            .get_latest_checkpoint_sequence_number()
-            .await
-            .map_err(|e| Self::sdk_error("sui_getLatestCheckpointSequenceNumber", e))?;
+    async fn get_latest_checkpoint_sequence(
+        &self,
+        Parameters(request): Parameters<SuiNetworkRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read(
+                "sui_getLatestCheckpointSequenceNumber",
+                |client| async move {
+                    client
+                        .read_api()
+                        .get_latest_checkpoint_sequence_number()
+                        .await
+                },
+            )
+            .await?;
 
         let response = format!("Latest checkpoint sequence: {}", result);
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -14,13 +23,16 @@
 
     /// Get total transaction count
     #[tool(description = "Get the total number of transactions on the Sui network")]
-    async fn get_total_transactions(&self) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .read_api()
-            .get_total_transaction_blocks()
-            .await
-            .map_err(|e| Self::sdk_error("sui_getTotalTransactionBlocks", e))?;
+    async fn get_total_transactions(
+        &self,
+        Parameters(request): Parameters<SuiNetworkRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("sui_getTotalTransactionBlocks", |client| async move {
+                client.read_api().get_total_transaction_blocks().await
+            })
+            .await?;
 
         let response = format!("Total transactions: {}", result);
         Ok(CallToolResult::success(vec![Content::text(response)]))
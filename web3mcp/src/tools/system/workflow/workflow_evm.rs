@@ -0,0 +1,686 @@
+    // ---------------- W3RT workflow: EVM stages ----------------
+    //
+    // Stitched into the plain helper impl by build.rs.
+    // Simulation uses the EVM toolbox (provider, 0x, preflight); approval/execute only touch the
+    // sqlite confirm store.
+
+    /// Resolve the EVM chain id of a workflow intent (explicit chain_id first, then network name).
+    fn w3rt_evm_chain_id(intent: &Value) -> Option<u64> {
+        let explicit = intent
+            .get("chain_id")
+            .or_else(|| intent.get("resolved_network").and_then(|v| v.get("chain_id")));
+        if let Some(v) = explicit {
+            if let Some(n) = v.as_u64() {
+                return Some(n);
+            }
+            if let Some(n) = v.as_str().and_then(|s| s.trim().parse::<u64>().ok()) {
+                return Some(n);
+            }
+        }
+
+        let name = intent.get("network").and_then(Value::as_str).or_else(|| {
+            intent
+                .get("resolved_network")
+                .and_then(|v| v.get("network_name"))
+                .and_then(Value::as_str)
+        })?;
+        Self::w3rt_evm_chain_id_for_name(name)
+    }
+
+    /// Map a registry chain name (e.g. "base", "arbitrum-one") to its chain id.
+    fn w3rt_evm_chain_id_for_name(name: &str) -> Option<u64> {
+        let lower = name.trim().to_lowercase();
+        crate::utils::evm_chain_registry::evm_default_chains()
+            .into_iter()
+            .find(|c| c.name == lower)
+            .map(|c| c.chain_id)
+    }
+
+    fn w3rt_evm_network_name(chain_id: u64) -> String {
+        crate::utils::evm_chain_registry::evm_default_chains()
+            .into_iter()
+            .find(|c| c.chain_id == chain_id)
+            .map(|c| c.name.to_string())
+            .unwrap_or_else(|| format!("evm-{}", chain_id))
+    }
+
+    /// Read a string-ish intent field (accepts JSON numbers too, e.g. amount: 0.5).
+    fn w3rt_intent_str(intent: &Value, key: &str) -> Option<String> {
+        match intent.get(key)? {
+            Value::String(s) if !s.trim().is_empty() && !s.starts_with('<') => {
+                Some(s.trim().to_string())
+            }
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    fn w3rt_intent_sender(intent: &Value) -> Option<String> {
+        ["from", "user_pubkey", "sender"]
+            .iter()
+            .find_map(|k| Self::w3rt_intent_str(intent, k))
+    }
+
+    async fn w3rt_evm_simulate(&self, intent: &Value) -> Result<Value, ErrorData> {
+        let action = intent.get("action").and_then(Value::as_str).unwrap_or("");
+        let chain_id = match Self::w3rt_evm_chain_id(intent) {
+            Some(id) => id,
+            None => Self::evm_default_chain_id()?,
+        };
+        let sender = Self::w3rt_intent_sender(intent).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("from (sender) is required for EVM intents"),
+            data: Some(json!({"action": action})),
+        })?;
+        Self::parse_evm_address(&sender)?;
+
+        match action {
+            "transfer_native" => self.w3rt_evm_simulate_transfer_native(intent, chain_id, &sender).await,
+            "transfer_erc20" => self.w3rt_evm_simulate_transfer_erc20(intent, chain_id, &sender).await,
+            "swap_exact_in" => self.w3rt_evm_simulate_swap_0x(intent, chain_id, &sender).await,
+            _ => Ok(json!({
+                "stage": "simulate",
+                "status": "todo",
+                "simulation_performed": false,
+                "intent": intent,
+                "note": format!("EVM action '{}' is not supported by the workflow yet (supported: transfer_native, transfer_erc20, swap_exact_in).", action)
+            })),
+        }
+    }
+
+    /// Preflight via evm_preflight; a failed estimate is a simulation failure, not a tool error.
+    async fn w3rt_evm_preflight(&self, tx: EvmTxRequest) -> Result<EvmTxRequest, String> {
+        let res = self
+            .evm_preflight(Parameters(EvmPreflightRequest { tx }))
+            .await
+            .map_err(|e| e.message.to_string())?;
+        let parsed = Self::evm_extract_first_json(&res)
+            .ok_or_else(|| "failed to parse evm_preflight response".to_string())?;
+        serde_json::from_value::<EvmTxRequest>(parsed.get("tx").cloned().unwrap_or(Value::Null))
+            .map_err(|e| format!("failed to decode preflight tx: {}", e))
+    }
+
+    fn w3rt_evm_simulate_artifact(
+        adapter: &str,
+        chain_id: u64,
+        tx: &EvmTxRequest,
+        preflight: &Result<EvmTxRequest, String>,
+        details: Value,
+    ) -> Value {
+        let ok = preflight.is_ok();
+        let tx_out = match preflight {
+            Ok(filled) => json!(filled),
+            Err(_) => json!(tx),
+        };
+        let mut out = json!({
+            "stage": "simulate",
+            "status": if ok { "ok" } else { "failed" },
+            "simulation_performed": true,
+            "adapter": adapter,
+            "network": Self::w3rt_evm_network_name(chain_id),
+            "chain_id": chain_id,
+            "tx": tx_out,
+            "simulation": {
+                "method": "evm_preflight",
+                "ok": ok,
+                "error": preflight.as_ref().err(),
+                "gas_limit": preflight.as_ref().ok().and_then(|t| t.gas_limit)
+            }
+        });
+        if let (Some(map), Some(extra)) = (out.as_object_mut(), details.as_object()) {
+            for (k, v) in extra {
+                map.insert(k.clone(), v.clone());
+            }
+        }
+        out
+    }
+
+    async fn w3rt_evm_simulate_transfer_native(
+        &self,
+        intent: &Value,
+        chain_id: u64,
+        sender: &str,
+    ) -> Result<Value, ErrorData> {
+        let to = Self::w3rt_intent_str(intent, "to").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("to (recipient) is required for transfer"),
+            data: None,
+        })?;
+        Self::parse_evm_address(&to)?;
+
+        let amount = Self::w3rt_intent_str(intent, "amount");
+        let value = if let Some(w) = Self::w3rt_intent_str(intent, "amount_wei") {
+            Self::parse_evm_u256("amount_wei", &w)?
+        } else {
+            let a = amount.clone().ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("amount (or amount_wei) is required for transfer"),
+                data: None,
+            })?;
+            Self::parse_decimal_to_u256(&a, 18)?
+        };
+
+        let tx = EvmTxRequest {
+            chain_id,
+            from: sender.to_string(),
+            to: to.clone(),
+            value_wei: value.to_string(),
+            data_hex: None,
+            nonce: None,
+            gas_limit: None,
+            max_fee_per_gas_wei: None,
+            max_priority_fee_per_gas_wei: None,
+        };
+        let preflight = self.w3rt_evm_preflight(tx.clone()).await;
+
+        Ok(Self::w3rt_evm_simulate_artifact(
+            "evm_native_transfer",
+            chain_id,
+            &tx,
+            &preflight,
+            json!({
+                "from": sender,
+                "to": to,
+                "amount_ui": amount,
+                "value_wei": value.to_string()
+            }),
+        ))
+    }
+
+    async fn w3rt_evm_simulate_transfer_erc20(
+        &self,
+        intent: &Value,
+        chain_id: u64,
+        sender: &str,
+    ) -> Result<Value, ErrorData> {
+        let to = Self::w3rt_intent_str(intent, "to").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("to (recipient) is required for transfer"),
+            data: None,
+        })?;
+        let to_addr = Self::parse_evm_address(&to)?;
+
+        let token = ["token", "asset", "input_token"]
+            .iter()
+            .find_map(|k| Self::w3rt_intent_str(intent, k))
+            .ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("token (symbol or 0x address) is required for transfer_erc20"),
+                data: None,
+            })?;
+        let token_address = if token.starts_with("0x") && token.len() == 42 {
+            token.clone()
+        } else {
+            Self::resolve_evm_erc20_address(&token, chain_id).ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!(
+                    "Unknown ERC20 symbol '{}' on chain_id {}; pass the token contract address instead",
+                    token, chain_id
+                )),
+                data: None,
+            })?
+        };
+        let token_addr = Self::parse_evm_address(&token_address)?;
+
+        let decimals = match intent.get("decimals").and_then(Value::as_u64) {
+            Some(d) => d as u8,
+            None => self.evm_read_erc20_decimals(chain_id, token_addr).await?,
+        };
+
+        let amount = Self::w3rt_intent_str(intent, "amount");
+        let amount_raw = if let Some(r) = Self::w3rt_intent_str(intent, "amount_raw") {
+            Self::parse_evm_u256("amount_raw", &r)?
+        } else {
+            let a = amount.clone().ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("amount (or amount_raw) is required for transfer_erc20"),
+                data: None,
+            })?;
+            Self::parse_decimal_to_u256(&a, decimals)?
+        };
+
+        let data = Self::encode_erc20_call(
+            "transfer(address,uint256)",
+            vec![
+                ethers::abi::Token::Address(to_addr),
+                ethers::abi::Token::Uint(amount_raw),
+            ],
+        );
+
+        let tx = EvmTxRequest {
+            chain_id,
+            from: sender.to_string(),
+            to: token_address.clone(),
+            value_wei: "0".to_string(),
+            data_hex: Some(format!("0x{}", hex::encode(data.as_ref()))),
+            nonce: None,
+            gas_limit: None,
+            max_fee_per_gas_wei: None,
+            max_priority_fee_per_gas_wei: None,
+        };
+        let preflight = self.w3rt_evm_preflight(tx.clone()).await;
+
+        Ok(Self::w3rt_evm_simulate_artifact(
+            "evm_erc20_transfer",
+            chain_id,
+            &tx,
+            &preflight,
+            json!({
+                "from": sender,
+                "to": to,
+                "token": token,
+                "token_address": token_address,
+                "decimals": decimals,
+                "amount_ui": amount,
+                "amount_raw": amount_raw.to_string()
+            }),
+        ))
+    }
+
+    async fn w3rt_evm_simulate_swap_0x(
+        &self,
+        intent: &Value,
+        chain_id: u64,
+        sender: &str,
+    ) -> Result<Value, ErrorData> {
+        let input_token = Self::w3rt_intent_str(intent, "input_token").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("input_token is required for swap"),
+            data: None,
+        })?;
+        let output_token = Self::w3rt_intent_str(intent, "output_token").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("output_token is required for swap"),
+            data: None,
+        })?;
+        let amount_in = Self::w3rt_intent_str(intent, "amount_in").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("amount_in is required for swap_exact_in"),
+            data: None,
+        })?;
+        let slippage_bps = intent
+            .get("slippage_bps")
+            .and_then(Value::as_u64)
+            .unwrap_or(100);
+
+        // 1) Build swap tx via 0x (exact approve amount, like the intent swap flow).
+        let built = self
+            .evm_0x_build_swap_tx(Parameters(Evm0xBuildSwapTxRequest {
+                chain_id,
+                sender: sender.to_string(),
+                sell_token: input_token.clone(),
+                buy_token: output_token.clone(),
+                sell_amount: amount_in.clone(),
+                sell_amount_is_wei: Some(false),
+                slippage: Some(format!("{}%", slippage_bps as f64 / 100.0)),
+                exact_approve: Some(true),
+            }))
+            .await?;
+        let built_json = Self::evm_extract_first_json(&built).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from("Failed to parse 0x build response"),
+            data: None,
+        })?;
+        let mut tx: EvmTxRequest =
+            serde_json::from_value(built_json.get("tx").cloned().unwrap_or(Value::Null)).map_err(
+                |e| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("Failed to decode built tx: {}", e)),
+                    data: None,
+                },
+            )?;
+        let quote = built_json.get("quote").cloned().unwrap_or(Value::Null);
+        let sell_amount_raw = quote
+            .get("sellAmount")
+            .and_then(Value::as_str)
+            .and_then(|s| ethers::types::U256::from_dec_str(s).ok())
+            .unwrap_or_else(|| ethers::types::U256::from(0));
+
+        // 2) Allowance: if the sell token is an ERC20 and allowance is short, preflight an approve first.
+        let allowance_target = built_json
+            .get("allowance_target")
+            .and_then(Value::as_str)
+            .map(|s| s.to_string());
+        let sell_token_address = built_json
+            .get("sell_token_address")
+            .and_then(Value::as_str)
+            .map(|s| s.to_string());
+
+        let mut approve = Value::Null;
+        let mut approve_ok = true;
+        if let (Some(token_addr), Some(spender)) = (sell_token_address.clone(), allowance_target.clone()) {
+            let is_native = token_addr == "ETH"
+                || token_addr.eq_ignore_ascii_case("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee");
+            if !is_native {
+                let allowance_res = self
+                    .evm_erc20_allowance(Parameters(EvmErc20AllowanceRequest {
+                        token: token_addr.clone(),
+                        owner: sender.to_string(),
+                        spender: spender.clone(),
+                        chain_id: Some(chain_id),
+                    }))
+                    .await?;
+                let allowance_json = Self::evm_extract_first_json(&allowance_res).unwrap_or(json!({}));
+                let allowance_raw = allowance_json
+                    .get("allowance_raw")
+                    .and_then(Value::as_str)
+                    .and_then(|s| ethers::types::U256::from_dec_str(s).ok())
+                    .unwrap_or_else(|| ethers::types::U256::from(0));
+
+                let needed = sell_amount_raw > ethers::types::U256::from(0) && allowance_raw < sell_amount_raw;
+                let mut approve_tx_out = Value::Null;
+                let mut approve_err: Option<String> = None;
+
+                if needed {
+                    let suggested = built_json
+                        .get("suggested_approve_tx")
+                        .cloned()
+                        .and_then(|v| serde_json::from_value::<EvmTxRequest>(v).ok());
+                    match suggested {
+                        Some(approve_tx) => match self.w3rt_evm_preflight(approve_tx.clone()).await {
+                            Ok(filled) => {
+                                // The swap lands right after the approve: reserve the next nonce and
+                                // take gas from the quote (estimateGas reverts without allowance).
+                                if let Some(n) = filled.nonce {
+                                    tx.nonce = Some(n + 1);
+                                }
+                                if tx.gas_limit.is_none() {
+                                    tx.gas_limit = quote
+                                        .get("gas")
+                                        .or_else(|| quote.get("estimatedGas"))
+                                        .and_then(Value::as_str)
+                                        .and_then(|s| s.parse::<u64>().ok())
+                                        .map(|g| g.saturating_mul(12) / 10);
+                                }
+                                approve_tx_out = json!(filled);
+                            }
+                            Err(e) => {
+                                approve_tx_out = json!(approve_tx);
+                                approve_err = Some(e);
+                            }
+                        },
+                        None => approve_err = Some("0x did not return a suggested approve tx".to_string()),
+                    }
+                    approve_ok = approve_err.is_none();
+                }
+
+                approve = json!({
+                    "needed": needed,
+                    "token": token_addr,
+                    "spender": spender,
+                    "allowance_raw": allowance_raw.to_string(),
+                    "required_raw": sell_amount_raw.to_string(),
+                    "tx": approve_tx_out,
+                    "preflight_error": approve_err
+                });
+            }
+        }
+
+        // 3) Preflight the swap itself.
+        let preflight = if approve_ok {
+            self.w3rt_evm_preflight(tx.clone()).await
+        } else {
+            Err("approve preflight failed; swap not preflighted".to_string())
+        };
+
+        let buy_amount = quote.get("buyAmount").and_then(Value::as_str);
+        let min_buy_amount = buy_amount
+            .and_then(|s| ethers::types::U256::from_dec_str(s).ok())
+            .map(|b| {
+                (b * ethers::types::U256::from(10_000u64.saturating_sub(slippage_bps))
+                    / ethers::types::U256::from(10_000u64))
+                .to_string()
+            });
+
+        Ok(Self::w3rt_evm_simulate_artifact(
+            "evm_0x",
+            chain_id,
+            &tx,
+            &preflight,
+            json!({
+                "user_pubkey": sender,
+                "input_token": input_token,
+                "output_token": output_token,
+                "amount_in": amount_in,
+                "slippage_bps": slippage_bps,
+                "sell_token_address": sell_token_address,
+                "buy_token_address": quote.get("buyTokenAddress"),
+                "sell_amount_raw": sell_amount_raw.to_string(),
+                "buy_amount_raw": buy_amount,
+                "min_buy_amount_raw": min_buy_amount,
+                "price_impact": quote.get("estimatedPriceImpact"),
+                "sources": quote.get("sources"),
+                "approve": approve,
+                "quote": quote
+            }),
+        ))
+    }
+
+    fn w3rt_evm_approval(simulate: &Value) -> Value {
+        let adapter = simulate.get("adapter").and_then(Value::as_str).unwrap_or("");
+        let tx: Option<EvmTxRequest> = simulate
+            .get("tx")
+            .cloned()
+            .and_then(|v| serde_json::from_value(v).ok());
+        let mut warnings: Vec<Value> = vec![];
+
+        if let Some(tx) = tx.as_ref() {
+            if crate::utils::evm_confirm_store::is_large_value(tx) {
+                warnings.push(json!({
+                    "kind": "large_value",
+                    "value_wei": tx.value_wei,
+                    "threshold_wei": crate::utils::evm_confirm_store::large_value_threshold_wei().to_string(),
+                    "note": "native value exceeds EVM_CONFIRM_LARGE_VALUE_THRESHOLD_WEI"
+                }));
+            }
+        }
+
+        if adapter == "evm_native_transfer" || adapter == "evm_erc20_transfer" {
+            let from = simulate.get("from").and_then(Value::as_str).unwrap_or("");
+            let to = simulate.get("to").and_then(Value::as_str).unwrap_or("");
+            if !from.is_empty() && from.eq_ignore_ascii_case(to) {
+                warnings.push(json!({
+                    "kind": "self_transfer",
+                    "note": "from == to"
+                }));
+            }
+        }
+
+        // Slippage / price-impact thresholds are approval policy rules (src/utils/approval_policy.rs).
+        if adapter == "evm_0x"
+            && simulate.get("min_buy_amount_raw").and_then(Value::as_str) == Some("0")
+        {
+            warnings.push(json!({
+                "kind": "min_out_zero",
+                "note": "computed min buy amount is 0 (check quote/buyAmount)"
+            }));
+        }
+
+        json!({
+            "stage": "approval",
+            "status": if warnings.is_empty() { "ok" } else { "needs_review" },
+            "network": simulate.get("network"),
+            "warnings": warnings,
+            "summary": {
+                "adapter": adapter,
+                "chain_id": simulate.get("chain_id"),
+                "from": simulate.get("from").or_else(|| simulate.get("user_pubkey")),
+                "to": simulate.get("to"),
+                "token": simulate.get("token_address"),
+                "amount_ui": simulate.get("amount_ui").or_else(|| simulate.get("amount_in")),
+                "value_wei": tx.as_ref().map(|t| t.value_wei.clone()),
+                "input_token": simulate.get("input_token"),
+                "output_token": simulate.get("output_token"),
+                "buy_amount_raw": simulate.get("buy_amount_raw"),
+                "min_buy_amount_raw": simulate.get("min_buy_amount_raw"),
+                "approve_needed": simulate.get("approve").and_then(|a| a.get("needed")),
+                "gas_limit": tx.as_ref().and_then(|t| t.gas_limit)
+            },
+            "note": "EVM transaction. Execution uses safe default (pending confirmation)."
+        })
+    }
+
+    /// Output amount a broadcast 0x swap step actually received (workflow v1 landed outputs):
+    /// ERC-20 Transfer logs to the sender, or its native balance change over the block.
+    /// `None` while the receipt is not available yet.
+    async fn w3rt_evm_received_raw(&self, simulate: &Value, tx_hash: &str) -> Result<Option<String>, ErrorData> {
+        let chain_id = simulate.get("chain_id").and_then(Value::as_u64).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("simulate artifact has no chain_id"),
+            data: None,
+        })?;
+        let provider = self.evm_provider(chain_id).await?;
+        let receipt = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_transaction_receipt(
+            &provider,
+            Self::parse_evm_h256(tx_hash)?,
+        )
+        .await
+        .map_err(|e| Self::sdk_error("w3rt_evm_landed:get_receipt", e))?;
+        let Some(receipt) = receipt else {
+            return Ok(None);
+        };
+
+        let sender = simulate.get("user_pubkey").and_then(Value::as_str).unwrap_or("");
+        let token = simulate.get("buy_token_address").and_then(Value::as_str).unwrap_or("");
+        let is_native = token.is_empty()
+            || token == "ETH"
+            || token.eq_ignore_ascii_case("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee");
+
+        let received = if receipt.status.map(|s| s.as_u64()) != Some(1) {
+            Err("transaction reverted".to_string())
+        } else if is_native {
+            let block = receipt.block_number.unwrap_or_default();
+            let address = Self::parse_evm_address(sender)?;
+            let mut balances = vec![];
+            for n in [block.saturating_sub(1u64.into()), block] {
+                let balance = <crate::utils::evm_rpc::EvmProvider as ethers::providers::Middleware>::get_balance(
+                    &provider,
+                    address,
+                    Some(ethers::types::BlockId::Number(n.into())),
+                )
+                .await
+                .map_err(|e| Self::sdk_error("w3rt_evm_landed:get_balance", e))?;
+                balances.push(balance);
+            }
+            let fee = receipt.gas_used.unwrap_or_default() * receipt.effective_gas_price.unwrap_or_default();
+            let value = simulate
+                .pointer("/tx/value_wei")
+                .and_then(Value::as_str)
+                .map(|v| Self::parse_evm_u256("value_wei", v))
+                .transpose()?
+                .unwrap_or_default();
+            crate::intent_adapters::evm_native_received_raw(balances[0], balances[1], fee, value)
+        } else {
+            let receipt = serde_json::to_value(&receipt).map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Failed to serialize receipt: {}", e)),
+                data: None,
+            })?;
+            crate::intent_adapters::evm_erc20_received_raw(&receipt, token, sender)
+        };
+        received.map(Some).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Cannot read the landed swap output: {}", e)),
+            data: Some(json!({"chain_id": chain_id, "tx_hash": tx_hash})),
+        })
+    }
+
+    fn w3rt_evm_pending_step(id: &str, tx: &EvmTxRequest, hash: &str, ttl_ms: u128) -> Value {
+        let token = crate::utils::evm_confirm_store::make_confirm_token(id, hash);
+        json!({
+            "confirmation_id": id,
+            "tx_summary_hash": hash,
+            "confirm_token": token,
+            "expires_in_ms": ttl_ms,
+            "tx_summary": crate::utils::evm_confirm_store::tx_summary_for_response(tx),
+            "confirm": {
+                "tool": "evm_retry_pending_confirmation",
+                "args": {
+                    "id": id,
+                    "tx_summary_hash": hash,
+                    "confirm_token": token,
+                    "chain_id": tx.chain_id
+                }
+            }
+        })
+    }
+
+    /// Store the simulated tx (and approve, if needed) as evm_confirm_store pending rows.
+    fn w3rt_evm_execute(run_id: &str, simulate: &Value, approval: &Value) -> Result<Value, ErrorData> {
+        let adapter = simulate.get("adapter").and_then(Value::as_str).unwrap_or("");
+        let tx: EvmTxRequest = serde_json::from_value(simulate.get("tx").cloned().unwrap_or(Value::Null))
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("missing/invalid tx from simulate stage: {}", e)),
+                data: Some(json!({"run_id": run_id})),
+            })?;
+
+        let now_ms = crate::utils::evm_confirm_store::now_ms();
+        let ttl_ms = crate::utils::evm_confirm_store::default_ttl_ms();
+        let expires_at_ms = now_ms + ttl_ms;
+
+        let label = if adapter == "evm_0x" { "w3rt_swap" } else { "w3rt_transfer" };
+        let id = crate::utils::evm_confirm_store::new_pending_id(label);
+        let hash = crate::utils::evm_confirm_store::tx_summary_hash(&tx);
+        crate::utils::evm_confirm_store::insert_pending(&id, &tx, now_ms, expires_at_ms, &hash)?;
+        let main_step = Self::w3rt_evm_pending_step(&id, &tx, &hash, ttl_ms);
+
+        let mut steps: Vec<Value> = vec![];
+        let mut approve_step = Value::Null;
+        if let Some(approve) = simulate.get("approve").filter(|a| a.is_object()) {
+            let token = approve.get("token").and_then(Value::as_str).unwrap_or("");
+            let spender = approve.get("spender").and_then(Value::as_str).unwrap_or("");
+            let required = approve.get("required_raw").and_then(Value::as_str).unwrap_or("0");
+
+            // Confirm-time guard: block the swap while allowance is still short.
+            crate::utils::evm_confirm_store::set_expected_allowance(&id, token, spender, required)?;
+
+            if approve.get("needed").and_then(Value::as_bool) == Some(true) {
+                let approve_tx: EvmTxRequest =
+                    serde_json::from_value(approve.get("tx").cloned().unwrap_or(Value::Null)).map_err(|e| {
+                        ErrorData {
+                            code: ErrorCode(-32603),
+                            message: Cow::from(format!("missing/invalid approve tx from simulate stage: {}", e)),
+                            data: Some(json!({"run_id": run_id})),
+                        }
+                    })?;
+                let approve_id = crate::utils::evm_confirm_store::new_pending_id("w3rt_approve");
+                let approve_hash = crate::utils::evm_confirm_store::tx_summary_hash(&approve_tx);
+                crate::utils::evm_confirm_store::insert_pending(
+                    &approve_id,
+                    &approve_tx,
+                    now_ms,
+                    expires_at_ms,
+                    &approve_hash,
+                )?;
+                crate::utils::evm_confirm_store::set_expected_allowance(&approve_id, token, spender, required)?;
+                crate::utils::evm_confirm_store::set_approve_link(&id, &approve_id)?;
+                crate::utils::evm_confirm_store::set_swap_link(&approve_id, &id)?;
+
+                approve_step = Self::w3rt_evm_pending_step(&approve_id, &approve_tx, &approve_hash, ttl_ms);
+                steps.push(approve_step.get("confirm").cloned().unwrap_or(Value::Null));
+            }
+        }
+        steps.push(main_step.get("confirm").cloned().unwrap_or(Value::Null));
+
+        Ok(json!({
+            "stage": "execute",
+            "status": "pending_confirmation_created",
+            "network": simulate.get("network"),
+            "chain_id": tx.chain_id,
+            "approval": approval,
+            "result": {
+                "tx": main_step,
+                "approve": approve_step
+            },
+            "next": {
+                "confirm": steps.last().cloned().unwrap_or(Value::Null),
+                "steps": steps
+            },
+            "note": if approve_step.is_null() {
+                "Pending confirmation created (safe default). Call evm_retry_pending_confirmation to sign+send (mainnet requires confirm_token)."
+            } else {
+                "Pending confirmations created (safe default). Confirm the approve first, then the swap, via evm_retry_pending_confirmation."
+            }
+        }))
+    }
@@ -0,0 +1,445 @@
+    /// W3RT workflow v1: drive a multi-step plan (see src/intent/plan.rs).
+    ///
+    /// Steps are simulated and approved in dependency order with their own artifacts under
+    /// `steps/<id>/`; one aggregated approval then gates execute for every simulated step.
+    /// Steps whose simulation depends on unconfirmed predecessors are deferred until resume;
+    /// once a predecessor's transaction has landed, its `outputs` are re-read from the chain
+    /// (`steps/<id>/landed`) before dependent steps resolve their bindings.
+    async fn w3rt_drive_plan(
+        &self,
+        store: &crate::utils::run_store::RunStore,
+        state: &mut crate::utils::run_store::RunState,
+        override_token: Option<&str>,
+    ) -> Result<Value, ErrorData> {
+        state.attempts += 1;
+        Self::w3rt_save_run_state(store, state)?;
+
+        match self.w3rt_drive_plan_steps(store, state, override_token).await {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                state.fail(&e.message);
+                let _ = store.save_state(state);
+                Err(e)
+            }
+        }
+    }
+
+    fn w3rt_write_step(
+        store: &crate::utils::run_store::RunStore,
+        run_id: &str,
+        step_id: &str,
+        stage: &str,
+        value: &Value,
+    ) -> Result<std::path::PathBuf, ErrorData> {
+        store
+            .write_step_artifact(run_id, step_id, stage, value)
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("failed to write {step_id}/{stage} artifact: {e}")),
+                data: None,
+            })
+    }
+
+    fn w3rt_plan_step_deferred(step_id: &str, reason: &str, waiting_on: &[String]) -> Value {
+        json!({
+            "stage": "simulate",
+            "status": "deferred",
+            "simulation_performed": false,
+            "step": step_id,
+            "waiting_on": waiting_on,
+            "reason": reason,
+            "note": "Confirm the predecessor steps first, then call w3rt_resume_run to simulate this step against the new state."
+        })
+    }
+
+    async fn w3rt_drive_plan_steps(
+        &self,
+        store: &crate::utils::run_store::RunStore,
+        state: &mut crate::utils::run_store::RunState,
+        override_token: Option<&str>,
+    ) -> Result<Value, ErrorData> {
+        use crate::intent_plan::{
+            aggregate_approval, output_refs, resolve_bindings, sequence_evm_nonces, EvmNonces, PlanStep,
+        };
+        use crate::utils::run_store::execute_stage_status;
+
+        let run_id = state.run_id.clone();
+        let analysis = store.read_stage_artifact(&run_id, "analysis").unwrap_or(Value::Null);
+        let plan = analysis.get("plan").cloned().ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("run has no plan; start a new run with w3rt_run_workflow_v1"),
+            data: Some(json!({"run_id": run_id})),
+        })?;
+        let steps: Vec<PlanStep> = serde_json::from_value(plan.get("steps").cloned().unwrap_or(Value::Null))
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("invalid plan in analysis artifact: {e}")),
+                data: Some(json!({"run_id": run_id})),
+            })?;
+        let order: Vec<usize> = plan
+            .get("order")
+            .and_then(Value::as_array)
+            .map(|a| a.iter().filter_map(Value::as_u64).map(|i| i as usize).collect())
+            .unwrap_or_else(|| (0..steps.len()).collect());
+        let sender = analysis.get("sender").and_then(Value::as_str);
+        let network = analysis.get("network").and_then(Value::as_str);
+
+        let mut ctx = json!({"steps": {}});
+        let mut reused: Vec<String> = vec![];
+        let mut approvals: Vec<(String, Value)> = vec![];
+        let mut deferred: Vec<String> = vec![];
+        let mut failed: Vec<String> = vec![];
+        // Steps whose outputs have not landed yet, with the reason.
+        let mut not_landed: HashMap<String, String> = HashMap::new();
+        let mut step_summaries: Vec<Value> = vec![];
+        let mut ready: Vec<(String, Value, Value, Value)> = vec![];
+        let mut recomputed = false;
+        let mut evm_nonces = EvmNonces::new();
+
+        // ---------------- Per step: simulate + approval ----------------
+        for &i in &order {
+            let Some(step) = steps.get(i) else {
+                continue;
+            };
+            let id = step.id.as_str();
+            let sim_key = format!("{id}:simulate");
+            let approval_key = format!("{id}:approval");
+
+            let cached = state
+                .is_done(&sim_key)
+                .then(|| {
+                    Some((
+                        store.read_step_artifact(&run_id, id, "analysis")?,
+                        store.read_step_artifact(&run_id, id, "simulate")?,
+                    ))
+                })
+                .flatten();
+
+            let (intent, simulate) = match cached {
+                Some((step_analysis, mut simulate)) => {
+                    reused.push(sim_key.clone());
+                    sequence_evm_nonces(&mut simulate, &mut evm_nonces, true);
+                    (step_analysis.get("intent").cloned().unwrap_or(Value::Null), simulate)
+                }
+                None => {
+                    recomputed = true;
+                    // A fresh simulation invalidates this step's approval and execute.
+                    state.finish(&approval_key, "stale");
+                    state.finish(&format!("{id}:execute"), "stale");
+                    state.finish(&format!("{id}:landed"), "stale");
+                    state.begin(&sim_key);
+                    Self::w3rt_save_run_state(store, state)?;
+
+                    // Bound outputs are only known once the predecessor's transaction landed.
+                    let bound = output_refs(&step.intent);
+                    let waiting: Vec<(String, String)> = step
+                        .depends_on
+                        .iter()
+                        .filter_map(|d| {
+                            if deferred.contains(d) || failed.contains(d) {
+                                Some((d.clone(), format!("step '{d}' has not simulated successfully")))
+                            } else if bound.contains(d) {
+                                not_landed
+                                    .get(d)
+                                    .map(|r| (d.clone(), format!("outputs of step '{d}' are not available: {r}")))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect();
+                    let waiting_on: Vec<String> = waiting.iter().map(|(d, _)| d.clone()).collect();
+
+                    let resolved = if waiting.is_empty() {
+                        resolve_bindings(&step.intent, &ctx)
+                    } else {
+                        Err(waiting.into_iter().map(|(_, r)| r).collect::<Vec<_>>().join("; "))
+                    };
+
+                    let (intent, mut simulate) = match resolved {
+                        Err(reason) if step.depends_on.is_empty() => (
+                            step.intent.clone(),
+                            json!({
+                                "stage": "simulate",
+                                "status": "failed",
+                                "simulation_performed": false,
+                                "error": reason
+                            }),
+                        ),
+                        Err(reason) => (
+                            step.intent.clone(),
+                            Self::w3rt_plan_step_deferred(id, &reason, &waiting_on),
+                        ),
+                        Ok(mut intent) => {
+                            crate::intent_adapters::normalize_intent(&mut intent, sender, network);
+                            let simulate = match crate::intent_adapters::adapter_for_intent(&intent) {
+                                Some(adapter) => match adapter.simulate(self, &intent).await {
+                                    Ok(v) => v,
+                                    // Dependent steps often cannot be built before their predecessors land.
+                                    Err(e) if !step.depends_on.is_empty() => {
+                                        Self::w3rt_plan_step_deferred(id, &e.message, &step.depends_on)
+                                    }
+                                    Err(e) => json!({
+                                        "stage": "simulate",
+                                        "status": "failed",
+                                        "simulation_performed": false,
+                                        "error": e.message,
+                                        "data": e.data
+                                    }),
+                                },
+                                None => json!({
+                                    "stage": "simulate",
+                                    "status": "todo",
+                                    "simulation_performed": false,
+                                    "intent": intent,
+                                    "note": "No chain adapter supports this intent yet."
+                                }),
+                            };
+                            (intent, simulate)
+                        }
+                    };
+
+                    // Every step is preflighted before any is sent; EVM steps from one sender
+                    // must not reuse a nonce (e.g. approve then swap).
+                    sequence_evm_nonces(&mut simulate, &mut evm_nonces, false);
+
+                    Self::w3rt_write_step(
+                        store,
+                        &run_id,
+                        id,
+                        "analysis",
+                        &json!({"stage": "analysis", "step": id, "depends_on": step.depends_on, "intent": intent}),
+                    )?;
+                    Self::w3rt_write_step(store, &run_id, id, "simulate", &simulate)?;
+
+                    let sim_status = match simulate.get("status").and_then(Value::as_str) {
+                        Some("ok") => "done",
+                        Some("deferred") => "deferred",
+                        // A failed dry-run of a dependent step is expected before its predecessors confirm.
+                        _ if !step.depends_on.is_empty() => "deferred",
+                        _ => "failed",
+                    };
+                    state.finish(&sim_key, sim_status);
+                    Self::w3rt_save_run_state(store, state)?;
+                    (intent, simulate)
+                }
+            };
+
+            let sim_status = state
+                .stages
+                .get(&sim_key)
+                .map(|s| s.status.clone())
+                .unwrap_or_default();
+            if sim_status != "done" {
+                if sim_status == "deferred" {
+                    deferred.push(id.to_string());
+                } else {
+                    failed.push(id.to_string());
+                }
+                step_summaries.push(json!({
+                    "id": id,
+                    "status": sim_status,
+                    "simulate_status": simulate.get("status"),
+                    "reason": simulate.get("reason").or_else(|| simulate.get("error"))
+                }));
+                continue;
+            }
+
+            let adapter = crate::intent_adapters::adapter_for_intent(&intent);
+            let mut outputs = adapter.map(|a| a.outputs(&simulate)).unwrap_or(Value::Null);
+
+            // Dependents bind to what the step's transaction actually produced, not to the quote.
+            let landed_key = format!("{id}:landed");
+            let cached = state
+                .is_done(&landed_key)
+                .then(|| store.read_step_artifact(&run_id, id, "landed"))
+                .flatten();
+            let executed = state
+                .is_done(&format!("{id}:execute"))
+                .then(|| store.read_step_artifact(&run_id, id, "execute"))
+                .flatten();
+            match (cached, executed, adapter) {
+                (Some(landed), _, _) => {
+                    reused.push(landed_key.clone());
+                    outputs = landed;
+                }
+                (None, Some(execute), Some(adapter)) => {
+                    match adapter.landed_outputs(self, &simulate, &execute).await {
+                        Ok(landed) if landed.is_null() => {
+                            not_landed.insert(id.to_string(), "its transaction has not landed yet".to_string());
+                        }
+                        Ok(landed) => {
+                            state.begin(&landed_key);
+                            Self::w3rt_write_step(store, &run_id, id, "landed", &landed)?;
+                            state.finish(&landed_key, "done");
+                            Self::w3rt_save_run_state(store, state)?;
+                            outputs = landed;
+                        }
+                        Err(e) => {
+                            not_landed.insert(id.to_string(), e.message.to_string());
+                        }
+                    }
+                }
+                _ => {
+                    not_landed.insert(id.to_string(), "it has not been executed yet".to_string());
+                }
+            }
+            ctx["steps"][id] = json!({"intent": intent, "simulate": simulate, "outputs": outputs});
+
+            let cached = state
+                .is_done(&approval_key)
+                .then(|| store.read_step_artifact(&run_id, id, "approval"))
+                .flatten();
+            let approval = match cached {
+                Some(v) => {
+                    reused.push(approval_key.clone());
+                    v
+                }
+                None => {
+                    recomputed = true;
+                    state.begin(&approval_key);
+                    Self::w3rt_save_run_state(store, state)?;
+                    let approval = match adapter {
+                        Some(adapter) => adapter.approve(self, &intent, &simulate).await?,
+                        None => crate::intent_adapters::stage_todo(
+                            "approval",
+                            "Approval not implemented for this intent yet.",
+                        ),
+                    };
+                    Self::w3rt_write_step(store, &run_id, id, "approval", &approval)?;
+                    state.finish(&approval_key, "done");
+                    Self::w3rt_save_run_state(store, state)?;
+                    approval
+                }
+            };
+
+            step_summaries.push(json!({
+                "id": id,
+                "status": "simulated",
+                "outputs": outputs,
+                "approval_status": approval.get("status")
+            }));
+            approvals.push((id.to_string(), approval.clone()));
+            ready.push((id.to_string(), intent, simulate, approval));
+        }
+
+        Self::w3rt_write_stage(
+            store,
+            &run_id,
+            "simulate",
+            &json!({"stage": "simulate", "steps": step_summaries, "deferred": deferred, "failed": failed}),
+        )?;
+
+        // ---------------- Aggregated approval ----------------
+        let cached = (!recomputed && state.is_done("approval"))
+            .then(|| store.read_stage_artifact(&run_id, "approval"))
+            .flatten();
+        let approval = match cached {
+            Some(v) => {
+                reused.push("approval".to_string());
+                v
+            }
+            None => {
+                state.begin("approval");
+                let approval = aggregate_approval(&approvals, &deferred, &failed);
+                Self::w3rt_write_stage(store, &run_id, "approval", &approval)?;
+                state.finish("approval", "done");
+                Self::w3rt_save_run_state(store, state)?;
+                approval
+            }
+        };
+
+        // ---------------- Execute (per simulated step, gated by the aggregated approval) ----------------
+        let mut executed: Vec<Value> = vec![];
+        let mut any_blocked = false;
+        let mut any_failed = !failed.is_empty();
+        for (id, intent, simulate, _step_approval) in &ready {
+            let exec_key = format!("{id}:execute");
+            let cached = state
+                .is_done(&exec_key)
+                .then(|| store.read_step_artifact(&run_id, id, "execute"))
+                .flatten();
+            let execute = match cached {
+                Some(v) => {
+                    // The pending confirmation already exists; never create a second one.
+                    reused.push(exec_key.clone());
+                    v
+                }
+                None => {
+                    state.begin(&exec_key);
+                    Self::w3rt_save_run_state(store, state)?;
+                    let adapter = crate::intent_adapters::adapter_for_intent(intent);
+                    let execute = self
+                        .w3rt_execute_stage(&run_id, adapter, intent, simulate, &approval, override_token)
+                        .await?;
+                    Self::w3rt_write_step(store, &run_id, id, "execute", &execute)?;
+                    state.finish(&exec_key, execute_stage_status(&execute));
+                    Self::w3rt_save_run_state(store, state)?;
+                    execute
+                }
+            };
+            match execute_stage_status(&execute) {
+                "done" => {}
+                "blocked" => any_blocked = true,
+                _ => any_failed = true,
+            }
+            executed.push(json!({
+                "id": id,
+                "status": execute.get("status"),
+                "next": execute.get("next").or_else(|| execute.get("guard").and_then(|g| g.get("next")))
+            }));
+        }
+
+        state.status = if any_blocked {
+            "blocked"
+        } else if any_failed {
+            "failed"
+        } else if !deferred.is_empty() {
+            "waiting"
+        } else {
+            "completed"
+        }
+        .to_string();
+        state.stage = "execute".to_string();
+        state.last_error = match state.status.as_str() {
+            "failed" if !failed.is_empty() => Some(format!("steps failed: {}", failed.join(", "))),
+            "failed" => Some("execute failed for a plan step".to_string()),
+            _ => None,
+        };
+
+        let execute = json!({
+            "stage": "execute",
+            "status": state.status,
+            "steps": executed,
+            "deferred": deferred,
+            "failed": failed,
+            "next": if deferred.is_empty() {
+                json!({"note": "Confirm the pending steps in plan order (each step's next)."})
+            } else {
+                json!({
+                    "note": "Confirm the pending steps in plan order, then resume to simulate deferred steps.",
+                    "resume": {"tool": "w3rt_resume_run", "args": {"run_id": run_id}}
+                })
+            }
+        });
+        Self::w3rt_write_stage(store, &run_id, "execute", &execute)?;
+        if state.status == "completed" {
+            state.begin("execute");
+            state.finish("execute", "done");
+            state.status = "completed".to_string();
+        }
+        Self::w3rt_save_run_state(store, state)?;
+
+        Ok(json!({
+            "artifacts": {
+                "analysis": store.stage_path(&run_id, "analysis"),
+                "simulate": store.stage_path(&run_id, "simulate"),
+                "approval": store.stage_path(&run_id, "approval"),
+                "execute": store.stage_path(&run_id, "execute"),
+                "steps": store.run_dir(&run_id).join("steps")
+            },
+            "reused": reused,
+            "approval_status": approval.get("status").cloned().unwrap_or(Value::Null),
+            "execute_status": state.status,
+            "deferred": deferred
+        }))
+    }
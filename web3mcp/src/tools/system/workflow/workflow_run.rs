@@ -0,0 +1,282 @@
+    /// W3RT: drive a run from its persisted state (simulate → approval → execute).
+    ///
+    /// Stages already marked done in `run_state.json` are reused from their artifacts, so a
+    /// resumed run never re-quotes and never creates a second pending confirmation.
+    async fn w3rt_drive_run(
+        &self,
+        store: &crate::utils::run_store::RunStore,
+        state: &mut crate::utils::run_store::RunState,
+        override_token: Option<&str>,
+    ) -> Result<Value, ErrorData> {
+        state.attempts += 1;
+        Self::w3rt_save_run_state(store, state)?;
+
+        match self.w3rt_drive_stages(store, state, override_token).await {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                state.fail(&e.message);
+                let _ = store.save_state(state);
+                Err(e)
+            }
+        }
+    }
+
+    fn w3rt_save_run_state(
+        store: &crate::utils::run_store::RunStore,
+        state: &crate::utils::run_store::RunState,
+    ) -> Result<std::path::PathBuf, ErrorData> {
+        store.save_state(state).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("failed to write run state: {e}")),
+            data: Some(json!({"run_id": state.run_id})),
+        })
+    }
+
+    fn w3rt_write_stage(
+        store: &crate::utils::run_store::RunStore,
+        run_id: &str,
+        stage: &str,
+        value: &Value,
+    ) -> Result<std::path::PathBuf, ErrorData> {
+        store.write_stage_artifact(run_id, stage, value).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("failed to write {stage} artifact: {e}")),
+            data: None,
+        })
+    }
+
+    async fn w3rt_drive_stages(
+        &self,
+        store: &crate::utils::run_store::RunStore,
+        state: &mut crate::utils::run_store::RunState,
+        override_token: Option<&str>,
+    ) -> Result<Value, ErrorData> {
+        use crate::utils::run_store::{execute_stage_status, run_status_for_execute};
+
+        let run_id = state.run_id.clone();
+        let analysis = store.read_stage_artifact(&run_id, "analysis").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("run has no analysis artifact; start a new run with w3rt_run_workflow_v0"),
+            data: Some(json!({"run_id": run_id})),
+        })?;
+        let intent_value = analysis.get("intent").cloned().unwrap_or(Value::Null);
+
+        // Chain-specific stages are dispatched through the adapter registry (src/intent/adapters.rs).
+        let adapter = crate::intent_adapters::adapter_for_intent(&intent_value);
+        let mut reused: Vec<&str> = vec![];
+
+        // ---------------- Stage 2: simulate ----------------
+        let cached = state
+            .is_done("simulate")
+            .then(|| store.read_stage_artifact(&run_id, "simulate"))
+            .flatten();
+        let simulate = match cached {
+            Some(v) => {
+                reused.push("simulate");
+                v
+            }
+            None => {
+                // A fresh simulation (new quote/tx) invalidates approval and execute.
+                state.invalidate_from("approval");
+                state.begin("simulate");
+                Self::w3rt_save_run_state(store, state)?;
+
+                let simulate = match adapter {
+                    Some(adapter) => adapter.simulate(self, &intent_value).await?,
+                    None => json!({
+                        "stage": "simulate",
+                        "status": "todo",
+                        "simulation_performed": false,
+                        "intent": intent_value,
+                        "note": "No chain adapter supports this intent yet."
+                    }),
+                };
+                Self::w3rt_write_stage(store, &run_id, "simulate", &simulate)?;
+
+                let ok = simulate.get("status").and_then(Value::as_str) == Some("ok");
+                state.finish("simulate", if ok { "done" } else { "failed" });
+                Self::w3rt_save_run_state(store, state)?;
+                simulate
+            }
+        };
+
+        // Stage 3: approval (policy)
+        let cached = state
+            .is_done("approval")
+            .then(|| store.read_stage_artifact(&run_id, "approval"))
+            .flatten();
+        let approval = match cached {
+            Some(v) => {
+                reused.push("approval");
+                v
+            }
+            None => {
+                state.invalidate_from("execute");
+                state.begin("approval");
+                Self::w3rt_save_run_state(store, state)?;
+
+                let approval = match adapter {
+                    Some(adapter) => adapter.approve(self, &intent_value, &simulate).await?,
+                    None => crate::intent_adapters::stage_todo(
+                        "approval",
+                        "Approval not implemented for this intent yet.",
+                    ),
+                };
+                Self::w3rt_write_stage(store, &run_id, "approval", &approval)?;
+
+                state.finish("approval", "done");
+                Self::w3rt_save_run_state(store, state)?;
+                approval
+            }
+        };
+
+        // Stage 4: execute (guards)
+        let cached = state
+            .is_done("execute")
+            .then(|| store.read_stage_artifact(&run_id, "execute"))
+            .flatten();
+        let execute = match cached {
+            Some(v) => {
+                // The pending confirmation already exists; never create a second one.
+                reused.push("execute");
+                state.status = "completed".to_string();
+                v
+            }
+            None => {
+                state.begin("execute");
+                Self::w3rt_save_run_state(store, state)?;
+
+                let execute = self
+                    .w3rt_execute_stage(&run_id, adapter, &intent_value, &simulate, &approval, override_token)
+                    .await?;
+                Self::w3rt_write_stage(store, &run_id, "execute", &execute)?;
+
+                let stage_status = execute_stage_status(&execute);
+                state.finish("execute", stage_status);
+                if state.is_done("simulate") {
+                    state.status = run_status_for_execute(stage_status).to_string();
+                } else {
+                    state.status = "failed".to_string();
+                    state.last_error = Some("simulation not ok".to_string());
+                }
+                execute
+            }
+        };
+        if state.status == "completed" {
+            state.last_error = None;
+        }
+        Self::w3rt_save_run_state(store, state)?;
+
+        Ok(json!({
+            "artifacts": {
+                "analysis": store.stage_path(&run_id, "analysis"),
+                "simulate": store.stage_path(&run_id, "simulate"),
+                "approval": store.stage_path(&run_id, "approval"),
+                "execute": store.stage_path(&run_id, "execute")
+            },
+            "reused": reused,
+            "execute_status": execute.get("status").cloned().unwrap_or(Value::Null)
+        }))
+    }
+
+    /// Stage 4 guards (no-sim-no-send, approval/override) followed by the adapter's execute.
+    async fn w3rt_execute_stage(
+        &self,
+        run_id: &str,
+        adapter: Option<&'static dyn crate::intent_adapters::ChainAdapter>,
+        intent_value: &Value,
+        simulate: &Value,
+        approval: &Value,
+        override_token: Option<&str>,
+    ) -> Result<Value, ErrorData> {
+        let simulation_ok = simulate
+            .get("simulation_performed")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+            && simulate.get("status").and_then(Value::as_str) == Some("ok");
+
+        let approval_status = approval.get("status").and_then(Value::as_str).unwrap_or("");
+        let approval_ok = approval_status == "ok";
+
+        // Optional approval override.
+        let provided_override = override_token.unwrap_or_default().to_string();
+        let (override_ok, override_diag) = if provided_override.is_empty() {
+            (false, None)
+        } else {
+            crate::utils::override_store::validate_override(run_id, &provided_override)
+                .unwrap_or((false, None))
+        };
+
+        let approval_gate_passed = approval_ok || override_ok;
+
+        let execute = if !simulation_ok {
+            // Guard 1: no-sim-no-send
+            json!({
+                "stage": "execute",
+                "status": "blocked",
+                "guard": {
+                    "guard_class": "no_sim_no_send",
+                    "next": {
+                        "mode": "simulate",
+                        "how_to": "Run (and pass) simulation first. See simulate artifact for logs/errors."
+                    }
+                },
+                "note": "Execution blocked: simulation not OK (safety)."
+            })
+        } else if approval_status == "blocked" {
+            // Guard 2a: a policy `block` decision cannot be overridden.
+            json!({
+                "stage": "execute",
+                "status": "blocked",
+                "guard": {
+                    "guard_class": "policy_blocked",
+                    "matched_rule_ids": approval.get("policy").and_then(|p| p.get("matched_rule_ids")),
+                    "next": {
+                        "mode": "revise",
+                        "how_to": "The approval policy blocks this intent. Change the intent (amount/recipient/token) or the policy file and start a new run."
+                    }
+                },
+                "approval": approval,
+                "note": "Execution blocked: approval.status == blocked (policy)."
+            })
+        } else if !approval_gate_passed {
+            // Guard 2b: approval policy says review is needed.
+            json!({
+                "stage": "execute",
+                "status": "blocked",
+                "guard": {
+                    "guard_class": "approval_required",
+                    "next": {
+                        "mode": "review",
+                        "how_to": "Review approval warnings (price impact / slippage / route). Adjust slippage/amount or pick a different pair and re-run.",
+                        "request_override": {
+                            "tool": "w3rt_request_override",
+                            "args": {
+                                "run_id": run_id,
+                                "reason": "<why you accept the risk>",
+                                "ttl_ms": 300000
+                            }
+                        }
+                    }
+                },
+                "approval": approval,
+                "override": {
+                    "provided": if provided_override.is_empty() { Value::Null } else { json!("<redacted>") },
+                    "validation": override_diag
+                },
+                "note": "Execution blocked: approval.status != ok (policy)."
+            })
+        } else if let Some(adapter) = adapter {
+            adapter
+                .execute(self, run_id, intent_value, simulate, approval)
+                .await?
+        } else {
+            json!({
+                "stage": "execute",
+                "status": "todo",
+                "note": "Execution not implemented for this intent yet."
+            })
+        };
+
+        Ok(execute)
+    }
@@ -0,0 +1,2548 @@
+    /// W3RT (Solana): build + simulate a workflow intent (Jupiter swaps, SOL/SPL transfers, read-only actions).
+    async fn w3rt_solana_simulate(&self, intent_value: &Value) -> Result<Value, ErrorData> {
+        use crate::utils::solana_workflow::*;
+
+        let mut simulate = json!({
+            "stage": "simulate",
+            "status": "todo",
+            "simulation_performed": false,
+            "intent": intent_value,
+        });
+
+        // Solana transfer_spl (USDC-first): build an SPL token transfer tx and simulate.
+        if intent_value["chain"] == "solana" && intent_value["action"] == "transfer_spl" {
+            use std::str::FromStr;
+
+            let from = intent_value.get("from").and_then(Value::as_str).unwrap_or("<sender>");
+            let to = intent_value.get("to").and_then(Value::as_str).unwrap_or("<recipient>");
+            let amount_s = intent_value.get("amount").and_then(Value::as_str).unwrap_or("<amount>");
+            let asset = intent_value.get("asset").and_then(Value::as_str).unwrap_or("usdc").to_lowercase();
+
+            if from.starts_with('<') {
+                return Err(ErrorData { code: ErrorCode(-32602), message: Cow::from("from (sender) is required"), data: None });
+            }
+            if to.starts_with('<') {
+                return Err(ErrorData { code: ErrorCode(-32602), message: Cow::from("to (recipient) is required"), data: None });
+            }
+
+            // Resolve mint + decimals.
+            // - If `asset` is a pubkey: treat it as mint address.
+            // - Else: support USDC/USDT fast-path, and fall back to Jupiter tokens list.
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            let (mint, decimals, token_info): (String, u8, Option<Value>) = if solana_is_pubkey(&asset) {
+                let mint = asset.clone();
+                let decimals = solana_get_mint_decimals(&rpc, &mint).await?;
+                (mint, decimals, Some(json!({"source": "mint_address"})))
+            } else if asset == "usdc" {
+                (
+                    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+                    6,
+                    Some(json!({"source": "builtin", "symbol": "USDC"})),
+                )
+            } else if asset == "usdt" {
+                (
+                    "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB".to_string(),
+                    6,
+                    Some(json!({"source": "builtin", "symbol": "USDT"})),
+                )
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(&asset)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("unsupported SPL asset (provide mint address or known symbol)"),
+                        data: Some(json!({"asset": asset})),
+                    })?;
+                let mint = found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter token entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string();
+
+                let decimals = found
+                    .get("decimals")
+                    .and_then(Value::as_u64)
+                    .map(|d| d as u8)
+                    .unwrap_or(0);
+
+                let decimals = if decimals == 0 {
+                    solana_get_mint_decimals(&rpc, &mint).await?
+                } else {
+                    decimals
+                };
+
+                (
+                    mint,
+                    decimals,
+                    Some(json!({
+                        "source": "jupiter_tokens",
+                        "symbol": found.get("symbol"),
+                        "name": found.get("name"),
+                        "address": found.get("address"),
+                        "decimals": decimals
+                    })),
+                )
+            };
+
+            let amount_base = parse_amount_to_base_units(amount_s, decimals as u32)?;
+
+            let from_pk = solana_sdk::pubkey::Pubkey::from_str(from).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid from pubkey: {e}")),
+                data: Some(json!({"from": from})),
+            })?;
+            let to_pk = solana_sdk::pubkey::Pubkey::from_str(to).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid to pubkey: {e}")),
+                data: Some(json!({"to": to})),
+            })?;
+            let mint_pk = solana_sdk::pubkey::Pubkey::from_str(mint.as_str()).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid mint pubkey: {e}")),
+                data: Some(json!({"mint": mint})),
+            })?;
+
+            // Detect token program (spl-token vs token-2022) from mint owner.
+            let token_program = solana_get_token_program_for_mint(&rpc, &mint).await?;
+            // Avoid type/version mismatches by treating program ids as strings and parsing into solana_sdk Pubkey.
+            let token_program_legacy = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+            let token_program_2022 = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+            let token_program_id = if token_program == token_program_2022 {
+                solana_sdk::pubkey::Pubkey::from_str(token_program_2022).map_err(|e| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("invalid token-2022 program id: {e}")),
+                    data: None,
+                })?
+            } else if token_program == token_program_legacy {
+                solana_sdk::pubkey::Pubkey::from_str(token_program_legacy).map_err(|e| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("invalid token program id: {e}")),
+                    data: None,
+                })?
+            } else {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("mint is not owned by spl-token or token-2022"),
+                    data: Some(json!({"mint": mint, "owner": token_program})),
+                });
+            };
+
+            let from_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+                &from_pk,
+                &mint_pk,
+                &token_program_id,
+            );
+            let to_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+                &to_pk,
+                &mint_pk,
+                &token_program_id,
+            );
+
+            let bh = rpc.get_latest_blockhash().await.map_err(|e| Self::sdk_error("solana_spl_transfer:get_latest_blockhash", e))?;
+
+            // Ensure sender ATA exists.
+            let _ = rpc.get_account(&from_ata).await.map_err(|_| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("sender token account (ATA) not found"),
+                data: Some(json!({"from_ata": from_ata.to_string(), "mint": mint})),
+            })?;
+
+            // Create recipient ATA if needed.
+            let to_ata_exists = rpc.get_account(&to_ata).await.is_ok();
+            let will_create_ata = !to_ata_exists;
+
+            let mut ixs: Vec<solana_sdk::instruction::Instruction> = vec![];
+            if will_create_ata {
+                // payer = from
+                ixs.push(spl_associated_token_account::instruction::create_associated_token_account(
+                    &from_pk,
+                    &to_pk,
+                    &mint_pk,
+                    &token_program_id,
+                ));
+            }
+
+            let transfer_ix = solana_build_token_transfer_checked_ix(
+                token_program_id,
+                from_ata,
+                mint_pk,
+                to_ata,
+                from_pk,
+                amount_base,
+                decimals,
+            );
+
+            ixs.push(transfer_ix);
+
+            let msg = solana_sdk::message::Message::new(&ixs, Some(&from_pk));
+            let mut tx = solana_sdk::transaction::Transaction::new_unsigned(msg);
+            tx.message.recent_blockhash = bh;
+
+            let cfg = solana_client::rpc_config::RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(solana_commitment_config::CommitmentConfig::processed()),
+                encoding: Some(solana_transaction_status::UiTransactionEncoding::Base64),
+                accounts: None,
+                min_context_slot: None,
+                inner_instructions: false,
+            };
+
+            let sim = rpc.simulate_transaction_with_config(&tx, cfg).await
+                .map_err(|e| Self::sdk_error("solana_spl_transfer:simulate", e))?;
+
+            let ok = sim.value.err.is_none();
+
+            let tx_bytes = bincode::serialize(&tx)
+                .map_err(|e| Self::sdk_error("solana_spl_transfer:serialize_tx", e))?;
+            let tx_b64 = base64::engine::general_purpose::STANDARD.encode(tx_bytes);
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": if ok { "ok" } else { "failed" },
+                "simulation_performed": true,
+                "adapter": "solana_spl_transfer",
+                "network": network,
+                "asset": asset,
+                "mint": mint,
+                "decimals": decimals,
+                "token_info": token_info,
+                "token_program": token_program,
+                "from": from,
+                "to": to,
+                "from_ata": from_ata.to_string(),
+                "to_ata": to_ata.to_string(),
+                "to_ata_exists": to_ata_exists,
+                "will_create_ata": will_create_ata,
+                "amount_ui": amount_s,
+                "amount_base": amount_base.to_string(),
+                "tx": { "tx_base64": tx_b64 },
+                "simulation": { "err": sim.value.err, "logs": sim.value.logs, "units_consumed": sim.value.units_consumed }
+            });
+        }
+
+        // Solana transfer_native (SOL): build a system transfer tx and simulate.
+        if intent_value["chain"] == "solana" && intent_value["action"] == "transfer_native" {
+            use std::str::FromStr;
+
+            let from = intent_value.get("from").and_then(Value::as_str).unwrap_or("<sender>");
+            let to = intent_value.get("to").and_then(Value::as_str).unwrap_or("<recipient>");
+            let amount_s = intent_value.get("amount").and_then(Value::as_str).unwrap_or("<amount>");
+            let asset = intent_value.get("asset").and_then(Value::as_str).unwrap_or("sol");
+
+            if from.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("from (sender) is required for transfer"),
+                    data: None,
+                });
+            }
+            if to.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("to (recipient) is required for transfer"),
+                    data: None,
+                });
+            }
+            if asset.to_lowercase() != "sol" {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("transfer_native currently supports SOL only"),
+                    data: Some(json!({"asset": asset})),
+                });
+            }
+
+            let from_pk = solana_sdk::pubkey::Pubkey::from_str(from).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid from pubkey: {e}")),
+                data: Some(json!({"from": from})),
+            })?;
+            let to_pk = solana_sdk::pubkey::Pubkey::from_str(to).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid to pubkey: {e}")),
+                data: Some(json!({"to": to})),
+            })?;
+
+            // Amount is UI SOL string.
+            let lamports = parse_amount_to_base_units(amount_s, 9)?;
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+            let bh = rpc
+                .get_latest_blockhash()
+                .await
+                .map_err(|e| Self::sdk_error("solana_transfer:get_latest_blockhash", e))?;
+
+            let ix = solana_system_interface::instruction::transfer(&from_pk, &to_pk, lamports);
+            let msg = solana_sdk::message::Message::new(&[ix], Some(&from_pk));
+            let mut tx = solana_sdk::transaction::Transaction::new_unsigned(msg);
+            tx.message.recent_blockhash = bh;
+
+            let cfg = solana_client::rpc_config::RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(solana_commitment_config::CommitmentConfig::processed()),
+                encoding: Some(solana_transaction_status::UiTransactionEncoding::Base64),
+                accounts: None,
+                min_context_slot: None,
+                inner_instructions: false,
+            };
+
+            let sim = rpc
+                .simulate_transaction_with_config(&tx, cfg)
+                .await
+                .map_err(|e| Self::sdk_error("solana_transfer:simulate", e))?;
+
+            let ok = sim.value.err.is_none();
+
+            let tx_bytes = bincode::serialize(&tx)
+                .map_err(|e| Self::sdk_error("solana_transfer:serialize_tx", e))?;
+            let tx_b64 = base64::engine::general_purpose::STANDARD.encode(tx_bytes);
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": if ok { "ok" } else { "failed" },
+                "simulation_performed": true,
+                "adapter": "solana_system_transfer",
+                "network": network,
+                "from": from,
+                "to": to,
+                "lamports": lamports.to_string(),
+                "amount_ui": amount_s,
+                "tx": { "tx_base64": tx_b64 },
+                "simulation": {
+                    "err": sim.value.err,
+                    "logs": sim.value.logs,
+                    "units_consumed": sim.value.units_consumed
+                }
+            });
+        }
+
+        // Solana swap_exact_in (sell exact amount) via Jupiter quote+swap + RPC simulate.
+        if intent_value["chain"] == "solana" && intent_value["action"] == "swap_exact_in" {
+            let user = intent_value
+                .get("user_pubkey")
+                .and_then(Value::as_str)
+                .unwrap_or("<sender>");
+            if user.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("user_pubkey (sender) is required for Solana swap workflow"),
+                    data: None,
+                });
+            }
+
+            let input_token = intent_value
+                .get("input_token")
+                .and_then(Value::as_str)
+                .unwrap_or("<sell_token>");
+            let output_token = intent_value
+                .get("output_token")
+                .and_then(Value::as_str)
+                .unwrap_or("<buy_token>");
+            let amount_in_s = intent_value
+                .get("amount_in")
+                .and_then(Value::as_str)
+                .unwrap_or("<amount>");
+            let slippage_bps = intent_value
+                .get("slippage_bps")
+                .and_then(Value::as_u64)
+                .unwrap_or(100);
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            // Resolve input/output tokens to mints.
+            let mut input_token_info: Option<Value> = None;
+            let input_mint: String = if solana_is_pubkey(input_token) {
+                input_token.trim().to_string()
+            } else if let Some(m) = solana_token_to_mint(input_token) {
+                input_token_info = Some(json!({"symbol": input_token, "address": m, "source": "builtin"}));
+                m.to_string()
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(input_token)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("unsupported input_token symbol (provide SPL mint address or known symbol)"),
+                        data: Some(json!({"input_token": input_token})),
+                    })?;
+                input_token_info = Some(json!({
+                    "symbol": found.get("symbol"),
+                    "name": found.get("name"),
+                    "address": found.get("address"),
+                    "decimals": found.get("decimals"),
+                    "source": "jupiter_tokens"
+                }));
+                found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter tokens entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string()
+            };
+
+            let mut output_token_info: Option<Value> = None;
+            let output_mint: String = if solana_is_pubkey(output_token) {
+                output_token.trim().to_string()
+            } else if let Some(m) = solana_token_to_mint(output_token) {
+                output_token_info = Some(json!({"symbol": output_token, "address": m, "source": "builtin"}));
+                m.to_string()
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(output_token)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("unsupported output_token symbol (provide SPL mint address or known symbol)"),
+                        data: Some(json!({"output_token": output_token})),
+                    })?;
+                output_token_info = Some(json!({
+                    "symbol": found.get("symbol"),
+                    "name": found.get("name"),
+                    "address": found.get("address"),
+                    "decimals": found.get("decimals"),
+                    "source": "jupiter_tokens"
+                }));
+                found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter tokens entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string()
+            };
+
+            // Resolve input decimals (for UI amount parsing).
+            let decimals: u8 = if input_mint == "So11111111111111111111111111111111111111112" {
+                9
+            } else if input_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                6
+            } else if let Some(d) = input_token_info
+                .as_ref()
+                .and_then(|v| v.get("decimals"))
+                .and_then(Value::as_u64)
+            {
+                d as u8
+            } else {
+                solana_get_mint_decimals(&rpc, &input_mint).await?
+            };
+
+            let amount_in = parse_amount_to_base_units(amount_in_s, decimals as u32)?;
+
+            let quote = jup_quote(&input_mint, &output_mint, amount_in, slippage_bps).await?;
+            let swap = jup_swap(&quote, user).await?;
+            let tx_b64 = swap
+                .get("swapTransaction")
+                .and_then(Value::as_str)
+                .ok_or_else(|| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from("Jupiter swap response missing swapTransaction"),
+                    data: Some(json!({"swap": swap})),
+                })?;
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            let raw = Self::decode_base64("swapTransaction", tx_b64)?;
+
+            let vtx: solana_sdk::transaction::VersionedTransaction =
+                bincode::deserialize(&raw).map_err(|e| Self::sdk_error("solana_swap:deserialize_tx", e))?;
+
+            let cfg = solana_client::rpc_config::RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(solana_commitment_config::CommitmentConfig::processed()),
+                encoding: Some(solana_transaction_status::UiTransactionEncoding::Base64),
+                accounts: None,
+                min_context_slot: None,
+                inner_instructions: false,
+            };
+
+            let sim = rpc
+                .simulate_transaction_with_config(&vtx, cfg)
+                .await
+                .map_err(|e| Self::sdk_error("solana_swap:simulate", e))?;
+
+            let ok = sim.value.err.is_none();
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": if ok { "ok" } else { "failed" },
+                "simulation_performed": true,
+                "adapter": "jupiter_v6",
+                "network": network,
+                "input_token": input_token,
+                "output_token": output_token,
+                "input_token_info": input_token_info,
+                "output_token_info": output_token_info,
+                "input_mint": input_mint,
+                "output_mint": output_mint,
+                "swap_mode": "ExactIn",
+                "input_decimals": decimals,
+                "amount_in": amount_in.to_string(),
+                "slippage_bps": slippage_bps,
+                "quote": quote,
+                "swap": {
+                    "lastValidBlockHeight": swap.get("lastValidBlockHeight"),
+                    "prioritizationFeeLamports": swap.get("prioritizationFeeLamports"),
+                    "computeUnitLimit": swap.get("computeUnitLimit"),
+                    "tx_base64": tx_b64
+                },
+                "simulation": {
+                    "err": sim.value.err,
+                    "logs": sim.value.logs,
+                    "units_consumed": sim.value.units_consumed
+                },
+                "note": if ok {
+                    "Simulation succeeded. Next step: create a pending confirmation and require explicit confirm_token on mainnet."
+                } else {
+                    "Simulation failed. Do not send; inspect logs/err and adjust params."
+                }
+            });
+        }
+
+        // Solana swap_exact_out (buy exact amount) via Jupiter quote+swap + RPC simulate.
+        if intent_value["chain"] == "solana" && intent_value["action"] == "swap_exact_out" {
+            let user = intent_value
+                .get("user_pubkey")
+                .and_then(Value::as_str)
+                .unwrap_or("<sender>");
+            if user.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("user_pubkey (sender) is required for Solana swap workflow"),
+                    data: None,
+                });
+            }
+
+            let input_token = intent_value
+                .get("input_token")
+                .and_then(Value::as_str)
+                .unwrap_or("<sell_token>");
+            let output_token = intent_value
+                .get("output_token")
+                .and_then(Value::as_str)
+                .unwrap_or("<buy_token>");
+            let amount_out_s = intent_value
+                .get("amount_out")
+                .and_then(Value::as_str)
+                .unwrap_or("<amount>");
+            let slippage_bps = intent_value
+                .get("slippage_bps")
+                .and_then(Value::as_u64)
+                .unwrap_or(100);
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            // Resolve input/output tokens to mints.
+            let mut input_token_info: Option<Value> = None;
+            let input_mint: String = if solana_is_pubkey(input_token) {
+                input_token.trim().to_string()
+            } else if let Some(m) = solana_token_to_mint(input_token) {
+                input_token_info = Some(json!({"symbol": input_token, "address": m, "source": "builtin"}));
+                m.to_string()
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(input_token)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(
+                            "unsupported input_token symbol (provide SPL mint address or known symbol)",
+                        ),
+                        data: Some(json!({"input_token": input_token})),
+                    })?;
+                input_token_info = Some(json!({
+                    "symbol": found.get("symbol"),
+                    "name": found.get("name"),
+                    "address": found.get("address"),
+                    "decimals": found.get("decimals"),
+                    "source": "jupiter_tokens"
+                }));
+                found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter tokens entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string()
+            };
+
+            let mut output_token_info: Option<Value> = None;
+            let output_mint: String = if solana_is_pubkey(output_token) {
+                output_token.trim().to_string()
+            } else if let Some(m) = solana_token_to_mint(output_token) {
+                output_token_info = Some(json!({"symbol": output_token, "address": m, "source": "builtin"}));
+                m.to_string()
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(output_token)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(
+                            "unsupported output_token symbol (provide SPL mint address or known symbol)",
+                        ),
+                        data: Some(json!({"output_token": output_token})),
+                    })?;
+                output_token_info = Some(json!({
+                    "symbol": found.get("symbol"),
+                    "name": found.get("name"),
+                    "address": found.get("address"),
+                    "decimals": found.get("decimals"),
+                    "source": "jupiter_tokens"
+                }));
+                found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter tokens entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string()
+            };
+
+            // Resolve input/output decimals (for UI amount parsing).
+            let input_decimals: u8 = if input_mint == "So11111111111111111111111111111111111111112" {
+                9
+            } else if input_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                6
+            } else if let Some(d) = input_token_info
+                .as_ref()
+                .and_then(|v| v.get("decimals"))
+                .and_then(Value::as_u64)
+            {
+                d as u8
+            } else {
+                solana_get_mint_decimals(&rpc, &input_mint).await?
+            };
+
+            let output_decimals: u8 = if output_mint == "So11111111111111111111111111111111111111112" {
+                9
+            } else if output_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                6
+            } else if let Some(d) = output_token_info
+                .as_ref()
+                .and_then(|v| v.get("decimals"))
+                .and_then(Value::as_u64)
+            {
+                d as u8
+            } else {
+                solana_get_mint_decimals(&rpc, &output_mint).await?
+            };
+
+            let amount_out = parse_amount_to_base_units(amount_out_s, output_decimals as u32)?;
+
+            let quote =
+                jup_quote_exact_out(&input_mint, &output_mint, amount_out, slippage_bps).await?;
+            let swap = jup_swap(&quote, user).await?;
+            let tx_b64 = swap
+                .get("swapTransaction")
+                .and_then(Value::as_str)
+                .ok_or_else(|| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from("Jupiter swap response missing swapTransaction"),
+                    data: Some(json!({"swap": swap})),
+                })?;
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            let raw = Self::decode_base64("swapTransaction", tx_b64)?;
+
+            let vtx: solana_sdk::transaction::VersionedTransaction =
+                bincode::deserialize(&raw).map_err(|e| Self::sdk_error("solana_swap:deserialize_tx", e))?;
+
+            let cfg = solana_client::rpc_config::RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(solana_commitment_config::CommitmentConfig::processed()),
+                encoding: Some(solana_transaction_status::UiTransactionEncoding::Base64),
+                accounts: None,
+                min_context_slot: None,
+                inner_instructions: false,
+            };
+
+            let sim = rpc
+                .simulate_transaction_with_config(&vtx, cfg)
+                .await
+                .map_err(|e| Self::sdk_error("solana_swap:simulate", e))?;
+
+            let ok = sim.value.err.is_none();
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": if ok { "ok" } else { "failed" },
+                "simulation_performed": true,
+                "adapter": "jupiter_v6",
+                "network": network,
+                "swap_mode": "ExactOut",
+                "input_token": input_token,
+                "output_token": output_token,
+                "input_token_info": input_token_info,
+                "output_token_info": output_token_info,
+                "input_mint": input_mint,
+                "output_mint": output_mint,
+                "input_decimals": input_decimals,
+                "output_decimals": output_decimals,
+                "amount_out": amount_out.to_string(),
+                "slippage_bps": slippage_bps,
+                "quote": quote,
+                "swap": {
+                    "lastValidBlockHeight": swap.get("lastValidBlockHeight"),
+                    "prioritizationFeeLamports": swap.get("prioritizationFeeLamports"),
+                    "computeUnitLimit": swap.get("computeUnitLimit"),
+                    "tx_base64": tx_b64
+                },
+                "simulation": {
+                    "err": sim.value.err,
+                    "logs": sim.value.logs,
+                    "units_consumed": sim.value.units_consumed
+                },
+                "note": if ok {
+                    "Simulation succeeded. Next step: create a pending confirmation and require explicit confirm_token on mainnet."
+                } else {
+                    "Simulation failed. Do not send; inspect logs/err and adjust params."
+                }
+            });
+        }
+
+        // Solana read-only quote (no tx built, no simulation).
+        // Today: defaults to Jupiter v6, but is designed to allow other quote adapters later.
+        if intent_value["chain"] == "solana"
+            && (intent_value["action"] == "quote" || intent_value["action"] == "jupiter_quote")
+        {
+            let input_token = intent_value
+                .get("input_token")
+                .and_then(Value::as_str)
+                .unwrap_or("<sell_token>");
+            let output_token = intent_value
+                .get("output_token")
+                .and_then(Value::as_str)
+                .unwrap_or("<buy_token>");
+            let slippage_bps = intent_value
+                .get("slippage_bps")
+                .and_then(Value::as_u64)
+                .unwrap_or(100);
+            let swap_mode = intent_value
+                .get("swap_mode")
+                .and_then(Value::as_str)
+                .unwrap_or("ExactIn");
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            // Resolve input/output tokens to mints.
+            let input_mint: String = if solana_is_pubkey(input_token) {
+                input_token.trim().to_string()
+            } else if let Some(m) = solana_token_to_mint(input_token) {
+                m.to_string()
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(input_token)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("unsupported input_token symbol (provide SPL mint address or known symbol)"),
+                        data: Some(json!({"input_token": input_token})),
+                    })?;
+                found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter tokens entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string()
+            };
+
+            let output_mint: String = if solana_is_pubkey(output_token) {
+                output_token.trim().to_string()
+            } else if let Some(m) = solana_token_to_mint(output_token) {
+                m.to_string()
+            } else {
+                let found = solana_resolve_symbol_via_jupiter_tokens(output_token)
+                    .await?
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("unsupported output_token symbol (provide SPL mint address or known symbol)"),
+                        data: Some(json!({"output_token": output_token})),
+                    })?;
+                found
+                    .get("address")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ErrorData {
+                        code: ErrorCode(-32603),
+                        message: Cow::from("Jupiter tokens entry missing address"),
+                        data: Some(found.clone()),
+                    })?
+                    .to_string()
+            };
+
+            // Determine which amount field we should parse and which decimals apply.
+            let (amount_field, amount_s, decimals) = if swap_mode == "ExactOut" {
+                let s = intent_value
+                    .get("amount_out")
+                    .and_then(Value::as_str)
+                    .unwrap_or("<amount>");
+
+                let out_decimals: u8 = if output_mint == "So11111111111111111111111111111111111111112" {
+                    9
+                } else if output_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                    6
+                } else {
+                    solana_get_mint_decimals(&rpc, &output_mint).await?
+                };
+                ("amount_out", s, out_decimals)
+            } else {
+                let s = intent_value
+                    .get("amount_in")
+                    .and_then(Value::as_str)
+                    .unwrap_or("<amount>");
+
+                let in_decimals: u8 = if input_mint == "So11111111111111111111111111111111111111112" {
+                    9
+                } else if input_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                    6
+                } else {
+                    solana_get_mint_decimals(&rpc, &input_mint).await?
+                };
+                ("amount_in", s, in_decimals)
+            };
+
+            let amount = parse_amount_to_base_units(amount_s, decimals as u32)?;
+
+            let quote = if swap_mode == "ExactOut" {
+                jup_quote_exact_out(&input_mint, &output_mint, amount, slippage_bps).await?
+            } else {
+                jup_quote(&input_mint, &output_mint, amount, slippage_bps).await?
+            };
+
+            let in_amt = quote.get("inAmount").and_then(Value::as_str).unwrap_or("");
+            let out_amt = quote.get("outAmount").and_then(Value::as_str).unwrap_or("");
+            let price_impact = quote.get("priceImpactPct").and_then(Value::as_str);
+            let route_steps = quote
+                .get("routePlan")
+                .and_then(Value::as_array)
+                .map(|a| a.len());
+
+            // Best-effort UI formatting (ExactIn uses input decimals; ExactOut uses output decimals).
+            let input_decimals: u8 = if input_mint == "So11111111111111111111111111111111111111112" {
+                9
+            } else if input_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                6
+            } else {
+                solana_get_mint_decimals(&rpc, &input_mint).await.unwrap_or(0)
+            };
+            let output_decimals: u8 = if output_mint == "So11111111111111111111111111111111111111112" {
+                9
+            } else if output_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                6
+            } else {
+                solana_get_mint_decimals(&rpc, &output_mint).await.unwrap_or(0)
+            };
+
+            let net_s = network.clone().unwrap_or_else(|| "mainnet".to_string());
+            let about_quote = if swap_mode == "ExactOut" {
+                format!(
+                    "Quote (ExactOut): target {} {} (mint {}) with max input {} {} (mint {}) on {} (slippage {} bps, priceImpact {}, routeSteps {:?})",
+                    format_base_units_ui(out_amt, output_decimals),
+                    output_token,
+                    output_mint,
+                    format_base_units_ui(in_amt, input_decimals),
+                    input_token,
+                    input_mint,
+                    net_s,
+                    slippage_bps,
+                    price_impact.unwrap_or("?"),
+                    route_steps
+                )
+            } else {
+                format!(
+                    "Quote (ExactIn): sell {} {} (mint {}) for ~{} {} (mint {}) on {} (slippage {} bps, priceImpact {}, routeSteps {:?})",
+                    format_base_units_ui(in_amt, input_decimals),
+                    input_token,
+                    input_mint,
+                    format_base_units_ui(out_amt, output_decimals),
+                    output_token,
+                    output_mint,
+                    net_s,
+                    slippage_bps,
+                    price_impact.unwrap_or("?"),
+                    route_steps
+                )
+            };
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": "ok",
+                "simulation_performed": true,
+                "adapter": "solana_quote",
+                "quote_adapter": "jupiter_v6",
+                "network": network,
+                "swap_mode": swap_mode,
+                "input_token": input_token,
+                "output_token": output_token,
+                "input_mint": input_mint,
+                "output_mint": output_mint,
+                "input_decimals": input_decimals,
+                "output_decimals": output_decimals,
+                "slippage_bps": slippage_bps,
+                amount_field: amount_s,
+                "amount_base": amount.to_string(),
+                "quote_summary": {
+                    "in_amount_base": in_amt,
+                    "in_amount_ui": if in_amt.is_empty() { Value::Null } else { json!(format_base_units_ui(in_amt, input_decimals)) },
+                    "out_amount_base": out_amt,
+                    "out_amount_ui": if out_amt.is_empty() { Value::Null } else { json!(format_base_units_ui(out_amt, output_decimals)) },
+                    "price_impact_pct": price_impact,
+                    "route_plan_steps": route_steps
+                },
+                "about_quote": about_quote,
+                "quote": quote,
+                "note": "Read-only quote (no transaction built, no broadcast)."
+            });
+        }
+
+        // Solana tx preview: decode a transaction and return a human-readable instruction summary.
+        // Useful for confirm-time verification / prompt-injection resistance.
+        if intent_value["chain"] == "solana" && intent_value["action"] == "tx_preview" {
+            use std::str::FromStr;
+
+            let tx_b64 = intent_value
+                .get("transaction_base64")
+                .and_then(Value::as_str)
+                .unwrap_or("<tx_base64>");
+            if tx_b64.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("transaction_base64 is required"),
+                    data: None,
+                });
+            }
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            let raw = Self::decode_base64("transaction_base64", tx_b64)?;
+
+            // Try decode versioned first, then legacy.
+            let vtx: Option<solana_transaction::versioned::VersionedTransaction> =
+                bincode::deserialize(&raw).ok();
+            let tx: Option<solana_sdk::transaction::Transaction> = if vtx.is_none() {
+                Some(
+                    bincode::deserialize(&raw).map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(format!("invalid transaction bytes: {e}")),
+                        data: None,
+                    })?,
+                )
+            } else {
+                None
+            };
+
+            let mut tx_version: String = "legacy".to_string();
+            let mut account_keys: Vec<solana_sdk::pubkey::Pubkey> = Vec::new();
+            let mut compiled: Vec<solana_message::compiled_instruction::CompiledInstruction> =
+                Vec::new();
+            let mut recent_blockhash: Option<String> = None;
+            let mut lut_lookups: Vec<Value> = Vec::new();
+
+            if let Some(t) = tx.as_ref() {
+                tx_version = "legacy".to_string();
+                account_keys = t.message.account_keys.clone();
+                compiled = t.message.instructions.clone();
+                recent_blockhash = Some(t.message.recent_blockhash.to_string());
+            }
+
+            if let Some(v) = vtx.as_ref() {
+                match &v.message {
+                    solana_message::VersionedMessage::Legacy(msg) => {
+                        tx_version = "legacy".to_string();
+                        account_keys = msg.account_keys.clone();
+                        compiled = msg.instructions.clone();
+                        recent_blockhash = Some(msg.recent_blockhash.to_string());
+                    }
+                    solana_message::VersionedMessage::V0(msg) => {
+                        tx_version = "v0".to_string();
+                        account_keys = msg.account_keys.clone();
+                        recent_blockhash = Some(msg.recent_blockhash.to_string());
+
+                        // Best-effort resolve LUT addresses so we can map instruction account indexes.
+                        let mut loaded_writable: Vec<solana_sdk::pubkey::Pubkey> = Vec::new();
+                        let mut loaded_readonly: Vec<solana_sdk::pubkey::Pubkey> = Vec::new();
+
+                        for l in &msg.address_table_lookups {
+                            let lut_addr = l.account_key;
+                            let mut resolved: Vec<solana_sdk::pubkey::Pubkey> = Vec::new();
+                            let mut err: Option<String> = None;
+
+                            match rpc.get_account(&lut_addr).await {
+                                Ok(a) => {
+                                    match solana_address_lookup_table_interface::state::AddressLookupTable::deserialize(&a.data) {
+                                        Ok(alt) => {
+                                            resolved = alt.addresses.to_vec();
+                                        }
+                                        Err(e) => err = Some(format!("ALT deserialize failed: {e}")),
+                                    }
+                                }
+                                Err(e) => err = Some(format!("ALT fetch failed: {e}")),
+                            }
+
+                            let w: Vec<solana_sdk::pubkey::Pubkey> = l
+                                .writable_indexes
+                                .iter()
+                                .filter_map(|i| resolved.get(*i as usize).cloned())
+                                .collect();
+                            let r: Vec<solana_sdk::pubkey::Pubkey> = l
+                                .readonly_indexes
+                                .iter()
+                                .filter_map(|i| resolved.get(*i as usize).cloned())
+                                .collect();
+
+                            loaded_writable.extend(w.iter().cloned());
+                            loaded_readonly.extend(r.iter().cloned());
+
+                            lut_lookups.push(json!({
+                                "address": lut_addr.to_string(),
+                                "writable_indexes_len": l.writable_indexes.len(),
+                                "readonly_indexes_len": l.readonly_indexes.len(),
+                                "resolved_writable_len": w.len(),
+                                "resolved_readonly_len": r.len(),
+                                "error": err
+                            }));
+                        }
+
+                        account_keys.extend(loaded_writable);
+                        account_keys.extend(loaded_readonly);
+                        compiled = msg.instructions.clone();
+                    }
+                }
+            }
+
+            let fee_payer = account_keys.first().map(|p| p.to_string());
+
+            fn decode_spl_token_ix(data: &[u8]) -> Option<Value> {
+                // Best-effort decode common SPL Token instructions.
+                // Reference: spl_token::instruction::TokenInstruction discriminants.
+                if data.is_empty() {
+                    return None;
+                }
+
+                let tag = data[0];
+
+                // helpers
+                fn le_u64(bytes: &[u8]) -> Option<u64> {
+                    if bytes.len() != 8 {
+                        return None;
+                    }
+                    let mut b = [0u8; 8];
+                    b.copy_from_slice(bytes);
+                    Some(u64::from_le_bytes(b))
+                }
+
+                match tag {
+                    // Transfer
+                    3 if data.len() == 9 => le_u64(&data[1..9]).map(|amount| {
+                        json!({"kind": "token_transfer", "amount": amount})
+                    }),
+
+                    // Approve
+                    4 if data.len() == 9 => le_u64(&data[1..9]).map(|amount| {
+                        json!({"kind": "token_approve", "amount": amount})
+                    }),
+
+                    // Revoke
+                    5 if data.len() == 1 => Some(json!({"kind": "token_revoke"})),
+
+                    // MintTo
+                    7 if data.len() == 9 => le_u64(&data[1..9]).map(|amount| {
+                        json!({"kind": "token_mint_to", "amount": amount})
+                    }),
+
+                    // Burn
+                    8 if data.len() == 9 => le_u64(&data[1..9]).map(|amount| {
+                        json!({"kind": "token_burn", "amount": amount})
+                    }),
+
+                    // CloseAccount
+                    9 if data.len() == 1 => Some(json!({"kind": "token_close_account"})),
+
+                    // TransferChecked
+                    12 if data.len() == 10 => {
+                        let amount = le_u64(&data[1..9])?;
+                        let decimals = data[9];
+                        Some(json!({
+                            "kind": "token_transfer_checked",
+                            "amount": amount,
+                            "decimals": decimals
+                        }))
+                    }
+
+                    // ApproveChecked
+                    13 if data.len() == 10 => {
+                        let amount = le_u64(&data[1..9])?;
+                        let decimals = data[9];
+                        Some(json!({
+                            "kind": "token_approve_checked",
+                            "amount": amount,
+                            "decimals": decimals
+                        }))
+                    }
+
+                    // MintToChecked
+                    14 if data.len() == 10 => {
+                        let amount = le_u64(&data[1..9])?;
+                        let decimals = data[9];
+                        Some(json!({
+                            "kind": "token_mint_to_checked",
+                            "amount": amount,
+                            "decimals": decimals
+                        }))
+                    }
+
+                    // BurnChecked
+                    15 if data.len() == 10 => {
+                        let amount = le_u64(&data[1..9])?;
+                        let decimals = data[9];
+                        Some(json!({
+                            "kind": "token_burn_checked",
+                            "amount": amount,
+                            "decimals": decimals
+                        }))
+                    }
+
+                    // SyncNative
+                    17 if data.len() == 1 => Some(json!({"kind": "token_sync_native"})),
+
+                    _ => None,
+                }
+            }
+
+            fn decode_system_transfer(data: &[u8]) -> Option<u64> {
+                // SystemProgram Transfer: tag(2 u32 LE) + lamports(u64 LE)
+                if data.len() == 12 {
+                    let mut tag = [0u8; 4];
+                    tag.copy_from_slice(&data[0..4]);
+                    let ix_tag = u32::from_le_bytes(tag);
+                    if ix_tag != 2 {
+                        return None;
+                    }
+                    let mut amt = [0u8; 8];
+                    amt.copy_from_slice(&data[4..12]);
+                    return Some(u64::from_le_bytes(amt));
+                }
+                None
+            }
+
+            fn decode_compute_budget_ix(data: &[u8]) -> Option<Value> {
+                // Best-effort decode a subset of ComputeBudget instructions.
+                if data.is_empty() {
+                    return None;
+                }
+                match data[0] {
+                    // SetComputeUnitLimit(u32)
+                    2 if data.len() == 5 => {
+                        let mut v = [0u8; 4];
+                        v.copy_from_slice(&data[1..5]);
+                        Some(json!({"kind": "set_compute_unit_limit", "units": u32::from_le_bytes(v)}))
+                    }
+                    // SetComputeUnitPrice(u64)
+                    3 if data.len() == 9 => {
+                        let mut v = [0u8; 8];
+                        v.copy_from_slice(&data[1..9]);
+                        Some(json!({"kind": "set_compute_unit_price", "micro_lamports": u64::from_le_bytes(v)}))
+                    }
+                    _ => None,
+                }
+            }
+
+            let system_program = solana_sdk::pubkey::Pubkey::from_str(
+                "11111111111111111111111111111111",
+            )
+            .unwrap();
+            let ata_program = spl_associated_token_account::id();
+            let token_legacy = solana_sdk::pubkey::Pubkey::from_str(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            )
+            .ok();
+            let token_2022 = solana_sdk::pubkey::Pubkey::from_str(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+            )
+            .ok();
+            let compute_budget = solana_sdk::pubkey::Pubkey::from_str(
+                "ComputeBudget111111111111111111111111111111",
+            )
+            .unwrap();
+            let memo_program = solana_sdk::pubkey::Pubkey::from_str(
+                "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+            )
+            .ok();
+
+            let mut instructions: Vec<Value> = Vec::new();
+            for (i, ix) in compiled.iter().enumerate() {
+                let program_id = account_keys.get(ix.program_id_index as usize).cloned();
+                let program_id = program_id.unwrap_or_else(solana_sdk::pubkey::Pubkey::new_unique);
+
+                // Resolve instruction account indexes to pubkeys.
+                let accounts: Vec<String> = ix
+                    .accounts
+                    .iter()
+                    .filter_map(|idx| account_keys.get(*idx as usize).map(|p| p.to_string()))
+                    .collect();
+
+                // Per-account meta flags (best-effort): fee payer + whether resolved via LUT (v0 only).
+                // v0 account layout: static keys then loaded_writable then loaded_readonly.
+                let static_len = if tx_version == "v0" {
+                    if let Some(v) = vtx.as_ref() {
+                        match &v.message {
+                            solana_message::VersionedMessage::V0(msg) => msg.account_keys.len(),
+                            solana_message::VersionedMessage::Legacy(msg) => msg.account_keys.len(),
+                        }
+                    } else {
+                        account_keys.len()
+                    }
+                } else {
+                    account_keys.len()
+                };
+                let loaded_start = static_len;
+
+                let account_metas: Vec<Value> = ix
+                    .accounts
+                    .iter()
+                    .filter_map(|idx| {
+                        let i = *idx as usize;
+                        let pk = account_keys.get(i)?;
+                        Some(json!({
+                            "index": i,
+                            "pubkey": pk.to_string(),
+                            "is_fee_payer": i == 0,
+                            "is_lut_loaded": tx_version == "v0" && i >= loaded_start
+                        }))
+                    })
+                    .collect();
+
+                let program_kind = if program_id == system_program {
+                    "system"
+                } else if program_id == ata_program {
+                    "associated_token_account"
+                } else if token_legacy.as_ref() == Some(&program_id) {
+                    "spl_token"
+                } else if token_2022.as_ref() == Some(&program_id) {
+                    "spl_token_2022"
+                } else if program_id == compute_budget {
+                    "compute_budget"
+                } else if memo_program.as_ref() == Some(&program_id) {
+                    "memo"
+                } else {
+                    "unknown"
+                };
+
+                let mut decoded: Option<Value> = None;
+                if program_kind == "system" {
+                    // accounts[0]=from, accounts[1]=to
+                    if let Some(lamports) = decode_system_transfer(&ix.data) {
+                        decoded = Some(json!({
+                            "kind": "system_transfer",
+                            "from": accounts.get(0),
+                            "to": accounts.get(1),
+                            "lamports": lamports,
+                            "sol_ui": format_base_units_ui(&lamports.to_string(), 9)
+                        }));
+                    }
+                } else if program_kind == "associated_token_account" {
+                    // Typical ATA create: [payer, ata, owner, mint, system, token, rent]
+                    // Variants exist (incl token-2022); so we annotate best-effort.
+                    decoded = Some(json!({
+                        "kind": "create_associated_token_account",
+                        "payer": accounts.get(0),
+                        "ata": accounts.get(1),
+                        "owner": accounts.get(2),
+                        "mint": accounts.get(3),
+                        "token_program": accounts.get(5),
+                        "accounts": accounts,
+                        "note": "ATA create detected (best-effort; account order may vary by variant)"
+                    }));
+                } else if program_kind == "spl_token" || program_kind == "spl_token_2022" {
+                    decoded = decode_spl_token_ix(&ix.data);
+                    if let Some(d) = decoded.as_mut() {
+                        // Attach best-effort account roles for common SPL token instructions.
+                        let kind = d.get("kind").and_then(Value::as_str).unwrap_or("");
+                        let roles: Option<Value> = match kind {
+                            "token_transfer" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "destination": accounts.get(1),
+                                    "authority": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_transfer_checked" => {
+                                let signers: Vec<String> = accounts.iter().skip(4).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "mint": accounts.get(1),
+                                    "destination": accounts.get(2),
+                                    "authority": accounts.get(3),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_approve" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "delegate": accounts.get(1),
+                                    "owner": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_approve_checked" => {
+                                let signers: Vec<String> = accounts.iter().skip(4).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "mint": accounts.get(1),
+                                    "delegate": accounts.get(2),
+                                    "owner": accounts.get(3),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_revoke" => {
+                                let signers: Vec<String> = accounts.iter().skip(2).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "owner": accounts.get(1),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_mint_to" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "mint": accounts.get(0),
+                                    "destination": accounts.get(1),
+                                    "authority": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_mint_to_checked" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "mint": accounts.get(0),
+                                    "destination": accounts.get(1),
+                                    "authority": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_burn" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "mint": accounts.get(1),
+                                    "authority": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_burn_checked" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "source": accounts.get(0),
+                                    "mint": accounts.get(1),
+                                    "authority": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_close_account" => {
+                                let signers: Vec<String> = accounts.iter().skip(3).cloned().collect();
+                                Some(json!({
+                                    "account": accounts.get(0),
+                                    "destination": accounts.get(1),
+                                    "owner": accounts.get(2),
+                                    "signers": if signers.is_empty() { Value::Null } else { json!(signers) }
+                                }))
+                            }
+                            "token_sync_native" => Some(json!({
+                                "account": accounts.get(0)
+                            })),
+                            _ => None,
+                        };
+
+                        if let Some(r) = roles {
+                            if let Value::Object(obj) = d {
+                                obj.insert("roles".to_string(), r);
+                            }
+                        }
+                    }
+                } else if program_kind == "compute_budget" {
+                    decoded = decode_compute_budget_ix(&ix.data);
+                }
+
+                instructions.push(json!({
+                    "index": i,
+                    "program_id": program_id.to_string(),
+                    "program_kind": program_kind,
+                    "program_id_index": ix.program_id_index,
+                    "accounts": accounts,
+                    "account_metas": account_metas,
+                    "data_len": ix.data.len(),
+                    "decoded": decoded
+                }));
+            }
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": "ok",
+                "simulation_performed": true,
+                "adapter": "solana_tx_preview",
+                "network": network,
+                "tx_version": tx_version,
+                "fee_payer": fee_payer,
+                "recent_blockhash": recent_blockhash,
+                "account_keys_len": account_keys.len(),
+                "lut_lookups": lut_lookups,
+                "instructions": instructions,
+                "note": "Read-only tx preview (no broadcast)."
+            });
+        }
+
+        // Solana read-only token balance: owner + (symbol|mint) -> aggregated amount.
+        if intent_value["chain"] == "solana" && intent_value["action"] == "get_token_balance" {
+            use std::str::FromStr;
+
+            let owner = intent_value
+                .get("owner")
+                .and_then(Value::as_str)
+                .unwrap_or("<owner>");
+            if owner.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("owner is required"),
+                    data: None,
+                });
+            }
+
+            let symbol = intent_value.get("symbol").and_then(Value::as_str).map(|s| s.to_string());
+            let mint_in = intent_value.get("mint").and_then(Value::as_str).map(|s| s.to_string());
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+
+            let owner_pk = solana_sdk::pubkey::Pubkey::from_str(owner).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid owner pubkey: {e}")),
+                data: Some(json!({"owner": owner})),
+            })?;
+
+            let mint: String = if let Some(m) = mint_in {
+                m
+            } else if let Some(sym) = symbol.as_ref() {
+                if let Some(m) = solana_token_to_mint(sym) {
+                    m.to_string()
+                } else {
+                    let found = solana_resolve_symbol_via_jupiter_tokens(sym)
+                        .await?
+                        .ok_or_else(|| ErrorData {
+                            code: ErrorCode(-32602),
+                            message: Cow::from("unsupported symbol (provide mint)"),
+                            data: Some(json!({"symbol": sym})),
+                        })?;
+                    found
+                        .get("address")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| ErrorData {
+                            code: ErrorCode(-32603),
+                            message: Cow::from("Jupiter tokens entry missing address"),
+                            data: Some(found.clone()),
+                        })?
+                        .to_string()
+                }
+            } else {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("symbol or mint is required"),
+                    data: None,
+                });
+            };
+
+            if mint == "So11111111111111111111111111111111111111112" {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("use get_portfolio for SOL balance"),
+                    data: Some(json!({"mint": mint})),
+                });
+            }
+
+            let mint_pk = solana_sdk::pubkey::Pubkey::from_str(&mint).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid mint pubkey: {e}")),
+                data: Some(json!({"mint": mint})),
+            })?;
+
+            let commitment = solana_commitment_config::CommitmentConfig::confirmed();
+            let res = rpc
+                .get_token_accounts_by_owner_with_commitment(
+                    &owner_pk,
+                    solana_client::rpc_request::TokenAccountsFilter::Mint(mint_pk),
+                    commitment,
+                )
+                .await
+                .map_err(|e| Self::sdk_error("solana_token_balance:get_token_accounts", e))?;
+
+            let mut total_raw: u128 = 0;
+            let mut decimals: Option<u8> = None;
+            let mut accounts: Vec<Value> = Vec::new();
+
+            for keyed in &res.value {
+                let v = serde_json::to_value(keyed).unwrap_or(Value::Null);
+                let ta = v
+                    .pointer("/account/data/parsed/info/tokenAmount")
+                    .cloned()
+                    .unwrap_or(Value::Null);
+                let amount_raw = ta
+                    .get("amount")
+                    .and_then(|x| x.as_str())
+                    .and_then(|s| s.parse::<u128>().ok())
+                    .unwrap_or(0);
+                let dec = ta.get("decimals").and_then(|x| x.as_u64()).map(|d| d as u8);
+                if decimals.is_none() {
+                    decimals = dec;
+                }
+                if amount_raw > 0 {
+                    total_raw += amount_raw;
+                    accounts.push(json!({
+                        "pubkey": v.get("pubkey"),
+                        "amount_raw": amount_raw.to_string(),
+                        "decimals": dec,
+                        "ui_amount": ta.get("uiAmountString")
+                    }));
+                }
+            }
+
+            let d: u8 = if let Some(d) = decimals {
+                d
+            } else {
+                solana_get_mint_decimals(&rpc, &mint).await.unwrap_or(0)
+            };
+            let total_ui = format_base_units_ui(&total_raw.to_string(), d);
+
+            let token_by_mint = solana_jupiter_token_map().await.unwrap_or_default();
+            let tok = token_by_mint.get(&mint).cloned();
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": "ok",
+                "simulation_performed": true,
+                "adapter": "solana_token_balance",
+                "network": network,
+                "owner": owner,
+                "symbol": symbol,
+                "mint": mint,
+                "token_info": tok,
+                "decimals": d,
+                "total_amount_raw": total_raw.to_string(),
+                "total_amount_ui": total_ui,
+                "accounts": accounts,
+                "note": "Read-only token balance (no transaction will be broadcast)."
+            });
+        }
+
+        // Solana read-only portfolio: SOL balance + token accounts (best effort).
+        if intent_value["chain"] == "solana" && intent_value["action"] == "get_portfolio" {
+            let owner = intent_value
+                .get("owner")
+                .and_then(Value::as_str)
+                .unwrap_or("<owner>");
+
+            // Optional filter: symbol or mint.
+            let filter_symbol = intent_value.get("symbol").and_then(Value::as_str).map(|s| s.to_string());
+            let filter_mint = intent_value.get("mint").and_then(Value::as_str).map(|s| s.to_string());
+            if owner.starts_with('<') {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("owner is required"),
+                    data: None,
+                });
+            }
+
+            let network = solana_network_from_intent(&intent_value);
+            let rpc = Self::solana_rpc(network.as_deref())?;
+            let owner_pk = solana_sdk::pubkey::Pubkey::from_str(owner).map_err(|e| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("invalid owner pubkey: {e}")),
+                data: Some(json!({"owner": owner})),
+            })?;
+
+            let lamports = rpc
+                .get_balance(&owner_pk)
+                .await
+                .map_err(|e| Self::sdk_error("solana_portfolio:get_balance", e))?;
+
+            let token_programs = vec![
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+            ];
+
+            let token_by_mint = solana_jupiter_token_map().await.unwrap_or_default();
+            let resolved_filter_mint: Option<String> = if let Some(m) = filter_mint.clone() {
+                Some(m)
+            } else if let Some(sym) = filter_symbol.clone() {
+                // Best-effort symbol->mint via Jupiter list (verified) with ambiguity protection.
+                let found = solana_resolve_symbol_via_jupiter_tokens(&sym).await?;
+                found
+                    .and_then(|v| v.get("address").and_then(Value::as_str).map(|s| s.to_string()))
+            } else {
+                None
+            };
+
+            let mut per_mint: std::collections::HashMap<String, (u128, u8)> =
+                std::collections::HashMap::new();
+            let mut accounts: Vec<Value> = Vec::new();
+
+            for pid in token_programs {
+                let program_id = solana_sdk::pubkey::Pubkey::from_str(pid).map_err(|e| ErrorData {
+                    code: ErrorCode(-32603),
+                    message: Cow::from(format!("invalid token program id: {e}")),
+                    data: Some(json!({"program_id": pid})),
+                })?;
+
+                let res = rpc
+                    .get_token_accounts_by_owner(
+                        &owner_pk,
+                        solana_client::rpc_request::TokenAccountsFilter::ProgramId(program_id),
+                    )
+                    .await
+                    .map_err(|e| Self::sdk_error("solana_portfolio:get_token_accounts", e))?;
+
+                for keyed in res {
+                    let v = serde_json::to_value(&keyed).unwrap_or(Value::Null);
+                    let mint = v
+                        .pointer("/account/data/parsed/info/mint")
+                        .and_then(Value::as_str)
+                        .unwrap_or("")
+                        .to_string();
+                    let ta = v
+                        .pointer("/account/data/parsed/info/tokenAmount")
+                        .cloned()
+                        .unwrap_or(Value::Null);
+                    let amount_raw = ta
+                        .get("amount")
+                        .and_then(|x| x.as_str())
+                        .and_then(|s| s.parse::<u128>().ok())
+                        .unwrap_or(0);
+                    let decimals = ta.get("decimals").and_then(|x| x.as_u64()).map(|d| d as u8).unwrap_or(0);
+                    let ui_amount = ta.get("uiAmountString").and_then(Value::as_str).unwrap_or("0");
+
+                    if !mint.is_empty() && amount_raw > 0 {
+                        if let Some(fm) = resolved_filter_mint.as_ref() {
+                            if fm != &mint {
+                                continue;
+                            }
+                        }
+
+                        per_mint
+                            .entry(mint.clone())
+                            .and_modify(|(a, _d)| *a += amount_raw)
+                            .or_insert((amount_raw, decimals));
+
+                        accounts.push(json!({
+                            "pubkey": v.get("pubkey"),
+                            "mint": mint,
+                            "amount_raw": amount_raw.to_string(),
+                            "decimals": decimals,
+                            "ui_amount": ui_amount,
+                            "program_id": pid
+                        }));
+                    }
+                }
+            }
+
+            let dust_threshold_ui: f64 = crate::utils::config::var("SOLANA_PORTFOLIO_DUST_UI")
+                .ok()
+                .and_then(|s| s.parse::<f64>().ok())
+                .unwrap_or(0.000001);
+
+            let mut holdings: Vec<Value> = per_mint
+                .into_iter()
+                .map(|(mint, (amount_raw, decimals))| {
+                    let amount_ui = format_base_units_ui(&amount_raw.to_string(), decimals);
+                    let token = token_by_mint.get(&mint).cloned();
+                    json!({
+                        "mint": mint,
+                        "amount_raw": amount_raw.to_string(),
+                        "decimals": decimals,
+                        "amount_ui": amount_ui,
+                        "symbol": token.as_ref().and_then(|v| v.get("symbol")).cloned(),
+                        "name": token.as_ref().and_then(|v| v.get("name")).cloned(),
+                        "logo_uri": token.as_ref().and_then(|v| v.get("logoURI")).cloned(),
+                        "token_info": token
+                    })
+                })
+                .filter(|h| {
+                    h.get("amount_ui")
+                        .and_then(Value::as_str)
+                        .and_then(|s| s.parse::<f64>().ok())
+                        .unwrap_or(0.0)
+                        >= dust_threshold_ui
+                })
+                .collect();
+
+            holdings.sort_by(|a, b| {
+                let av = a
+                    .get("amount_ui")
+                    .and_then(Value::as_str)
+                    .and_then(|s| s.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                let bv = b
+                    .get("amount_ui")
+                    .and_then(Value::as_str)
+                    .and_then(|s| s.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                bv.partial_cmp(&av).unwrap_or(std::cmp::Ordering::Equal)
+            });
+
+            // Optionally include SOL as a holding row for UI symmetry.
+            if resolved_filter_mint.is_none() && filter_symbol.is_none() && filter_mint.is_none() {
+                let sol_ui = format_base_units_ui(&lamports.to_string(), 9);
+                holdings.insert(
+                    0,
+                    json!({
+                        "mint": "So11111111111111111111111111111111111111112",
+                        "amount_raw": lamports.to_string(),
+                        "decimals": 9,
+                        "amount_ui": sol_ui,
+                        "symbol": "SOL",
+                        "name": "Solana",
+                        "logo_uri": Value::Null,
+                        "token_info": Value::Null,
+                        "native": true
+                    }),
+                );
+            }
+
+            simulate = json!({
+                "stage": "simulate",
+                "status": "ok",
+                "simulation_performed": true,
+                "adapter": "solana_portfolio",
+                "network": network,
+                "owner": owner,
+                "lamports": lamports,
+                "sol_ui": format_base_units_ui(&lamports.to_string(), 9),
+                "dust_threshold_ui": dust_threshold_ui,
+                "holdings": holdings,
+                "accounts": accounts,
+                "note": "Read-only portfolio snapshot (no transaction will be broadcast)."
+            });
+        }
+
+        Ok(simulate)
+    }
+
+    /// W3RT (Solana): approval policy for a simulate artifact.
+    async fn w3rt_solana_approval(&self, simulate: &Value) -> Result<Value, ErrorData> {
+        use crate::utils::solana_workflow::*;
+
+        let approval = if simulate.get("adapter").and_then(Value::as_str) == Some("jupiter_v6")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            let network = simulate.get("network").and_then(|v| v.as_str()).map(|s| s.to_string());
+            let input_decimals = simulate.get("input_decimals").and_then(Value::as_u64).unwrap_or(0) as u8;
+
+            let output_mint = simulate.get("output_mint").and_then(Value::as_str).unwrap_or("").to_string();
+            let output_decimals: u8 = if output_mint == "So11111111111111111111111111111111111111112" {
+                9
+            } else if output_mint == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" {
+                6
+            } else {
+                match Self::solana_rpc(network.as_deref()) {
+                    Ok(rpc) => solana_get_mint_decimals(&rpc, &output_mint).await.unwrap_or(0),
+                    Err(_) => 0,
+                }
+            };
+
+            let quote = simulate.get("quote").cloned().unwrap_or(Value::Null);
+            let in_amount = quote.get("inAmount").and_then(Value::as_str).unwrap_or("");
+            let out_amount = quote.get("outAmount").and_then(Value::as_str).unwrap_or("");
+            let price_impact_pct = quote.get("priceImpactPct").and_then(Value::as_str);
+
+            // Compute min_out for ExactIn (best-effort) using quote.outAmount and slippage_bps.
+            let mut min_out_base: Option<String> = None;
+            let mut min_out_ui: Option<String> = None;
+            {
+                let slippage_bps = simulate.get("slippage_bps").and_then(Value::as_u64).unwrap_or(100);
+                if let Ok(out_u128) = out_amount.parse::<u128>() {
+                    let factor_num: u128 = 10_000u128.saturating_sub(slippage_bps as u128);
+                    let min_u128 = out_u128.saturating_mul(factor_num) / 10_000u128;
+                    min_out_base = Some(min_u128.to_string());
+                    min_out_ui = Some(format_base_units_ui(&min_u128.to_string(), output_decimals));
+                }
+            }
+
+            // Thresholds (slippage, price impact, route size, ExactOut max-in, ExactIn min-out)
+            // are approval policy rules (src/utils/approval_policy.rs); only hard checks live here.
+            let mut warnings: Vec<Value> = vec![];
+
+            let slippage_bps = simulate.get("slippage_bps").and_then(Value::as_u64).unwrap_or(100);
+            let swap_mode = simulate
+                .get("swap_mode")
+                .and_then(Value::as_str)
+                .unwrap_or("ExactIn");
+
+            // ExactIn: if computed min_out is zero, it's almost certainly not intended.
+            if swap_mode != "ExactOut" && min_out_base.as_deref() == Some("0") {
+                warnings.push(json!({
+                    "kind": "min_out_zero",
+                    "note": "computed min_out is 0 (check quote/outAmount)"
+                }));
+            }
+
+            // Program allow/deny policy (env-configurable).
+            // - Deny list is always enforced if set.
+            // - Allow list is enforced only if set (non-empty). Because Jupiter routes can involve many DEX programs.
+            let policy = crate::utils::solana_policy::load_solana_confirm_policy();
+
+            let deny_set: std::collections::HashSet<String> = policy
+                .program_policy
+                .deny
+                .iter()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect();
+            let allow_set: std::collections::HashSet<String> = policy
+                .program_policy
+                .allow
+                .iter()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect();
+
+            if !deny_set.is_empty() || !allow_set.is_empty() {
+                let tx_b64 = simulate
+                    .get("swap")
+                    .and_then(|s| s.get("tx_base64"))
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+
+                if !tx_b64.is_empty() {
+                    let raw = Self::decode_base64("swap.tx_base64", tx_b64)?;
+                    let vtx: Option<solana_transaction::versioned::VersionedTransaction> =
+                        bincode::deserialize(&raw).ok();
+                    let tx: Option<solana_sdk::transaction::Transaction> = if vtx.is_none() {
+                        bincode::deserialize(&raw).ok()
+                    } else {
+                        None
+                    };
+
+                    let mut account_keys: Vec<solana_sdk::pubkey::Pubkey> = Vec::new();
+                    let mut ins: Vec<solana_message::compiled_instruction::CompiledInstruction> =
+                        Vec::new();
+
+                    if let Some(t) = tx.as_ref() {
+                        account_keys = t.message.account_keys.clone();
+                        ins = t.message.instructions.clone();
+                    }
+                    if let Some(v) = vtx.as_ref() {
+                        match &v.message {
+                            solana_message::VersionedMessage::Legacy(msg) => {
+                                account_keys = msg.account_keys.clone();
+                                ins = msg.instructions.clone();
+                            }
+                            solana_message::VersionedMessage::V0(msg) => {
+                                account_keys = msg.account_keys.clone();
+                                ins = msg.instructions.clone();
+                            }
+                        }
+                    }
+
+                    let mut programs_used: std::collections::HashSet<String> = std::collections::HashSet::new();
+                    for ix in &ins {
+                        if let Some(pid) = account_keys.get(ix.program_id_index as usize) {
+                            programs_used.insert(pid.to_string());
+                        }
+                    }
+
+                    // Deny always wins.
+                    let denied: Vec<String> = programs_used
+                        .iter()
+                        .filter(|p| deny_set.contains(*p))
+                        .cloned()
+                        .collect();
+                    if !denied.is_empty() {
+                        warnings.push(json!({
+                            "kind": "program_denied",
+                            "programs": denied,
+                            "note": "transaction touches a denied program id"
+                        }));
+                    }
+
+                    // Allowlist: if set, any unknown program triggers review.
+                    if !allow_set.is_empty() {
+                        let not_allowed: Vec<String> = programs_used
+                            .iter()
+                            .filter(|p| !allow_set.contains(*p))
+                            .cloned()
+                            .collect();
+                        if !not_allowed.is_empty() {
+                            warnings.push(json!({
+                                "kind": "program_not_allowed",
+                                "programs": not_allowed,
+                                "note": "transaction touches a program id not in allowlist"
+                            }));
+                        }
+                    }
+                }
+            }
+
+            let has_denied_program = warnings.iter().any(|w| {
+                w.get("kind").and_then(Value::as_str) == Some("program_denied")
+            });
+
+            json!({
+                "stage": "approval",
+                "status": if has_denied_program {
+                    "blocked"
+                } else if warnings.is_empty() {
+                    "ok"
+                } else {
+                    "needs_review"
+                },
+                "network": network,
+                "summary": {
+                    "program_policy": {
+                        "deny": policy.program_policy.deny,
+                        "allow": policy.program_policy.allow
+                    },
+                    "swap_mode": swap_mode,
+                    "slippage_bps": slippage_bps,
+                    "in_amount_base": in_amount,
+                    "in_amount_ui": format_base_units_ui(in_amount, input_decimals),
+                    "out_amount_base": out_amount,
+                    "out_amount_ui": format_base_units_ui(out_amount, output_decimals),
+                    "min_out_base": min_out_base,
+                    "min_out_ui": min_out_ui,
+                    "input_decimals": input_decimals,
+                    "output_decimals": output_decimals,
+                    "price_impact_pct": price_impact_pct,
+                    "route_plan_steps": quote.get("routePlan").and_then(Value::as_array).map(|a| a.len()),
+                    "exact_out": if swap_mode == "ExactOut" {
+                        json!({
+                            "target_out_amount_base": out_amount,
+                            "target_out_amount_ui": format_base_units_ui(out_amount, output_decimals),
+                            "max_in_amount_base": in_amount,
+                            "max_in_amount_ui": format_base_units_ui(in_amount, input_decimals)
+                        })
+                    } else {
+                        Value::Null
+                    }
+                },
+                "warnings": warnings,
+                "note": "Hard checks above; threshold rules come from the approval policy. Execution uses safe default (pending confirmation)."
+            })
+        } else if simulate.get("adapter").and_then(Value::as_str) == Some("solana_system_transfer")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            // Large-transfer thresholds are approval policy rules (builtin.transfer.large_native).
+            let amount_ui = simulate.get("amount_ui").and_then(Value::as_str).unwrap_or("0");
+            let mut warnings: Vec<Value> = vec![];
+
+            let from = simulate.get("from").and_then(Value::as_str).unwrap_or("");
+            let to = simulate.get("to").and_then(Value::as_str).unwrap_or("");
+            if !from.is_empty() && from == to {
+                warnings.push(json!({
+                    "kind": "self_transfer",
+                    "note": "from == to"
+                }));
+            }
+
+            json!({
+                "stage": "approval",
+                "status": if warnings.is_empty() { "ok" } else { "needs_review" },
+                "network": simulate.get("network"),
+                "warnings": warnings,
+                "summary": {
+                    "adapter": simulate.get("adapter"),
+                    "from": from,
+                    "to": to,
+                    "amount_ui": amount_ui,
+                    "lamports": simulate.get("lamports"),
+                    "simulation_units": simulate.get("simulation").and_then(|v| v.get("units_consumed"))
+                },
+                "note": "Native SOL transfer. Execution uses safe default (pending confirmation)."
+            })
+        } else if simulate.get("adapter").and_then(Value::as_str) == Some("solana_quote")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            let quote = simulate.get("quote").cloned().unwrap_or(Value::Null);
+            json!({
+                "stage": "approval",
+                "status": "ok",
+                "network": simulate.get("network"),
+                "warnings": [],
+                "summary": {
+                    "adapter": simulate.get("adapter"),
+                    "quote_adapter": simulate.get("quote_adapter"),
+                    "swap_mode": simulate.get("swap_mode"),
+                    "input_mint": simulate.get("input_mint"),
+                    "output_mint": simulate.get("output_mint"),
+                    "price_impact_pct": quote.get("priceImpactPct"),
+                    "route_plan_steps": quote.get("routePlan").and_then(Value::as_array).map(|a| a.len()),
+                },
+                "note": "Read-only quote; no approval required."
+            })
+        } else if simulate.get("adapter").and_then(Value::as_str) == Some("solana_tx_preview")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            let ins_n = simulate
+                .get("instructions")
+                .and_then(|v| v.as_array())
+                .map(|a| a.len())
+                .unwrap_or(0);
+            json!({
+                "stage": "approval",
+                "status": "ok",
+                "network": simulate.get("network"),
+                "warnings": [],
+                "summary": {
+                    "adapter": simulate.get("adapter"),
+                    "tx_version": simulate.get("tx_version"),
+                    "fee_payer": simulate.get("fee_payer"),
+                    "instructions_count": ins_n
+                },
+                "note": "Read-only request; no approval required."
+            })
+        } else if simulate.get("adapter").and_then(Value::as_str) == Some("solana_token_balance")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            json!({
+                "stage": "approval",
+                "status": "ok",
+                "network": simulate.get("network"),
+                "warnings": [],
+                "summary": {
+                    "adapter": simulate.get("adapter"),
+                    "owner": simulate.get("owner"),
+                    "symbol": simulate.get("symbol"),
+                    "mint": simulate.get("mint"),
+                    "total_amount_ui": simulate.get("total_amount_ui")
+                },
+                "note": "Read-only request; no approval required."
+            })
+        } else if simulate.get("adapter").and_then(Value::as_str) == Some("solana_portfolio")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            let holdings_n = simulate
+                .get("holdings")
+                .and_then(|v| v.as_array())
+                .map(|a| a.len())
+                .unwrap_or(0);
+            json!({
+                "stage": "approval",
+                "status": "ok",
+                "network": simulate.get("network"),
+                "warnings": [],
+                "summary": {
+                    "adapter": simulate.get("adapter"),
+                    "owner": simulate.get("owner"),
+                    "sol_ui": simulate.get("sol_ui"),
+                    "holdings_count": holdings_n
+                },
+                "note": "Read-only request; no approval required."
+            })
+        } else if simulate.get("adapter").and_then(Value::as_str) == Some("solana_spl_transfer")
+            && simulate.get("status").and_then(Value::as_str) == Some("ok")
+        {
+            // Large-transfer thresholds are approval policy rules (builtin.transfer.large_spl).
+            let amount_ui = simulate.get("amount_ui").and_then(Value::as_str).unwrap_or("0");
+            let asset = simulate.get("asset").and_then(Value::as_str).unwrap_or("spl");
+            let mut warnings: Vec<Value> = vec![];
+
+            let from = simulate.get("from").and_then(Value::as_str).unwrap_or("");
+            let to = simulate.get("to").and_then(Value::as_str).unwrap_or("");
+            if !from.is_empty() && from == to {
+                warnings.push(json!({ "kind": "self_transfer", "note": "from == to" }));
+            }
+
+            json!({
+                "stage": "approval",
+                "status": if warnings.is_empty() { "ok" } else { "needs_review" },
+                "network": simulate.get("network"),
+                "warnings": warnings,
+                "summary": {
+                    "adapter": simulate.get("adapter"),
+                    "asset": asset,
+                    "mint": simulate.get("mint"),
+                    "decimals": simulate.get("decimals"),
+                    "token_program": simulate.get("token_program"),
+                    "token_info": simulate.get("token_info"),
+                    "from": from,
+                    "to": to,
+                    "from_ata": simulate.get("from_ata"),
+                    "to_ata": simulate.get("to_ata"),
+                    "to_ata_exists": simulate.get("to_ata_exists"),
+                    "will_create_ata": simulate.get("will_create_ata"),
+                    "amount_ui": amount_ui,
+                    "amount_base": simulate.get("amount_base"),
+                    "simulation_units": simulate.get("simulation").and_then(|v| v.get("units_consumed"))
+                },
+                "note": "SPL token transfer. Execution uses safe default (pending confirmation)."
+            })
+        } else {
+            json!({
+                "stage": "approval",
+                "status": "todo",
+                "note": "Approval not implemented for this intent yet."
+            })
+        };
+
+        Ok(approval)
+    }
+
+    /// W3RT (Solana): create the pending confirmation (or echo read-only results) once guards have passed.
+    async fn w3rt_solana_execute(
+        &self,
+        run_id: &str,
+        intent_value: &Value,
+        simulate: &Value,
+        approval: &Value,
+    ) -> Result<Value, ErrorData> {
+        use crate::utils::solana_workflow::format_base_units_ui;
+
+        let execute = if intent_value["chain"] == "solana"
+            && (intent_value["action"] == "quote"
+                || intent_value["action"] == "jupiter_quote"
+                || intent_value["action"] == "get_portfolio"
+                || intent_value["action"] == "get_token_balance"
+                || intent_value["action"] == "tx_preview")
+        {
+            json!({
+                "stage": "execute",
+                "status": "ok",
+                "network": simulate.get("network"),
+                "result": simulate,
+                "note": "Read-only request; no execution step required."
+            })
+        } else if intent_value["chain"] == "solana" && intent_value["action"] == "transfer_native" {
+
+            // Sign locally (requires SOLANA_KEYPAIR_PATH) and create pending confirmation.
+            let tx_b64 = simulate
+                .get("tx")
+                .and_then(|v| v.get("tx_base64"))
+                .and_then(Value::as_str)
+                .unwrap_or("");
+
+            if tx_b64.is_empty() {
+                json!({
+                    "stage": "execute",
+                    "status": "error",
+                    "note": "missing tx_base64 from simulate stage"
+                })
+            } else {
+                // Load the configured signer and sign.
+                let kp = Self::solana_signer(simulate.get("network").and_then(Value::as_str)).await?;
+                let kp_pubkey = Self::solana_signer_pubkey(kp.as_ref())?;
+
+                let from = simulate.get("from").and_then(Value::as_str).unwrap_or("");
+                if !from.is_empty() {
+                    let from_pk = solana_sdk::pubkey::Pubkey::from_str(from).map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(format!("invalid from pubkey: {e}")),
+                        data: Some(json!({"from": from})),
+                    })?;
+                    if kp_pubkey != from_pk {
+                        return Err(ErrorData {
+                            code: ErrorCode(-32602),
+                            message: Cow::from("Solana signer pubkey does not match sender"),
+                            data: Some(json!({"sender": from_pk.to_string(), "keypair_pubkey": kp_pubkey.to_string()})),
+                        });
+                    }
+                }
+
+                let network = simulate.get("network").and_then(|v| v.as_str()).map(|s| s.to_string());
+                let rpc = Self::solana_rpc(network.as_deref())?;
+                let bh = rpc.get_latest_blockhash().await.map_err(|e| Self::sdk_error("solana_transfer:get_latest_blockhash", e))?;
+
+                let raw = base64::engine::general_purpose::STANDARD
+                    .decode(tx_b64.trim())
+                    .map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(format!("invalid tx_base64: {e}")),
+                        data: None,
+                    })?;
+
+                let mut tx = bincode::deserialize::<solana_sdk::transaction::Transaction>(&raw)
+                    .map_err(|e| Self::sdk_error("solana_transfer:deserialize_tx", e))?;
+                Self::solana_sign_legacy(&mut tx, kp.as_ref(), bh).await?;
+
+                let bytes = bincode::serialize(&tx).map_err(|e| Self::sdk_error("solana_transfer:serialize_tx", e))?;
+                let signed_b64 = base64::engine::general_purpose::STANDARD.encode(bytes);
+
+                let summary = Some(json!({
+                    "tool": "w3rt_run_workflow_v0",
+                    "run_id": run_id,
+                    "adapter": simulate.get("adapter"),
+                    "approval": approval,
+                    "intent": {
+                        "chain": intent_value.get("chain"),
+                        "action": intent_value.get("action"),
+                        "asset": intent_value.get("asset"),
+                        "amount": intent_value.get("amount"),
+                        "from": intent_value.get("from"),
+                        "to": intent_value.get("to")
+                    },
+                    "transfer": {
+                        "kind": "native",
+                        "from": simulate.get("from"),
+                        "to": simulate.get("to"),
+                        "amount_ui": simulate.get("amount_ui"),
+                        "lamports": simulate.get("lamports"),
+                        "network": simulate.get("network"),
+                        "simulation_units": simulate.get("simulation").and_then(|v| v.get("units_consumed"))
+                    }
+                }));
+
+                let parsed = Self::solana_create_pending_confirmation(
+                    network.as_deref(),
+                    &signed_b64,
+                    "w3rt_run_workflow_v0",
+                    summary,
+                )?;
+
+                json!({
+                    "stage": "execute",
+                    "status": "pending_confirmation_created",
+                    "network": network,
+                    "approval": approval,
+                    "result": parsed,
+                    "next": parsed.get("next").cloned().unwrap_or(json!({})),
+                    "note": "Pending confirmation created (safe default). On mainnet you must call solana_confirm_transaction with confirm_token to broadcast."
+                })
+            }
+        } else if intent_value["chain"] == "solana" && intent_value["action"] == "transfer_spl" {
+            // Sign locally (requires SOLANA_KEYPAIR_PATH) and create pending confirmation.
+            let tx_b64 = simulate
+                .get("tx")
+                .and_then(|v| v.get("tx_base64"))
+                .and_then(Value::as_str)
+                .unwrap_or("");
+
+            if tx_b64.is_empty() {
+                json!({
+                    "stage": "execute",
+                    "status": "error",
+                    "note": "missing tx_base64 from simulate stage"
+                })
+            } else {
+                let kp = Self::solana_signer(simulate.get("network").and_then(Value::as_str)).await?;
+                let kp_pubkey = Self::solana_signer_pubkey(kp.as_ref())?;
+
+                let from = simulate.get("from").and_then(Value::as_str).unwrap_or("");
+                if !from.is_empty() {
+                    let from_pk = solana_sdk::pubkey::Pubkey::from_str(from).map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(format!("invalid from pubkey: {e}")),
+                        data: Some(json!({"from": from})),
+                    })?;
+                    if kp_pubkey != from_pk {
+                        return Err(ErrorData {
+                            code: ErrorCode(-32602),
+                            message: Cow::from("Solana signer pubkey does not match sender"),
+                            data: Some(json!({"sender": from_pk.to_string(), "keypair_pubkey": kp_pubkey.to_string()})),
+                        });
+                    }
+                }
+
+                let network = simulate.get("network").and_then(|v| v.as_str()).map(|s| s.to_string());
+                let rpc = Self::solana_rpc(network.as_deref())?;
+                let bh = rpc.get_latest_blockhash().await.map_err(|e| Self::sdk_error("solana_spl_transfer:get_latest_blockhash", e))?;
+
+                let raw = base64::engine::general_purpose::STANDARD
+                    .decode(tx_b64.trim())
+                    .map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(format!("invalid tx_base64: {e}")),
+                        data: None,
+                    })?;
+
+                let mut tx = bincode::deserialize::<solana_sdk::transaction::Transaction>(&raw)
+                    .map_err(|e| Self::sdk_error("solana_spl_transfer:deserialize_tx", e))?;
+                Self::solana_sign_legacy(&mut tx, kp.as_ref(), bh).await?;
+
+                let bytes = bincode::serialize(&tx).map_err(|e| Self::sdk_error("solana_spl_transfer:serialize_tx", e))?;
+                let signed_b64 = base64::engine::general_purpose::STANDARD.encode(bytes);
+
+                let summary = Some(json!({
+                    "tool": "w3rt_run_workflow_v0",
+                    "run_id": run_id,
+                    "adapter": simulate.get("adapter"),
+                    "approval": approval,
+                    "intent": {
+                        "chain": intent_value.get("chain"),
+                        "action": intent_value.get("action"),
+                        "asset": intent_value.get("asset"),
+                        "amount": intent_value.get("amount"),
+                        "from": intent_value.get("from"),
+                        "to": intent_value.get("to")
+                    },
+                    "transfer": {
+                        "kind": "spl",
+                        "asset": simulate.get("asset"),
+                        "mint": simulate.get("mint"),
+                        "decimals": simulate.get("decimals"),
+                        "token_program": simulate.get("token_program"),
+                        "token_info": simulate.get("token_info"),
+                        "from": simulate.get("from"),
+                        "to": simulate.get("to"),
+                        "from_ata": simulate.get("from_ata"),
+                        "to_ata": simulate.get("to_ata"),
+                        "to_ata_exists": simulate.get("to_ata_exists"),
+                        "will_create_ata": simulate.get("will_create_ata"),
+                        "amount_ui": simulate.get("amount_ui"),
+                        "amount_base": simulate.get("amount_base"),
+                        "network": simulate.get("network"),
+                        "simulation_units": simulate.get("simulation").and_then(|v| v.get("units_consumed"))
+                    }
+                }));
+
+                let parsed = Self::solana_create_pending_confirmation(
+                    network.as_deref(),
+                    &signed_b64,
+                    "w3rt_run_workflow_v0",
+                    summary,
+                )?;
+
+                json!({
+                    "stage": "execute",
+                    "status": "pending_confirmation_created",
+                    "network": network,
+                    "approval": approval,
+                    "result": parsed,
+                    "next": parsed.get("next").cloned().unwrap_or(json!({})),
+                    "note": "Pending confirmation created (safe default). On mainnet you must call solana_confirm_transaction with confirm_token to broadcast."
+                })
+            }
+        } else if intent_value["chain"] == "solana"
+            && (intent_value["action"] == "swap_exact_in" || intent_value["action"] == "swap_exact_out")
+        {
+            // Safe default: create a pending confirmation (confirm=false). Broadcast requires confirm_token on mainnet.
+            let tx_b64 = simulate
+                .get("swap")
+                .and_then(|v| v.get("tx_base64"))
+                .and_then(Value::as_str)
+                .unwrap_or("");
+            if tx_b64.is_empty() {
+                json!({
+                    "stage": "execute",
+                    "status": "error",
+                    "note": "missing tx_base64 from simulate stage"
+                })
+            } else {
+                let network = simulate.get("network").and_then(|v| {
+                    if v.is_string() { v.as_str().map(|s| s.to_string()) } else { None }
+                });
+
+                // Minimal surface: create a pending confirmation directly (do not expose solana_send_transaction).
+                let quote = simulate.get("quote");
+                let swap_mode = simulate
+                    .get("swap_mode")
+                    .and_then(Value::as_str)
+                    .unwrap_or("ExactIn");
+
+                let input_decimals = simulate.get("input_decimals");
+                let output_decimals = simulate.get("output_decimals");
+
+                let in_base = quote.and_then(|q| q.get("inAmount")).and_then(Value::as_str).unwrap_or("");
+                let out_base = quote.and_then(|q| q.get("outAmount")).and_then(Value::as_str).unwrap_or("");
+
+                let in_dec_u8 = input_decimals.and_then(Value::as_u64).unwrap_or(0) as u8;
+                let out_dec_u8 = output_decimals.and_then(Value::as_u64).unwrap_or(0) as u8;
+
+                let summary = Some(json!({
+                    "tool": "w3rt_run_workflow_v0",
+                    "run_id": run_id,
+                    "adapter": simulate.get("adapter"),
+                    "approval": approval,
+                    "intent": {
+                        "chain": intent_value.get("chain"),
+                        "action": intent_value.get("action"),
+                        "user_pubkey": intent_value.get("user_pubkey"),
+                        "input_token": intent_value.get("input_token"),
+                        "output_token": intent_value.get("output_token"),
+                        "amount_in": intent_value.get("amount_in"),
+                        "amount_out": intent_value.get("amount_out"),
+                        "slippage_bps": intent_value.get("slippage_bps")
+                    },
+                    "swap": {
+                        "swap_mode": swap_mode,
+                        "network": simulate.get("network"),
+                        "user_pubkey": simulate.get("user_pubkey"),
+                        "input_mint": simulate.get("input_mint"),
+                        "output_mint": simulate.get("output_mint"),
+                        "input_decimals": input_decimals,
+                        "output_decimals": output_decimals,
+                        "amount_in_base": quote.and_then(|q| q.get("inAmount")),
+                        "amount_in_ui": if in_base.is_empty() { Value::Null } else { json!(format_base_units_ui(in_base, in_dec_u8)) },
+                        "amount_out_base": quote.and_then(|q| q.get("outAmount")),
+                        "amount_out_ui": if out_base.is_empty() { Value::Null } else { json!(format_base_units_ui(out_base, out_dec_u8)) },
+                        "min_out_base": if swap_mode == "ExactIn" {
+                            // floor(outAmount * (1 - slippage))
+                            quote
+                                .and_then(|q| q.get("outAmount"))
+                                .and_then(Value::as_str)
+                                .and_then(|s| s.parse::<u128>().ok())
+                                .map(|out_u| {
+                                    let slip = simulate.get("slippage_bps").and_then(Value::as_u64).unwrap_or(100) as u128;
+                                    (out_u.saturating_mul(10_000u128.saturating_sub(slip)) / 10_000u128).to_string()
+                                })
+                        } else {
+                            None
+                        },
+                        "min_out_ui": if swap_mode == "ExactIn" {
+                            quote
+                                .and_then(|q| q.get("outAmount"))
+                                .and_then(Value::as_str)
+                                .and_then(|s| s.parse::<u128>().ok())
+                                .map(|out_u| {
+                                    let slip = simulate.get("slippage_bps").and_then(Value::as_u64).unwrap_or(100) as u128;
+                                    let min_u = out_u.saturating_mul(10_000u128.saturating_sub(slip)) / 10_000u128;
+                                    format_base_units_ui(&min_u.to_string(), out_dec_u8)
+                                })
+                        } else {
+                            None
+                        },
+                        "max_in_base": if swap_mode == "ExactOut" {
+                            quote.and_then(|q| q.get("inAmount")).and_then(Value::as_str).map(|s| s.to_string())
+                        } else {
+                            None
+                        },
+                        "max_in_ui": if swap_mode == "ExactOut" {
+                            if in_base.is_empty() { None } else { Some(format_base_units_ui(in_base, in_dec_u8)) }
+                        } else {
+                            None
+                        },
+                        "price_impact_pct": quote.and_then(|q| q.get("priceImpactPct")),
+                        "slippage_bps": simulate.get("slippage_bps"),
+                        "route_plan_steps": quote.and_then(|q| q.get("routePlan")).and_then(|v| v.as_array()).map(|a| a.len()),
+                        "simulation_units": simulate.get("simulation").and_then(|v| v.get("units_consumed"))
+                    }
+                }));
+
+                let parsed = Self::solana_create_pending_confirmation(
+                    network.as_deref(),
+                    tx_b64,
+                    "w3rt_run_workflow_v0",
+                    summary,
+                )?;
+
+                let pending_id = parsed.get("pending_confirmation_id").and_then(Value::as_str);
+                let tx_hash = parsed.get("tx_summary_hash").and_then(Value::as_str);
+                let confirm_token = parsed.get("confirm_token").and_then(Value::as_str);
+
+                let mut next: Value = json!({});
+                if let (Some(id), Some(h)) = (pending_id, tx_hash) {
+                    // Provide a copy/paste next step.
+                    let args = if confirm_token.is_some() {
+                        json!({
+                            "id": id,
+                            "hash": h,
+                            "confirm_token": confirm_token,
+                            "commitment": "confirmed"
+                        })
+                    } else {
+                        json!({
+                            "id": id,
+                            "hash": h,
+                            "commitment": "confirmed"
+                        })
+                    };
+
+                    next = json!({
+                        "confirm": {
+                            "tool": "solana_confirm_transaction",
+                            "args": args
+                        }
+                    });
+                }
+
+                json!({
+                    "stage": "execute",
+                    "status": "pending_confirmation_created",
+                    "network": network,
+                    "approval": approval,
+                    "result": parsed,
+                    "next": next,
+                    "note": "Pending confirmation created (safe default). On mainnet you must call solana_confirm_transaction with confirm_token to broadcast."
+                })
+            }
+        } else {
+            json!({
+                "stage": "execute",
+                "status": "todo",
+                "note": "Execution not implemented for this intent yet."
+            })
+        };
+
+        Ok(execute)
+    }
+
+    /// Output amount a broadcast swap step actually received (workflow v1 landed outputs);
+    /// `None` while the signature has no status yet.
+    async fn w3rt_solana_received_raw(
+        &self,
+        simulate: &Value,
+        signature: &str,
+    ) -> Result<Option<String>, ErrorData> {
+        let network = simulate.get("network").and_then(Value::as_str);
+        let client = Self::solana_rpc(network)?;
+        let sig = solana_sdk::signature::Signature::from_str(signature.trim()).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid signature: {}", e)),
+            data: None,
+        })?;
+
+        let status = client
+            .get_signature_statuses(&[sig])
+            .await
+            .map_err(|e| Self::sdk_error("w3rt_solana_landed:get_signature_statuses", e))?;
+        if status.value.first().cloned().flatten().is_none() {
+            return Ok(None);
+        }
+
+        let cfg = solana_client::rpc_config::RpcTransactionConfig {
+            encoding: Some(solana_transaction_status::UiTransactionEncoding::JsonParsed),
+            commitment: Some(solana_commitment_config::CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        let tx = client
+            .get_transaction_with_config(&sig, cfg)
+            .await
+            .map_err(|e| Self::sdk_error("w3rt_solana_landed:get_transaction", e))?;
+        let tx = serde_json::to_value(&tx).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to serialize transaction: {}", e)),
+            data: None,
+        })?;
+
+        let output_mint = simulate.get("output_mint").and_then(Value::as_str).unwrap_or("");
+        crate::intent_adapters::solana_received_raw(&tx, output_mint)
+            .map(Some)
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Cannot read the landed swap output: {}", e)),
+                data: Some(json!({"signature": signature})),
+            })
+    }
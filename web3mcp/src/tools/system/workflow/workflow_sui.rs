@@ -0,0 +1,554 @@
+    // ---------------- W3RT workflow: Sui stages ----------------
+    //
+    // Stitched into the plain helper impl by build.rs.
+    // Builders (transfer/pay/7K) live in the advanced toolbox; simulation is a dry run via
+    // `preflight_tx_data`, execution stores a `sui_confirm_store` pending row.
+
+    async fn w3rt_sui_simulate(&self, intent: &Value) -> Result<Value, ErrorData> {
+        let action = intent.get("action").and_then(Value::as_str).unwrap_or("");
+        let sender = Self::w3rt_intent_sender(intent).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("from (sender) is required for Sui intents"),
+            data: Some(json!({"action": action})),
+        })?;
+        Self::parse_address(&sender)?;
+
+        // A recognised resolved_network.network_name (mainnet|testnet|devnet|localnet|URL) picks
+        // the Sui client; anything else (e.g. "sui") keeps the server default.
+        let requested = intent
+            .get("resolved_network")
+            .and_then(|v| v.get("network_name"))
+            .and_then(Value::as_str)
+            .filter(|n| crate::sui::client_pool::SuiNetwork::parse(n).is_ok());
+        let this = self.for_sui_network(requested).await?;
+
+        match action {
+            "transfer_native" | "transfer_sui" => this.w3rt_sui_simulate_transfer(intent, &sender).await,
+            "pay" | "pay_sui" => this.w3rt_sui_simulate_pay(intent, &sender).await,
+            "swap_exact_in" => this.w3rt_sui_simulate_swap_7k(intent, &sender).await,
+            _ => Ok(json!({
+                "stage": "simulate",
+                "status": "todo",
+                "simulation_performed": false,
+                "intent": intent,
+                "note": format!("Sui action '{}' is not supported by the workflow yet (supported: transfer_native, pay_sui, swap_exact_in).", action)
+            })),
+        }
+    }
+
+    /// Parse a UI amount (e.g. "1.5") into base units for a coin with `decimals`.
+    fn w3rt_sui_parse_amount(amount: &str, decimals: u8) -> Result<u64, ErrorData> {
+        let v = Self::parse_decimal_to_u256(amount, decimals)?;
+        if v > ethers::types::U256::from(u64::MAX) {
+            return Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from(format!("amount too large: {}", amount)),
+                data: None,
+            });
+        }
+        Ok(v.as_u64())
+    }
+
+    fn w3rt_sui_coin_type(token: &str) -> Result<String, ErrorData> {
+        let t = token.trim();
+        if t.contains("::") {
+            return Ok(t.to_string());
+        }
+        if t.eq_ignore_ascii_case("sui") {
+            return Ok("0x2::sui::SUI".to_string());
+        }
+        Self::resolve_sui_coin_type(t).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!(
+                "Unknown Sui token '{}'; pass a full coin type (0x...::module::NAME)",
+                t
+            )),
+            data: None,
+        })
+    }
+
+    async fn w3rt_sui_coin_decimals(&self, coin_type: &str) -> Result<u8, ErrorData> {
+        if coin_type == "0x2::sui::SUI" {
+            return Ok(9);
+        }
+        let meta = self
+            .sui_read("w3rt_sui:get_coin_metadata", |client| async move {
+                client
+                    .coin_read_api()
+                    .get_coin_metadata(coin_type.to_string())
+                    .await
+            })
+            .await?;
+        meta.map(|m| m.decimals).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!(
+                "Coin metadata not found for {}; pass amount_in_raw instead",
+                coin_type
+            )),
+            data: None,
+        })
+    }
+
+    /// Dry-run a built tx and shape the simulate artifact (bytes are kept for the execute stage).
+    async fn w3rt_sui_dry_run_artifact(
+        &self,
+        adapter: &str,
+        intent: &Value,
+        tx_data: &TransactionData,
+        details: Value,
+    ) -> Result<Value, ErrorData> {
+        let tx_bytes_b64 = Self::encode_tx_bytes(tx_data)?;
+        let dry_run = self.preflight_tx_data(tx_data).await?;
+        let ok = dry_run.effects.status().is_ok() && dry_run.execution_error_source.is_none();
+        let gas = dry_run.effects.gas_cost_summary();
+
+        let mut out = json!({
+            "stage": "simulate",
+            "status": if ok { "ok" } else { "failed" },
+            "simulation_performed": true,
+            "adapter": adapter,
+            "network": self.resolve_network_kind(),
+            "sui_network": self.sui_network.key(),
+            "requested_network": intent
+                .get("resolved_network")
+                .and_then(|v| v.get("network_name"))
+                .cloned(),
+            "tx": { "tx_bytes_b64": tx_bytes_b64 },
+            "simulation": {
+                "method": "preflight_tx_data",
+                "status": dry_run.effects.status(),
+                "error": dry_run.execution_error_source,
+                "gas_used": gas.gas_used(),
+                "computation_cost": gas.computation_cost,
+                "storage_cost": gas.storage_cost,
+                "storage_rebate": gas.storage_rebate,
+                "balance_changes": dry_run.balance_changes
+            }
+        });
+        if let (Some(map), Some(extra)) = (out.as_object_mut(), details.as_object()) {
+            for (k, v) in extra {
+                map.insert(k.clone(), v.clone());
+            }
+        }
+        Ok(out)
+    }
+
+    async fn w3rt_sui_simulate_transfer(&self, intent: &Value, sender: &str) -> Result<Value, ErrorData> {
+        let to = Self::w3rt_intent_str(intent, "to").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("to (recipient) is required for transfer"),
+            data: None,
+        })?;
+        let amount = Self::w3rt_intent_str(intent, "amount");
+        let amount_mist = if let Some(m) = Self::w3rt_intent_str(intent, "amount_mist") {
+            Self::parse_u64_string("amount_mist", &m)?
+        } else {
+            let a = amount.clone().ok_or_else(|| ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("amount (or amount_mist) is required for transfer"),
+                data: None,
+            })?;
+            Self::w3rt_sui_parse_amount(&a, 9)?
+        };
+
+        // Large-transfer policy is enforced by the approval stage, not the builder.
+        let (tx_data, input_coins, gas_budget) = self
+            .build_transfer_sui_data(
+                sender,
+                &to,
+                Some(amount_mist),
+                None,
+                &[],
+                Some(true),
+                Some(true),
+                None,
+            )
+            .await?;
+
+        self.w3rt_sui_dry_run_artifact(
+            "sui_transfer",
+            intent,
+            &tx_data,
+            json!({
+                "from": sender,
+                "to": to,
+                "amount_ui": amount,
+                "amount_mist": amount_mist.to_string(),
+                "total_mist": amount_mist.to_string(),
+                "input_coins": input_coins,
+                "gas_budget": gas_budget
+            }),
+        )
+        .await
+    }
+
+    async fn w3rt_sui_simulate_pay(&self, intent: &Value, sender: &str) -> Result<Value, ErrorData> {
+        fn value_strings(v: Option<&Value>) -> Vec<String> {
+            v.and_then(Value::as_array)
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|x| match x {
+                            Value::String(s) => Some(s.trim().to_string()),
+                            Value::Number(n) => Some(n.to_string()),
+                            _ => None,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        let recipients = value_strings(intent.get("recipients"));
+        let amounts_mist: Vec<u64> = if intent.get("amounts_mist").is_some() {
+            value_strings(intent.get("amounts_mist"))
+                .iter()
+                .map(|s| Self::parse_u64_string("amounts_mist", s))
+                .collect::<Result<_, _>>()?
+        } else {
+            value_strings(intent.get("amounts"))
+                .iter()
+                .map(|s| Self::w3rt_sui_parse_amount(s, 9))
+                .collect::<Result<_, _>>()?
+        };
+        if recipients.is_empty() || recipients.len() != amounts_mist.len() {
+            return Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("pay_sui requires recipients[] and amounts[] (or amounts_mist[]) of equal length"),
+                data: Some(json!({"recipients": recipients.len(), "amounts": amounts_mist.len()})),
+            });
+        }
+        let total: u128 = amounts_mist.iter().map(|a| *a as u128).sum();
+
+        // PaySui smashes the input coins and pays gas from them: select enough SUI for total + gas reserve.
+        let gas_reserve: u128 = 10_000_000;
+        let owner = Self::parse_address(sender)?;
+        let mut coins = self
+            .sui_read("w3rt_sui_pay:get_coins", |client| async move {
+                client
+                    .coin_read_api()
+                    .get_coins(owner, None, None, None)
+                    .await
+            })
+            .await?
+            .data;
+        coins.sort_by(|a, b| b.balance.cmp(&a.balance));
+        let mut selected: Vec<String> = vec![];
+        let mut selected_total: u128 = 0;
+        for coin in coins {
+            if selected_total >= total + gas_reserve {
+                break;
+            }
+            selected_total += coin.balance as u128;
+            selected.push(coin.coin_object_id.to_string());
+        }
+        if selected_total < total + gas_reserve {
+            return Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("Insufficient SUI balance to cover pay amounts and gas"),
+                data: Some(json!({
+                    "required_mist": (total + gas_reserve).to_string(),
+                    "available_mist": selected_total.to_string()
+                })),
+            });
+        }
+
+        let (tx_data, gas_budget) = self
+            .build_pay_sui_data(sender, &recipients, &amounts_mist, &selected, None)
+            .await?;
+
+        self.w3rt_sui_dry_run_artifact(
+            "sui_pay",
+            intent,
+            &tx_data,
+            json!({
+                "from": sender,
+                "recipients": recipients,
+                "amounts_mist": amounts_mist.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+                "total_mist": total.to_string(),
+                "input_coins": selected,
+                "gas_budget": gas_budget
+            }),
+        )
+        .await
+    }
+
+    async fn w3rt_sui_simulate_swap_7k(&self, intent: &Value, sender: &str) -> Result<Value, ErrorData> {
+        let input_token = Self::w3rt_intent_str(intent, "input_token").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("input_token is required for swap"),
+            data: None,
+        })?;
+        let output_token = Self::w3rt_intent_str(intent, "output_token").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("output_token is required for swap"),
+            data: None,
+        })?;
+        let from_coin_type = Self::w3rt_sui_coin_type(&input_token)?;
+        let to_coin_type = Self::w3rt_sui_coin_type(&output_token)?;
+        let slippage_bps = intent
+            .get("slippage_bps")
+            .and_then(Value::as_u64)
+            .unwrap_or(100);
+
+        let amount_in = Self::w3rt_intent_str(intent, "amount_in");
+        let amount_in_raw = match Self::w3rt_intent_str(intent, "amount_in_raw") {
+            Some(r) => Self::parse_u64_string("amount_in_raw", &r)?,
+            None => {
+                let a = amount_in.clone().ok_or_else(|| ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("amount_in (or amount_in_raw) is required for swap_exact_in"),
+                    data: None,
+                })?;
+                let decimals = self.w3rt_sui_coin_decimals(&from_coin_type).await?;
+                Self::w3rt_sui_parse_amount(&a, decimals)?
+            }
+        };
+
+        let base_url = Self::get_7k_base_url(None)?;
+        let (quote, quote_endpoint) = self
+            .make_7k_quote_request(
+                &base_url,
+                &amount_in_raw.to_string(),
+                &from_coin_type,
+                &to_coin_type,
+                Self::SEVEN_K_DEFAULT_SOURCES,
+                Some(sender),
+                15_000,
+            )
+            .await?;
+
+        // Builds via build_7k_swap_tx_data (coin selection, shared objects, gas estimate).
+        let built = self
+            .sui_7k_build_swap_tx(Parameters(Sui7kBuildSwapTxRequest {
+                quote: quote.clone(),
+                sender: sender.to_string(),
+                slippage_bps: Some(slippage_bps),
+                partner: None,
+                commission_bps: None,
+                base_url: None,
+                timeout_ms: None,
+                network: None,
+            }))
+            .await?;
+        let built_json = Self::extract_first_json(&built).ok_or_else(|| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from("Failed to parse sui_7k_build_swap_tx response"),
+            data: None,
+        })?;
+        let tx_b64 = built_json
+            .get("tx_bytes_b64")
+            .and_then(Value::as_str)
+            .ok_or_else(|| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from("sui_7k_build_swap_tx returned no tx_bytes_b64"),
+                data: None,
+            })?;
+        let tx_bytes = Self::decode_base64("tx_bytes_b64", tx_b64)?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Invalid 7K tx bytes: {}", e)),
+            data: None,
+        })?;
+
+        self.w3rt_sui_dry_run_artifact(
+            "sui_7k",
+            intent,
+            &tx_data,
+            json!({
+                "user_pubkey": sender,
+                "input_token": input_token,
+                "output_token": output_token,
+                "input_coin_type": from_coin_type,
+                "output_coin_type": to_coin_type,
+                "amount_in": amount_in,
+                "amount_in_raw": amount_in_raw.to_string(),
+                "slippage_bps": slippage_bps,
+                "expected_return": built_json.get("expected_return"),
+                "min_received": built_json.get("min_received"),
+                "quote_endpoint": quote_endpoint,
+                "quote": quote
+            }),
+        )
+        .await
+    }
+
+    fn w3rt_sui_approval(simulate: &Value) -> Value {
+        let adapter = simulate.get("adapter").and_then(Value::as_str).unwrap_or("");
+        let mut warnings: Vec<Value> = vec![];
+
+        // The server talks to one Sui fullnode; flag intents that asked for a different network.
+        let network = simulate.get("network").and_then(Value::as_str).unwrap_or("");
+        if let Some(requested) = simulate.get("requested_network").and_then(Value::as_str) {
+            if !requested.is_empty() && requested != network {
+                warnings.push(json!({
+                    "kind": "network_mismatch",
+                    "requested_network": requested,
+                    "server_network": network,
+                    "note": "intent network differs from the configured Sui RPC"
+                }));
+            }
+        }
+
+        // Large-transfer / slippage thresholds are approval policy rules (src/utils/approval_policy.rs).
+        if adapter == "sui_transfer" || adapter == "sui_pay" {
+            let from = simulate.get("from").and_then(Value::as_str).unwrap_or("");
+            let self_pay = simulate.get("to").and_then(Value::as_str) == Some(from)
+                || simulate
+                    .get("recipients")
+                    .and_then(Value::as_array)
+                    .is_some_and(|r| r.iter().any(|v| v.as_str() == Some(from)));
+            if !from.is_empty() && self_pay {
+                warnings.push(json!({
+                    "kind": "self_transfer",
+                    "note": "sender is also a recipient"
+                }));
+            }
+        }
+
+        if adapter == "sui_7k" && simulate.get("min_received").and_then(Value::as_str) == Some("0") {
+            warnings.push(json!({
+                "kind": "min_out_zero",
+                "note": "computed min_received is 0 (check quote)"
+            }));
+        }
+
+        json!({
+            "stage": "approval",
+            "status": if warnings.is_empty() { "ok" } else { "needs_review" },
+            "network": simulate.get("network"),
+            "warnings": warnings,
+            "summary": {
+                "adapter": adapter,
+                "from": simulate.get("from").or_else(|| simulate.get("user_pubkey")),
+                "to": simulate.get("to"),
+                "recipients": simulate.get("recipients"),
+                "total_mist": simulate.get("total_mist"),
+                "input_coin_type": simulate.get("input_coin_type"),
+                "output_coin_type": simulate.get("output_coin_type"),
+                "amount_in_raw": simulate.get("amount_in_raw"),
+                "expected_return": simulate.get("expected_return"),
+                "min_received": simulate.get("min_received"),
+                "gas_budget": simulate.get("gas_budget"),
+                "gas_used": simulate.get("simulation").and_then(|v| v.get("gas_used"))
+            },
+            "note": "Sui transaction. Execution uses safe default (pending confirmation)."
+        })
+    }
+
+    /// Output amount a broadcast 7K swap step actually received (workflow v1 landed outputs).
+    async fn w3rt_sui_received_raw(&self, simulate: &Value, digest: &str) -> Result<String, ErrorData> {
+        let this = self
+            .for_sui_network(simulate.get("sui_network").and_then(Value::as_str))
+            .await?;
+        let tx_digest = Self::parse_digest(digest)?;
+        let options = SuiTransactionBlockResponseOptions::new()
+            .with_effects()
+            .with_balance_changes();
+        let tx = this
+            .sui_read("w3rt_sui_landed:get_transaction", |client| {
+                let options = options.clone();
+                async move {
+                    client
+                        .read_api()
+                        .get_transaction_with_options(tx_digest, options)
+                        .await
+                }
+            })
+            .await?;
+        let tx = serde_json::to_value(&tx).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to serialize transaction: {}", e)),
+            data: None,
+        })?;
+
+        let owner = simulate.get("user_pubkey").and_then(Value::as_str).unwrap_or("");
+        let coin_type = simulate.get("output_coin_type").and_then(Value::as_str).unwrap_or("");
+        crate::intent_adapters::sui_received_raw(&tx, owner, coin_type).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Cannot read the landed swap output: {}", e)),
+            data: Some(json!({"digest": digest})),
+        })
+    }
+
+    /// Store the dry-run tx bytes as a sui_confirm_store pending row.
+    fn w3rt_sui_execute(
+        &self,
+        run_id: &str,
+        simulate: &Value,
+        approval: &Value,
+    ) -> Result<Value, ErrorData> {
+        let adapter = simulate.get("adapter").and_then(Value::as_str).unwrap_or("");
+        let tx_b64 = simulate
+            .get("tx")
+            .and_then(|v| v.get("tx_bytes_b64"))
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        if tx_b64.is_empty() {
+            return Ok(json!({
+                "stage": "execute",
+                "status": "error",
+                "note": "missing tx_bytes_b64 from simulate stage"
+            }));
+        }
+        let tx_bytes = Self::decode_base64("tx_bytes_b64", tx_b64)?;
+        let hash = crate::utils::sui_confirm_store::tx_summary_hash(&tx_bytes);
+
+        let created = crate::utils::evm_confirm_store::now_ms();
+        let ttl_ms = crate::utils::sui_confirm_store::default_ttl_ms();
+        let label = match adapter {
+            "sui_7k" => "w3rt_swap",
+            "sui_pay" => "w3rt_pay",
+            _ => "w3rt_transfer",
+        };
+        let id = format!("sui_confirm_{}_{}", created, label);
+        // Confirm on the network the simulate stage ran against.
+        let sui_network = simulate
+            .get("sui_network")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| self.sui_network.key());
+
+        let summary = json!({
+            "tool": "w3rt_run_workflow_v0",
+            "run_id": run_id,
+            "adapter": adapter,
+            "approval": approval.get("summary"),
+        });
+        crate::utils::sui_confirm_store::insert_pending(
+            &id,
+            tx_b64,
+            created,
+            created.saturating_add(ttl_ms),
+            &hash,
+            "w3rt_run_workflow_v0",
+            Some(summary),
+            &sui_network,
+        )?;
+
+        let confirm_token = crate::utils::sui_confirm_store::make_confirm_token(&id, &hash);
+        let is_mainnet = simulate.get("network").and_then(Value::as_str) == Some("mainnet");
+        let args = if is_mainnet {
+            json!({"id": id, "tx_summary_hash": hash, "confirm_token": confirm_token, "preflight": true})
+        } else {
+            json!({"id": id, "tx_summary_hash": hash, "preflight": true})
+        };
+
+        Ok(json!({
+            "stage": "execute",
+            "status": "pending_confirmation_created",
+            "network": simulate.get("network"),
+            "approval": approval,
+            "result": {
+                "confirmation_id": id,
+                "tx_summary_hash": hash,
+                "confirm_token": confirm_token,
+                "expires_in_ms": ttl_ms as u64
+            },
+            "next": {
+                "confirm": {
+                    "tool": "sui_confirm_execution",
+                    "args": args
+                }
+            },
+            "note": "Pending confirmation created (safe default). Call sui_confirm_execution to sign+broadcast (mainnet requires confirm_token)."
+        }))
+    }
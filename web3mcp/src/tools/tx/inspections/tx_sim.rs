@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<DryRunTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
         let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
             code: ErrorCode(-32602),
@@ -11,12 +12,12 @@
             data: None,
         })?;
 
-        let result = self
-            .client
-            .read_api()
-            .dry_run_transaction_block(tx_data)
-            .await
-            .map_err(|e| Self::sdk_error("dry_run_transaction", e))?;
+        let result = this
+            .sui_read("dry_run_transaction", |client| {
+                let tx_data = tx_data.clone();
+                async move { client.read_api().dry_run_transaction_block(tx_data).await }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -28,6 +29,7 @@
         &self,
         Parameters(request): Parameters<DevInspectTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
         let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
@@ -36,15 +38,20 @@
             data: None,
         })?;
         let tx_kind = tx_data.as_v1().kind.clone();
-        let gas_price = request.gas_price.map(BigInt::from);
-        let epoch = request.epoch.map(BigInt::from);
 
-        let result = self
-            .client
-            .read_api()
-            .dev_inspect_transaction_block(sender, tx_kind, gas_price, epoch, None)
-            .await
-            .map_err(|e| Self::sdk_error("dev_inspect_transaction", e))?;
+        let result = this
+            .sui_read("dev_inspect_transaction", |client| {
+                let tx_kind = tx_kind.clone();
+                let gas_price = request.gas_price.map(BigInt::from);
+                let epoch = request.epoch.map(BigInt::from);
+                async move {
+                    client
+                        .read_api()
+                        .dev_inspect_transaction_block(sender, tx_kind, gas_price, epoch, None)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
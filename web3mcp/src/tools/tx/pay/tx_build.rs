@@ -4,7 +4,8 @@
         &self,
         Parameters(request): Parameters<BuildTransferObjectRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, gas_budget) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, gas_budget) = this
             .build_transfer_object_data(
                 &request.sender,
                 &request.object_id,
@@ -27,7 +28,8 @@
         &self,
         Parameters(request): Parameters<BuildTransferSuiRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, input_coin_ids, gas_budget) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, input_coin_ids, gas_budget) = this
             .build_transfer_sui_data(
                 &request.sender,
                 &request.recipient,
@@ -54,9 +56,10 @@
         &self,
         Parameters(request): Parameters<ExecuteTransferSuiRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let merge_summary = if request.auto_merge_small_coins.unwrap_or(false) {
             Some(
-                self.merge_small_sui_coins(
+                this.merge_small_sui_coins(
                     &request.sender,
                     request.merge_threshold.unwrap_or(10),
                     request.merge_max_inputs.unwrap_or(10),
@@ -69,7 +72,7 @@
             None
         };
 
-        let (tx_data, input_coin_ids, _) = self
+        let (tx_data, input_coin_ids, _) = this
             .build_transfer_sui_data(
                 &request.sender,
                 &request.recipient,
@@ -82,12 +85,14 @@
             )
             .await?;
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(signer) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            Self::parse_address(&request.sender)?
-        };
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(Self::parse_address(&request.sender)?),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
         let tx_sender = tx_data.sender();
         if tx_sender != signer && !request.allow_sender_mismatch.unwrap_or(false) {
@@ -101,10 +106,9 @@
             });
         }
 
-        let (result, preflight) = self
+        let (result, preflight) = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data,
                 request.allow_sender_mismatch,
                 request.preflight,
@@ -122,7 +126,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_transfer_sui",
             json!({
                 "sender": request.sender,
@@ -143,7 +147,8 @@
         &self,
         Parameters(request): Parameters<ExecuteTransferObjectRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, _) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, _) = this
             .build_transfer_object_data(
                 &request.sender,
                 &request.object_id,
@@ -188,6 +193,7 @@
                 &hash,
                 "execute_transfer_object",
                 Some(summary.clone()),
+                &this.sui_network.key(),
             )?;
 
             let response = Self::pretty_json(&json!({
@@ -201,23 +207,24 @@
                 "expires_in_ms": ttl,
                 "note": "Not broadcast. Call sui_confirm_execution to sign+broadcast (requires keystore_path).",
                 "next": {
-                    "how_to_confirm": if self.sui_is_mainnet_rpc_url() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
+                    "how_to_confirm": if this.sui_is_mainnet() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
                 }
             }))?;
             return Ok(CallToolResult::success(vec![Content::text(response)]));
         }
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(signer) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            Self::parse_address(&request.sender)?
-        };
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(Self::parse_address(&request.sender)?),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
-        let (result, preflight) = self
+        let (result, preflight) = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data,
                 request.allow_sender_mismatch,
                 request.preflight,
@@ -233,7 +240,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_transfer_object",
             json!({
                 "sender": request.sender,
@@ -253,8 +260,9 @@
         &self,
         Parameters(request): Parameters<ExecutePaySuiRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         // NOTE: safe-default confirmation handling happens after tx_data is built.
-        let (tx_data, _) = self
+        let (tx_data, _) = this
             .build_pay_sui_data(
                 &request.sender,
                 &request.recipients,
@@ -300,6 +308,7 @@
                 &hash,
                 "execute_pay_sui",
                 Some(summary.clone()),
+                &this.sui_network.key(),
             )?;
 
             let response = Self::pretty_json(&json!({
@@ -313,23 +322,24 @@
                 "expires_in_ms": ttl,
                 "note": "Not broadcast. Call sui_confirm_execution to sign+broadcast (requires keystore_path).",
                 "next": {
-                    "how_to_confirm": if self.sui_is_mainnet_rpc_url() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
+                    "how_to_confirm": if this.sui_is_mainnet() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
                 }
             }))?;
             return Ok(CallToolResult::success(vec![Content::text(response)]));
         }
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(signer) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            Self::parse_address(&request.sender)?
-        };
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(Self::parse_address(&request.sender)?),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
-        let (result, preflight) = self
+        let (result, preflight) = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data,
                 request.allow_sender_mismatch,
                 request.preflight,
@@ -345,7 +355,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_pay_sui",
             json!({
                 "sender": request.sender,
@@ -365,7 +375,8 @@
         &self,
         Parameters(request): Parameters<ExecuteAddStakeRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, _) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, _) = this
             .build_add_stake_data(
                 &request.sender,
                 &request.validator,
@@ -410,6 +421,7 @@
                 &hash,
                 "execute_add_stake",
                 Some(summary.clone()),
+                &this.sui_network.key(),
             )?;
 
             let response = Self::pretty_json(&json!({
@@ -423,23 +435,24 @@
                 "expires_in_ms": ttl,
                 "note": "Not broadcast. Call sui_confirm_execution to sign+broadcast (requires keystore_path).",
                 "next": {
-                    "how_to_confirm": if self.sui_is_mainnet_rpc_url() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
+                    "how_to_confirm": if this.sui_is_mainnet() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
                 }
             }))?;
             return Ok(CallToolResult::success(vec![Content::text(response)]));
         }
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(signer) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            Self::parse_address(&request.sender)?
-        };
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(Self::parse_address(&request.sender)?),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
-        let (result, preflight) = self
+        let (result, preflight) = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data,
                 request.allow_sender_mismatch,
                 request.preflight,
@@ -455,7 +468,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_add_stake",
             json!({
                 "sender": request.sender,
@@ -475,7 +488,8 @@
         &self,
         Parameters(request): Parameters<ExecuteWithdrawStakeRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, _) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, _) = this
             .build_withdraw_stake_data(
                 &request.sender,
                 &request.staked_sui,
@@ -516,6 +530,7 @@
                 &hash,
                 "execute_withdraw_stake",
                 Some(summary.clone()),
+                &this.sui_network.key(),
             )?;
 
             let response = Self::pretty_json(&json!({
@@ -529,23 +544,24 @@
                 "expires_in_ms": ttl,
                 "note": "Not broadcast. Call sui_confirm_execution to sign+broadcast (requires keystore_path).",
                 "next": {
-                    "how_to_confirm": if self.sui_is_mainnet_rpc_url() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
+                    "how_to_confirm": if this.sui_is_mainnet() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
                 }
             }))?;
             return Ok(CallToolResult::success(vec![Content::text(response)]));
         }
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(signer) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            Self::parse_address(&request.sender)?
-        };
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(Self::parse_address(&request.sender)?),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
-        let (result, preflight) = self
+        let (result, preflight) = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data,
                 request.allow_sender_mismatch,
                 request.preflight,
@@ -561,7 +577,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_withdraw_stake",
             json!({
                 "sender": request.sender,
@@ -607,11 +623,13 @@
         let mut selected_total_balance: Option<u128> = None;
         let input_coin_ids = if input_coins.is_empty() && auto_select {
             let coins = self
-                .client
-                .coin_read_api()
-                .get_coins(sender, None, None, None)
-                .await
-                .map_err(|e| Self::sdk_error("build_transfer_sui", e))?;
+                .sui_read("build_transfer_sui", |client| async move {
+                    client
+                        .coin_read_api()
+                        .get_coins(sender, None, None, None)
+                        .await
+                })
+                .await?;
             let mut coin_list = coins.data;
             if coin_list.is_empty() {
                 return Err(ErrorData {
@@ -794,11 +812,13 @@
     ) -> Result<Value, ErrorData> {
         let sender_addr = Self::parse_address(sender)?;
         let coins = self
-            .client
-            .coin_read_api()
-            .get_coins(sender_addr, None, None, None)
-            .await
-            .map_err(|e| Self::sdk_error("merge_small_sui_coins", e))?;
+            .sui_read("merge_small_sui_coins", |client| async move {
+                client
+                    .coin_read_api()
+                    .get_coins(sender_addr, None, None, None)
+                    .await
+            })
+            .await?;
 
         if coins.data.len() <= threshold {
             return Ok(json!({
@@ -825,12 +845,9 @@
             }));
         }
 
-        let keystore = self.load_file_keystore(keystore_path)?;
-        let signer = if let Some(signer) = signer {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            sender_addr
-        };
+        let sui_signer = self
+            .sui_signer(keystore_path, signer, Some(sender_addr))
+            .await?;
 
         let mut digests = Vec::new();
         for coin_to_merge in merge_candidates.iter() {
@@ -843,8 +860,7 @@
 
             let (result, _preflight) = self
                 .sign_and_execute_tx_data(
-                    &keystore,
-                    signer,
+                    sui_signer.as_ref(),
                     tx_data,
                     Some(false),
                     Some(false),
@@ -994,15 +1010,37 @@
         tx_data: &TransactionData,
     ) -> Result<u64, ErrorData> {
         let result = self
-            .client
-            .read_api()
-            .dry_run_transaction_block(tx_data.clone())
-            .await
-            .map_err(|e| Self::sdk_error("estimate_gas_budget", e))?;
+            .sui_read("estimate_gas_budget", |client| async move {
+                client
+                    .read_api()
+                    .dry_run_transaction_block(tx_data.clone())
+                    .await
+            })
+            .await?;
         let summary = result.effects.gas_cost_summary();
         Ok(summary.gas_used())
     }
 
+    /// Spend of a Sui transaction for the confirm-time spend-limit guard (dry-run balance
+    /// changes). Skips the dry run when no limits are configured.
+    async fn sui_spend_entries(
+        &self,
+        tx_data: &TransactionData,
+    ) -> Result<Vec<crate::utils::spend_limits::SpendEntry>, ErrorData> {
+        if !crate::utils::spend_limits::limits_configured() {
+            return Ok(Vec::new());
+        }
+        let dry_run = self.preflight_tx_data(tx_data).await?;
+        let changes = serde_json::to_value(&dry_run.balance_changes).unwrap_or(Value::Null);
+        Ok(crate::utils::spend_limits::sui_entries_from_balance_changes(
+            &self.resolve_network_kind(),
+            &tx_data.sender().to_string(),
+            &changes,
+            Self::resolve_sui_coin_type("usdc").as_deref(),
+            crate::utils::spend_limits::now_ms(),
+        ))
+    }
+
     fn gas_budget_with_buffer(estimate: u64) -> u64 {
         estimate
             .saturating_add(estimate / 5)
@@ -1011,14 +1049,14 @@
 
     async fn sign_and_execute_tx_data(
         &self,
-        keystore: &sui_keys::keystore::FileBasedKeystore,
-        signer: SuiAddress,
+        sui_signer: &dyn crate::utils::signer::Signer,
         tx_data: TransactionData,
         allow_sender_mismatch: Option<bool>,
         preflight: Option<bool>,
         allow_preflight_failure: Option<bool>,
         context: &str,
     ) -> Result<(SuiTransactionBlockResponse, Option<DryRunTransactionBlockResponse>), ErrorData> {
+        let signer = Self::sui_signer_address(sui_signer)?;
         let tx_sender = tx_data.sender();
         if tx_sender != signer && !allow_sender_mismatch.unwrap_or(false) {
             return Err(ErrorData {
@@ -1031,51 +1069,66 @@
             });
         }
 
-        let preflight_result = if preflight.unwrap_or(false) {
-            let result = self.preflight_tx_data(&tx_data).await?;
-            if result.execution_error_source.is_some()
-                && !allow_preflight_failure.unwrap_or(false)
-            {
-                let msg = result
-                    .execution_error_source
-                    .as_deref()
-                    .unwrap_or("dry-run failed (missing execution_error_source)");
-                self.write_audit_log(
-                    context,
-                    json!({
-                        "event": "dry_run_failed",
-                        "message": msg,
-                        "allow_preflight_failure": allow_preflight_failure.unwrap_or(false),
-                        "sender": signer.to_string(),
-                    }),
-                );
-                return Err(ErrorData {
-                    code: ErrorCode(-32603),
-                    message: Cow::from(format!("Dry-run failed: {}", msg)),
-                    data: Some(json!({
-                        "dry_run": result,
-                        "note": "Set allow_preflight_failure=true to proceed anyway"
-                    })),
-                });
-            }
-            Some(result)
-        } else {
-            None
-        };
+        let preflight_result = self
+            .sui_preflight_guard(&tx_data, preflight, allow_preflight_failure, signer, context)
+            .await?;
 
-        let signature = keystore
-            .sign_secure(&signer, &tx_data, shared_crypto::intent::Intent::sui_transaction())
-            .await
-            .map_err(|e| ErrorData {
+        let signature = Self::sui_sign_tx_data(sui_signer, &tx_data).await?;
+        let result = self
+            .execute_signed_tx_data(tx_data, vec![GenericSignature::Signature(signature)], context)
+            .await?;
+
+        Ok((result, preflight_result))
+    }
+
+    /// Optional dry run before broadcast; a failed dry run aborts unless allowed.
+    async fn sui_preflight_guard(
+        &self,
+        tx_data: &TransactionData,
+        preflight: Option<bool>,
+        allow_preflight_failure: Option<bool>,
+        sender: SuiAddress,
+        context: &str,
+    ) -> Result<Option<DryRunTransactionBlockResponse>, ErrorData> {
+        if !preflight.unwrap_or(false) {
+            return Ok(None);
+        }
+        let result = self.preflight_tx_data(tx_data).await?;
+        if result.execution_error_source.is_some() && !allow_preflight_failure.unwrap_or(false) {
+            let msg = result
+                .execution_error_source
+                .as_deref()
+                .unwrap_or("dry-run failed (missing execution_error_source)");
+            self.write_audit_log(
+                context,
+                json!({
+                    "event": "dry_run_failed",
+                    "message": msg,
+                    "allow_preflight_failure": allow_preflight_failure.unwrap_or(false),
+                    "sender": sender.to_string(),
+                }),
+            );
+            return Err(ErrorData {
                 code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to sign transaction: {}", e)),
-                data: None,
-            })?;
+                message: Cow::from(format!("Dry-run failed: {}", msg)),
+                data: Some(json!({
+                    "dry_run": result,
+                    "note": "Set allow_preflight_failure=true to proceed anyway"
+                })),
+            });
+        }
+        Ok(Some(result))
+    }
 
-        let tx = Transaction::from_generic_sig_data(
-            tx_data,
-            vec![GenericSignature::Signature(signature)],
-        );
+    /// Broadcast `tx_data` with already-collected signatures (single-key, multisig, or sender
+    /// plus gas sponsor).
+    async fn execute_signed_tx_data(
+        &self,
+        tx_data: TransactionData,
+        signatures: Vec<GenericSignature>,
+        context: &str,
+    ) -> Result<SuiTransactionBlockResponse, ErrorData> {
+        let tx = Transaction::from_generic_sig_data(tx_data, signatures);
 
         let options = SuiTransactionBlockResponseOptions::new()
             .with_input()
@@ -1084,14 +1137,11 @@
             .with_object_changes()
             .with_balance_changes();
 
-        let result = self
-            .client
+        self.client
             .quorum_driver_api()
             .execute_transaction_block(tx, options, None)
             .await
-            .map_err(|e| Self::sdk_error(context, e))?;
-
-        Ok((result, preflight_result))
+            .map_err(|e| Self::sdk_error(context, e))
     }
 
     /// Build a pay SUI transaction
@@ -1100,7 +1150,8 @@
         &self,
         Parameters(request): Parameters<BuildPaySuiRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, gas_budget) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, gas_budget) = this
             .build_pay_sui_data(
                 &request.sender,
                 &request.recipients,
@@ -1123,11 +1174,12 @@
         &self,
         Parameters(request): Parameters<BuildPayAllSuiRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let recipient = Self::parse_address(&request.recipient)?;
         let input_coins = Self::parse_object_ids(&request.input_coins)?;
 
-        let tx_data = self
+        let tx_data = this
             .client
             .transaction_builder()
             .pay_all_sui(sender, input_coins, recipient, request.gas_budget)
@@ -1144,6 +1196,7 @@
         &self,
         Parameters(request): Parameters<BuildMoveCallRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let package = Self::parse_object_id(&request.package)?;
         let type_args = request
@@ -1157,7 +1210,7 @@
             None => None,
         };
 
-        let tx_data = self
+        let tx_data = this
             .client
             .transaction_builder()
             .move_call(
@@ -1184,6 +1237,7 @@
         &self,
         Parameters(request): Parameters<BuildPublishRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let dependencies = Self::parse_object_ids(&request.dependencies)?;
         let modules = request
@@ -1196,7 +1250,7 @@
             None => None,
         };
 
-        let tx_data = self
+        let tx_data = this
             .client
             .transaction_builder()
             .publish(sender, modules, dependencies, gas, request.gas_budget)
@@ -1213,6 +1267,7 @@
         &self,
         Parameters(request): Parameters<BuildSplitCoinRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let coin_object_id = Self::parse_object_id(&request.coin_object_id)?;
         let gas = match request.gas_object_id {
@@ -1220,7 +1275,7 @@
             None => None,
         };
 
-        let tx_data = self
+        let tx_data = this
             .client
             .transaction_builder()
             .split_coin(
@@ -1243,6 +1298,7 @@
         &self,
         Parameters(request): Parameters<BuildMergeCoinsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let primary_coin = Self::parse_object_id(&request.primary_coin)?;
         let coin_to_merge = Self::parse_object_id(&request.coin_to_merge)?;
@@ -1251,7 +1307,7 @@
             None => None,
         };
 
-        let tx_data = self
+        let tx_data = this
             .client
             .transaction_builder()
             .merge_coins(
@@ -1274,7 +1330,8 @@
         &self,
         Parameters(request): Parameters<BuildBatchTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, gas_budget) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, gas_budget) = this
             .build_batch_transaction_data(
                 &request.sender,
                 request.requests,
@@ -1296,9 +1353,10 @@
         &self,
         Parameters(request): Parameters<ExecuteBatchTransactionRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let requests_count = request.requests.len();
 
-        let (tx_data, _) = self
+        let (tx_data, _) = this
             .build_batch_transaction_data(
                 &request.sender,
                 request.requests,
@@ -1333,6 +1391,7 @@
                 &hash,
                 "execute_batch_transaction",
                 Some(summary.clone()),
+                &this.sui_network.key(),
             )?;
 
             let response = Self::pretty_json(&json!({
@@ -1346,23 +1405,24 @@
                 "expires_in_ms": ttl,
                 "note": "Not broadcast. Call sui_confirm_execution to sign+broadcast (requires keystore_path).",
                 "next": {
-                    "how_to_confirm": if self.sui_is_mainnet_rpc_url() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
+                    "how_to_confirm": if this.sui_is_mainnet() { let t = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash); format!("sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>", confirmation_id, hash, t) } else { format!("sui_confirm_execution id:{} tx_summary_hash:{} keystore_path:<path>", confirmation_id, hash) }
                 }
             }))?;
             return Ok(CallToolResult::success(vec![Content::text(response)]));
         }
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(signer) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(signer))?
-        } else {
-            Self::parse_address(&request.sender)?
-        };
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(Self::parse_address(&request.sender)?),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
 
-        let (result, preflight) = self
+        let (result, preflight) = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data,
                 request.allow_sender_mismatch,
                 request.preflight,
@@ -1378,7 +1438,7 @@
             "summary": summary
         }))?;
 
-        self.write_audit_log(
+        this.write_audit_log(
             "execute_batch_transaction",
             json!({
                 "sender": request.sender,
@@ -1389,16 +1449,12 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    fn sui_is_mainnet_rpc_url(&self) -> bool {
-        let url = self.rpc_url.to_lowercase();
-        url.contains("mainnet") && !url.contains("testnet") && !url.contains("devnet")
-    }
-
     #[tool(description = "Sui: create a pending confirmation from tx_bytes_b64 (BCS TransactionData). Does not broadcast.")]
     async fn sui_create_pending_confirmation(
         &self,
         Parameters(request): Parameters<SuiCreatePendingConfirmationRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let tx_bytes = Self::decode_base64("tx_bytes_b64", &request.tx_bytes_b64)?;
 
         // Validate BCS TransactionData
@@ -1432,6 +1488,7 @@
             &hash,
             &tool_context,
             request.summary.clone(),
+            &this.sui_network.key(),
         )?;
 
         let confirm_token = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &hash);
@@ -1444,7 +1501,7 @@
             "expires_in_ms": ttl_ms,
             "note": "Not broadcast. Call sui_confirm_execution to sign+broadcast (mainnet requires confirm_token).",
             "next": {
-                "how_to_confirm": if self.sui_is_mainnet_rpc_url() {
+                "how_to_confirm": if this.sui_is_mainnet() {
                     format!(
                         "sui_confirm_execution id:{} tx_summary_hash:{} confirm_token:{} keystore_path:<path>",
                         confirmation_id, hash, confirm_token
@@ -1490,6 +1547,9 @@
             }
         };
 
+        // Execute on the network the transaction was built against.
+        let this = self.for_sui_network(row.network.as_deref()).await?;
+
         if row.status != "pending" {
             return Self::guard_result(
                 "sui_confirm_execution",
@@ -1542,7 +1602,7 @@
         }
 
         // Mainnet safety: require confirm_token.
-        if self.sui_is_mainnet_rpc_url() {
+        if this.sui_is_mainnet() {
             let expected = crate::utils::sui_confirm_store::make_confirm_token(
                 &request.id,
                 &request.tx_summary_hash,
@@ -1581,11 +1641,37 @@
             data: None,
         })?;
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(s) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(s))?
-        } else {
-            tx_data.sender()
+        // A multisig transaction is already signed by its members; otherwise sign here.
+        let multisig = request
+            .multisig_signature
+            .as_deref()
+            .map(|encoded| {
+                crate::utils::sui_multisig::verify(
+                    &crate::utils::sui_multisig::decode(encoded)?,
+                    &tx_data,
+                )
+            })
+            .transpose()?;
+        let (sui_signer, signer) = match multisig.as_ref() {
+            Some(checked) => (None, checked.address),
+            None => {
+                let sui_signer = this
+                    .sui_signer(
+                        request.keystore_path.as_deref(),
+                        request.signer.as_deref(),
+                        Some(tx_data.sender()),
+                    )
+                    .await?;
+                let signer = Self::sui_signer_address(sui_signer.as_ref())?;
+                (Some(sui_signer), signer)
+            }
+        };
+
+        // Rolling-window spend limits: value the sender's outflows with a dry run.
+        let spend = this.sui_spend_entries(&tx_data).await?;
+        let reservation = match Self::spend_limit_guard("sui_confirm_execution", &spend, &row.id) {
+            crate::utils::spend_limits::SpendGuard::Reserved(r) => r,
+            crate::utils::spend_limits::SpendGuard::Blocked(blocked) => return blocked,
         };
 
         // Mark as consumed before signing/broadcast (best-effort).
@@ -1595,17 +1681,44 @@
 
         let tx_data_for_send = tx_data.clone();
 
-        let sent = self
-            .sign_and_execute_tx_data(
-                &keystore,
-                signer,
-                tx_data_for_send,
-                request.allow_sender_mismatch,
-                Some(preflight_enabled),
-                request.allow_preflight_failure,
-                "sui_confirm_execution",
-            )
-            .await;
+        let sent = match (sui_signer.as_deref(), multisig.as_ref()) {
+            (Some(sui_signer), _) => {
+                this.sign_and_execute_tx_data(
+                    sui_signer,
+                    tx_data_for_send,
+                    request.allow_sender_mismatch,
+                    Some(preflight_enabled),
+                    request.allow_preflight_failure,
+                    "sui_confirm_execution",
+                )
+                .await
+            }
+            (None, multisig) => {
+                let signatures = multisig
+                    .map(|checked| vec![GenericSignature::MultiSig(checked.multisig.clone())])
+                    .unwrap_or_default();
+                match this
+                    .sui_preflight_guard(
+                        &tx_data_for_send,
+                        Some(preflight_enabled),
+                        request.allow_preflight_failure,
+                        signer,
+                        "sui_confirm_execution",
+                    )
+                    .await
+                {
+                    Ok(preflight) => this
+                        .execute_signed_tx_data(
+                            tx_data_for_send,
+                            signatures,
+                            "sui_confirm_execution",
+                        )
+                        .await
+                        .map(|result| (result, preflight)),
+                    Err(e) => Err(e),
+                }
+            }
+        };
 
         match sent {
             Ok((result, preflight)) => {
@@ -1621,14 +1734,30 @@
 
                 let digest = result.digest.to_string();
                 let _ = crate::utils::sui_confirm_store::mark_sent(&conn, &row.id, &digest);
+                // Sent: the booked spend stays (a failed row drops out of the ledger).
+                reservation.keep();
+                match result.effects.as_ref().map(|e| e.status()) {
+                    Some(status) if status.is_ok() => {
+                        let _ = crate::utils::sui_confirm_store::mark_confirmed(&conn, &row.id);
+                    }
+                    Some(status) => {
+                        let _ = crate::utils::sui_confirm_store::mark_failed(
+                            &conn,
+                            &row.id,
+                            &format!("execution failed: {:?}", status),
+                        );
+                    }
+                    None => {}
+                }
 
-                self.write_audit_log(
+                this.write_audit_log(
                     "sui_confirm_execution",
                     json!({
                         "event": "sent",
                         "confirmation_id": row.id,
                         "digest": result.digest,
                         "signer": signer.to_string(),
+                        "multisig": multisig.as_ref().map(|checked| checked.to_json()),
                     }),
                 );
 
@@ -1642,6 +1771,7 @@
                     "status": "sent",
                     "confirmation_id": row.id,
                     "digest": result.digest,
+                    "multisig": multisig.as_ref().map(|checked| checked.to_json()),
                     "tool_context": row.tool_context,
                     "summary": stored_summary,
                     "dry_run": preflight,
@@ -1653,7 +1783,7 @@
             Err(e) => {
                 // If confirm-time preflight was enabled, try a best-effort dry-run to capture details.
                 if preflight_enabled {
-                    if let Ok(dr) = self.preflight_tx_data(&tx_data).await {
+                    if let Ok(dr) = this.preflight_tx_data(&tx_data).await {
                         let err = dr.execution_error_source.as_deref();
                         let _ = crate::utils::sui_confirm_store::set_last_dry_run(
                             &conn,
@@ -1665,7 +1795,7 @@
                 }
 
                 let _ = crate::utils::sui_confirm_store::mark_failed(&conn, &row.id, &e.message);
-                self.write_audit_log(
+                this.write_audit_log(
                     "sui_confirm_execution",
                     json!({
                         "event": "failed",
@@ -1698,7 +1828,7 @@
     ) -> Result<CallToolResult, ErrorData> {
         let base_url = request
             .base_url
-            .or_else(|| std::env::var("SUI_AGGREGATOR_BASE_URL").ok())
+            .or_else(|| crate::utils::config::var("SUI_AGGREGATOR_BASE_URL").ok())
             .ok_or_else(|| ErrorData {
                 code: ErrorCode(-32602),
                 message: Cow::from(
@@ -1805,95 +1935,47 @@
         )?;
 
         let now_ms = crate::utils::evm_confirm_store::now_ms() as i64;
-        let limit = request.limit.unwrap_or(20).min(200) as i64;
+        let limit = request.limit.unwrap_or(20).min(200) as usize;
         let include_tx_bytes = request.include_tx_bytes.unwrap_or(false);
 
-        let status = request.status.as_deref().map(|s| s.trim().to_lowercase());
-        if let Some(st) = status.as_deref() {
-            let allowed = ["pending", "consumed", "sent", "failed"];
-            if !allowed.contains(&st) {
-                return Err(ErrorData {
+        let status = match request.status.as_deref() {
+            Some(st) => Some(
+                crate::utils::pending_store::PendingStatus::parse(st).ok_or_else(|| ErrorData {
                     code: ErrorCode(-32602),
-                    message: Cow::from("status must be one of: pending|consumed|sent|failed"),
+                    message: Cow::from(format!(
+                        "status must be one of: {}",
+                        crate::utils::pending_store::PendingStatus::names()
+                    )),
                     data: None,
-                });
-            }
-        }
+                })?,
+            ),
+            None => None,
+        };
 
         let mut items: Vec<Value> = Vec::new();
 
-        let mut sql = "SELECT id, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash, status, digest, last_error, tool_context, summary_json, last_dry_run_error, tx_bytes_b64 FROM sui_pending_confirmations".to_string();
-        let mut params: Vec<rusqlite::types::Value> = Vec::new();
-        if let Some(st) = status {
-            sql.push_str(&format!(" WHERE status = ?{}", params.len() + 1));
-            params.push(rusqlite::types::Value::Text(st));
-        }
-        sql.push_str(" ORDER BY created_at_ms DESC");
-        sql.push_str(&format!(" LIMIT ?{}", params.len() + 1));
-        params.push(rusqlite::types::Value::Integer(limit));
-
-        let mut stmt = conn.prepare(&sql).map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to prepare select: {}", e)),
-            data: None,
-        })?;
-
-        let rows = stmt
-            .query_map(rusqlite::params_from_iter(params), |row| {
-                let id: String = row.get(0)?;
-                let created_at_ms: i64 = row.get(1)?;
-                let updated_at_ms: i64 = row.get(2)?;
-                let expires_at_ms: i64 = row.get(3)?;
-                let tx_summary_hash: String = row.get(4)?;
-                let status: String = row.get(5)?;
-                let digest: Option<String> = row.get(6)?;
-                let last_error: Option<String> = row.get(7)?;
-                let tool_context: Option<String> = row.get(8)?;
-                let summary_json: Option<String> = row.get(9)?;
-                let last_dry_run_error: Option<String> = row.get(10)?;
-                let tx_bytes_b64: Option<String> = row.get(11)?;
-                Ok((
-                    id,
-                    created_at_ms,
-                    updated_at_ms,
-                    expires_at_ms,
-                    tx_summary_hash,
-                    status,
-                    digest,
-                    last_error,
-                    tool_context,
-                    summary_json,
-                    last_dry_run_error,
-                    tx_bytes_b64,
-                ))
-            })
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to query_map: {}", e)),
-                data: None,
-            })?;
-
-        for r in rows.flatten() {
-            let expires_in_ms = (r.3 - now_ms).max(0);
+        for r in crate::utils::sui_confirm_store::list_rows(&conn, status, limit)? {
+            let expires_in_ms = (r.expires_at_ms as i64 - now_ms).max(0);
             items.push(json!({
-                "id": r.0,
-                "created_at_ms": r.1,
-                "updated_at_ms": r.2,
-                "expires_at_ms": r.3,
+                "id": r.id,
+                "network": r.network,
+                "created_at_ms": r.created_at_ms,
+                "updated_at_ms": r.updated_at_ms,
+                "expires_at_ms": r.expires_at_ms,
                 "expires_in_ms": expires_in_ms,
-                "tx_summary_hash": r.4,
-                "status": r.5,
-                "digest": r.6,
-                "last_error": r.7,
-                "tool_context": r.8,
-                "summary": r.9.as_deref().and_then(|s| serde_json::from_str::<Value>(s).ok()),
-                "last_dry_run_error": r.10,
-                "tx_bytes_b64": if include_tx_bytes { r.11 } else { None },
+                "tx_summary_hash": r.tx_summary_hash,
+                "status": r.status,
+                "digest": r.digest,
+                "last_error": r.last_error,
+                "tool_context": r.tool_context,
+                "summary": r.summary_json.as_deref().and_then(|s| serde_json::from_str::<Value>(s).ok()),
+                "last_dry_run_error": r.last_dry_run_error,
+                "tx_bytes_b64": if include_tx_bytes { Some(r.tx_bytes_b64) } else { None },
             }));
         }
 
         let response = Self::pretty_json(&json!({
-            "db_path": crate::utils::evm_confirm_store::pending_db_path_from_cwd()?.to_string_lossy(),
+            "db_path": crate::utils::evm_confirm_store::pending_db_path()?.to_string_lossy(),
             "count": items.len(),
             "items": items
         }))?;
@@ -1915,9 +1997,10 @@
         let row = crate::utils::sui_confirm_store::get_row(&conn, request.id.trim())?;
 
         let response = Self::pretty_json(&json!({
-            "db_path": crate::utils::evm_confirm_store::pending_db_path_from_cwd()?.to_string_lossy(),
+            "db_path": crate::utils::evm_confirm_store::pending_db_path()?.to_string_lossy(),
             "item": row.map(|r| json!({
                 "id": r.id,
+                "network": r.network,
                 "created_at_ms": r.created_at_ms,
                 "updated_at_ms": r.updated_at_ms,
                 "expires_at_ms": r.expires_at_ms,
@@ -1937,7 +2020,7 @@
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
 
-    #[tool(description = "Sui: retry executing a pending/failed/consumed confirmation (sqlite-backed). Safe: requires matching tx_summary_hash.")]
+    #[tool(description = "Sui: retry executing a pending/failed/signed confirmation (sqlite-backed). Safe: requires matching tx_summary_hash.")]
     async fn sui_retry_pending_confirmation(
         &self,
         Parameters(request): Parameters<SuiRetryPendingConfirmationRequest>,
@@ -1965,6 +2048,9 @@
             }
         };
 
+        // Execute on the network the transaction was built against.
+        let this = self.for_sui_network(row.network.as_deref()).await?;
+
         if row.tx_summary_hash != request.tx_summary_hash {
             return Self::guard_result(
                 "sui_retry_pending_confirmation",
@@ -1989,7 +2075,7 @@
         }
 
         // Mainnet safety: require confirm_token.
-        if self.sui_is_mainnet_rpc_url() {
+        if this.sui_is_mainnet() {
             let expected = crate::utils::sui_confirm_store::make_confirm_token(
                 request.id.trim(),
                 request.tx_summary_hash.trim(),
@@ -2020,7 +2106,7 @@
             }
         }
 
-        let allowed = ["pending", "failed", "consumed"];
+        let allowed = ["pending", "failed", "signed"];
         if !allowed.contains(&row.status.as_str()) {
             return Self::guard_result(
                 "sui_retry_pending_confirmation",
@@ -2055,11 +2141,20 @@
             data: None,
         })?;
 
-        let keystore = self.load_file_keystore(request.keystore_path.as_deref())?;
-        let signer = if let Some(s) = request.signer.as_deref() {
-            self.resolve_keystore_signer(&keystore, Some(s))?
-        } else {
-            tx_data.sender()
+        let sui_signer = this
+            .sui_signer(
+                request.keystore_path.as_deref(),
+                request.signer.as_deref(),
+                Some(tx_data.sender()),
+            )
+            .await?;
+        let signer = Self::sui_signer_address(sui_signer.as_ref())?;
+
+        // Rolling-window spend limits: value the sender's outflows with a dry run.
+        let spend = this.sui_spend_entries(&tx_data).await?;
+        let reservation = match Self::spend_limit_guard("sui_retry_pending_confirmation", &spend, &row.id) {
+            crate::utils::spend_limits::SpendGuard::Reserved(r) => r,
+            crate::utils::spend_limits::SpendGuard::Blocked(blocked) => return blocked,
         };
 
         crate::utils::sui_confirm_store::mark_consumed(&conn, &row.id)?;
@@ -2067,10 +2162,9 @@
         let preflight_enabled = request.preflight.unwrap_or(true);
         let tx_data_for_send = tx_data.clone();
 
-        let sent = self
+        let sent = this
             .sign_and_execute_tx_data(
-                &keystore,
-                signer,
+                sui_signer.as_ref(),
                 tx_data_for_send,
                 request.allow_sender_mismatch,
                 Some(preflight_enabled),
@@ -2093,8 +2187,23 @@
 
                 let digest = result.digest.to_string();
                 let _ = crate::utils::sui_confirm_store::mark_sent(&conn, &row.id, &digest);
+                // Sent: the booked spend stays (a failed row drops out of the ledger).
+                reservation.keep();
+                match result.effects.as_ref().map(|e| e.status()) {
+                    Some(status) if status.is_ok() => {
+                        let _ = crate::utils::sui_confirm_store::mark_confirmed(&conn, &row.id);
+                    }
+                    Some(status) => {
+                        let _ = crate::utils::sui_confirm_store::mark_failed(
+                            &conn,
+                            &row.id,
+                            &format!("execution failed: {:?}", status),
+                        );
+                    }
+                    None => {}
+                }
 
-                self.write_audit_log(
+                this.write_audit_log(
                     "sui_retry_pending_confirmation",
                     json!({
                         "event": "sent",
@@ -2124,7 +2233,7 @@
             }
             Err(e) => {
                 if preflight_enabled {
-                    if let Ok(dr) = self.preflight_tx_data(&tx_data).await {
+                    if let Ok(dr) = this.preflight_tx_data(&tx_data).await {
                         let err = dr.execution_error_source.as_deref();
                         let _ = crate::utils::sui_confirm_store::set_last_dry_run(
                             &conn,
@@ -2137,7 +2246,7 @@
 
                 let _ = crate::utils::sui_confirm_store::mark_failed(&conn, &row.id, &e.message);
 
-                self.write_audit_log(
+                this.write_audit_log(
                     "sui_retry_pending_confirmation",
                     json!({
                         "event": "failed",
@@ -2169,7 +2278,8 @@
         &self,
         Parameters(request): Parameters<BuildAddStakeRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, gas_budget) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, gas_budget) = this
             .build_add_stake_data(
                 &request.sender,
                 &request.validator,
@@ -2193,7 +2303,8 @@
         &self,
         Parameters(request): Parameters<BuildWithdrawStakeRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let (tx_data, gas_budget) = self
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let (tx_data, gas_budget) = this
             .build_withdraw_stake_data(
                 &request.sender,
                 &request.staked_sui,
@@ -2215,6 +2326,7 @@
         &self,
         Parameters(request): Parameters<BuildUpgradeRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let package_id = Self::parse_object_id(&request.package_id)?;
         let dependencies = Self::parse_object_ids(&request.dependencies)?;
@@ -2230,7 +2342,7 @@
             .collect::<Result<Vec<_>, _>>()?;
         let digest = Self::decode_base64("digest", &request.digest)?;
 
-        let tx_data = self
+        let tx_data = this
             .client
             .transaction_builder()
             .upgrade(
@@ -2255,10 +2367,10 @@
         if let Some(url) = override_url {
             return Ok(url.to_string());
         }
-        if let Ok(url) = std::env::var("SUI_7K_BASE_URL") {
+        if let Ok(url) = crate::utils::config::var("SUI_7K_BASE_URL") {
             return Ok(url);
         }
-        if let Ok(url) = std::env::var("SUI_AGGREGATOR_BASE_URL") {
+        if let Ok(url) = crate::utils::config::var("SUI_AGGREGATOR_BASE_URL") {
             return Ok(url);
         }
         Ok("https://api.7k.ag".to_string())
@@ -2270,6 +2382,7 @@
         "0x47442a93f7727d188ba7cb71031170d1786af70013cb7ad5115f3fe877ff0c54";
     const SEVEN_K_VAULT_ID: &'static str =
         "0x442ad50389ed5cda6f7a6f5a7ae6361a4c05ef1d9fb2e54fbba5a268d690bfe6";
+    const SEVEN_K_DEFAULT_SOURCES: &'static str = "suiswap,turbos,cetus,bluemove,kriya,kriya_v3,aftermath,deepbook_v3,flowx,flowx_v3,bluefin,springsui,obric,stsui,steamm,magma,haedal_pmm,momentum,sevenk_v1,fullsail,cetus_dlmm,ferra_dlmm,ferra_clmm";
 
     /// Helper to make 7K quote request with automatic fallback
     async fn make_7k_quote_request(
@@ -2961,11 +3074,13 @@
     ) -> Result<ObjectArg, ErrorData> {
         let object_id = Self::parse_object_id(object_id)?;
         let object = self
-            .client
-            .read_api()
-            .get_object_with_options(object_id, SuiObjectDataOptions::new().with_owner())
-            .await
-            .map_err(|e| Self::sdk_error("sui_7k_build_swap_tx:get_object", e))?;
+            .sui_read("sui_7k_build_swap_tx:get_object", |client| async move {
+                client
+                    .read_api()
+                    .get_object_with_options(object_id, SuiObjectDataOptions::new().with_owner())
+                    .await
+            })
+            .await?;
         let object = object.object().map_err(|e| ErrorData {
             code: ErrorCode(-32602),
             message: Cow::from(format!("Object not found: {}", e)),
@@ -3002,11 +3117,13 @@
         amount: u64,
     ) -> Result<Vec<sui_types::base_types::ObjectRef>, ErrorData> {
         let coins = self
-            .client
-            .coin_read_api()
-            .get_coins(sender, Some(coin_type.to_string()), None, None)
-            .await
-            .map_err(|e| Self::sdk_error("sui_7k_build_swap_tx:get_coins", e))?;
+            .sui_read("sui_7k_build_swap_tx:get_coins", |client| async move {
+                client
+                    .coin_read_api()
+                    .get_coins(sender, Some(coin_type.to_string()), None, None)
+                    .await
+            })
+            .await?;
         let mut coin_list = coins.data;
         if coin_list.is_empty() {
             return Err(ErrorData {
@@ -3326,8 +3443,10 @@
     ) -> Result<CallToolResult, ErrorData> {
         let base_url = Self::get_7k_base_url(request.base_url.as_deref())?;
 
-        let default_sources = "suiswap,turbos,cetus,bluemove,kriya,kriya_v3,aftermath,deepbook_v3,flowx,flowx_v3,bluefin,springsui,obric,stsui,steamm,magma,haedal_pmm,momentum,sevenk_v1,fullsail,cetus_dlmm,ferra_dlmm,ferra_clmm";
-        let sources = request.sources.as_deref().unwrap_or(default_sources);
+        let sources = request
+            .sources
+            .as_deref()
+            .unwrap_or(Self::SEVEN_K_DEFAULT_SOURCES);
 
         let timeout_ms = request.timeout_ms.unwrap_or(15_000);
 
@@ -3370,6 +3489,7 @@
         &self,
         Parameters(request): Parameters<Sui7kBuildSwapTxRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let sender = Self::parse_address(&request.sender)?;
         let slippage_bps = request.slippage_bps.unwrap_or(100);
         let commission_bps = request.commission_bps.unwrap_or(0);
@@ -3409,32 +3529,31 @@
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let coin_refs = self
+        let coin_refs = this
             .select_coin_refs_for_amount(sender, token_in, swap_amount)
             .await?;
 
-        let config_arg = self
+        let config_arg = this
             .resolve_shared_object_arg(
                 Self::SEVEN_K_CONFIG_ID,
                 sui_types::transaction::SharedObjectMutability::Immutable,
             )
             .await?;
-        let vault_arg = self
+        let vault_arg = this
             .resolve_shared_object_arg(
                 Self::SEVEN_K_VAULT_ID,
                 sui_types::transaction::SharedObjectMutability::Mutable,
             )
             .await?;
 
-        let gas_price = self
-            .client
-            .read_api()
-            .get_reference_gas_price()
-            .await
-            .map_err(|e| Self::sdk_error("sui_7k_build_swap_tx:gas_price", e))?;
+        let gas_price = this
+            .sui_read("sui_7k_build_swap_tx:gas_price", |client| async move {
+                client.read_api().get_reference_gas_price().await
+            })
+            .await?;
 
         let mut gas_budget = 1_000_000;
-        let tx_data = self
+        let tx_data = this
             .build_7k_swap_tx_data(
                 sender,
                 token_in,
@@ -3454,10 +3573,10 @@
             )
             .await?;
 
-        let estimated = self.estimate_gas_budget(&tx_data).await?;
+        let estimated = this.estimate_gas_budget(&tx_data).await?;
         gas_budget = Self::gas_budget_with_buffer(estimated);
         let tx_data = if gas_budget != 1_000_000 {
-                self
+                this
                 .build_7k_swap_tx_data(
                     sender,
                     token_in,
@@ -3502,16 +3621,19 @@
         &self,
         Parameters(request): Parameters<Sui7kSwapExactInRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let base_url = Self::get_7k_base_url(request.base_url.as_deref())?;
         let _sender_addr = Self::parse_address(&request.sender)?;
         let slippage_bps = request.slippage_bps.unwrap_or(100);
         let commission_bps = request.commission_bps.unwrap_or(0);
         let timeout_ms = request.timeout_ms.unwrap_or(15_000);
 
-        let default_sources = "suiswap,turbos,cetus,bluemove,kriya,kriya_v3,aftermath,deepbook_v3,flowx,flowx_v3,bluefin,springsui,obric,stsui,steamm,magma,haedal_pmm,momentum,sevenk_v1,fullsail,cetus_dlmm,ferra_dlmm,ferra_clmm";
-        let sources = request.sources.as_deref().unwrap_or(default_sources);
+        let sources = request
+            .sources
+            .as_deref()
+            .unwrap_or(Self::SEVEN_K_DEFAULT_SOURCES);
 
-        let (quote, quote_endpoint) = self
+        let (quote, quote_endpoint) = this
             .make_7k_quote_request(
                 &base_url,
                 &request.amount_in,
@@ -3532,7 +3654,7 @@
             return_amount_num * (10000 - commission_bps as u128) / 10000;
         let min_received = return_after_commission * (10000 - slippage_bps as u128) / 10000;
 
-        let is_mainnet = self.resolve_network_kind() == "mainnet";
+        let is_mainnet = this.resolve_network_kind() == "mainnet";
         let skip_confirmation = request.skip_confirmation.unwrap_or(false);
 
         let client = reqwest::Client::builder()
@@ -3666,6 +3788,7 @@
                 &tx_summary_hash,
                 "sui_7k_swap_exact_in",
                 Some(swap_summary.clone()),
+                &this.sui_network.key(),
             )?;
 
             let confirm_token = crate::utils::sui_confirm_store::make_confirm_token(&confirmation_id, &tx_summary_hash);
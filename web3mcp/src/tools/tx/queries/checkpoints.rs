@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<GetCheckpointRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let checkpoint_id = match (request.sequence_number, request.digest) {
             (Some(seq), None) => CheckpointId::SequenceNumber(seq),
             (None, Some(digest)) => {
@@ -19,12 +20,12 @@
             }
         };
 
-        let result = self
-            .client
-            .read_api()
-            .get_checkpoint(checkpoint_id)
-            .await
-            .map_err(|e| Self::sdk_error("get_checkpoint", e))?;
+        let result = this
+            .sui_read("get_checkpoint", |client| {
+                let checkpoint_id = checkpoint_id.clone();
+                async move { client.read_api().get_checkpoint(checkpoint_id).await }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -36,16 +37,21 @@
         &self,
         Parameters(request): Parameters<GetCheckpointsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let cursor = request.cursor.map(BigInt::from);
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let limit = request.limit.map(|limit| Self::clamp_limit(Some(limit), limit, 100));
         let descending = request.descending_order.unwrap_or(false);
 
-        let result = self
-            .client
-            .read_api()
-            .get_checkpoints(cursor, limit, descending)
-            .await
-            .map_err(|e| Self::sdk_error("get_checkpoints", e))?;
+        let result = this
+            .sui_read("get_checkpoints", |client| {
+                let cursor = request.cursor.map(BigInt::from);
+                async move {
+                    client
+                        .read_api()
+                        .get_checkpoints(cursor, limit, descending)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -4,15 +4,22 @@
         &self,
         Parameters(request): Parameters<SelectCoinsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let owner = Self::parse_address(&request.owner)?;
         let exclude = Self::parse_object_ids(&request.exclude)?;
 
-        let result = self
-            .client
-            .coin_read_api()
-            .select_coins(owner, request.coin_type, request.amount, exclude)
-            .await
-            .map_err(|e| Self::sdk_error("select_coins", e))?;
+        let result = this
+            .sui_read("select_coins", |client| {
+                let coin_type = request.coin_type.clone();
+                let exclude = exclude.clone();
+                async move {
+                    client
+                        .coin_read_api()
+                        .select_coins(owner, coin_type, request.amount, exclude)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -24,12 +31,13 @@
         &self,
         Parameters(request): Parameters<GetCoinMetadataRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .coin_read_api()
-            .get_coin_metadata(request.coin_type)
-            .await
-            .map_err(|e| Self::sdk_error("get_coin_metadata", e))?;
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("get_coin_metadata", |client| {
+                let coin_type = request.coin_type.clone();
+                async move { client.coin_read_api().get_coin_metadata(coin_type).await }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -41,12 +49,13 @@
         &self,
         Parameters(request): Parameters<GetTotalSupplyRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .coin_read_api()
-            .get_total_supply(request.coin_type)
-            .await
-            .map_err(|e| Self::sdk_error("get_total_supply", e))?;
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("get_total_supply", |client| {
+                let coin_type = request.coin_type.clone();
+                async move { client.coin_read_api().get_total_supply(coin_type).await }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -4,6 +4,7 @@
         &self,
         Parameters(request): Parameters<QueryTransactionBlocksRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let filter = match request.filter {
             Some(value) => Some(
                 serde_json::from_value::<TransactionFilter>(value).map_err(|e| ErrorData {
@@ -23,12 +24,17 @@
         let limit = request.limit.map(|limit| Self::clamp_limit(Some(limit), limit, 50));
         let descending = request.descending_order.unwrap_or(false);
 
-        let result = self
-            .client
-            .read_api()
-            .query_transaction_blocks(query, cursor, limit, descending)
-            .await
-            .map_err(|e| Self::sdk_error("query_transaction_blocks", e))?;
+        let result = this
+            .sui_read("query_transaction_blocks", |client| {
+                let query = query.clone();
+                async move {
+                    client
+                        .read_api()
+                        .query_transaction_blocks(query, cursor, limit, descending)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -40,6 +46,7 @@
         &self,
         Parameters(request): Parameters<MultiGetTransactionsRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let digests = request
             .digests
             .iter()
@@ -47,12 +54,18 @@
             .collect::<Result<Vec<_>, _>>()?;
         let options = Self::tx_options_from_request(request.options);
 
-        let result = self
-            .client
-            .read_api()
-            .multi_get_transactions_with_options(digests, options)
-            .await
-            .map_err(|e| Self::sdk_error("multi_get_transactions", e))?;
+        let result = this
+            .sui_read("multi_get_transactions", |client| {
+                let digests = digests.clone();
+                let options = options.clone();
+                async move {
+                    client
+                        .read_api()
+                        .multi_get_transactions_with_options(digests, options)
+                        .await
+                }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
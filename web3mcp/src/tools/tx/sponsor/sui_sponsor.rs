@@ -0,0 +1,621 @@
+    /// Move targets, gas-coin use and never-sponsored commands of a transaction kind.
+    fn sui_sponsor_tx_shape(
+        kind: &sui_types::transaction::TransactionKind,
+    ) -> Result<crate::utils::sui_sponsor::TxShape, ErrorData> {
+        use sui_types::transaction::{Argument, Command, TransactionKind};
+
+        let pt = match kind {
+            TransactionKind::ProgrammableTransaction(pt) => pt,
+            _ => {
+                return Err(ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("Only programmable transactions can be sponsored"),
+                    data: None,
+                })
+            }
+        };
+
+        let mut shape = crate::utils::sui_sponsor::TxShape::default();
+        let mut arguments: Vec<&Argument> = Vec::new();
+        for command in &pt.commands {
+            match command {
+                Command::MoveCall(call) => {
+                    shape.move_targets.push(format!(
+                        "{}::{}::{}",
+                        call.package, call.module, call.function
+                    ));
+                    arguments.extend(call.arguments.iter());
+                }
+                Command::TransferObjects(objects, recipient) => {
+                    arguments.extend(objects.iter());
+                    arguments.push(recipient);
+                }
+                Command::SplitCoins(coin, amounts) => {
+                    arguments.push(coin);
+                    arguments.extend(amounts.iter());
+                }
+                Command::MergeCoins(target, coins) => {
+                    arguments.push(target);
+                    arguments.extend(coins.iter());
+                }
+                Command::MakeMoveVec(_, elements) => arguments.extend(elements.iter()),
+                Command::Publish(_, _) => shape.unsupported.push("Publish".to_string()),
+                Command::Upgrade(_, _, _, ticket) => {
+                    shape.unsupported.push("Upgrade".to_string());
+                    arguments.push(ticket);
+                }
+            }
+        }
+        shape.uses_gas_coin = arguments.iter().any(|a| matches!(a, Argument::GasCoin));
+        Ok(shape)
+    }
+
+    /// `kind` sent by `sender` with gas paid from `sponsor`'s coins.
+    fn sui_sponsored_tx_data(
+        kind: sui_types::transaction::TransactionKind,
+        sender: SuiAddress,
+        sponsor: SuiAddress,
+        payment: Vec<sui_types::base_types::ObjectRef>,
+        gas_budget: u64,
+        gas_price: u64,
+    ) -> TransactionData {
+        TransactionData::new_with_gas_coins_allow_sponsor(
+            kind, sender, payment, gas_budget, gas_price, sponsor,
+        )
+    }
+
+    /// Sponsor policy, or a guard result when no sponsor account is configured.
+    fn sui_sponsor_policy(
+        context: &str,
+    ) -> Result<crate::utils::sui_sponsor::Policy, Result<CallToolResult, ErrorData>> {
+        let policy = crate::utils::sui_sponsor::Policy::from_config();
+        if policy.account.is_none() {
+            return Err(Self::guard_result(
+                context,
+                "SPONSOR_NOT_CONFIGURED",
+                "No Sui gas sponsor is configured",
+                false,
+                Some("Set sui.sponsor.account (SUI_SPONSOR_ACCOUNT) to a Sui account of the signer keystore"),
+                None,
+                None,
+            ));
+        }
+        Ok(policy)
+    }
+
+    /// Check a transaction against the sponsor policy and the sender's budget; `Some` is the
+    /// guard result to return.
+    fn sui_sponsor_check(
+        &self,
+        context: &str,
+        policy: &crate::utils::sui_sponsor::Policy,
+        kind: &sui_types::transaction::TransactionKind,
+        sender: SuiAddress,
+        gas_budget: u64,
+    ) -> Result<Option<Result<CallToolResult, ErrorData>>, ErrorData> {
+        let shape = Self::sui_sponsor_tx_shape(kind)?;
+        let conn = crate::utils::sui_sponsor::connect()?;
+        let now = crate::utils::pending_store::now_ms();
+        let spent = crate::utils::sui_sponsor::spent_since(
+            &conn,
+            &sender.to_string(),
+            now.saturating_sub(policy.window_ms),
+        )?;
+
+        let violations = policy.violations(&shape, gas_budget, spent);
+        if violations.is_empty() {
+            return Ok(None);
+        }
+        let budget_only = shape.move_targets.iter().all(|t| policy.move_target_allowed(t))
+            && shape.unsupported.is_empty()
+            && !shape.uses_gas_coin
+            && gas_budget <= policy.max_gas_budget;
+        self.write_audit_log(
+            context,
+            json!({
+                "event": "sponsor_denied",
+                "sender": sender.to_string(),
+                "gas_budget": gas_budget,
+                "violations": violations
+            }),
+        );
+        let extra = json!({
+            "sender": sender.to_string(),
+            "violations": violations,
+            "move_targets": shape.move_targets,
+            "sponsored_in_window_mist": spent,
+            "policy": policy.to_json()
+        });
+        Ok(Some(if budget_only {
+            Self::guard_result(
+                context,
+                "SPONSOR_BUDGET_EXCEEDED",
+                "Sender exceeded its sponsored gas budget for the current window",
+                true,
+                Some("Wait for the budget window to roll over, or pay gas from the sender"),
+                None,
+                Some(extra),
+            )
+        } else {
+            Self::guard_result(
+                context,
+                "SPONSOR_POLICY_DENIED",
+                "Transaction is not eligible for gas sponsorship",
+                false,
+                Some("Only call Move targets in sui.sponsor.allowed_move_targets and never use the gas coin"),
+                None,
+                Some(extra),
+            )
+        }))
+    }
+
+    /// Wrap `kind` into sponsored `TransactionData`: reference gas price, sponsor coins, and a
+    /// buffered dry-run budget (or `gas_budget`) capped by the policy.
+    async fn sui_sponsor_wrap(
+        &self,
+        policy: &crate::utils::sui_sponsor::Policy,
+        sponsor: SuiAddress,
+        sender: SuiAddress,
+        kind: sui_types::transaction::TransactionKind,
+        gas_budget: Option<u64>,
+    ) -> Result<(TransactionData, u64), ErrorData> {
+        if sender == sponsor {
+            return Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("The sponsor account cannot sponsor its own transactions"),
+                data: Some(json!({ "sponsor": sponsor.to_string() })),
+            });
+        }
+        let gas_price = self
+            .sui_read("sui_sponsor_build", |client| async move {
+                client.read_api().get_reference_gas_price().await
+            })
+            .await?;
+
+        let mut coins = self
+            .sui_read("sui_sponsor_build", |client| async move {
+                client
+                    .coin_read_api()
+                    .get_coins(sponsor, None, None, None)
+                    .await
+            })
+            .await?
+            .data;
+        coins.sort_by(|a, b| b.balance.cmp(&a.balance));
+        let mut payment = Vec::new();
+        let mut total: u64 = 0;
+        for coin in coins {
+            total = total.saturating_add(coin.balance);
+            payment.push(coin.object_ref());
+            if total >= policy.max_gas_budget {
+                break;
+            }
+        }
+        if payment.is_empty() {
+            return Err(ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from("Gas sponsor has no SUI coins"),
+                data: Some(json!({ "sponsor": sponsor.to_string() })),
+            });
+        }
+
+        let gas_budget = match gas_budget {
+            Some(budget) => budget,
+            None => {
+                let probe = Self::sui_sponsored_tx_data(
+                    kind.clone(),
+                    sender,
+                    sponsor,
+                    payment.clone(),
+                    policy.max_gas_budget.min(total),
+                    gas_price,
+                );
+                let estimate = self.estimate_gas_budget(&probe).await?;
+                Self::gas_budget_with_buffer(estimate).min(policy.max_gas_budget)
+            }
+        };
+        if gas_budget > total {
+            return Err(ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from("Gas sponsor balance is below the gas budget"),
+                data: Some(json!({
+                    "sponsor": sponsor.to_string(),
+                    "balance_mist": total,
+                    "gas_budget": gas_budget
+                })),
+            });
+        }
+
+        let tx_data =
+            Self::sui_sponsored_tx_data(kind, sender, sponsor, payment, gas_budget, gas_price);
+        Ok((tx_data, gas_budget))
+    }
+
+    /// Shared tail of the sponsored build tools.
+    async fn sui_sponsor_build_response(
+        &self,
+        context: &str,
+        sender: SuiAddress,
+        kind: sui_types::transaction::TransactionKind,
+        gas_budget: Option<u64>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let policy = match Self::sui_sponsor_policy(context) {
+            Ok(policy) => policy,
+            Err(guard) => return guard,
+        };
+        // Check the commands first so a denied transaction never costs a dry run.
+        if let Some(guard) =
+            self.sui_sponsor_check(context, &policy, &kind, sender, gas_budget.unwrap_or(0))?
+        {
+            return guard;
+        }
+        let sponsor_signer = Self::sui_sponsor_signer(policy.account.as_deref().unwrap_or(""))?;
+        let sponsor = Self::sui_signer_address(sponsor_signer.as_ref())?;
+
+        let (tx_data, gas_budget) = self
+            .sui_sponsor_wrap(&policy, sponsor, sender, kind.clone(), gas_budget)
+            .await?;
+        if let Some(guard) = self.sui_sponsor_check(context, &policy, &kind, sender, gas_budget)? {
+            return guard;
+        }
+
+        let response = Self::pretty_json(&json!({
+            "tx_bytes": Self::encode_tx_bytes(&tx_data)?,
+            "sender": sender.to_string(),
+            "sponsor": sponsor.to_string(),
+            "gas_budget": gas_budget,
+            "gas_price": tx_data.gas_price(),
+            "network": self.resolve_network_kind(),
+            "next": "Sign tx_bytes as the sender, then call sui_execute_sponsored with the signature"
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Sponsor policy, sponsor account and balance
+    #[tool(description = "Sui gas station: show the sponsor policy, sponsor address and balance, and (optionally) the gas sponsored for a sender in the current window")]
+    async fn sui_sponsor_status(
+        &self,
+        Parameters(request): Parameters<SuiSponsorStatusRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let policy = crate::utils::sui_sponsor::Policy::from_config();
+
+        let mut response = json!({
+            "enabled": policy.account.is_some(),
+            "network": this.resolve_network_kind(),
+            "policy": policy.to_json()
+        });
+        if let Some(account) = policy.account.as_deref() {
+            match Self::sui_sponsor_signer(account)
+                .and_then(|signer| Self::sui_signer_address(signer.as_ref()))
+            {
+                Ok(sponsor) => {
+                    response["sponsor"] = json!(sponsor.to_string());
+                    let balance = this
+                        .sui_read("sui_sponsor_status", |client| async move {
+                            client.coin_read_api().get_balance(sponsor, None).await
+                        })
+                        .await?;
+                    response["sponsor_balance_mist"] = json!(balance.total_balance.to_string());
+                }
+                Err(e) => response["sponsor_error"] = json!(e.message),
+            }
+        }
+        if let Some(sender) = request.sender.as_deref() {
+            let sender = Self::parse_address(sender)?;
+            let conn = crate::utils::sui_sponsor::connect()?;
+            let spent = crate::utils::sui_sponsor::spent_since(
+                &conn,
+                &sender.to_string(),
+                crate::utils::pending_store::now_ms().saturating_sub(policy.window_ms),
+            )?;
+            response["sender"] = json!({
+                "address": sender.to_string(),
+                "sponsored_in_window_mist": spent,
+                "remaining_mist": policy.sender_budget_mist.saturating_sub(spent)
+            });
+        }
+
+        let response = Self::pretty_json(&response)?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Wrap a transaction into a sponsored one
+    #[tool(description = "Sui gas station: wrap transaction bytes or transaction kind bytes into a sponsored transaction paid by the configured sponsor (policy-checked, not signed)")]
+    async fn sui_sponsor_build(
+        &self,
+        Parameters(request): Parameters<SuiSponsorBuildRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let sender = Self::parse_address(&request.sender)?;
+
+        let kind: sui_types::transaction::TransactionKind =
+            match (request.tx_bytes.as_deref(), request.tx_kind_bytes.as_deref()) {
+                (Some(tx_bytes), None) => {
+                    let bytes = Self::decode_base64("tx_bytes", tx_bytes)?;
+                    let tx_data: TransactionData =
+                        bcs::from_bytes(&bytes).map_err(|e| ErrorData {
+                            code: ErrorCode(-32602),
+                            message: Cow::from(format!("Invalid transaction bytes: {}", e)),
+                            data: None,
+                        })?;
+                    if tx_data.sender() != sender {
+                        return Err(ErrorData {
+                            code: ErrorCode(-32602),
+                            message: Cow::from(format!(
+                                "Transaction sender {} does not match sender {}",
+                                tx_data.sender(),
+                                sender
+                            )),
+                            data: None,
+                        });
+                    }
+                    tx_data.into_kind()
+                }
+                (None, Some(kind_bytes)) => {
+                    let bytes = Self::decode_base64("tx_kind_bytes", kind_bytes)?;
+                    bcs::from_bytes(&bytes).map_err(|e| ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from(format!("Invalid transaction kind bytes: {}", e)),
+                        data: None,
+                    })?
+                }
+                _ => {
+                    return Err(ErrorData {
+                        code: ErrorCode(-32602),
+                        message: Cow::from("Provide exactly one of tx_bytes or tx_kind_bytes"),
+                        data: None,
+                    })
+                }
+            };
+
+        this.sui_sponsor_build_response("sui_sponsor_build", sender, kind, request.gas_budget)
+            .await
+    }
+
+    /// Build a sponsored Move call transaction
+    #[tool(description = "Sui gas station: build a Move call transaction whose gas is paid by the configured sponsor (policy-checked, not signed)")]
+    async fn build_sponsored_move_call(
+        &self,
+        Parameters(request): Parameters<BuildSponsoredMoveCallRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let sender = Self::parse_address(&request.sender)?;
+        let package = Self::parse_object_id(&request.package)?;
+        let type_args = request
+            .type_args
+            .into_iter()
+            .map(SuiTypeTag::new)
+            .collect::<Vec<_>>();
+        let call_args = Self::parse_json_args(&request.arguments)?;
+
+        let kind = this
+            .client
+            .transaction_builder()
+            .move_call_tx_kind(
+                package,
+                &request.module,
+                &request.function,
+                type_args,
+                call_args,
+            )
+            .await
+            .map_err(|e| Self::sdk_error("build_sponsored_move_call", e))?;
+
+        this.sui_sponsor_build_response(
+            "build_sponsored_move_call",
+            sender,
+            kind,
+            request.gas_budget,
+        )
+        .await
+    }
+
+    /// Co-sign and execute a sponsored transaction
+    #[tool(description = "Sui gas station: verify the sender signature, co-sign as gas sponsor and execute a transaction built by sui_sponsor_build (policy and per-sender budget re-checked)")]
+    async fn sui_execute_sponsored(
+        &self,
+        Parameters(request): Parameters<SuiExecuteSponsoredRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        use shared_crypto::intent::{Intent, IntentMessage};
+        use sui_types::crypto::SuiSignature;
+
+        let context = "sui_execute_sponsored";
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let policy = match Self::sui_sponsor_policy(context) {
+            Ok(policy) => policy,
+            Err(guard) => return guard,
+        };
+        let sponsor_signer = Self::sui_sponsor_signer(policy.account.as_deref().unwrap_or(""))?;
+        let sponsor = Self::sui_signer_address(sponsor_signer.as_ref())?;
+
+        let tx_bytes = Self::decode_base64("tx_bytes", &request.tx_bytes)?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid transaction bytes: {}", e)),
+            data: None,
+        })?;
+        let sender = tx_data.sender();
+        if tx_data.gas_owner() != sponsor || sender == sponsor {
+            return Err(ErrorData {
+                code: ErrorCode(-32602),
+                message: Cow::from("Transaction gas is not paid by the configured sponsor"),
+                data: Some(json!({
+                    "gas_owner": tx_data.gas_owner().to_string(),
+                    "sponsor": sponsor.to_string(),
+                    "sender": sender.to_string()
+                })),
+            });
+        }
+        if let Some(guard) =
+            this.sui_sponsor_check(context, &policy, tx_data.kind(), sender, tx_data.gas_budget())?
+        {
+            return guard;
+        }
+
+        // The sponsor signature is only useful together with a valid sender signature.
+        let sender_bytes = Self::decode_base64("sender_signature", request.sender_signature.trim())?;
+        let sender_signature = GenericSignature::from_bytes(&sender_bytes).map_err(|e| {
+            crate::utils::signer::signature_invalid(
+                format!("Invalid sender signature: {}", e),
+                json!({ "sender": sender.to_string() }),
+            )
+        })?;
+        match &sender_signature {
+            GenericSignature::Signature(sig) => {
+                let msg = IntentMessage::new(Intent::sui_transaction(), tx_data.clone());
+                sig.verify_secure(&msg, sender, sig.scheme()).map_err(|e| {
+                    crate::utils::signer::signature_invalid(
+                        format!("Sender signature does not verify for this transaction: {}", e),
+                        json!({ "sender": sender.to_string() }),
+                    )
+                })?;
+            }
+            GenericSignature::MultiSig(multisig) => {
+                crate::utils::sui_multisig::verify(multisig, &tx_data)?;
+            }
+            // Needs the JWKs and epoch the validators use, so the fullnode's verifier checks it.
+            GenericSignature::ZkLoginAuthenticator(_) => {
+                let verdict = this
+                    .sui_read(context, |client| {
+                        let bytes = request.tx_bytes.trim().to_string();
+                        let signature = request.sender_signature.trim().to_string();
+                        async move {
+                            client
+                                .read_api()
+                                .verify_zklogin_signature(
+                                    bytes,
+                                    signature,
+                                    ZkLoginIntentScope::TransactionData,
+                                    sender,
+                                )
+                                .await
+                        }
+                    })
+                    .await?;
+                if !verdict.success {
+                    return Err(crate::utils::signer::signature_invalid(
+                        "zkLogin sender signature does not verify for this transaction",
+                        json!({ "sender": sender.to_string(), "errors": verdict.errors }),
+                    ));
+                }
+            }
+            // Passkey signatures cannot be checked here, and the sponsor never co-signs blind.
+            _ => {
+                return Err(crate::utils::signer::signature_invalid(
+                    "Passkey sender signatures cannot be sponsored; sign with a key, multisig or zkLogin",
+                    json!({ "sender": sender.to_string() }),
+                ));
+            }
+        }
+
+        this.sui_preflight_guard(
+            &tx_data,
+            Some(request.preflight.unwrap_or(true)),
+            Some(false),
+            sender,
+            context,
+        )
+        .await?;
+
+        let digest = tx_data.digest().to_string();
+        let network = this.resolve_network_kind();
+        let mut conn = crate::utils::sui_sponsor::connect()?;
+        // Re-checks the window budget atomically: the check above ran before the preflight.
+        let reservation = crate::utils::sui_sponsor::reserve(
+            &mut conn,
+            &policy,
+            &digest,
+            &sender.to_string(),
+            Some(&network),
+            tx_data.gas_budget(),
+            crate::utils::pending_store::now_ms(),
+        )?;
+        if let crate::utils::sui_sponsor::Reservation::OverBudget { spent } = reservation {
+            return Self::guard_result(
+                context,
+                "SPONSOR_BUDGET_EXCEEDED",
+                "Sender exceeded its sponsored gas budget for the current window",
+                true,
+                Some("Wait for the budget window to roll over, or pay gas from the sender"),
+                None,
+                Some(json!({
+                    "sender": sender.to_string(),
+                    "gas_budget": tx_data.gas_budget(),
+                    "sponsored_in_window_mist": spent,
+                    "policy": policy.to_json()
+                })),
+            );
+        }
+
+        let sponsor_signature = match Self::sui_sign_tx_data(sponsor_signer.as_ref(), &tx_data).await
+        {
+            Ok(sig) => sig,
+            Err(e) => {
+                let _ = crate::utils::sui_sponsor::release(&conn, &digest);
+                return Err(e);
+            }
+        };
+        let result = match this
+            .execute_signed_tx_data(
+                tx_data.clone(),
+                vec![sender_signature, GenericSignature::Signature(sponsor_signature)],
+                context,
+            )
+            .await
+        {
+            Ok(result) => result,
+            // The transaction may have been submitted (e.g. a timeout), so the reservation stays
+            // booked at the full gas budget rather than uncounting gas the sponsor paid.
+            Err(e) => {
+                self.write_audit_log(
+                    context,
+                    json!({
+                        "event": "execute_failed",
+                        "digest": digest,
+                        "sender": sender.to_string(),
+                        "sponsor": sponsor.to_string(),
+                        "reserved_gas_mist": tx_data.gas_budget(),
+                        "error": e.message
+                    }),
+                );
+                return Err(e);
+            }
+        };
+
+        // Failed executions still charge gas, so settle whatever the effects report.
+        let gas_charged = result
+            .effects
+            .as_ref()
+            .map(|effects| effects.gas_cost_summary().net_gas_usage().max(0) as u64)
+            .unwrap_or_else(|| tx_data.gas_budget());
+        crate::utils::sui_sponsor::settle(
+            &conn,
+            &digest,
+            gas_charged,
+            crate::utils::pending_store::now_ms(),
+        )?;
+
+        self.write_audit_log(
+            context,
+            json!({
+                "event": "sent",
+                "digest": result.digest.to_string(),
+                "network": network,
+                "sender": sender.to_string(),
+                "sponsor": sponsor.to_string(),
+                "gas_budget": tx_data.gas_budget(),
+                "gas_charged_mist": gas_charged
+            }),
+        );
+
+        let response = Self::pretty_json(&json!({
+            "status": "sent",
+            "digest": result.digest.to_string(),
+            "sender": sender.to_string(),
+            "sponsor": sponsor.to_string(),
+            "gas_charged_mist": gas_charged,
+            "result": result
+        }))?;
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
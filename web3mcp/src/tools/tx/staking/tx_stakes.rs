@@ -4,13 +4,13 @@
         &self,
         Parameters(request): Parameters<GetStakesRequest>,
     ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
         let owner = Self::parse_address(&request.owner)?;
-        let result = self
-            .client
-            .governance_api()
-            .get_stakes(owner)
-            .await
-            .map_err(|e| Self::sdk_error("get_stakes", e))?;
+        let result = this
+            .sui_read("get_stakes", |client| async move {
+                client.governance_api().get_stakes(owner).await
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -22,13 +22,13 @@
         &self,
         Parameters(request): Parameters<GetCommitteeInfoRequest>,
     ) -> Result<CallToolResult, ErrorData> {
-        let epoch = request.epoch.map(BigInt::from);
-        let result = self
-            .client
-            .governance_api()
-            .get_committee_info(epoch)
-            .await
-            .map_err(|e| Self::sdk_error("get_committee_info", e))?;
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("get_committee_info", |client| {
+                let epoch = request.epoch.map(BigInt::from);
+                async move { client.governance_api().get_committee_info(epoch).await }
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -36,13 +36,16 @@
 
     /// Get latest Sui system state
     #[tool(description = "Get the latest Sui system state summary")]
-    async fn get_latest_sui_system_state(&self) -> Result<CallToolResult, ErrorData> {
-        let result = self
-            .client
-            .governance_api()
-            .get_latest_sui_system_state()
-            .await
-            .map_err(|e| Self::sdk_error("get_latest_sui_system_state", e))?;
+    async fn get_latest_sui_system_state(
+        &self,
+        Parameters(request): Parameters<SuiNetworkRequest>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let this = self.for_sui_network(request.network.as_deref()).await?;
+        let result = this
+            .sui_read("get_latest_sui_system_state", |client| async move {
+                client.governance_api().get_latest_sui_system_state().await
+            })
+            .await?;
 
         let response = Self::pretty_json(&result)?;
         Ok(CallToolResult::success(vec![Content::text(response)]))
@@ -106,4 +106,74 @@ mod tests {
         let tx_bytes_b64 = Web3McpServer::encode_tx_bytes(&tx_data).expect("encode bytes");
         assert!(!tx_bytes_b64.is_empty());
     }
+
+    #[test]
+    fn sponsored_tx_data_and_shape() {
+        use move_core_types::identifier::Identifier;
+        use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+        use sui_types::transaction::TransactionDataAPI;
+
+        let sender = SuiAddress::from_str(
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+        )
+        .expect("valid sender");
+        let sponsor = SuiAddress::from_str(
+            "0x6666666666666666666666666666666666666666666666666666666666666666",
+        )
+        .expect("valid sponsor");
+        let gas_coin_id = ObjectID::from_hex_literal(
+            "0x3333333333333333333333333333333333333333333333333333333333333333",
+        )
+        .expect("valid gas id");
+        let gas_ref = (
+            gas_coin_id,
+            SequenceNumber::from(1),
+            ObjectDigest::new([1u8; 32]),
+        );
+
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder.programmable_move_call(
+            ObjectID::from_hex_literal("0x2").expect("valid package"),
+            Identifier::new("tx_context").expect("valid module"),
+            Identifier::new("epoch").expect("valid function"),
+            vec![],
+            vec![],
+        );
+        let kind = TransactionKind::programmable(builder.finish());
+
+        let shape = Web3McpServer::sui_sponsor_tx_shape(&kind).expect("shape");
+        assert_eq!(shape.move_targets.len(), 1);
+        assert!(!shape.uses_gas_coin);
+        assert!(shape.unsupported.is_empty());
+        let policy = crate::utils::sui_sponsor::Policy {
+            account: Some("sponsor".to_string()),
+            max_gas_budget: 50_000_000,
+            sender_budget_mist: 1_000_000_000,
+            window_ms: 86_400_000,
+            allowed_move_targets: vec!["0x2::tx_context::*".to_string()],
+        };
+        assert!(policy.violations(&shape, 1_000_000, 0).is_empty());
+
+        let tx_data = Web3McpServer::sui_sponsored_tx_data(
+            kind,
+            sender,
+            sponsor,
+            vec![gas_ref],
+            1_000_000,
+            1_000,
+        );
+        assert_eq!(tx_data.sender(), sender);
+        assert_eq!(tx_data.gas_owner(), sponsor);
+        assert_eq!(tx_data.gas_budget(), 1_000_000);
+
+        // Paying SUI out of the gas coin spends the sponsor's money.
+        let mut builder = ProgrammableTransactionBuilder::new();
+        builder
+            .pay_sui(vec![sender], vec![1])
+            .expect("pay_sui from gas");
+        let kind = TransactionKind::programmable(builder.finish());
+        let shape = Web3McpServer::sui_sponsor_tx_shape(&kind).expect("shape");
+        assert!(shape.uses_gas_coin);
+        assert!(!policy.violations(&shape, 1_000_000, 0).is_empty());
+    }
 }
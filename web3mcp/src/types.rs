@@ -21,18 +21,35 @@ pub struct TransactionResponseOptionsRequest {
     pub show_raw_effects: Option<bool>,
 }
 
+/// Arguments of Sui read tools that take nothing but the network.
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct SuiNetworkRequest {
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetBalanceRequest {
     #[schemars(description = "The Sui address to query (hex format starting with 0x)")]
     pub address: String,
     #[schemars(description = "Optional coin type (defaults to 0x2::sui::SUI)")]
     pub coin_type: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetAllBalancesRequest {
     #[schemars(description = "The Sui address to query (hex format starting with 0x)")]
     pub address: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 // ---- EVM / Base (experimental multi-chain) ----
@@ -117,7 +134,7 @@ pub struct EvmEventTopic0Request {
 pub struct EvmListPendingConfirmationsRequest {
     #[schemars(description = "Optional EVM chain id filter")]
     pub chain_id: Option<u64>,
-    #[schemars(description = "Optional status filter (pending|consumed|sent|failed)")]
+    #[schemars(description = "Optional status filter (pending|signed|sent|confirmed|failed|expired)")]
     pub status: Option<String>,
     #[schemars(description = "Include tx_summary in each item (default true)")]
     pub include_tx_summary: Option<bool>,
@@ -348,12 +365,102 @@ pub struct EvmBuildTransferNativeRequest {
 
 // ---- Solana Keystore ----
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SolanaGetWalletAddressRequest {
+    #[schemars(
+        description = "Optional network (mainnet|devnet|testnet); picks the keystore account selected for it"
+    )]
+    pub network: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SolanaKeypairInfoRequest {
     #[schemars(
         description = "Optional keypair path (defaults to SOLANA_KEYPAIR_PATH or ~/.config/solana/id.json)"
     )]
     pub keypair_path: Option<String>,
+    #[schemars(
+        description = "Optional encrypted keystore account name (see solana_keystore_list); wins over keypair_path"
+    )]
+    pub account: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SolanaKeystoreImportRequest {
+    #[schemars(description = "Account name (letters, digits, '-', '_', '.')")]
+    pub account: String,
+    #[schemars(
+        description = "Plaintext Solana JSON keypair file to import (e.g. ~/.config/solana/id.json)"
+    )]
+    pub keypair_path: Option<String>,
+    #[schemars(
+        description = "Secret key instead of keypair_path: base58 (wallet export) or a JSON byte array"
+    )]
+    pub secret_key: Option<String>,
+    #[schemars(description = "Encryption password (defaults to WEB3MCP_SIGNER_KEYSTORE_PASSWORD)")]
+    pub password: Option<String>,
+    #[schemars(description = "Replace an existing account with the same name (default false)")]
+    pub overwrite: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SolanaKeystoreExportPublicRequest {
+    #[schemars(description = "Account name")]
+    pub account: String,
+    #[schemars(
+        description = "Optional password; when set the key is decrypted and checked against the stored pubkey"
+    )]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SolanaKeystoreSelectRequest {
+    #[schemars(description = "Account name to sign with")]
+    pub account: String,
+    #[schemars(description = "Network (mainnet|devnet|testnet, default mainnet)")]
+    pub network: Option<String>,
+}
+
+// ---- Mnemonic (HD accounts) ----
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MnemonicImportRequest {
+    #[schemars(description = "BIP-39 mnemonic (English, 12-24 words); stored encrypted only")]
+    pub mnemonic: String,
+    #[schemars(description = "Name to store it under (default: default)")]
+    pub name: Option<String>,
+    #[schemars(description = "Encryption password (defaults to WEB3MCP_SIGNER_KEYSTORE_PASSWORD)")]
+    pub password: Option<String>,
+    #[schemars(description = "Replace a stored mnemonic with the same name (default false)")]
+    pub overwrite: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MnemonicDeriveRequest {
+    #[schemars(description = "Chain: sui | solana | evm")]
+    pub chain: String,
+    #[schemars(description = "Stored mnemonic name (default: default)")]
+    pub name: Option<String>,
+    #[schemars(description = "First account index on the standard path (default 0)")]
+    pub start_index: Option<u32>,
+    #[schemars(description = "Number of accounts (default 5, max 100)")]
+    pub count: Option<u32>,
+    #[schemars(description = "Derive one explicit path instead of an index range")]
+    pub derivation_path: Option<String>,
+    #[schemars(description = "Password (defaults to WEB3MCP_SIGNER_KEYSTORE_PASSWORD)")]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MnemonicSelectRequest {
+    #[schemars(description = "Chain: sui | solana | evm")]
+    pub chain: String,
+    #[schemars(description = "Stored mnemonic name (default: default)")]
+    pub name: Option<String>,
+    #[schemars(description = "Account index on the standard path (default 0)")]
+    pub index: Option<u32>,
+    #[schemars(description = "Explicit derivation path instead of index")]
+    pub derivation_path: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -756,6 +863,10 @@ pub struct Sui7kBuildSwapTxRequest {
 
     #[schemars(description = "Timeout ms (default 15000)")]
     pub timeout_ms: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -803,6 +914,10 @@ pub struct Sui7kSwapExactInRequest {
 
     #[schemars(description = "Optional keystore path for signing")]
     pub keystore_path: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2009,6 +2124,124 @@ pub struct PromptExplainEvmConceptRequest {
     pub concept: String,
 }
 
+#[derive(Debug, Clone, Copy, Default, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PromptSolanaNetwork {
+    #[default]
+    Mainnet,
+    Devnet,
+    Testnet,
+}
+
+impl PromptSolanaNetwork {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mainnet => "mainnet",
+            Self::Devnet => "devnet",
+            Self::Testnet => "testnet",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PromptChain {
+    Solana,
+    Sui,
+    Evm,
+}
+
+impl PromptChain {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Solana => "solana",
+            Self::Sui => "sui",
+            Self::Evm => "evm",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PromptPortfolioFocus {
+    #[default]
+    Overview,
+    Risk,
+    Cleanup,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct PromptSolanaPortfolioReviewRequest {
+    #[schemars(description = "Wallet address (base58)")]
+    pub owner: String,
+    #[schemars(description = "Network: mainnet|devnet|testnet (default mainnet)")]
+    pub network: Option<PromptSolanaNetwork>,
+    #[schemars(
+        description = "Focus: overview (holdings) | risk (concentration, delegations, unknown mints) | cleanup (empty / dust token accounts). Default overview."
+    )]
+    pub focus: Option<PromptPortfolioFocus>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct PromptSolanaTxPostmortemRequest {
+    #[schemars(description = "Transaction signature (base58)")]
+    pub signature: String,
+    #[schemars(description = "Network: mainnet|devnet|testnet (default mainnet)")]
+    pub network: Option<PromptSolanaNetwork>,
+    #[schemars(
+        description = "What the transaction was supposed to do (e.g. 'swap 1 SOL to USDC'), to compare against what happened"
+    )]
+    pub expected_outcome: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct PromptSuiMoveExploreRequest {
+    #[schemars(description = "Package object ID")]
+    pub package: String,
+    #[schemars(description = "Move module name (optional; default: list the package's modules)")]
+    pub module: Option<String>,
+    #[schemars(description = "Move function name (optional; default: list entry functions)")]
+    pub function: Option<String>,
+    #[schemars(description = "Sender address, to suggest owned objects as inputs (optional)")]
+    pub sender: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct PromptW3rtSafeSwapRequest {
+    #[schemars(description = "Chain: solana|sui|evm")]
+    pub chain: PromptChain,
+    #[schemars(description = "Input token (symbol, mint, coin type or 0x address)")]
+    pub input_token: String,
+    #[schemars(description = "Output token (symbol, mint, coin type or 0x address)")]
+    pub output_token: String,
+    #[schemars(description = "Amount of input token (UI units, e.g. 0.5)")]
+    pub amount_in: String,
+    #[schemars(description = "Sender wallet address")]
+    pub sender: String,
+    #[schemars(
+        description = "Network (solana: mainnet|devnet|testnet; evm: registry name such as base; sui: server network). Optional."
+    )]
+    pub network: Option<String>,
+    #[schemars(description = "Max slippage in basis points (optional; default: adapter default)")]
+    pub slippage_bps: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct PromptPendingTriageRequest {
+    #[schemars(description = "Chain filter: solana|sui|evm (optional; default all)")]
+    pub chain: Option<PromptChain>,
+    #[schemars(
+        description = "Network filter (solana: mainnet|testnet|devnet; sui: rpc network; evm: chain id). Optional."
+    )]
+    pub network: Option<String>,
+    #[schemars(description = "Triage only this confirmation id (optional)")]
+    pub id: Option<String>,
+}
+
 // ---------- EVM decode / simulation helpers ----------
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2340,6 +2573,10 @@ pub struct GetObjectRequest {
     pub object_id: String,
     #[schemars(description = "Include content in response (default: true)")]
     pub show_content: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2348,18 +2585,30 @@ pub struct GetOwnedObjectsRequest {
     pub address: String,
     #[schemars(description = "Optional limit on number of results (max 50)")]
     pub limit: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetTransactionRequest {
     #[schemars(description = "The transaction digest to query (base58 encoded)")]
     pub digest: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct QueryEventsRequest {
     #[schemars(description = "The transaction digest to query events for")]
     pub digest: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2370,6 +2619,10 @@ pub struct GetCoinsRequest {
     pub coin_type: Option<String>,
     #[schemars(description = "Optional limit on number of results")]
     pub limit: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2390,6 +2643,10 @@ pub struct WalletOverviewRequest {
     pub include_coins: Option<bool>,
     #[schemars(description = "Optional limit for coin objects (default: 20, max: 50)")]
     pub coins_limit: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2430,6 +2687,10 @@ pub struct ZkLoginExecuteTransactionRequest {
     pub max_epoch: u64,
     #[schemars(description = "Ephemeral user signature over tx bytes (base64 flag||sig||pubkey)")]
     pub user_signature: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2444,6 +2705,10 @@ pub struct VerifyZkLoginSignatureRequest {
         description = "Intent scope: transaction or personal_message (default: transaction)"
     )]
     pub intent_scope: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2488,6 +2753,10 @@ pub struct KeystoreExecuteTransactionRequest {
     pub preflight: Option<bool>,
     #[schemars(description = "Allow execution even if dry-run fails (default: false)")]
     pub allow_preflight_failure: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2502,6 +2771,10 @@ pub struct BuildTransferObjectRequest {
     pub gas_budget: Option<u64>,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2526,6 +2799,10 @@ pub struct BuildTransferSuiRequest {
         description = "Large transfer threshold in raw SUI (default: 1_000_000_000 = 1 SUI)"
     )]
     pub large_transfer_threshold: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2568,6 +2845,10 @@ pub struct ExecuteTransferSuiRequest {
     pub merge_threshold: Option<usize>,
     #[schemars(description = "Maximum number of coins to merge (default: 10)")]
     pub merge_max_inputs: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2596,6 +2877,10 @@ pub struct ExecuteTransferObjectRequest {
     pub allow_preflight_failure: Option<bool>,
     #[schemars(description = "Confirm sensitive action (required)")]
     pub confirm: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2626,6 +2911,10 @@ pub struct ExecutePaySuiRequest {
         description = "Confirm sensitive action (required). If false, a confirmation_id is returned."
     )]
     pub confirm: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2648,6 +2937,10 @@ pub struct SuiConfirmExecutionRequest {
     pub preflight: Option<bool>,
     #[schemars(description = "Allow execution even if dry-run fails (default: false)")]
     pub allow_preflight_failure: Option<bool>,
+    #[schemars(
+        description = "Combined multisig signature from sui_multisig_combine; executes with it instead of the configured signer"
+    )]
+    pub multisig_signature: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2662,13 +2955,17 @@ pub struct SuiCreatePendingConfirmationRequest {
     pub summary: Option<Value>,
     #[schemars(description = "Optional TTL in ms (defaults to 10 minutes)")]
     pub ttl_ms: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SuiListPendingConfirmationsRequest {
     #[schemars(description = "Max items (default 20, max 200)")]
     pub limit: Option<u64>,
-    #[schemars(description = "Filter by status: pending|consumed|sent|failed")]
+    #[schemars(description = "Filter by status: pending|signed|sent|confirmed|failed|expired")]
     pub status: Option<String>,
     #[schemars(description = "Include tx_bytes_b64 in response (default false)")]
     pub include_tx_bytes: Option<bool>,
@@ -2732,6 +3029,10 @@ pub struct ExecuteAddStakeRequest {
     pub allow_preflight_failure: Option<bool>,
     #[schemars(description = "Confirm sensitive action (required)")]
     pub confirm: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2758,6 +3059,10 @@ pub struct ExecuteWithdrawStakeRequest {
     pub allow_preflight_failure: Option<bool>,
     #[schemars(description = "Confirm sensitive action (required)")]
     pub confirm: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2772,6 +3077,10 @@ pub struct BuildPaySuiRequest {
     pub input_coins: Vec<String>,
     #[schemars(description = "Gas budget for the transaction (optional)")]
     pub gas_budget: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2784,6 +3093,10 @@ pub struct BuildPayAllSuiRequest {
     pub input_coins: Vec<String>,
     #[schemars(description = "Gas budget for the transaction")]
     pub gas_budget: u64,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2806,6 +3119,10 @@ pub struct BuildMoveCallRequest {
     pub gas_object_id: Option<String>,
     #[schemars(description = "Optional gas price override")]
     pub gas_price: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2820,6 +3137,10 @@ pub struct BuildPublishRequest {
     pub gas_budget: u64,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2834,6 +3155,10 @@ pub struct BuildSplitCoinRequest {
     pub gas_budget: u64,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2848,6 +3173,10 @@ pub struct BuildMergeCoinsRequest {
     pub gas_budget: u64,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2860,6 +3189,10 @@ pub struct BuildBatchTransactionRequest {
     pub gas_budget: Option<u64>,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2886,6 +3219,10 @@ pub struct ExecuteBatchTransactionRequest {
     pub allow_preflight_failure: Option<bool>,
     #[schemars(description = "Confirm sensitive action (required)")]
     pub confirm: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2902,6 +3239,10 @@ pub struct BuildAddStakeRequest {
     pub gas_budget: Option<u64>,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2914,6 +3255,10 @@ pub struct BuildWithdrawStakeRequest {
     pub gas_budget: Option<u64>,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2936,12 +3281,20 @@ pub struct BuildUpgradeRequest {
     pub gas_budget: u64,
     #[schemars(description = "Optional gas object ID")]
     pub gas_object_id: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct DryRunTransactionRequest {
     #[schemars(description = "Base64-encoded transaction bytes (BCS TransactionData)")]
     pub tx_bytes: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2954,18 +3307,30 @@ pub struct DevInspectTransactionRequest {
     pub gas_price: Option<u64>,
     #[schemars(description = "Optional epoch override")]
     pub epoch: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetStakesRequest {
     #[schemars(description = "Owner address to query stakes for")]
     pub owner: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetCommitteeInfoRequest {
     #[schemars(description = "Optional epoch to query")]
     pub epoch: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2974,6 +3339,10 @@ pub struct GetCheckpointRequest {
     pub sequence_number: Option<u64>,
     #[schemars(description = "Checkpoint digest")]
     pub digest: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2984,6 +3353,10 @@ pub struct GetCheckpointsRequest {
     pub limit: Option<usize>,
     #[schemars(description = "Return in descending order")]
     pub descending_order: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -2998,6 +3371,10 @@ pub struct QueryTransactionBlocksRequest {
     pub descending_order: Option<bool>,
     #[schemars(description = "Optional response options")]
     pub options: Option<TransactionResponseOptionsRequest>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3006,6 +3383,10 @@ pub struct MultiGetTransactionsRequest {
     pub digests: Vec<String>,
     #[schemars(description = "Optional response options")]
     pub options: Option<TransactionResponseOptionsRequest>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3018,24 +3399,40 @@ pub struct SelectCoinsRequest {
     pub amount: u128,
     #[schemars(description = "Object IDs to exclude")]
     pub exclude: Vec<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetCoinMetadataRequest {
     #[schemars(description = "Coin type to query")]
     pub coin_type: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetTotalSupplyRequest {
     #[schemars(description = "Coin type to query")]
     pub coin_type: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetNormalizedMoveModulesRequest {
     #[schemars(description = "Package object ID")]
     pub package: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3046,6 +3443,10 @@ pub struct GetDynamicFieldsRequest {
     pub cursor: Option<String>,
     #[schemars(description = "Optional limit on results (max 50)")]
     pub limit: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3056,12 +3457,20 @@ pub struct GetDynamicFieldObjectRequest {
     pub name_type: String,
     #[schemars(description = "Dynamic field name value (JSON)")]
     pub name_value: Value,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GetMoveObjectBcsRequest {
     #[schemars(description = "Object ID")]
     pub object_id: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3090,6 +3499,10 @@ pub struct GetPastObjectRequest {
     pub version: u64,
     #[schemars(description = "Optional object response options")]
     pub options: Option<ObjectOptionsRequest>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3106,6 +3519,10 @@ pub struct MultiGetPastObjectsRequest {
     pub objects: Vec<PastObjectRequestItem>,
     #[schemars(description = "Optional object response options")]
     pub options: Option<ObjectOptionsRequest>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3116,6 +3533,10 @@ pub struct GetAllCoinsRequest {
     pub cursor: Option<String>,
     #[schemars(description = "Optional limit on results")]
     pub limit: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3126,6 +3547,10 @@ pub struct DescribeMoveFunctionRequest {
     pub module: String,
     #[schemars(description = "Move function name")]
     pub function: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3136,12 +3561,20 @@ pub struct GenerateModuleTemplatesRequest {
     pub module: Option<String>,
     #[schemars(description = "Only include entry functions (default true)")]
     pub entry_only: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SuggestObjectMethodsRequest {
     #[schemars(description = "Object ID to inspect")]
     pub object_id: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3152,6 +3585,10 @@ pub struct GetDynamicFieldTreeRequest {
     pub max_depth: Option<usize>,
     #[schemars(description = "Limit per level (default 50)")]
     pub limit: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3164,6 +3601,10 @@ pub struct GenerateMoveCallFormSchemaRequest {
     pub function: String,
     #[schemars(description = "Maximum struct expansion depth (default 2)")]
     pub max_struct_depth: Option<usize>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3180,6 +3621,10 @@ pub struct SuggestMoveCallInputsRequest {
     pub limit: Option<usize>,
     #[schemars(description = "Optional gas budget for auto gas selection")]
     pub gas_budget: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3194,6 +3639,10 @@ pub struct ResolveMoveCallArgsRequest {
     pub type_args: Vec<String>,
     #[schemars(description = "Arguments as JSON values")]
     pub arguments: Vec<Value>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3224,6 +3673,10 @@ pub struct AutoExecuteMoveCallRequest {
     pub max_epoch: u64,
     #[schemars(description = "Ephemeral user signature over tx bytes (base64 flag||sig||pubkey)")]
     pub user_signature: String,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3246,6 +3699,10 @@ pub struct PrepareMoveCallRequest {
     pub gas_object_id: Option<String>,
     #[schemars(description = "Optional gas price override")]
     pub gas_price: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3268,6 +3725,10 @@ pub struct AutoFillMoveCallRequest {
     pub gas_object_id: Option<String>,
     #[schemars(description = "Optional gas price override")]
     pub gas_price: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3302,6 +3763,10 @@ pub struct DappMoveCallRequest {
         description = "Optional manifest file path (defaults to SUI_DAPP_MANIFEST or ./dapps.json)"
     )]
     pub manifest_path: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -3343,6 +3808,10 @@ pub struct AutoPrepareMoveCallRequest {
     pub gas_object_id: Option<String>,
     #[schemars(description = "Optional gas price override")]
     pub gas_price: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3359,6 +3828,10 @@ pub struct GenerateMoveCallPayloadRequest {
     pub gas_budget: Option<u64>,
     #[schemars(description = "Optional gas price")]
     pub gas_price: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -3385,6 +3858,130 @@ pub struct VerifySimpleSignatureRequest {
     pub signature_base64: String,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiMultisigMember {
+    #[schemars(description = "Public key (base64 flag||pk, as printed by `sui keytool list`)")]
+    pub public_key: String,
+    #[schemars(description = "Member weight (1-255)")]
+    pub weight: u8,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiMultisigAddressRequest {
+    #[schemars(description = "Members in order (the order changes the address)")]
+    pub members: Vec<SuiMultisigMember>,
+    #[schemars(description = "Signed weight required to authorize a transaction")]
+    pub threshold: u16,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiMultisigSignRequest {
+    #[schemars(
+        description = "Transaction bytes (BCS TransactionData, base64) from a build_* tool"
+    )]
+    pub tx_bytes: String,
+    #[schemars(description = "Multisig members in order")]
+    pub members: Vec<SuiMultisigMember>,
+    #[schemars(description = "Multisig threshold")]
+    pub threshold: u16,
+    #[schemars(
+        description = "Optional keystore path (defaults to SUI_KEYSTORE_PATH or ~/.sui/sui_config/sui.keystore)"
+    )]
+    pub keystore_path: Option<String>,
+    #[schemars(description = "Signer address or alias (must be a member)")]
+    pub signer: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiMultisigCombineRequest {
+    #[schemars(description = "Transaction bytes (BCS TransactionData, base64) that was signed")]
+    pub tx_bytes: String,
+    #[schemars(description = "Multisig members in order")]
+    pub members: Vec<SuiMultisigMember>,
+    #[schemars(description = "Multisig threshold")]
+    pub threshold: u16,
+    #[schemars(description = "Partial signatures (base64 flag||sig||pk), any order")]
+    pub signatures: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiMultisigVerifyRequest {
+    #[schemars(description = "Transaction bytes (BCS TransactionData, base64)")]
+    pub tx_bytes: String,
+    #[schemars(description = "Combined multisig signature (base64)")]
+    pub multisig_signature: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiSponsorStatusRequest {
+    #[schemars(description = "Sender address to report sponsored gas for (optional)")]
+    pub sender: Option<String>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiSponsorBuildRequest {
+    #[schemars(description = "Sender address (signs the transaction, pays no gas)")]
+    pub sender: String,
+    #[schemars(
+        description = "Transaction bytes (BCS TransactionData, base64); only its commands are kept"
+    )]
+    pub tx_bytes: Option<String>,
+    #[schemars(description = "Transaction kind bytes (BCS TransactionKind, base64)")]
+    pub tx_kind_bytes: Option<String>,
+    #[schemars(
+        description = "Gas budget in MIST (optional; default: dry-run estimate, capped by sui.sponsor.max_gas_budget)"
+    )]
+    pub gas_budget: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct BuildSponsoredMoveCallRequest {
+    #[schemars(description = "Sender address (signs the transaction, pays no gas)")]
+    pub sender: String,
+    #[schemars(description = "Package object ID")]
+    pub package: String,
+    #[schemars(description = "Move module name")]
+    pub module: String,
+    #[schemars(description = "Move function name")]
+    pub function: String,
+    #[schemars(description = "Type arguments, e.g. 0x2::sui::SUI")]
+    pub type_args: Vec<String>,
+    #[schemars(description = "Move call arguments as JSON values")]
+    pub arguments: Vec<Value>,
+    #[schemars(
+        description = "Gas budget in MIST (optional; default: dry-run estimate, capped by sui.sponsor.max_gas_budget)"
+    )]
+    pub gas_budget: Option<u64>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SuiExecuteSponsoredRequest {
+    #[schemars(description = "Sponsored transaction bytes from sui_sponsor_build (base64)")]
+    pub tx_bytes: String,
+    #[schemars(
+        description = "Sender signature over tx_bytes (base64 serialized Sui signature or multisig)"
+    )]
+    pub sender_signature: String,
+    #[schemars(description = "Run dry-run before execution (default: true)")]
+    pub preflight: Option<bool>,
+    #[schemars(
+        description = "Sui network: mainnet|testnet|devnet|localnet or an RPC URL (optional; default: server network)"
+    )]
+    pub network: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct GraphqlHelperRequest {
     #[schemars(description = "GraphQL endpoint (defaults to SUI_GRAPHQL_URL)")]
@@ -3435,6 +4032,24 @@ pub struct SystemRunWorkflowV0Request {
     pub override_token: Option<String>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SystemRunWorkflowV1Request {
+    #[schemars(
+        description = "Plan steps: ordered list of intents, or {id, intent, depends_on} objects forming a DAG. Bind earlier outputs with \"{{steps.<id>.outputs.<field>}}\" (e.g. amount_out, min_amount_out)"
+    )]
+    pub steps: Vec<Value>,
+    #[schemars(description = "Optional sender/address applied to every step")]
+    pub sender: Option<String>,
+    #[schemars(description = "Optional network override applied to every step")]
+    pub network: Option<String>,
+    #[schemars(description = "Optional label for UX/debugging")]
+    pub label: Option<String>,
+    #[schemars(
+        description = "Optional override token to bypass approval_required guard (short-lived; issued by w3rt_request_override)"
+    )]
+    pub override_token: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct W3rtGetRunRequest {
     #[schemars(description = "Run id returned by w3rt_run_workflow_v0")]
@@ -3443,6 +4058,54 @@ pub struct W3rtGetRunRequest {
     pub include_artifacts: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct W3rtResumeRunRequest {
+    #[schemars(description = "Run id returned by w3rt_run_workflow_v0")]
+    pub run_id: String,
+    #[schemars(description = "Optional override token (from w3rt_request_override) to pass the approval_required guard")]
+    pub override_token: Option<String>,
+    #[schemars(
+        description = "Optional stage to recompute from: simulate (re-quote) | approval | execute. Default: continue after the last completed stage"
+    )]
+    pub from_stage: Option<String>,
+    #[schemars(description = "Allow from_stage on a completed run (creates a new pending confirmation)")]
+    pub force: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct W3rtListRunsRequest {
+    #[schemars(description = "Filter by chain (solana|evm|sui)")]
+    pub chain: Option<String>,
+    #[schemars(description = "Filter by intent action (e.g. swap_exact_in, transfer_native)")]
+    pub action: Option<String>,
+    #[schemars(description = "Filter by run status (running|completed|blocked|failed)")]
+    pub status: Option<String>,
+    #[schemars(description = "Filter by sender address/pubkey")]
+    pub sender: Option<String>,
+    #[schemars(description = "Only runs created at or after this unix ms")]
+    pub since_ms: Option<u64>,
+    #[schemars(description = "Only runs created before this unix ms")]
+    pub until_ms: Option<u64>,
+    #[schemars(description = "Include archived runs (default false)")]
+    pub include_archived: Option<bool>,
+    #[schemars(description = "Page size (default 20, max 200)")]
+    pub limit: Option<u32>,
+    #[schemars(description = "Page offset (use next_offset from the previous page)")]
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct W3rtPruneRunsRequest {
+    #[schemars(description = "Prune runs older than this many days (default: WEB3MCP_RUNS_MAX_AGE_DAYS)")]
+    pub max_age_days: Option<u64>,
+    #[schemars(description = "Keep only the newest N runs (default: WEB3MCP_RUNS_MAX_COUNT)")]
+    pub max_runs: Option<u64>,
+    #[schemars(description = "archive (move to <runs_dir>/archive) or delete (default: WEB3MCP_RUNS_RETENTION_MODE, else archive)")]
+    pub mode: Option<String>,
+    #[schemars(description = "Only report which runs would be pruned")]
+    pub dry_run: Option<bool>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct W3rtRequestOverrideRequest {
     #[schemars(description = "Run id that is currently blocked by approval_required")]
@@ -3511,6 +4174,90 @@ pub struct SystemDebugBundleRequest {
     pub include_pending_samples: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SystemListPendingConfirmationsRequest {
+    #[schemars(description = "Optional chain filter: solana|sui|evm")]
+    pub chain: Option<String>,
+    #[schemars(
+        description = "Optional network filter (solana: mainnet|testnet|devnet; sui: rpc network; evm: chain id)"
+    )]
+    pub network: Option<String>,
+    #[schemars(description = "Optional status filter (pending|signed|sent|confirmed|failed|expired)")]
+    pub status: Option<String>,
+    #[schemars(description = "Include the chain-specific payload (tx bytes etc). Default false.")]
+    pub include_payload: Option<bool>,
+    #[schemars(description = "Max items (default 20, max 200)")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SystemGetPendingConfirmationRequest {
+    #[schemars(description = "Confirmation id (any chain)")]
+    pub id: String,
+    #[schemars(description = "Include the chain-specific payload (tx bytes etc). Default true.")]
+    pub include_payload: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SystemCleanupPendingConfirmationsRequest {
+    #[schemars(description = "Optional chain filter: solana|sui|evm")]
+    pub chain: Option<String>,
+    #[schemars(description = "Optional network filter (same format as the list tool)")]
+    pub network: Option<String>,
+    #[schemars(description = "Optional status filter (pending|signed|sent|confirmed|failed|expired)")]
+    pub status: Option<String>,
+    #[schemars(
+        description = "Also delete matching rows last updated more than this many ms ago. Rows that still count toward spend limits are kept."
+    )]
+    pub older_than_ms: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct SystemAuditVerifyRequest {
+    #[schemars(
+        description = "Head hash recorded earlier (e.g. from a previous verify). The check fails if the log's head differs, which catches a rewritten chain."
+    )]
+    pub expected_head: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct SystemAuditQueryRequest {
+    #[schemars(description = "Tool name or glob (e.g. evm_*)")]
+    pub tool: Option<String>,
+    #[schemars(description = "Chain: sui|solana|evm")]
+    pub chain: Option<String>,
+    #[schemars(description = "Address appearing in the record (sender, recipient, signer, spender, ...)")]
+    pub address: Option<String>,
+    #[schemars(description = "Transaction hash / digest / signature appearing in the record")]
+    pub tx_hash: Option<String>,
+    #[schemars(description = "Only records at or after this time (unix ms)")]
+    pub since_ms: Option<u64>,
+    #[schemars(description = "Only records at or before this time (unix ms)")]
+    pub until_ms: Option<u64>,
+    #[schemars(description = "Max records, newest first (default 50, max 1000)")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct SystemCacheClearRequest {
+    #[schemars(
+        description = "Category to clear: finalized_tx|move_modules|token_metadata|token_list|balance (default: all)"
+    )]
+    pub category: Option<String>,
+    #[schemars(
+        description = "Only keys starting with this prefix (e.g. 'evm:8453:', 'sui:', 'solana:')"
+    )]
+    pub key_prefix: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct SystemMetricsRequest {
+    #[schemars(description = "Only this tool (exact name; json format only)")]
+    pub tool: Option<String>,
+    #[schemars(description = "Output format: json (default, per-tool summary) | prometheus")]
+    pub format: Option<String>,
+}
+
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct SystemHealthcheckRequest {
     #[schemars(description = "Optional EVM chain id to probe (defaults to EVM_DEFAULT_CHAIN_ID)")]
@@ -3519,6 +4266,26 @@ pub struct SystemHealthcheckRequest {
         description = "Optional Solana network to probe: mainnet|testnet|devnet (default mainnet)"
     )]
     pub solana_network: Option<String>,
+    #[schemars(
+        description = "Optional Sui network to probe: mainnet|testnet|devnet|localnet or an RPC URL (default: server network)"
+    )]
+    pub sui_network: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct SystemRpcHealthRequest {
+    #[schemars(description = "EVM chain ids to check (default: EVM_DEFAULT_CHAIN_ID)")]
+    pub evm_chain_ids: Option<Vec<u64>>,
+    #[schemars(description = "Solana networks to check: mainnet|testnet|devnet (default mainnet)")]
+    pub solana_networks: Option<Vec<String>>,
+    #[schemars(
+        description = "Sui networks to check: mainnet|testnet|devnet|localnet or an RPC URL (default: server network)"
+    )]
+    pub sui_networks: Option<Vec<String>>,
+    #[schemars(
+        description = "Probe every endpoint now (default true); false only reports health recorded by earlier calls"
+    )]
+    pub probe: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
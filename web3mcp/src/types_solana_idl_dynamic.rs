@@ -21,7 +21,7 @@ pub struct SolanaIdlLoadRequest {
     pub idl_path: Option<String>,
 
     #[schemars(
-        description = "If true, also write into abi_registry/solana/<program_id>/<name>.json (requires program_id)"
+        description = "If true, also write into the Solana IDL registry (<data_dir>/abi_registry/solana/<program_id>/<name>.json) (requires program_id)"
     )]
     pub persist: Option<bool>,
 
@@ -0,0 +1,832 @@
+//! Declarative approval policy for the W3RT approval stage.
+//!
+//! Rules match on chain / action / token / swap mode and fire on conditions over the simulated
+//! intent (USD amount, UI amount, slippage, price impact, route size, recipients). Each rule
+//! evaluates to `ok`, `needs_review` or `block`; the strictest fired rule wins and the matched
+//! rule ids are recorded in `stage_approval.json` under `policy`.
+//!
+//! Source: `W3RT_APPROVAL_POLICY` (path), else `approval_policy.toml` or
+//! `approval_policy.json` in `<data_dir>/policies`, else the built-in rules.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::utils::solana_workflow::format_base_units_ui;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    Ok,
+    NeedsReview,
+    Block,
+}
+
+impl Decision {
+    /// Approval-stage status for this decision.
+    pub fn status(self) -> &'static str {
+        match self {
+            Decision::Ok => "ok",
+            Decision::NeedsReview => "needs_review",
+            Decision::Block => "blocked",
+        }
+    }
+
+    fn from_status(status: &str) -> Option<Self> {
+        match status {
+            "ok" => Some(Decision::Ok),
+            "needs_review" => Some(Decision::NeedsReview),
+            "blocked" => Some(Decision::Block),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyRule {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub decision: Decision,
+    /// Warning kind emitted when the rule fires (default `policy_rule`).
+    #[serde(default)]
+    pub kind: Option<String>,
+
+    // Scope (empty = any).
+    #[serde(default)]
+    pub chain: Vec<String>,
+    #[serde(default)]
+    pub action: Vec<String>,
+    /// Symbols, mints, token addresses or coin types; matches input/output/transferred tokens.
+    #[serde(default)]
+    pub token: Vec<String>,
+    /// ExactIn | ExactOut (swaps).
+    #[serde(default)]
+    pub swap_mode: Option<String>,
+
+    // Conditions (all set conditions must hold).
+    #[serde(default)]
+    pub amount_usd_gte: Option<f64>,
+    /// Fires when the USD value is (true) or is not (false) known.
+    #[serde(default)]
+    pub amount_usd_unknown: Option<bool>,
+    #[serde(default)]
+    pub amount_ui_gte: Option<f64>,
+    #[serde(default)]
+    pub slippage_bps_gte: Option<u64>,
+    /// Percent (1.0 = 1%).
+    #[serde(default)]
+    pub price_impact_pct_gte: Option<f64>,
+    #[serde(default)]
+    pub route_steps_gt: Option<u64>,
+    #[serde(default)]
+    pub min_out_ui_lt: Option<f64>,
+    #[serde(default)]
+    pub max_in_ui_gte: Option<f64>,
+    /// Deny list: fires if any recipient is listed.
+    #[serde(default)]
+    pub recipient_in: Vec<String>,
+    /// Allow list: fires if any recipient is not listed.
+    #[serde(default)]
+    pub recipient_not_in: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalPolicy {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+/// Facts about a simulated intent that rules are evaluated against.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PolicyFacts {
+    pub chain: String,
+    pub action: String,
+    pub tokens: Vec<String>,
+    pub swap_mode: Option<String>,
+    pub amount_ui: Option<f64>,
+    pub amount_usd: Option<f64>,
+    pub slippage_bps: Option<u64>,
+    pub price_impact_pct: Option<f64>,
+    pub route_steps: Option<u64>,
+    pub min_out_ui: Option<f64>,
+    pub max_in_ui: Option<f64>,
+    pub recipients: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchedRule {
+    pub id: String,
+    pub decision: Decision,
+    pub kind: String,
+    pub reason: String,
+}
+
+fn env_f64(key: &str) -> Option<f64> {
+    crate::utils::config::var(key)
+        .ok()
+        .and_then(|s| s.trim().parse::<f64>().ok())
+}
+
+fn env_u64(key: &str) -> Option<u64> {
+    crate::utils::config::var(key)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+}
+
+fn rule(id: &str, kind: &str, description: &str, decision: Decision) -> PolicyRule {
+    PolicyRule {
+        id: id.to_string(),
+        description: Some(description.to_string()),
+        decision,
+        kind: Some(kind.to_string()),
+        chain: vec![],
+        action: vec![],
+        token: vec![],
+        swap_mode: None,
+        amount_usd_gte: None,
+        amount_usd_unknown: None,
+        amount_ui_gte: None,
+        slippage_bps_gte: None,
+        price_impact_pct_gte: None,
+        route_steps_gt: None,
+        min_out_ui_lt: None,
+        max_in_ui_gte: None,
+        recipient_in: vec![],
+        recipient_not_in: vec![],
+    }
+}
+
+fn strs(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+/// Built-in rules used when no policy file exists (the former hard-coded approval checks).
+///
+/// The legacy `W3RT_SWAP_*` env vars still seed these thresholds; a policy file replaces them.
+pub fn builtin_policy() -> ApprovalPolicy {
+    let swaps = strs(&["swap_exact_in", "swap_exact_out"]);
+    let mut rules = vec![];
+
+    let mut r = rule(
+        "builtin.swap.max_slippage",
+        "high_slippage",
+        "slippage_bps >= threshold",
+        Decision::NeedsReview,
+    );
+    r.action = swaps.clone();
+    r.slippage_bps_gte = Some(env_u64("W3RT_SWAP_MAX_SLIPPAGE_BPS").unwrap_or(300));
+    rules.push(r);
+
+    let mut r = rule(
+        "builtin.swap.price_impact",
+        "high_price_impact",
+        "price impact >= 1%",
+        Decision::NeedsReview,
+    );
+    r.action = swaps.clone();
+    r.price_impact_pct_gte = Some(1.0);
+    rules.push(r);
+
+    let mut r = rule(
+        "builtin.swap.route_steps",
+        "route_too_complex",
+        "routePlan has too many steps",
+        Decision::NeedsReview,
+    );
+    r.action = swaps.clone();
+    r.route_steps_gt = Some(env_u64("W3RT_SWAP_MAX_ROUTE_STEPS").unwrap_or(4));
+    rules.push(r);
+
+    let mut r = rule(
+        "builtin.swap.exact_out_slippage",
+        "exact_out_high_slippage",
+        "ExactOut with high slippage can be risky (max-in budget)",
+        Decision::NeedsReview,
+    );
+    r.action = swaps.clone();
+    r.swap_mode = Some("ExactOut".to_string());
+    r.slippage_bps_gte = Some(env_u64("W3RT_SWAP_EXACT_OUT_MAX_SLIPPAGE_BPS").unwrap_or(200));
+    rules.push(r);
+
+    let mut r = rule(
+        "builtin.swap.exact_out_max_in",
+        "exact_out_high_max_in",
+        "ExactOut max-in budget exceeds threshold",
+        Decision::NeedsReview,
+    );
+    r.action = swaps.clone();
+    r.swap_mode = Some("ExactOut".to_string());
+    r.max_in_ui_gte = Some(env_f64("W3RT_SWAP_EXACT_OUT_MAX_IN_UI").unwrap_or(100.0));
+    rules.push(r);
+
+    // Disabled by default: pairs have wildly different scales.
+    if let Some(min_out) = env_f64("W3RT_SWAP_EXACT_IN_MIN_OUT_UI").filter(|v| *v > 0.0) {
+        let mut r = rule(
+            "builtin.swap.exact_in_min_out",
+            "min_out_too_low",
+            "computed min_out is below threshold",
+            Decision::NeedsReview,
+        );
+        r.action = swaps;
+        r.swap_mode = Some("ExactIn".to_string());
+        r.min_out_ui_lt = Some(min_out);
+        rules.push(r);
+    }
+
+    let mut r = rule(
+        "builtin.transfer.large_native",
+        "large_transfer",
+        "native transfer >= 1 SOL/SUI",
+        Decision::NeedsReview,
+    );
+    r.chain = strs(&["solana", "sui"]);
+    r.action = strs(&["transfer_native", "transfer_sui", "pay", "pay_sui"]);
+    r.amount_ui_gte = Some(1.0);
+    rules.push(r);
+
+    let mut r = rule(
+        "builtin.transfer.large_spl",
+        "large_transfer",
+        "SPL transfer amount >= 1000",
+        Decision::NeedsReview,
+    );
+    r.chain = strs(&["solana"]);
+    r.action = strs(&["transfer_spl"]);
+    r.amount_ui_gte = Some(1000.0);
+    rules.push(r);
+
+    ApprovalPolicy { version: 1, rules }
+}
+
+/// Parse a policy document (`.toml` by extension, JSON otherwise) and validate rule ids.
+pub fn parse_policy(text: &str, is_toml: bool) -> Result<ApprovalPolicy, String> {
+    let policy: ApprovalPolicy = if is_toml {
+        toml::from_str(text).map_err(|e| format!("invalid TOML policy: {e}"))?
+    } else {
+        serde_json::from_str(text).map_err(|e| format!("invalid JSON policy: {e}"))?
+    };
+    if policy.version != 1 {
+        return Err(format!("unsupported policy version {}", policy.version));
+    }
+    let mut seen = std::collections::HashSet::new();
+    for r in &policy.rules {
+        if r.id.trim().is_empty() {
+            return Err("every rule needs a non-empty id".to_string());
+        }
+        if !seen.insert(r.id.as_str()) {
+            return Err(format!("duplicate rule id '{}'", r.id));
+        }
+    }
+    Ok(policy)
+}
+
+fn policy_path() -> Option<std::path::PathBuf> {
+    if let Ok(p) = crate::utils::config::var("W3RT_APPROVAL_POLICY") {
+        if !p.trim().is_empty() {
+            return Some(std::path::PathBuf::from(p.trim()));
+        }
+    }
+    let dir = crate::utils::data_dir::policies_dir();
+    ["approval_policy.toml", "approval_policy.json"]
+        .iter()
+        .map(|f| dir.join(f))
+        .find(|p| p.exists())
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadedPolicy {
+    /// File path, or `builtin`.
+    pub source: String,
+    pub policy: ApprovalPolicy,
+}
+
+#[derive(Debug, Clone)]
+struct PolicyCache {
+    path: std::path::PathBuf,
+    mtime_ms: u128,
+    policy: ApprovalPolicy,
+}
+
+static POLICY_CACHE: std::sync::OnceLock<std::sync::Mutex<Option<PolicyCache>>> =
+    std::sync::OnceLock::new();
+
+fn file_mtime_ms(path: &std::path::Path) -> Option<u128> {
+    let mt = std::fs::metadata(path).ok()?.modified().ok()?;
+    mt.duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis())
+}
+
+/// Load the active policy (cached per file mtime). A broken policy file is an error, never a
+/// silent fallback to the built-in rules.
+pub fn load_policy() -> Result<LoadedPolicy, String> {
+    let Some(path) = policy_path() else {
+        return Ok(LoadedPolicy {
+            source: "builtin".to_string(),
+            policy: builtin_policy(),
+        });
+    };
+    let source = path.display().to_string();
+    let mtime = file_mtime_ms(&path).unwrap_or(0);
+
+    let lock = POLICY_CACHE.get_or_init(|| std::sync::Mutex::new(None));
+    {
+        let guard = lock.lock().unwrap();
+        if let Some(ref cached) = *guard {
+            if cached.path == path && cached.mtime_ms == mtime {
+                return Ok(LoadedPolicy {
+                    source,
+                    policy: cached.policy.clone(),
+                });
+            }
+        }
+    }
+
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read policy {source}: {e}"))?;
+    let is_toml = path.extension().and_then(|e| e.to_str()) == Some("toml");
+    let policy = parse_policy(&text, is_toml).map_err(|e| format!("{source}: {e}"))?;
+
+    let mut guard = lock.lock().unwrap();
+    *guard = Some(PolicyCache {
+        path,
+        mtime_ms: mtime,
+        policy: policy.clone(),
+    });
+    Ok(LoadedPolicy { source, policy })
+}
+
+fn num(v: Option<&Value>) -> Option<f64> {
+    let v = v?;
+    v.as_f64()
+        .or_else(|| v.as_str().and_then(|s| s.trim().parse::<f64>().ok()))
+}
+
+fn is_stable(token: &str) -> bool {
+    let t = token.trim();
+    matches!(
+        t.to_ascii_uppercase().as_str(),
+        "USDC" | "USDT" | "DAI" | "USDC.E" | "USDBC" | "PYUSD"
+    ) || t == "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        || t == "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"
+        || t.to_ascii_lowercase().ends_with("::usdc::usdc")
+}
+
+impl PolicyFacts {
+    /// Collect facts from the normalized intent, the simulate artifact and the adapter's approval summary.
+    pub fn collect(chain: &str, intent: &Value, simulate: &Value, approval: &Value) -> Self {
+        let action = intent
+            .get("action")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+        let summary = approval.get("summary").cloned().unwrap_or(Value::Null);
+        let is_swap = action.starts_with("swap");
+
+        let mut tokens: Vec<String> = vec![];
+        for (src, keys) in [
+            (
+                intent,
+                &["input_token", "output_token", "asset", "token"][..],
+            ),
+            (
+                simulate,
+                &[
+                    "input_token",
+                    "output_token",
+                    "asset",
+                    "input_mint",
+                    "output_mint",
+                    "mint",
+                    "token_address",
+                    "sell_token_address",
+                    "buy_token_address",
+                    "input_coin_type",
+                    "output_coin_type",
+                ][..],
+            ),
+        ] {
+            for key in keys {
+                if let Some(s) = src.get(*key).and_then(Value::as_str) {
+                    if !s.is_empty() && !tokens.iter().any(|t| t.eq_ignore_ascii_case(s)) {
+                        tokens.push(s.to_string());
+                    }
+                }
+            }
+        }
+        if matches!(
+            action.as_str(),
+            "transfer_native" | "transfer_sui" | "pay" | "pay_sui"
+        ) {
+            match chain {
+                "solana" => tokens.push("SOL".to_string()),
+                "sui" => tokens.push("SUI".to_string()),
+                _ => {}
+            }
+        }
+
+        let swap_mode = is_swap.then(|| {
+            simulate
+                .get("swap_mode")
+                .and_then(Value::as_str)
+                .unwrap_or(if action == "swap_exact_out" {
+                    "ExactOut"
+                } else {
+                    "ExactIn"
+                })
+                .to_string()
+        });
+
+        // Transfers: amount moved; swaps: amount in (UI).
+        let amount_ui = if is_swap {
+            num(summary.get("in_amount_ui"))
+                .or_else(|| num(simulate.get("amount_in")))
+                .or_else(|| num(intent.get("amount_in")))
+        } else {
+            num(simulate.get("amount_ui")).or_else(|| {
+                simulate
+                    .get("total_mist")
+                    .and_then(Value::as_str)
+                    .and_then(|m| format_base_units_ui(m, 9).parse::<f64>().ok())
+            })
+        };
+
+        // USD value: aggregator estimate first, else whichever side is a stablecoin.
+        let quote = simulate.get("quote").cloned().unwrap_or(Value::Null);
+        let amount_usd = num(quote.get("swapUsdValue")).or_else(|| {
+            if is_swap {
+                let input = intent
+                    .get("input_token")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let output = intent
+                    .get("output_token")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let in_mint = simulate
+                    .get("input_mint")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                let out_mint = simulate
+                    .get("output_mint")
+                    .and_then(Value::as_str)
+                    .unwrap_or("");
+                if is_stable(input) || is_stable(in_mint) {
+                    amount_ui
+                } else if is_stable(output) || is_stable(out_mint) {
+                    num(summary.get("out_amount_ui"))
+                } else {
+                    None
+                }
+            } else if tokens.iter().any(|t| is_stable(t)) {
+                amount_ui
+            } else {
+                None
+            }
+        });
+
+        // Jupiter reports priceImpactPct as a fraction ("0.0012" = 0.12%); 0x as a percent.
+        let price_impact_pct = match chain {
+            "solana" => num(quote.get("priceImpactPct")).map(|f| f * 100.0),
+            _ => num(simulate.get("price_impact")),
+        };
+
+        let mut recipients: Vec<String> = vec![];
+        for v in [
+            simulate.get("to"),
+            intent.get("to"),
+            intent.get("recipient"),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if let Some(s) = v.as_str() {
+                recipients.push(s.to_string());
+            }
+        }
+        for v in [simulate.get("recipients"), intent.get("recipients")]
+            .into_iter()
+            .flatten()
+        {
+            for r in v.as_array().into_iter().flatten().filter_map(Value::as_str) {
+                recipients.push(r.to_string());
+            }
+        }
+        recipients.retain(|r| !r.is_empty() && !r.starts_with('<'));
+        recipients.sort();
+        recipients.dedup();
+
+        Self {
+            chain: chain.to_string(),
+            action,
+            tokens,
+            swap_mode,
+            amount_ui,
+            amount_usd,
+            slippage_bps: simulate.get("slippage_bps").and_then(Value::as_u64),
+            price_impact_pct,
+            route_steps: quote
+                .get("routePlan")
+                .and_then(Value::as_array)
+                .map(|a| a.len() as u64),
+            min_out_ui: num(summary.get("min_out_ui")),
+            max_in_ui: num(summary
+                .get("exact_out")
+                .and_then(|e| e.get("max_in_amount_ui"))),
+            recipients,
+        }
+    }
+}
+
+fn in_list(list: &[String], value: &str) -> bool {
+    list.iter().any(|v| v.trim().eq_ignore_ascii_case(value))
+}
+
+/// Reasons the rule fired, or None if it does not apply to these facts.
+fn rule_matches(rule: &PolicyRule, f: &PolicyFacts) -> Option<Vec<String>> {
+    if !rule.chain.is_empty() && !in_list(&rule.chain, &f.chain) {
+        return None;
+    }
+    if !rule.action.is_empty() && !in_list(&rule.action, &f.action) {
+        return None;
+    }
+    if !rule.token.is_empty() && !f.tokens.iter().any(|t| in_list(&rule.token, t)) {
+        return None;
+    }
+    if let Some(mode) = rule.swap_mode.as_deref() {
+        if !f
+            .swap_mode
+            .as_deref()
+            .is_some_and(|m| m.eq_ignore_ascii_case(mode))
+        {
+            return None;
+        }
+    }
+
+    let mut reasons = vec![];
+    if let Some(t) = rule.amount_usd_gte {
+        match f.amount_usd {
+            Some(v) if v >= t => reasons.push(format!("amount_usd {v} >= {t}")),
+            _ => return None,
+        }
+    }
+    if let Some(unknown) = rule.amount_usd_unknown {
+        if f.amount_usd.is_none() != unknown {
+            return None;
+        }
+        reasons.push(format!("amount_usd unknown = {unknown}"));
+    }
+    if let Some(t) = rule.amount_ui_gte {
+        match f.amount_ui {
+            Some(v) if v >= t => reasons.push(format!("amount_ui {v} >= {t}")),
+            _ => return None,
+        }
+    }
+    if let Some(t) = rule.slippage_bps_gte {
+        match f.slippage_bps {
+            Some(v) if v >= t => reasons.push(format!("slippage_bps {v} >= {t}")),
+            _ => return None,
+        }
+    }
+    if let Some(t) = rule.price_impact_pct_gte {
+        match f.price_impact_pct {
+            Some(v) if v >= t => reasons.push(format!("price_impact_pct {v} >= {t}")),
+            _ => return None,
+        }
+    }
+    if let Some(t) = rule.route_steps_gt {
+        match f.route_steps {
+            Some(v) if v > t => reasons.push(format!("route_steps {v} > {t}")),
+            _ => return None,
+        }
+    }
+    if let Some(t) = rule.min_out_ui_lt {
+        match f.min_out_ui {
+            Some(v) if v > 0.0 && v < t => reasons.push(format!("min_out_ui {v} < {t}")),
+            _ => return None,
+        }
+    }
+    if let Some(t) = rule.max_in_ui_gte {
+        match f.max_in_ui {
+            Some(v) if v >= t => reasons.push(format!("max_in_ui {v} >= {t}")),
+            _ => return None,
+        }
+    }
+    if !rule.recipient_in.is_empty() {
+        let hits: Vec<&String> = f
+            .recipients
+            .iter()
+            .filter(|r| in_list(&rule.recipient_in, r))
+            .collect();
+        if hits.is_empty() {
+            return None;
+        }
+        reasons.push(format!("recipient listed: {:?}", hits));
+    }
+    if !rule.recipient_not_in.is_empty() {
+        let misses: Vec<&String> = f
+            .recipients
+            .iter()
+            .filter(|r| !in_list(&rule.recipient_not_in, r))
+            .collect();
+        if misses.is_empty() {
+            return None;
+        }
+        reasons.push(format!("recipient not allowed: {:?}", misses));
+    }
+    if reasons.is_empty() {
+        reasons.push("scope matched".to_string());
+    }
+    Some(reasons)
+}
+
+/// Evaluate every rule; returns the strictest decision and the rules that fired (in file order).
+pub fn evaluate(policy: &ApprovalPolicy, facts: &PolicyFacts) -> (Decision, Vec<MatchedRule>) {
+    let mut decision = Decision::Ok;
+    let mut matched = vec![];
+    for rule in &policy.rules {
+        let Some(reasons) = rule_matches(rule, facts) else {
+            continue;
+        };
+        decision = decision.max(rule.decision);
+        let reason = match rule.description.as_deref() {
+            Some(d) => format!("{d} ({})", reasons.join(", ")),
+            None => reasons.join(", "),
+        };
+        matched.push(MatchedRule {
+            id: rule.id.clone(),
+            decision: rule.decision,
+            kind: rule
+                .kind
+                .clone()
+                .unwrap_or_else(|| "policy_rule".to_string()),
+            reason,
+        });
+    }
+    (decision, matched)
+}
+
+/// Apply the active policy to an adapter's approval artifact.
+///
+/// Fired rules become warnings (`rule_id`, `decision`); the approval status becomes the stricter
+/// of the adapter's own checks and the policy decision. Non-ok simulations are left untouched.
+pub fn apply(chain: &str, intent: &Value, simulate: &Value, mut approval: Value) -> Value {
+    if simulate.get("status").and_then(Value::as_str) != Some("ok") || !approval.is_object() {
+        return approval;
+    }
+    let current = approval
+        .get("status")
+        .and_then(Value::as_str)
+        .and_then(Decision::from_status);
+    let Some(current) = current else {
+        // Read-only / todo stages have nothing to approve.
+        return approval;
+    };
+
+    let facts = PolicyFacts::collect(chain, intent, simulate, &approval);
+    let mut warnings: Vec<Value> = approval
+        .get("warnings")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    let (source, decision, matched) = match load_policy() {
+        Ok(loaded) => {
+            let (decision, matched) = evaluate(&loaded.policy, &facts);
+            (loaded.source, decision, matched)
+        }
+        Err(e) => {
+            // Fail closed: a policy that cannot be read requires review.
+            warnings.push(json!({
+                "kind": "policy_error",
+                "error": e,
+                "note": "approval policy could not be loaded"
+            }));
+            ("error".to_string(), Decision::NeedsReview, vec![])
+        }
+    };
+
+    for m in matched.iter().filter(|m| m.decision != Decision::Ok) {
+        warnings.push(json!({
+            "kind": m.kind,
+            "rule_id": m.id,
+            "decision": m.decision,
+            "note": m.reason
+        }));
+    }
+
+    let status = current.max(decision);
+    approval["status"] = json!(status.status());
+    approval["warnings"] = Value::Array(warnings);
+    approval["policy"] = json!({
+        "source": source,
+        "decision": decision,
+        "matched_rule_ids": matched.iter().map(|m| m.id.clone()).collect::<Vec<_>>(),
+        "matched_rules": matched,
+        "facts": facts
+    });
+    approval
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap_facts(slippage_bps: u64, amount_usd: Option<f64>) -> PolicyFacts {
+        PolicyFacts {
+            chain: "solana".to_string(),
+            action: "swap_exact_in".to_string(),
+            tokens: strs(&["SOL", "USDC"]),
+            swap_mode: Some("ExactIn".to_string()),
+            amount_usd,
+            slippage_bps: Some(slippage_bps),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn toml_policy_evaluates_strictest_decision() {
+        let policy = parse_policy(
+            r#"
+            [[rules]]
+            id = "big-usd"
+            decision = "block"
+            chain = ["solana"]
+            amount_usd_gte = 10000.0
+
+            [[rules]]
+            id = "slippage"
+            decision = "needs_review"
+            action = ["swap_exact_in"]
+            slippage_bps_gte = 100
+            "#,
+            true,
+        )
+        .unwrap();
+
+        let (d, m) = evaluate(&policy, &swap_facts(50, Some(20.0)));
+        assert_eq!(d, Decision::Ok);
+        assert!(m.is_empty());
+
+        let (d, m) = evaluate(&policy, &swap_facts(150, Some(20_000.0)));
+        assert_eq!(d, Decision::Block);
+        let ids: Vec<&str> = m.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["big-usd", "slippage"]);
+    }
+
+    #[test]
+    fn recipient_allow_and_deny_lists() {
+        let policy = parse_policy(
+            r#"{"rules": [
+                {"id": "deny", "decision": "block", "recipient_in": ["0xBAD"]},
+                {"id": "allow", "decision": "needs_review", "recipient_not_in": ["0xgood", "0xbad"]}
+            ]}"#,
+            false,
+        )
+        .unwrap();
+        let mut facts = PolicyFacts {
+            chain: "evm".to_string(),
+            action: "transfer_native".to_string(),
+            recipients: strs(&["0xGOOD"]),
+            ..Default::default()
+        };
+        assert_eq!(evaluate(&policy, &facts).0, Decision::Ok);
+        facts.recipients = strs(&["0xbad"]);
+        assert_eq!(evaluate(&policy, &facts).0, Decision::Block);
+        facts.recipients = strs(&["0xother"]);
+        assert_eq!(evaluate(&policy, &facts).0, Decision::NeedsReview);
+    }
+
+    #[test]
+    fn invalid_policies_are_rejected() {
+        assert!(parse_policy(r#"{"rules": [{"id": "a", "decision": "maybe"}]}"#, false).is_err());
+        assert!(parse_policy(
+            r#"{"rules": [{"id": "a", "decision": "ok"}, {"id": "a", "decision": "ok"}]}"#,
+            false
+        )
+        .is_err());
+        assert!(parse_policy(
+            r#"{"rules": [{"id": "a", "decision": "ok", "slipage": 1}]}"#,
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn facts_use_stablecoin_side_for_usd() {
+        let intent = json!({"chain": "evm", "action": "swap_exact_in", "input_token": "USDC", "output_token": "WETH", "amount_in": "250"});
+        let simulate = json!({"status": "ok", "slippage_bps": 50, "price_impact": "0.3"});
+        let facts = PolicyFacts::collect("evm", &intent, &simulate, &json!({"status": "ok"}));
+        assert_eq!(facts.amount_usd, Some(250.0));
+        assert_eq!(facts.price_impact_pct, Some(0.3));
+        assert_eq!(facts.swap_mode.as_deref(), Some("ExactIn"));
+    }
+}
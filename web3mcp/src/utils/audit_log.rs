@@ -0,0 +1,759 @@
+//! Hash-chained audit log (`<data_dir>/audit.log`, JSON lines).
+//!
+//! Every record carries `seq`, `prev_hash` and `hash`, where `hash` is the SHA-256 of the
+//! record's JSON without the `hash` field (the line up to `,"hash":`). Editing, dropping or
+//! reordering a line breaks the chain, which `verify` reports. A full rewrite of the chain is
+//! only caught against a head hash kept elsewhere (`expected_head`).
+//!
+//! The current file is rotated to `audit.log.<rotated_at_ms>` by size / age; the chain
+//! continues across files. Lines written before the chain existed are moved aside to
+//! `audit.log.legacy-<ms>` and not verified.
+
+use rmcp::model::{ErrorCode, ErrorData};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// `prev_hash` of the first record.
+pub const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+const HASH_SUFFIX_LEN: usize = r#","hash":""#.len() + 64 + r#""}"#.len();
+const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_ROTATE_HOURS: u64 = 24 * 7;
+const MAX_PROBLEMS: usize = 20;
+
+static WRITE_FAILURES: AtomicU64 = AtomicU64::new(0);
+
+/// When the current file is rotated, and how many rotated files are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    /// Rotate once the file reaches this size (0 = never).
+    pub max_bytes: u64,
+    /// Rotate once the file's first record is this old (0 = never).
+    pub max_age_ms: u64,
+    /// Delete the oldest rotated files beyond this count (0 = keep all).
+    pub keep_files: usize,
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_BYTES,
+            max_age_ms: DEFAULT_ROTATE_HOURS * 3_600_000,
+            keep_files: 0,
+        }
+    }
+}
+
+impl Rotation {
+    pub fn from_env() -> Self {
+        let num = |key: &str| {
+            crate::utils::config::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let d = Self::default();
+        Self {
+            max_bytes: num("WEB3MCP_AUDIT_MAX_BYTES").unwrap_or(d.max_bytes),
+            max_age_ms: num("WEB3MCP_AUDIT_ROTATE_HOURS")
+                .map(|h| h.saturating_mul(3_600_000))
+                .unwrap_or(d.max_age_ms),
+            keep_files: num("WEB3MCP_AUDIT_KEEP_FILES")
+                .map(|n| n as usize)
+                .unwrap_or(d.keep_files),
+        }
+    }
+}
+
+/// The hashed part of a line; field order is the line's byte order.
+#[derive(Debug, Serialize)]
+struct Body<'a> {
+    seq: u64,
+    timestamp_ms: u64,
+    tool: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_id: Option<String>,
+    entry: &'a Value,
+    prev_hash: &'a str,
+}
+
+/// A parsed line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub seq: u64,
+    pub timestamp_ms: u64,
+    pub tool: String,
+    #[serde(default)]
+    pub chain: Option<String>,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    pub entry: Value,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Split a chained line into (hashed body, stored hash, record); `None` for anything else
+/// (legacy or damaged lines).
+fn split_line(line: &str) -> Option<(String, String, AuditRecord)> {
+    let line = line.trim_end();
+    if line.len() < HASH_SUFFIX_LEN || !line.is_char_boundary(line.len() - HASH_SUFFIX_LEN) {
+        return None;
+    }
+    let (head, suffix) = line.split_at(line.len() - HASH_SUFFIX_LEN);
+    let hash = suffix
+        .strip_prefix(r#","hash":""#)?
+        .strip_suffix(r#""}"#)?
+        .to_string();
+    if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let record = serde_json::from_str::<AuditRecord>(line).ok()?;
+    Some((format!("{}}}", head), hash, record))
+}
+
+/// Chain a tool name / entry belongs to (`entry.chain`, else the tool name).
+pub fn chain_of(tool: &str, entry: &Value) -> Option<String> {
+    if let Some(c) = entry.get("chain").and_then(Value::as_str) {
+        return Some(c.to_lowercase());
+    }
+    let t = tool.to_lowercase();
+    ["evm", "solana", "sui"]
+        .into_iter()
+        .find(|c| t.starts_with(&format!("{}_", c)) || t.ends_with(&format!("_{}", c)))
+        .map(str::to_string)
+}
+
+/// Whether any string in `v` equals `needle` (ASCII case-insensitive).
+fn contains_str(v: &Value, needle: &str) -> bool {
+    match v {
+        Value::String(s) => s.eq_ignore_ascii_case(needle),
+        Value::Array(items) => items.iter().any(|i| contains_str(i, needle)),
+        Value::Object(m) => m.values().any(|i| contains_str(i, needle)),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    /// Tool name or glob (`evm_*`).
+    pub tool: Option<String>,
+    pub chain: Option<String>,
+    /// Matches any string field of the entry (sender, recipient, signer, spender, ...).
+    pub address: Option<String>,
+    /// Matches any string field of the entry (digest, tx_hash, signature, ...).
+    pub tx_hash: Option<String>,
+    pub since_ms: Option<u64>,
+    pub until_ms: Option<u64>,
+    /// Only records written by this session (HTTP sessions see their own records only).
+    pub session_id: Option<String>,
+    pub limit: usize,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, r: &AuditRecord) -> bool {
+        if let Some(t) = self.tool.as_deref() {
+            if !crate::utils::tool_profiles::glob_match(t, &r.tool) {
+                return false;
+            }
+        }
+        if let Some(c) = self.chain.as_deref() {
+            if !r
+                .chain
+                .as_deref()
+                .is_some_and(|rc| rc.eq_ignore_ascii_case(c))
+            {
+                return false;
+            }
+        }
+        if self
+            .session_id
+            .as_deref()
+            .is_some_and(|s| r.session_id.as_deref() != Some(s))
+        {
+            return false;
+        }
+        if self.since_ms.is_some_and(|s| r.timestamp_ms < s)
+            || self.until_ms.is_some_and(|u| r.timestamp_ms > u)
+        {
+            return false;
+        }
+        [self.address.as_deref(), self.tx_hash.as_deref()]
+            .into_iter()
+            .flatten()
+            .all(|needle| contains_str(&r.entry, needle.trim()))
+    }
+}
+
+fn io_err(context: &str, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("{}: {}", context, e)),
+        data: None,
+    }
+}
+
+/// Last non-empty line of a file, read from the end.
+fn last_line(path: &Path) -> std::io::Result<Option<String>> {
+    let mut file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let len = file.metadata()?.len();
+    let mut chunk = 64 * 1024u64;
+    loop {
+        let start = len.saturating_sub(chunk);
+        file.seek(SeekFrom::Start(start))?;
+        let mut buf = Vec::new();
+        Read::take(&mut file, len - start).read_to_end(&mut buf)?;
+        let text = String::from_utf8_lossy(&buf);
+        let trimmed = text.trim_end();
+        match trimmed.rfind('\n') {
+            Some(i) => return Ok(Some(trimmed[i + 1..].to_string())),
+            None if start == 0 => {
+                return Ok((!trimmed.is_empty()).then(|| trimmed.to_string()));
+            }
+            None => chunk *= 4,
+        }
+    }
+}
+
+fn first_line(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut line = String::new();
+    std::io::BufReader::new(file).read_line(&mut line).ok()?;
+    Some(line)
+}
+
+/// Cross-process lock next to the log (`<file>.lock`), so concurrent servers sharing a data
+/// dir do not fork the chain. A lock older than 10s is considered stale.
+struct FileLock(PathBuf);
+
+impl FileLock {
+    fn acquire(path: PathBuf) -> std::io::Result<Self> {
+        for _ in 0..500 {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Self(path)),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let stale = std::fs::metadata(&path)
+                        .and_then(|m| m.modified())
+                        .ok()
+                        .and_then(|t| t.elapsed().ok())
+                        .is_some_and(|age| age.as_secs() >= 10);
+                    if stale {
+                        let _ = std::fs::remove_file(&path);
+                    } else {
+                        std::thread::sleep(std::time::Duration::from_millis(10));
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            format!("audit log is locked ({})", path.display()),
+        ))
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+pub struct AuditLog {
+    path: PathBuf,
+    rotation: Rotation,
+}
+
+impl AuditLog {
+    pub fn new(path: PathBuf, rotation: Rotation) -> Self {
+        Self { path, rotation }
+    }
+
+    /// `data_dir::audit_log_path()` with the configured rotation.
+    pub fn open_default() -> Self {
+        Self::new(
+            crate::utils::data_dir::audit_log_path(),
+            Rotation::from_env(),
+        )
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audit.log".to_string())
+    }
+
+    fn sibling(&self, suffix: &str) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.{}", self.file_name(), suffix))
+    }
+
+    /// Rotated files (oldest first) and legacy files.
+    fn rotated(&self) -> (Vec<PathBuf>, Vec<PathBuf>) {
+        let prefix = format!("{}.", self.file_name());
+        let mut rotated: Vec<(u64, PathBuf)> = Vec::new();
+        let mut legacy = Vec::new();
+        let dir = match self.path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(suffix) = name.strip_prefix(&prefix) else {
+                continue;
+            };
+            if let Ok(ms) = suffix.parse::<u64>() {
+                rotated.push((ms, entry.path()));
+            } else if suffix.starts_with("legacy-") {
+                legacy.push(entry.path());
+            }
+        }
+        rotated.sort();
+        legacy.sort();
+        (rotated.into_iter().map(|(_, p)| p).collect(), legacy)
+    }
+
+    /// Every chained file, oldest first (rotated files, then the current one).
+    pub fn segments(&self) -> Vec<PathBuf> {
+        let mut out = self.rotated().0;
+        if self.path.is_file() {
+            out.push(self.path.clone());
+        }
+        out
+    }
+
+    /// `(seq, hash)` of the last record, `(0, GENESIS)` for an empty log.
+    fn head(&self) -> std::io::Result<(u64, String)> {
+        for path in std::iter::once(self.path.clone()).chain(self.rotated().0.into_iter().rev()) {
+            if let Some(line) = last_line(&path)? {
+                if let Some((_, hash, r)) = split_line(&line) {
+                    return Ok((r.seq, hash));
+                }
+            }
+        }
+        Ok((0, GENESIS.to_string()))
+    }
+
+    fn move_aside(&self, suffix: String) -> std::io::Result<()> {
+        let mut target = self.sibling(&suffix);
+        let mut n = 1;
+        while target.exists() {
+            target = self.sibling(&format!("{}-{}", suffix, n));
+            n += 1;
+        }
+        std::fs::rename(&self.path, target)
+    }
+
+    /// Move the current file aside when it is a pre-chain log or due for rotation.
+    fn maybe_rotate(&self, now_ms: u64) -> std::io::Result<()> {
+        let Ok(meta) = std::fs::metadata(&self.path) else {
+            return Ok(());
+        };
+        if meta.len() == 0 {
+            return Ok(());
+        }
+        let first = first_line(&self.path).and_then(|l| split_line(&l));
+        let Some((_, _, first)) = first else {
+            return self.move_aside(format!("legacy-{}", now_ms));
+        };
+        let too_big = self.rotation.max_bytes > 0 && meta.len() >= self.rotation.max_bytes;
+        let too_old = self.rotation.max_age_ms > 0
+            && now_ms.saturating_sub(first.timestamp_ms) >= self.rotation.max_age_ms;
+        if !(too_big || too_old) {
+            return Ok(());
+        }
+        // Plain digits so the name sorts into `rotated()`.
+        let mut target = now_ms;
+        while self.sibling(&target.to_string()).exists() {
+            target += 1;
+        }
+        std::fs::rename(&self.path, self.sibling(&target.to_string()))?;
+
+        if self.rotation.keep_files > 0 {
+            let rotated = self.rotated().0;
+            let excess = rotated.len().saturating_sub(self.rotation.keep_files);
+            for old in rotated.into_iter().take(excess) {
+                std::fs::remove_file(old)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Append one record, chained to the current head.
+    pub fn append(
+        &self,
+        now_ms: u64,
+        tool: &str,
+        entry: &Value,
+        session_id: Option<String>,
+    ) -> std::io::Result<AuditRecord> {
+        static LOCK: Mutex<()> = Mutex::new(());
+        let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+        let _lock = FileLock::acquire(self.sibling("lock"))?;
+
+        self.maybe_rotate(now_ms)?;
+        let (seq, prev_hash) = self.head()?;
+        let body = Body {
+            seq: seq + 1,
+            timestamp_ms: now_ms,
+            tool,
+            chain: chain_of(tool, entry),
+            session_id,
+            entry,
+            prev_hash: &prev_hash,
+        };
+        let body_json = serde_json::to_string(&body)?;
+        let hash = sha256_hex(body_json.as_bytes());
+        let line = format!(
+            r#"{},"hash":"{}"}}"#,
+            &body_json[..body_json.len() - 1],
+            hash
+        );
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)?;
+        file.sync_data()?;
+
+        Ok(AuditRecord {
+            seq: body.seq,
+            timestamp_ms: now_ms,
+            tool: tool.to_string(),
+            chain: body.chain,
+            session_id: body.session_id,
+            entry: entry.clone(),
+            prev_hash,
+            hash,
+        })
+    }
+
+    /// Walk every chained file and check hashes, links and sequence numbers.
+    pub fn verify(&self, expected_head: Option<&str>) -> Result<Value, ErrorData> {
+        let _guard = FileLock::acquire(self.sibling("lock"))
+            .map_err(|e| io_err("Failed to lock audit log", e))?;
+        let (_, legacy) = self.rotated();
+        let mut problems: Vec<Value> = Vec::new();
+        let mut problem_count = 0usize;
+        let mut files = Vec::new();
+        let mut prev: Option<(u64, String)> = None;
+        let mut first_seq: Option<u64> = None;
+        let mut records = 0u64;
+
+        for path in self.segments() {
+            let file = std::fs::File::open(&path)
+                .map_err(|e| io_err(&format!("Failed to open {}", path.display()), e))?;
+            let mut file_records = 0u64;
+            for (i, line) in std::io::BufReader::new(file).lines().enumerate() {
+                let line = line.map_err(|e| io_err("Failed to read audit log", e))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let mut problem = |seq: Option<u64>, error: String| {
+                    problem_count += 1;
+                    if problems.len() < MAX_PROBLEMS {
+                        problems.push(json!({
+                            "file": path.display().to_string(),
+                            "line": i + 1,
+                            "seq": seq,
+                            "error": error,
+                        }));
+                    }
+                };
+                let Some((body, hash, r)) = split_line(&line) else {
+                    problem(None, "unparseable record".to_string());
+                    continue;
+                };
+                file_records += 1;
+                records += 1;
+                first_seq.get_or_insert(r.seq);
+                if sha256_hex(body.as_bytes()) != hash {
+                    problem(
+                        Some(r.seq),
+                        "hash mismatch (record was modified)".to_string(),
+                    );
+                }
+                if let Some((prev_seq, prev_hash)) = &prev {
+                    if r.prev_hash != *prev_hash {
+                        problem(
+                            Some(r.seq),
+                            format!("broken link: prev_hash does not match record {}", prev_seq),
+                        );
+                    }
+                    if r.seq != prev_seq + 1 {
+                        problem(
+                            Some(r.seq),
+                            format!("sequence gap: expected {}", prev_seq + 1),
+                        );
+                    }
+                }
+                prev = Some((r.seq, hash));
+            }
+            files.push(json!({"path": path.display().to_string(), "records": file_records}));
+        }
+
+        let head = prev
+            .as_ref()
+            .map(|(seq, hash)| json!({"seq": seq, "hash": hash}));
+        let head_matches = expected_head.map(|h| {
+            prev.as_ref()
+                .is_some_and(|(_, hash)| hash.eq_ignore_ascii_case(h.trim()))
+        });
+        // The oldest kept record links to GENESIS unless older files were rotated away.
+        let complete = first_seq.is_none_or(|s| s == 1);
+        Ok(json!({
+            "ok": problem_count == 0 && head_matches != Some(false),
+            "records": records,
+            "head": head,
+            "expected_head_matches": head_matches,
+            "starts_at_seq": first_seq,
+            "complete_from_genesis": complete,
+            "problem_count": problem_count,
+            "problems": problems,
+            "files": files,
+            "legacy_files_not_verified": legacy.iter().map(|p| p.display().to_string()).collect::<Vec<_>>(),
+            "write_failures_since_start": WRITE_FAILURES.load(Ordering::Relaxed),
+        }))
+    }
+
+    /// Matching records, newest first.
+    pub fn query(&self, filter: &AuditFilter) -> Result<Vec<AuditRecord>, ErrorData> {
+        let limit = filter.limit.max(1);
+        let mut out = Vec::new();
+        for path in self.segments().into_iter().rev() {
+            let text = std::fs::read_to_string(&path)
+                .map_err(|e| io_err(&format!("Failed to read {}", path.display()), e))?;
+            for line in text.lines().rev() {
+                let Some((_, _, r)) = split_line(line) else {
+                    continue;
+                };
+                if filter.matches(&r) {
+                    out.push(r);
+                    if out.len() >= limit {
+                        return Ok(out);
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Append to the default log. Failures are logged and counted (see `verify`) rather than
+/// failing the tool call that already happened.
+pub fn write(tool: &str, entry: Value) {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let log = AuditLog::open_default();
+    if let Err(e) = log.append(now_ms, tool, &entry, crate::utils::session_scope::current()) {
+        WRITE_FAILURES.fetch_add(1, Ordering::Relaxed);
+        tracing::error!(
+            tool,
+            path = %log.path().display(),
+            error = %e,
+            "Failed to write audit record"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(dir: &Path, rotation: Rotation) -> AuditLog {
+        AuditLog::new(dir.join("audit.log"), rotation)
+    }
+
+    fn append(l: &AuditLog, ms: u64, tool: &str, entry: Value) -> AuditRecord {
+        l.append(ms, tool, &entry, None).unwrap()
+    }
+
+    fn append_in(l: &AuditLog, ms: u64, tool: &str, session: &str) -> AuditRecord {
+        l.append(ms, tool, &json!({}), Some(session.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn chain_verifies_and_detects_edits() {
+        let tmp = tempfile::tempdir().unwrap();
+        let l = log(tmp.path(), Rotation::default());
+        let a = append(
+            &l,
+            1,
+            "execute_transfer_sui",
+            json!({"amount": 5, "sender": "0xA"}),
+        );
+        let b = append(&l, 2, "evm_sign_transaction_local", json!({"to": "0xB"}));
+        assert_eq!((a.seq, a.prev_hash.as_str()), (1, GENESIS));
+        assert_eq!((b.seq, b.prev_hash.as_str()), (2, a.hash.as_str()));
+        assert_eq!(a.chain.as_deref(), Some("sui"));
+
+        let report = l.verify(Some(&b.hash)).unwrap();
+        assert_eq!(report["ok"], true, "{}", report);
+        assert_eq!(report["records"], 2);
+        assert_eq!(l.verify(Some(&a.hash)).unwrap()["ok"], false);
+
+        let text = std::fs::read_to_string(l.path()).unwrap();
+        std::fs::write(l.path(), text.replacen("\"amount\":5", "\"amount\":50", 1)).unwrap();
+        let report = l.verify(None).unwrap();
+        assert_eq!(report["ok"], false);
+        assert_eq!(report["problems"][0]["seq"], 1);
+
+        // Dropping a record breaks the link and the sequence.
+        let second = text.lines().nth(1).unwrap();
+        std::fs::write(l.path(), format!("{}\n", second)).unwrap();
+        append(&l, 3, "evm_x", json!({}));
+        let report = l.verify(None).unwrap();
+        assert_eq!(report["complete_from_genesis"], false);
+        assert_eq!(
+            report["ok"], true,
+            "a truncated head still verifies from where it starts"
+        );
+    }
+
+    #[test]
+    fn rotation_keeps_the_chain_and_moves_legacy_lines_aside() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("audit.log"),
+            "{\"timestamp_ms\":1,\"tool\":\"old\"}\n",
+        )
+        .unwrap();
+        let l = log(
+            tmp.path(),
+            Rotation {
+                max_bytes: 1,
+                max_age_ms: 0,
+                keep_files: 0,
+            },
+        );
+        for i in 0..3 {
+            append(&l, 10 + i, "solana_send", json!({"i": i}));
+        }
+        assert_eq!(l.segments().len(), 3);
+        let report = l.verify(None).unwrap();
+        assert_eq!(report["ok"], true, "{}", report);
+        assert_eq!(report["records"], 3);
+        assert_eq!(report["complete_from_genesis"], true);
+        assert_eq!(
+            report["legacy_files_not_verified"]
+                .as_array()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let l = log(
+            tmp.path(),
+            Rotation {
+                max_bytes: 1,
+                max_age_ms: 0,
+                keep_files: 1,
+            },
+        );
+        append(&l, 20, "solana_send", json!({}));
+        assert_eq!(l.segments().len(), 2);
+        assert_eq!(l.verify(None).unwrap()["ok"], true);
+    }
+
+    #[test]
+    fn query_filters_and_returns_newest_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        let l = log(tmp.path(), Rotation::default());
+        append(
+            &l,
+            100,
+            "execute_transfer_sui",
+            json!({"sender": "0xAbC", "digest": "D1"}),
+        );
+        append(
+            &l,
+            200,
+            "evm_sign_transaction_local",
+            json!({"from": "0xabc", "chain_id": 1}),
+        );
+        append(&l, 300, "evm_confirm_execution", json!({"tx_hash": "0xT"}));
+
+        let q = |f: AuditFilter| -> Vec<u64> {
+            l.query(&AuditFilter { limit: 10, ..f })
+                .unwrap()
+                .iter()
+                .map(|r| r.seq)
+                .collect()
+        };
+        assert_eq!(q(AuditFilter::default()), vec![3, 2, 1]);
+        assert_eq!(
+            q(AuditFilter {
+                address: Some("0xABC".to_string()),
+                ..Default::default()
+            }),
+            vec![2, 1]
+        );
+        assert_eq!(
+            q(AuditFilter {
+                tool: Some("evm_*".to_string()),
+                since_ms: Some(250),
+                ..Default::default()
+            }),
+            vec![3]
+        );
+        assert_eq!(
+            q(AuditFilter {
+                chain: Some("sui".to_string()),
+                tx_hash: Some("d1".to_string()),
+                ..Default::default()
+            }),
+            vec![1]
+        );
+    }
+    #[test]
+    fn query_is_scoped_to_the_session() {
+        let tmp = tempfile::tempdir().unwrap();
+        let l = log(tmp.path(), Rotation::default());
+        append_in(&l, 100, "evm_confirm_execution", "a");
+        append_in(&l, 200, "evm_confirm_execution", "b");
+        append(&l, 300, "evm_confirm_execution", json!({}));
+
+        let seqs = |session: Option<&str>| -> Vec<u64> {
+            let filter = AuditFilter {
+                session_id: session.map(str::to_string),
+                limit: 10,
+                ..Default::default()
+            };
+            l.query(&filter).unwrap().iter().map(|r| r.seq).collect()
+        };
+        assert_eq!(seqs(Some("a")), vec![1]);
+        assert_eq!(seqs(Some("b")), vec![2]);
+        assert_eq!(seqs(None), vec![3, 2, 1]);
+    }
+}
@@ -0,0 +1,1128 @@
+//! Server configuration file (`web3mcp.toml`).
+//!
+//! One typed file replaces the scattered env vars. It is looked up at `WEB3MCP_CONFIG`, else
+//! `./web3mcp.toml`, else `$WEB3MCP_DATA_DIR/web3mcp.toml` (default `~/.web3mcp/web3mcp.toml`).
+//! Every setting keeps its env var and the env var wins over the file, so existing deployments
+//! keep working. Code reads settings through [`var`], a drop-in for `std::env::var`; see
+//! `web3mcp.example.toml` for the schema.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+pub const CONFIG_ENV: &str = "WEB3MCP_CONFIG";
+pub const FILE_NAME: &str = "web3mcp.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub storage: StorageConfig,
+    pub runs: RunsConfig,
+    pub audit: AuditConfig,
+    pub cache: CacheConfig,
+    pub sui: SuiConfig,
+    pub solana: SolanaConfig,
+    pub evm: EvmConfig,
+    pub signer: SignerConfig,
+    pub w3rt: W3rtConfig,
+    pub rpc: RpcConfig,
+    pub tools: ToolsConfig,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub sse_bind: Option<String>,
+    /// Streamable HTTP transport (`--http`).
+    pub http_bind: Option<String>,
+    pub http_path: Option<String>,
+    /// Bearer / API-key tokens accepted by `--http` (see `http_auth`).
+    pub auth_tokens: Option<Vec<String>>,
+    /// Browser origins allowed by CORS on `--http`.
+    pub cors_origins: Option<Vec<String>>,
+    /// Prometheus endpoint of `--http` (default `/metrics`).
+    pub metrics_path: Option<String>,
+    /// How often subscribed MCP resources are checked for changes (0 = no notifications).
+    pub resource_poll_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    pub data_dir: Option<String>,
+    pub runs_dir: Option<String>,
+    pub audit_log: Option<String>,
+    pub evm_abi_registry_dir: Option<String>,
+    pub solana_idl_registry_dir: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RunsConfig {
+    pub max_age_days: Option<u64>,
+    pub max_count: Option<u64>,
+    pub retention_mode: Option<String>,
+}
+
+/// Audit log rotation (see `audit_log`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuditConfig {
+    pub max_bytes: Option<u64>,
+    pub rotate_hours: Option<u64>,
+    pub keep_files: Option<u64>,
+}
+
+/// Shared RPC response cache (see `response_cache`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    pub mode: Option<String>,
+    pub max_entries: Option<u64>,
+    pub token_metadata_ttl_secs: Option<u64>,
+    pub token_list_ttl_secs: Option<u64>,
+    pub balance_ttl_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiConfig {
+    pub rpc_url: Option<String>,
+    /// Per-network RPC (`mainnet` / `testnet` / `devnet` / `localnet`).
+    pub rpc_urls: BTreeMap<String, String>,
+    pub network: Option<String>,
+    pub grpc_url: Option<String>,
+    pub graphql_url: Option<String>,
+    pub keystore_path: Option<String>,
+    pub default_signer: Option<String>,
+    pub dapp_manifest: Option<String>,
+    pub aggregator_base_url: Option<String>,
+    pub usdc_coin_type: Option<String>,
+    pub usdt_coin_type: Option<String>,
+    /// Gas station mode (`[sui.sponsor]`).
+    pub sponsor: SuiSponsorConfig,
+}
+
+/// Sponsor account and policy for sponsored Sui transactions (see `sui_sponsor`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SuiSponsorConfig {
+    pub account: Option<String>,
+    pub max_gas_budget: Option<u64>,
+    pub sender_budget_mist: Option<u64>,
+    pub budget_window_secs: Option<u64>,
+    pub allowed_move_targets: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SolanaConfig {
+    pub rpc_url: Option<String>,
+    /// Per-network RPC (`mainnet` / `devnet` / `testnet`).
+    pub rpc_urls: BTreeMap<String, String>,
+    pub keypair_path: Option<String>,
+    pub jupiter_quote_base_url: Option<String>,
+    pub jupiter_tokens_url: Option<String>,
+    pub meteora_dlmm_api_base_url: Option<String>,
+    pub portfolio_dust_ui: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EvmConfig {
+    pub default_chain_id: Option<u64>,
+    /// RPC URL per chain id (`"8453" = "https://..."`).
+    pub rpc_urls: BTreeMap<String, String>,
+    /// ERC20 address per chain id and lowercase symbol (`[evm.token_addresses.8453] usdc = "0x.."`).
+    pub token_addresses: BTreeMap<String, BTreeMap<String, String>>,
+    pub keystore_dir: Option<String>,
+    pub private_key: Option<String>,
+    pub confirm_large_value_threshold_wei: Option<String>,
+}
+
+/// Where the execute / confirm paths get signatures from (see `signer`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SignerConfig {
+    pub backend: Option<String>,
+    /// Per-chain overrides of `backend`.
+    pub sui_backend: Option<String>,
+    pub solana_backend: Option<String>,
+    pub evm_backend: Option<String>,
+    pub keystore_dir: Option<String>,
+    pub keystore_account: Option<String>,
+    pub keystore_password: Option<String>,
+    pub remote_url: Option<String>,
+    pub remote_token: Option<String>,
+    pub remote_timeout_ms: Option<u64>,
+    /// Stored mnemonic and per-chain HD paths for the `mnemonic` backend.
+    pub mnemonic: Option<String>,
+    pub sui_derivation_path: Option<String>,
+    pub solana_derivation_path: Option<String>,
+    pub evm_derivation_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct W3rtConfig {
+    pub approval_policy: Option<String>,
+    pub spend_limits: Option<String>,
+    pub swap_max_slippage_bps: Option<u64>,
+    pub swap_max_route_steps: Option<u64>,
+    pub swap_exact_out_max_slippage_bps: Option<u64>,
+    pub swap_exact_out_max_in_ui: Option<f64>,
+    pub swap_exact_in_min_out_ui: Option<f64>,
+}
+
+/// Failover across the URLs of an RPC list (see `rpc_endpoints`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RpcConfig {
+    pub max_attempts: Option<u64>,
+    pub timeout_ms: Option<u64>,
+    pub probe_interval_secs: Option<u64>,
+}
+
+/// Which tools are listed / callable (see `tool_profiles`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolsConfig {
+    pub profile: Option<String>,
+    /// Globs added to / removed from the selected profile.
+    pub allow: Option<Vec<String>>,
+    pub deny: Option<Vec<String>>,
+    /// Custom profiles (`[tools.profiles.<name>]`).
+    pub profiles: BTreeMap<String, ToolProfileConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolProfileConfig {
+    pub description: Option<String>,
+    /// Built-in profile this one starts from (its globs come first).
+    pub extends: Option<String>,
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Str,
+    Path,
+    Url,
+    /// Comma-separated http(s) URLs (primary first, then fallbacks).
+    UrlList,
+    U64,
+    F64,
+    /// Non-negative integer too large for `u64` (wei amounts).
+    Digits,
+    SocketAddr,
+    OneOf(&'static [&'static str]),
+    /// Comma-separated tool-name globs (a TOML array in the file).
+    GlobList,
+    /// Comma-separated `package::module::function` globs (a TOML array in the file).
+    MoveTargetList,
+    /// Never shown by `system_config_show`.
+    Secret,
+}
+
+/// A file key and the env var(s) that override it (first set env var wins).
+pub struct Setting {
+    pub key: &'static str,
+    pub env: &'static [&'static str],
+    pub kind: Kind,
+}
+
+const fn setting(key: &'static str, env: &'static [&'static str], kind: Kind) -> Setting {
+    Setting { key, env, kind }
+}
+
+pub const SETTINGS: &[Setting] = &[
+    setting("server.sse_bind", &["WEB3MCP_SSE_BIND"], Kind::SocketAddr),
+    setting("server.http_bind", &["WEB3MCP_HTTP_BIND"], Kind::SocketAddr),
+    setting("server.http_path", &["WEB3MCP_HTTP_PATH"], Kind::Str),
+    setting("server.metrics_path", &["WEB3MCP_METRICS_PATH"], Kind::Str),
+    setting("server.auth_tokens", &["WEB3MCP_AUTH_TOKENS"], Kind::Secret),
+    setting(
+        "server.cors_origins",
+        &["WEB3MCP_CORS_ORIGINS"],
+        Kind::UrlList,
+    ),
+    setting(
+        "server.resource_poll_ms",
+        &["WEB3MCP_RESOURCE_POLL_MS"],
+        Kind::U64,
+    ),
+    setting("storage.data_dir", &["WEB3MCP_DATA_DIR"], Kind::Path),
+    setting("storage.runs_dir", &["WEB3MCP_RUNS_DIR"], Kind::Path),
+    setting(
+        "storage.audit_log",
+        &["WEB3MCP_AUDIT_LOG", "SUI_MCP_AUDIT_LOG"],
+        Kind::Path,
+    ),
+    setting(
+        "storage.evm_abi_registry_dir",
+        &["EVM_ABI_REGISTRY_DIR"],
+        Kind::Path,
+    ),
+    setting(
+        "storage.solana_idl_registry_dir",
+        &["SOLANA_IDL_REGISTRY_DIR"],
+        Kind::Path,
+    ),
+    setting(
+        "runs.max_age_days",
+        &["WEB3MCP_RUNS_MAX_AGE_DAYS"],
+        Kind::U64,
+    ),
+    setting("runs.max_count", &["WEB3MCP_RUNS_MAX_COUNT"], Kind::U64),
+    setting(
+        "runs.retention_mode",
+        &["WEB3MCP_RUNS_RETENTION_MODE"],
+        Kind::OneOf(&["archive", "delete"]),
+    ),
+    setting("audit.max_bytes", &["WEB3MCP_AUDIT_MAX_BYTES"], Kind::U64),
+    setting(
+        "audit.rotate_hours",
+        &["WEB3MCP_AUDIT_ROTATE_HOURS"],
+        Kind::U64,
+    ),
+    setting("audit.keep_files", &["WEB3MCP_AUDIT_KEEP_FILES"], Kind::U64),
+    setting(
+        "cache.mode",
+        &["WEB3MCP_CACHE_MODE"],
+        Kind::OneOf(&["off", "memory", "sqlite"]),
+    ),
+    setting(
+        "cache.max_entries",
+        &["WEB3MCP_CACHE_MAX_ENTRIES"],
+        Kind::U64,
+    ),
+    setting(
+        "cache.token_metadata_ttl_secs",
+        &["WEB3MCP_CACHE_TTL_TOKEN_METADATA_SECS"],
+        Kind::U64,
+    ),
+    setting(
+        "cache.token_list_ttl_secs",
+        &["WEB3MCP_CACHE_TTL_TOKEN_LIST_SECS"],
+        Kind::U64,
+    ),
+    setting(
+        "cache.balance_ttl_secs",
+        &["WEB3MCP_CACHE_TTL_BALANCE_SECS"],
+        Kind::U64,
+    ),
+    setting("sui.rpc_url", &["SUI_RPC_URL"], Kind::UrlList),
+    setting(
+        "sui.network",
+        &["SUI_NETWORK"],
+        Kind::OneOf(&["mainnet", "testnet", "devnet", "localnet"]),
+    ),
+    setting("sui.grpc_url", &["SUI_GRPC_URL"], Kind::Url),
+    setting("sui.graphql_url", &["SUI_GRAPHQL_URL"], Kind::Url),
+    setting("sui.keystore_path", &["SUI_KEYSTORE_PATH"], Kind::Path),
+    setting("sui.default_signer", &["SUI_DEFAULT_SIGNER"], Kind::Str),
+    setting("sui.dapp_manifest", &["SUI_DAPP_MANIFEST"], Kind::Path),
+    setting(
+        "sui.aggregator_base_url",
+        &["SUI_7K_BASE_URL", "SUI_AGGREGATOR_BASE_URL"],
+        Kind::Url,
+    ),
+    setting("sui.usdc_coin_type", &["SUI_USDC_COIN_TYPE"], Kind::Str),
+    setting("sui.usdt_coin_type", &["SUI_USDT_COIN_TYPE"], Kind::Str),
+    setting("sui.sponsor.account", &["SUI_SPONSOR_ACCOUNT"], Kind::Str),
+    setting(
+        "sui.sponsor.max_gas_budget",
+        &["SUI_SPONSOR_MAX_GAS_BUDGET"],
+        Kind::U64,
+    ),
+    setting(
+        "sui.sponsor.sender_budget_mist",
+        &["SUI_SPONSOR_SENDER_BUDGET_MIST"],
+        Kind::U64,
+    ),
+    setting(
+        "sui.sponsor.budget_window_secs",
+        &["SUI_SPONSOR_BUDGET_WINDOW_SECS"],
+        Kind::U64,
+    ),
+    setting(
+        "sui.sponsor.allowed_move_targets",
+        &["SUI_SPONSOR_ALLOWED_MOVE_TARGETS"],
+        Kind::MoveTargetList,
+    ),
+    setting("solana.rpc_url", &["SOLANA_RPC_URL"], Kind::UrlList),
+    setting("solana.keypair_path", &["SOLANA_KEYPAIR_PATH"], Kind::Path),
+    setting(
+        "solana.jupiter_quote_base_url",
+        &["SOLANA_JUPITER_QUOTE_BASE_URL"],
+        Kind::Url,
+    ),
+    setting(
+        "solana.jupiter_tokens_url",
+        &["SOLANA_JUPITER_TOKENS_URL"],
+        Kind::Url,
+    ),
+    setting(
+        "solana.meteora_dlmm_api_base_url",
+        &["SOLANA_METEORA_DLMM_API_BASE_URL"],
+        Kind::Url,
+    ),
+    setting(
+        "solana.portfolio_dust_ui",
+        &["SOLANA_PORTFOLIO_DUST_UI"],
+        Kind::F64,
+    ),
+    setting("evm.default_chain_id", &["EVM_DEFAULT_CHAIN_ID"], Kind::U64),
+    setting("evm.keystore_dir", &["EVM_KEYSTORE_DIR"], Kind::Path),
+    setting("evm.private_key", &["EVM_PRIVATE_KEY"], Kind::Secret),
+    setting(
+        "evm.confirm_large_value_threshold_wei",
+        &["EVM_CONFIRM_LARGE_VALUE_THRESHOLD_WEI"],
+        Kind::Digits,
+    ),
+    setting(
+        "signer.backend",
+        &["WEB3MCP_SIGNER_BACKEND"],
+        Kind::OneOf(SIGNER_BACKENDS),
+    ),
+    setting(
+        "signer.sui_backend",
+        &["WEB3MCP_SIGNER_SUI_BACKEND"],
+        Kind::OneOf(SIGNER_BACKENDS),
+    ),
+    setting(
+        "signer.solana_backend",
+        &["WEB3MCP_SIGNER_SOLANA_BACKEND"],
+        Kind::OneOf(SIGNER_BACKENDS),
+    ),
+    setting(
+        "signer.evm_backend",
+        &["WEB3MCP_SIGNER_EVM_BACKEND"],
+        Kind::OneOf(SIGNER_BACKENDS),
+    ),
+    setting(
+        "signer.keystore_dir",
+        &["WEB3MCP_SIGNER_KEYSTORE_DIR"],
+        Kind::Path,
+    ),
+    setting(
+        "signer.keystore_account",
+        &["WEB3MCP_SIGNER_KEYSTORE_ACCOUNT"],
+        Kind::Str,
+    ),
+    setting(
+        "signer.keystore_password",
+        &["WEB3MCP_SIGNER_KEYSTORE_PASSWORD"],
+        Kind::Secret,
+    ),
+    setting("signer.remote_url", &["WEB3MCP_SIGNER_URL"], Kind::Url),
+    setting(
+        "signer.remote_token",
+        &["WEB3MCP_SIGNER_TOKEN"],
+        Kind::Secret,
+    ),
+    setting(
+        "signer.remote_timeout_ms",
+        &["WEB3MCP_SIGNER_TIMEOUT_MS"],
+        Kind::U64,
+    ),
+    setting("signer.mnemonic", &["WEB3MCP_SIGNER_MNEMONIC"], Kind::Str),
+    setting(
+        "signer.sui_derivation_path",
+        &["WEB3MCP_SIGNER_SUI_DERIVATION_PATH"],
+        Kind::Str,
+    ),
+    setting(
+        "signer.solana_derivation_path",
+        &["WEB3MCP_SIGNER_SOLANA_DERIVATION_PATH"],
+        Kind::Str,
+    ),
+    setting(
+        "signer.evm_derivation_path",
+        &["WEB3MCP_SIGNER_EVM_DERIVATION_PATH"],
+        Kind::Str,
+    ),
+    setting(
+        "w3rt.approval_policy",
+        &["W3RT_APPROVAL_POLICY"],
+        Kind::Path,
+    ),
+    setting("w3rt.spend_limits", &["W3RT_SPEND_LIMITS"], Kind::Path),
+    setting(
+        "w3rt.swap_max_slippage_bps",
+        &["W3RT_SWAP_MAX_SLIPPAGE_BPS"],
+        Kind::U64,
+    ),
+    setting(
+        "w3rt.swap_max_route_steps",
+        &["W3RT_SWAP_MAX_ROUTE_STEPS"],
+        Kind::U64,
+    ),
+    setting(
+        "w3rt.swap_exact_out_max_slippage_bps",
+        &["W3RT_SWAP_EXACT_OUT_MAX_SLIPPAGE_BPS"],
+        Kind::U64,
+    ),
+    setting(
+        "w3rt.swap_exact_out_max_in_ui",
+        &["W3RT_SWAP_EXACT_OUT_MAX_IN_UI"],
+        Kind::F64,
+    ),
+    setting(
+        "w3rt.swap_exact_in_min_out_ui",
+        &["W3RT_SWAP_EXACT_IN_MIN_OUT_UI"],
+        Kind::F64,
+    ),
+    setting("rpc.max_attempts", &["WEB3MCP_RPC_MAX_ATTEMPTS"], Kind::U64),
+    setting("rpc.timeout_ms", &["WEB3MCP_RPC_TIMEOUT_MS"], Kind::U64),
+    setting(
+        "rpc.probe_interval_secs",
+        &["WEB3MCP_RPC_PROBE_INTERVAL_SECS"],
+        Kind::U64,
+    ),
+    setting("tools.profile", &["WEB3MCP_TOOL_PROFILE"], Kind::Str),
+    setting("tools.allow", &["WEB3MCP_TOOLS_ALLOW"], Kind::GlobList),
+    setting("tools.deny", &["WEB3MCP_TOOLS_DENY"], Kind::GlobList),
+];
+
+const SIGNER_BACKENDS: &[&str] = &["local", "keystore", "remote", "mnemonic"];
+const SOLANA_NETWORKS: &[&str] = &["mainnet", "devnet", "testnet"];
+const SUI_NETWORKS: &[&str] = &["mainnet", "testnet", "devnet", "localnet"];
+
+#[derive(Debug, Clone, Default)]
+pub struct Loaded {
+    pub path: Option<PathBuf>,
+    pub config: Config,
+    /// `config` as JSON, for key lookups.
+    json: Value,
+    /// Read / parse error of the file (the file is then ignored).
+    pub error: Option<String>,
+}
+
+impl Loaded {
+    pub fn from_toml(path: Option<PathBuf>, text: &str) -> Self {
+        match toml::from_str::<Config>(text) {
+            Ok(config) => Self::from_config(path, config),
+            Err(e) => Self {
+                error: Some(format!("invalid {}: {}", FILE_NAME, e)),
+                path,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn from_config(path: Option<PathBuf>, config: Config) -> Self {
+        let json = serde_json::to_value(&config).unwrap_or(Value::Null);
+        Self {
+            path,
+            config,
+            json,
+            error: None,
+        }
+    }
+
+    /// File value for an env var name (static settings plus the per-network / per-chain maps).
+    pub fn file_value(&self, env_key: &str) -> Option<String> {
+        if let Some(s) = SETTINGS.iter().find(|s| s.env.contains(&env_key)) {
+            return json_str(
+                self.json
+                    .pointer(&format!("/{}", s.key.replace('.', "/")))?,
+            );
+        }
+        if let Some(net) = env_key.strip_prefix("SUI_RPC_URL_") {
+            return self.config.sui.rpc_urls.get(&net.to_lowercase()).cloned();
+        }
+        if let Some(net) = env_key.strip_prefix("SOLANA_RPC_URL_") {
+            return self
+                .config
+                .solana
+                .rpc_urls
+                .get(&net.to_lowercase())
+                .cloned();
+        }
+        if let Some(chain_id) = env_key.strip_prefix("EVM_RPC_URL_") {
+            return self.config.evm.rpc_urls.get(chain_id).cloned();
+        }
+        if let Some((symbol, chain_id)) = env_key
+            .strip_prefix("EVM_")
+            .and_then(|k| k.rsplit_once("_ADDRESS_"))
+        {
+            return self
+                .config
+                .evm
+                .token_addresses
+                .get(chain_id)?
+                .get(&symbol.to_lowercase())
+                .cloned();
+        }
+        None
+    }
+}
+
+fn json_str(v: &Value) -> Option<String> {
+    match v {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        // Lists come back in their env var form.
+        Value::Array(items) => Some(
+            items
+                .iter()
+                .filter_map(json_str)
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        _ => None,
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(p) = std::env::var(CONFIG_ENV)
+        .ok()
+        .filter(|p| !p.trim().is_empty())
+    {
+        return Some(PathBuf::from(p.trim()));
+    }
+    let local = PathBuf::from(FILE_NAME);
+    if local.is_file() {
+        return Some(local);
+    }
+    // Not `data_dir::data_dir()`: that can itself come from this file.
+    let data_dir = std::env::var("WEB3MCP_DATA_DIR")
+        .ok()
+        .filter(|p| !p.trim().is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var("HOME")
+                .ok()
+                .map(|h| PathBuf::from(h).join(".web3mcp"))
+        })?;
+    let p = data_dir.join(FILE_NAME);
+    p.is_file().then_some(p)
+}
+
+fn load() -> Loaded {
+    let Some(path) = config_path() else {
+        return Loaded::from_config(None, Config::default());
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(text) => Loaded::from_toml(Some(path), &text),
+        Err(e) => Loaded {
+            error: Some(format!("failed to read {}: {}", path.display(), e)),
+            path: Some(path),
+            ..Default::default()
+        },
+    }
+}
+
+/// The config file, read once per process.
+pub fn loaded() -> &'static Loaded {
+    static LOADED: OnceLock<Loaded> = OnceLock::new();
+    LOADED.get_or_init(load)
+}
+
+/// Drop-in for `std::env::var`: the env var when set, else the matching `web3mcp.toml` value.
+pub fn var(key: &str) -> Result<String, std::env::VarError> {
+    if let Ok(v) = std::env::var(key) {
+        return Ok(v);
+    }
+    loaded()
+        .file_value(key)
+        .ok_or(std::env::VarError::NotPresent)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Env(&'static str),
+    File,
+    Unset,
+}
+
+impl Source {
+    pub fn label(&self) -> String {
+        match self {
+            Source::Env(k) => format!("env:{}", k),
+            Source::File => "file".to_string(),
+            Source::Unset => "unset".to_string(),
+        }
+    }
+}
+
+fn resolve(loaded: &Loaded, s: &Setting) -> (Option<String>, Source) {
+    for key in s.env {
+        if let Ok(v) = std::env::var(key) {
+            return (Some(v), Source::Env(key));
+        }
+    }
+    match loaded.file_value(s.env[0]) {
+        Some(v) => (Some(v), Source::File),
+        None => (None, Source::Unset),
+    }
+}
+
+fn check(kind: Kind, value: &str) -> Result<(), String> {
+    let v = value.trim();
+    let ok = match kind {
+        Kind::Str | Kind::Path | Kind::Secret => true,
+        Kind::Url => is_http_url(v),
+        Kind::UrlList => {
+            let urls = crate::utils::rpc_endpoints::parse_url_list(v);
+            !urls.is_empty() && urls.iter().all(|u| is_http_url(u))
+        }
+        Kind::U64 => v.parse::<u64>().is_ok(),
+        Kind::F64 => v.parse::<f64>().is_ok_and(|f| f.is_finite() && f >= 0.0),
+        Kind::Digits => !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()),
+        Kind::SocketAddr => v.parse::<std::net::SocketAddr>().is_ok(),
+        Kind::OneOf(allowed) => allowed.contains(&v.to_lowercase().as_str()),
+        Kind::GlobList => crate::utils::tool_profiles::parse_globs(v)
+            .iter()
+            .all(|g| crate::utils::tool_profiles::is_valid_glob(g)),
+        Kind::MoveTargetList => crate::utils::tool_profiles::parse_globs(v)
+            .iter()
+            .all(|t| crate::utils::sui_sponsor::is_valid_move_target(t)),
+    };
+    if ok {
+        return Ok(());
+    }
+    Err(match kind {
+        Kind::Url => "expected an http(s) URL".to_string(),
+        Kind::UrlList => "expected http(s) URLs (comma-separated)".to_string(),
+        Kind::U64 => "expected a non-negative integer".to_string(),
+        Kind::F64 => "expected a non-negative number".to_string(),
+        Kind::Digits => "expected a non-negative integer (decimal digits)".to_string(),
+        Kind::SocketAddr => "expected host:port (e.g. 127.0.0.1:8000)".to_string(),
+        Kind::OneOf(allowed) => format!("expected one of: {}", allowed.join("|")),
+        Kind::GlobList => {
+            "expected tool-name globs (letters, digits, _ - * ?; comma-separated)".to_string()
+        }
+        Kind::MoveTargetList => {
+            "expected Move targets (0x2::coin::join, 0xpkg::module::*; comma-separated)".to_string()
+        }
+        Kind::Str | Kind::Path | Kind::Secret => unreachable!(),
+    })
+}
+
+fn is_http_url(v: &str) -> bool {
+    v.starts_with("http://") || v.starts_with("https://")
+}
+
+/// Problems with the file and the effective values (file + env), one line each.
+pub fn validate(loaded: &Loaded) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Some(e) = &loaded.error {
+        problems.push(e.clone());
+    }
+    for s in SETTINGS {
+        let (value, source) = resolve(loaded, s);
+        if let Some(v) = value {
+            if let Err(e) = check(s.kind, &v) {
+                problems.push(format!("{} ({}): {}", s.key, source.label(), e));
+            }
+        }
+    }
+
+    for (net, url) in &loaded.config.sui.rpc_urls {
+        if !SUI_NETWORKS.contains(&net.as_str()) {
+            problems.push(format!(
+                "sui.rpc_urls.{} (file): network must be one of: {}",
+                net,
+                SUI_NETWORKS.join("|")
+            ));
+        }
+        if let Err(e) = check(Kind::UrlList, url) {
+            problems.push(format!("sui.rpc_urls.{} (file): {}", net, e));
+        }
+    }
+    for (net, url) in &loaded.config.solana.rpc_urls {
+        if !SOLANA_NETWORKS.contains(&net.as_str()) {
+            problems.push(format!(
+                "solana.rpc_urls.{} (file): network must be one of: {}",
+                net,
+                SOLANA_NETWORKS.join("|")
+            ));
+        }
+        if let Err(e) = check(Kind::UrlList, url) {
+            problems.push(format!("solana.rpc_urls.{} (file): {}", net, e));
+        }
+    }
+    for (chain_id, url) in &loaded.config.evm.rpc_urls {
+        if chain_id.parse::<u64>().is_err() {
+            problems.push(format!(
+                "evm.rpc_urls.{} (file): key must be a chain id",
+                chain_id
+            ));
+        }
+        if let Err(e) = check(Kind::UrlList, url) {
+            problems.push(format!("evm.rpc_urls.{} (file): {}", chain_id, e));
+        }
+    }
+    for (chain_id, tokens) in &loaded.config.evm.token_addresses {
+        if chain_id.parse::<u64>().is_err() {
+            problems.push(format!(
+                "evm.token_addresses.{} (file): key must be a chain id",
+                chain_id
+            ));
+        }
+        for (symbol, address) in tokens {
+            let a = address.trim();
+            let hex_ok = a.len() == 42
+                && a.starts_with("0x")
+                && a[2..].chars().all(|c| c.is_ascii_hexdigit());
+            if !hex_ok {
+                problems.push(format!(
+                    "evm.token_addresses.{}.{} (file): expected a 0x-prefixed 20-byte address",
+                    chain_id, symbol
+                ));
+            }
+        }
+    }
+    problems.extend(crate::utils::tool_profiles::validate(loaded));
+    for (key, value) in std::env::vars() {
+        if let Some(chain_id) = key.strip_prefix("EVM_RPC_URL_") {
+            if chain_id.parse::<u64>().is_ok() {
+                if let Err(e) = check(Kind::UrlList, &value) {
+                    problems.push(format!("evm.rpc_urls.{} (env:{}): {}", chain_id, key, e));
+                }
+            }
+        } else if let Some(net) = key.strip_prefix("SUI_RPC_URL_") {
+            let net = net.to_lowercase();
+            if SUI_NETWORKS.contains(&net.as_str()) {
+                if let Err(e) = check(Kind::UrlList, &value) {
+                    problems.push(format!("sui.rpc_urls.{} (env:{}): {}", net, key, e));
+                }
+            }
+        }
+    }
+    problems
+}
+
+/// Load and validate at startup. Returns the file that was used, if any.
+pub fn init() -> Result<Option<PathBuf>, String> {
+    let loaded = loaded();
+    let problems = validate(loaded);
+    if !problems.is_empty() {
+        let from = loaded
+            .path
+            .as_ref()
+            .map(|p| format!(" (config file: {})", p.display()))
+            .unwrap_or_default();
+        return Err(format!(
+            "invalid configuration{}:\n  - {}",
+            from,
+            problems.join("\n  - ")
+        ));
+    }
+    Ok(loaded.path.clone())
+}
+
+/// Strip credentials from a URL: userinfo, query string and key-like path segments
+/// (provider URLs such as `.../v2/<api key>`).
+pub fn redact_url(url: &str) -> String {
+    let (scheme, rest) = match url.split_once("://") {
+        Some((s, r)) => (format!("{}://", s), r),
+        None => (String::new(), url),
+    };
+    let (rest, had_query) = match rest.split_once('?') {
+        Some((r, _)) => (r, true),
+        None => (rest, false),
+    };
+    let (authority, path) = match rest.split_once('/') {
+        Some((a, p)) => (a, Some(p)),
+        None => (rest, None),
+    };
+    let authority = match authority.rsplit_once('@') {
+        Some((_, host)) => format!("<redacted>@{}", host),
+        None => authority.to_string(),
+    };
+    let mut out = format!("{}{}", scheme, authority);
+    if let Some(path) = path {
+        let segments: Vec<&str> = path
+            .split('/')
+            .map(|seg| {
+                let key_like = seg.len() >= 20
+                    && seg
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+                if key_like {
+                    "<redacted>"
+                } else {
+                    seg
+                }
+            })
+            .collect();
+        out.push('/');
+        out.push_str(&segments.join("/"));
+    }
+    if had_query {
+        out.push_str("?<redacted>");
+    }
+    out
+}
+
+/// [`redact_url`] for each URL of a comma-separated list.
+pub fn redact_url_list(value: &str) -> String {
+    crate::utils::rpc_endpoints::parse_url_list(value)
+        .iter()
+        .map(|u| redact_url(u))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn shown(kind: Kind, value: &str) -> Value {
+    match kind {
+        Kind::Secret => json!("<redacted>"),
+        Kind::Url => json!(redact_url(value)),
+        Kind::UrlList => json!(redact_url_list(value)),
+        Kind::U64 => value
+            .trim()
+            .parse::<u64>()
+            .map(|n| json!(n))
+            .unwrap_or_else(|_| json!(value)),
+        Kind::F64 => value
+            .trim()
+            .parse::<f64>()
+            .map(|n| json!(n))
+            .unwrap_or_else(|_| json!(value)),
+        Kind::GlobList | Kind::MoveTargetList => {
+            json!(crate::utils::tool_profiles::parse_globs(value))
+        }
+        _ => json!(value),
+    }
+}
+
+fn insert_dotted(root: &mut Map<String, Value>, dotted: &str, value: Value) {
+    let mut parts: Vec<&str> = dotted.split('.').collect();
+    let last = parts.pop().unwrap_or(dotted);
+    let mut cur = root;
+    for p in parts {
+        cur = cur
+            .entry(p.to_string())
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .expect("config sections are objects");
+    }
+    cur.insert(last.to_string(), value);
+}
+
+/// Effective config (file + env overrides) with secrets redacted, plus where each value came from.
+pub fn effective_json(loaded: &Loaded) -> Value {
+    let mut effective = Map::new();
+    let mut sources = Map::new();
+    for s in SETTINGS {
+        let (value, source) = resolve(loaded, s);
+        if let Some(v) = value {
+            insert_dotted(&mut effective, s.key, shown(s.kind, &v));
+            sources.insert(s.key.to_string(), json!(source.label()));
+        }
+    }
+
+    let mut sui_rpc: BTreeMap<String, (String, String)> = loaded
+        .config
+        .sui
+        .rpc_urls
+        .iter()
+        .map(|(k, v)| (k.clone(), (v.clone(), "file".to_string())))
+        .collect();
+    let mut solana_rpc: BTreeMap<String, (String, String)> = loaded
+        .config
+        .solana
+        .rpc_urls
+        .iter()
+        .map(|(k, v)| (k.clone(), (v.clone(), "file".to_string())))
+        .collect();
+    let mut evm_rpc: BTreeMap<String, (String, String)> = loaded
+        .config
+        .evm
+        .rpc_urls
+        .iter()
+        .map(|(k, v)| (k.clone(), (v.clone(), "file".to_string())))
+        .collect();
+    let mut evm_tokens: BTreeMap<(String, String), (String, String)> = BTreeMap::new();
+    for (chain_id, tokens) in &loaded.config.evm.token_addresses {
+        for (symbol, address) in tokens {
+            evm_tokens.insert(
+                (chain_id.clone(), symbol.clone()),
+                (address.clone(), "file".to_string()),
+            );
+        }
+    }
+    for (key, value) in std::env::vars() {
+        let source = format!("env:{}", key);
+        if let Some(net) = key.strip_prefix("SUI_RPC_URL_") {
+            sui_rpc.insert(net.to_lowercase(), (value, source));
+        } else if let Some(net) = key.strip_prefix("SOLANA_RPC_URL_") {
+            solana_rpc.insert(net.to_lowercase(), (value, source));
+        } else if let Some(chain_id) = key.strip_prefix("EVM_RPC_URL_") {
+            evm_rpc.insert(chain_id.to_string(), (value, source));
+        } else if let Some((symbol, chain_id)) = key
+            .strip_prefix("EVM_")
+            .and_then(|k| k.rsplit_once("_ADDRESS_"))
+        {
+            evm_tokens.insert(
+                (chain_id.to_string(), symbol.to_lowercase()),
+                (value, source),
+            );
+        }
+    }
+    for (net, (url, source)) in sui_rpc {
+        let key = format!("sui.rpc_urls.{}", net);
+        insert_dotted(&mut effective, &key, json!(redact_url_list(&url)));
+        sources.insert(key, json!(source));
+    }
+    for (net, (url, source)) in solana_rpc {
+        let key = format!("solana.rpc_urls.{}", net);
+        insert_dotted(&mut effective, &key, json!(redact_url_list(&url)));
+        sources.insert(key, json!(source));
+    }
+    for (chain_id, (url, source)) in evm_rpc {
+        let key = format!("evm.rpc_urls.{}", chain_id);
+        insert_dotted(&mut effective, &key, json!(redact_url_list(&url)));
+        sources.insert(key, json!(source));
+    }
+    for ((chain_id, symbol), (address, source)) in evm_tokens {
+        let key = format!("evm.token_addresses.{}.{}", chain_id, symbol);
+        insert_dotted(&mut effective, &key, json!(address));
+        sources.insert(key, json!(source));
+    }
+    for (name, profile) in &loaded.config.tools.profiles {
+        let key = format!("tools.profiles.{}", name);
+        insert_dotted(&mut effective, &key, json!(profile));
+        sources.insert(key, json!("file"));
+    }
+
+    json!({
+        "config_path": loaded.path.as_ref().map(|p| p.to_string_lossy().to_string()),
+        "config_env": CONFIG_ENV,
+        "effective": Value::Object(effective),
+        "sources": Value::Object(sources),
+        "problems": validate(loaded),
+        "note": "Env vars override web3mcp.toml. Secrets and URL credentials are redacted."
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_file_parses() {
+        let loaded = Loaded::from_toml(None, include_str!("../../web3mcp.example.toml"));
+        assert_eq!(loaded.error, None);
+        assert_eq!(
+            loaded.file_value("SOLANA_RPC_URL_DEVNET").as_deref(),
+            Some("https://api.devnet.solana.com")
+        );
+        assert_eq!(validate(&loaded), Vec::<String>::new());
+    }
+
+    #[test]
+    fn file_values_map_to_env_keys() {
+        let loaded = Loaded::from_toml(
+            None,
+            r#"
+            [sui]
+            aggregator_base_url = "https://api.7k.ag"
+            [evm]
+            default_chain_id = 8453
+            [evm.rpc_urls]
+            8453 = "https://mainnet.base.org"
+            [evm.token_addresses.8453]
+            usdc = "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"
+            "#,
+        );
+        assert_eq!(loaded.error, None);
+        assert_eq!(
+            loaded.file_value("EVM_DEFAULT_CHAIN_ID").as_deref(),
+            Some("8453")
+        );
+        assert_eq!(
+            loaded.file_value("SUI_AGGREGATOR_BASE_URL").as_deref(),
+            Some("https://api.7k.ag")
+        );
+        assert_eq!(
+            loaded.file_value("EVM_RPC_URL_8453").as_deref(),
+            Some("https://mainnet.base.org")
+        );
+        assert_eq!(
+            loaded.file_value("EVM_USDC_ADDRESS_8453").as_deref(),
+            Some("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913")
+        );
+        assert_eq!(loaded.file_value("EVM_RPC_URL_1"), None);
+    }
+
+    #[test]
+    fn unknown_keys_and_bad_values_are_reported() {
+        let loaded = Loaded::from_toml(None, "[sui]\nrpc = \"x\"\n");
+        assert!(loaded
+            .error
+            .as_deref()
+            .unwrap_or("")
+            .contains("unknown field"));
+
+        let loaded = Loaded::from_toml(
+            None,
+            "[sui]\nnetwork = \"moon\"\n[evm.rpc_urls]\nbase = \"ftp://x\"\n",
+        );
+        let problems = validate(&loaded);
+        assert!(problems.iter().any(|p| p.starts_with("sui.network (file)")));
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("evm.rpc_urls.base (file): key must be a chain id")));
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("evm.rpc_urls.base (file): expected http(s) URLs")));
+    }
+
+    #[test]
+    fn rpc_url_lists_are_validated_per_url() {
+        let loaded = Loaded::from_toml(
+            None,
+            r#"
+            [sui.rpc_urls]
+            testnet = "https://fullnode.testnet.sui.io:443, https://sui-testnet.example"
+            moon = "https://x"
+            [evm.rpc_urls]
+            8453 = "https://mainnet.base.org,ftp://backup"
+            "#,
+        );
+        assert_eq!(
+            loaded.file_value("SUI_RPC_URL_TESTNET").as_deref(),
+            Some("https://fullnode.testnet.sui.io:443, https://sui-testnet.example")
+        );
+        let problems = validate(&loaded);
+        assert!(!problems
+            .iter()
+            .any(|p| p.starts_with("sui.rpc_urls.testnet")));
+        assert!(problems
+            .iter()
+            .any(|p| p.starts_with("sui.rpc_urls.moon (file): network must be one of")));
+        assert!(problems
+            .iter()
+            .any(|p| p.starts_with("evm.rpc_urls.8453 (file): expected http(s) URLs")));
+        assert_eq!(
+            redact_url_list("https://a.example/v2/AbCdEfGhIjKlMnOpQrStUvWx,https://b.example"),
+            "https://a.example/v2/<redacted>, https://b.example"
+        );
+    }
+
+    #[test]
+    fn urls_are_redacted() {
+        assert_eq!(
+            redact_url("https://base-mainnet.g.alchemy.com/v2/AbCdEfGhIjKlMnOpQrStUvWx"),
+            "https://base-mainnet.g.alchemy.com/v2/<redacted>"
+        );
+        assert_eq!(
+            redact_url("https://user:pw@rpc.example.com/path?api-key=1"),
+            "https://<redacted>@rpc.example.com/path?<redacted>"
+        );
+        assert_eq!(
+            redact_url("https://fullnode.mainnet.sui.io:443"),
+            "https://fullnode.mainnet.sui.io:443"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Where the server keeps its local state.
+//!
+//! Every store resolves against one data directory: `WEB3MCP_DATA_DIR` (or `storage.data_dir`
+//! in `web3mcp.toml`), else `~/.web3mcp` (`./.web3mcp` when `HOME` is unset). Store-specific
+//! settings (`WEB3MCP_RUNS_DIR`, `WEB3MCP_AUDIT_LOG`, `EVM_ABI_REGISTRY_DIR`,
+//! `SOLANA_IDL_REGISTRY_DIR`, or their `[storage]` keys) still win over it.
+//! State is never created relative to the working directory, so the same server started from a
+//! different directory (e.g. by a desktop client) sees the same state. Besides `./web3mcp.toml`
+//! (see `config`), the working directory is only read once for the pending stores older versions
+//! kept there: `./.data/pending.sqlite` and `./confirm_store/solana_confirm_store.json` are
+//! imported into the pending store.
+
+use std::path::PathBuf;
+
+pub const DATA_DIR_ENV: &str = "WEB3MCP_DATA_DIR";
+
+/// Env var, else its `web3mcp.toml` value (`[storage]`).
+fn env_path(key: &str) -> Option<PathBuf> {
+    crate::utils::config::var(key)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Root data directory (not created here).
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env_path(DATA_DIR_ENV) {
+        return dir;
+    }
+    env_path("HOME")
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".web3mcp")
+}
+
+/// Shared pending-confirmation store (all chains).
+pub fn pending_db_path() -> PathBuf {
+    data_dir().join("pending.sqlite")
+}
+
+/// Where older versions kept the pending store: `<cwd>/.data/pending.sqlite`.
+pub fn legacy_pending_db_path() -> Option<PathBuf> {
+    std::env::current_dir()
+        .ok()
+        .map(|cwd| cwd.join(".data").join("pending.sqlite"))
+}
+
+/// W3RT run artifacts (`WEB3MCP_RUNS_DIR` overrides).
+pub fn runs_dir() -> PathBuf {
+    env_path("WEB3MCP_RUNS_DIR").unwrap_or_else(|| data_dir().join("runs"))
+}
+
+/// JSONL audit log (`WEB3MCP_AUDIT_LOG`, or the pre-rename `SUI_MCP_AUDIT_LOG`, override).
+pub fn audit_log_path() -> PathBuf {
+    env_path("WEB3MCP_AUDIT_LOG")
+        .or_else(|| env_path("SUI_MCP_AUDIT_LOG"))
+        .unwrap_or_else(|| data_dir().join("audit.log"))
+}
+
+/// EVM ABI registry (`EVM_ABI_REGISTRY_DIR` overrides).
+pub fn evm_abi_registry_dir() -> PathBuf {
+    env_path("EVM_ABI_REGISTRY_DIR").unwrap_or_else(|| data_dir().join("abi_registry").join("evm"))
+}
+
+/// Solana IDL registry (`SOLANA_IDL_REGISTRY_DIR` overrides).
+pub fn solana_idl_registry_dir() -> PathBuf {
+    env_path("SOLANA_IDL_REGISTRY_DIR")
+        .unwrap_or_else(|| data_dir().join("abi_registry").join("solana"))
+}
+
+/// Approval policy, spend limit and Solana confirm policy files (`<data_dir>/policies`);
+/// `W3RT_APPROVAL_POLICY` / `W3RT_SPEND_LIMITS` point at a single file instead.
+pub fn policies_dir() -> PathBuf {
+    data_dir().join("policies")
+}
+
+/// Downloaded / derived caches that are safe to delete.
+pub fn cache_dir() -> PathBuf {
+    data_dir().join("cache")
+}
+
+/// Resolved locations, for diagnostics.
+pub fn describe() -> serde_json::Value {
+    serde_json::json!({
+        "data_dir": data_dir().to_string_lossy(),
+        "data_dir_env": DATA_DIR_ENV,
+        "data_dir_from_env": env_path(DATA_DIR_ENV).is_some(),
+        "pending_db": pending_db_path().to_string_lossy(),
+        "runs_dir": runs_dir().to_string_lossy(),
+        "audit_log": audit_log_path().to_string_lossy(),
+        "evm_abi_registry": evm_abi_registry_dir().to_string_lossy(),
+        "solana_idl_registry": solana_idl_registry_dir().to_string_lossy(),
+        "policies_dir": policies_dir().to_string_lossy(),
+        "cache_dir": cache_dir().to_string_lossy(),
+    })
+}
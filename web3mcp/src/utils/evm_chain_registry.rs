@@ -5,6 +5,8 @@ pub struct EvmChainInfo {
     pub chain_id: u64,
     pub name: &'static str,
     pub default_rpc_url: &'static str,
+    /// Tried after `default_rpc_url` when it times out or rate-limits.
+    pub fallback_rpc_urls: &'static [&'static str],
     pub explorer_base: Option<&'static str>,
     pub confirmations: u64,
 }
@@ -17,6 +19,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 1,
             name: "ethereum",
             default_rpc_url: "https://ethereum-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://eth.llamarpc.com"],
             explorer_base: Some("https://etherscan.io"),
             confirmations: 3,
         },
@@ -24,6 +27,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 11155111,
             name: "sepolia",
             default_rpc_url: "https://ethereum-sepolia-rpc.publicnode.com",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://sepolia.etherscan.io"),
             confirmations: 2,
         },
@@ -31,6 +35,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 8453,
             name: "base",
             default_rpc_url: "https://mainnet.base.org",
+            fallback_rpc_urls: &["https://base-rpc.publicnode.com"],
             explorer_base: Some("https://basescan.org"),
             confirmations: 3,
         },
@@ -38,6 +43,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 84532,
             name: "base-sepolia",
             default_rpc_url: "https://sepolia.base.org",
+            fallback_rpc_urls: &["https://base-sepolia-rpc.publicnode.com"],
             explorer_base: Some("https://sepolia.basescan.org"),
             confirmations: 2,
         },
@@ -45,6 +51,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 42161,
             name: "arbitrum-one",
             default_rpc_url: "https://arbitrum-one-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://arb1.arbitrum.io/rpc"],
             explorer_base: Some("https://arbiscan.io"),
             confirmations: 3,
         },
@@ -52,6 +59,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 421614,
             name: "arbitrum-sepolia",
             default_rpc_url: "https://arbitrum-sepolia-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://sepolia-rollup.arbitrum.io/rpc"],
             explorer_base: Some("https://sepolia.arbiscan.io"),
             confirmations: 2,
         },
@@ -59,6 +67,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 56,
             name: "bsc",
             default_rpc_url: "https://bsc-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://bsc-dataseed.bnbchain.org"],
             explorer_base: Some("https://bscscan.com"),
             confirmations: 3,
         },
@@ -66,6 +75,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 97,
             name: "bsc-testnet",
             default_rpc_url: "https://bsc-testnet-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://data-seed-prebsc-1-s1.bnbchain.org:8545"],
             explorer_base: Some("https://testnet.bscscan.com"),
             confirmations: 2,
         },
@@ -74,6 +84,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 59144,
             name: "linea",
             default_rpc_url: "https://rpc.linea.build",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://lineascan.build"),
             confirmations: 3,
         },
@@ -81,6 +92,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 59141,
             name: "linea-sepolia",
             default_rpc_url: "https://rpc.sepolia.linea.build",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://sepolia.lineascan.build"),
             confirmations: 2,
         },
@@ -89,6 +101,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 534352,
             name: "scroll",
             default_rpc_url: "https://rpc.scroll.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://scrollscan.com"),
             confirmations: 3,
         },
@@ -96,6 +109,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 534351,
             name: "scroll-sepolia",
             default_rpc_url: "https://sepolia-rpc.scroll.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://sepolia.scrollscan.com"),
             confirmations: 2,
         },
@@ -104,6 +118,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 324,
             name: "zksync-era",
             default_rpc_url: "https://mainnet.era.zksync.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://explorer.zksync.io"),
             confirmations: 3,
         },
@@ -111,6 +126,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 300,
             name: "zksync-era-sepolia",
             default_rpc_url: "https://sepolia.era.zksync.dev",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://sepolia.explorer.zksync.io"),
             confirmations: 2,
         },
@@ -119,6 +135,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 81457,
             name: "blast",
             default_rpc_url: "https://rpc.blast.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://blastscan.io"),
             confirmations: 3,
         },
@@ -126,6 +143,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 168587773,
             name: "blast-sepolia",
             default_rpc_url: "https://sepolia.blast.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://sepolia.blastscan.io"),
             confirmations: 2,
         },
@@ -133,6 +151,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 10,
             name: "optimism",
             default_rpc_url: "https://optimism-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://mainnet.optimism.io"],
             explorer_base: Some("https://optimistic.etherscan.io"),
             confirmations: 3,
         },
@@ -140,6 +159,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 11155420,
             name: "optimism-sepolia",
             default_rpc_url: "https://optimism-sepolia-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://sepolia.optimism.io"],
             explorer_base: Some("https://sepolia-optimism.etherscan.io"),
             confirmations: 2,
         },
@@ -147,6 +167,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 137,
             name: "polygon-pos",
             default_rpc_url: "https://polygon-bor-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://polygon-rpc.com"],
             explorer_base: Some("https://polygonscan.com"),
             confirmations: 3,
         },
@@ -154,6 +175,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 80002,
             name: "polygon-amoy",
             default_rpc_url: "https://polygon-amoy-bor-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://rpc-amoy.polygon.technology"],
             explorer_base: Some("https://amoy.polygonscan.com"),
             confirmations: 2,
         },
@@ -161,6 +183,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 43114,
             name: "avalanche-c",
             default_rpc_url: "https://avalanche-c-chain-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://api.avax.network/ext/bc/C/rpc"],
             explorer_base: Some("https://snowtrace.io"),
             confirmations: 3,
         },
@@ -168,6 +191,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 43113,
             name: "avalanche-fuji",
             default_rpc_url: "https://avalanche-fuji-c-chain-rpc.publicnode.com",
+            fallback_rpc_urls: &["https://api.avax-test.network/ext/bc/C/rpc"],
             explorer_base: Some("https://testnet.snowtrace.io"),
             confirmations: 2,
         },
@@ -175,6 +199,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 42220,
             name: "celo",
             default_rpc_url: "https://forno.celo.org",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://celoscan.io"),
             confirmations: 3,
         },
@@ -182,6 +207,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 44787,
             name: "celo-alfajores",
             default_rpc_url: "https://alfajores-forno.celo-testnet.org",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://alfajores.celoscan.io"),
             confirmations: 2,
         },
@@ -189,6 +215,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 2222,
             name: "kava",
             default_rpc_url: "https://evm.kava.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://kavascan.com"),
             confirmations: 3,
         },
@@ -196,6 +223,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 2221,
             name: "kava-testnet",
             default_rpc_url: "https://evm.testnet.kava.io",
+            fallback_rpc_urls: &[],
             explorer_base: None,
             confirmations: 2,
         },
@@ -203,6 +231,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 480,
             name: "worldchain",
             default_rpc_url: "https://worldchain-mainnet.g.alchemy.com/public",
+            fallback_rpc_urls: &[],
             explorer_base: None,
             confirmations: 3,
         },
@@ -210,6 +239,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 4801,
             name: "worldchain-sepolia",
             default_rpc_url: "https://worldchain-sepolia.g.alchemy.com/public",
+            fallback_rpc_urls: &[],
             explorer_base: None,
             confirmations: 2,
         },
@@ -217,6 +247,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 143,
             name: "monad",
             default_rpc_url: "https://rpc.monad.xyz",
+            fallback_rpc_urls: &[],
             explorer_base: None,
             confirmations: 2,
         },
@@ -224,6 +255,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 10143,
             name: "monad-testnet",
             default_rpc_url: "https://testnet-rpc.monad.xyz",
+            fallback_rpc_urls: &[],
             explorer_base: None,
             confirmations: 2,
         },
@@ -231,6 +263,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 8217,
             name: "kaia",
             default_rpc_url: "https://public-en.node.kaia.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://scope.klaytn.com"),
             confirmations: 3,
         },
@@ -238,6 +271,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 1001,
             name: "kaia-kairos",
             default_rpc_url: "https://public-en-kairos.node.kaia.io",
+            fallback_rpc_urls: &[],
             explorer_base: Some("https://baobab.scope.klaytn.com"),
             confirmations: 2,
         },
@@ -245,6 +279,7 @@ pub fn evm_default_chains() -> Vec<EvmChainInfo> {
             chain_id: 998,
             name: "hyperevm-testnet",
             default_rpc_url: "https://api.hyperliquid-testnet.xyz/evm",
+            fallback_rpc_urls: &[],
             explorer_base: None,
             confirmations: 2,
         },
@@ -287,14 +322,18 @@ pub fn evm_chain_list_json() -> serde_json::Value {
         .into_iter()
         .map(|c| {
             let env_key = format!("EVM_RPC_URL_{}", c.chain_id);
-            let env_override = std::env::var(&env_key).ok();
+            let env_override = crate::utils::config::var(&env_key).ok();
             json!({
                 "chain_id": c.chain_id,
                 "name": c.name,
                 "default_rpc_url": c.default_rpc_url,
                 "env_key": env_key,
                 "env_override": env_override,
-                "rpc_url_effective": env_override.clone().unwrap_or_else(|| c.default_rpc_url.to_string()),
+                "fallback_rpc_urls": c.fallback_rpc_urls,
+                "rpc_url_effective": env_override
+                    .as_deref()
+                    .and_then(|v| crate::utils::rpc_endpoints::parse_url_list(v).into_iter().next())
+                    .unwrap_or_else(|| c.default_rpc_url.to_string()),
                 "explorer_base": c.explorer_base,
                 "confirmations": c.confirmations
             })
@@ -1,6 +1,9 @@
 use crate::types::EvmTxRequest;
+use crate::utils::pending_store::{
+    self, ListFilter, NewPending, PendingChain, PendingRecord, PendingStatus,
+};
 use rmcp::model::{ErrorCode, ErrorData};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::borrow::Cow;
 
 #[derive(Clone, Debug)]
@@ -34,167 +37,80 @@ pub fn now_ms() -> u128 {
         .as_millis()
 }
 
+/// `evm_pending_<now_ms>_<seq>_<label>`. The per-process sequence keeps ids minted in the same
+/// millisecond apart; `insert_pending` would otherwise replace the earlier row.
+pub fn new_pending_id(label: &str) -> String {
+    static SEQ: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+    let seq = SEQ.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    format!("evm_pending_{}_{}_{}", now_ms(), seq, label)
+}
+
 pub fn default_ttl_ms() -> u128 {
-    10 * 60 * 1000
+    PendingChain::Evm.default_ttl_ms() as u128
 }
 
-pub fn pending_db_path_from_cwd() -> Result<std::path::PathBuf, ErrorData> {
-    let cwd = std::env::current_dir().map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to get current_dir: {}", e)),
-        data: None,
-    })?;
-    Ok(cwd.join(".data").join("pending.sqlite"))
+pub fn pending_db_path() -> Result<std::path::PathBuf, ErrorData> {
+    pending_store::db_path()
 }
 
+/// Open the shared pending store (rows of this module have `chain = 'evm'`).
 pub fn connect() -> Result<rusqlite::Connection, ErrorData> {
-    let path = pending_db_path_from_cwd()?;
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to create data dir: {}", e)),
-            data: None,
-        })?;
-    }
+    pending_store::connect()
+}
 
-    let conn = rusqlite::Connection::open(path).map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to open sqlite db: {}", e)),
-        data: None,
-    })?;
+/// Expire stale rows and delete old ones (see `pending_store::sweep`).
+pub fn cleanup_expired(conn: &rusqlite::Connection, now_ms: u128) -> Result<(), ErrorData> {
+    pending_store::sweep(conn, now_ms as u64)?;
+    Ok(())
+}
 
-    // Base table (older versions).
-    conn.execute_batch(
-        "BEGIN;
-         CREATE TABLE IF NOT EXISTS evm_pending_confirmations (
-           id TEXT PRIMARY KEY,
-           chain_id INTEGER NOT NULL,
-           tx_json TEXT NOT NULL,
-           created_at_ms INTEGER NOT NULL,
-           expires_at_ms INTEGER NOT NULL,
-           tx_summary_hash TEXT NOT NULL
-         );
-         CREATE INDEX IF NOT EXISTS idx_evm_pending_expires ON evm_pending_confirmations(expires_at_ms);
-         COMMIT;",
+fn row_from_record(r: PendingRecord) -> Result<PendingRow, ErrorData> {
+    let tx: EvmTxRequest = serde_json::from_value(
+        r.payload.get("tx").cloned().unwrap_or(Value::Null),
     )
     .map_err(|e| ErrorData {
         code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to init sqlite schema: {}", e)),
+        message: Cow::from(format!("Failed to parse stored EVM tx: {}", e)),
         data: None,
     })?;
-
-    // Migrations: add new columns if missing.
-    // Use PRAGMA table_info to avoid depending on sqlite error strings.
-    let mut existing_cols = std::collections::HashSet::<String>::new();
-    {
-        let mut stmt = conn
-            .prepare("PRAGMA table_info(evm_pending_confirmations)")
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to prepare PRAGMA table_info: {}", e)),
-                data: None,
-            })?;
-        let rows = stmt
-            .query_map([], |row| row.get::<_, String>(1))
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to query PRAGMA table_info: {}", e)),
-                data: None,
-            })?;
-        for name in rows.flatten() {
-            existing_cols.insert(name);
-        }
-    }
-
-    let migrations: [(&str, &str); 13] = [
-        (
-            "updated_at_ms",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN updated_at_ms INTEGER",
-        ),
-        (
-            "status",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN status TEXT",
-        ),
-        (
-            "tx_hash",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN tx_hash TEXT",
-        ),
-        (
-            "last_error",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN last_error TEXT",
-        ),
-        (
-            "raw_tx_prefix",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN raw_tx_prefix TEXT",
-        ),
-        (
-            "signed_at_ms",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN signed_at_ms INTEGER",
-        ),
-        (
-            "second_confirm_token",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN second_confirm_token TEXT",
-        ),
-        (
-            "second_confirmed",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN second_confirmed INTEGER",
-        ),
-        (
-            "expected_spender",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN expected_spender TEXT",
-        ),
-        (
-            "required_allowance_raw",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN required_allowance_raw TEXT",
-        ),
-        (
-            "expected_token",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN expected_token TEXT",
-        ),
-        (
-            "approve_confirmation_id",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN approve_confirmation_id TEXT",
-        ),
-        (
-            "swap_confirmation_id",
-            "ALTER TABLE evm_pending_confirmations ADD COLUMN swap_confirmation_id TEXT",
-        ),
-    ];
-
-    for (col, stmt) in migrations {
-        if !existing_cols.contains(col) {
-            conn.execute(stmt, []).map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("SQLite migration failed: {} ({})", stmt, e)),
-                data: None,
-            })?;
-        }
-    }
-
-    // Backfill defaults if needed.
-    let _ = conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET updated_at_ms = COALESCE(updated_at_ms, created_at_ms),
-             status = COALESCE(status, 'pending'),
-             second_confirmed = COALESCE(second_confirmed, 0)
-         WHERE updated_at_ms IS NULL OR status IS NULL OR second_confirmed IS NULL",
-        [],
-    );
-
-    Ok(conn)
+    let opt = |key: &str| r.payload_str(key).map(|s| s.to_string());
+    Ok(PendingRow {
+        chain_id: tx.chain_id,
+        raw_tx_prefix: opt("raw_tx_prefix"),
+        signed_at_ms: r
+            .payload
+            .get("signed_at_ms")
+            .and_then(Value::as_u64)
+            .map(|v| v as u128),
+        second_confirm_token: opt("second_confirm_token"),
+        second_confirmed: r
+            .payload
+            .get("second_confirmed")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+        expected_spender: opt("expected_spender"),
+        required_allowance_raw: opt("required_allowance_raw"),
+        expected_token: opt("expected_token"),
+        approve_confirmation_id: opt("approve_confirmation_id"),
+        swap_confirmation_id: opt("swap_confirmation_id"),
+        id: r.id,
+        tx,
+        created_at_ms: r.created_at_ms as u128,
+        updated_at_ms: r.updated_at_ms as u128,
+        expires_at_ms: r.expires_at_ms as u128,
+        tx_summary_hash: r.tx_summary_hash,
+        status: r.status.as_str().to_string(),
+        tx_hash: r.tx_ref,
+        last_error: r.last_error,
+    })
 }
 
-pub fn cleanup_expired(conn: &rusqlite::Connection, now_ms: u128) -> Result<(), ErrorData> {
-    conn.execute(
-        "DELETE FROM evm_pending_confirmations WHERE expires_at_ms < ?1",
-        [now_ms as i64],
-    )
-    .map_err(|e| ErrorData {
+fn tx_value(tx: &EvmTxRequest) -> Result<Value, ErrorData> {
+    serde_json::to_value(tx).map_err(|e| ErrorData {
         code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to cleanup expired confirmations: {}", e)),
+        message: Cow::from(format!("Failed to serialize tx: {}", e)),
         data: None,
-    })?;
-    Ok(())
+    })
 }
 
 pub fn insert_pending(
@@ -207,157 +123,52 @@ pub fn insert_pending(
     let conn = connect()?;
     cleanup_expired(&conn, now_ms())?;
 
-    let tx_json = serde_json::to_string(tx).map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to serialize tx: {}", e)),
-        data: None,
-    })?;
-
-    conn.execute(
-        "INSERT OR REPLACE INTO evm_pending_confirmations
-         (id, chain_id, tx_json, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash, status, tx_hash, last_error)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 'pending', NULL, NULL)",
-        rusqlite::params![
+    let payload = json!({ "tx": tx_value(tx)?, "second_confirmed": false });
+    pending_store::insert(
+        &conn,
+        &NewPending {
             id,
-            tx.chain_id as i64,
-            tx_json,
-            created_at_ms as i64,
-            created_at_ms as i64,
-            expires_at_ms as i64,
-            tx_summary_hash
-        ],
+            chain: PendingChain::Evm,
+            network: Some(&tx.chain_id.to_string()),
+            created_at_ms: created_at_ms as u64,
+            expires_at_ms: expires_at_ms as u64,
+            tx_summary_hash,
+            source_tool: None,
+            summary: None,
+            payload: &payload,
+        },
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to insert pending confirmation: {}", e)),
-        data: None,
-    })?;
-
-    Ok(())
 }
 
 pub fn get_row(conn: &rusqlite::Connection, id: &str) -> Result<Option<PendingRow>, ErrorData> {
     cleanup_expired(conn, now_ms())?;
 
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, chain_id, tx_json, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash, status, tx_hash, last_error,
-                    raw_tx_prefix, signed_at_ms, second_confirm_token, second_confirmed,
-                    expected_spender, required_allowance_raw, expected_token, approve_confirmation_id, swap_confirmation_id
-             FROM evm_pending_confirmations
-             WHERE id = ?1",
-        )
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to prepare select: {}", e)),
-            data: None,
-        })?;
-
-    let row = match stmt.query_row([id], |row| {
-        let id: String = row.get(0)?;
-        let chain_id: i64 = row.get(1)?;
-        let tx_json: String = row.get(2)?;
-        let created_at_ms: i64 = row.get(3)?;
-        let updated_at_ms: i64 = row.get(4)?;
-        let expires_at_ms: i64 = row.get(5)?;
-        let tx_summary_hash: String = row.get(6)?;
-        let status: String = row.get(7)?;
-        let tx_hash: Option<String> = row.get(8)?;
-        let last_error: Option<String> = row.get(9)?;
-        let raw_tx_prefix: Option<String> = row.get(10)?;
-        let signed_at_ms: Option<i64> = row.get(11)?;
-        let second_confirm_token: Option<String> = row.get(12)?;
-        let second_confirmed: Option<i64> = row.get(13)?;
-        let expected_spender: Option<String> = row.get(14)?;
-        let required_allowance_raw: Option<String> = row.get(15)?;
-        let expected_token: Option<String> = row.get(16)?;
-        let approve_confirmation_id: Option<String> = row.get(17)?;
-        let swap_confirmation_id: Option<String> = row.get(18)?;
-        Ok((
-            id,
-            chain_id,
-            tx_json,
-            created_at_ms,
-            updated_at_ms,
-            expires_at_ms,
-            tx_summary_hash,
-            status,
-            tx_hash,
-            last_error,
-            raw_tx_prefix,
-            signed_at_ms,
-            second_confirm_token,
-            second_confirmed.unwrap_or(0),
-            expected_spender,
-            required_allowance_raw,
-            expected_token,
-            approve_confirmation_id,
-            swap_confirmation_id,
-        ))
-    }) {
-        Ok(v) => Some(v),
-        Err(rusqlite::Error::QueryReturnedNoRows) => None,
-        Err(e) => {
-            return Err(ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to query row: {}", e)),
-                data: None,
-            })
-        }
-    };
-
-    let Some((
-        id,
-        chain_id,
-        tx_json,
-        created_at_ms,
-        updated_at_ms,
-        expires_at_ms,
-        tx_summary_hash,
-        status,
-        tx_hash,
-        last_error,
-        raw_tx_prefix,
-        signed_at_ms,
-        second_confirm_token,
-        second_confirmed,
-        expected_spender,
-        required_allowance_raw,
-        expected_token,
-        approve_confirmation_id,
-        swap_confirmation_id,
-    )) = row
-    else {
-        return Ok(None);
-    };
-
-    let tx: EvmTxRequest = serde_json::from_str(&tx_json).map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to parse tx_json: {}", e)),
-        data: None,
-    })?;
+    match pending_store::get(conn, id)? {
+        Some(r) if r.chain == PendingChain::Evm => row_from_record(r).map(Some),
+        _ => Ok(None),
+    }
+}
 
-    Ok(Some(PendingRow {
-        id,
-        chain_id: chain_id as u64,
-        tx,
-        created_at_ms: created_at_ms as u128,
-        updated_at_ms: updated_at_ms as u128,
-        expires_at_ms: expires_at_ms as u128,
-        tx_summary_hash,
-        status,
-        tx_hash,
-        last_error,
-        raw_tx_prefix,
-        signed_at_ms: signed_at_ms.map(|v| v.max(0) as u128),
-        second_confirm_token,
-        second_confirmed: second_confirmed == 1,
-        expected_spender,
-        required_allowance_raw,
-        expected_token,
-        approve_confirmation_id,
-        swap_confirmation_id,
-    }))
+/// EVM rows, newest first (optionally filtered by chain id / status).
+pub fn list_rows(
+    conn: &rusqlite::Connection,
+    chain_id: Option<u64>,
+    status: Option<PendingStatus>,
+    limit: usize,
+) -> Result<Vec<PendingRow>, ErrorData> {
+    let records = pending_store::list(
+        conn,
+        &ListFilter {
+            chain: Some(PendingChain::Evm),
+            network: chain_id.map(|c| c.to_string()),
+            status,
+            limit,
+        },
+    )?;
+    Ok(records
+        .into_iter()
+        .filter_map(|r| row_from_record(r).ok())
+        .collect())
 }
 
 pub fn ensure_second_confirmation(
@@ -373,74 +184,36 @@ pub fn ensure_second_confirmation(
     }
 
     // Get current state.
-    let mut stmt = conn
-        .prepare(
-            "SELECT second_confirm_token, second_confirmed FROM evm_pending_confirmations WHERE id = ?1",
-        )
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to prepare select: {}", e)),
-            data: None,
-        })?;
-
-    let row = match stmt.query_row([id], |row| {
-        let token: Option<String> = row.get(0)?;
-        let confirmed: Option<i64> = row.get(1)?;
-        Ok((token, confirmed.unwrap_or(0)))
-    }) {
-        Ok(v) => Some(v),
-        Err(rusqlite::Error::QueryReturnedNoRows) => None,
-        Err(e) => {
-            return Err(ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to query row: {}", e)),
-                data: None,
-            })
-        }
-    };
-
-    let Some((token, confirmed)) = row else {
+    let Some(row) = pending_store::get(conn, id)? else {
         return Ok(None);
     };
-
-    if confirmed == 1 {
+    if row.payload.get("second_confirmed").and_then(Value::as_bool) == Some(true) {
         return Ok(None);
     }
 
     // Ensure token exists.
-    let token = token.unwrap_or_else(|| make_confirm_token(id, tx_summary_hash));
+    let token = row
+        .payload_str("second_confirm_token")
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| make_confirm_token(id, tx_summary_hash));
 
     // If user provided token, validate.
     let provided = extract_confirm_token(user_text);
-    if let Some(p) = provided {
-        if p == token {
-            conn.execute(
-                "UPDATE evm_pending_confirmations
-                 SET second_confirm_token=?2, second_confirmed=1, updated_at_ms=?3
-                 WHERE id=?1",
-                rusqlite::params![id, token, now_ms() as i64],
-            )
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to set second_confirmed: {}", e)),
-                data: None,
-            })?;
-            return Ok(None);
-        }
+    if provided.as_deref() == Some(token.as_str()) {
+        pending_store::patch_payload(
+            conn,
+            id,
+            &json!({ "second_confirm_token": token, "second_confirmed": true }),
+        )?;
+        return Ok(None);
     }
 
     // Persist token (if not already).
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET second_confirm_token=?2, second_confirmed=0, updated_at_ms=?3
-         WHERE id=?1",
-        rusqlite::params![id, token, now_ms() as i64],
-    )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to store second_confirm_token: {}", e)),
-        data: None,
-    })?;
+    pending_store::patch_payload(
+        conn,
+        id,
+        &json!({ "second_confirm_token": token, "second_confirmed": false }),
+    )?;
 
     // Return (token, message) to prompt user.
     let msg = format!(
@@ -450,6 +223,7 @@ pub fn ensure_second_confirmation(
     Ok(Some((token, msg)))
 }
 
+/// Replace the tx after a confirm-time re-quote; the row goes back to `pending`.
 pub fn update_pending(
     conn: &rusqlite::Connection,
     id: &str,
@@ -458,117 +232,44 @@ pub fn update_pending(
     tx_summary_hash: &str,
 ) -> Result<(), ErrorData> {
     cleanup_expired(conn, now_ms())?;
-
-    let tx_json = serde_json::to_string(tx).map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to serialize tx: {}", e)),
-        data: None,
-    })?;
-
-    let updated_at_ms = now_ms() as i64;
-
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET tx_json = ?2,
-             updated_at_ms = ?3,
-             expires_at_ms = ?4,
-             tx_summary_hash = ?5,
-             status = 'pending',
-             tx_hash = NULL,
-             last_error = NULL
-         WHERE id = ?1",
-        rusqlite::params![
-            id,
-            tx_json,
-            updated_at_ms,
-            expires_at_ms as i64,
-            tx_summary_hash
-        ],
+    pending_store::requote(
+        conn,
+        id,
+        &json!({ "tx": tx_value(tx)? }),
+        expires_at_ms as u64,
+        tx_summary_hash,
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to update pending confirmation: {}", e)),
-        data: None,
-    })?;
-
-    Ok(())
 }
 
+/// Claim the row for signing (`signed`); fails if another confirm already claimed or sent it.
 pub fn mark_consumed(conn: &rusqlite::Connection, id: &str) -> Result<(), ErrorData> {
-    let updated_at_ms = now_ms() as i64;
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET status='consumed', updated_at_ms=?2
-         WHERE id=?1",
-        rusqlite::params![id, updated_at_ms],
-    )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to mark consumed: {}", e)),
-        data: None,
-    })?;
+    pending_store::transition(conn, id, PendingStatus::Signed, None, None)?;
     Ok(())
 }
 
 pub fn mark_sent(conn: &rusqlite::Connection, id: &str, tx_hash: &str) -> Result<(), ErrorData> {
-    let updated_at_ms = now_ms() as i64;
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET status='sent', tx_hash=?2, updated_at_ms=?3
-         WHERE id=?1",
-        rusqlite::params![id, tx_hash, updated_at_ms],
-    )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to mark sent: {}", e)),
-        data: None,
-    })?;
+    pending_store::transition(conn, id, PendingStatus::Sent, Some(tx_hash), None)?;
     Ok(())
 }
 
+/// Record the signed tx prefix for observability (the full raw tx is not stored).
 pub fn mark_signed(conn: &rusqlite::Connection, id: &str, raw_tx: &str) -> Result<(), ErrorData> {
-    let updated_at_ms = now_ms() as i64;
-    let signed_at_ms = updated_at_ms;
     let prefix = raw_tx.chars().take(18).collect::<String>();
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET raw_tx_prefix=?2, signed_at_ms=?3, updated_at_ms=?4
-         WHERE id=?1",
-        rusqlite::params![id, prefix, signed_at_ms, updated_at_ms],
+    pending_store::patch_payload(
+        conn,
+        id,
+        &json!({ "raw_tx_prefix": prefix, "signed_at_ms": pending_store::now_ms() }),
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to mark signed: {}", e)),
-        data: None,
-    })?;
-    Ok(())
 }
 
 pub fn mark_failed(conn: &rusqlite::Connection, id: &str, err: &str) -> Result<(), ErrorData> {
-    let updated_at_ms = now_ms() as i64;
-    conn.execute(
-        "UPDATE evm_pending_confirmations SET status='failed', last_error=?2, updated_at_ms=?3 WHERE id=?1",
-        rusqlite::params![id, err, updated_at_ms],
-    )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to mark failed: {}", e)),
-        data: None,
-    })?;
+    pending_store::transition(conn, id, PendingStatus::Failed, None, Some(err))?;
     Ok(())
 }
 
+/// Close a row without broadcasting (e.g. an approve made unnecessary by an existing allowance).
 pub fn mark_skipped(conn: &rusqlite::Connection, id: &str, reason: &str) -> Result<(), ErrorData> {
-    let updated_at_ms = now_ms() as i64;
-    conn.execute(
-        "UPDATE evm_pending_confirmations SET status='skipped', last_error=?2, updated_at_ms=?3 WHERE id=?1",
-        rusqlite::params![id, reason, updated_at_ms],
-    )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to mark skipped: {}", e)),
-        data: None,
-    })?;
+    pending_store::transition(conn, id, PendingStatus::Expired, None, Some(reason))?;
     Ok(())
 }
 
@@ -579,24 +280,15 @@ pub fn set_expected_allowance(
     required_allowance_raw: &str,
 ) -> Result<(), ErrorData> {
     let conn = connect()?;
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET expected_token=?2, expected_spender=?3, required_allowance_raw=?4, updated_at_ms=?5
-         WHERE id=?1",
-        rusqlite::params![
-            id,
-            expected_token,
-            expected_spender,
-            required_allowance_raw,
-            now_ms() as i64
-        ],
+    pending_store::patch_payload(
+        &conn,
+        id,
+        &json!({
+            "expected_token": expected_token,
+            "expected_spender": expected_spender,
+            "required_allowance_raw": required_allowance_raw,
+        }),
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to set expected allowance metadata: {}", e)),
-        data: None,
-    })?;
-    Ok(())
 }
 
 pub fn set_approve_link(
@@ -604,22 +296,11 @@ pub fn set_approve_link(
     approve_confirmation_id: &str,
 ) -> Result<(), ErrorData> {
     let conn = connect()?;
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET approve_confirmation_id=?2, updated_at_ms=?3
-         WHERE id=?1",
-        rusqlite::params![
-            swap_confirmation_id,
-            approve_confirmation_id,
-            now_ms() as i64
-        ],
+    pending_store::patch_payload(
+        &conn,
+        swap_confirmation_id,
+        &json!({ "approve_confirmation_id": approve_confirmation_id }),
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to link approve confirmation: {}", e)),
-        data: None,
-    })?;
-    Ok(())
 }
 
 pub fn set_swap_link(
@@ -627,33 +308,16 @@ pub fn set_swap_link(
     swap_confirmation_id: &str,
 ) -> Result<(), ErrorData> {
     let conn = connect()?;
-    conn.execute(
-        "UPDATE evm_pending_confirmations
-         SET swap_confirmation_id=?2, updated_at_ms=?3
-         WHERE id=?1",
-        rusqlite::params![
-            approve_confirmation_id,
-            swap_confirmation_id,
-            now_ms() as i64
-        ],
+    pending_store::patch_payload(
+        &conn,
+        approve_confirmation_id,
+        &json!({ "swap_confirmation_id": swap_confirmation_id }),
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to link swap confirmation: {}", e)),
-        data: None,
-    })?;
-    Ok(())
 }
 
 #[allow(dead_code)]
 pub fn delete_row(conn: &rusqlite::Connection, id: &str) -> Result<(), ErrorData> {
-    conn.execute("DELETE FROM evm_pending_confirmations WHERE id = ?1", [id])
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to delete row: {}", e)),
-            data: None,
-        })?;
-    Ok(())
+    pending_store::delete(conn, id)
 }
 
 pub fn tx_summary_hash(tx: &EvmTxRequest) -> String {
@@ -701,7 +365,7 @@ pub fn extract_confirmation_id(text: &str) -> Option<String> {
 pub fn large_value_threshold_wei() -> ethers::types::U256 {
     // default: 0.01 ETH
     let default = ethers::types::U256::from(10_000_000_000_000_000u64);
-    let Ok(v) = std::env::var("EVM_CONFIRM_LARGE_VALUE_THRESHOLD_WEI") else {
+    let Ok(v) = crate::utils::config::var("EVM_CONFIRM_LARGE_VALUE_THRESHOLD_WEI") else {
         return default;
     };
     if let Ok(u) = ethers::types::U256::from_dec_str(v.trim()) {
@@ -0,0 +1,187 @@
+//! EVM JSON-RPC transport over several endpoints.
+//!
+//! [`FailoverHttp`] is an ethers `JsonRpcClient` holding one `Http` transport per configured
+//! URL of a chain and routing each request through [`rpc_endpoints::call`]: reads fail over,
+//! `eth_sendRawTransaction` / `eth_sendTransaction` are sent once to the healthiest endpoint.
+
+use crate::utils::rpc_endpoints::{self, EndpointSet, RetryPolicy};
+use async_trait::async_trait;
+use ethers::providers::{
+    Http, HttpClientError, JsonRpcClient, JsonRpcError, ProviderError, RpcError,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+
+/// What every EVM tool talks to.
+pub type EvmProvider = ethers::providers::Provider<FailoverHttp>;
+
+const NON_IDEMPOTENT_METHODS: &[&str] = &["eth_sendRawTransaction", "eth_sendTransaction"];
+
+#[derive(Debug)]
+pub enum FailoverHttpError {
+    Http(HttpClientError),
+    Timeout {
+        rpc_url: String,
+        after_ms: u128,
+    },
+    /// Request params could not be serialized.
+    Params(serde_json::Error),
+}
+
+impl std::fmt::Display for FailoverHttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http(e) => write!(f, "{}", e),
+            Self::Timeout { rpc_url, after_ms } => write!(
+                f,
+                "request to {} timed out after {}ms",
+                crate::utils::config::redact_url(rpc_url),
+                after_ms
+            ),
+            Self::Params(e) => write!(f, "invalid request params: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FailoverHttpError {}
+
+impl RpcError for FailoverHttpError {
+    fn as_error_response(&self) -> Option<&JsonRpcError> {
+        match self {
+            Self::Http(e) => e.as_error_response(),
+            _ => None,
+        }
+    }
+
+    fn as_serde_error(&self) -> Option<&serde_json::Error> {
+        match self {
+            Self::Http(e) => e.as_serde_error(),
+            Self::Params(e) => Some(e),
+            Self::Timeout { .. } => None,
+        }
+    }
+}
+
+impl From<FailoverHttpError> for ProviderError {
+    fn from(e: FailoverHttpError) -> Self {
+        match e {
+            FailoverHttpError::Http(e) => e.into(),
+            other => ProviderError::JsonRpcClientError(Box::new(other)),
+        }
+    }
+}
+
+/// Rate limits and unreachable / overloaded endpoints, as opposed to node answers such as
+/// `execution reverted`.
+fn is_transient(e: &FailoverHttpError) -> bool {
+    match e {
+        FailoverHttpError::Http(HttpClientError::ReqwestError(_)) => true,
+        FailoverHttpError::Http(HttpClientError::JsonRpcError(e)) => {
+            // 429 / -32005 "limit exceeded" are used by most providers for throttling.
+            e.code == 429 || e.code == -32005 || rpc_endpoints::is_transient_error(&e.message)
+        }
+        // A non-JSON body is a gateway / rate-limit page rather than a node response.
+        FailoverHttpError::Http(HttpClientError::SerdeJson { text, .. }) => {
+            serde_json::from_str::<serde_json::Value>(text).is_err()
+                || rpc_endpoints::is_transient_error(text)
+        }
+        FailoverHttpError::Timeout { .. } => true,
+        FailoverHttpError::Params(_) => false,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FailoverHttp {
+    set: Arc<EndpointSet>,
+    transports: Vec<Http>,
+    policy: RetryPolicy,
+}
+
+impl FailoverHttp {
+    pub fn new(set: Arc<EndpointSet>, policy: RetryPolicy) -> Result<Self, String> {
+        if set.urls().is_empty() {
+            return Err(format!("no RPC URL configured for {}", set.key()));
+        }
+        let transports = set
+            .urls()
+            .iter()
+            .map(|url| {
+                url.parse::<Http>()
+                    .map_err(|e| format!("invalid RPC URL {}: {}", url, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            set,
+            transports,
+            policy,
+        })
+    }
+
+    pub fn endpoints(&self) -> &EndpointSet {
+        &self.set
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for FailoverHttp {
+    type Error = FailoverHttpError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: std::fmt::Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        // Serialized once so every attempt sends identical params.
+        let params = serde_json::to_value(&params).map_err(FailoverHttpError::Params)?;
+        let idempotent = !NON_IDEMPOTENT_METHODS.contains(&method);
+        let timeout = self.policy.timeout;
+        rpc_endpoints::call(
+            &self.set,
+            &self.policy,
+            idempotent,
+            is_transient,
+            |rpc_url| FailoverHttpError::Timeout {
+                rpc_url: rpc_url.to_string(),
+                after_ms: timeout.as_millis(),
+            },
+            |idx| {
+                let params = params.clone();
+                async move {
+                    self.transports[idx]
+                        .request(method, params)
+                        .await
+                        .map_err(FailoverHttpError::Http)
+                }
+            },
+        )
+        .await
+    }
+}
+
+/// `eth_blockNumber` against every URL of `set` (one transport each, no failover), recorded in
+/// its health. Returns the set's snapshot.
+pub async fn probe_endpoints(set: &EndpointSet, timeout: std::time::Duration) -> serde_json::Value {
+    for (idx, url) in set.urls().iter().enumerate() {
+        let transport = match url.parse::<Http>() {
+            Ok(t) => t,
+            Err(e) => {
+                set.record_failure(idx, &format!("invalid RPC URL: {}", e));
+                continue;
+            }
+        };
+        let started = std::time::Instant::now();
+        let result = tokio::time::timeout(
+            timeout,
+            transport.request::<_, ethers::types::U64>("eth_blockNumber", ()),
+        )
+        .await;
+        match result {
+            Ok(Ok(_)) => set.record_success(idx, started.elapsed()),
+            Ok(Err(e)) => set.record_failure(idx, &e.to_string()),
+            Err(_) => {
+                set.record_failure(idx, &format!("timed out after {}ms", timeout.as_millis()))
+            }
+        }
+    }
+    set.snapshot()
+}
@@ -0,0 +1,120 @@
+//! Authentication and CORS rules of the network transports (`--http`).
+//!
+//! Clients present one of the configured tokens (`WEB3MCP_AUTH_TOKENS` / `[server]
+//! auth_tokens`) as `Authorization: Bearer <token>` or `X-API-Key: <token>`. Only SHA-256
+//! digests of the tokens are kept in memory. Without tokens the server only binds to loopback
+//! addresses.
+
+use sha2::{Digest, Sha256};
+use std::net::SocketAddr;
+
+pub const TOKENS_ENV: &str = "WEB3MCP_AUTH_TOKENS";
+pub const CORS_ORIGINS_ENV: &str = "WEB3MCP_CORS_ORIGINS";
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Comma-separated list, blanks dropped.
+pub fn parse_list(raw: &str) -> Vec<String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HttpAuth {
+    digests: Vec<[u8; 32]>,
+}
+
+fn digest(token: &str) -> [u8; 32] {
+    Sha256::digest(token.as_bytes()).into()
+}
+
+impl HttpAuth {
+    pub fn new(tokens: &[String]) -> Self {
+        Self {
+            digests: tokens.iter().map(|t| digest(t)).collect(),
+        }
+    }
+
+    pub fn from_config() -> Self {
+        let tokens = crate::utils::config::var(TOKENS_ENV)
+            .map(|v| parse_list(&v))
+            .unwrap_or_default();
+        Self::new(&tokens)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.digests.is_empty()
+    }
+
+    /// Whether a request with these `Authorization` / `X-API-Key` header values may pass.
+    pub fn authorize(&self, authorization: Option<&str>, api_key: Option<&str>) -> bool {
+        if !self.is_enabled() {
+            return true;
+        }
+        let bearer = authorization.and_then(|h| {
+            let (scheme, token) = h.trim().split_once(' ')?;
+            scheme.eq_ignore_ascii_case("bearer").then(|| token.trim())
+        });
+        [bearer, api_key.map(str::trim)]
+            .into_iter()
+            .flatten()
+            .filter(|t| !t.is_empty())
+            // Comparing digests keeps the comparison time independent of the token bytes.
+            .any(|t| self.digests.contains(&digest(t)))
+    }
+
+    /// Refuse to expose an unauthenticated server beyond this host.
+    pub fn check_bind(&self, bind: &SocketAddr) -> Result<(), String> {
+        if self.is_enabled() || bind.ip().is_loopback() {
+            return Ok(());
+        }
+        Err(format!(
+            "refusing to serve on {} without authentication: set {} (or [server] auth_tokens), \
+             or bind to 127.0.0.1",
+            bind, TOKENS_ENV
+        ))
+    }
+}
+
+/// Browser origins allowed by CORS (`scheme://host[:port]`, no trailing slash). Empty: no CORS
+/// headers, so browsers cannot call the server cross-origin.
+pub fn cors_origins() -> Vec<String> {
+    crate::utils::config::var(CORS_ORIGINS_ENV)
+        .map(|v| {
+            parse_list(&v)
+                .into_iter()
+                .map(|o| o.trim_end_matches('/').to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bearer_and_api_key_are_accepted() {
+        let auth = HttpAuth::new(&parse_list("s3cret, other "));
+        assert!(auth.authorize(Some("Bearer s3cret"), None));
+        assert!(auth.authorize(Some("bearer  other"), None));
+        assert!(auth.authorize(None, Some("other")));
+        assert!(!auth.authorize(Some("Bearer wrong"), Some("")));
+        assert!(!auth.authorize(Some("Basic s3cret"), None));
+        assert!(!auth.authorize(Some("s3cret"), None));
+        assert!(!auth.authorize(None, None));
+        assert!(HttpAuth::default().authorize(None, None));
+    }
+
+    #[test]
+    fn unauthenticated_servers_stay_on_loopback() {
+        let open = HttpAuth::default();
+        assert!(open.check_bind(&"127.0.0.1:8080".parse().unwrap()).is_ok());
+        assert!(open.check_bind(&"[::1]:8080".parse().unwrap()).is_ok());
+        assert!(open.check_bind(&"0.0.0.0:8080".parse().unwrap()).is_err());
+        let auth = HttpAuth::new(&["t".to_string()]);
+        assert!(auth.check_bind(&"0.0.0.0:8080".parse().unwrap()).is_ok());
+    }
+}
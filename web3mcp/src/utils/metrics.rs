@@ -0,0 +1,390 @@
+//! In-process tool-call metrics.
+//!
+//! Every `tools/call` is recorded once (see `utils/tool_telemetry.rs`): a call counter by
+//! tool / chain / network / outcome, an error counter by `error_class` (from
+//! `Web3McpServer::classify_error`) and a latency histogram per tool. The registry is
+//! rendered in the Prometheus text format (`/metrics` on `--http`) and as JSON
+//! (`system_metrics`). Values reset when the process restarts.
+
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Mutex, OnceLock};
+
+/// Histogram upper bounds, in seconds.
+pub const BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+/// Solana cluster and Sui network names the tools accept.
+const NAMED_NETWORKS: &[&str] = &[
+    "mainnet",
+    "mainnet-beta",
+    "mainnet_beta",
+    "testnet",
+    "devnet",
+    "localnet",
+    "local",
+];
+
+/// One finished tool call.
+#[derive(Debug, Clone)]
+pub struct CallSample<'a> {
+    pub tool: &'a str,
+    pub chain: &'a str,
+    pub network: &'a str,
+    /// `None` when the call succeeded.
+    pub error_class: Option<&'a str>,
+    pub seconds: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct CallKey {
+    tool: String,
+    chain: String,
+    network: String,
+    outcome: &'static str,
+}
+
+#[derive(Debug, Clone)]
+struct Histogram {
+    /// Non-cumulative counts per bucket; the last slot is `+Inf`.
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+    max: f64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: vec![0; BUCKETS.len() + 1],
+            sum: 0.0,
+            count: 0,
+            max: 0.0,
+        }
+    }
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        let idx = BUCKETS
+            .iter()
+            .position(|b| seconds <= *b)
+            .unwrap_or(BUCKETS.len());
+        self.buckets[idx] += 1;
+        self.sum += seconds;
+        self.count += 1;
+        self.max = self.max.max(seconds);
+    }
+
+    /// Upper bound of the bucket holding quantile `q` (`None` above the last bound).
+    fn quantile_bound(&self, q: f64) -> Option<f64> {
+        if self.count == 0 {
+            return Some(0.0);
+        }
+        let rank = (q * self.count as f64).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (i, n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                return BUCKETS.get(i).copied();
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    started_ms: u64,
+    calls: BTreeMap<CallKey, u64>,
+    errors: BTreeMap<(String, String), u64>,
+    durations: BTreeMap<String, Histogram>,
+}
+
+/// Keep the `network` label to a closed set: Solana / Sui network names, EVM chains of the
+/// registry (id or name) and chain ids with an `EVM_RPC_URL_<id>` configured. Anything else
+/// (RPC URLs, typos, arbitrary ids) becomes `custom`.
+pub fn network_label(raw: Option<&str>) -> String {
+    let v = raw.map(|s| s.trim().to_lowercase()).unwrap_or_default();
+    if v.is_empty() {
+        return "default".to_string();
+    }
+    let known = NAMED_NETWORKS.contains(&v.as_str())
+        || crate::utils::evm_chain_registry::evm_default_chains()
+            .iter()
+            .any(|c| c.name == v || c.chain_id.to_string() == v)
+        || v.parse::<u64>()
+            .is_ok_and(|id| crate::utils::config::var(&format!("EVM_RPC_URL_{}", id)).is_ok());
+    if known {
+        v
+    } else {
+        "custom".to_string()
+    }
+}
+
+fn escape_label(v: &str) -> String {
+    v.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn round_ms(seconds: f64) -> f64 {
+    (seconds * 1_000_000.0).round() / 1000.0
+}
+
+impl Registry {
+    pub fn new(started_ms: u64) -> Self {
+        Self {
+            started_ms,
+            ..Default::default()
+        }
+    }
+
+    pub fn record(&mut self, sample: &CallSample<'_>) {
+        let key = CallKey {
+            tool: sample.tool.to_string(),
+            chain: sample.chain.to_string(),
+            network: sample.network.to_string(),
+            outcome: if sample.error_class.is_some() {
+                "error"
+            } else {
+                "ok"
+            },
+        };
+        *self.calls.entry(key).or_default() += 1;
+        if let Some(class) = sample.error_class {
+            *self
+                .errors
+                .entry((sample.tool.to_string(), class.to_string()))
+                .or_default() += 1;
+        }
+        self.durations
+            .entry(sample.tool.to_string())
+            .or_default()
+            .observe(sample.seconds.max(0.0));
+    }
+
+    /// Prometheus text exposition format (version 0.0.4).
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# HELP web3mcp_start_time_seconds Unix time the server process started.\n\
+             # TYPE web3mcp_start_time_seconds gauge\n\
+             web3mcp_start_time_seconds {}",
+            self.started_ms / 1000
+        );
+
+        out.push_str(
+            "# HELP web3mcp_tool_calls_total Tool calls by tool, chain, network and outcome.\n\
+             # TYPE web3mcp_tool_calls_total counter\n",
+        );
+        for (k, n) in &self.calls {
+            let _ = writeln!(
+                out,
+                "web3mcp_tool_calls_total{{tool=\"{}\",chain=\"{}\",network=\"{}\",outcome=\"{}\"}} {}",
+                escape_label(&k.tool),
+                escape_label(&k.chain),
+                escape_label(&k.network),
+                k.outcome,
+                n
+            );
+        }
+
+        out.push_str(
+            "# HELP web3mcp_tool_errors_total Failed tool calls by error class.\n\
+             # TYPE web3mcp_tool_errors_total counter\n",
+        );
+        for ((tool, class), n) in &self.errors {
+            let _ = writeln!(
+                out,
+                "web3mcp_tool_errors_total{{tool=\"{}\",error_class=\"{}\"}} {}",
+                escape_label(tool),
+                escape_label(class),
+                n
+            );
+        }
+
+        out.push_str(
+            "# HELP web3mcp_tool_call_duration_seconds Tool call latency.\n\
+             # TYPE web3mcp_tool_call_duration_seconds histogram\n",
+        );
+        for (tool, h) in &self.durations {
+            let tool = escape_label(tool);
+            let mut cumulative = 0;
+            for (i, n) in h.buckets.iter().enumerate() {
+                cumulative += n;
+                let le = BUCKETS
+                    .get(i)
+                    .map(|b| b.to_string())
+                    .unwrap_or_else(|| "+Inf".to_string());
+                let _ = writeln!(
+                    out,
+                    "web3mcp_tool_call_duration_seconds_bucket{{tool=\"{}\",le=\"{}\"}} {}",
+                    tool, le, cumulative
+                );
+            }
+            let _ = writeln!(
+                out,
+                "web3mcp_tool_call_duration_seconds_sum{{tool=\"{}\"}} {}\n\
+                 web3mcp_tool_call_duration_seconds_count{{tool=\"{}\"}} {}",
+                tool, h.sum, tool, h.count
+            );
+        }
+        out
+    }
+
+    /// Per-tool summary; `tool` filters by exact name.
+    pub fn snapshot_json(&self, now_ms: u64, tool: Option<&str>) -> Value {
+        let mut tools: BTreeMap<&str, Map<String, Value>> = BTreeMap::new();
+        let wanted = |t: &str| tool.is_none_or(|w| w == t);
+
+        for (k, n) in self.calls.iter().filter(|(k, _)| wanted(&k.tool)) {
+            let entry = tools.entry(&k.tool).or_default();
+            entry.insert("chain".to_string(), json!(k.chain));
+            let total = entry.get("calls").and_then(Value::as_u64).unwrap_or(0);
+            entry.insert("calls".to_string(), json!(total + n));
+            if k.outcome == "error" {
+                let errors = entry.get("errors").and_then(Value::as_u64).unwrap_or(0);
+                entry.insert("errors".to_string(), json!(errors + n));
+            }
+            let by_network = entry
+                .entry("by_network".to_string())
+                .or_insert_with(|| json!({}));
+            let slot = &mut by_network[k.network.as_str()][k.outcome];
+            *slot = json!(slot.as_u64().unwrap_or(0) + n);
+        }
+        for ((t, class), n) in self.errors.iter().filter(|((t, _), _)| wanted(t)) {
+            let entry = tools.entry(t).or_default();
+            let classes = entry
+                .entry("error_classes".to_string())
+                .or_insert_with(|| json!({}));
+            classes[class.as_str()] = json!(n);
+        }
+        for (t, h) in self.durations.iter().filter(|(t, _)| wanted(t)) {
+            let entry = tools.entry(t).or_default();
+            let bound = |q| h.quantile_bound(q).map(round_ms);
+            entry.insert(
+                "latency_ms".to_string(),
+                json!({
+                    "avg": if h.count > 0 { round_ms(h.sum / h.count as f64) } else { 0.0 },
+                    "max": round_ms(h.max),
+                    // Bucket upper bounds; null means above the largest bucket.
+                    "p50_le": bound(0.5),
+                    "p95_le": bound(0.95),
+                }),
+            );
+            entry.entry("errors".to_string()).or_insert(json!(0));
+        }
+
+        let (calls, errors) = tools.values().fold((0, 0), |(c, e), t| {
+            (
+                c + t.get("calls").and_then(Value::as_u64).unwrap_or(0),
+                e + t.get("errors").and_then(Value::as_u64).unwrap_or(0),
+            )
+        });
+        json!({
+            "uptime_secs": now_ms.saturating_sub(self.started_ms) / 1000,
+            "calls": calls,
+            "errors": errors,
+            "tools": tools,
+        })
+    }
+}
+
+fn global() -> &'static Mutex<Registry> {
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(Registry::new(crate::utils::pending_store::now_ms())))
+}
+
+pub fn record(sample: &CallSample<'_>) {
+    if let Ok(mut r) = global().lock() {
+        r.record(sample);
+    }
+}
+
+pub fn render_prometheus() -> String {
+    global()
+        .lock()
+        .map(|r| r.render_prometheus())
+        .unwrap_or_default()
+}
+
+pub fn snapshot_json(tool: Option<&str>) -> Value {
+    let now = crate::utils::pending_store::now_ms();
+    global()
+        .lock()
+        .map(|r| r.snapshot_json(now, tool))
+        .unwrap_or(Value::Null)
+}
+
+/// Start the uptime clock at server start instead of at the first call.
+pub fn init() {
+    let _ = global();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample<'a>(tool: &'a str, error_class: Option<&'a str>, seconds: f64) -> CallSample<'a> {
+        CallSample {
+            tool,
+            chain: "evm",
+            network: "8453",
+            error_class,
+            seconds,
+        }
+    }
+
+    #[test]
+    fn prometheus_output_counts_calls_errors_and_latency() {
+        let mut r = Registry::new(1_000);
+        r.record(&sample("evm_get_balance", None, 0.02));
+        r.record(&sample("evm_get_balance", None, 0.3));
+        r.record(&sample("evm_get_balance", Some("RPC_UNAVAILABLE"), 45.0));
+        let text = r.render_prometheus();
+
+        assert!(text.contains(
+            "web3mcp_tool_calls_total{tool=\"evm_get_balance\",chain=\"evm\",network=\"8453\",outcome=\"ok\"} 2"
+        ));
+        assert!(text.contains(
+            "web3mcp_tool_errors_total{tool=\"evm_get_balance\",error_class=\"RPC_UNAVAILABLE\"} 1"
+        ));
+        assert!(text.contains(
+            "web3mcp_tool_call_duration_seconds_bucket{tool=\"evm_get_balance\",le=\"0.025\"} 1"
+        ));
+        assert!(text.contains(
+            "web3mcp_tool_call_duration_seconds_bucket{tool=\"evm_get_balance\",le=\"+Inf\"} 3"
+        ));
+        assert!(
+            text.contains("web3mcp_tool_call_duration_seconds_count{tool=\"evm_get_balance\"} 3")
+        );
+        assert!(text.contains("web3mcp_start_time_seconds 1\n"));
+
+        let snap = r.snapshot_json(11_000, Some("evm_get_balance"));
+        let t = &snap["tools"]["evm_get_balance"];
+        assert_eq!(snap["uptime_secs"], 10);
+        assert_eq!(t["calls"], 3);
+        assert_eq!(t["errors"], 1);
+        assert_eq!(t["by_network"]["8453"]["ok"], 2);
+        assert_eq!(t["error_classes"]["RPC_UNAVAILABLE"], 1);
+        assert_eq!(t["latency_ms"]["p50_le"], 500.0);
+        assert!(t["latency_ms"]["p95_le"].is_null());
+        assert_eq!(r.snapshot_json(0, Some("other"))["calls"], 0);
+    }
+
+    #[test]
+    fn labels_stay_bounded_and_escaped() {
+        assert_eq!(network_label(None), "default");
+        assert_eq!(network_label(Some(" Mainnet ")), "mainnet");
+        assert_eq!(network_label(Some("https://rpc.example/key")), "custom");
+        assert_eq!(network_label(Some(&"x".repeat(40))), "custom");
+        assert_eq!(network_label(Some("Base")), "base");
+        assert_eq!(network_label(Some("mainnet-beta")), "mainnet-beta");
+        assert_eq!(network_label(Some("typo-net")), "custom");
+        assert_eq!(network_label(Some("987654321")), "custom");
+        assert_eq!(escape_label("a\"b\\c"), "a\\\"b\\\\c");
+    }
+}
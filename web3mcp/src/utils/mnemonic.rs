@@ -0,0 +1,499 @@
+//! BIP-39 mnemonics and HD derivation for Sui, Solana and EVM accounts.
+//!
+//! Phrases are stored encrypted (Web3 Secret Storage, like the keystore backend) at
+//! `<signer.keystore_dir>/mnemonic/<name>.json`; only the word count is kept in plaintext.
+//! Keys are derived with an empty BIP-39 passphrase:
+//! - Sui: SLIP-0010 ed25519, `m/44'/784'/{index}'/0'/0'`.
+//! - Solana: SLIP-0010 ed25519, `m/44'/501'/{index}'/0'` (the Phantom / `solana-keygen` layout).
+//! - EVM: BIP-32 secp256k1, `m/44'/60'/0'/0/{index}`.
+//!
+//! The `mnemonic` signer backend signs with the account picked by `mnemonic_select` (stored in
+//! `selection.json`), overridable with `signer.mnemonic` and `signer.<chain>_derivation_path`.
+
+use crate::utils::signer::{self, signer_unavailable, SignerChain};
+use ethers::signers::coins_bip39::{English, Mnemonic};
+use hmac::{Hmac, Mac};
+use rmcp::model::{ErrorCode, ErrorData};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::Sha512;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const MNEMONIC_ENV: &str = "WEB3MCP_SIGNER_MNEMONIC";
+pub const SELECTION_FILE: &str = "selection.json";
+pub const DEFAULT_NAME: &str = "default";
+const HARDENED: u32 = 0x8000_0000;
+
+/// Account a chain signs with under the `mnemonic` backend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Selection {
+    pub mnemonic: String,
+    pub derivation_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredMnemonic {
+    pub name: String,
+    pub words: Option<u64>,
+    pub path: String,
+}
+
+fn invalid(message: impl Into<String>, data: Value) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(message.into()),
+        data: Some(data),
+    }
+}
+
+fn io_error(action: &str, path: &Path, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("Failed to {} {}: {}", action, path.display(), e)),
+        data: Some(json!({ "path": path.display().to_string() })),
+    }
+}
+
+/// Per-chain override of the selected derivation path.
+pub fn derivation_path_env(chain: SignerChain) -> &'static str {
+    match chain {
+        SignerChain::Sui => "WEB3MCP_SIGNER_SUI_DERIVATION_PATH",
+        SignerChain::Solana => "WEB3MCP_SIGNER_SOLANA_DERIVATION_PATH",
+        SignerChain::Evm => "WEB3MCP_SIGNER_EVM_DERIVATION_PATH",
+    }
+}
+
+/// Standard path of account `index` on `chain`.
+pub fn default_path(chain: SignerChain, index: u32) -> String {
+    match chain {
+        SignerChain::Sui => format!("m/44'/784'/{}'/0'/0'", index),
+        SignerChain::Solana => format!("m/44'/501'/{}'/0'", index),
+        SignerChain::Evm => format!("m/44'/60'/0'/0/{}", index),
+    }
+}
+
+/// `m/a/b'/...` into child numbers (`'` or `h` marks a hardened index).
+pub fn parse_path(path: &str) -> Result<Vec<u32>, ErrorData> {
+    let bad = |why: &str| {
+        invalid(
+            format!("Invalid derivation path '{}': {}", path, why),
+            json!({ "derivation_path": path }),
+        )
+    };
+    let mut parts = path.trim().split('/');
+    if parts.next() != Some("m") {
+        return Err(bad("must start with m/"));
+    }
+    parts
+        .map(|part| {
+            let (digits, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                Some(d) => (d, true),
+                None => (part, false),
+            };
+            let index: u32 = digits.parse().map_err(|_| bad("indexes must be numbers"))?;
+            if index >= HARDENED {
+                return Err(bad("index out of range"));
+            }
+            Ok(if hardened { index | HARDENED } else { index })
+        })
+        .collect()
+}
+
+/// Parse `path` and check it belongs to `chain`: purpose 44' and the chain's coin type, and
+/// hardened-only for the ed25519 chains.
+pub fn validate_path(chain: SignerChain, path: &str) -> Result<Vec<u32>, ErrorData> {
+    let indexes = parse_path(path)?;
+    let coin = match chain {
+        SignerChain::Sui => 784,
+        SignerChain::Solana => 501,
+        SignerChain::Evm => 60,
+    };
+    let prefix_ok =
+        indexes.len() >= 2 && indexes[0] == 44 | HARDENED && indexes[1] == coin | HARDENED;
+    let ed25519 = chain != SignerChain::Evm;
+    let ok = prefix_ok
+        && match chain {
+            SignerChain::Sui => indexes.len() == 5,
+            SignerChain::Solana => indexes.len() <= 5,
+            SignerChain::Evm => indexes.len() == 5,
+        }
+        && (!ed25519 || indexes.iter().all(|i| i & HARDENED != 0));
+    if ok {
+        Ok(indexes)
+    } else {
+        Err(invalid(
+            format!(
+                "Derivation path '{}' is not a {} path (expected like {}{})",
+                path,
+                chain.as_str(),
+                default_path(chain, 0),
+                if ed25519 {
+                    ", all indexes hardened"
+                } else {
+                    ""
+                }
+            ),
+            json!({ "derivation_path": path, "chain": chain.as_str() }),
+        ))
+    }
+}
+
+/// Lowercased, single-spaced phrase with a valid BIP-39 (English) checksum.
+pub fn normalize_phrase(phrase: &str) -> Result<String, ErrorData> {
+    let normalized = phrase
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ");
+    Mnemonic::<English>::new_from_phrase(&normalized).map_err(|e| {
+        invalid(
+            format!("Invalid BIP-39 mnemonic: {}", e),
+            json!({ "words": normalized.split(' ').count() }),
+        )
+    })?;
+    Ok(normalized)
+}
+
+fn seed(phrase: &str) -> Result<[u8; 64], ErrorData> {
+    let mnemonic = Mnemonic::<English>::new_from_phrase(phrase)
+        .map_err(|e| invalid(format!("Invalid BIP-39 mnemonic: {}", e), json!({})))?;
+    mnemonic
+        .to_seed(None)
+        .map_err(|e| invalid(format!("Failed to derive BIP-39 seed: {}", e), json!({})))
+}
+
+/// SLIP-0010 ed25519 private key for hardened `path` indexes.
+pub fn slip10_ed25519(seed: &[u8], path: &[u32]) -> Result<[u8; 32], ErrorData> {
+    fn hmac512(key: &[u8], parts: &[&[u8]]) -> [u8; 64] {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts any key length");
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().into()
+    }
+
+    let mut node = hmac512(b"ed25519 seed", &[seed]);
+    for index in path {
+        if index & HARDENED == 0 {
+            return Err(invalid(
+                "ed25519 derivation only supports hardened indexes",
+                json!({ "index": index }),
+            ));
+        }
+        let (key, chain_code) = node.split_at(32);
+        node = hmac512(chain_code, &[&[0u8], key, &index.to_be_bytes()]);
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&node[..32]);
+    Ok(key)
+}
+
+/// EVM wallet at `path` (chain id left at the ethers default).
+pub fn evm_wallet(phrase: &str, path: &str) -> Result<ethers::signers::LocalWallet, ErrorData> {
+    validate_path(SignerChain::Evm, path)?;
+    ethers::signers::MnemonicBuilder::<English>::default()
+        .phrase(phrase)
+        .derivation_path(path)
+        .and_then(|b| b.build())
+        .map_err(|e| {
+            invalid(
+                format!("Failed to derive EVM key: {}", e),
+                json!({ "derivation_path": path }),
+            )
+        })
+}
+
+/// Raw private key at `path`: the 32-byte ed25519 seed for Sui / Solana, the secp256k1 scalar
+/// for EVM.
+pub fn derive_secret(chain: SignerChain, phrase: &str, path: &str) -> Result<Vec<u8>, ErrorData> {
+    match chain {
+        SignerChain::Evm => Ok(evm_wallet(phrase, path)?.signer().to_bytes().to_vec()),
+        SignerChain::Sui | SignerChain::Solana => {
+            let indexes = validate_path(chain, path)?;
+            Ok(slip10_ed25519(&seed(phrase)?, &indexes)?.to_vec())
+        }
+    }
+}
+
+// ---------------- encrypted storage ----------------
+
+pub fn dir() -> PathBuf {
+    signer::keystore_dir().join("mnemonic")
+}
+
+pub fn validate_name(name: &str) -> Result<String, ErrorData> {
+    let stem = name.trim();
+    let stem = stem.strip_suffix(".json").unwrap_or(stem);
+    let ok = !stem.is_empty()
+        && stem.len() <= 64
+        && !stem.starts_with('.')
+        && stem != SELECTION_FILE.trim_end_matches(".json")
+        && stem
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if ok {
+        Ok(stem.to_string())
+    } else {
+        Err(invalid(
+            "name must be 1-64 characters of letters, digits, '-', '_' or '.' (not 'selection')",
+            json!({ "name": name }),
+        ))
+    }
+}
+
+fn path_of(name: &str) -> Result<PathBuf, ErrorData> {
+    Ok(dir().join(format!("{}.json", validate_name(name)?)))
+}
+
+/// Encrypt `phrase` (already normalized) as `name`.
+pub fn store(
+    name: &str,
+    phrase: &str,
+    password: &str,
+    overwrite: bool,
+) -> Result<PathBuf, ErrorData> {
+    let name = validate_name(name)?;
+    let dir = dir();
+    let file = format!("{}.json", name);
+    let path = dir.join(&file);
+    if path.exists() && !overwrite {
+        return Err(invalid(
+            format!("Mnemonic '{}' already exists", name),
+            json!({ "name": name, "hint": "set overwrite=true to replace it" }),
+        ));
+    }
+    signer::write_keystore_file(
+        &dir,
+        &file,
+        phrase.as_bytes(),
+        password,
+        json!({ "words": phrase.split(' ').count() }),
+    )
+}
+
+/// Decrypt mnemonic `name`.
+pub fn load(name: &str, password: &str) -> Result<String, ErrorData> {
+    let path = path_of(name)?;
+    let bytes = signer::decrypt_keystore_file(&path, password)?;
+    String::from_utf8(bytes).map_err(|_| {
+        invalid(
+            format!("{} does not hold a mnemonic", path.display()),
+            json!({ "path": path.display().to_string() }),
+        )
+    })
+}
+
+/// Stored mnemonics sorted by name; empty when none were imported.
+pub fn list() -> Result<Vec<StoredMnemonic>, ErrorData> {
+    let dir = dir();
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_error("read", &dir, e)),
+    };
+    let mut out: Vec<StoredMnemonic> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file = entry.file_name().to_str()?.to_string();
+            let name = file.strip_suffix(".json")?.to_string();
+            if file == SELECTION_FILE || name.starts_with('.') {
+                return None;
+            }
+            let words = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+                .and_then(|doc| doc.get("words").and_then(Value::as_u64));
+            Some(StoredMnemonic {
+                name,
+                words,
+                path: entry.path().display().to_string(),
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(out)
+}
+
+/// Chain name -> selected account.
+pub fn selections() -> BTreeMap<String, Selection> {
+    std::fs::read_to_string(dir().join(SELECTION_FILE))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Select the account `chain` signs with; returns the previous selection.
+pub fn select(
+    chain: SignerChain,
+    name: &str,
+    derivation_path: &str,
+) -> Result<Option<Selection>, ErrorData> {
+    let name = validate_name(name)?;
+    let path = path_of(&name)?;
+    if !path.exists() {
+        return Err(invalid(
+            format!("Mnemonic '{}' not found", name),
+            json!({ "name": name, "path": path.display().to_string() }),
+        ));
+    }
+    validate_path(chain, derivation_path)?;
+    let mut all = selections();
+    let previous = all.insert(
+        chain.as_str().to_string(),
+        Selection {
+            mnemonic: name,
+            derivation_path: derivation_path.trim().to_string(),
+        },
+    );
+    std::fs::create_dir_all(dir()).map_err(|e| io_error("create", &dir(), e))?;
+    signer::write_owner_only(
+        &dir().join(SELECTION_FILE),
+        &serde_json::to_string_pretty(&all).unwrap_or_default(),
+    )?;
+    Ok(previous)
+}
+
+/// Account the `mnemonic` backend signs with on `chain`: `signer.mnemonic` /
+/// `signer.<chain>_derivation_path` over the selection, else `default` at index 0.
+pub fn active(chain: SignerChain) -> Selection {
+    let selected = selections().remove(chain.as_str());
+    let env = |key: &str| {
+        crate::utils::config::var(key)
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    Selection {
+        mnemonic: env(MNEMONIC_ENV)
+            .or_else(|| selected.as_ref().map(|s| s.mnemonic.clone()))
+            .unwrap_or_else(|| DEFAULT_NAME.to_string()),
+        derivation_path: env(derivation_path_env(chain))
+            .or_else(|| selected.map(|s| s.derivation_path))
+            .unwrap_or_else(|| default_path(chain, 0)),
+    }
+}
+
+/// Secret of the active account for `chain`, with a `mnemonic:<name>/<path>` source label.
+/// Unlocked with `WEB3MCP_SIGNER_KEYSTORE_PASSWORD`.
+pub fn signer_secret(chain: SignerChain) -> Result<(Vec<u8>, String), ErrorData> {
+    let active = active(chain);
+    let path = path_of(&active.mnemonic)?;
+    if !path.exists() {
+        return Err(signer_unavailable(
+            format!(
+                "No mnemonic '{}' at {}; import one with mnemonic_import",
+                active.mnemonic,
+                path.display()
+            ),
+            json!({ "path": path.display().to_string() }),
+        ));
+    }
+    let password = signer::keystore_password(None)?;
+    let phrase = load(&active.mnemonic, &password)?;
+    let secret = derive_secret(chain, &phrase, &active.derivation_path)?;
+    Ok((
+        secret,
+        format!("mnemonic:{}/{}", active.mnemonic, active.derivation_path),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn slip10_matches_the_spec_vector() {
+        // SLIP-0010 test vector 1 for ed25519.
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = slip10_ed25519(&seed, &[]).unwrap();
+        assert_eq!(
+            hex::encode(master),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        let path = parse_path("m/0'/1'/2'/2'/1000000000'").unwrap();
+        assert_eq!(
+            hex::encode(slip10_ed25519(&seed, &path).unwrap()),
+            "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"
+        );
+        assert!(slip10_ed25519(&seed, &[1]).is_err());
+    }
+
+    #[test]
+    fn paths_are_checked_per_chain() {
+        for chain in SignerChain::ALL {
+            assert!(validate_path(chain, &default_path(chain, 7)).is_ok());
+        }
+        assert_eq!(
+            parse_path("m/44h/60'/0").unwrap(),
+            [44 | HARDENED, 60 | HARDENED, 0]
+        );
+        assert!(validate_path(SignerChain::Sui, "m/44'/784'/0'/0/0").is_err());
+        assert!(validate_path(SignerChain::Solana, "m/44'/60'/0'/0'").is_err());
+        assert!(validate_path(SignerChain::Evm, "m/44'/60'/0'").is_err());
+        assert!(parse_path("44'/60'").is_err());
+    }
+
+    #[test]
+    fn derives_the_well_known_evm_account() {
+        assert!(normalize_phrase("abandon abandon").is_err());
+        let phrase = normalize_phrase(&PHRASE.to_uppercase().replace(' ', "  ")).unwrap();
+        assert_eq!(phrase, PHRASE);
+        let wallet = evm_wallet(&phrase, &default_path(SignerChain::Evm, 0)).unwrap();
+        assert_eq!(
+            format!("{:?}", ethers::signers::Signer::address(&wallet)),
+            "0x9858effd232b4033e47d90003d41ec34ecaeda94"
+        );
+        let sol0 = derive_secret(
+            SignerChain::Solana,
+            &phrase,
+            &default_path(SignerChain::Solana, 0),
+        );
+        let sol1 = derive_secret(
+            SignerChain::Solana,
+            &phrase,
+            &default_path(SignerChain::Solana, 1),
+        );
+        assert_eq!(sol0.as_ref().unwrap().len(), 32);
+        assert_ne!(sol0.unwrap(), sol1.unwrap());
+    }
+
+    #[test]
+    fn stores_encrypted_and_selects_accounts() {
+        let _guard = signer::ENV_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let tmp = tempfile::tempdir().unwrap();
+        std::env::set_var(signer::KEYSTORE_DIR_ENV, tmp.path());
+
+        let path = store("seed", PHRASE, "pw", false).unwrap();
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("abandon"));
+        assert!(store("seed", PHRASE, "pw", false).is_err());
+        assert_eq!(load("seed", "pw").unwrap(), PHRASE);
+        assert!(load("seed", "nope").is_err());
+        assert_eq!(list().unwrap()[0].words, Some(12));
+
+        assert_eq!(
+            active(SignerChain::Sui).derivation_path,
+            default_path(SignerChain::Sui, 0)
+        );
+        let sui3 = default_path(SignerChain::Sui, 3);
+        assert_eq!(select(SignerChain::Sui, "seed", &sui3).unwrap(), None);
+        assert!(select(SignerChain::Sui, "seed", "m/44'/60'/0'/0/0").is_err());
+        assert!(select(SignerChain::Sui, "missing", &sui3).is_err());
+        assert_eq!(
+            active(SignerChain::Sui),
+            Selection {
+                mnemonic: "seed".to_string(),
+                derivation_path: sui3
+            }
+        );
+        assert_eq!(list().unwrap().len(), 1);
+
+        std::env::remove_var(signer::KEYSTORE_DIR_ENV);
+    }
+}
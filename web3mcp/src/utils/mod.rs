@@ -1,29 +1,52 @@
+pub mod approval_policy;
 pub mod audit;
+pub mod audit_log;
 pub mod base64;
+pub mod config;
+pub mod data_dir;
 pub mod errors;
 pub mod evm_calldata;
 pub mod evm_chain_registry;
 pub mod evm_confirm_store;
 pub mod evm_confirm_ux;
 pub mod evm_decode;
+pub mod evm_rpc;
 pub mod evm_selector;
 pub mod evm_tx_replace;
+pub mod http_auth;
 pub mod json;
+pub mod metrics;
+pub mod mnemonic;
 pub mod network;
 pub mod override_store;
+pub mod pending_store;
+pub mod resource_uri;
+pub mod response_cache;
+pub mod rpc_endpoints;
+pub mod run_index;
 pub mod run_store;
+pub mod session_scope;
+pub mod signer;
+pub mod signer_backends;
 pub mod solana_confirm_store;
 #[cfg(feature = "solana-extended-tools")]
 pub mod solana_idl;
 pub mod solana_idl_cache;
-#[cfg(feature = "solana-extended-tools")]
 pub mod solana_idl_registry;
+pub mod solana_keystore;
 pub mod solana_policy;
+pub mod solana_rpc;
+pub mod solana_workflow;
+pub mod spend_limits;
 
 #[cfg(feature = "solana-extended-tools")]
 pub mod solana_idl_arg_examples;
 #[cfg(feature = "solana-extended-tools")]
 pub mod solana_idl_enum_hints;
 pub mod sui_confirm_store;
+pub mod sui_multisig;
 pub mod sui_parse;
+pub mod sui_sponsor;
 pub mod token_registry;
+pub mod tool_telemetry;
+pub mod tool_profiles;
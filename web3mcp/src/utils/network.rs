@@ -3,7 +3,7 @@ use crate::Web3McpServer;
 impl Web3McpServer {
     pub fn resolve_network(network: Option<String>) -> String {
         network
-            .or_else(|| std::env::var("SUI_NETWORK").ok())
+            .or_else(|| crate::utils::config::var("SUI_NETWORK").ok())
             .unwrap_or_else(|| "mainnet".to_string())
     }
 }
@@ -0,0 +1,1216 @@
+//! Unified pending-confirmation store (`<data_dir>/pending.sqlite`, see [`crate::utils::data_dir`]).
+//!
+//! Solana, Sui and EVM confirmations live in one `pending_confirmations` table. The shared
+//! columns carry identity, TTL, status, the broadcast reference (signature / digest / tx hash)
+//! and the spend ledger (`spend_json`); `payload_json` holds the chain-specific part (Solana
+//! `tx_base64`, Sui `tx_bytes_b64` + last dry run, EVM tx request + approve/second-confirm
+//! metadata). The `*_confirm_store` modules are typed views over this table.
+//!
+//! Status machine (see [`PendingStatus::can_transition_to`]):
+//! `pending → signed → sent → confirmed`, with `failed` and `expired` exits; `failed` rows can
+//! be retried until they expire. Schema changes are versioned migrations ([`apply_migrations`]).
+
+use crate::utils::session_scope;
+use rmcp::model::{ErrorCode, ErrorData};
+use rusqlite::OptionalExtension as _;
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+
+/// Migration component name for this store in `schema_migrations`.
+pub const COMPONENT: &str = "pending_confirmations";
+
+/// Rows that recorded a broadcast spend (`spend_json`) are kept this long after their last
+/// update. This is the spend-limit ledger, so it must cover the longest limit window.
+pub const SPEND_LEDGER_RETENTION_MS: u64 = 31 * 24 * 60 * 60 * 1000;
+
+/// Other rows stay listable this long past their expiry before cleanup deletes them.
+pub const HISTORY_RETENTION_MS: u64 = 24 * 60 * 60 * 1000;
+
+fn db_err(ctx: &str, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("{}: {}", ctx, e)),
+        data: None,
+    }
+}
+
+pub fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingChain {
+    Solana,
+    Sui,
+    Evm,
+}
+
+impl PendingChain {
+    pub const ALL: [PendingChain; 3] = [PendingChain::Solana, PendingChain::Sui, PendingChain::Evm];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PendingChain::Solana => "solana",
+            PendingChain::Sui => "sui",
+            PendingChain::Evm => "evm",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "solana" => Some(PendingChain::Solana),
+            "sui" => Some(PendingChain::Sui),
+            "evm" => Some(PendingChain::Evm),
+            _ => None,
+        }
+    }
+
+    pub fn default_ttl_ms(self) -> u64 {
+        match self {
+            PendingChain::Solana => 15 * 60 * 1000,
+            PendingChain::Sui | PendingChain::Evm => 10 * 60 * 1000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingStatus {
+    /// Awaiting user confirmation.
+    Pending,
+    /// Claimed by a confirm call and being signed; not broadcast yet.
+    Signed,
+    /// Broadcast; `tx_ref` holds the signature / digest / tx hash.
+    Sent,
+    /// Broadcast and observed as landed.
+    Confirmed,
+    /// Signing, broadcast or on-chain execution failed (`last_error`); retryable until expiry.
+    Failed,
+    /// Never broadcast before its TTL ran out (or closed without a broadcast).
+    Expired,
+}
+
+impl PendingStatus {
+    pub const ALL: [PendingStatus; 6] = [
+        PendingStatus::Pending,
+        PendingStatus::Signed,
+        PendingStatus::Sent,
+        PendingStatus::Confirmed,
+        PendingStatus::Failed,
+        PendingStatus::Expired,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PendingStatus::Pending => "pending",
+            PendingStatus::Signed => "signed",
+            PendingStatus::Sent => "sent",
+            PendingStatus::Confirmed => "confirmed",
+            PendingStatus::Failed => "failed",
+            PendingStatus::Expired => "expired",
+        }
+    }
+
+    /// Parse a status name. `consumed` (the per-chain stores' old name for a claimed row) is
+    /// accepted as `signed`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "pending" => Some(PendingStatus::Pending),
+            "signed" | "consumed" => Some(PendingStatus::Signed),
+            "sent" => Some(PendingStatus::Sent),
+            "confirmed" => Some(PendingStatus::Confirmed),
+            "failed" => Some(PendingStatus::Failed),
+            "expired" => Some(PendingStatus::Expired),
+            _ => None,
+        }
+    }
+
+    pub fn names() -> String {
+        Self::ALL
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    pub fn is_terminal(self) -> bool {
+        matches!(self, PendingStatus::Confirmed | PendingStatus::Expired)
+    }
+
+    /// Allowed status changes. Re-entering `pending` (re-quote) or `signed` (re-claim for a
+    /// retry) is allowed; everything else must move forward.
+    pub fn can_transition_to(self, to: PendingStatus) -> bool {
+        use PendingStatus::*;
+        matches!(
+            (self, to),
+            (Pending | Signed, Pending | Signed | Sent | Failed | Expired)
+                | (Failed, Pending | Signed | Expired)
+                | (Sent, Confirmed | Failed)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingRecord {
+    pub id: String,
+    pub chain: PendingChain,
+    /// EVM chain id, or the Solana/Sui network when the creating tool recorded it.
+    pub network: Option<String>,
+    pub status: PendingStatus,
+    pub created_at_ms: u64,
+    pub updated_at_ms: u64,
+    pub expires_at_ms: u64,
+    pub tx_summary_hash: String,
+    pub source_tool: Option<String>,
+    pub summary: Option<Value>,
+    /// Chain-specific fields (object).
+    pub payload: Value,
+    /// Signature (Solana), digest (Sui) or tx hash (EVM) once broadcast.
+    pub tx_ref: Option<String>,
+    pub last_error: Option<String>,
+    pub spend_json: Option<String>,
+}
+
+impl PendingRecord {
+    pub fn payload_str(&self, key: &str) -> Option<&str> {
+        self.payload.get(key).and_then(Value::as_str)
+    }
+
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        now_ms > self.expires_at_ms
+    }
+
+    /// Chain-neutral JSON view used by the cross-chain tools.
+    pub fn to_json(&self, now_ms: u64, include_payload: bool) -> Value {
+        json!({
+            "id": self.id,
+            "chain": self.chain.as_str(),
+            "network": self.network,
+            "status": self.status.as_str(),
+            "created_at_ms": self.created_at_ms,
+            "updated_at_ms": self.updated_at_ms,
+            "expires_at_ms": self.expires_at_ms,
+            "expires_in_ms": self.expires_at_ms.saturating_sub(now_ms),
+            "tx_summary_hash": self.tx_summary_hash,
+            "source_tool": self.source_tool,
+            "summary": self.summary,
+            "tx_ref": self.tx_ref,
+            "last_error": self.last_error,
+            "spend": self.spend_json.as_deref().and_then(|s| serde_json::from_str::<Value>(s).ok()),
+            "payload": if include_payload { Some(&self.payload) } else { None },
+        })
+    }
+}
+
+/// A new pending row (status `pending`).
+pub struct NewPending<'a> {
+    pub id: &'a str,
+    pub chain: PendingChain,
+    pub network: Option<&'a str>,
+    pub created_at_ms: u64,
+    pub expires_at_ms: u64,
+    pub tx_summary_hash: &'a str,
+    pub source_tool: Option<&'a str>,
+    pub summary: Option<&'a Value>,
+    pub payload: &'a Value,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ListFilter {
+    pub chain: Option<PendingChain>,
+    pub network: Option<String>,
+    pub status: Option<PendingStatus>,
+    pub limit: usize,
+}
+
+/// Second-step confirmation token for a pending row (`0x` + sha256 of `chain:id:hash`).
+pub fn confirm_token(chain: PendingChain, id: &str, tx_summary_hash: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let msg = format!("{}:{}:{}", chain.as_str(), id, tx_summary_hash);
+    format!("0x{}", hex::encode(Sha256::digest(msg.as_bytes())))
+}
+
+// ---- Migrations ----------------------------------------------------------------------------
+
+/// One schema step. Versions are per component and applied in order, each in its own
+/// transaction, and recorded in `schema_migrations`.
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub up: fn(&rusqlite::Transaction) -> rusqlite::Result<()>,
+}
+
+/// Apply the migrations of `component` that are not recorded yet. Returns the applied names.
+pub fn apply_migrations(
+    conn: &mut rusqlite::Connection,
+    component: &str,
+    migrations: &[Migration],
+) -> Result<Vec<&'static str>, ErrorData> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+           component TEXT NOT NULL,
+           version INTEGER NOT NULL,
+           name TEXT NOT NULL,
+           applied_at_ms INTEGER NOT NULL,
+           PRIMARY KEY (component, version)
+         );",
+    )
+    .map_err(|e| db_err("Failed to init schema_migrations", e))?;
+
+    let current = |conn: &rusqlite::Connection| -> Result<i64, ErrorData> {
+        conn.query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM schema_migrations WHERE component=?1",
+            [component],
+            |row| row.get(0),
+        )
+        .map_err(|e| db_err("Failed to read schema version", e))
+    };
+
+    let latest = migrations.iter().map(|m| m.version).max().unwrap_or(0);
+    if current(conn)? >= latest {
+        return Ok(Vec::new());
+    }
+
+    let mut applied = Vec::new();
+    for m in migrations {
+        // IMMEDIATE takes the write lock up front, so concurrent processes apply each step once.
+        let tx = conn
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+            .map_err(|e| db_err("Failed to start migration transaction", e))?;
+        if current(&tx)? >= m.version {
+            continue;
+        }
+        (m.up)(&tx).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!(
+                "SQLite migration {}#{} ({}) failed: {}",
+                component, m.version, m.name, e
+            )),
+            data: None,
+        })?;
+        tx.execute(
+            "INSERT INTO schema_migrations (component, version, name, applied_at_ms) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![component, m.version, m.name, now_ms() as i64],
+        )
+        .map_err(|e| db_err("Failed to record migration", e))?;
+        tx.commit()
+            .map_err(|e| db_err("Failed to commit migration", e))?;
+        applied.push(m.name);
+    }
+    Ok(applied)
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "create_pending_confirmations",
+        up: m001_create_table,
+    },
+    Migration {
+        version: 2,
+        name: "import_solana_pending_confirmations",
+        up: m002_import_solana,
+    },
+    Migration {
+        version: 3,
+        name: "import_sui_pending_confirmations",
+        up: m003_import_sui,
+    },
+    Migration {
+        version: 4,
+        name: "import_evm_pending_confirmations",
+        up: m004_import_evm,
+    },
+    Migration {
+        version: 5,
+        name: "add_session_id",
+        up: m005_add_session_id,
+    },
+];
+
+fn m001_create_table(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS pending_confirmations (
+           id TEXT PRIMARY KEY,
+           chain TEXT NOT NULL,
+           network TEXT,
+           status TEXT NOT NULL,
+           created_at_ms INTEGER NOT NULL,
+           updated_at_ms INTEGER NOT NULL,
+           expires_at_ms INTEGER NOT NULL,
+           tx_summary_hash TEXT NOT NULL,
+           source_tool TEXT,
+           summary_json TEXT,
+           payload_json TEXT NOT NULL,
+           tx_ref TEXT,
+           last_error TEXT,
+           spend_json TEXT
+         );
+         CREATE INDEX IF NOT EXISTS idx_pending_chain_status ON pending_confirmations(chain, status);
+         CREATE INDEX IF NOT EXISTS idx_pending_expires ON pending_confirmations(expires_at_ms);
+         CREATE INDEX IF NOT EXISTS idx_pending_updated ON pending_confirmations(updated_at_ms);",
+    )
+}
+
+fn table_exists(tx: &rusqlite::Transaction, table: &str) -> rusqlite::Result<bool> {
+    tx.query_row(
+        "SELECT COUNT(1) FROM sqlite_master WHERE type='table' AND name=?1",
+        [table],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n > 0)
+}
+
+/// All rows of a pre-unification table as JSON objects (column set varies by version).
+fn legacy_rows(
+    tx: &rusqlite::Transaction,
+    table: &str,
+) -> rusqlite::Result<Vec<Map<String, Value>>> {
+    use rusqlite::types::ValueRef;
+    let mut stmt = tx.prepare(&format!("SELECT * FROM {table}"))?;
+    let names: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+    let rows = stmt.query_map([], |row| {
+        let mut m = Map::new();
+        for (i, name) in names.iter().enumerate() {
+            let v = match row.get_ref(i)? {
+                ValueRef::Null => Value::Null,
+                ValueRef::Integer(n) => json!(n),
+                ValueRef::Real(f) => json!(f),
+                ValueRef::Text(t) | ValueRef::Blob(t) => {
+                    Value::String(String::from_utf8_lossy(t).into_owned())
+                }
+            };
+            m.insert(name.clone(), v);
+        }
+        Ok(m)
+    })?;
+    rows.collect()
+}
+
+fn legacy_str(row: &Map<String, Value>, key: &str) -> Option<String> {
+    row.get(key).and_then(Value::as_str).map(|s| s.to_string())
+}
+
+fn legacy_i64(row: &Map<String, Value>, key: &str) -> Option<i64> {
+    row.get(key).and_then(Value::as_i64)
+}
+
+fn legacy_json(row: &Map<String, Value>, key: &str) -> Option<Value> {
+    row.get(key)
+        .and_then(Value::as_str)
+        .and_then(|s| serde_json::from_str::<Value>(s).ok())
+}
+
+/// Old per-chain statuses: `consumed` became `signed`; EVM `skipped` (approve not needed) is a
+/// row closed without a broadcast, i.e. `expired`.
+fn legacy_status(row: &Map<String, Value>) -> PendingStatus {
+    match legacy_str(row, "status").as_deref() {
+        Some("skipped") => PendingStatus::Expired,
+        Some(s) => PendingStatus::parse(s).unwrap_or(PendingStatus::Pending),
+        None => PendingStatus::Pending,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn import_row(
+    tx: &rusqlite::Transaction,
+    row: &Map<String, Value>,
+    chain: PendingChain,
+    network: Option<String>,
+    source_tool: Option<String>,
+    summary_json: Option<String>,
+    payload: Value,
+    tx_ref: Option<String>,
+) -> rusqlite::Result<()> {
+    let created = legacy_i64(row, "created_at_ms").unwrap_or(0);
+    tx.execute(
+        "INSERT OR IGNORE INTO pending_confirmations
+         (id, chain, network, status, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash,
+          source_tool, summary_json, payload_json, tx_ref, last_error, spend_json)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        rusqlite::params![
+            legacy_str(row, "id").unwrap_or_default(),
+            chain.as_str(),
+            network,
+            legacy_status(row).as_str(),
+            created,
+            legacy_i64(row, "updated_at_ms").unwrap_or(created),
+            legacy_i64(row, "expires_at_ms").unwrap_or(created),
+            legacy_str(row, "tx_summary_hash").unwrap_or_default(),
+            source_tool,
+            summary_json,
+            payload.to_string(),
+            tx_ref,
+            legacy_str(row, "last_error"),
+            legacy_str(row, "spend_json"),
+        ],
+    )?;
+    Ok(())
+}
+
+fn m002_import_solana(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    const TABLE: &str = "solana_pending_confirmations";
+    if !table_exists(tx, TABLE)? {
+        return Ok(());
+    }
+    for row in legacy_rows(tx, TABLE)? {
+        import_row(
+            tx,
+            &row,
+            PendingChain::Solana,
+            None,
+            legacy_str(&row, "source_tool"),
+            legacy_str(&row, "summary_json"),
+            json!({ "tx_base64": legacy_str(&row, "tx_base64") }),
+            legacy_str(&row, "signature"),
+        )?;
+    }
+    tx.execute_batch(&format!("DROP TABLE {TABLE};"))
+}
+
+fn m003_import_sui(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    const TABLE: &str = "sui_pending_confirmations";
+    if !table_exists(tx, TABLE)? {
+        return Ok(());
+    }
+    for row in legacy_rows(tx, TABLE)? {
+        import_row(
+            tx,
+            &row,
+            PendingChain::Sui,
+            None,
+            legacy_str(&row, "tool_context"),
+            legacy_str(&row, "summary_json"),
+            json!({
+                "tx_bytes_b64": legacy_str(&row, "tx_bytes_b64"),
+                "last_dry_run": legacy_json(&row, "last_dry_run_json"),
+                "last_dry_run_error": legacy_str(&row, "last_dry_run_error"),
+            }),
+            legacy_str(&row, "digest"),
+        )?;
+    }
+    tx.execute_batch(&format!("DROP TABLE {TABLE};"))
+}
+
+fn m004_import_evm(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    const TABLE: &str = "evm_pending_confirmations";
+    if !table_exists(tx, TABLE)? {
+        return Ok(());
+    }
+    for row in legacy_rows(tx, TABLE)? {
+        import_row(
+            tx,
+            &row,
+            PendingChain::Evm,
+            legacy_i64(&row, "chain_id").map(|c| c.to_string()),
+            None,
+            None,
+            json!({
+                "tx": legacy_json(&row, "tx_json"),
+                "raw_tx_prefix": legacy_str(&row, "raw_tx_prefix"),
+                "signed_at_ms": legacy_i64(&row, "signed_at_ms"),
+                "second_confirm_token": legacy_str(&row, "second_confirm_token"),
+                "second_confirmed": legacy_i64(&row, "second_confirmed") == Some(1),
+                "expected_spender": legacy_str(&row, "expected_spender"),
+                "required_allowance_raw": legacy_str(&row, "required_allowance_raw"),
+                "expected_token": legacy_str(&row, "expected_token"),
+                "approve_confirmation_id": legacy_str(&row, "approve_confirmation_id"),
+                "swap_confirmation_id": legacy_str(&row, "swap_confirmation_id"),
+            }),
+            legacy_str(&row, "tx_hash"),
+        )?;
+    }
+    tx.execute_batch(&format!("DROP TABLE {TABLE};"))
+}
+
+/// Owning MCP session of a row (see `session_scope`); NULL for rows created unscoped.
+fn m005_add_session_id(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "ALTER TABLE pending_confirmations ADD COLUMN session_id TEXT;
+         CREATE INDEX IF NOT EXISTS idx_pending_session ON pending_confirmations(session_id);",
+    )
+}
+
+// ---- Connection ----------------------------------------------------------------------------
+
+pub fn db_path() -> Result<std::path::PathBuf, ErrorData> {
+    Ok(crate::utils::data_dir::pending_db_path())
+}
+
+fn open(path: &std::path::Path) -> Result<rusqlite::Connection, ErrorData> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| db_err("Failed to create data dir", e))?;
+    }
+    let mut conn =
+        rusqlite::Connection::open(path).map_err(|e| db_err("Failed to open sqlite db", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| db_err("Failed to set sqlite busy_timeout", e))?;
+    apply_migrations(&mut conn, COMPONENT, MIGRATIONS)?;
+    Ok(conn)
+}
+
+pub fn connect() -> Result<rusqlite::Connection, ErrorData> {
+    let path = db_path()?;
+    let conn = open(&path)?;
+    if let Some(legacy) = crate::utils::data_dir::legacy_pending_db_path() {
+        // Best-effort: a legacy file we cannot read or rename must not block the store.
+        if let Err(e) = import_legacy_db(&conn, &path, &legacy) {
+            tracing::warn!(legacy = %legacy.display(), error = %e.message, "Legacy pending store import failed");
+        }
+    }
+    Ok(conn)
+}
+
+/// Merge a store left at `legacy` (older versions used `<cwd>/.data/pending.sqlite`) into
+/// `conn`, then rename it to `*.migrated` so it is imported once. Existing ids win. Returns the
+/// number of imported rows, or `None` when there was nothing to import.
+pub fn import_legacy_db(
+    conn: &rusqlite::Connection,
+    path: &std::path::Path,
+    legacy: &std::path::Path,
+) -> Result<Option<usize>, ErrorData> {
+    if !legacy.is_file() {
+        return Ok(None);
+    }
+    if let (Ok(a), Ok(b)) = (path.canonicalize(), legacy.canonicalize()) {
+        if a == b {
+            return Ok(None);
+        }
+    }
+
+    // Bring the old file to the current schema first (imports its per-chain tables too).
+    drop(open(legacy)?);
+
+    conn.execute(
+        "ATTACH DATABASE ?1 AS legacy",
+        [legacy.to_string_lossy().to_string()],
+    )
+    .map_err(|e| db_err("Failed to attach legacy pending store", e))?;
+    let imported = conn.execute(
+        &format!(
+            "INSERT OR IGNORE INTO main.pending_confirmations ({COLUMNS})
+             SELECT {COLUMNS} FROM legacy.pending_confirmations"
+        ),
+        [],
+    );
+    let _ = conn.execute_batch("DETACH DATABASE legacy");
+    let imported = imported.map_err(|e| db_err("Failed to import legacy pending store", e))?;
+
+    let mut migrated = legacy.as_os_str().to_owned();
+    migrated.push(".migrated");
+    std::fs::rename(legacy, &migrated)
+        .map_err(|e| db_err("Failed to rename legacy pending store", e))?;
+    Ok(Some(imported))
+}
+
+// ---- Rows ----------------------------------------------------------------------------------
+//
+// Inside a session scope (network transports) rows are stamped with the session and every
+// lookup below only sees that session's rows, so one client can neither list nor confirm
+// another's transactions. `sweep` and the spend ledger stay global.
+
+const COLUMNS: &str = "id, chain, network, status, created_at_ms, updated_at_ms, expires_at_ms, \
+                       tx_summary_hash, source_tool, summary_json, payload_json, tx_ref, last_error, spend_json";
+
+fn record_from_row(row: &rusqlite::Row) -> rusqlite::Result<PendingRecord> {
+    let chain: String = row.get(1)?;
+    let status: String = row.get(3)?;
+    let summary_json: Option<String> = row.get(9)?;
+    let payload_json: String = row.get(10)?;
+    Ok(PendingRecord {
+        id: row.get(0)?,
+        chain: PendingChain::parse(&chain).unwrap_or(PendingChain::Evm),
+        network: row.get(2)?,
+        status: PendingStatus::parse(&status).unwrap_or(PendingStatus::Pending),
+        created_at_ms: row.get::<_, i64>(4)?.max(0) as u64,
+        updated_at_ms: row.get::<_, i64>(5)?.max(0) as u64,
+        expires_at_ms: row.get::<_, i64>(6)?.max(0) as u64,
+        tx_summary_hash: row.get(7)?,
+        source_tool: row.get(8)?,
+        summary: summary_json
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<Value>(s).ok()),
+        payload: serde_json::from_str::<Value>(&payload_json).unwrap_or_else(|_| json!({})),
+        tx_ref: row.get(11)?,
+        last_error: row.get(12)?,
+        spend_json: row.get(13)?,
+    })
+}
+
+/// Insert (or replace) a row in status `pending`. An existing id is only replaced when it
+/// belongs to the current session; a row owned by another session is left untouched and the
+/// insert fails with `CONFIRMATION_ID_CONFLICT`.
+pub fn insert(conn: &rusqlite::Connection, p: &NewPending) -> Result<(), ErrorData> {
+    let summary_json = p
+        .summary
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "{}".to_string()));
+    let changed = conn
+        .execute(
+            "INSERT INTO pending_confirmations
+         (id, chain, network, status, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash,
+          source_tool, summary_json, payload_json, tx_ref, last_error, spend_json, session_id)
+         VALUES (?1, ?2, ?3, 'pending', ?4, ?5, ?6, ?7, ?8, ?9, ?10, NULL, NULL, NULL, ?11)
+         ON CONFLICT(id) DO UPDATE SET
+           chain=excluded.chain, network=excluded.network, status='pending',
+           created_at_ms=excluded.created_at_ms, updated_at_ms=excluded.updated_at_ms,
+           expires_at_ms=excluded.expires_at_ms, tx_summary_hash=excluded.tx_summary_hash,
+           source_tool=excluded.source_tool, summary_json=excluded.summary_json,
+           payload_json=excluded.payload_json, tx_ref=NULL, last_error=NULL, spend_json=NULL,
+           session_id=excluded.session_id
+         WHERE ?11 IS NULL OR pending_confirmations.session_id IS ?11",
+            rusqlite::params![
+                p.id,
+                p.chain.as_str(),
+                p.network,
+                p.created_at_ms as i64,
+                now_ms() as i64,
+                p.expires_at_ms as i64,
+                p.tx_summary_hash,
+                p.source_tool,
+                summary_json,
+                p.payload.to_string(),
+                session_scope::current(),
+            ],
+        )
+        .map_err(|e| db_err("Failed to insert pending confirmation", e))?;
+    if changed == 0 {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("Confirmation id is already in use"),
+            data: Some(json!({
+                "error_class": "CONFIRMATION_ID_CONFLICT",
+                "retryable": false,
+                "suggest_fix": "Re-run the build/send step to mint a fresh confirmation id",
+                "id": p.id
+            })),
+        });
+    }
+    Ok(())
+}
+
+pub fn get(conn: &rusqlite::Connection, id: &str) -> Result<Option<PendingRecord>, ErrorData> {
+    conn.query_row(
+        &format!(
+            "SELECT {COLUMNS} FROM pending_confirmations WHERE id=?1 AND {}",
+            in_session(2)
+        ),
+        rusqlite::params![id, session_scope::current()],
+        record_from_row,
+    )
+    .optional()
+    .map_err(|e| db_err("Failed to query pending confirmation", e))
+}
+
+/// Newest first.
+pub fn list(
+    conn: &rusqlite::Connection,
+    filter: &ListFilter,
+) -> Result<Vec<PendingRecord>, ErrorData> {
+    let mut sql = format!("SELECT {COLUMNS} FROM pending_confirmations");
+    let mut params: Vec<rusqlite::types::Value> = Vec::new();
+    let mut where_clauses: Vec<String> = Vec::new();
+    if let Some(session) = session_scope::current() {
+        where_clauses.push(format!("session_id = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(session));
+    }
+    if let Some(chain) = filter.chain {
+        where_clauses.push(format!("chain = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(chain.as_str().to_string()));
+    }
+    if let Some(network) = filter.network.as_deref() {
+        where_clauses.push(format!("network = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(network.to_string()));
+    }
+    if let Some(status) = filter.status {
+        where_clauses.push(format!("status = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(status.as_str().to_string()));
+    }
+    if !where_clauses.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&where_clauses.join(" AND "));
+    }
+    sql.push_str(&format!(
+        " ORDER BY created_at_ms DESC LIMIT ?{}",
+        params.len() + 1
+    ));
+    params.push(rusqlite::types::Value::Integer(filter.limit.max(1) as i64));
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| db_err("Failed to prepare pending list", e))?;
+    let rows = stmt
+        .query_map(rusqlite::params_from_iter(params), record_from_row)
+        .map_err(|e| db_err("Failed to list pending confirmations", e))?;
+    Ok(rows.flatten().collect())
+}
+
+/// Row counts per `(chain, status)`.
+pub fn counts(conn: &rusqlite::Connection) -> Result<Vec<(String, String, i64)>, ErrorData> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT chain, status, COUNT(1) FROM pending_confirmations WHERE {}
+             GROUP BY chain, status ORDER BY chain, status",
+            in_session(1)
+        ))
+        .map_err(|e| db_err("Failed to prepare pending counts", e))?;
+    let rows = stmt
+        .query_map([session_scope::current()], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .map_err(|e| db_err("Failed to count pending confirmations", e))?;
+    Ok(rows.flatten().collect())
+}
+
+/// SQL condition "row belongs to the current session", with the session bound at `?{param}`
+/// (NULL outside a scope matches every row).
+fn in_session(param: usize) -> String {
+    format!("(?{param} IS NULL OR session_id = ?{param})")
+}
+
+fn unknown_id(id: &str) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from("Unknown confirmation id"),
+        data: Some(json!({
+            "error_class": "UNKNOWN_CONFIRMATION_ID",
+            "retryable": false,
+            "suggest_fix": "Re-run the build/send step to create a new pending confirmation",
+            "id": id
+        })),
+    }
+}
+
+/// Move a row to `to`, enforcing the status machine. `tx_ref`/`last_error` are recorded when
+/// given; returning to `pending` clears both. The update is conditional on the status that was
+/// checked, so a concurrent change in between is reported instead of overwritten.
+pub fn transition(
+    conn: &rusqlite::Connection,
+    id: &str,
+    to: PendingStatus,
+    tx_ref: Option<&str>,
+    last_error: Option<&str>,
+) -> Result<PendingStatus, ErrorData> {
+    let current: Option<String> = conn
+        .query_row(
+            &format!(
+                "SELECT status FROM pending_confirmations WHERE id=?1 AND {}",
+                in_session(2)
+            ),
+            rusqlite::params![id, session_scope::current()],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| db_err("Failed to read pending status", e))?;
+    let Some(current) = current else {
+        return Err(unknown_id(id));
+    };
+    let from = PendingStatus::parse(&current).unwrap_or(PendingStatus::Pending);
+    if !from.can_transition_to(to) {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!(
+                "Pending confirmation {} cannot move from {} to {}",
+                id,
+                from.as_str(),
+                to.as_str()
+            )),
+            data: Some(json!({
+                "error_class": "INVALID_STATUS_TRANSITION",
+                "retryable": false,
+                "id": id,
+                "from": from.as_str(),
+                "to": to.as_str(),
+            })),
+        });
+    }
+
+    let reset = to == PendingStatus::Pending;
+    let changed = conn
+        .execute(
+            "UPDATE pending_confirmations
+             SET status=?3,
+                 tx_ref=CASE WHEN ?6 THEN NULL ELSE COALESCE(?4, tx_ref) END,
+                 last_error=CASE WHEN ?6 THEN NULL ELSE COALESCE(?5, last_error) END,
+                 updated_at_ms=?7
+             WHERE id=?1 AND status=?2",
+            rusqlite::params![
+                id,
+                current,
+                to.as_str(),
+                tx_ref,
+                last_error,
+                reset,
+                now_ms() as i64
+            ],
+        )
+        .map_err(|e| db_err("Failed to update pending status", e))?;
+    if changed == 0 {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!(
+                "Pending confirmation {} changed concurrently (was {})",
+                id, current
+            )),
+            data: Some(json!({
+                "error_class": "CONCURRENT_STATUS_CHANGE",
+                "retryable": true,
+                "id": id,
+                "from": current,
+                "to": to.as_str(),
+            })),
+        });
+    }
+    Ok(from)
+}
+
+/// Merge `patch` (object) into the row's payload.
+pub fn patch_payload(
+    conn: &rusqlite::Connection,
+    id: &str,
+    patch: &Value,
+) -> Result<(), ErrorData> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| db_err("Failed to start sqlite transaction", e))?;
+    let payload_json: Option<String> = tx
+        .query_row(
+            &format!(
+                "SELECT payload_json FROM pending_confirmations WHERE id=?1 AND {}",
+                in_session(2)
+            ),
+            rusqlite::params![id, session_scope::current()],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| db_err("Failed to read pending payload", e))?;
+    let Some(payload_json) = payload_json else {
+        return Err(unknown_id(id));
+    };
+    let mut payload = serde_json::from_str::<Value>(&payload_json).unwrap_or_else(|_| json!({}));
+    if let (Value::Object(dst), Value::Object(src)) = (&mut payload, patch) {
+        for (k, v) in src {
+            dst.insert(k.clone(), v.clone());
+        }
+    }
+    tx.execute(
+        "UPDATE pending_confirmations SET payload_json=?2, updated_at_ms=?3 WHERE id=?1",
+        rusqlite::params![id, payload.to_string(), now_ms() as i64],
+    )
+    .map_err(|e| db_err("Failed to update pending payload", e))?;
+    tx.commit()
+        .map_err(|e| db_err("Failed to commit pending payload", e))?;
+    Ok(())
+}
+
+/// Replace the transaction of a row (e.g. after a confirm-time re-quote) and put it back to
+/// `pending` with a fresh TTL and summary hash.
+pub fn requote(
+    conn: &rusqlite::Connection,
+    id: &str,
+    payload_patch: &Value,
+    expires_at_ms: u64,
+    tx_summary_hash: &str,
+) -> Result<(), ErrorData> {
+    transition(conn, id, PendingStatus::Pending, None, None)?;
+    patch_payload(conn, id, payload_patch)?;
+    conn.execute(
+        "UPDATE pending_confirmations SET expires_at_ms=?2, tx_summary_hash=?3 WHERE id=?1",
+        rusqlite::params![id, expires_at_ms as i64, tx_summary_hash],
+    )
+    .map_err(|e| db_err("Failed to update pending confirmation", e))?;
+    Ok(())
+}
+
+/// Book a spend on a row (spend-limit ledger) without changing the status.
+pub fn set_spend(conn: &rusqlite::Connection, id: &str, spend_json: &str) -> Result<(), ErrorData> {
+    conn.execute(
+        "UPDATE pending_confirmations SET spend_json=?2, updated_at_ms=?3 WHERE id=?1",
+        rusqlite::params![id, spend_json, now_ms() as i64],
+    )
+    .map_err(|e| db_err("Failed to record spend", e))?;
+    Ok(())
+}
+
+/// Drop a spend booked before broadcast (`spend_limits::reserve`) when nothing was sent.
+pub fn clear_spend(conn: &rusqlite::Connection, id: &str) -> Result<(), ErrorData> {
+    conn.execute(
+        "UPDATE pending_confirmations SET spend_json=NULL, updated_at_ms=?2 WHERE id=?1",
+        rusqlite::params![id, now_ms() as i64],
+    )
+    .map_err(|e| db_err("Failed to release spend", e))?;
+    Ok(())
+}
+
+pub fn delete(conn: &rusqlite::Connection, id: &str) -> Result<(), ErrorData> {
+    conn.execute(
+        &format!(
+            "DELETE FROM pending_confirmations WHERE id=?1 AND {}",
+            in_session(2)
+        ),
+        rusqlite::params![id, session_scope::current()],
+    )
+    .map_err(|e| db_err("Failed to delete pending confirmation", e))?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SweepResult {
+    pub expired: usize,
+    pub deleted: usize,
+}
+
+/// Expire rows that were never broadcast before their TTL, and delete rows past
+/// `HISTORY_RETENTION_MS` (rows carrying a spend stay for `SPEND_LEDGER_RETENTION_MS`).
+pub fn sweep(conn: &rusqlite::Connection, now_ms: u64) -> Result<SweepResult, ErrorData> {
+    let expired = conn
+        .execute(
+            "UPDATE pending_confirmations SET status='expired', updated_at_ms=?1
+             WHERE status IN ('pending', 'signed', 'failed') AND expires_at_ms < ?1",
+            [now_ms as i64],
+        )
+        .map_err(|e| db_err("Failed to expire pending confirmations", e))?;
+    let deleted = conn
+        .execute(
+            "DELETE FROM pending_confirmations
+             WHERE expires_at_ms < ?1 AND (spend_json IS NULL OR updated_at_ms < ?2)",
+            rusqlite::params![
+                now_ms.saturating_sub(HISTORY_RETENTION_MS) as i64,
+                now_ms.saturating_sub(SPEND_LEDGER_RETENTION_MS) as i64
+            ],
+        )
+        .map_err(|e| db_err("Failed to cleanup pending confirmations", e))?;
+    Ok(SweepResult { expired, deleted })
+}
+
+/// Delete rows last updated before `cutoff_ms`, optionally limited to a chain/network/status.
+/// Rows still inside the spend ledger are kept.
+pub fn purge(
+    conn: &rusqlite::Connection,
+    filter: &ListFilter,
+    cutoff_ms: u64,
+    now_ms: u64,
+) -> Result<usize, ErrorData> {
+    let mut sql = "DELETE FROM pending_confirmations
+                   WHERE updated_at_ms < ?1 AND (spend_json IS NULL OR updated_at_ms < ?2)"
+        .to_string();
+    let mut params: Vec<rusqlite::types::Value> = vec![
+        rusqlite::types::Value::Integer(cutoff_ms as i64),
+        rusqlite::types::Value::Integer(now_ms.saturating_sub(SPEND_LEDGER_RETENTION_MS) as i64),
+    ];
+    if let Some(session) = session_scope::current() {
+        sql.push_str(&format!(" AND session_id = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(session));
+    }
+    if let Some(chain) = filter.chain {
+        sql.push_str(&format!(" AND chain = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(chain.as_str().to_string()));
+    }
+    if let Some(network) = filter.network.as_deref() {
+        sql.push_str(&format!(" AND network = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(network.to_string()));
+    }
+    if let Some(status) = filter.status {
+        sql.push_str(&format!(" AND status = ?{}", params.len() + 1));
+        params.push(rusqlite::types::Value::Text(status.as_str().to_string()));
+    }
+    conn.execute(&sql, rusqlite::params_from_iter(params))
+        .map_err(|e| db_err("Failed to purge pending confirmations", e))
+}
+
+/// `(id, spend_json)` of rows that recorded a spend since `since_ms` and did not fail.
+pub fn ledger_rows(
+    conn: &rusqlite::Connection,
+    since_ms: u64,
+) -> Result<Vec<(String, String)>, ErrorData> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, spend_json FROM pending_confirmations
+             WHERE spend_json IS NOT NULL AND status <> 'failed' AND updated_at_ms >= ?1",
+        )
+        .map_err(|e| db_err("Failed to prepare spend ledger query", e))?;
+    let rows = stmt
+        .query_map([since_ms as i64], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| db_err("Failed to query spend ledger", e))?;
+    Ok(rows.flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mem() -> rusqlite::Connection {
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        apply_migrations(&mut conn, COMPONENT, MIGRATIONS).unwrap();
+        conn
+    }
+
+    fn new_row(conn: &rusqlite::Connection, id: &str, expires_at_ms: u64) {
+        insert(
+            conn,
+            &NewPending {
+                id,
+                chain: PendingChain::Solana,
+                network: Some("devnet"),
+                created_at_ms: 1,
+                expires_at_ms,
+                tx_summary_hash: "abc",
+                source_tool: Some("test"),
+                summary: None,
+                payload: &json!({"tx_base64": "AA=="}),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn state_machine_rejects_backwards_moves() {
+        use PendingStatus::*;
+        assert!(Pending.can_transition_to(Signed));
+        assert!(Signed.can_transition_to(Sent));
+        assert!(Sent.can_transition_to(Confirmed));
+        assert!(Failed.can_transition_to(Pending));
+        assert!(!Sent.can_transition_to(Pending));
+        assert!(!Confirmed.can_transition_to(Failed));
+        assert!(!Expired.can_transition_to(Pending));
+        assert_eq!(PendingStatus::parse("consumed"), Some(Signed));
+    }
+
+    #[test]
+    fn transition_enforces_machine_and_records_refs() {
+        let conn = mem();
+        new_row(&conn, "a", u64::MAX / 4);
+        transition(&conn, "a", PendingStatus::Signed, None, None).unwrap();
+        transition(&conn, "a", PendingStatus::Sent, Some("sig1"), None).unwrap();
+        let err = transition(&conn, "a", PendingStatus::Pending, None, None).unwrap_err();
+        assert_eq!(
+            err.data.unwrap()["error_class"],
+            "INVALID_STATUS_TRANSITION"
+        );
+        let r = get(&conn, "a").unwrap().unwrap();
+        assert_eq!(r.status, PendingStatus::Sent);
+        assert_eq!(r.tx_ref.as_deref(), Some("sig1"));
+        assert!(transition(&conn, "missing", PendingStatus::Sent, None, None).is_err());
+    }
+
+    #[test]
+    fn sweep_expires_then_deletes_but_keeps_ledger_rows() {
+        let conn = mem();
+        let now = 10 * HISTORY_RETENTION_MS;
+        new_row(&conn, "stale", now - 1);
+        new_row(&conn, "old", 1);
+        new_row(&conn, "spent", 1);
+        set_spend(&conn, "spent", "[]").unwrap();
+
+        let res = sweep(&conn, now).unwrap();
+        assert_eq!(res.deleted, 1);
+        assert_eq!(
+            get(&conn, "stale").unwrap().unwrap().status,
+            PendingStatus::Expired
+        );
+        assert!(get(&conn, "old").unwrap().is_none());
+        assert!(get(&conn, "spent").unwrap().is_some());
+    }
+
+    #[test]
+    fn sessions_only_see_their_own_rows() {
+        let conn = mem();
+        let a = Some("sess_a".to_string());
+        let b = Some("sess_b".to_string());
+        session_scope::sync_scope(a.clone(), || new_row(&conn, "a1", u64::MAX / 4));
+        session_scope::sync_scope(b.clone(), || new_row(&conn, "b1", u64::MAX / 4));
+        new_row(&conn, "local", u64::MAX / 4);
+
+        let all = ListFilter {
+            limit: 10,
+            ..Default::default()
+        };
+        session_scope::sync_scope(a.clone(), || {
+            let ids: Vec<String> = list(&conn, &all)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.id)
+                .collect();
+            assert_eq!(ids, vec!["a1".to_string()]);
+            assert!(get(&conn, "b1").unwrap().is_none());
+            assert!(get(&conn, "local").unwrap().is_none());
+            let err = transition(&conn, "b1", PendingStatus::Signed, None, None).unwrap_err();
+            assert_eq!(err.data.unwrap()["error_class"], "UNKNOWN_CONFIRMATION_ID");
+            delete(&conn, "b1").unwrap();
+            assert_eq!(counts(&conn).unwrap().iter().map(|c| c.2).sum::<i64>(), 1);
+        });
+        // Unscoped (stdio) sees everything, and b's row survived a's delete.
+        assert_eq!(list(&conn, &all).unwrap().len(), 3);
+        assert!(get(&conn, "b1").unwrap().is_some());
+    }
+
+    #[test]
+    fn insert_cannot_replace_another_sessions_row() {
+        let conn = mem();
+        let a = Some("sess_a".to_string());
+        let b = Some("sess_b".to_string());
+        session_scope::sync_scope(a.clone(), || new_row(&conn, "shared", u64::MAX / 4));
+        transition(&conn, "shared", PendingStatus::Signed, None, None).unwrap();
+
+        let theirs = NewPending {
+            id: "shared",
+            chain: PendingChain::Evm,
+            network: None,
+            created_at_ms: 2,
+            expires_at_ms: u64::MAX / 4,
+            tx_summary_hash: "evil",
+            source_tool: None,
+            summary: None,
+            payload: &json!({}),
+        };
+        let err = session_scope::sync_scope(b.clone(), || insert(&conn, &theirs)).unwrap_err();
+        assert_eq!(err.data.unwrap()["error_class"], "CONFIRMATION_ID_CONFLICT");
+
+        let row = session_scope::sync_scope(a.clone(), || get(&conn, "shared"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.tx_summary_hash, "abc");
+        assert_eq!(row.status, PendingStatus::Signed);
+
+        // The owning session may still re-create its own row.
+        session_scope::sync_scope(a.clone(), || new_row(&conn, "shared", u64::MAX / 4));
+        let row = session_scope::sync_scope(a, || get(&conn, "shared"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.status, PendingStatus::Pending);
+    }
+
+    #[test]
+    fn legacy_tables_are_imported_once() {
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE evm_pending_confirmations (
+               id TEXT PRIMARY KEY, chain_id INTEGER NOT NULL, tx_json TEXT NOT NULL,
+               created_at_ms INTEGER NOT NULL, expires_at_ms INTEGER NOT NULL,
+               tx_summary_hash TEXT NOT NULL, status TEXT, tx_hash TEXT, second_confirmed INTEGER);
+             INSERT INTO evm_pending_confirmations VALUES
+               ('evm_1', 8453, '{\"chain_id\":8453}', 5, 50, '0xh', 'consumed', NULL, 1),
+               ('evm_2', 1, '{}', 6, 60, '0xi', 'skipped', NULL, NULL);",
+        )
+        .unwrap();
+        let applied = apply_migrations(&mut conn, COMPONENT, MIGRATIONS).unwrap();
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert!(apply_migrations(&mut conn, COMPONENT, MIGRATIONS)
+            .unwrap()
+            .is_empty());
+
+        let r = get(&conn, "evm_1").unwrap().unwrap();
+        assert_eq!(r.chain, PendingChain::Evm);
+        assert_eq!(r.network.as_deref(), Some("8453"));
+        assert_eq!(r.status, PendingStatus::Signed);
+        assert_eq!(r.updated_at_ms, 5);
+        assert_eq!(r.payload["tx"]["chain_id"], 8453);
+        assert_eq!(r.payload["second_confirmed"], true);
+        assert_eq!(
+            get(&conn, "evm_2").unwrap().unwrap().status,
+            PendingStatus::Expired
+        );
+        assert!(!table_exists(&conn.transaction().unwrap(), "evm_pending_confirmations").unwrap());
+    }
+}
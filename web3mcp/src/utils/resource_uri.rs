@@ -0,0 +1,164 @@
+//! `web3mcp://` URIs of the MCP resources (runs, pending confirmations, registries).
+//!
+//! Path segments are store keys (run ids, confirmation ids, program ids, addresses) and are
+//! restricted to `[A-Za-z0-9_.-]` so a URI can never point outside its store.
+
+pub const SCHEME: &str = "web3mcp://";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceUri {
+    /// Recent W3RT runs (run index).
+    Runs,
+    Run { run_id: String },
+    /// Pending confirmations of every chain.
+    Pending,
+    PendingItem { id: String },
+    /// Solana IDL registry index.
+    SolanaIdls,
+    SolanaIdl { program_id: String, name: String },
+    /// EVM ABI registry index.
+    EvmAbis,
+    EvmAbi { chain_id: u64, address: String },
+    /// Sui dapp manifest (`SUI_DAPP_MANIFEST` / `./dapps.json`).
+    Dapps,
+}
+
+/// `(uri_template, name, description)` of the parameterised resources.
+pub const TEMPLATES: &[(&str, &str, &str)] = &[
+    (
+        "web3mcp://runs/{run_id}",
+        "W3RT run",
+        "Run state and stage artifacts of a workflow run",
+    ),
+    (
+        "web3mcp://pending/{id}",
+        "Pending confirmation",
+        "One pending confirmation (any chain), including its payload",
+    ),
+    (
+        "web3mcp://solana/idls/{program_id}/{name}",
+        "Solana IDL",
+        "An IDL from the Solana IDL registry",
+    ),
+    (
+        "web3mcp://evm/abis/{chain_id}/{address}",
+        "EVM contract ABI",
+        "A registered contract from the EVM ABI registry",
+    ),
+];
+
+fn segment_ok(s: &str) -> bool {
+    !s.is_empty()
+        && s != "."
+        && s != ".."
+        && s
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+impl ResourceUri {
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let path = uri
+            .trim()
+            .strip_prefix(SCHEME)
+            .ok_or_else(|| format!("resource URIs start with {}", SCHEME))?;
+        let parts: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+        if parts.iter().skip(1).any(|p| !segment_ok(p)) {
+            return Err(format!("invalid resource URI: {}", uri));
+        }
+        let parsed = match parts.as_slice() {
+            ["runs"] => Self::Runs,
+            ["runs", run_id] => Self::Run {
+                run_id: run_id.to_string(),
+            },
+            ["pending"] => Self::Pending,
+            ["pending", id] => Self::PendingItem { id: id.to_string() },
+            ["solana", "idls"] => Self::SolanaIdls,
+            ["solana", "idls", program_id, name] => Self::SolanaIdl {
+                program_id: program_id.to_string(),
+                name: name.to_string(),
+            },
+            ["evm", "abis"] => Self::EvmAbis,
+            ["evm", "abis", chain_id, address] => Self::EvmAbi {
+                chain_id: chain_id
+                    .parse()
+                    .map_err(|_| format!("invalid chain id in resource URI: {}", uri))?,
+                address: address.to_lowercase(),
+            },
+            ["sui", "dapps"] => Self::Dapps,
+            _ => return Err(format!("unknown resource: {}", uri)),
+        };
+        Ok(parsed)
+    }
+
+    pub fn uri(&self) -> String {
+        let path = match self {
+            Self::Runs => "runs".to_string(),
+            Self::Run { run_id } => format!("runs/{}", run_id),
+            Self::Pending => "pending".to_string(),
+            Self::PendingItem { id } => format!("pending/{}", id),
+            Self::SolanaIdls => "solana/idls".to_string(),
+            Self::SolanaIdl { program_id, name } => format!("solana/idls/{}/{}", program_id, name),
+            Self::EvmAbis => "evm/abis".to_string(),
+            Self::EvmAbi { chain_id, address } => format!("evm/abis/{}/{}", chain_id, address),
+            Self::Dapps => "sui/dapps".to_string(),
+        };
+        format!("{}{}", SCHEME, path)
+    }
+}
+
+impl std::fmt::Display for ResourceUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.uri())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uris_round_trip() {
+        for r in [
+            ResourceUri::Runs,
+            ResourceUri::Run {
+                run_id: "run_1700000000000_1234".to_string(),
+            },
+            ResourceUri::Pending,
+            ResourceUri::PendingItem {
+                id: "solana_confirm_0123456789abcdef".to_string(),
+            },
+            ResourceUri::SolanaIdls,
+            ResourceUri::SolanaIdl {
+                program_id: "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4".to_string(),
+                name: "jupiter".to_string(),
+            },
+            ResourceUri::EvmAbis,
+            ResourceUri::EvmAbi {
+                chain_id: 8453,
+                address: "0x833589fcd6edb6e08f4c7c32d4f71b54bda02913".to_string(),
+            },
+            ResourceUri::Dapps,
+        ] {
+            assert_eq!(ResourceUri::parse(&r.uri()), Ok(r.clone()), "{}", r);
+        }
+        assert_eq!(
+            ResourceUri::parse("web3mcp://evm/abis/8453/0xABC/"),
+            Ok(ResourceUri::EvmAbi {
+                chain_id: 8453,
+                address: "0xabc".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn bad_uris_are_rejected() {
+        assert!(ResourceUri::parse("file:///etc/passwd").is_err());
+        assert!(ResourceUri::parse("web3mcp://runs/..").is_err());
+        assert!(ResourceUri::parse("web3mcp://runs/a%2Fb").is_err());
+        assert!(ResourceUri::parse("web3mcp://solana/idls/x/../y").is_err());
+        assert!(ResourceUri::parse("web3mcp://evm/abis/base/0xabc").is_err());
+        assert!(ResourceUri::parse("web3mcp://runs/a/b").is_err());
+        assert!(ResourceUri::parse("web3mcp://nope").is_err());
+    }
+}
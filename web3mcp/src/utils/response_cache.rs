@@ -0,0 +1,733 @@
+//! Shared cache for immutable and slow-changing RPC reads.
+//!
+//! Entries are JSON values keyed by category + key (keys carry the chain and a redacted RPC
+//! URL or network, e.g. `sui:https://fullnode.mainnet.sui.io:443:0x2`). Each category has its
+//! own TTL: finalized transactions and Move package modules never expire, token lists and
+//! token metadata last minutes, balances seconds. `WEB3MCP_CACHE_MODE` selects `memory`
+//! (default), `sqlite` (also persisted to `<data_dir>/cache/responses.sqlite`, so entries
+//! survive restarts) or `off`. A successful broadcasting tool call drops every cached balance.
+
+use crate::utils::pending_store::{apply_migrations, Migration};
+use rmcp::model::{ErrorCode, ErrorData};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+pub const COMPONENT: &str = "response_cache";
+pub const MODE_ENV: &str = "WEB3MCP_CACHE_MODE";
+pub const MAX_ENTRIES_ENV: &str = "WEB3MCP_CACHE_MAX_ENTRIES";
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CacheCategory {
+    /// Finalized transactions / receipts.
+    FinalizedTx,
+    /// Normalized Move modules of a package (packages are immutable; upgrades get a new id).
+    MoveModules,
+    /// Mint decimals, ERC20 name / symbol / decimals.
+    TokenMetadata,
+    /// Token lists (Jupiter verified tokens).
+    TokenList,
+    Balance,
+}
+
+impl CacheCategory {
+    pub const ALL: [CacheCategory; 5] = [
+        CacheCategory::FinalizedTx,
+        CacheCategory::MoveModules,
+        CacheCategory::TokenMetadata,
+        CacheCategory::TokenList,
+        CacheCategory::Balance,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CacheCategory::FinalizedTx => "finalized_tx",
+            CacheCategory::MoveModules => "move_modules",
+            CacheCategory::TokenMetadata => "token_metadata",
+            CacheCategory::TokenList => "token_list",
+            CacheCategory::Balance => "balance",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s.trim().to_lowercase())
+    }
+
+    pub fn names() -> String {
+        Self::ALL
+            .iter()
+            .map(|c| c.as_str())
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    /// Env var overriding the TTL (seconds); `None` for categories that never expire.
+    pub fn ttl_env(self) -> Option<&'static str> {
+        match self {
+            CacheCategory::FinalizedTx | CacheCategory::MoveModules => None,
+            CacheCategory::TokenMetadata => Some("WEB3MCP_CACHE_TTL_TOKEN_METADATA_SECS"),
+            CacheCategory::TokenList => Some("WEB3MCP_CACHE_TTL_TOKEN_LIST_SECS"),
+            CacheCategory::Balance => Some("WEB3MCP_CACHE_TTL_BALANCE_SECS"),
+        }
+    }
+
+    /// `None`: never expires.
+    pub fn default_ttl_secs(self) -> Option<u64> {
+        match self {
+            CacheCategory::FinalizedTx | CacheCategory::MoveModules => None,
+            CacheCategory::TokenMetadata => Some(60 * 60),
+            CacheCategory::TokenList => Some(10 * 60),
+            CacheCategory::Balance => Some(10),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMode {
+    Off,
+    Memory,
+    Sqlite,
+}
+
+impl CacheMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CacheMode::Off => "off",
+            CacheMode::Memory => "memory",
+            CacheMode::Sqlite => "sqlite",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "off" => Some(CacheMode::Off),
+            "memory" => Some(CacheMode::Memory),
+            "sqlite" => Some(CacheMode::Sqlite),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheSettings {
+    pub mode: CacheMode,
+    pub max_entries: usize,
+    /// Per category; `None` never expires, `Some(0)` disables caching of that category.
+    pub ttl_secs: BTreeMap<CacheCategory, Option<u64>>,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self {
+            mode: CacheMode::Memory,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            ttl_secs: CacheCategory::ALL
+                .into_iter()
+                .map(|c| (c, c.default_ttl_secs()))
+                .collect(),
+        }
+    }
+}
+
+impl CacheSettings {
+    pub fn from_config() -> Self {
+        let var = |k: &str| crate::utils::config::var(k).ok();
+        let mut s = Self::default();
+        if let Some(mode) = var(MODE_ENV).and_then(|v| CacheMode::parse(&v)) {
+            s.mode = mode;
+        }
+        if let Some(n) = var(MAX_ENTRIES_ENV).and_then(|v| v.trim().parse().ok()) {
+            s.max_entries = n;
+        }
+        for c in CacheCategory::ALL {
+            if let Some(secs) = c
+                .ttl_env()
+                .and_then(var)
+                .and_then(|v| v.trim().parse::<u64>().ok())
+            {
+                s.ttl_secs.insert(c, Some(secs));
+            }
+        }
+        s
+    }
+
+    fn ttl_ms(&self, category: CacheCategory) -> Option<u64> {
+        self.ttl_secs
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| category.default_ttl_secs())
+            .map(|s| s.saturating_mul(1000))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    stored_ms: u64,
+    expires_ms: Option<u64>,
+}
+
+impl Entry {
+    fn is_live(&self, now_ms: u64) -> bool {
+        self.expires_ms.is_none_or(|e| now_ms < e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    hits: u64,
+    misses: u64,
+    stores: u64,
+    evictions: u64,
+}
+
+type Key = (CacheCategory, String);
+
+pub struct ResponseCache {
+    settings: CacheSettings,
+    entries: Mutex<HashMap<Key, Entry>>,
+    counters: Mutex<BTreeMap<CacheCategory, Counters>>,
+    db: Option<Mutex<rusqlite::Connection>>,
+    db_path: Option<PathBuf>,
+}
+
+fn db_err(ctx: &str, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("{}: {}", ctx, e)),
+        data: None,
+    }
+}
+
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "create_response_cache",
+    up: m001_create_table,
+}];
+
+fn m001_create_table(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS response_cache (
+           category TEXT NOT NULL,
+           key TEXT NOT NULL,
+           value_json TEXT NOT NULL,
+           stored_ms INTEGER NOT NULL,
+           expires_ms INTEGER,
+           PRIMARY KEY (category, key)
+         );
+         CREATE INDEX IF NOT EXISTS idx_response_cache_expires ON response_cache(expires_ms);",
+    )
+}
+
+/// Open (and migrate) the persistent cache at `path`, dropping expired rows.
+pub fn open_db(path: &std::path::Path, now_ms: u64) -> Result<rusqlite::Connection, ErrorData> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| db_err("Failed to create cache dir", e))?;
+    }
+    let mut conn =
+        rusqlite::Connection::open(path).map_err(|e| db_err("Failed to open sqlite db", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| db_err("Failed to set sqlite busy_timeout", e))?;
+    apply_migrations(&mut conn, COMPONENT, MIGRATIONS)?;
+    conn.execute(
+        "DELETE FROM response_cache WHERE expires_ms IS NOT NULL AND expires_ms <= ?1",
+        [now_ms as i64],
+    )
+    .map_err(|e| db_err("Failed to purge expired cache rows", e))?;
+    Ok(conn)
+}
+
+pub fn db_path() -> PathBuf {
+    crate::utils::data_dir::cache_dir().join("responses.sqlite")
+}
+
+impl ResponseCache {
+    /// `db` is only used in `sqlite` mode.
+    pub fn new(settings: CacheSettings, db: Option<(rusqlite::Connection, PathBuf)>) -> Self {
+        let (db, db_path) = match db {
+            Some((conn, path)) if settings.mode == CacheMode::Sqlite => {
+                (Some(Mutex::new(conn)), Some(path))
+            }
+            _ => (None, None),
+        };
+        Self {
+            settings,
+            entries: Mutex::new(HashMap::new()),
+            counters: Mutex::new(BTreeMap::new()),
+            db,
+            db_path,
+        }
+    }
+
+    /// Settings from the config; falls back to memory when the sqlite file cannot be opened.
+    pub fn from_config(now_ms: u64) -> Self {
+        let mut settings = CacheSettings::from_config();
+        let mut db = None;
+        if settings.mode == CacheMode::Sqlite {
+            let path = db_path();
+            match open_db(&path, now_ms) {
+                Ok(conn) => db = Some((conn, path)),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e.message, "Response cache falls back to memory");
+                    settings.mode = CacheMode::Memory;
+                }
+            }
+        }
+        Self::new(settings, db)
+    }
+
+    pub fn settings(&self) -> &CacheSettings {
+        &self.settings
+    }
+
+    fn enabled(&self, category: CacheCategory) -> bool {
+        self.settings.mode != CacheMode::Off && self.settings.ttl_ms(category) != Some(0)
+    }
+
+    fn count(&self, category: CacheCategory, f: impl FnOnce(&mut Counters)) {
+        if let Ok(mut c) = self.counters.lock() {
+            f(c.entry(category).or_default());
+        }
+    }
+
+    pub fn get(&self, category: CacheCategory, key: &str, now_ms: u64) -> Option<Value> {
+        if !self.enabled(category) {
+            return None;
+        }
+        let k = (category, key.to_string());
+        let found = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            match entries.get(&k) {
+                Some(e) if e.is_live(now_ms) => Some(e.value.clone()),
+                Some(_) => {
+                    entries.remove(&k);
+                    None
+                }
+                None => None,
+            }
+        };
+        let found = found.or_else(|| {
+            let entry = self.db_get(category, key, now_ms)?;
+            let value = entry.value.clone();
+            self.insert_memory(k, entry, now_ms);
+            Some(value)
+        });
+        self.count(category, |c| {
+            if found.is_some() {
+                c.hits += 1
+            } else {
+                c.misses += 1
+            }
+        });
+        found
+    }
+
+    pub fn put(&self, category: CacheCategory, key: &str, value: Value, now_ms: u64) {
+        if !self.enabled(category) {
+            return;
+        }
+        let entry = Entry {
+            value,
+            stored_ms: now_ms,
+            expires_ms: self
+                .settings
+                .ttl_ms(category)
+                .map(|ttl| now_ms.saturating_add(ttl)),
+        };
+        self.db_put(category, key, &entry);
+        self.insert_memory((category, key.to_string()), entry, now_ms);
+        self.count(category, |c| c.stores += 1);
+    }
+
+    fn insert_memory(&self, key: Key, entry: Entry, now_ms: u64) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(key, entry);
+        let max = self.settings.max_entries.max(1);
+        if entries.len() <= max {
+            return;
+        }
+        // Drop expired entries, then the oldest tenth.
+        let mut evicted: Vec<CacheCategory> = Vec::new();
+        entries.retain(|(c, _), e| {
+            let live = e.is_live(now_ms);
+            if !live {
+                evicted.push(*c);
+            }
+            live
+        });
+        if entries.len() > max {
+            let mut by_age: Vec<(u64, Key)> = entries
+                .iter()
+                .map(|(k, e)| (e.stored_ms, k.clone()))
+                .collect();
+            by_age.sort();
+            let drop = entries.len() - max + max / 10;
+            for (_, k) in by_age.into_iter().take(drop) {
+                entries.remove(&k);
+                evicted.push(k.0);
+            }
+        }
+        drop(entries);
+        for c in evicted {
+            self.count(c, |n| n.evictions += 1);
+        }
+    }
+
+    fn db_get(&self, category: CacheCategory, key: &str, now_ms: u64) -> Option<Entry> {
+        use rusqlite::OptionalExtension as _;
+        let db = self.db.as_ref()?.lock().ok()?;
+        let row: Option<(String, i64, Option<i64>)> = db
+            .query_row(
+                "SELECT value_json, stored_ms, expires_ms FROM response_cache WHERE category=?1 AND key=?2",
+                rusqlite::params![category.as_str(), key],
+                |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)),
+            )
+            .optional()
+            .ok()
+            .flatten();
+        let (value_json, stored_ms, expires_ms) = row?;
+        let entry = Entry {
+            value: serde_json::from_str(&value_json).ok()?,
+            stored_ms: stored_ms as u64,
+            expires_ms: expires_ms.map(|e| e as u64),
+        };
+        entry.is_live(now_ms).then_some(entry)
+    }
+
+    fn db_put(&self, category: CacheCategory, key: &str, entry: &Entry) {
+        let Some(db) = self.db.as_ref() else {
+            return;
+        };
+        let Ok(db) = db.lock() else {
+            return;
+        };
+        let res = db.execute(
+            "INSERT OR REPLACE INTO response_cache (category, key, value_json, stored_ms, expires_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![
+                category.as_str(),
+                key,
+                entry.value.to_string(),
+                entry.stored_ms as i64,
+                entry.expires_ms.map(|e| e as i64)
+            ],
+        );
+        if let Err(e) = res {
+            tracing::warn!(error = %e, "Failed to persist response cache entry");
+        }
+    }
+
+    /// Remove entries of `category` (all when `None`) whose key starts with `key_prefix`.
+    /// Returns how many were removed (memory and sqlite rows counted once per key).
+    pub fn clear(&self, category: Option<CacheCategory>, key_prefix: Option<&str>) -> usize {
+        let matches = |c: CacheCategory, k: &str| {
+            category.is_none_or(|want| want == c) && key_prefix.is_none_or(|p| k.starts_with(p))
+        };
+        let mut removed: std::collections::HashSet<Key> = std::collections::HashSet::new();
+        {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            entries.retain(|(c, k), _| {
+                let hit = matches(*c, k);
+                if hit {
+                    removed.insert((*c, k.clone()));
+                }
+                !hit
+            });
+        }
+        if let Some(Ok(db)) = self.db.as_ref().map(|d| d.lock()) {
+            let rows: Vec<(String, String)> = db
+                .prepare("SELECT category, key FROM response_cache")
+                .and_then(|mut st| {
+                    st.query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?
+                        .collect::<rusqlite::Result<Vec<_>>>()
+                })
+                .unwrap_or_default();
+            for (c, k) in rows {
+                let Some(cat) = CacheCategory::parse(&c) else {
+                    continue;
+                };
+                if matches(cat, &k) {
+                    let _ = db.execute(
+                        "DELETE FROM response_cache WHERE category=?1 AND key=?2",
+                        rusqlite::params![c, k],
+                    );
+                    removed.insert((cat, k));
+                }
+            }
+        }
+        removed.len()
+    }
+
+    pub fn stats(&self, now_ms: u64) -> Value {
+        let counters = self.counters.lock().map(|c| c.clone()).unwrap_or_default();
+        let mut live: BTreeMap<CacheCategory, (u64, u64)> = BTreeMap::new();
+        if let Ok(entries) = self.entries.lock() {
+            for ((c, _), e) in entries.iter().filter(|(_, e)| e.is_live(now_ms)) {
+                let slot = live.entry(*c).or_default();
+                slot.0 += 1;
+                slot.1 += e.value.to_string().len() as u64;
+            }
+        }
+        let mut persisted: BTreeMap<String, u64> = BTreeMap::new();
+        if let Some(Ok(db)) = self.db.as_ref().map(|d| d.lock()) {
+            let rows: Vec<(String, i64)> = db
+                .prepare(
+                    "SELECT category, COUNT(*) FROM response_cache
+                     WHERE expires_ms IS NULL OR expires_ms > ?1 GROUP BY category",
+                )
+                .and_then(|mut st| {
+                    st.query_map([now_ms as i64], |r| Ok((r.get(0)?, r.get(1)?)))?
+                        .collect::<rusqlite::Result<Vec<_>>>()
+                })
+                .unwrap_or_default();
+            persisted = rows.into_iter().map(|(c, n)| (c, n as u64)).collect();
+        }
+
+        let mut categories = Map::new();
+        let (mut hits, mut misses) = (0, 0);
+        for c in CacheCategory::ALL {
+            let n = counters.get(&c).copied().unwrap_or_default();
+            let (entries, bytes) = live.get(&c).copied().unwrap_or_default();
+            hits += n.hits;
+            misses += n.misses;
+            let lookups = n.hits + n.misses;
+            categories.insert(
+                c.as_str().to_string(),
+                json!({
+                    "ttl_secs": self.settings.ttl_ms(c).map(|ms| ms / 1000),
+                    "entries": entries,
+                    "approx_bytes": bytes,
+                    "persisted_entries": self.db.as_ref().map(|_| persisted.get(c.as_str()).copied().unwrap_or(0)),
+                    "hits": n.hits,
+                    "misses": n.misses,
+                    "hit_rate": if lookups > 0 { Some(n.hits as f64 / lookups as f64) } else { None },
+                    "stores": n.stores,
+                    "evictions": n.evictions,
+                }),
+            );
+        }
+        json!({
+            "mode": self.settings.mode.as_str(),
+            "sqlite_path": self.db_path.as_ref().map(|p| p.display().to_string()),
+            "max_entries": self.settings.max_entries,
+            "hits": hits,
+            "misses": misses,
+            "categories": categories,
+        })
+    }
+}
+
+pub fn global() -> &'static ResponseCache {
+    static CACHE: OnceLock<ResponseCache> = OnceLock::new();
+    CACHE.get_or_init(|| ResponseCache::from_config(crate::utils::pending_store::now_ms()))
+}
+
+/// Return the cached value for `key`, else run `fetch` and cache its result.
+pub async fn cached<T, F, Fut>(category: CacheCategory, key: &str, fetch: F) -> Result<T, ErrorData>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, ErrorData>>,
+{
+    cached_if(category, key, fetch, |_| true).await
+}
+
+/// Like [`cached`], but only results for which `keep` holds are stored (e.g. finalized txs).
+pub async fn cached_if<T, F, Fut>(
+    category: CacheCategory,
+    key: &str,
+    fetch: F,
+    keep: impl FnOnce(&T) -> bool,
+) -> Result<T, ErrorData>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, ErrorData>>,
+{
+    let cache = global();
+    let now = crate::utils::pending_store::now_ms();
+    if let Some(v) = cache.get(category, key, now) {
+        match serde_json::from_value::<T>(v) {
+            Ok(hit) => return Ok(hit),
+            // Shape changed since the entry was written: refetch and overwrite it.
+            Err(e) => tracing::debug!(key, error = %e, "Ignoring undecodable cache entry"),
+        }
+    }
+    let value = fetch().await?;
+    if keep(&value) {
+        if let Ok(v) = serde_json::to_value(&value) {
+            cache.put(category, key, v, crate::utils::pending_store::now_ms());
+        }
+    }
+    Ok(value)
+}
+
+/// Drop every entry of `category` (e.g. balances after a broadcast).
+pub fn invalidate(category: CacheCategory) -> usize {
+    global().clear(Some(category), None)
+}
+
+/// Tools that may land a transaction; a successful call drops every cached balance.
+const BROADCAST_TOOLS: &[&str] = &[
+    "execute_*",
+    "*_execute*",
+    "*_send_*",
+    "*_confirm_execution",
+    "solana_confirm_transaction",
+    "w3rt_run_workflow*",
+];
+
+pub fn is_broadcast_tool(tool: &str) -> bool {
+    BROADCAST_TOOLS
+        .iter()
+        .any(|p| crate::utils::tool_profiles::glob_match(p, tool))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory(max_entries: usize) -> ResponseCache {
+        ResponseCache::new(
+            CacheSettings {
+                max_entries,
+                ..Default::default()
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn broadcast_tools_are_recognised() {
+        for tool in [
+            "execute_transfer_sui",
+            "evm_execute_erc20_transfer",
+            "evm_send_raw_transaction",
+            "solana_send_transaction",
+            "solana_confirm_transaction",
+            "sui_confirm_execution",
+            "sign_and_execute_tx_data",
+            "w3rt_run_workflow_v0",
+        ] {
+            assert!(is_broadcast_tool(tool), "{tool}");
+        }
+        for tool in [
+            "evm_get_balance",
+            "solana_get_transaction",
+            "system_list_pending_confirmations",
+            "evm_wait_for_confirmations",
+        ] {
+            assert!(!is_broadcast_tool(tool), "{tool}");
+        }
+    }
+
+    #[test]
+    fn ttls_follow_the_category() {
+        let cache = memory(100);
+        cache.put(
+            CacheCategory::MoveModules,
+            "sui:m:0x2",
+            json!({"coin": {}}),
+            0,
+        );
+        cache.put(CacheCategory::Balance, "sol:a", json!(5), 0);
+
+        assert_eq!(
+            cache.get(CacheCategory::Balance, "sol:a", 9_999),
+            Some(json!(5))
+        );
+        assert_eq!(cache.get(CacheCategory::Balance, "sol:a", 10_000), None);
+        assert!(cache
+            .get(CacheCategory::MoveModules, "sui:m:0x2", u64::MAX / 2)
+            .is_some());
+        // Same key, other category.
+        assert_eq!(cache.get(CacheCategory::TokenList, "sui:m:0x2", 0), None);
+
+        let stats = cache.stats(0);
+        assert_eq!(stats["categories"]["balance"]["hits"], 1);
+        assert_eq!(stats["categories"]["balance"]["misses"], 1);
+        assert_eq!(stats["categories"]["move_modules"]["ttl_secs"], Value::Null);
+        assert_eq!(stats["categories"]["move_modules"]["entries"], 1);
+    }
+
+    #[test]
+    fn clear_by_category_and_prefix_and_evict_oldest() {
+        let cache = memory(100);
+        cache.put(CacheCategory::TokenMetadata, "evm:1:0xa", json!(1), 0);
+        cache.put(CacheCategory::TokenMetadata, "evm:8453:0xa", json!(2), 0);
+        cache.put(CacheCategory::Balance, "evm:1:0xa", json!(3), 0);
+        assert_eq!(
+            cache.clear(Some(CacheCategory::TokenMetadata), Some("evm:1:")),
+            1
+        );
+        assert!(cache
+            .get(CacheCategory::TokenMetadata, "evm:8453:0xa", 0)
+            .is_some());
+        assert_eq!(cache.clear(None, None), 2);
+
+        let small = memory(10);
+        for i in 0..11u64 {
+            small.put(CacheCategory::FinalizedTx, &format!("tx{}", i), json!(i), i);
+        }
+        assert_eq!(small.get(CacheCategory::FinalizedTx, "tx0", 20), None);
+        assert_eq!(
+            small.get(CacheCategory::FinalizedTx, "tx10", 20),
+            Some(json!(10))
+        );
+        assert_eq!(
+            small.stats(20)["categories"]["finalized_tx"]["evictions"],
+            2
+        );
+
+        let off = ResponseCache::new(
+            CacheSettings {
+                mode: CacheMode::Off,
+                ..Default::default()
+            },
+            None,
+        );
+        off.put(CacheCategory::FinalizedTx, "tx", json!(1), 0);
+        assert_eq!(off.get(CacheCategory::FinalizedTx, "tx", 0), None);
+    }
+
+    #[test]
+    fn sqlite_entries_survive_a_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("responses.sqlite");
+        let settings = CacheSettings {
+            mode: CacheMode::Sqlite,
+            ..Default::default()
+        };
+        let first = ResponseCache::new(
+            settings.clone(),
+            Some((open_db(&path, 0).unwrap(), path.clone())),
+        );
+        first.put(CacheCategory::MoveModules, "sui:0x2", json!({"coin": 1}), 0);
+        first.put(CacheCategory::Balance, "sol:a", json!(5), 0);
+        drop(first);
+
+        // Expired balances are purged on open; modules never expire.
+        let second = ResponseCache::new(settings, Some((open_db(&path, 60_000).unwrap(), path)));
+        assert_eq!(
+            second.stats(60_000)["categories"]["balance"]["persisted_entries"],
+            0
+        );
+        assert_eq!(
+            second.get(CacheCategory::MoveModules, "sui:0x2", 60_000),
+            Some(json!({"coin": 1}))
+        );
+        assert_eq!(second.clear(Some(CacheCategory::MoveModules), None), 1);
+        assert_eq!(
+            second.stats(60_000)["categories"]["move_modules"]["persisted_entries"],
+            0
+        );
+    }
+}
@@ -0,0 +1,523 @@
+//! Multi-endpoint RPC selection shared by the EVM, Solana and Sui clients.
+//!
+//! Every chain / network gets an [`EndpointSet`]: its configured URLs (a comma-separated list
+//! in `EVM_RPC_URL_<id>`, `SOLANA_RPC_URL[_<NET>]`, `SUI_RPC_URL[_<NET>]`) plus per-endpoint
+//! health. [`call`] tries endpoints best-first: idempotent reads fail over on timeouts, 429s and
+//! connection errors with exponential backoff; sends get a single attempt so a transaction is
+//! never broadcast twice by the retry layer. An endpoint that keeps failing is skipped for a
+//! cooldown that doubles with each consecutive failure.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+const COOLDOWN_BASE: Duration = Duration::from_secs(5);
+const COOLDOWN_MAX: Duration = Duration::from_secs(300);
+
+/// Split a comma-separated URL list (`"https://a, https://b"`), dropping empty items and
+/// duplicates while keeping the configured order.
+pub fn parse_url_list(value: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for url in value.split(',').map(str::trim).filter(|u| !u.is_empty()) {
+        if !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+        }
+    }
+    urls
+}
+
+/// Whether an RPC error is worth retrying on another endpoint (timeouts, rate limits,
+/// connection failures, gateway errors) rather than an answer from a healthy node.
+pub fn is_transient_error(message: &str) -> bool {
+    let m = message.to_ascii_lowercase();
+    [
+        "timed out",
+        "timeout",
+        "429",
+        "too many requests",
+        "rate limit",
+        "rate-limit",
+        "ratelimit",
+        "limit exceeded",
+        "connection refused",
+        "connection reset",
+        "connection closed",
+        "error sending request",
+        "error trying to connect",
+        "dns error",
+        "broken pipe",
+        "502",
+        "503",
+        "504",
+        "bad gateway",
+        "service unavailable",
+        "gateway timeout",
+        "node is behind",
+        "node is unhealthy",
+    ]
+    .iter()
+    .any(|needle| m.contains(needle))
+}
+
+/// Skip window after `consecutive_failures` failures in a row (0 = no cooldown).
+pub fn cooldown(consecutive_failures: u32) -> Duration {
+    if consecutive_failures == 0 {
+        return Duration::ZERO;
+    }
+    let factor = 1u32 << (consecutive_failures - 1).min(16);
+    COOLDOWN_BASE.saturating_mul(factor).min(COOLDOWN_MAX)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts for an idempotent read, across all endpoints (sends always get one).
+    pub max_attempts: usize,
+    /// Per-attempt timeout.
+    pub timeout: Duration,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            timeout: Duration::from_secs(30),
+            base_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Defaults overridden by `WEB3MCP_RPC_MAX_ATTEMPTS` / `WEB3MCP_RPC_TIMEOUT_MS` (`[rpc]`).
+    pub fn from_config() -> Self {
+        let num = |key: &str| {
+            crate::utils::config::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .filter(|n| *n > 0)
+        };
+        let mut policy = Self::default();
+        if let Some(n) = num("WEB3MCP_RPC_MAX_ATTEMPTS") {
+            policy.max_attempts = n as usize;
+        }
+        if let Some(ms) = num("WEB3MCP_RPC_TIMEOUT_MS") {
+            policy.timeout = Duration::from_millis(ms);
+        }
+        policy
+    }
+
+    /// Sleep before retry number `retry` (0-based): 200ms, 400ms, 800ms, ... capped.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.base_backoff
+            .saturating_mul(1u32 << retry.min(16))
+            .min(self.max_backoff)
+    }
+}
+
+/// How long a probed endpoint counts as fresh (`WEB3MCP_RPC_PROBE_INTERVAL_SECS`, default 30).
+pub fn probe_interval() -> Duration {
+    let secs = crate::utils::config::var("WEB3MCP_RPC_PROBE_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(30);
+    Duration::from_secs(secs)
+}
+
+#[derive(Debug, Clone, Default)]
+struct Health {
+    successes: u64,
+    failures: u64,
+    consecutive_failures: u32,
+    down_until: Option<Instant>,
+    last_latency_ms: Option<u64>,
+    last_error: Option<String>,
+    last_checked: Option<Instant>,
+}
+
+impl Health {
+    fn cooling_down(&self, now: Instant) -> bool {
+        self.down_until.is_some_and(|t| t > now)
+    }
+}
+
+/// The URLs of one chain / network and what we have seen from each.
+#[derive(Debug)]
+pub struct EndpointSet {
+    key: String,
+    urls: Vec<String>,
+    health: Mutex<Vec<Health>>,
+}
+
+impl EndpointSet {
+    pub fn new(key: &str, urls: Vec<String>) -> Self {
+        let health = vec![Health::default(); urls.len()];
+        Self {
+            key: key.to_string(),
+            urls,
+            health: Mutex::new(health),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    pub fn url(&self, idx: usize) -> &str {
+        &self.urls[idx]
+    }
+
+    /// Endpoint indices, best first: available ones by consecutive failures, then latency,
+    /// then configured order; endpoints in cooldown go last (soonest available first) so a
+    /// call still has somewhere to go when every endpoint is down.
+    pub fn order(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let health = self.lock();
+        let mut idx: Vec<usize> = (0..self.urls.len()).collect();
+        idx.sort_by_key(|&i| {
+            let h = &health[i];
+            let cooling = h.cooling_down(now);
+            (
+                cooling,
+                cooling.then_some(h.down_until).flatten(),
+                h.consecutive_failures,
+                h.last_latency_ms.unwrap_or(u64::MAX / 2) / 50,
+                i,
+            )
+        });
+        idx
+    }
+
+    /// Best endpoint index (0 for an empty set).
+    pub fn best(&self) -> usize {
+        self.order().first().copied().unwrap_or(0)
+    }
+
+    pub fn record_success(&self, idx: usize, latency: Duration) {
+        let mut health = self.lock();
+        let Some(h) = health.get_mut(idx) else {
+            return;
+        };
+        h.successes += 1;
+        h.consecutive_failures = 0;
+        h.down_until = None;
+        h.last_latency_ms = Some(latency.as_millis() as u64);
+        h.last_checked = Some(Instant::now());
+    }
+
+    pub fn record_failure(&self, idx: usize, error: &str) {
+        let mut health = self.lock();
+        let Some(h) = health.get_mut(idx) else {
+            return;
+        };
+        let now = Instant::now();
+        h.failures += 1;
+        h.consecutive_failures = h.consecutive_failures.saturating_add(1);
+        h.down_until = Some(now + cooldown(h.consecutive_failures));
+        h.last_error = Some(error.chars().take(300).collect());
+        h.last_checked = Some(now);
+    }
+
+    /// Whether `idx` has not been seen working within `max_age`.
+    pub fn is_stale(&self, idx: usize, max_age: Duration) -> bool {
+        let health = self.lock();
+        health
+            .get(idx)
+            .and_then(|h| h.last_checked)
+            .is_none_or(|t| t.elapsed() > max_age)
+    }
+
+    /// Health per endpoint with credentials redacted, for diagnostics.
+    pub fn snapshot(&self) -> Value {
+        let now = Instant::now();
+        let health = self.lock();
+        let endpoints: Vec<Value> = self
+            .urls
+            .iter()
+            .zip(health.iter())
+            .map(|(url, h)| {
+                json!({
+                    "rpc_url": crate::utils::config::redact_url(url),
+                    "available": !h.cooling_down(now),
+                    "cooldown_remaining_ms": h
+                        .down_until
+                        .filter(|t| *t > now)
+                        .map(|t| (t - now).as_millis() as u64),
+                    "successes": h.successes,
+                    "failures": h.failures,
+                    "consecutive_failures": h.consecutive_failures,
+                    "last_latency_ms": h.last_latency_ms,
+                    "last_error": h.last_error,
+                    "last_checked_secs_ago": h.last_checked.map(|t| t.elapsed().as_secs()),
+                })
+            })
+            .collect();
+        drop(health);
+        json!({
+            "key": self.key,
+            "preferred": self.urls.get(self.best()).map(|u| crate::utils::config::redact_url(u)),
+            "endpoints": endpoints,
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Health>> {
+        self.health.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn registry() -> &'static Mutex<HashMap<String, Arc<EndpointSet>>> {
+    static SETS: OnceLock<Mutex<HashMap<String, Arc<EndpointSet>>>> = OnceLock::new();
+    SETS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The process-wide set for `key` (`evm:8453`, `solana:devnet`, `sui:mainnet`), so health
+/// survives across tool calls. A changed URL list starts a fresh set.
+pub fn endpoint_set(key: &str, urls: Vec<String>) -> Arc<EndpointSet> {
+    let mut sets = registry().lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(set) = sets.get(key) {
+        if set.urls == urls {
+            return set.clone();
+        }
+    }
+    let set = Arc::new(EndpointSet::new(key, urls));
+    sets.insert(key.to_string(), set.clone());
+    set
+}
+
+/// Every set used so far, sorted by key.
+pub fn snapshot() -> Vec<Value> {
+    let sets: Vec<Arc<EndpointSet>> = registry()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .values()
+        .cloned()
+        .collect();
+    let mut items: Vec<Value> = sets.iter().map(|s| s.snapshot()).collect();
+    items.sort_by_key(|v| v["key"].as_str().unwrap_or_default().to_string());
+    items
+}
+
+/// Run `attempt(endpoint_index)` against `set`, best endpoint first.
+///
+/// Errors `is_transient` accepts (and timeouts, built with `timeout_error(url)`) mark the
+/// endpoint down and, for idempotent calls, move on to the next one after a backoff. Other
+/// errors are answers from a working node and are returned as-is.
+pub async fn call<T, E, F, Fut>(
+    set: &EndpointSet,
+    policy: &RetryPolicy,
+    idempotent: bool,
+    is_transient: impl Fn(&E) -> bool,
+    timeout_error: impl Fn(&str) -> E,
+    mut attempt: F,
+) -> Result<T, E>
+where
+    E: std::fmt::Display,
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let order = set.order();
+    if order.is_empty() {
+        return Err(timeout_error(""));
+    }
+    let attempts = if idempotent {
+        policy.max_attempts.max(1)
+    } else {
+        1
+    };
+    let mut last_error = None;
+    for n in 0..attempts {
+        if n > 0 {
+            tokio::time::sleep(policy.backoff(n as u32 - 1)).await;
+        }
+        let idx = order[n % order.len()];
+        let started = Instant::now();
+        match tokio::time::timeout(policy.timeout, attempt(idx)).await {
+            Ok(Ok(value)) => {
+                set.record_success(idx, started.elapsed());
+                return Ok(value);
+            }
+            Ok(Err(e)) if is_transient(&e) => {
+                tracing::warn!("rpc {} via {} failed: {}", set.key(), set.url(idx), e);
+                set.record_failure(idx, &e.to_string());
+                last_error = Some(e);
+            }
+            Ok(Err(e)) => {
+                set.record_success(idx, started.elapsed());
+                return Err(e);
+            }
+            Err(_) => {
+                let e = timeout_error(set.url(idx));
+                tracing::warn!("rpc {} via {} failed: {}", set.key(), set.url(idx), e);
+                set.record_failure(idx, &e.to_string());
+                last_error = Some(e);
+            }
+        }
+    }
+    Err(last_error.expect("at least one attempt"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn set(n: usize) -> EndpointSet {
+        EndpointSet::new(
+            "test",
+            (0..n)
+                .map(|i| format!("https://rpc{}.example", i))
+                .collect(),
+        )
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            timeout: Duration::from_millis(200),
+            base_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn url_lists_are_split_and_deduplicated() {
+        assert_eq!(
+            parse_url_list(" https://a , https://b,,https://a "),
+            vec!["https://a".to_string(), "https://b".to_string()]
+        );
+        assert!(parse_url_list(" , ").is_empty());
+    }
+
+    #[test]
+    fn transient_errors_are_recognised() {
+        assert!(is_transient_error(
+            "HTTP status client error (429 Too Many Requests)"
+        ));
+        assert!(is_transient_error(
+            "error sending request for url: operation timed out"
+        ));
+        assert!(is_transient_error("503 Service Unavailable"));
+        assert!(!is_transient_error(
+            "execution reverted: ERC20: insufficient balance"
+        ));
+        assert!(!is_transient_error("invalid params"));
+    }
+
+    #[test]
+    fn cooldown_and_backoff_grow_and_cap() {
+        assert_eq!(cooldown(0), Duration::ZERO);
+        assert_eq!(cooldown(1), Duration::from_secs(5));
+        assert_eq!(cooldown(3), Duration::from_secs(20));
+        assert_eq!(cooldown(30), Duration::from_secs(300));
+        let p = RetryPolicy::default();
+        assert_eq!(p.backoff(0), Duration::from_millis(200));
+        assert_eq!(p.backoff(2), Duration::from_millis(800));
+        assert_eq!(p.backoff(20), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn failing_endpoints_move_to_the_back() {
+        let s = set(3);
+        assert_eq!(s.order(), vec![0, 1, 2]);
+        s.record_failure(0, "timeout");
+        assert_eq!(s.order(), vec![1, 2, 0]);
+        s.record_success(2, Duration::from_millis(10));
+        s.record_success(1, Duration::from_millis(900));
+        assert_eq!(s.order(), vec![2, 1, 0]);
+        s.record_success(0, Duration::from_millis(10));
+        assert_eq!(s.order(), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn registry_reuses_sets_until_urls_change() {
+        let a = endpoint_set("test:registry", vec!["https://a".into()]);
+        a.record_failure(0, "timeout");
+        let b = endpoint_set("test:registry", vec!["https://a".into()]);
+        assert!(Arc::ptr_eq(&a, &b));
+        let c = endpoint_set("test:registry", vec!["https://b".into()]);
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert!(snapshot().iter().any(|v| v["key"] == "test:registry"));
+    }
+
+    #[tokio::test]
+    async fn reads_fail_over_and_sends_do_not() {
+        let s = set(2);
+        let calls = AtomicUsize::new(0);
+        let result: Result<usize, String> = call(
+            &s,
+            &fast_policy(),
+            true,
+            |e: &String| is_transient_error(e),
+            |url| format!("{} timed out", url),
+            |idx| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if idx == 0 {
+                        Err("429 Too Many Requests".to_string())
+                    } else {
+                        Ok(idx)
+                    }
+                }
+            },
+        )
+        .await;
+        assert_eq!(result, Ok(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(s.best(), 1);
+
+        let s = set(2);
+        let calls = AtomicUsize::new(0);
+        let result: Result<usize, String> = call(
+            &s,
+            &fast_policy(),
+            false,
+            |e: &String| is_transient_error(e),
+            |url| format!("{} timed out", url),
+            |_| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                async { Err("connection refused".to_string()) }
+            },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn node_errors_and_timeouts() {
+        let s = set(2);
+        let result: Result<(), String> = call(
+            &s,
+            &fast_policy(),
+            true,
+            |e: &String| is_transient_error(e),
+            |url| format!("{} timed out", url),
+            |_| async { Err("execution reverted".to_string()) },
+        )
+        .await;
+        assert_eq!(result, Err("execution reverted".to_string()));
+        assert_eq!(s.order(), vec![0, 1]);
+
+        let result: Result<(), String> = call(
+            &s,
+            &fast_policy(),
+            true,
+            |e: &String| is_transient_error(e),
+            |url| format!("{} timed out", url),
+            |_| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            },
+        )
+        .await;
+        assert!(result.unwrap_err().ends_with("timed out"));
+        assert!(s.snapshot()["endpoints"][0]["failures"].as_u64() >= Some(1));
+    }
+}
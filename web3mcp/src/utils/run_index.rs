@@ -0,0 +1,389 @@
+//! SQLite index of W3RT runs (`<runs_dir>/index.sqlite`) plus the retention policy.
+//!
+//! Stage artifacts stay the source of truth; the index is a cache that `RunStore::save_state`
+//! keeps current and `backfill` rebuilds from run directories.
+
+use crate::utils::run_store::{RunState, RunStore};
+use rmcp::model::{ErrorCode, ErrorData};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+const DEFAULT_LIMIT: u32 = 20;
+const MAX_LIMIT: u32 = 200;
+
+fn db_err(ctx: &str, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("{}: {}", ctx, e)),
+        data: None,
+    }
+}
+
+pub fn index_path(store: &RunStore) -> PathBuf {
+    store.root().join("index.sqlite")
+}
+
+pub fn connect(store: &RunStore) -> Result<rusqlite::Connection, ErrorData> {
+    std::fs::create_dir_all(store.root()).map_err(|e| db_err("Failed to create runs dir", e))?;
+    let conn = rusqlite::Connection::open(index_path(store))
+        .map_err(|e| db_err("Failed to open run index", e))?;
+
+    conn.execute_batch(
+        "BEGIN;
+         CREATE TABLE IF NOT EXISTS w3rt_runs (
+           run_id TEXT PRIMARY KEY,
+           chain TEXT,
+           action TEXT,
+           status TEXT NOT NULL,
+           stage TEXT NOT NULL,
+           sender TEXT,
+           network TEXT,
+           label TEXT,
+           created_ms INTEGER NOT NULL,
+           updated_ms INTEGER NOT NULL,
+           archived INTEGER NOT NULL DEFAULT 0
+         );
+         CREATE INDEX IF NOT EXISTS idx_w3rt_runs_created ON w3rt_runs(created_ms);
+         CREATE INDEX IF NOT EXISTS idx_w3rt_runs_status ON w3rt_runs(status);
+         CREATE INDEX IF NOT EXISTS idx_w3rt_runs_chain ON w3rt_runs(chain, action);
+         COMMIT;",
+    )
+    .map_err(|e| db_err("Failed to init run index schema", e))?;
+
+    // Owning MCP session of a run (see `RunState::session_id`); added after the first schema.
+    let has_session: bool = conn
+        .query_row(
+            "SELECT COUNT(1) FROM pragma_table_info('w3rt_runs') WHERE name='session_id'",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map(|n| n > 0)
+        .map_err(|e| db_err("Failed to inspect run index schema", e))?;
+    if !has_session {
+        conn.execute_batch(
+            "ALTER TABLE w3rt_runs ADD COLUMN session_id TEXT;
+             CREATE INDEX IF NOT EXISTS idx_w3rt_runs_session ON w3rt_runs(session_id);",
+        )
+        .map_err(|e| db_err("Failed to add session_id to run index", e))?;
+    }
+
+    Ok(conn)
+}
+
+fn intent_field(intent: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|k| intent.get(*k).and_then(Value::as_str))
+        .filter(|s| !s.starts_with('<'))
+        .map(|s| s.to_string())
+}
+
+/// Insert or refresh the index row for a run (chain/action/sender come from the analysis artifact).
+pub fn upsert(store: &RunStore, state: &RunState) -> Result<(), ErrorData> {
+    let analysis = store.read_stage_artifact(&state.run_id, "analysis").unwrap_or(Value::Null);
+    let intent = analysis.get("intent").cloned().unwrap_or(Value::Null);
+    let network = intent
+        .get("resolved_network")
+        .and_then(|v| v.get("network_name"))
+        .and_then(Value::as_str)
+        .map(|s| s.to_string())
+        .or_else(|| intent_field(&intent, &["network"]));
+
+    let conn = connect(store)?;
+    conn.execute(
+        "INSERT INTO w3rt_runs (run_id, chain, action, status, stage, sender, network, label, created_ms, updated_ms, archived, session_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, 0, ?11)
+         ON CONFLICT(run_id) DO UPDATE SET
+           chain=excluded.chain, action=excluded.action, status=excluded.status, stage=excluded.stage,
+           sender=excluded.sender, network=excluded.network, label=excluded.label, updated_ms=excluded.updated_ms,
+           session_id=excluded.session_id",
+        rusqlite::params![
+            state.run_id,
+            intent_field(&intent, &["chain"]),
+            intent_field(&intent, &["action"]),
+            state.status,
+            state.stage,
+            intent_field(&intent, &["user_pubkey", "from", "sender"]),
+            network,
+            analysis.get("label").and_then(Value::as_str),
+            state.created_ms as i64,
+            state.updated_ms as i64,
+            state.session_id,
+        ],
+    )
+    .map_err(|e| db_err("Failed to upsert run index row", e))?;
+    Ok(())
+}
+
+/// Index run directories that have no row yet (runs created before the index existed).
+/// Returns the number of rows added.
+pub fn backfill(store: &RunStore) -> Result<u64, ErrorData> {
+    let conn = connect(store)?;
+    let entries = match std::fs::read_dir(store.root()) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(0),
+    };
+
+    let mut added = 0u64;
+    for entry in entries.flatten() {
+        let run_id = entry.file_name().to_string_lossy().to_string();
+        if !run_id.starts_with("run_") || !entry.path().is_dir() {
+            continue;
+        }
+        let exists: bool = conn
+            .query_row(
+                "SELECT COUNT(1) FROM w3rt_runs WHERE run_id=?1",
+                [&run_id],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)
+            .unwrap_or(false);
+        if exists {
+            continue;
+        }
+        let loaded = store.load_state(&run_id);
+        let inferred = loaded.is_none();
+        let Some(mut state) = loaded.or_else(|| store.infer_state(&run_id)) else {
+            continue;
+        };
+        // Inferred states are stamped "now"; the run id carries the real creation time.
+        if inferred {
+            if let Some(ms) = created_ms_from_run_id(&run_id) {
+                state.created_ms = ms;
+                state.updated_ms = ms;
+            }
+        }
+        upsert(store, &state)?;
+        added += 1;
+    }
+    Ok(added)
+}
+
+/// `run_<ms>_<rand>` → ms.
+pub fn created_ms_from_run_id(run_id: &str) -> Option<u64> {
+    run_id.strip_prefix("run_")?.split('_').next()?.parse().ok()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RunFilter {
+    pub chain: Option<String>,
+    pub action: Option<String>,
+    pub status: Option<String>,
+    pub sender: Option<String>,
+    pub since_ms: Option<u64>,
+    pub until_ms: Option<u64>,
+    pub include_archived: bool,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunRow {
+    pub run_id: String,
+    pub chain: Option<String>,
+    pub action: Option<String>,
+    pub status: String,
+    pub stage: String,
+    pub sender: Option<String>,
+    pub network: Option<String>,
+    pub label: Option<String>,
+    pub created_ms: u64,
+    pub updated_ms: u64,
+    pub archived: bool,
+}
+
+/// Filtered page of runs (newest first) plus the total number of matching rows. Inside a
+/// session scope only that session's runs are listed.
+pub fn list(store: &RunStore, filter: &RunFilter) -> Result<(Vec<RunRow>, u64), ErrorData> {
+    let conn = connect(store)?;
+
+    let mut clauses: Vec<&str> = vec![];
+    let mut args: Vec<Box<dyn rusqlite::ToSql>> = vec![];
+    if let Some(session) = crate::utils::session_scope::current() {
+        clauses.push("session_id=?");
+        args.push(Box::new(session));
+    }
+    let text_filters = [
+        ("chain=?", &filter.chain),
+        ("action=?", &filter.action),
+        ("status=?", &filter.status),
+        ("sender=?", &filter.sender),
+    ];
+    for (clause, value) in text_filters {
+        if let Some(v) = value.as_ref().map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            clauses.push(clause);
+            args.push(Box::new(v.to_string()));
+        }
+    }
+    if let Some(ms) = filter.since_ms {
+        clauses.push("created_ms>=?");
+        args.push(Box::new(ms as i64));
+    }
+    if let Some(ms) = filter.until_ms {
+        clauses.push("created_ms<?");
+        args.push(Box::new(ms as i64));
+    }
+    if !filter.include_archived {
+        clauses.push("archived=0");
+    }
+    let where_sql = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", clauses.join(" AND "))
+    };
+
+    let total: i64 = conn
+        .query_row(
+            &format!("SELECT COUNT(1) FROM w3rt_runs{}", where_sql),
+            rusqlite::params_from_iter(args.iter()),
+            |row| row.get(0),
+        )
+        .map_err(|e| db_err("Failed to count runs", e))?;
+
+    let limit = filter.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let offset = filter.offset.unwrap_or(0);
+    let sql = format!(
+        "SELECT run_id, chain, action, status, stage, sender, network, label, created_ms, updated_ms, archived
+         FROM w3rt_runs{} ORDER BY created_ms DESC LIMIT {} OFFSET {}",
+        where_sql, limit, offset
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| db_err("Failed to prepare list_runs", e))?;
+    let rows = stmt
+        .query_map(rusqlite::params_from_iter(args.iter()), |row| {
+            Ok(RunRow {
+                run_id: row.get(0)?,
+                chain: row.get(1)?,
+                action: row.get(2)?,
+                status: row.get(3)?,
+                stage: row.get(4)?,
+                sender: row.get(5)?,
+                network: row.get(6)?,
+                label: row.get(7)?,
+                created_ms: row.get::<_, i64>(8)? as u64,
+                updated_ms: row.get::<_, i64>(9)? as u64,
+                archived: row.get::<_, i64>(10)? != 0,
+            })
+        })
+        .map_err(|e| db_err("Failed to query list_runs", e))?;
+
+    Ok((rows.flatten().collect(), total.max(0) as u64))
+}
+
+pub fn archive_dir(store: &RunStore) -> PathBuf {
+    store.root().join("archive")
+}
+
+pub fn is_archived(store: &RunStore, run_id: &str) -> bool {
+    archive_dir(store).join(run_id).is_dir() && !store.root().join(run_id).is_dir()
+}
+
+/// Retention policy: prune runs older than `max_age_ms` and/or beyond the newest `max_runs`.
+///
+/// Env defaults: `WEB3MCP_RUNS_MAX_AGE_DAYS`, `WEB3MCP_RUNS_MAX_COUNT`,
+/// `WEB3MCP_RUNS_RETENTION_MODE` (`archive` (default) moves run dirs to `<runs_dir>/archive`;
+/// `delete` removes them).
+#[derive(Debug, Clone, Default)]
+pub struct RetentionPolicy {
+    pub max_age_ms: Option<u64>,
+    pub max_runs: Option<u64>,
+    pub delete: bool,
+}
+
+impl RetentionPolicy {
+    pub fn from_env() -> Self {
+        fn env_u64(key: &str) -> Option<u64> {
+            crate::utils::config::var(key).ok().and_then(|v| v.trim().parse::<u64>().ok())
+        }
+        Self {
+            max_age_ms: env_u64("WEB3MCP_RUNS_MAX_AGE_DAYS").map(|d| d.saturating_mul(86_400_000)),
+            max_runs: env_u64("WEB3MCP_RUNS_MAX_COUNT"),
+            delete: crate::utils::config::var("WEB3MCP_RUNS_RETENTION_MODE")
+                .map(|m| m.trim().eq_ignore_ascii_case("delete"))
+                .unwrap_or(false),
+        }
+    }
+
+    pub fn is_noop(&self) -> bool {
+        self.max_age_ms.is_none() && self.max_runs.is_none()
+    }
+}
+
+/// Apply a retention policy. With `dry_run`, only reports what would be pruned. Inside a
+/// session scope only that session's runs are counted and pruned, so `max_runs` is per session.
+pub fn prune(store: &RunStore, policy: &RetentionPolicy, now_ms: u64, dry_run: bool) -> Result<Value, ErrorData> {
+    if policy.is_noop() {
+        return Ok(json!({"pruned": [], "mode": "noop", "note": "no max_age / max_runs configured"}));
+    }
+    // Count/age limits must see every run, including ones not indexed yet.
+    backfill(store)?;
+    let conn = connect(store)?;
+
+    let session = crate::utils::session_scope::current();
+    let mut stmt = conn
+        .prepare(
+            "SELECT run_id, created_ms FROM w3rt_runs
+             WHERE archived=0 AND (?1 IS NULL OR session_id=?1) ORDER BY created_ms DESC",
+        )
+        .map_err(|e| db_err("Failed to prepare retention scan", e))?;
+    let live: Vec<(String, u64)> = stmt
+        .query_map([&session], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64)))
+        .map_err(|e| db_err("Failed to scan runs for retention", e))?
+        .flatten()
+        .collect();
+
+    let cutoff = policy.max_age_ms.map(|age| now_ms.saturating_sub(age));
+    let keep = policy.max_runs.unwrap_or(u64::MAX);
+    let victims: Vec<&str> = live
+        .iter()
+        .enumerate()
+        .filter(|(i, (_, created))| (*i as u64) >= keep || cutoff.is_some_and(|c| *created < c))
+        .map(|(_, (id, _))| id.as_str())
+        .collect();
+
+    let mode = if policy.delete { "delete" } else { "archive" };
+    if !dry_run {
+        for run_id in &victims {
+            prune_one(store, &conn, run_id, policy.delete)?;
+        }
+    }
+
+    Ok(json!({
+        "mode": mode,
+        "dry_run": dry_run,
+        "pruned": victims,
+        "remaining": live.len().saturating_sub(victims.len()),
+    }))
+}
+
+fn prune_one(store: &RunStore, conn: &rusqlite::Connection, run_id: &str, delete: bool) -> Result<(), ErrorData> {
+    let dir = store.root().join(run_id);
+    if delete {
+        if dir.is_dir() {
+            std::fs::remove_dir_all(&dir).map_err(|e| db_err("Failed to delete run dir", e))?;
+        }
+        conn.execute("DELETE FROM w3rt_runs WHERE run_id=?1", [run_id])
+            .map_err(|e| db_err("Failed to delete run index row", e))?;
+    } else {
+        if dir.is_dir() {
+            let dest = archive_dir(store);
+            std::fs::create_dir_all(&dest).map_err(|e| db_err("Failed to create archive dir", e))?;
+            std::fs::rename(&dir, dest.join(run_id))
+                .map_err(|e| db_err("Failed to move run dir to archive", e))?;
+        }
+        conn.execute("UPDATE w3rt_runs SET archived=1 WHERE run_id=?1", [run_id])
+            .map_err(|e| db_err("Failed to mark run archived", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_id_carries_creation_time() {
+        assert_eq!(created_ms_from_run_id("run_1700000000000_1234"), Some(1_700_000_000_000));
+        assert_eq!(created_ms_from_run_id("other"), None);
+    }
+}
@@ -1,7 +1,134 @@
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Workflow stages in execution order.
+pub const RUN_STAGES: [&str; 4] = ["analysis", "simulate", "approval", "execute"];
+
+fn now_ms() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageState {
+    /// running | done | blocked | failed | stale
+    pub status: String,
+    pub attempts: u32,
+    pub updated_ms: u64,
+}
+
+/// Persisted run-state record (`run_state.json`), used to resume a run from its last completed stage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunState {
+    pub run_id: String,
+    /// Last stage entered.
+    pub stage: String,
+    /// running | completed | blocked | failed | waiting (plan steps deferred until predecessors confirm)
+    pub status: String,
+    /// Number of times the run has been driven (initial run + resumes).
+    pub attempts: u32,
+    pub created_ms: u64,
+    pub updated_ms: u64,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub stages: BTreeMap<String, StageState>,
+    /// Owning MCP session (see `session_scope`); `None` for runs created unscoped.
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+impl RunState {
+    pub fn new(run_id: &str) -> Self {
+        let now = now_ms();
+        Self {
+            run_id: run_id.to_string(),
+            stage: "analysis".to_string(),
+            status: "running".to_string(),
+            attempts: 0,
+            created_ms: now,
+            updated_ms: now,
+            last_error: None,
+            stages: BTreeMap::new(),
+            session_id: crate::utils::session_scope::current(),
+        }
+    }
+
+    /// Whether the running request may see this run: its own session's runs, or every run when
+    /// unscoped (stdio).
+    pub fn in_current_session(&self) -> bool {
+        match crate::utils::session_scope::current() {
+            Some(session) => self.session_id.as_deref() == Some(session.as_str()),
+            None => true,
+        }
+    }
+
+    pub fn is_done(&self, stage: &str) -> bool {
+        self.stages.get(stage).is_some_and(|s| s.status == "done")
+    }
+
+    /// Mark `stage` as running (bumps its attempt counter).
+    pub fn begin(&mut self, stage: &str) {
+        let now = now_ms();
+        let entry = self.stages.entry(stage.to_string()).or_insert(StageState {
+            status: "running".to_string(),
+            attempts: 0,
+            updated_ms: now,
+        });
+        entry.status = "running".to_string();
+        entry.attempts += 1;
+        entry.updated_ms = now;
+        self.stage = stage.to_string();
+        self.status = "running".to_string();
+        self.updated_ms = now;
+    }
+
+    /// Record the outcome of the current stage (done | blocked | failed).
+    pub fn finish(&mut self, stage: &str, status: &str) {
+        let now = now_ms();
+        if let Some(entry) = self.stages.get_mut(stage) {
+            entry.status = status.to_string();
+            entry.updated_ms = now;
+        }
+        self.updated_ms = now;
+    }
+
+    /// Mark the current stage (and the run) failed.
+    pub fn fail(&mut self, error: &str) {
+        let stage = self.stage.clone();
+        self.finish(&stage, "failed");
+        self.status = "failed".to_string();
+        self.last_error = Some(error.to_string());
+    }
+
+    /// Mark `stage` and every later stage stale, so the next drive recomputes them.
+    /// Plan step keys (`<step_id>:<stage>`) are invalidated the same way. Attempt counters are kept.
+    pub fn invalidate_from(&mut self, stage: &str) {
+        let Some(idx) = RUN_STAGES.iter().position(|s| *s == stage) else {
+            return;
+        };
+        let now = now_ms();
+        for (key, entry) in self.stages.iter_mut() {
+            let key_stage = key.rsplit(':').next().unwrap_or(key);
+            let stale = RUN_STAGES
+                .iter()
+                .position(|s| *s == key_stage)
+                .is_some_and(|i| i >= idx);
+            if stale {
+                entry.status = "stale".to_string();
+                entry.updated_ms = now;
+            }
+        }
+        self.updated_ms = now;
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RunStore {
     root: PathBuf,
@@ -9,17 +136,10 @@ pub struct RunStore {
 
 impl RunStore {
     pub fn new() -> Self {
-        // Keep default location stable and local.
-        // Users can override with WEB3MCP_RUNS_DIR.
-        let root = std::env::var("WEB3MCP_RUNS_DIR")
-            .map(PathBuf::from)
-            .unwrap_or_else(|_| {
-                let home = std::env::var("HOME")
-                    .map(PathBuf::from)
-                    .unwrap_or_else(|_| PathBuf::from("."));
-                home.join(".web3mcp").join("runs")
-            });
-        Self { root }
+        // `<data_dir>/runs`; users can override with WEB3MCP_RUNS_DIR.
+        Self {
+            root: crate::utils::data_dir::runs_dir(),
+        }
     }
 
     pub fn root(&self) -> &Path {
@@ -39,16 +159,293 @@ impl RunStore {
         Ok(dir)
     }
 
+    /// Directory holding a run's files; falls back to `archive/<run_id>` for archived runs (read-only).
+    pub fn run_dir(&self, run_id: &str) -> PathBuf {
+        let live = self.root.join(run_id);
+        let archived = self.root.join("archive").join(run_id);
+        if !live.is_dir() && archived.is_dir() {
+            archived
+        } else {
+            live
+        }
+    }
+
+    pub fn stage_path(&self, run_id: &str, stage: &str) -> PathBuf {
+        self.run_dir(run_id).join(format!("stage_{}.json", stage))
+    }
+
     pub fn write_stage_artifact(
         &self,
         run_id: &str,
         stage: &str,
         value: &Value,
     ) -> Result<PathBuf, std::io::Error> {
-        let dir = self.ensure_run_dir(run_id)?;
-        let path = dir.join(format!("stage_{}.json", stage));
+        self.ensure_run_dir(run_id)?;
+        let path = self.stage_path(run_id, stage);
         let bytes = serde_json::to_vec_pretty(value).unwrap_or_else(|_| b"{}".to_vec());
         fs::write(&path, bytes)?;
         Ok(path)
     }
+
+    pub fn read_stage_artifact(&self, run_id: &str, stage: &str) -> Option<Value> {
+        let bytes = fs::read(self.stage_path(run_id, stage)).ok()?;
+        serde_json::from_slice::<Value>(&bytes).ok()
+    }
+
+    /// Per-step artifact of a workflow v1 plan: `<run_dir>/steps/<step_id>/stage_<stage>.json`.
+    pub fn step_stage_path(&self, run_id: &str, step_id: &str, stage: &str) -> PathBuf {
+        self.run_dir(run_id)
+            .join("steps")
+            .join(step_id)
+            .join(format!("stage_{}.json", stage))
+    }
+
+    pub fn write_step_artifact(
+        &self,
+        run_id: &str,
+        step_id: &str,
+        stage: &str,
+        value: &Value,
+    ) -> Result<PathBuf, std::io::Error> {
+        self.ensure_run_dir(run_id)?;
+        let path = self.step_stage_path(run_id, step_id, stage);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let bytes = serde_json::to_vec_pretty(value).unwrap_or_else(|_| b"{}".to_vec());
+        fs::write(&path, bytes)?;
+        Ok(path)
+    }
+
+    pub fn read_step_artifact(&self, run_id: &str, step_id: &str, stage: &str) -> Option<Value> {
+        let bytes = fs::read(self.step_stage_path(run_id, step_id, stage)).ok()?;
+        serde_json::from_slice::<Value>(&bytes).ok()
+    }
+
+    fn state_path(&self, run_id: &str) -> PathBuf {
+        self.run_dir(run_id).join("run_state.json")
+    }
+
+    pub fn load_state(&self, run_id: &str) -> Option<RunState> {
+        let bytes = fs::read(self.state_path(run_id)).ok()?;
+        serde_json::from_slice::<RunState>(&bytes).ok()
+    }
+
+    pub fn save_state(&self, state: &RunState) -> Result<PathBuf, std::io::Error> {
+        self.ensure_run_dir(&state.run_id)?;
+        let path = self.state_path(&state.run_id);
+        // Write-then-rename so a crash never leaves a truncated state file behind.
+        let tmp = path.with_extension("json.tmp");
+        let bytes = serde_json::to_vec_pretty(state).unwrap_or_else(|_| b"{}".to_vec());
+        fs::write(&tmp, bytes)?;
+        fs::rename(&tmp, &path)?;
+        // The index is a cache over run dirs; a failed update is repaired by `run_index::backfill`.
+        let _ = crate::utils::run_index::upsert(self, state);
+        Ok(path)
+    }
+
+    /// Whether the running request may see `run_id` (see `RunState::in_current_session`).
+    /// Unscoped callers see every run, including ones that do not exist yet.
+    pub fn visible_in_session(&self, run_id: &str) -> bool {
+        crate::utils::session_scope::current().is_none()
+            || self
+                .load_state(run_id)
+                .or_else(|| self.infer_state(run_id))
+                .is_some_and(|s| s.in_current_session())
+    }
+
+    /// Rebuild a run state from stage artifacts (runs created before `run_state.json` existed).
+    pub fn infer_state(&self, run_id: &str) -> Option<RunState> {
+        self.read_stage_artifact(run_id, "analysis")?;
+        let mut state = RunState::new(run_id);
+        // Runs without run_state.json predate sessions, so they belong to no session.
+        state.session_id = None;
+        state.begin("analysis");
+        state.finish("analysis", "done");
+
+        if let Some(sim) = self.read_stage_artifact(run_id, "simulate") {
+            state.begin("simulate");
+            let ok = sim.get("status").and_then(Value::as_str) == Some("ok");
+            state.finish("simulate", if ok { "done" } else { "failed" });
+            if ok && self.read_stage_artifact(run_id, "approval").is_some() {
+                state.begin("approval");
+                state.finish("approval", "done");
+            }
+        }
+        if let Some(exec) = self.read_stage_artifact(run_id, "execute") {
+            state.begin("execute");
+            let status = execute_stage_status(&exec);
+            state.finish("execute", status);
+            state.status = run_status_for_execute(status).to_string();
+        } else {
+            state.status = "failed".to_string();
+        }
+        Some(state)
+    }
+}
+
+/// Stage status for an execute artifact: pending confirmations and read-only results are final.
+pub fn execute_stage_status(execute: &Value) -> &'static str {
+    match execute.get("status").and_then(Value::as_str) {
+        Some("pending_confirmation_created") | Some("ok") => "done",
+        Some("blocked") => "blocked",
+        _ => "failed",
+    }
+}
+
+pub fn run_status_for_execute(stage_status: &str) -> &'static str {
+    match stage_status {
+        "done" => "completed",
+        "blocked" => "blocked",
+        _ => "failed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalidate_from_clears_stage_and_downstream() {
+        let mut state = RunState::new("run_test");
+        for stage in RUN_STAGES {
+            state.begin(stage);
+            state.finish(stage, "done");
+        }
+        state.invalidate_from("approval");
+        assert!(state.is_done("analysis"));
+        assert!(state.is_done("simulate"));
+        assert!(!state.is_done("approval"));
+        assert!(!state.is_done("execute"));
+        assert_eq!(state.stages["execute"].attempts, 1);
+
+        for key in ["swap:simulate", "swap:execute"] {
+            state.begin(key);
+            state.finish(key, "done");
+        }
+        state.invalidate_from("execute");
+        assert!(state.is_done("swap:simulate"));
+        assert!(!state.is_done("swap:execute"));
+    }
+
+    #[test]
+    fn begin_counts_attempts_and_fail_records_error() {
+        let mut state = RunState::new("run_test");
+        state.begin("execute");
+        state.fail("rpc timeout");
+        state.begin("execute");
+        assert_eq!(state.stages["execute"].attempts, 2);
+        assert_eq!(state.status, "running");
+        assert_eq!(state.last_error.as_deref(), Some("rpc timeout"));
+    }
+
+    #[test]
+    fn state_roundtrips_and_is_inferred_from_artifacts() {
+        let root = std::env::temp_dir().join(format!("w3rt_run_store_{}", now_ms()));
+        let store = RunStore { root: root.clone() };
+
+        store
+            .write_stage_artifact("run_a", "analysis", &serde_json::json!({"intent": {}}))
+            .unwrap();
+        store
+            .write_stage_artifact("run_a", "simulate", &serde_json::json!({"status": "ok"}))
+            .unwrap();
+        store
+            .write_stage_artifact(
+                "run_a",
+                "approval",
+                &serde_json::json!({"status": "needs_review"}),
+            )
+            .unwrap();
+        store
+            .write_stage_artifact(
+                "run_a",
+                "execute",
+                &serde_json::json!({"status": "blocked"}),
+            )
+            .unwrap();
+
+        let inferred = store.infer_state("run_a").expect("analysis exists");
+        assert!(inferred.is_done("approval"));
+        assert_eq!(inferred.stages["execute"].status, "blocked");
+        assert_eq!(inferred.status, "blocked");
+
+        store.save_state(&inferred).unwrap();
+        let loaded = store.load_state("run_a").expect("state saved");
+        assert_eq!(loaded.status, "blocked");
+        assert!(store.infer_state("run_missing").is_none());
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn sessions_only_see_their_own_runs() {
+        use crate::utils::{run_index, session_scope};
+
+        let root = std::env::temp_dir().join(format!("w3rt_run_sessions_{}", now_ms()));
+        let store = RunStore { root: root.clone() };
+        let a = Some("sess_a".to_string());
+        let b = Some("sess_b".to_string());
+        for (session, run_id) in [
+            (a.clone(), "run_1_a"),
+            (b.clone(), "run_2_b"),
+            (None, "run_3"),
+        ] {
+            let state = session_scope::sync_scope(session, || RunState::new(run_id));
+            store.save_state(&state).unwrap();
+        }
+
+        let all = run_index::RunFilter::default();
+        session_scope::sync_scope(a.clone(), || {
+            let (rows, total) = run_index::list(&store, &all).unwrap();
+            assert_eq!(total, 1);
+            assert_eq!(rows[0].run_id, "run_1_a");
+            assert!(store.visible_in_session("run_1_a"));
+            assert!(!store.visible_in_session("run_2_b"));
+            assert!(!store.visible_in_session("run_3"));
+        });
+        // Unscoped (stdio) sees every run.
+        assert_eq!(run_index::list(&store, &all).unwrap().1, 3);
+        assert!(store.visible_in_session("run_2_b"));
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn session_retention_only_prunes_the_sessions_own_runs() {
+        use crate::utils::{run_index, session_scope};
+
+        let root = std::env::temp_dir().join(format!("w3rt_run_prune_{}", now_ms()));
+        let store = RunStore { root: root.clone() };
+        let a = Some("sess_a".to_string());
+        for (session, run_id) in [
+            (a.clone(), "run_1_a"),
+            (Some("sess_b".to_string()), "run_2_b"),
+            (None, "run_3"),
+        ] {
+            let state = session_scope::sync_scope(session, || RunState::new(run_id));
+            store.save_state(&state).unwrap();
+        }
+
+        let policy = run_index::RetentionPolicy {
+            max_runs: Some(0),
+            delete: true,
+            ..Default::default()
+        };
+        let result =
+            session_scope::sync_scope(a, || run_index::prune(&store, &policy, now_ms(), false))
+                .unwrap();
+        assert_eq!(result["pruned"], serde_json::json!(["run_1_a"]));
+        assert!(!store.run_dir("run_1_a").is_dir());
+        assert!(store.run_dir("run_2_b").is_dir());
+        assert!(store.run_dir("run_3").is_dir());
+        assert_eq!(
+            run_index::list(&store, &run_index::RunFilter::default())
+                .unwrap()
+                .1,
+            2
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
 }
@@ -0,0 +1,38 @@
+//! The MCP session a request is served for.
+//!
+//! Network transports (streamable HTTP, SSE) give every session its own id and run its
+//! requests inside [`scope`]; stores that keep per-client state (pending confirmations, W3RT runs) stamp
+//! rows with [`current`] and only show a session its own rows. Outside a scope (stdio, the
+//! single local client) everything is visible, as before.
+
+use std::future::Future;
+
+tokio::task_local! {
+    static SESSION: String;
+}
+
+/// New random session id.
+pub fn new_id() -> String {
+    format!("sess_{}", uuid::Uuid::new_v4().simple())
+}
+
+/// Run `f` as `session` (`None` runs it unscoped).
+pub async fn scope<F: Future>(session: Option<String>, f: F) -> F::Output {
+    match session {
+        Some(id) => SESSION.scope(id, f).await,
+        None => f.await,
+    }
+}
+
+/// Synchronous [`scope`].
+pub fn sync_scope<R>(session: Option<String>, f: impl FnOnce() -> R) -> R {
+    match session {
+        Some(id) => SESSION.sync_scope(id, f),
+        None => f(),
+    }
+}
+
+/// Session of the running request, if any.
+pub fn current() -> Option<String> {
+    SESSION.try_with(|s| s.clone()).ok()
+}
@@ -0,0 +1,956 @@
+//! Pluggable signers for the Sui, Solana and EVM execute / confirm paths.
+//!
+//! A [`Signer`] signs one chain-native payload and returns raw signature bytes:
+//! - Sui: BCS `IntentMessage<TransactionData>`; returns the serialized Sui signature
+//!   (`flag || signature || public key`).
+//! - Solana: the serialized transaction message; returns the 64-byte ed25519 signature.
+//! - EVM: the unsigned typed-transaction RLP (`TypedTransaction::rlp`, chain id set);
+//!   returns 65 bytes `r || s || v` (`v` as 0/1, 27/28 or EIP-155).
+//!
+//! Payloads are whole transactions rather than digests so a remote signer can apply its own
+//! policy before signing. Every signature is checked against the signer address before use.
+//!
+//! Backends (`signer.backend`, per chain `signer.<chain>_backend`):
+//! - `local` (default): `SUI_KEYSTORE_PATH`, `SOLANA_KEYPAIR_PATH`, `EVM_PRIVATE_KEY`.
+//! - `keystore`: Web3 Secret Storage files (scrypt + AES-128-CTR, the Foundry / geth format)
+//!   at `<signer.keystore_dir>/<chain>/<account>.json`, unlocked with
+//!   `WEB3MCP_SIGNER_KEYSTORE_PASSWORD`. Solana uses the account selected for the network
+//!   (see `solana_keystore`) before `signer.keystore_account`.
+//! - `remote`: an HTTP/JSON signer at `signer.remote_url`, so keys never enter this process.
+//!   `POST <url>/address {"chain", "address"?}` returns `{"address"}` and
+//!   `POST <url>/sign {"chain", "address", "payload_base64"}` returns `{"signature_base64"}`;
+//!   `signer.remote_token` is sent as `Authorization: Bearer <token>`.
+//! - `mnemonic`: an HD account derived from an encrypted BIP-39 phrase (see `mnemonic`).
+
+use async_trait::async_trait;
+use base64::Engine as _;
+use ethers::signers::Signer as _;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use rmcp::model::{ErrorCode, ErrorData};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const BACKEND_ENV: &str = "WEB3MCP_SIGNER_BACKEND";
+pub const KEYSTORE_DIR_ENV: &str = "WEB3MCP_SIGNER_KEYSTORE_DIR";
+pub const KEYSTORE_ACCOUNT_ENV: &str = "WEB3MCP_SIGNER_KEYSTORE_ACCOUNT";
+pub const KEYSTORE_PASSWORD_ENV: &str = "WEB3MCP_SIGNER_KEYSTORE_PASSWORD";
+pub const REMOTE_URL_ENV: &str = "WEB3MCP_SIGNER_URL";
+pub const REMOTE_TOKEN_ENV: &str = "WEB3MCP_SIGNER_TOKEN";
+pub const REMOTE_TIMEOUT_MS_ENV: &str = "WEB3MCP_SIGNER_TIMEOUT_MS";
+
+const DEFAULT_ACCOUNT: &str = "default";
+const DEFAULT_REMOTE_TIMEOUT_MS: u64 = 30_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignerChain {
+    Sui,
+    Solana,
+    Evm,
+}
+
+impl SignerChain {
+    pub const ALL: [SignerChain; 3] = [SignerChain::Sui, SignerChain::Solana, SignerChain::Evm];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SignerChain::Sui => "sui",
+            SignerChain::Solana => "solana",
+            SignerChain::Evm => "evm",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+
+    /// Per-chain override of [`BACKEND_ENV`].
+    pub fn backend_env(self) -> &'static str {
+        match self {
+            SignerChain::Sui => "WEB3MCP_SIGNER_SUI_BACKEND",
+            SignerChain::Solana => "WEB3MCP_SIGNER_SOLANA_BACKEND",
+            SignerChain::Evm => "WEB3MCP_SIGNER_EVM_BACKEND",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignerBackend {
+    Local,
+    Keystore,
+    Remote,
+    Mnemonic,
+}
+
+impl SignerBackend {
+    pub const NAMES: &'static [&'static str] = &["local", "keystore", "remote", "mnemonic"];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SignerBackend::Local => "local",
+            SignerBackend::Keystore => "keystore",
+            SignerBackend::Remote => "remote",
+            SignerBackend::Mnemonic => "mnemonic",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "local" => Some(SignerBackend::Local),
+            "keystore" => Some(SignerBackend::Keystore),
+            "remote" => Some(SignerBackend::Remote),
+            "mnemonic" => Some(SignerBackend::Mnemonic),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+pub trait Signer: Send + Sync {
+    fn chain(&self) -> SignerChain;
+
+    fn backend(&self) -> SignerBackend;
+
+    /// Chain-native address: Sui `0x…`, Solana base58, EVM `0x…`.
+    fn address(&self) -> &str;
+
+    /// Where the key lives: a file path, a keystore file or the (redacted) remote URL.
+    fn source(&self) -> String;
+
+    /// Sign one payload (see the module docs for the per-chain format).
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData>;
+
+    fn describe(&self) -> Value {
+        json!({
+            "chain": self.chain().as_str(),
+            "backend": self.backend().as_str(),
+            "address": self.address(),
+            "source": self.source(),
+        })
+    }
+}
+
+pub fn signer_unavailable(message: impl Into<String>, data: Value) -> ErrorData {
+    let mut data = data;
+    if let Some(obj) = data.as_object_mut() {
+        obj.insert("error_class".to_string(), json!("SIGNER_UNAVAILABLE"));
+    }
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(message.into()),
+        data: Some(data),
+    }
+}
+
+pub fn signature_invalid(message: impl Into<String>, data: Value) -> ErrorData {
+    let mut data = data;
+    if let Some(obj) = data.as_object_mut() {
+        obj.insert("error_class".to_string(), json!("SIGNATURE_INVALID"));
+    }
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(message.into()),
+        data: Some(data),
+    }
+}
+
+fn var(key: &str) -> Option<String> {
+    crate::utils::config::var(key)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Backend for `chain`: `signer.<chain>_backend`, else `signer.backend`, else `local`.
+pub fn backend_for(chain: SignerChain) -> Result<SignerBackend, ErrorData> {
+    let (key, raw) = match var(chain.backend_env()) {
+        Some(v) => (chain.backend_env(), v),
+        None => match var(BACKEND_ENV) {
+            Some(v) => (BACKEND_ENV, v),
+            None => return Ok(SignerBackend::Local),
+        },
+    };
+    SignerBackend::parse(&raw).ok_or_else(|| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!(
+            "{} must be one of: {}",
+            key,
+            SignerBackend::NAMES.join("|")
+        )),
+        data: Some(json!({ "provided": raw })),
+    })
+}
+
+// ---------------- keystore backend ----------------
+
+/// `signer.keystore_dir`, default `<data_dir>/keystores`.
+pub fn keystore_dir() -> PathBuf {
+    var(KEYSTORE_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| crate::utils::data_dir::data_dir().join("keystores"))
+}
+
+/// Account names are file stems: letters, digits, `-`, `_` and `.`, not starting with `.` (a
+/// `.json` suffix is dropped), so a name can never leave `<keystore_dir>/<chain>/`.
+pub fn validate_account_name(name: &str) -> Result<String, ErrorData> {
+    let trimmed = name.trim();
+    let stem = trimmed.strip_suffix(".json").unwrap_or(trimmed);
+    let ok = !stem.is_empty()
+        && stem.len() <= 64
+        && !stem.starts_with('.')
+        && stem
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if ok {
+        Ok(stem.to_string())
+    } else {
+        Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(
+                "keystore account must be 1-64 characters of letters, digits, '-', '_' or '.'",
+            ),
+            data: Some(json!({ "account": name })),
+        })
+    }
+}
+
+/// `<keystore_dir>/<chain>/<account>.json`; `account` defaults to `signer.keystore_account`,
+/// then `default`.
+pub fn keystore_path(chain: SignerChain, account: Option<&str>) -> Result<PathBuf, ErrorData> {
+    let account = account
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .or_else(|| var(KEYSTORE_ACCOUNT_ENV))
+        .unwrap_or_else(|| DEFAULT_ACCOUNT.to_string());
+    let stem = validate_account_name(&account)?;
+    Ok(keystore_dir()
+        .join(chain.as_str())
+        .join(format!("{}.json", stem)))
+}
+
+/// Decrypt one Web3 Secret Storage file into the raw secret it holds.
+pub fn decrypt_keystore_file(path: &Path, password: &str) -> Result<Vec<u8>, ErrorData> {
+    if !path.exists() {
+        return Err(signer_unavailable(
+            format!("Keystore file not found at {}", path.display()),
+            json!({ "path": path.display().to_string() }),
+        ));
+    }
+    eth_keystore::decrypt_key(path, password).map_err(|e| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!(
+            "Failed to decrypt keystore {}: {}",
+            path.display(),
+            e
+        )),
+        data: Some(json!({ "path": path.display().to_string() })),
+    })
+}
+
+/// Explicit password, else `WEB3MCP_SIGNER_KEYSTORE_PASSWORD`.
+pub fn keystore_password(explicit: Option<&str>) -> Result<String, ErrorData> {
+    if let Some(p) = explicit.filter(|p| !p.is_empty()) {
+        return Ok(p.to_string());
+    }
+    var(KEYSTORE_PASSWORD_ENV)
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Provide password or set {}", KEYSTORE_PASSWORD_ENV)),
+            data: None,
+        })
+}
+
+/// Encrypt `secret` into `<dir>/<file>` and add the `public` fields in plaintext. The file is
+/// readable by the owner only from the start: it is written inside an owner-only staging
+/// directory and renamed into place.
+pub fn write_keystore_file(
+    dir: &Path,
+    file: &str,
+    secret: &[u8],
+    password: &str,
+    public: Value,
+) -> Result<PathBuf, ErrorData> {
+    let path = dir.join(file);
+    let io_error = |action: &str, e: &dyn std::fmt::Display| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("Failed to {} {}: {}", action, path.display(), e)),
+        data: Some(json!({ "path": path.display().to_string() })),
+    };
+    std::fs::create_dir_all(dir).map_err(|e| io_error("create the directory of", &e))?;
+    let staging =
+        StagingDir::create(dir).map_err(|e| io_error("create a staging directory for", &e))?;
+    let staged = staging.0.join(file);
+    let mut rng = ethers::core::rand::thread_rng();
+    eth_keystore::encrypt_key(&staging.0, &mut rng, secret, password, Some(file))
+        .map_err(|e| io_error("write", &e))?;
+
+    let raw = std::fs::read_to_string(&staged).map_err(|e| io_error("read", &e))?;
+    let mut doc: Value = serde_json::from_str(&raw).map_err(|e| io_error("parse", &e))?;
+    if let (Some(doc), Value::Object(public)) = (doc.as_object_mut(), public) {
+        doc.extend(public);
+    }
+    write_owner_only(
+        &staged,
+        &serde_json::to_string_pretty(&doc).unwrap_or_default(),
+    )?;
+    std::fs::rename(&staged, &path).map_err(|e| io_error("write", &e))?;
+    Ok(path)
+}
+
+/// Owner-only directory next to the target file, removed on drop.
+struct StagingDir(PathBuf);
+
+impl StagingDir {
+    fn create(parent: &Path) -> std::io::Result<Self> {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let dir = parent.join(format!(".staging-{}-{}", std::process::id(), nanos));
+        let mut builder = std::fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder.create(&dir)?;
+        Ok(Self(dir))
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Write `contents` readable by the owner only (created with mode 0600; an existing file is
+/// narrowed to it before the new contents go in).
+pub fn write_owner_only(path: &Path, contents: &str) -> Result<(), ErrorData> {
+    use std::io::Write;
+    let io_error = |e: std::io::Error| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("Failed to write {}: {}", path.display(), e)),
+        data: Some(json!({ "path": path.display().to_string() })),
+    };
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        if path.exists() {
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+                .map_err(io_error)?;
+        }
+    }
+    let mut f = options.open(path).map_err(io_error)?;
+    f.write_all(contents.as_bytes()).map_err(io_error)?;
+    Ok(())
+}
+
+/// Serializes tests that set process env vars (`KEYSTORE_DIR_ENV`, RPC URL lists).
+#[cfg(test)]
+pub(crate) static ENV_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Secret of the configured keystore account for `chain`, with the file it came from.
+pub fn keystore_secret(
+    chain: SignerChain,
+    account: Option<&str>,
+) -> Result<(Vec<u8>, PathBuf), ErrorData> {
+    let path = keystore_path(chain, account)?;
+    let password = var(KEYSTORE_PASSWORD_ENV).ok_or_else(|| {
+        signer_unavailable(
+            format!(
+                "{} is required by the keystore signer",
+                KEYSTORE_PASSWORD_ENV
+            ),
+            json!({ "path": path.display().to_string() }),
+        )
+    })?;
+    let secret = decrypt_keystore_file(&path, &password)?;
+    Ok((secret, path))
+}
+
+// ---------------- EVM (local key / keystore) ----------------
+
+/// `EVM_PRIVATE_KEY` or a keystore secp256k1 key held in memory.
+pub struct EvmWalletSigner {
+    wallet: ethers::signers::LocalWallet,
+    address: String,
+    backend: SignerBackend,
+    source: String,
+}
+
+impl EvmWalletSigner {
+    pub fn new(
+        wallet: ethers::signers::LocalWallet,
+        backend: SignerBackend,
+        source: impl Into<String>,
+    ) -> Self {
+        let address = format!("{:?}", wallet.address());
+        Self {
+            wallet,
+            address,
+            backend,
+            source: source.into(),
+        }
+    }
+
+    pub fn local() -> Result<Self, ErrorData> {
+        let pk = var("EVM_PRIVATE_KEY").ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("Missing EVM_PRIVATE_KEY env var"),
+            data: None,
+        })?;
+        let wallet: ethers::signers::LocalWallet = pk.parse().map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid EVM_PRIVATE_KEY: {}", e)),
+            data: None,
+        })?;
+        Ok(Self::new(
+            wallet,
+            SignerBackend::Local,
+            "env:EVM_PRIVATE_KEY",
+        ))
+    }
+
+    pub fn from_keystore_file(path: &Path, password: &str) -> Result<Self, ErrorData> {
+        let secret = decrypt_keystore_file(path, password)?;
+        Self::from_secret(&secret, SignerBackend::Keystore, path.display().to_string())
+    }
+
+    pub fn keystore(account: Option<&str>) -> Result<Self, ErrorData> {
+        let (secret, path) = keystore_secret(SignerChain::Evm, account)?;
+        Self::from_secret(&secret, SignerBackend::Keystore, path.display().to_string())
+    }
+
+    fn from_secret(
+        secret: &[u8],
+        backend: SignerBackend,
+        source: String,
+    ) -> Result<Self, ErrorData> {
+        let wallet = ethers::signers::LocalWallet::from_bytes(secret).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid EVM key in {}: {}", source, e)),
+            data: None,
+        })?;
+        Ok(Self::new(wallet, backend, source))
+    }
+}
+
+#[async_trait]
+impl Signer for EvmWalletSigner {
+    fn chain(&self) -> SignerChain {
+        SignerChain::Evm
+    }
+
+    fn backend(&self) -> SignerBackend {
+        self.backend
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData> {
+        let tx: TypedTransaction = ethers::utils::rlp::decode(payload).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid EVM signing payload: {}", e)),
+            data: None,
+        })?;
+        let sig = self
+            .wallet
+            .sign_transaction_sync(&tx)
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Failed to sign transaction: {}", e)),
+                data: None,
+            })?;
+        Ok(sig.to_vec())
+    }
+}
+
+/// Any configured EVM signer (`local` / `keystore` / `remote` / `mnemonic`).
+pub async fn evm_signer(account: Option<&str>) -> Result<Box<dyn Signer>, ErrorData> {
+    match backend_for(SignerChain::Evm)? {
+        SignerBackend::Local => Ok(Box::new(EvmWalletSigner::local()?)),
+        SignerBackend::Keystore => Ok(Box::new(EvmWalletSigner::keystore(account)?)),
+        SignerBackend::Mnemonic => {
+            let (secret, source) = crate::utils::mnemonic::signer_secret(SignerChain::Evm)?;
+            Ok(Box::new(EvmWalletSigner::from_secret(
+                &secret,
+                SignerBackend::Mnemonic,
+                source,
+            )?))
+        }
+        SignerBackend::Remote => Ok(Box::new(
+            RemoteSigner::connect(SignerChain::Evm, None).await?,
+        )),
+    }
+}
+
+/// Sign `tx` with an EVM signer and check the signature recovers to the signer address.
+/// The chain id must already be set on `tx`.
+pub async fn evm_sign_typed(
+    signer: &dyn Signer,
+    tx: &TypedTransaction,
+) -> Result<ethers::types::Signature, ErrorData> {
+    let chain_id = tx.chain_id().map(|c| c.as_u64()).ok_or_else(|| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from("EVM transaction is missing chain_id"),
+        data: None,
+    })?;
+    let bytes = signer.sign(tx.rlp().as_ref()).await?;
+    let mut sig = ethers::types::Signature::try_from(bytes.as_slice()).map_err(|e| {
+        signature_invalid(
+            format!("Signer returned an invalid EVM signature: {}", e),
+            json!({ "signer": signer.address(), "len": bytes.len() }),
+        )
+    })?;
+    let recovery_id = match sig.v {
+        0 | 1 => sig.v,
+        27 | 28 => sig.v - 27,
+        v if v >= 35 => (v - 35) % 2,
+        v => {
+            return Err(signature_invalid(
+                format!("Signer returned an EVM signature with v={}", v),
+                json!({ "signer": signer.address() }),
+            ))
+        }
+    };
+    sig.v = ethers::signers::to_eip155_v(recovery_id as u8, chain_id);
+
+    let recovered = sig.recover(tx.sighash()).map_err(|e| {
+        signature_invalid(
+            format!("EVM signature does not recover: {}", e),
+            json!({ "signer": signer.address() }),
+        )
+    })?;
+    if !format!("{:?}", recovered).eq_ignore_ascii_case(signer.address()) {
+        return Err(signature_invalid(
+            "EVM signature was not made by the signer address",
+            json!({ "signer": signer.address(), "recovered": format!("{:?}", recovered) }),
+        ));
+    }
+    Ok(sig)
+}
+
+// ---------------- remote backend ----------------
+
+#[derive(Debug, Clone)]
+pub struct RemoteSignerConfig {
+    pub url: String,
+    pub token: Option<String>,
+    pub timeout_ms: u64,
+}
+
+impl RemoteSignerConfig {
+    pub fn from_config() -> Result<Self, ErrorData> {
+        let url = var(REMOTE_URL_ENV).ok_or_else(|| {
+            signer_unavailable(
+                format!("{} is required by the remote signer", REMOTE_URL_ENV),
+                json!({}),
+            )
+        })?;
+        Ok(Self {
+            url: url.trim_end_matches('/').to_string(),
+            token: var(REMOTE_TOKEN_ENV),
+            timeout_ms: var(REMOTE_TIMEOUT_MS_ENV)
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_REMOTE_TIMEOUT_MS),
+        })
+    }
+}
+
+/// Signer that forwards every payload to an HTTP/JSON signing service.
+pub struct RemoteSigner {
+    chain: SignerChain,
+    config: RemoteSignerConfig,
+    client: reqwest::Client,
+    address: String,
+}
+
+impl RemoteSigner {
+    /// Remote signer from `signer.remote_*`. `address` asks for one specific account.
+    pub async fn connect(chain: SignerChain, address: Option<&str>) -> Result<Self, ErrorData> {
+        Self::connect_with(RemoteSignerConfig::from_config()?, chain, address).await
+    }
+
+    pub async fn connect_with(
+        config: RemoteSignerConfig,
+        chain: SignerChain,
+        address: Option<&str>,
+    ) -> Result<Self, ErrorData> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .map_err(|e| {
+                signer_unavailable(format!("Failed to build HTTP client: {}", e), json!({}))
+            })?;
+        let mut signer = Self {
+            chain,
+            config,
+            client,
+            address: String::new(),
+        };
+        let resp = signer
+            .post(
+                "address",
+                json!({ "chain": chain.as_str(), "address": address }),
+            )
+            .await?;
+        let resolved = resp
+            .get("address")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .ok_or_else(|| {
+                signer_unavailable(
+                    "Remote signer returned no address",
+                    json!({ "chain": chain.as_str(), "response": resp }),
+                )
+            })?;
+        if let Some(wanted) = address {
+            if !resolved.eq_ignore_ascii_case(wanted.trim()) {
+                return Err(signer_unavailable(
+                    format!("Remote signer cannot sign for {}", wanted),
+                    json!({ "chain": chain.as_str(), "requested": wanted, "address": resolved }),
+                ));
+            }
+        }
+        signer.address = resolved.to_string();
+        Ok(signer)
+    }
+
+    async fn post(&self, path: &str, body: Value) -> Result<Value, ErrorData> {
+        let url = format!("{}/{}", self.config.url, path);
+        let redacted = crate::utils::config::redact_url(&url);
+        let mut req = self.client.post(&url).json(&body);
+        if let Some(token) = self.config.token.as_deref() {
+            req = req.bearer_auth(token);
+        }
+        let resp = req.send().await.map_err(|e| {
+            signer_unavailable(
+                format!("Remote signer request failed: {}", e),
+                json!({ "url": redacted }),
+            )
+        })?;
+        let status = resp.status();
+        let value: Value = resp.json().await.unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = value
+                .get("error")
+                .and_then(Value::as_str)
+                .unwrap_or_else(|| status.canonical_reason().unwrap_or("error"))
+                .to_string();
+            return Err(signer_unavailable(
+                format!("Remote signer refused ({}): {}", status.as_u16(), message),
+                json!({ "url": redacted, "status": status.as_u16(), "response": value }),
+            ));
+        }
+        Ok(value)
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    fn chain(&self) -> SignerChain {
+        self.chain
+    }
+
+    fn backend(&self) -> SignerBackend {
+        SignerBackend::Remote
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn source(&self) -> String {
+        crate::utils::config::redact_url(&self.config.url)
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData> {
+        let resp = self
+            .post(
+                "sign",
+                json!({
+                    "chain": self.chain.as_str(),
+                    "address": self.address,
+                    "payload_base64": base64::engine::general_purpose::STANDARD.encode(payload),
+                }),
+            )
+            .await?;
+        let encoded = resp
+            .get("signature_base64")
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                signature_invalid(
+                    "Remote signer returned no signature_base64",
+                    json!({ "response": resp }),
+                )
+            })?;
+        base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| {
+                signature_invalid(
+                    format!("Remote signer returned invalid base64: {}", e),
+                    json!({}),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    const KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn wallet() -> ethers::signers::LocalWallet {
+        KEY.parse().unwrap()
+    }
+
+    fn tx(chain_id: u64) -> TypedTransaction {
+        let mut tx: TypedTransaction =
+            ethers::types::transaction::eip1559::Eip1559TransactionRequest {
+                to: Some(ethers::types::Address::repeat_byte(0x11).into()),
+                value: Some(1_000u64.into()),
+                nonce: Some(7u64.into()),
+                gas: Some(21_000u64.into()),
+                max_fee_per_gas: Some(30u64.into()),
+                max_priority_fee_per_gas: Some(1u64.into()),
+                ..Default::default()
+            }
+            .into();
+        tx.set_chain_id(chain_id);
+        tx
+    }
+
+    /// Local stand-in for a remote signing service: answers `/address` and `/sign` for one
+    /// EVM key and rejects requests without the expected bearer token.
+    async fn stand_in(token: &'static str) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let signer = EvmWalletSigner::new(wallet(), SignerBackend::Local, "stand-in");
+            loop {
+                let (mut sock, _) = listener.accept().await.unwrap();
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                let (head, body) = loop {
+                    let n = sock.read(&mut chunk).await.unwrap();
+                    buf.extend_from_slice(&chunk[..n]);
+                    if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                        let head = String::from_utf8_lossy(&buf[..end]).to_lowercase();
+                        let len = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        while buf.len() < end + 4 + len {
+                            let n = sock.read(&mut chunk).await.unwrap();
+                            buf.extend_from_slice(&chunk[..n]);
+                        }
+                        break (head, buf[end + 4..end + 4 + len].to_vec());
+                    }
+                };
+                let req: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+                let (status, reply) = if !head.contains(&format!("bearer {}", token)) {
+                    ("401 Unauthorized", json!({"error": "bad token"}))
+                } else if head.starts_with("post /address") {
+                    match req["address"].as_str() {
+                        Some(a) if !a.eq_ignore_ascii_case(signer.address()) => {
+                            ("403 Forbidden", json!({"error": "unknown account"}))
+                        }
+                        _ => ("200 OK", json!({"address": signer.address()})),
+                    }
+                } else {
+                    let payload = base64::engine::general_purpose::STANDARD
+                        .decode(req["payload_base64"].as_str().unwrap_or(""))
+                        .unwrap();
+                    let sig = signer.sign(&payload).await.unwrap();
+                    (
+                        "200 OK",
+                        json!({"signature_base64": base64::engine::general_purpose::STANDARD.encode(sig)}),
+                    )
+                };
+                let body = reply.to_string();
+                let resp = format!(
+                    "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = sock.write_all(resp.as_bytes()).await;
+            }
+        });
+        url
+    }
+
+    fn remote_config(url: &str, token: &str) -> RemoteSignerConfig {
+        RemoteSignerConfig {
+            url: url.to_string(),
+            token: Some(token.to_string()),
+            timeout_ms: 5_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn local_evm_signature_recovers_to_the_signer() {
+        let signer = EvmWalletSigner::new(wallet(), SignerBackend::Local, "test");
+        let tx = tx(8453);
+        let sig = evm_sign_typed(&signer, &tx).await.unwrap();
+        assert_eq!(sig.recover(tx.sighash()).unwrap(), wallet().address());
+
+        let raw = tx.rlp_signed(&sig);
+        let (decoded, decoded_sig) =
+            TypedTransaction::decode_signed(&ethers::utils::rlp::Rlp::new(raw.as_ref())).unwrap();
+        // Typed transactions store the bare recovery id.
+        assert_eq!((decoded_sig.r, decoded_sig.s), (sig.r, sig.s));
+        assert_eq!(decoded.chain_id(), Some(8453u64.into()));
+    }
+
+    #[tokio::test]
+    async fn remote_signer_round_trips_through_a_stand_in() {
+        let url = stand_in("s3cret").await;
+        let signer =
+            RemoteSigner::connect_with(remote_config(&url, "s3cret"), SignerChain::Evm, None)
+                .await
+                .unwrap();
+        assert_eq!(signer.address(), format!("{:?}", wallet().address()));
+        assert_eq!(signer.backend(), SignerBackend::Remote);
+
+        let tx = tx(1);
+        let sig = evm_sign_typed(&signer, &tx).await.unwrap();
+        assert_eq!(sig.recover(tx.sighash()).unwrap(), wallet().address());
+
+        let bad_token =
+            RemoteSigner::connect_with(remote_config(&url, "nope"), SignerChain::Evm, None)
+                .await
+                .err()
+                .unwrap();
+        assert!(bad_token.message.contains("401"), "{}", bad_token.message);
+        assert_eq!(bad_token.data.unwrap()["error_class"], "SIGNER_UNAVAILABLE");
+
+        let other = format!("{:?}", ethers::types::Address::repeat_byte(0x22));
+        let wrong_account = RemoteSigner::connect_with(
+            remote_config(&url, "s3cret"),
+            SignerChain::Evm,
+            Some(&other),
+        )
+        .await
+        .err()
+        .unwrap();
+        assert!(
+            wrong_account.message.contains("403"),
+            "{}",
+            wrong_account.message
+        );
+    }
+
+    #[tokio::test]
+    async fn signatures_from_another_key_are_rejected() {
+        struct Impostor(EvmWalletSigner);
+
+        #[async_trait]
+        impl Signer for Impostor {
+            fn chain(&self) -> SignerChain {
+                SignerChain::Evm
+            }
+            fn backend(&self) -> SignerBackend {
+                SignerBackend::Remote
+            }
+            fn address(&self) -> &str {
+                "0x1111111111111111111111111111111111111111"
+            }
+            fn source(&self) -> String {
+                "test".to_string()
+            }
+            async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData> {
+                self.0.sign(payload).await
+            }
+        }
+
+        let impostor = Impostor(EvmWalletSigner::new(wallet(), SignerBackend::Local, "test"));
+        let err = evm_sign_typed(&impostor, &tx(1)).await.err().unwrap();
+        assert_eq!(err.data.unwrap()["error_class"], "SIGNATURE_INVALID");
+    }
+
+    #[test]
+    fn keystore_files_decrypt_with_the_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let secret = hex::decode(KEY).unwrap();
+        let mut rng = ethers::core::rand::thread_rng();
+        eth_keystore::encrypt_key(dir.path(), &mut rng, &secret, "pw", Some("default.json"))
+            .unwrap();
+        let path = dir.path().join("default.json");
+
+        let signer = EvmWalletSigner::from_keystore_file(&path, "pw").unwrap();
+        assert_eq!(signer.address(), format!("{:?}", wallet().address()));
+        assert_eq!(signer.backend(), SignerBackend::Keystore);
+
+        let err = EvmWalletSigner::from_keystore_file(&path, "wrong")
+            .err()
+            .unwrap();
+        assert_eq!(err.code, ErrorCode(-32602));
+        let missing = decrypt_keystore_file(&dir.path().join("nope.json"), "pw")
+            .err()
+            .unwrap();
+        assert_eq!(missing.data.unwrap()["error_class"], "SIGNER_UNAVAILABLE");
+    }
+
+    #[test]
+    fn keystore_files_are_owner_only_without_leftovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let secret = hex::decode(KEY).unwrap();
+        let path = write_keystore_file(
+            dir.path(),
+            "default.json",
+            &secret,
+            "pw",
+            json!({ "address": "0xabc" }),
+        )
+        .unwrap();
+
+        let doc: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(doc["address"], "0xabc");
+        assert!(EvmWalletSigner::from_keystore_file(&path, "pw").is_ok());
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1, "staging directory left behind");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn keystore_account_names_stay_inside_the_keystore_dir() {
+        let _guard = ENV_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let dir = tempfile::tempdir().unwrap();
+        std::env::set_var(KEYSTORE_DIR_ENV, dir.path());
+
+        let path = keystore_path(SignerChain::Sui, Some("sponsor.json")).unwrap();
+        assert_eq!(path, dir.path().join("sui").join("sponsor.json"));
+        for bad in ["../evil", "/etc/passwd", "a/b", "..", ".hidden", "x\\y"] {
+            let err = keystore_path(SignerChain::Sui, Some(bad)).err().unwrap();
+            assert_eq!(err.code, ErrorCode(-32602), "{}", bad);
+        }
+
+        std::env::remove_var(KEYSTORE_DIR_ENV);
+    }
+}
@@ -0,0 +1,474 @@
+use crate::utils::signer::{
+    signature_invalid, signer_unavailable, Signer, SignerBackend, SignerChain,
+};
+use crate::Web3McpServer;
+use async_trait::async_trait;
+use rmcp::model::*;
+use serde_json::json;
+use shared_crypto::intent::IntentMessage;
+use std::borrow::Cow;
+use std::str::FromStr;
+use sui_keys::keystore::AccountKeystore;
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{Signature, SuiKeyPair, SuiSignature, ToFromBytes};
+use sui_types::transaction::TransactionData;
+
+/// Solana keypair file or keystore secret held in memory.
+pub struct SolanaKeypairSigner {
+    keypair: solana_sdk::signature::Keypair,
+    address: String,
+    backend: SignerBackend,
+    source: String,
+}
+
+impl SolanaKeypairSigner {
+    pub fn new(
+        keypair: solana_sdk::signature::Keypair,
+        backend: SignerBackend,
+        source: impl Into<String>,
+    ) -> Self {
+        let address = solana_sdk::signature::Signer::pubkey(&keypair).to_string();
+        Self {
+            keypair,
+            address,
+            backend,
+            source: source.into(),
+        }
+    }
+
+    /// 64-byte keypair (the `solana-keygen` layout) or 32-byte ed25519 seed.
+    pub fn from_secret(
+        secret: &[u8],
+        backend: SignerBackend,
+        source: impl Into<String>,
+    ) -> Result<Self, ErrorData> {
+        let source = source.into();
+        let keypair = solana_keypair_from_secret(secret, &source)?;
+        Ok(Self::new(keypair, backend, source))
+    }
+}
+
+/// Sui ed25519 keypair from a 32-byte private key (e.g. a SLIP-0010 derived one).
+pub fn sui_ed25519_keypair(secret: &[u8], source: &str) -> Result<SuiKeyPair, ErrorData> {
+    let mut bytes = vec![sui_types::crypto::SignatureScheme::ED25519.flag()];
+    bytes.extend_from_slice(secret);
+    SuiKeyPair::from_bytes(&bytes).map_err(|e| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!("Invalid Sui ed25519 key from {}: {}", source, e)),
+        data: None,
+    })
+}
+
+/// Sui keystore-backend signer for `account` (`<keystore_dir>/sui/<account>.json`), or the
+/// default key file.
+fn sui_keystore_signer(account: Option<&str>) -> Result<SuiKeyPairSigner, ErrorData> {
+    let (secret, path) = crate::utils::signer::keystore_secret(SignerChain::Sui, account)?;
+    let keypair = SuiKeyPair::from_bytes(&secret).map_err(|e| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!("Invalid Sui key in {}: {}", path.display(), e)),
+        data: None,
+    })?;
+    let address = SuiAddress::from(&keypair.public());
+    Ok(SuiKeyPairSigner {
+        keypair,
+        address_str: address.to_string(),
+        backend: SignerBackend::Keystore,
+        source: path.display().to_string(),
+    })
+}
+
+/// Address of the HD account at `path` on `chain`.
+pub fn mnemonic_address(chain: SignerChain, phrase: &str, path: &str) -> Result<String, ErrorData> {
+    let source = format!("mnemonic {}", path);
+    match chain {
+        SignerChain::Evm => {
+            let wallet = crate::utils::mnemonic::evm_wallet(phrase, path)?;
+            Ok(format!("{:?}", ethers::signers::Signer::address(&wallet)))
+        }
+        SignerChain::Solana => {
+            let secret = crate::utils::mnemonic::derive_secret(chain, phrase, path)?;
+            let keypair = solana_keypair_from_secret(&secret, &source)?;
+            Ok(solana_sdk::signature::Signer::pubkey(&keypair).to_string())
+        }
+        SignerChain::Sui => {
+            let secret = crate::utils::mnemonic::derive_secret(chain, phrase, path)?;
+            let keypair = sui_ed25519_keypair(&secret, &source)?;
+            Ok(SuiAddress::from(&keypair.public()).to_string())
+        }
+    }
+}
+
+/// 64-byte keypair or 32-byte seed; `source` names where it came from in errors.
+pub fn solana_keypair_from_secret(
+    secret: &[u8],
+    source: &str,
+) -> Result<solana_sdk::signature::Keypair, ErrorData> {
+    match secret.len() {
+        64 => solana_sdk::signature::Keypair::try_from(secret).map_err(|e| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!("Invalid Solana keypair in {}: {}", source, e)),
+            data: None,
+        }),
+        32 => {
+            let mut seed = [0u8; 32];
+            seed.copy_from_slice(secret);
+            Ok(solana_sdk::signature::Keypair::new_from_array(seed))
+        }
+        n => Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!(
+                "Invalid Solana key in {}: expected 32 or 64 bytes, got {}",
+                source, n
+            )),
+            data: None,
+        }),
+    }
+}
+
+#[async_trait]
+impl Signer for SolanaKeypairSigner {
+    fn chain(&self) -> SignerChain {
+        SignerChain::Solana
+    }
+
+    fn backend(&self) -> SignerBackend {
+        self.backend
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData> {
+        let sig = solana_sdk::signature::Signer::sign_message(&self.keypair, payload);
+        Ok(sig.as_ref().to_vec())
+    }
+}
+
+/// Account of a Sui file keystore (`SUI_KEYSTORE_PATH`).
+pub struct SuiKeystoreSigner {
+    keystore: sui_keys::keystore::FileBasedKeystore,
+    address: SuiAddress,
+    address_str: String,
+    source: String,
+}
+
+/// Sui key from a keystore-backend file (`SuiKeyPair` bytes: scheme flag || private key).
+pub struct SuiKeyPairSigner {
+    keypair: SuiKeyPair,
+    address_str: String,
+    backend: SignerBackend,
+    source: String,
+}
+
+fn decode_sui_intent(payload: &[u8]) -> Result<IntentMessage<TransactionData>, ErrorData> {
+    bcs::from_bytes(payload).map_err(|e| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!("Invalid Sui signing payload: {}", e)),
+        data: None,
+    })
+}
+
+#[async_trait]
+impl Signer for SuiKeystoreSigner {
+    fn chain(&self) -> SignerChain {
+        SignerChain::Sui
+    }
+
+    fn backend(&self) -> SignerBackend {
+        SignerBackend::Local
+    }
+
+    fn address(&self) -> &str {
+        &self.address_str
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData> {
+        let msg = decode_sui_intent(payload)?;
+        let sig = self
+            .keystore
+            .sign_secure(&self.address, &msg.value, msg.intent)
+            .await
+            .map_err(|e| ErrorData {
+                code: ErrorCode(-32603),
+                message: Cow::from(format!("Failed to sign transaction: {}", e)),
+                data: None,
+            })?;
+        Ok(sig.as_ref().to_vec())
+    }
+}
+
+#[async_trait]
+impl Signer for SuiKeyPairSigner {
+    fn chain(&self) -> SignerChain {
+        SignerChain::Sui
+    }
+
+    fn backend(&self) -> SignerBackend {
+        self.backend
+    }
+
+    fn address(&self) -> &str {
+        &self.address_str
+    }
+
+    fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ErrorData> {
+        let msg = decode_sui_intent(payload)?;
+        Ok(Signature::new_secure(&msg, &self.keypair).as_ref().to_vec())
+    }
+}
+
+impl Web3McpServer {
+    /// Signer for the Solana execute / confirm paths on `network`. The `keystore` backend
+    /// unlocks the account selected for that network (`solana_keystore_select`).
+    pub async fn solana_signer(network: Option<&str>) -> Result<Box<dyn Signer>, ErrorData> {
+        match crate::utils::signer::backend_for(SignerChain::Solana)? {
+            SignerBackend::Local => {
+                let path = Self::solana_keypair_path()?;
+                let keypair = Self::solana_read_keypair_from_json_file(&path)?;
+                Ok(Box::new(SolanaKeypairSigner::new(
+                    keypair,
+                    SignerBackend::Local,
+                    path,
+                )))
+            }
+            SignerBackend::Keystore => {
+                let path = crate::utils::solana_keystore::signer_account(network)?;
+                let password = crate::utils::signer::keystore_password(None)?;
+                let secret = crate::utils::signer::decrypt_keystore_file(&path, &password)?;
+                Ok(Box::new(SolanaKeypairSigner::from_secret(
+                    &secret,
+                    SignerBackend::Keystore,
+                    path.display().to_string(),
+                )?))
+            }
+            SignerBackend::Remote => Ok(Box::new(
+                crate::utils::signer::RemoteSigner::connect(SignerChain::Solana, None).await?,
+            )),
+            SignerBackend::Mnemonic => {
+                let (secret, source) = crate::utils::mnemonic::signer_secret(SignerChain::Solana)?;
+                Ok(Box::new(SolanaKeypairSigner::from_secret(
+                    &secret,
+                    SignerBackend::Mnemonic,
+                    source,
+                )?))
+            }
+        }
+    }
+
+    pub fn solana_signer_pubkey(
+        signer: &dyn Signer,
+    ) -> Result<solana_sdk::pubkey::Pubkey, ErrorData> {
+        solana_sdk::pubkey::Pubkey::from_str(signer.address()).map_err(|e| {
+            signer_unavailable(
+                format!("Signer address is not a Solana pubkey: {}", e),
+                json!({ "address": signer.address() }),
+            )
+        })
+    }
+
+    async fn solana_signature(
+        signer: &dyn Signer,
+        message: &[u8],
+    ) -> Result<solana_sdk::signature::Signature, ErrorData> {
+        let pubkey = Self::solana_signer_pubkey(signer)?;
+        let bytes = signer.sign(message).await?;
+        let sig = solana_sdk::signature::Signature::try_from(bytes.as_slice()).map_err(|_| {
+            signature_invalid(
+                "Signer returned an invalid Solana signature",
+                json!({ "signer": signer.address(), "len": bytes.len() }),
+            )
+        })?;
+        if !sig.verify(pubkey.as_ref(), message) {
+            return Err(signature_invalid(
+                "Solana signature does not verify for the signer pubkey",
+                json!({ "signer": signer.address() }),
+            ));
+        }
+        Ok(sig)
+    }
+
+    fn solana_not_a_signer(signer: &dyn Signer) -> ErrorData {
+        ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from(format!(
+                "Signer {} is not a required signer of this transaction",
+                signer.address()
+            )),
+            data: Some(json!({ "signer": signer.address() })),
+        }
+    }
+
+    /// Set `recent_blockhash` and add `signer`'s signature (like `Transaction::sign`). Other
+    /// signatures are kept unless the blockhash changes.
+    pub async fn solana_sign_legacy(
+        tx: &mut solana_sdk::transaction::Transaction,
+        signer: &dyn Signer,
+        recent_blockhash: solana_sdk::hash::Hash,
+    ) -> Result<(), ErrorData> {
+        let pubkey = Self::solana_signer_pubkey(signer)?;
+        let required = tx.message.header.num_required_signatures as usize;
+        if tx.message.recent_blockhash != recent_blockhash || tx.signatures.len() != required {
+            tx.message.recent_blockhash = recent_blockhash;
+            tx.signatures = vec![solana_sdk::signature::Signature::default(); required];
+        }
+        let position = tx.message.account_keys[..required.min(tx.message.account_keys.len())]
+            .iter()
+            .position(|k| *k == pubkey)
+            .ok_or_else(|| Self::solana_not_a_signer(signer))?;
+        let sig = Self::solana_signature(signer, &tx.message_data()).await?;
+        tx.signatures[position] = sig;
+        Ok(())
+    }
+
+    /// Add `signer`'s signature to a versioned transaction (blockhash unchanged).
+    pub async fn solana_sign_versioned(
+        tx: &mut solana_transaction::versioned::VersionedTransaction,
+        signer: &dyn Signer,
+    ) -> Result<(), ErrorData> {
+        let pubkey = Self::solana_signer_pubkey(signer)?;
+        let required = tx.message.header().num_required_signatures as usize;
+        let keys = tx.message.static_account_keys();
+        let position = keys[..required.min(keys.len())]
+            .iter()
+            .position(|k| *k == pubkey)
+            .ok_or_else(|| Self::solana_not_a_signer(signer))?;
+        if tx.signatures.len() != required {
+            tx.signatures
+                .resize(required, solana_sdk::signature::Signature::default());
+        }
+        let sig = Self::solana_signature(signer, &tx.message.serialize()).await?;
+        tx.signatures[position] = sig;
+        Ok(())
+    }
+
+    /// Signer for the Sui execute / confirm paths. With the `local` backend `signer` (address
+    /// or alias) picks the keystore account, else `fallback` (usually the tx sender), else
+    /// `SUI_DEFAULT_SIGNER` or the only account. A remote signer is asked for the same account.
+    pub async fn sui_signer(
+        &self,
+        keystore_path: Option<&str>,
+        signer: Option<&str>,
+        fallback: Option<SuiAddress>,
+    ) -> Result<Box<dyn Signer>, ErrorData> {
+        match crate::utils::signer::backend_for(SignerChain::Sui)? {
+            SignerBackend::Local => {
+                let keystore = self.load_file_keystore(keystore_path)?;
+                let address = match (signer, fallback) {
+                    (Some(s), _) => self.resolve_keystore_signer(&keystore, Some(s))?,
+                    (None, Some(addr)) => addr,
+                    (None, None) => self.resolve_keystore_signer(&keystore, None)?,
+                };
+                let source = keystore_path
+                    .map(str::to_string)
+                    .or_else(|| crate::utils::config::var("SUI_KEYSTORE_PATH").ok())
+                    .unwrap_or_else(|| "~/.sui/sui_config/sui.keystore".to_string());
+                Ok(Box::new(SuiKeystoreSigner {
+                    keystore,
+                    address,
+                    address_str: address.to_string(),
+                    source,
+                }))
+            }
+            SignerBackend::Keystore => Ok(Box::new(sui_keystore_signer(None)?)),
+            SignerBackend::Mnemonic => {
+                let (secret, source) = crate::utils::mnemonic::signer_secret(SignerChain::Sui)?;
+                let keypair = sui_ed25519_keypair(&secret, &source)?;
+                let address = SuiAddress::from(&keypair.public());
+                Ok(Box::new(SuiKeyPairSigner {
+                    keypair,
+                    address_str: address.to_string(),
+                    backend: SignerBackend::Mnemonic,
+                    source,
+                }))
+            }
+            SignerBackend::Remote => {
+                let wanted = signer
+                    .map(str::to_string)
+                    .or_else(|| fallback.map(|a| a.to_string()));
+                Ok(Box::new(
+                    crate::utils::signer::RemoteSigner::connect(
+                        SignerChain::Sui,
+                        wanted.as_deref(),
+                    )
+                    .await?,
+                ))
+            }
+        }
+    }
+
+    /// Gas sponsor signer (`sui.sponsor.account` in the signer keystore). Independent of the
+    /// Sui signer backend, so the sponsor key never doubles as a sender key.
+    pub fn sui_sponsor_signer(account: &str) -> Result<Box<dyn Signer>, ErrorData> {
+        Ok(Box::new(sui_keystore_signer(Some(account))?))
+    }
+
+    pub fn sui_signer_address(signer: &dyn Signer) -> Result<SuiAddress, ErrorData> {
+        SuiAddress::from_str(signer.address()).map_err(|e| {
+            signer_unavailable(
+                format!("Signer address is not a Sui address: {}", e),
+                json!({ "address": signer.address() }),
+            )
+        })
+    }
+
+    /// Sign `tx_data` as a Sui transaction; the signature must verify over the transaction intent
+    /// for `signer`'s address.
+    pub async fn sui_sign_tx_data(
+        signer: &dyn Signer,
+        tx_data: &TransactionData,
+    ) -> Result<Signature, ErrorData> {
+        let address = Self::sui_signer_address(signer)?;
+        let msg = IntentMessage::new(
+            shared_crypto::intent::Intent::sui_transaction(),
+            tx_data.clone(),
+        );
+        let payload = bcs::to_bytes(&msg).map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("Failed to serialize transaction: {}", e)),
+            data: None,
+        })?;
+        let bytes = signer.sign(&payload).await?;
+        let sig = Signature::from_bytes(&bytes).map_err(|e| {
+            signature_invalid(
+                format!("Signer returned an invalid Sui signature: {}", e),
+                json!({ "signer": signer.address() }),
+            )
+        })?;
+        let signed_by = sig
+            .to_public_key()
+            .map(|pk| SuiAddress::from(&pk))
+            .map_err(|e| {
+                signature_invalid(
+                    format!("Sui signature has no usable public key: {}", e),
+                    json!({ "signer": signer.address() }),
+                )
+            })?;
+        if signed_by != address {
+            return Err(signature_invalid(
+                "Sui signature was not made by the signer address",
+                json!({ "signer": signer.address(), "signed_by": signed_by.to_string() }),
+            ));
+        }
+        // Catch a broken or malicious remote signer before the signature reaches the network.
+        sig.verify_secure(&msg, address, sig.scheme()).map_err(|e| {
+            signature_invalid(
+                format!("Sui signature does not verify for this transaction: {}", e),
+                json!({ "signer": signer.address() }),
+            )
+        })?;
+        Ok(sig)
+    }
+}
@@ -1,7 +1,9 @@
+use crate::utils::pending_store::{
+    self, ListFilter, NewPending, PendingChain, PendingRecord, PendingStatus,
+};
 use rmcp::model::{ErrorCode, ErrorData};
-use rusqlite::OptionalExtension as _;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::borrow::Cow;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,24 +18,15 @@ pub struct PendingSolanaConfirmation {
 }
 
 pub fn now_ms() -> u64 {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64
+    pending_store::now_ms()
 }
 
 pub fn default_ttl_ms() -> u64 {
-    15 * 60 * 1000
+    PendingChain::Solana.default_ttl_ms()
 }
 
-pub fn pending_db_path_from_cwd() -> Result<std::path::PathBuf, ErrorData> {
-    let cwd = std::env::current_dir().map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to get current_dir: {}", e)),
-        data: None,
-    })?;
-    Ok(cwd.join(".data").join("pending.sqlite"))
+pub fn pending_db_path() -> Result<std::path::PathBuf, ErrorData> {
+    pending_store::db_path()
 }
 
 fn legacy_json_store_path_from_cwd() -> Result<std::path::PathBuf, ErrorData> {
@@ -46,104 +39,21 @@ fn legacy_json_store_path_from_cwd() -> Result<std::path::PathBuf, ErrorData> {
 }
 
 pub fn store_path() -> std::path::PathBuf {
-    pending_db_path_from_cwd().unwrap_or_else(|_| std::path::PathBuf::from(".data/pending.sqlite"))
+    crate::utils::data_dir::pending_db_path()
 }
 
-fn connect() -> Result<rusqlite::Connection, ErrorData> {
-    let path = pending_db_path_from_cwd()?;
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to create data dir: {}", e)),
-            data: None,
-        })?;
-    }
-
-    let mut conn = rusqlite::Connection::open(path).map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to open sqlite db: {}", e)),
-        data: None,
-    })?;
-
-    // Base schema
-    conn.execute_batch(
-        "BEGIN;
-         CREATE TABLE IF NOT EXISTS solana_pending_confirmations (
-           id TEXT PRIMARY KEY,
-           tx_base64 TEXT NOT NULL,
-           created_at_ms INTEGER NOT NULL,
-           expires_at_ms INTEGER NOT NULL,
-           tx_summary_hash TEXT NOT NULL,
-           source_tool TEXT NOT NULL
-         );
-         CREATE INDEX IF NOT EXISTS idx_solana_pending_expires ON solana_pending_confirmations(expires_at_ms);
-         COMMIT;",
-    )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to init sqlite schema: {}", e)),
-        data: None,
-    })?;
-
-    // Migrations
-    let mut existing_cols = std::collections::HashSet::<String>::new();
-    {
-        let mut stmt = conn
-            .prepare("PRAGMA table_info(solana_pending_confirmations)")
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to prepare PRAGMA table_info: {}", e)),
-                data: None,
-            })?;
-        let rows = stmt
-            .query_map([], |row| row.get::<_, String>(1))
-            .map_err(|e| ErrorData {
-                code: ErrorCode(-32603),
-                message: Cow::from(format!("Failed to query PRAGMA table_info: {}", e)),
-                data: None,
-            })?;
-        for c in rows.flatten() {
-            existing_cols.insert(c);
-        }
-    }
-
-    let mut alters: Vec<&str> = Vec::new();
-    if !existing_cols.contains("updated_at_ms") {
-        alters.push("ALTER TABLE solana_pending_confirmations ADD COLUMN updated_at_ms INTEGER");
-    }
-    if !existing_cols.contains("status") {
-        alters.push("ALTER TABLE solana_pending_confirmations ADD COLUMN status TEXT");
-    }
-    if !existing_cols.contains("signature") {
-        alters.push("ALTER TABLE solana_pending_confirmations ADD COLUMN signature TEXT");
-    }
-    if !existing_cols.contains("last_error") {
-        alters.push("ALTER TABLE solana_pending_confirmations ADD COLUMN last_error TEXT");
-    }
-    if !existing_cols.contains("summary_json") {
-        alters.push("ALTER TABLE solana_pending_confirmations ADD COLUMN summary_json TEXT");
-    }
-
-    for sql in alters {
-        let _ = conn.execute(sql, []);
-    }
-
-    let _ = conn.execute(
-        "UPDATE solana_pending_confirmations
-         SET updated_at_ms = COALESCE(updated_at_ms, created_at_ms),
-             status = COALESCE(status, 'pending')
-         WHERE updated_at_ms IS NULL OR status IS NULL",
-        [],
-    );
+/// Open the shared pending store (rows of this module have `chain = 'solana'`).
+pub fn connect() -> Result<rusqlite::Connection, ErrorData> {
+    let conn = pending_store::connect()?;
 
     // Best-effort migrate legacy JSON store into sqlite (one-way, keep file).
-    migrate_legacy_json_store_if_needed(&mut conn)?;
+    migrate_legacy_json_store_if_needed(&conn)?;
 
     Ok(conn)
 }
 
-fn migrate_legacy_json_store_if_needed(conn: &mut rusqlite::Connection) -> Result<(), ErrorData> {
-    // If legacy store exists and sqlite table is empty, import.
+fn migrate_legacy_json_store_if_needed(conn: &rusqlite::Connection) -> Result<(), ErrorData> {
+    // If legacy store exists and there are no Solana rows yet, import.
     let legacy_path = legacy_json_store_path_from_cwd()?;
     if !legacy_path.exists() {
         return Ok(());
@@ -151,7 +61,7 @@ fn migrate_legacy_json_store_if_needed(conn: &mut rusqlite::Connection) -> Resul
 
     let count: i64 = conn
         .query_row(
-            "SELECT COUNT(1) FROM solana_pending_confirmations",
+            "SELECT COUNT(1) FROM pending_confirmations WHERE chain='solana'",
             [],
             |row| row.get(0),
         )
@@ -178,32 +88,26 @@ fn migrate_legacy_json_store_if_needed(conn: &mut rusqlite::Connection) -> Resul
         data: Some(serde_json::json!({"path": legacy_path.to_string_lossy()})),
     })?;
 
-    let tx = conn.transaction().map_err(|e| ErrorData {
+    let tx = conn.unchecked_transaction().map_err(|e| ErrorData {
         code: ErrorCode(-32603),
         message: Cow::from(format!("Failed to start sqlite transaction: {}", e)),
         data: None,
     })?;
 
     for (id, v) in store.pending {
-        let summary_json = v
-            .summary
-            .as_ref()
-            .map(|vv| serde_json::to_string(vv).unwrap_or_else(|_| "{}".to_string()));
-
-        let _ = tx.execute(
-            "INSERT OR REPLACE INTO solana_pending_confirmations
-             (id, tx_base64, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash, status, signature, last_error, source_tool, summary_json)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'pending', NULL, NULL, ?7, ?8)",
-            rusqlite::params![
-                id,
-                v.tx_base64,
-                v.created_ms as i64,
-                v.created_ms as i64,
-                v.expires_ms as i64,
-                v.tx_summary_hash,
-                v.source_tool,
-                summary_json,
-            ],
+        let _ = pending_store::insert(
+            &tx,
+            &NewPending {
+                id: &id,
+                chain: PendingChain::Solana,
+                network: None,
+                created_at_ms: v.created_ms,
+                expires_at_ms: v.expires_ms,
+                tx_summary_hash: &v.tx_summary_hash,
+                source_tool: Some(&v.source_tool),
+                summary: v.summary.as_ref(),
+                payload: &json!({ "tx_base64": v.tx_base64 }),
+            },
         );
     }
 
@@ -223,62 +127,51 @@ pub fn tx_summary_hash(tx_bytes: &[u8]) -> String {
 }
 
 pub fn make_confirm_token(id: &str, tx_summary_hash: &str) -> String {
-    use sha2::{Digest, Sha256};
-    let msg = format!("solana:{}:{}", id, tx_summary_hash);
-    let h = Sha256::digest(msg.as_bytes());
-    format!("0x{}", hex::encode(h))
+    pending_store::confirm_token(PendingChain::Solana, id, tx_summary_hash)
+}
+
+fn confirmation_from_record(r: PendingRecord) -> PendingSolanaConfirmation {
+    PendingSolanaConfirmation {
+        tx_base64: r.payload_str("tx_base64").unwrap_or_default().to_string(),
+        id: r.id,
+        created_ms: r.created_at_ms,
+        expires_ms: r.expires_at_ms,
+        tx_summary_hash: r.tx_summary_hash,
+        source_tool: r.source_tool.unwrap_or_default(),
+        summary: r.summary,
+    }
+}
+
+fn pending_filter(limit: usize) -> ListFilter {
+    ListFilter {
+        chain: Some(PendingChain::Solana),
+        network: None,
+        status: Some(PendingStatus::Pending),
+        limit,
+    }
+}
+
+fn count_pending(conn: &rusqlite::Connection) -> i64 {
+    conn.query_row(
+        "SELECT COUNT(1) FROM pending_confirmations WHERE chain='solana' AND status='pending'",
+        [],
+        |row| row.get(0),
+    )
+    .unwrap_or(0)
 }
 
 pub fn list_pending() -> Result<Vec<PendingSolanaConfirmation>, ErrorData> {
     let conn = connect()?;
-    let now = now_ms() as i64;
 
     // best-effort cleanup expired
-    let _ = conn.execute(
-        "DELETE FROM solana_pending_confirmations WHERE expires_at_ms < ?1",
-        [now],
-    );
-
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, tx_base64, created_at_ms, expires_at_ms, tx_summary_hash, source_tool, summary_json
-             FROM solana_pending_confirmations
-             WHERE status='pending'
-             ORDER BY created_at_ms DESC",
-        )
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to prepare list_pending: {}", e)),
-            data: None,
-        })?;
-
-    let rows = stmt
-        .query_map([], |row| {
-            let summary_json: Option<String> = row.get(6)?;
-            let summary = summary_json
-                .as_deref()
-                .and_then(|s| serde_json::from_str::<Value>(s).ok());
-            Ok(PendingSolanaConfirmation {
-                id: row.get(0)?,
-                tx_base64: row.get(1)?,
-                created_ms: row.get::<_, i64>(2)? as u64,
-                expires_ms: row.get::<_, i64>(3)? as u64,
-                tx_summary_hash: row.get(4)?,
-                source_tool: row.get(5)?,
-                summary,
-            })
-        })
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to query list_pending: {}", e)),
-            data: None,
-        })?;
-
-    let mut out: Vec<PendingSolanaConfirmation> = Vec::new();
-    for v in rows.flatten() {
-        out.push(v);
-    }
-    Ok(out)
+    let _ = pending_store::sweep(&conn, now_ms());
+
+    Ok(
+        pending_store::list(&conn, &pending_filter(i64::MAX as usize))?
+            .into_iter()
+            .map(confirmation_from_record)
+            .collect(),
+    )
 }
 
 pub fn cleanup(
@@ -287,37 +180,21 @@ pub fn cleanup(
 ) -> Result<serde_json::Value, ErrorData> {
     let conn = connect()?;
 
-    let before: i64 = conn
-        .query_row(
-            "SELECT COUNT(1) FROM solana_pending_confirmations WHERE status='pending'",
-            [],
-            |row| row.get(0),
-        )
-        .unwrap_or(0);
+    let before = count_pending(&conn);
 
-    // Always remove expired
-    let _ = conn.execute(
-        "DELETE FROM solana_pending_confirmations WHERE expires_at_ms < ?1",
-        [now as i64],
-    );
+    // Always expire/remove stale rows
+    pending_store::sweep(&conn, now)?;
 
-    // Optionally remove old entries by created_at_ms
+    // Optionally remove old Solana entries (spend-ledger rows are kept)
     if let Some(age) = delete_older_than_ms {
-        let cutoff = now.saturating_sub(age);
-        let _ = conn.execute(
-            "DELETE FROM solana_pending_confirmations WHERE created_at_ms < ?1",
-            [cutoff as i64],
-        );
+        let filter = ListFilter {
+            chain: Some(PendingChain::Solana),
+            ..ListFilter::default()
+        };
+        pending_store::purge(&conn, &filter, now.saturating_sub(age), now)?;
     }
 
-    let after: i64 = conn
-        .query_row(
-            "SELECT COUNT(1) FROM solana_pending_confirmations WHERE status='pending'",
-            [],
-            |row| row.get(0),
-        )
-        .unwrap_or(0);
-
+    let after = count_pending(&conn);
     let removed = (before - after).max(0);
 
     Ok(serde_json::json!({
@@ -329,28 +206,9 @@ pub fn cleanup(
 
 pub fn cleanup_expired() -> Result<usize, ErrorData> {
     let conn = connect()?;
-    let now = now_ms() as i64;
-    let before: i64 = conn
-        .query_row(
-            "SELECT COUNT(1) FROM solana_pending_confirmations WHERE status='pending'",
-            [],
-            |row| row.get(0),
-        )
-        .unwrap_or(0);
-
-    let _ = conn.execute(
-        "DELETE FROM solana_pending_confirmations WHERE expires_at_ms < ?1",
-        [now],
-    );
-
-    let after: i64 = conn
-        .query_row(
-            "SELECT COUNT(1) FROM solana_pending_confirmations WHERE status='pending'",
-            [],
-            |row| row.get(0),
-        )
-        .unwrap_or(0);
-
+    let before = count_pending(&conn);
+    pending_store::sweep(&conn, now_ms())?;
+    let after = count_pending(&conn);
     Ok((before - after).max(0) as usize)
 }
 
@@ -364,94 +222,71 @@ pub fn insert_pending(
     summary: Option<Value>,
 ) -> Result<(), ErrorData> {
     let conn = connect()?;
-
-    let summary_json = summary
-        .as_ref()
-        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "{}".to_string()));
-
-    conn.execute(
-        "INSERT OR REPLACE INTO solana_pending_confirmations
-         (id, tx_base64, created_at_ms, updated_at_ms, expires_at_ms, tx_summary_hash, status, signature, last_error, source_tool, summary_json)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'pending', NULL, NULL, ?7, ?8)",
-        rusqlite::params![
+    pending_store::insert(
+        &conn,
+        &NewPending {
             id,
-            tx_base64,
-            created_ms as i64,
-            now_ms() as i64,
-            expires_ms as i64,
+            chain: PendingChain::Solana,
+            network: summary
+                .as_ref()
+                .and_then(|s| s.get("network"))
+                .and_then(Value::as_str),
+            created_at_ms: created_ms,
+            expires_at_ms: expires_ms,
             tx_summary_hash,
-            source_tool,
-            summary_json,
-        ],
+            source_tool: Some(source_tool),
+            summary: summary.as_ref(),
+            payload: &json!({ "tx_base64": tx_base64 }),
+        },
     )
-    .map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to insert pending solana confirmation: {}", e)),
-        data: None,
-    })?;
-
-    Ok(())
 }
 
+/// A confirmation that is still `pending` (sent/expired rows are reported as unknown).
 pub fn get_pending(id: &str) -> Result<PendingSolanaConfirmation, ErrorData> {
     let conn = connect()?;
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, tx_base64, created_at_ms, expires_at_ms, tx_summary_hash, source_tool, summary_json
-             FROM solana_pending_confirmations
-             WHERE id=?1 AND status='pending'",
-        )
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to prepare get_pending: {}", e)),
-            data: None,
-        })?;
-
-    let row_opt = stmt
-        .query_row([id], |row| {
-            let summary_json: Option<String> = row.get(6)?;
-            let summary = summary_json
-                .as_deref()
-                .and_then(|s| serde_json::from_str::<Value>(s).ok());
-            Ok(PendingSolanaConfirmation {
-                id: row.get(0)?,
-                tx_base64: row.get(1)?,
-                created_ms: row.get::<_, i64>(2)? as u64,
-                expires_ms: row.get::<_, i64>(3)? as u64,
-                tx_summary_hash: row.get(4)?,
-                source_tool: row.get(5)?,
-                summary,
-            })
+    pending_store::get(&conn, id)?
+        .filter(|r| r.chain == PendingChain::Solana && r.status == PendingStatus::Pending)
+        .map(confirmation_from_record)
+        .ok_or_else(|| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("Unknown confirmation id"),
+            data: Some(serde_json::json!({
+                "error_class": "UNKNOWN_CONFIRMATION_ID",
+                "retryable": false,
+                "suggest_fix": "Re-run the build/send step to create a new pending confirmation",
+                "id": id
+            })),
         })
-        .optional()
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!("Failed to query get_pending: {}", e)),
-            data: None,
-        })?;
-
-    row_opt.ok_or_else(|| ErrorData {
-        code: ErrorCode(-32602),
-        message: Cow::from("Unknown confirmation id"),
-        data: Some(serde_json::json!({
-            "error_class": "UNKNOWN_CONFIRMATION_ID",
-            "retryable": false,
-            "suggest_fix": "Re-run the build/send step to create a new pending confirmation",
-            "id": id
-        })),
-    })
 }
 
 pub fn remove_pending(id: &str) -> Result<(), ErrorData> {
     let conn = connect()?;
-    conn.execute("DELETE FROM solana_pending_confirmations WHERE id=?1", [id])
-        .map_err(|e| ErrorData {
-            code: ErrorCode(-32603),
-            message: Cow::from(format!(
-                "Failed to remove pending solana confirmation: {}",
-                e
-            )),
-            data: None,
-        })?;
+    pending_store::delete(&conn, id)
+}
+
+/// Mark a pending confirmation as broadcast. The row is kept (no longer `pending`) so its
+/// spend stays in the ledger.
+pub fn mark_sent(id: &str, signature: &str, spend_json: Option<&str>) -> Result<(), ErrorData> {
+    let conn = connect()?;
+    if let Some(j) = spend_json {
+        pending_store::set_spend(&conn, id, j)?;
+    }
+    pending_store::transition(&conn, id, PendingStatus::Sent, Some(signature), None)?;
+    Ok(())
+}
+
+/// Mark a broadcast as landed at the requested commitment (via `sent` if still pending).
+pub fn mark_confirmed(
+    id: &str,
+    signature: &str,
+    spend_json: Option<&str>,
+) -> Result<(), ErrorData> {
+    let conn = connect()?;
+    if pending_store::get(&conn, id)?.is_some_and(|r| r.status != PendingStatus::Sent) {
+        mark_sent(id, signature, spend_json)?;
+    } else if let Some(j) = spend_json {
+        pending_store::set_spend(&conn, id, j)?;
+    }
+    pending_store::transition(&conn, id, PendingStatus::Confirmed, Some(signature), None)?;
     Ok(())
 }
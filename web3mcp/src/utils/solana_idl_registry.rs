@@ -3,11 +3,8 @@ use serde_json::Value;
 use std::borrow::Cow;
 
 pub fn registry_root() -> std::path::PathBuf {
-    if let Ok(dir) = std::env::var("SOLANA_IDL_REGISTRY_DIR") {
-        return std::path::PathBuf::from(dir);
-    }
     // mirror EVM default layout
-    std::path::PathBuf::from("./abi_registry/solana")
+    crate::utils::data_dir::solana_idl_registry_dir()
 }
 
 pub fn sanitize_name(name: &str) -> String {
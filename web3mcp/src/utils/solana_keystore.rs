@@ -0,0 +1,321 @@
+//! Encrypted multi-account Solana keystore.
+//!
+//! Each account is a Web3 Secret Storage file (scrypt + AES-128-CTR, the format of the Foundry
+//! EVM keystores) at `<signer.keystore_dir>/solana/<name>.json` holding the 64-byte keypair.
+//! The file also carries the plaintext `pubkey`, so listing and exporting the public key need no
+//! password. `defaults.json` next to the accounts maps a network to the account the `keystore`
+//! signer uses there.
+
+use crate::utils::signer::{self, signer_unavailable, SignerChain};
+use rmcp::model::{ErrorCode, ErrorData};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULTS_FILE: &str = "defaults.json";
+pub const NETWORKS: &[&str] = &["mainnet", "devnet", "testnet"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Account {
+    pub name: String,
+    /// `None` for files without a readable `pubkey` field.
+    pub pubkey: Option<String>,
+    pub path: String,
+    /// Networks this account is the selected signer for.
+    pub default_for: Vec<String>,
+}
+
+fn invalid(message: impl Into<String>, data: Value) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(message.into()),
+        data: Some(data),
+    }
+}
+
+fn io_error(action: &str, path: &Path, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("Failed to {} {}: {}", action, path.display(), e)),
+        data: Some(json!({ "path": path.display().to_string() })),
+    }
+}
+
+pub fn dir() -> PathBuf {
+    signer::keystore_dir().join(SignerChain::Solana.as_str())
+}
+
+/// `mainnet` (default, also `mainnet-beta`), `devnet` or `testnet`.
+pub fn normalize_network(network: Option<&str>) -> Result<String, ErrorData> {
+    let raw = network.map(str::trim).filter(|n| !n.is_empty());
+    let n = raw.unwrap_or("mainnet").to_lowercase();
+    let n = match n.as_str() {
+        "mainnet-beta" | "mainnet_beta" => "mainnet".to_string(),
+        _ => n,
+    };
+    if NETWORKS.contains(&n.as_str()) {
+        Ok(n)
+    } else {
+        Err(invalid(
+            format!("network must be one of: {}", NETWORKS.join("|")),
+            json!({ "network": raw }),
+        ))
+    }
+}
+
+/// Account names are file stems: letters, digits, `-`, `_` and `.` (a `.json` suffix is dropped).
+pub fn validate_name(name: &str) -> Result<String, ErrorData> {
+    match signer::validate_account_name(name) {
+        Ok(stem) if stem != DEFAULTS_FILE.trim_end_matches(".json") => Ok(stem),
+        _ => Err(invalid(
+            "account must be 1-64 characters of letters, digits, '-', '_' or '.' (not 'defaults')",
+            json!({ "account": name }),
+        )),
+    }
+}
+
+pub fn account_path(name: &str) -> Result<PathBuf, ErrorData> {
+    Ok(dir().join(format!("{}.json", validate_name(name)?)))
+}
+
+/// A secret key as a JSON byte array (the `solana-keygen` file layout) or a base58 string (the
+/// wallet export format). 64-byte keypairs and 32-byte seeds are accepted.
+pub fn parse_secret(input: &str) -> Result<Vec<u8>, ErrorData> {
+    let input = input.trim();
+    let bytes = if input.starts_with('[') {
+        serde_json::from_str::<Vec<u8>>(input)
+            .map_err(|e| invalid(format!("Invalid keypair JSON: {}", e), json!({})))?
+    } else {
+        bs58::decode(input)
+            .into_vec()
+            .map_err(|e| invalid(format!("Invalid base58 secret key: {}", e), json!({})))?
+    };
+    match bytes.len() {
+        32 | 64 => Ok(bytes),
+        n => Err(invalid(
+            format!("Solana secret key must be 32 or 64 bytes, got {}", n),
+            json!({ "len": n }),
+        )),
+    }
+}
+
+/// Encrypt `keypair` (64 bytes) as account `name`. Fails if the account exists unless
+/// `overwrite`.
+pub fn import(
+    name: &str,
+    keypair: &[u8],
+    pubkey: &str,
+    password: &str,
+    overwrite: bool,
+) -> Result<PathBuf, ErrorData> {
+    let name = validate_name(name)?;
+    let dir = dir();
+    let file = format!("{}.json", name);
+    let path = dir.join(&file);
+    if path.exists() && !overwrite {
+        return Err(invalid(
+            format!("Solana keystore account '{}' already exists", name),
+            json!({
+                "account": name,
+                "path": path.display().to_string(),
+                "hint": "set overwrite=true to replace it",
+            }),
+        ));
+    }
+    signer::write_keystore_file(&dir, &file, keypair, password, json!({ "pubkey": pubkey }))
+}
+
+fn read_pubkey_at(path: &Path) -> Result<String, ErrorData> {
+    let raw = std::fs::read_to_string(path).map_err(|e| io_error("read", path, e))?;
+    let doc: Value = serde_json::from_str(&raw).map_err(|e| io_error("parse", path, e))?;
+    doc.get("pubkey")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            invalid(
+                format!("Keystore {} has no 'pubkey' field", path.display()),
+                json!({ "path": path.display().to_string() }),
+            )
+        })
+}
+
+/// Plaintext public key of account `name`.
+pub fn read_pubkey(name: &str) -> Result<String, ErrorData> {
+    let path = account_path(name)?;
+    if !path.exists() {
+        return Err(not_found(name, &path));
+    }
+    read_pubkey_at(&path)
+}
+
+fn not_found(name: &str, path: &Path) -> ErrorData {
+    invalid(
+        format!("Solana keystore account '{}' not found", name.trim()),
+        json!({ "account": name, "path": path.display().to_string() }),
+    )
+}
+
+/// Decrypt account `name` into its secret bytes.
+pub fn decrypt(name: &str, password: &str) -> Result<Vec<u8>, ErrorData> {
+    signer::decrypt_keystore_file(&account_path(name)?, password)
+}
+
+/// Accounts sorted by name; empty when the directory does not exist yet.
+pub fn list() -> Result<Vec<Account>, ErrorData> {
+    let dir = dir();
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_error("read keystore dir", &dir, e)),
+    };
+    let defaults = defaults();
+    let mut accounts: Vec<Account> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file = entry.file_name().to_str()?.to_string();
+            let name = file.strip_suffix(".json")?.to_string();
+            if file == DEFAULTS_FILE || name.starts_with('.') {
+                return None;
+            }
+            let path = entry.path();
+            Some(Account {
+                pubkey: read_pubkey_at(&path).ok(),
+                path: path.display().to_string(),
+                default_for: defaults
+                    .iter()
+                    .filter(|(_, account)| **account == name)
+                    .map(|(network, _)| network.clone())
+                    .collect(),
+                name,
+            })
+        })
+        .collect();
+    accounts.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(accounts)
+}
+
+/// Network -> account selections; empty when none were made.
+pub fn defaults() -> BTreeMap<String, String> {
+    std::fs::read_to_string(dir().join(DEFAULTS_FILE))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Make `name` the signer for `network`; returns the previous selection.
+pub fn set_default(network: Option<&str>, name: &str) -> Result<Option<String>, ErrorData> {
+    let network = normalize_network(network)?;
+    let name = validate_name(name)?;
+    let path = account_path(&name)?;
+    if !path.exists() {
+        return Err(not_found(&name, &path));
+    }
+    let mut defaults = defaults();
+    let previous = defaults.insert(network, name);
+    let file = dir().join(DEFAULTS_FILE);
+    signer::write_owner_only(
+        &file,
+        &serde_json::to_string_pretty(&defaults).unwrap_or_default(),
+    )?;
+    Ok(previous)
+}
+
+/// Selected account for `network` (default `mainnet`), if any.
+pub fn default_account(network: Option<&str>) -> Option<String> {
+    let network = normalize_network(network).ok()?;
+    defaults().remove(&network)
+}
+
+/// Account the `keystore` signer unlocks for `network`: the selection for that network, else
+/// `signer.keystore_account` / `default`. Errors when that account file is missing.
+pub fn signer_account(network: Option<&str>) -> Result<PathBuf, ErrorData> {
+    let path = signer::keystore_path(SignerChain::Solana, default_account(network).as_deref())?;
+    if path.exists() {
+        Ok(path)
+    } else {
+        Err(signer_unavailable(
+            format!(
+                "No Solana keystore account at {}; import one with solana_keystore_import",
+                path.display()
+            ),
+            json!({ "path": path.display().to_string(), "network": network }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_networks_and_names() {
+        assert_eq!(normalize_network(None).unwrap(), "mainnet");
+        assert_eq!(normalize_network(Some("Mainnet-Beta")).unwrap(), "mainnet");
+        assert_eq!(normalize_network(Some("devnet")).unwrap(), "devnet");
+        assert!(normalize_network(Some("localnet")).is_err());
+
+        assert_eq!(validate_name("trading.json").unwrap(), "trading");
+        assert_eq!(validate_name(" hot_wallet-2 ").unwrap(), "hot_wallet-2");
+        for bad in ["", "../x", "a/b", ".hidden", "defaults", "with space"] {
+            assert!(validate_name(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn parses_json_and_base58_secrets() {
+        let keypair: Vec<u8> = (0u8..64).collect();
+        let json = serde_json::to_string(&keypair).unwrap();
+        assert_eq!(parse_secret(&json).unwrap(), keypair);
+        let b58 = bs58::encode(&keypair).into_string();
+        assert_eq!(parse_secret(&b58).unwrap(), keypair);
+        assert_eq!(
+            parse_secret(&bs58::encode([7u8; 32]).into_string())
+                .unwrap()
+                .len(),
+            32
+        );
+        assert!(parse_secret("[1,2,3]").is_err());
+        assert!(parse_secret("not-base58-0OIl").is_err());
+    }
+
+    #[test]
+    fn import_list_select_and_decrypt() {
+        let _guard = signer::ENV_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let tmp = tempfile::tempdir().unwrap();
+        std::env::set_var(signer::KEYSTORE_DIR_ENV, tmp.path());
+
+        let keypair = [9u8; 64];
+        let path = import("trading", &keypair, "Pubkey1111", "pw", false).unwrap();
+        assert!(path.ends_with("solana/trading.json"));
+        assert!(import("trading", &keypair, "Pubkey1111", "pw", false).is_err());
+        import("cold", &[3u8; 64], "Pubkey2222", "pw", false).unwrap();
+
+        assert_eq!(read_pubkey("trading").unwrap(), "Pubkey1111");
+        assert_eq!(decrypt("trading", "pw").unwrap(), keypair.to_vec());
+        assert!(decrypt("trading", "wrong").is_err());
+
+        assert_eq!(set_default(Some("devnet"), "cold").unwrap(), None);
+        assert_eq!(
+            set_default(Some("devnet"), "trading").unwrap().as_deref(),
+            Some("cold")
+        );
+        assert!(set_default(None, "missing").is_err());
+        assert_eq!(default_account(Some("devnet")).as_deref(), Some("trading"));
+        assert_eq!(default_account(None), None);
+        assert!(signer_account(Some("devnet"))
+            .unwrap()
+            .ends_with("solana/trading.json"));
+
+        let accounts = list().unwrap();
+        let names: Vec<&str> = accounts.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["cold", "trading"]);
+        assert_eq!(accounts[1].default_for, ["devnet"]);
+        assert_eq!(accounts[0].pubkey.as_deref(), Some("Pubkey2222"));
+
+        std::env::remove_var(signer::KEYSTORE_DIR_ENV);
+    }
+}
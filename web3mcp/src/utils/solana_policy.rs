@@ -1,6 +1,4 @@
-use rmcp::model::{ErrorCode, ErrorData};
 use serde::Deserialize;
-use std::borrow::Cow;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct SolanaConfirmPolicy {
@@ -119,13 +117,9 @@ fn default_token_authority_mode() -> String {
     "strict".to_string()
 }
 
-fn policy_path_from_cwd() -> Result<std::path::PathBuf, ErrorData> {
-    let cwd = std::env::current_dir().map_err(|e| ErrorData {
-        code: ErrorCode(-32603),
-        message: Cow::from(format!("Failed to get current_dir: {e}")),
-        data: None,
-    })?;
-    Ok(cwd.join("policies").join("solana_confirm_policy.json"))
+/// `<data_dir>/policies/solana_confirm_policy.json`.
+pub fn policy_path() -> std::path::PathBuf {
+    crate::utils::data_dir::policies_dir().join("solana_confirm_policy.json")
 }
 
 #[derive(Debug, Clone)]
@@ -147,10 +141,7 @@ fn file_mtime_ms(path: &std::path::Path) -> Option<u128> {
 }
 
 pub fn load_solana_confirm_policy() -> SolanaConfirmPolicy {
-    let path = match policy_path_from_cwd() {
-        Ok(p) => p,
-        Err(_) => return SolanaConfirmPolicy::default_fallback(),
-    };
+    let path = policy_path();
 
     let mtime = file_mtime_ms(&path).unwrap_or(0);
 
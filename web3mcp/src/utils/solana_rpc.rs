@@ -0,0 +1,107 @@
+//! Solana RPC transport over several endpoints.
+//!
+//! [`FailoverSender`] is a `solana_client` `RpcSender` holding one `HttpSender` per configured
+//! URL of a network, so every `RpcClient` method gets failover for free: reads move to the next
+//! endpoint on timeouts, 429s and connection errors; `sendTransaction` / `requestAirdrop` are
+//! sent once.
+
+use crate::utils::rpc_endpoints::{self, EndpointSet, RetryPolicy};
+use async_trait::async_trait;
+use solana_client::http_sender::HttpSender;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_sender::{RpcSender, RpcTransportStats};
+use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind, Result};
+use solana_rpc_client_api::request::RpcRequest;
+use std::sync::Arc;
+
+fn is_transient(e: &ClientError) -> bool {
+    matches!(e.kind(), ErrorKind::Io(_) | ErrorKind::Reqwest(_))
+        || rpc_endpoints::is_transient_error(&e.to_string())
+}
+
+pub struct FailoverSender {
+    set: Arc<EndpointSet>,
+    senders: Vec<HttpSender>,
+    policy: RetryPolicy,
+}
+
+impl FailoverSender {
+    pub fn new(set: Arc<EndpointSet>, policy: RetryPolicy) -> Self {
+        let senders = set
+            .urls()
+            .iter()
+            .map(|url| HttpSender::new_with_timeout(url, policy.timeout))
+            .collect();
+        Self {
+            set,
+            senders,
+            policy,
+        }
+    }
+}
+
+#[async_trait]
+impl RpcSender for FailoverSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let idempotent = !matches!(
+            request,
+            RpcRequest::SendTransaction | RpcRequest::RequestAirdrop
+        );
+        let timeout = self.policy.timeout;
+        rpc_endpoints::call(
+            &self.set,
+            &self.policy,
+            idempotent,
+            is_transient,
+            |rpc_url| {
+                ErrorKind::Custom(format!(
+                    "request to {} timed out after {}ms",
+                    crate::utils::config::redact_url(rpc_url),
+                    timeout.as_millis()
+                ))
+                .into()
+            },
+            |idx| self.senders[idx].send(request, params.clone()),
+        )
+        .await
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        let mut total = RpcTransportStats::default();
+        for stats in self.senders.iter().map(|s| s.get_transport_stats()) {
+            total.request_count += stats.request_count;
+            total.elapsed_time += stats.elapsed_time;
+            total.rate_limited_time += stats.rate_limited_time;
+        }
+        total
+    }
+
+    /// The endpoint the next request goes to first.
+    fn url(&self) -> String {
+        self.set.url(self.set.best()).to_string()
+    }
+}
+
+/// Nonblocking client over every URL of `set` (same default commitment as `RpcClient::new`).
+pub fn failover_client(set: Arc<EndpointSet>) -> RpcClient {
+    let sender = FailoverSender::new(set, RetryPolicy::from_config());
+    RpcClient::new_sender(sender, Default::default())
+}
+
+/// `getHealth` against every URL of `set` (one client each, no failover), recorded in its
+/// health. Returns the set's snapshot.
+pub async fn probe_endpoints(set: &EndpointSet, timeout: std::time::Duration) -> serde_json::Value {
+    for (idx, url) in set.urls().iter().enumerate() {
+        let client = RpcClient::new_with_timeout(url.clone(), timeout);
+        let started = std::time::Instant::now();
+        match client.get_health().await {
+            Ok(()) => set.record_success(idx, started.elapsed()),
+            Err(e) => set.record_failure(idx, &e.to_string()),
+        }
+    }
+    set.snapshot()
+}
@@ -0,0 +1,535 @@
+//! Solana helpers for the W3RT workflow adapter (network/token resolution, Jupiter quote/swap, SPL instructions).
+
+use rmcp::model::{ErrorCode, ErrorData};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+pub fn solana_network_from_intent(intent: &Value) -> Option<String> {
+    // Best-effort: extract network name; default is mainnet.
+    let mut net = intent
+        .get("resolved_network")
+        .and_then(|v| v.get("network_name"))
+        .and_then(Value::as_str)
+        .map(|s| s.to_string());
+
+    if net.is_none() {
+        net = intent
+            .get("resolved_network")
+            .and_then(|v| v.get("network"))
+            .and_then(Value::as_str)
+            .map(|s| s.to_string());
+    }
+
+    let n = net.unwrap_or_else(|| "mainnet".to_string());
+    let lower = n.to_lowercase();
+    if lower.contains("devnet") {
+        Some("devnet".to_string())
+    } else if lower.contains("testnet") {
+        Some("testnet".to_string())
+    } else if lower.contains("main") {
+        Some("mainnet".to_string())
+    } else if lower.contains("local") || lower.contains("localhost") {
+        Some("localhost".to_string())
+    } else {
+        // If it's something unrecognized, keep it (solana_tools has mapping logic).
+        Some(n)
+    }
+}
+
+pub fn jup_base_url() -> String {
+    crate::utils::config::var("SOLANA_JUPITER_QUOTE_BASE_URL")
+        .unwrap_or_else(|_| "https://quote-api.jup.ag".to_string())
+}
+
+pub fn solana_token_to_mint(token: &str) -> Option<&'static str> {
+    // Fast-path mapping for common tokens.
+    // NOTE: This is only a fallback; for general symbols we resolve via Jupiter token list.
+    match token.trim().to_lowercase().as_str() {
+        "sol" | "wsol" | "wrapped sol" => Some("So11111111111111111111111111111111111111112"),
+        "usdc" => Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+        "usdt" => Some("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"),
+        "jup" => Some("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN"),
+        "bonk" => Some("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263"),
+        _ => None,
+    }
+}
+
+pub fn solana_is_pubkey(s: &str) -> bool {
+    use std::str::FromStr;
+    let t = s.trim();
+    if t.len() < 32 || t.len() > 64 {
+        return false;
+    }
+    solana_sdk::pubkey::Pubkey::from_str(t).is_ok()
+}
+
+pub fn jup_tokens_url() -> String {
+    crate::utils::config::var("SOLANA_JUPITER_TOKENS_URL")
+        .unwrap_or_else(|_| "https://tokens.jup.ag/tokens?tags=verified".to_string())
+}
+
+pub async fn solana_fetch_jupiter_tokens_verified() -> Result<Value, ErrorData> {
+    let url = jup_tokens_url();
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_millis(15_000))
+        .build()
+        .map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("jup_tokens: failed to build client: {e}")),
+            data: None,
+        })?;
+
+    let resp = client.get(&url).send().await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("jup_tokens: request failed: {e}")),
+        data: Some(json!({"url": url})),
+    })?;
+
+    let status = resp.status();
+    let text = resp.text().await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("jup_tokens: failed to read body: {e}")),
+        data: None,
+    })?;
+
+    let parsed: Value = serde_json::from_str(&text).unwrap_or_else(|_| json!({"raw": text}));
+    if !status.is_success() {
+        return Err(ErrorData {
+            code: ErrorCode(i32::from(status.as_u16())),
+            message: Cow::from("HTTP error from Jupiter tokens API"),
+            data: Some(json!({"url": url, "status": status.as_u16(), "body": parsed})),
+        });
+    }
+
+    Ok(parsed)
+}
+
+pub async fn solana_jupiter_tokens_verified() -> Result<Value, ErrorData> {
+    // Shared response cache (token_list TTL, 10 minutes by default; persisted in sqlite mode).
+    crate::utils::response_cache::cached(
+        crate::utils::response_cache::CacheCategory::TokenList,
+        &format!("solana:jupiter:tokens_verified:{}", jup_tokens_url()),
+        solana_fetch_jupiter_tokens_verified,
+    )
+    .await
+}
+
+pub async fn solana_jupiter_token_map(
+) -> Result<std::collections::HashMap<String, Value>, ErrorData> {
+    use std::sync::{Mutex, OnceLock};
+
+    fn now_ms() -> u128 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+    }
+
+    // In-memory cache to avoid reparsing the token list on every portfolio call.
+    // TTL matches on-disk token list TTL (10 minutes).
+    static CACHE: OnceLock<Mutex<(u128, std::collections::HashMap<String, Value>)>> =
+        OnceLock::new();
+    let ttl_ms: u128 = 600_000;
+
+    let cache = CACHE.get_or_init(|| Mutex::new((0, std::collections::HashMap::new())));
+    {
+        let guard = cache.lock().unwrap_or_else(|e| e.into_inner());
+        let (ts, map) = (&guard.0, &guard.1);
+        if *ts > 0 && now_ms().saturating_sub(*ts) <= ttl_ms && !map.is_empty() {
+            return Ok(map.clone());
+        }
+    }
+
+    let token_list = solana_jupiter_tokens_verified().await?;
+    let mut map: std::collections::HashMap<String, Value> = std::collections::HashMap::new();
+    if let Some(arr) = token_list.as_array() {
+        for t in arr {
+            if let Some(addr) = t.get("address").and_then(Value::as_str) {
+                map.insert(addr.to_string(), t.clone());
+            }
+        }
+    }
+
+    {
+        let mut guard = cache.lock().unwrap_or_else(|e| e.into_inner());
+        *guard = (now_ms(), map.clone());
+    }
+
+    Ok(map)
+}
+
+pub async fn solana_resolve_symbol_via_jupiter_tokens(
+    symbol: &str,
+) -> Result<Option<Value>, ErrorData> {
+    let parsed = solana_jupiter_tokens_verified().await?;
+
+    let want = symbol.trim().to_lowercase();
+    let arr = parsed.as_array().ok_or_else(|| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from("unexpected Jupiter tokens response (expected array)"),
+        data: Some(json!({"body": parsed})),
+    })?;
+
+    let mut matches: Vec<Value> = Vec::new();
+    for t in arr {
+        let sym = t
+            .get("symbol")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_lowercase();
+        if sym == want {
+            matches.push(t.clone());
+        }
+    }
+
+    if matches.is_empty() {
+        return Ok(None);
+    }
+    if matches.len() > 1 {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("ambiguous SPL token symbol; please provide mint address"),
+            data: Some(json!({
+                "symbol": symbol,
+                "matches": matches
+                    .iter()
+                    .take(5)
+                    .map(|m| json!({"symbol": m.get("symbol"), "name": m.get("name"), "address": m.get("address"), "decimals": m.get("decimals")}))
+                    .collect::<Vec<_>>()
+            })),
+        });
+    }
+
+    Ok(Some(matches.remove(0)))
+}
+
+pub async fn solana_get_mint_decimals(
+    rpc: &solana_client::nonblocking::rpc_client::RpcClient,
+    mint: &str,
+) -> Result<u8, ErrorData> {
+    // Decimals are fixed when the mint is created; cached with the token_metadata TTL.
+    let key = format!(
+        "solana:{}:mint_decimals:{}",
+        crate::utils::config::redact_url(&rpc.url()),
+        mint.trim()
+    );
+    crate::utils::response_cache::cached(
+        crate::utils::response_cache::CacheCategory::TokenMetadata,
+        &key,
+        || fetch_mint_decimals(rpc, mint),
+    )
+    .await
+}
+
+async fn fetch_mint_decimals(
+    rpc: &solana_client::nonblocking::rpc_client::RpcClient,
+    mint: &str,
+) -> Result<u8, ErrorData> {
+    // Token Mint layout (SPL Token + Token-2022 share the base layout):
+    // mint_authority (COption<Pubkey>) = 36 bytes
+    // supply (u64)                    = 8 bytes
+    // decimals (u8)                   = 1 byte  <-- offset 44
+    // is_initialized (u8)             = 1 byte
+    // freeze_authority (COption<Pubkey>) = 36 bytes
+    // Total base = 82 bytes, extensions may follow (token-2022).
+    use std::str::FromStr;
+
+    let pk = solana_sdk::pubkey::Pubkey::from_str(mint).map_err(|e| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!("invalid mint pubkey: {e}")),
+        data: Some(json!({"mint": mint})),
+    })?;
+
+    let acct = rpc.get_account(&pk).await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("failed to fetch mint account: {e}")),
+        data: Some(json!({"mint": mint})),
+    })?;
+
+    // Base mint size should be at least 82 bytes.
+    if acct.data.len() < 82 {
+        return Err(ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from("mint account data too short (expected >=82 bytes)"),
+            data: Some(json!({"mint": mint, "len": acct.data.len()})),
+        });
+    }
+
+    Ok(acct.data[44])
+}
+
+pub async fn solana_get_token_program_for_mint(
+    rpc: &solana_client::nonblocking::rpc_client::RpcClient,
+    mint: &str,
+) -> Result<String, ErrorData> {
+    use std::str::FromStr;
+    let pk = solana_sdk::pubkey::Pubkey::from_str(mint).map_err(|e| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(format!("invalid mint pubkey: {e}")),
+        data: Some(json!({"mint": mint})),
+    })?;
+
+    let acct = rpc.get_account(&pk).await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("failed to fetch mint account: {e}")),
+        data: Some(json!({"mint": mint})),
+    })?;
+
+    Ok(acct.owner.to_string())
+}
+
+pub fn solana_build_token_transfer_checked_ix(
+    program_id: solana_sdk::pubkey::Pubkey,
+    source: solana_sdk::pubkey::Pubkey,
+    mint: solana_sdk::pubkey::Pubkey,
+    destination: solana_sdk::pubkey::Pubkey,
+    authority: solana_sdk::pubkey::Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> solana_sdk::instruction::Instruction {
+    let mut data = Vec::with_capacity(1 + 8 + 1);
+    data.push(12u8); // TransferChecked
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    let accounts = vec![
+        solana_sdk::instruction::AccountMeta::new(source, false),
+        solana_sdk::instruction::AccountMeta::new_readonly(mint, false),
+        solana_sdk::instruction::AccountMeta::new(destination, false),
+        solana_sdk::instruction::AccountMeta::new_readonly(authority, true),
+    ];
+
+    solana_sdk::instruction::Instruction {
+        program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn parse_amount_to_base_units(amount: &str, decimals: u32) -> Result<u64, ErrorData> {
+    let s = amount.trim();
+    if s.is_empty() {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("amount_in is required"),
+            data: None,
+        });
+    }
+
+    if !s.contains('.') {
+        return s.parse::<u64>().map_err(|_| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("amount_in must be an integer base-unit string (or a decimal UI amount for SOL/USDC)"),
+            data: Some(json!({"provided": s})),
+        });
+    }
+
+    // Decimal UI amount.
+    let parts: Vec<&str> = s.split('.').collect();
+    if parts.len() != 2 {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("invalid decimal amount"),
+            data: Some(json!({"provided": s})),
+        });
+    }
+    let whole = parts[0];
+    let frac = parts[1];
+    if frac.len() > decimals as usize {
+        return Err(ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("too many decimal places for token"),
+            data: Some(json!({"provided": s, "decimals": decimals})),
+        });
+    }
+    let whole_u: u64 = whole.parse().map_err(|_| ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from("invalid decimal amount (whole part)"),
+        data: Some(json!({"provided": s})),
+    })?;
+    let mut frac_s = frac.to_string();
+    while frac_s.len() < decimals as usize {
+        frac_s.push('0');
+    }
+    let frac_u: u64 = if frac_s.is_empty() {
+        0
+    } else {
+        frac_s.parse().map_err(|_| ErrorData {
+            code: ErrorCode(-32602),
+            message: Cow::from("invalid decimal amount (fractional part)"),
+            data: Some(json!({"provided": s})),
+        })?
+    };
+
+    let mul = 10u64.checked_pow(decimals).ok_or_else(|| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from("decimal conversion overflow"),
+        data: Some(json!({"decimals": decimals})),
+    })?;
+
+    whole_u
+        .checked_mul(mul)
+        .and_then(|x| x.checked_add(frac_u))
+        .ok_or_else(|| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from("decimal conversion overflow"),
+            data: Some(json!({"provided": s, "decimals": decimals})),
+        })
+}
+
+pub async fn jup_quote(
+    input_mint: &str,
+    output_mint: &str,
+    amount: u64,
+    slippage_bps: u64,
+) -> Result<Value, ErrorData> {
+    jup_quote_with_mode(input_mint, output_mint, amount, slippage_bps, None).await
+}
+
+pub async fn jup_quote_exact_out(
+    input_mint: &str,
+    output_mint: &str,
+    amount_out: u64,
+    slippage_bps: u64,
+) -> Result<Value, ErrorData> {
+    jup_quote_with_mode(
+        input_mint,
+        output_mint,
+        amount_out,
+        slippage_bps,
+        Some("ExactOut"),
+    )
+    .await
+}
+
+pub async fn jup_quote_with_mode(
+    input_mint: &str,
+    output_mint: &str,
+    amount: u64,
+    slippage_bps: u64,
+    swap_mode: Option<&str>,
+) -> Result<Value, ErrorData> {
+    let base = jup_base_url();
+    let mode_q = swap_mode
+        .filter(|m| !m.trim().is_empty())
+        .map(|m| format!("&swapMode={}", urlencoding::encode(m)))
+        .unwrap_or_default();
+
+    let url = format!(
+        "{}/v6/quote?inputMint={}&outputMint={}&amount={}&slippageBps={}{}",
+        base.trim_end_matches('/'),
+        urlencoding::encode(input_mint),
+        urlencoding::encode(output_mint),
+        amount,
+        slippage_bps,
+        mode_q
+    );
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_millis(15_000))
+        .build()
+        .map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("jup_quote: failed to build client: {e}")),
+            data: None,
+        })?;
+
+    let resp = client.get(&url).send().await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("jup_quote: request failed: {e}")),
+        data: Some(json!({"url": url})),
+    })?;
+
+    let status = resp.status();
+    let text = resp.text().await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("jup_quote: failed to read body: {e}")),
+        data: None,
+    })?;
+    let parsed: Value = serde_json::from_str(&text).unwrap_or_else(|_| json!({ "raw": text }));
+
+    if !status.is_success() {
+        return Err(ErrorData {
+            code: ErrorCode(i32::from(status.as_u16())),
+            message: Cow::from("HTTP error from Jupiter quote API"),
+            data: Some(json!({"url": url, "status": status.as_u16(), "body": parsed})),
+        });
+    }
+
+    Ok(parsed)
+}
+
+pub async fn jup_swap(quote: &Value, user_pubkey: &str) -> Result<Value, ErrorData> {
+    let base = jup_base_url();
+    let url = format!("{}/v6/swap", base.trim_end_matches('/'));
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_millis(20_000))
+        .build()
+        .map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("jup_swap: failed to build client: {e}")),
+            data: None,
+        })?;
+
+    // Minimal swap request (Jupiter v6).
+    let body = json!({
+        "quoteResponse": quote,
+        "userPublicKey": user_pubkey,
+        "wrapAndUnwrapSol": true,
+        "dynamicComputeUnitLimit": true
+    });
+
+    let resp = client
+        .post(&url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| ErrorData {
+            code: ErrorCode(-32603),
+            message: Cow::from(format!("jup_swap: request failed: {e}")),
+            data: Some(json!({"url": url})),
+        })?;
+
+    let status = resp.status();
+    let text = resp.text().await.map_err(|e| ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("jup_swap: failed to read body: {e}")),
+        data: None,
+    })?;
+
+    let parsed: Value = serde_json::from_str(&text).unwrap_or_else(|_| json!({ "raw": text }));
+
+    if !status.is_success() {
+        return Err(ErrorData {
+            code: ErrorCode(i32::from(status.as_u16())),
+            message: Cow::from("HTTP error from Jupiter swap API"),
+            data: Some(json!({"url": url, "status": status.as_u16(), "body": parsed})),
+        });
+    }
+
+    Ok(parsed)
+}
+
+pub fn format_base_units_ui(amount: &str, decimals: u8) -> String {
+    let s = amount.trim();
+    if decimals == 0 {
+        return s.to_string();
+    }
+    // Normalize: ensure only digits.
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return s.to_string();
+    }
+    let d = decimals as usize;
+    if s.len() <= d {
+        let mut out = String::from("0.");
+        out.push_str(&"0".repeat(d - s.len()));
+        out.push_str(s);
+        out
+    } else {
+        let (a, b) = s.split_at(s.len() - d);
+        format!("{}.{}", a, b)
+    }
+}
@@ -0,0 +1,1175 @@
+//! Rolling-window spend limits enforced at confirm time.
+//!
+//! A limit caps how much of one token a wallet may spend on a chain within a rolling window
+//! (e.g. 500 USDC per 24h on Base, 10 SOL per hour). The ledger is not a separate table: every
+//! confirm path books its spend in the pending row's `spend_json` column before broadcasting
+//! ([`reserve`]) and releases it if nothing was sent, and the cumulative spend is summed from the
+//! pending-confirmation store (`<data_dir>/pending.sqlite`). Rows carrying a spend are kept for
+//! `SPEND_LEDGER_RETENTION_MS` (the longest window).
+//!
+//! Source: `W3RT_SPEND_LIMITS` (path), else `spend_limits.toml` or `spend_limits.json` in
+//! `<data_dir>/policies`. No file means no limits.
+
+use rmcp::model::{CallToolResult, ErrorCode, ErrorData};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::types::EvmTxRequest;
+use crate::Web3McpServer;
+
+/// Longest supported window (the pending store retains spent rows this long).
+pub const MAX_WINDOW_MS: u64 = crate::utils::pending_store::SPEND_LEDGER_RETENTION_MS;
+
+/// Token id used for the chain's native asset (SOL, SUI, ETH, ...).
+pub const NATIVE: &str = "native";
+
+const SOLANA_WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const SOLANA_USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const SOLANA_USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+const SOLANA_SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+const SOLANA_TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const SOLANA_TOKEN_2022_PROGRAM: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpendLimit {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// `solana`, `sui`, `evm`, or an EVM network name / chain id (`base`, `8453`).
+    pub chain: String,
+    /// Optional network (`mainnet`, `devnet`, EVM network name or chain id).
+    #[serde(default)]
+    pub network: Option<String>,
+    /// Wallet address; when unset the limit applies to each wallet separately.
+    #[serde(default)]
+    pub wallet: Option<String>,
+    /// Symbol (`USDC`, `SOL`), `native`, mint, ERC20 address or Sui coin type.
+    pub token: String,
+    /// Decimals for tokens the server does not know (arbitrary mints / ERC20s / coin types).
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    /// Maximum spend within the window, in UI units.
+    pub max_amount: f64,
+    /// Rolling window: `30m`, `1h`, `24h`, `7d`, `1w` or plain seconds.
+    pub window: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpendLimits {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub limits: Vec<SpendLimit>,
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+/// One token outflow of a transaction, as recorded in `spend_json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpendEntry {
+    /// `solana`, `sui` or `evm`.
+    pub chain: String,
+    /// Cluster / network name (EVM: registry name, else the chain id).
+    pub network: String,
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+    pub wallet: String,
+    /// `native`, mint, ERC20 address (lowercase) or coin type.
+    pub token: String,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    pub amount_raw: String,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    pub at_ms: u64,
+}
+
+impl SpendEntry {
+    fn amount_ui(&self, fallback_decimals: Option<u8>) -> Option<f64> {
+        let raw = self.amount_raw.parse::<u128>().ok()? as f64;
+        let decimals = self.decimals.or(fallback_decimals)?;
+        Some(raw / 10f64.powi(decimals as i32))
+    }
+}
+
+/// Parse a window like `1h` / `24h` / `7d` into milliseconds.
+pub fn parse_window_ms(window: &str) -> Result<u64, String> {
+    let w = window.trim().to_lowercase();
+    let split = w.find(|c: char| !c.is_ascii_digit()).unwrap_or(w.len());
+    let (num, unit) = w.split_at(split);
+    let n: u64 = num
+        .parse()
+        .map_err(|_| format!("invalid window '{window}' (expected e.g. 30m, 1h, 24h, 7d)"))?;
+    let unit_ms: u64 = match unit.trim() {
+        "" | "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        "w" => 7 * 86_400_000,
+        _ => return Err(format!("invalid window unit in '{window}' (use s|m|h|d|w)")),
+    };
+    let ms = n.saturating_mul(unit_ms);
+    if ms == 0 || ms > MAX_WINDOW_MS {
+        return Err(format!("window '{window}' must be between 1s and 31d"));
+    }
+    Ok(ms)
+}
+
+/// Parse a limits document (`.toml` by extension, JSON otherwise) and validate it.
+pub fn parse_limits(text: &str, is_toml: bool) -> Result<SpendLimits, String> {
+    let limits: SpendLimits = if is_toml {
+        toml::from_str(text).map_err(|e| format!("invalid TOML spend limits: {e}"))?
+    } else {
+        serde_json::from_str(text).map_err(|e| format!("invalid JSON spend limits: {e}"))?
+    };
+    if limits.version != 1 {
+        return Err(format!(
+            "unsupported spend limits version {}",
+            limits.version
+        ));
+    }
+    let mut seen = std::collections::HashSet::new();
+    for l in &limits.limits {
+        if l.id.trim().is_empty() {
+            return Err("every limit needs a non-empty id".to_string());
+        }
+        if !seen.insert(l.id.as_str()) {
+            return Err(format!("duplicate limit id '{}'", l.id));
+        }
+        if l.chain.trim().is_empty() || l.token.trim().is_empty() {
+            return Err(format!("limit '{}' needs chain and token", l.id));
+        }
+        if !l.max_amount.is_finite() || l.max_amount < 0.0 {
+            return Err(format!("limit '{}' has an invalid max_amount", l.id));
+        }
+        parse_window_ms(&l.window).map_err(|e| format!("limit '{}': {e}", l.id))?;
+    }
+    Ok(limits)
+}
+
+fn limits_path() -> Option<std::path::PathBuf> {
+    if let Ok(p) = crate::utils::config::var("W3RT_SPEND_LIMITS") {
+        if !p.trim().is_empty() {
+            return Some(std::path::PathBuf::from(p.trim()));
+        }
+    }
+    let dir = crate::utils::data_dir::policies_dir();
+    ["spend_limits.toml", "spend_limits.json"]
+        .iter()
+        .map(|f| dir.join(f))
+        .find(|p| p.exists())
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadedLimits {
+    /// File path, or `none`.
+    pub source: String,
+    pub limits: SpendLimits,
+}
+
+#[derive(Debug, Clone)]
+struct LimitsCache {
+    path: std::path::PathBuf,
+    mtime_ms: u128,
+    limits: SpendLimits,
+}
+
+static LIMITS_CACHE: std::sync::OnceLock<std::sync::Mutex<Option<LimitsCache>>> =
+    std::sync::OnceLock::new();
+
+fn file_mtime_ms(path: &std::path::Path) -> Option<u128> {
+    let mt = std::fs::metadata(path).ok()?.modified().ok()?;
+    mt.duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis())
+}
+
+/// Load the configured limits (cached per file mtime). A broken file is an error so that
+/// confirmations fail closed instead of silently running without limits.
+pub fn load_limits() -> Result<LoadedLimits, String> {
+    let Some(path) = limits_path() else {
+        return Ok(LoadedLimits {
+            source: "none".to_string(),
+            limits: SpendLimits::default(),
+        });
+    };
+    let source = path.display().to_string();
+    let mtime = file_mtime_ms(&path).unwrap_or(0);
+
+    let lock = LIMITS_CACHE.get_or_init(|| std::sync::Mutex::new(None));
+    {
+        let guard = lock.lock().unwrap();
+        if let Some(ref cached) = *guard {
+            if cached.path == path && cached.mtime_ms == mtime {
+                return Ok(LoadedLimits {
+                    source,
+                    limits: cached.limits.clone(),
+                });
+            }
+        }
+    }
+
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read spend limits {source}: {e}"))?;
+    let is_toml = path.extension().and_then(|e| e.to_str()) == Some("toml");
+    let limits = parse_limits(&text, is_toml).map_err(|e| format!("{source}: {e}"))?;
+
+    let mut guard = lock.lock().unwrap();
+    *guard = Some(LimitsCache {
+        path,
+        mtime_ms: mtime,
+        limits: limits.clone(),
+    });
+    Ok(LoadedLimits { source, limits })
+}
+
+// ---------------------------------------------------------------------------
+// Extraction: what a pending transaction is about to spend.
+// ---------------------------------------------------------------------------
+
+fn str_of(v: Option<&Value>) -> Option<String> {
+    let v = v?;
+    v.as_str()
+        .map(|s| s.trim().to_string())
+        .or_else(|| v.as_u64().map(|n| n.to_string()))
+        .filter(|s| !s.is_empty())
+}
+
+fn u8_of(v: Option<&Value>) -> Option<u8> {
+    let v = v?;
+    v.as_u64()
+        .or_else(|| v.as_str().and_then(|s| s.trim().parse().ok()))
+        .and_then(|n| u8::try_from(n).ok())
+}
+
+fn solana_known_mint(mint: &str) -> Option<(&'static str, u8)> {
+    match mint {
+        SOLANA_USDC_MINT => Some(("USDC", 6)),
+        SOLANA_USDT_MINT => Some(("USDT", 6)),
+        _ => None,
+    }
+}
+
+fn solana_entry(
+    network: &str,
+    wallet: &str,
+    mint: &str,
+    symbol: Option<String>,
+    amount_raw: String,
+    decimals: Option<u8>,
+    now_ms: u64,
+) -> SpendEntry {
+    let (token, symbol, decimals) = if mint == NATIVE || mint == SOLANA_WSOL_MINT {
+        (NATIVE.to_string(), Some("SOL".to_string()), Some(9))
+    } else {
+        let known = solana_known_mint(mint);
+        (
+            mint.to_string(),
+            symbol.or_else(|| known.map(|k| k.0.to_string())),
+            decimals.or(known.map(|k| k.1)),
+        )
+    };
+    SpendEntry {
+        chain: "solana".to_string(),
+        network: network.to_string(),
+        chain_id: None,
+        wallet: wallet.to_string(),
+        token,
+        symbol,
+        amount_raw,
+        decimals,
+        at_ms: now_ms,
+    }
+}
+
+/// Spend described by the structured summary W3RT stores with Solana transfers and swaps.
+pub fn solana_entries_from_summary(network: &str, summary: &Value, now_ms: u64) -> Vec<SpendEntry> {
+    if let Some(t) = summary.get("transfer") {
+        let from = str_of(t.get("from")).unwrap_or_default();
+        match t.get("kind").and_then(Value::as_str) {
+            Some("native") => {
+                if let Some(lamports) = str_of(t.get("lamports")) {
+                    return vec![solana_entry(
+                        network,
+                        &from,
+                        NATIVE,
+                        None,
+                        lamports,
+                        Some(9),
+                        now_ms,
+                    )];
+                }
+            }
+            Some("spl") => {
+                let mint = str_of(t.get("mint"));
+                let raw = str_of(t.get("amount_base"));
+                if let (Some(mint), Some(raw)) = (mint, raw) {
+                    return vec![solana_entry(
+                        network,
+                        &from,
+                        &mint,
+                        str_of(t.get("asset")),
+                        raw,
+                        u8_of(t.get("decimals")),
+                        now_ms,
+                    )];
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some(sw) = summary.get("swap") {
+        let user = str_of(sw.get("user_pubkey")).unwrap_or_default();
+        let mint = str_of(sw.get("input_mint"));
+        let raw = str_of(sw.get("amount_in_base"));
+        if let (Some(mint), Some(raw)) = (mint, raw) {
+            return vec![solana_entry(
+                network,
+                &user,
+                &mint,
+                None,
+                raw,
+                u8_of(sw.get("input_decimals")),
+                now_ms,
+            )];
+        }
+    }
+    Vec::new()
+}
+
+/// A decoded instruction: program id, account addresses, data.
+pub type SolanaInstruction = (String, Vec<String>, Vec<u8>);
+
+fn le_u64(data: &[u8], at: usize) -> Option<u64> {
+    let bytes: [u8; 8] = data.get(at..at + 8)?.try_into().ok()?;
+    Some(u64::from_le_bytes(bytes))
+}
+
+/// Best-effort spend from raw instructions: System transfers and SPL token transfers. A plain
+/// SPL `Transfer` carries no mint, so `fallback_mint` (from the summary) is used when known.
+pub fn solana_entries_from_instructions(
+    network: &str,
+    instructions: &[SolanaInstruction],
+    fallback_mint: Option<(String, Option<u8>)>,
+    now_ms: u64,
+) -> Vec<SpendEntry> {
+    let mut out = Vec::new();
+    for (program, accounts, data) in instructions {
+        match program.as_str() {
+            // SystemInstruction::Transfer
+            SOLANA_SYSTEM_PROGRAM if data.len() == 12 && data[0..4] == 2u32.to_le_bytes() => {
+                let lamports = le_u64(data, 4).unwrap_or(0);
+                if let Some(from) = accounts.first().filter(|_| lamports > 0) {
+                    out.push(solana_entry(
+                        network,
+                        from,
+                        NATIVE,
+                        None,
+                        lamports.to_string(),
+                        Some(9),
+                        now_ms,
+                    ));
+                }
+            }
+            SOLANA_TOKEN_PROGRAM | SOLANA_TOKEN_2022_PROGRAM => match data.first() {
+                // TransferChecked: [source, mint, destination, authority]
+                Some(12) => {
+                    let (Some(amount), Some(mint), Some(authority)) =
+                        (le_u64(data, 1), accounts.get(1), accounts.get(3))
+                    else {
+                        continue;
+                    };
+                    let decimals = data.get(9).copied();
+                    out.push(solana_entry(
+                        network,
+                        authority,
+                        mint,
+                        None,
+                        amount.to_string(),
+                        decimals,
+                        now_ms,
+                    ));
+                }
+                // Transfer: [source, destination, authority]
+                Some(3) => {
+                    let (Some(amount), Some(authority), Some((mint, decimals))) =
+                        (le_u64(data, 1), accounts.get(2), fallback_mint.as_ref())
+                    else {
+                        continue;
+                    };
+                    out.push(solana_entry(
+                        network,
+                        authority,
+                        mint,
+                        None,
+                        amount.to_string(),
+                        *decimals,
+                        now_ms,
+                    ));
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Spend of a pending Solana transaction: the structured summary when present, otherwise the
+/// System / SPL transfer instructions found in the transaction itself.
+pub fn solana_entries(
+    network: &str,
+    summary: Option<&Value>,
+    tx_bytes: &[u8],
+    now_ms: u64,
+) -> Vec<SpendEntry> {
+    if let Some(s) = summary {
+        let from_summary = solana_entries_from_summary(network, s, now_ms);
+        if !from_summary.is_empty() {
+            return from_summary;
+        }
+    }
+
+    let (keys, ixs) = if let Ok(vtx) =
+        bincode::deserialize::<solana_transaction::versioned::VersionedTransaction>(tx_bytes)
+    {
+        (
+            vtx.message.static_account_keys().to_vec(),
+            vtx.message.instructions().to_vec(),
+        )
+    } else if let Ok(tx) = bincode::deserialize::<solana_sdk::transaction::Transaction>(tx_bytes) {
+        (
+            tx.message.account_keys.clone(),
+            tx.message.instructions.clone(),
+        )
+    } else {
+        return Vec::new();
+    };
+
+    let instructions: Vec<SolanaInstruction> = ixs
+        .iter()
+        .filter_map(|ix| {
+            let program = keys.get(ix.program_id_index as usize)?.to_string();
+            let accounts = ix
+                .accounts
+                .iter()
+                .map(|i| {
+                    keys.get(*i as usize)
+                        .map(|k| k.to_string())
+                        .unwrap_or_default()
+                })
+                .collect();
+            Some((program, accounts, ix.data.clone()))
+        })
+        .collect();
+
+    let fallback_mint = summary.and_then(|s| {
+        let mint = str_of(s.get("mint"))?;
+        Some((
+            mint,
+            u8_of(s.get("mint_decimals")).or_else(|| u8_of(s.get("decimals"))),
+        ))
+    });
+    solana_entries_from_instructions(network, &instructions, fallback_mint, now_ms)
+}
+
+/// Normalize a Sui coin type so `0x2::sui::SUI` and its long form compare equal.
+pub fn normalize_sui_coin_type(coin_type: &str) -> String {
+    let ct = coin_type.trim();
+    let Some((addr, rest)) = ct.split_once("::") else {
+        return ct.to_string();
+    };
+    let hex = addr.trim_start_matches("0x").trim_start_matches('0');
+    format!("0x{}::{}", if hex.is_empty() { "0" } else { hex }, rest).to_lowercase()
+}
+
+/// Spend of a Sui transaction from its dry-run `balance_changes`: every negative change owned
+/// by the sender (SUI outflows include gas).
+pub fn sui_entries_from_balance_changes(
+    network: &str,
+    sender: &str,
+    balance_changes: &Value,
+    usdc_coin_type: Option<&str>,
+    now_ms: u64,
+) -> Vec<SpendEntry> {
+    let sui = normalize_sui_coin_type("0x2::sui::SUI");
+    let usdc = usdc_coin_type.map(normalize_sui_coin_type);
+    let sender = sender.to_lowercase();
+
+    let mut out = Vec::new();
+    for change in balance_changes.as_array().into_iter().flatten() {
+        let owner = change
+            .get("owner")
+            .and_then(|o| o.get("AddressOwner"))
+            .and_then(Value::as_str)
+            .map(|s| s.to_lowercase());
+        if owner.as_deref() != Some(sender.as_str()) {
+            continue;
+        }
+        let amount = change.get("amount").and_then(|v| {
+            v.as_i64()
+                .map(|n| n as i128)
+                .or_else(|| v.as_str().and_then(|s| s.parse::<i128>().ok()))
+        });
+        let Some(amount) = amount.filter(|a| *a < 0) else {
+            continue;
+        };
+        let Some(coin_type) = change.get("coinType").and_then(Value::as_str) else {
+            continue;
+        };
+        let ct = normalize_sui_coin_type(coin_type);
+        let (token, symbol, decimals) = if ct == sui {
+            (NATIVE.to_string(), Some("SUI".to_string()), Some(9))
+        } else if usdc.as_deref() == Some(ct.as_str()) {
+            (ct, Some("USDC".to_string()), Some(6))
+        } else {
+            (ct, None, None)
+        };
+        out.push(SpendEntry {
+            chain: "sui".to_string(),
+            network: network.to_string(),
+            chain_id: None,
+            wallet: sender.clone(),
+            token,
+            symbol,
+            amount_raw: amount.unsigned_abs().to_string(),
+            decimals,
+            at_ms: now_ms,
+        });
+    }
+    out
+}
+
+fn evm_native_symbol(chain_id: u64) -> &'static str {
+    match chain_id {
+        56 | 97 => "BNB",
+        137 | 80002 => "POL",
+        43114 | 43113 => "AVAX",
+        42220 | 44787 => "CELO",
+        2222 | 2221 => "KAVA",
+        143 | 10143 => "MON",
+        8217 | 1001 => "KAIA",
+        998 => "HYPE",
+        _ => "ETH",
+    }
+}
+
+fn evm_word_u128(data: &[u8], word: usize) -> Option<u128> {
+    let w = data.get(4 + word * 32..4 + (word + 1) * 32)?;
+    if w[..16].iter().any(|b| *b != 0) {
+        return Some(u128::MAX);
+    }
+    Some(u128::from_be_bytes(w[16..].try_into().ok()?))
+}
+
+fn evm_word_address(data: &[u8], word: usize) -> Option<String> {
+    let w = data.get(4 + word * 32..4 + (word + 1) * 32)?;
+    Some(format!("0x{}", hex::encode(&w[12..])))
+}
+
+/// Spend of an EVM transaction: native `value` plus ERC20 `transfer` / `transferFrom` (when
+/// pulling from the sender). `known_tokens` maps lowercase token addresses to symbol/decimals.
+pub fn evm_entries(
+    tx: &EvmTxRequest,
+    known_tokens: &[(String, &str, u8)],
+    now_ms: u64,
+) -> Vec<SpendEntry> {
+    let network = crate::utils::evm_chain_registry::evm_default_chains()
+        .into_iter()
+        .find(|c| c.chain_id == tx.chain_id)
+        .map(|c| c.name.to_string())
+        .unwrap_or_else(|| tx.chain_id.to_string());
+    let wallet = tx.from.trim().to_lowercase();
+    let entry = |token: String,
+                 symbol: Option<String>,
+                 amount_raw: String,
+                 decimals: Option<u8>| SpendEntry {
+        chain: "evm".to_string(),
+        network: network.clone(),
+        chain_id: Some(tx.chain_id),
+        wallet: wallet.clone(),
+        token,
+        symbol,
+        amount_raw,
+        decimals,
+        at_ms: now_ms,
+    };
+
+    let mut out = Vec::new();
+    let value = tx.value_wei.trim().parse::<u128>().unwrap_or(0);
+    if value > 0 {
+        out.push(entry(
+            NATIVE.to_string(),
+            Some(evm_native_symbol(tx.chain_id).to_string()),
+            value.to_string(),
+            Some(18),
+        ));
+    }
+
+    let data = tx
+        .data_hex
+        .as_deref()
+        .map(|h| h.trim().trim_start_matches("0x"))
+        .and_then(|h| hex::decode(h).ok())
+        .unwrap_or_default();
+    let amount = match data.get(0..4) {
+        // transfer(address,uint256)
+        Some([0xa9, 0x05, 0x9c, 0xbb]) => evm_word_u128(&data, 1),
+        // transferFrom(address,address,uint256) out of the sender's own balance
+        Some([0x23, 0xb8, 0x72, 0xdd])
+            if evm_word_address(&data, 0).as_deref() == Some(wallet.as_str()) =>
+        {
+            evm_word_u128(&data, 2)
+        }
+        _ => None,
+    };
+    if let Some(amount) = amount.filter(|a| *a > 0) {
+        let token = tx.to.trim().to_lowercase();
+        let known = known_tokens.iter().find(|(addr, _, _)| *addr == token);
+        out.push(entry(
+            token,
+            known.map(|k| k.1.to_string()),
+            amount.to_string(),
+            known.map(|k| k.2),
+        ));
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation
+// ---------------------------------------------------------------------------
+
+fn eq_id(chain: &str, a: &str, b: &str) -> bool {
+    match chain {
+        "solana" => a.trim() == b.trim(),
+        "sui" => normalize_sui_coin_type(a) == normalize_sui_coin_type(b),
+        _ => a.trim().eq_ignore_ascii_case(b.trim()),
+    }
+}
+
+fn scope_matches(want: &str, e: &SpendEntry) -> bool {
+    let want = want.trim();
+    want.eq_ignore_ascii_case(&e.chain)
+        || want.eq_ignore_ascii_case(&e.network)
+        || e.chain_id.map(|id| id.to_string()).as_deref() == Some(want)
+}
+
+fn limit_matches(limit: &SpendLimit, e: &SpendEntry) -> bool {
+    if !scope_matches(&limit.chain, e) {
+        return false;
+    }
+    if let Some(n) = limit.network.as_deref().filter(|n| !n.trim().is_empty()) {
+        if !scope_matches(n, e) {
+            return false;
+        }
+    }
+    if let Some(w) = limit
+        .wallet
+        .as_deref()
+        .filter(|w| !w.trim().is_empty() && w.trim() != "*")
+    {
+        if !eq_id(&e.chain, w, &e.wallet) {
+            return false;
+        }
+    }
+    let t = limit.token.trim();
+    t.eq_ignore_ascii_case(&e.token)
+        || e.symbol
+            .as_deref()
+            .is_some_and(|s| t.eq_ignore_ascii_case(s))
+        || (e.token != NATIVE && eq_id(&e.chain, t, &e.token))
+}
+
+/// Outcome of checking a transaction's spend against the limits.
+#[derive(Debug, Clone)]
+pub enum SpendCheck {
+    /// Per-limit usage for every limit the transaction touched.
+    Allowed(Vec<Value>),
+    /// The first limit that would be exceeded (or could not be evaluated).
+    Exceeded { message: String, data: Value },
+}
+
+/// Check `entries` against `limits`, given the recorded `ledger`.
+pub fn evaluate(
+    limits: &[SpendLimit],
+    ledger: &[SpendEntry],
+    entries: &[SpendEntry],
+    now_ms: u64,
+) -> SpendCheck {
+    let mut checks = Vec::new();
+    for limit in limits {
+        let Ok(window_ms) = parse_window_ms(&limit.window) else {
+            continue;
+        };
+        let since = now_ms.saturating_sub(window_ms);
+
+        let mut wallets: Vec<&str> = Vec::new();
+        for e in entries.iter().filter(|e| limit_matches(limit, e)) {
+            if !wallets.iter().any(|w| eq_id(&e.chain, w, &e.wallet)) {
+                wallets.push(e.wallet.as_str());
+            }
+        }
+
+        for wallet in wallets {
+            let same_wallet =
+                |e: &&SpendEntry| limit_matches(limit, e) && eq_id(&e.chain, wallet, &e.wallet);
+            let requested: Vec<&SpendEntry> = entries.iter().filter(same_wallet).collect();
+            let mut spent: Vec<&SpendEntry> = ledger
+                .iter()
+                .filter(|e| e.at_ms >= since && e.at_ms <= now_ms)
+                .filter(same_wallet)
+                .collect();
+            spent.sort_by_key(|e| e.at_ms);
+
+            let base = json!({
+                "limit_id": limit.id,
+                "chain": limit.chain,
+                "token": limit.token,
+                "wallet": wallet,
+                "window": limit.window,
+                "max_amount": limit.max_amount,
+            });
+
+            let unpriced = requested
+                .iter()
+                .chain(spent.iter())
+                .find(|e| e.amount_ui(limit.decimals).is_none());
+            if let Some(e) = unpriced {
+                let mut data = base;
+                data["error_class"] = json!("SPEND_LIMIT_UNPRICED");
+                data["unpriced_token"] = json!(e.token);
+                return SpendCheck::Exceeded {
+                    message: format!(
+                        "Spend limit '{}' cannot value token {} (set `decimals` on the limit)",
+                        limit.id, e.token
+                    ),
+                    data,
+                };
+            }
+
+            let ui = |e: &&SpendEntry| e.amount_ui(limit.decimals).unwrap_or(0.0);
+            let used: f64 = spent.iter().map(ui).sum();
+            let req: f64 = requested.iter().map(ui).sum();
+            let remaining = (limit.max_amount - used).max(0.0);
+
+            if used + req > limit.max_amount * (1.0 + 1e-9) {
+                // Earliest time at which enough of the recorded spend leaves the window.
+                let mut resets_in_ms = None;
+                if req <= limit.max_amount {
+                    let mut left = used;
+                    for e in &spent {
+                        left -= ui(e);
+                        if left + req <= limit.max_amount * (1.0 + 1e-9) {
+                            resets_in_ms = Some((e.at_ms + window_ms).saturating_sub(now_ms));
+                            break;
+                        }
+                    }
+                }
+                let mut data = base;
+                data["error_class"] = json!("SPEND_LIMIT_EXCEEDED");
+                data["used"] = json!(used);
+                data["requested"] = json!(req);
+                data["remaining"] = json!(remaining);
+                data["resets_in_ms"] = json!(resets_in_ms);
+                return SpendCheck::Exceeded {
+                    message: format!(
+                        "Spend limit '{}' exceeded: {} + {} > {} {} per {}",
+                        limit.id, used, req, limit.max_amount, limit.token, limit.window
+                    ),
+                    data,
+                };
+            }
+
+            let mut check = base;
+            check["used"] = json!(used);
+            check["requested"] = json!(req);
+            check["remaining_after"] = json!(remaining - req);
+            checks.push(check);
+        }
+    }
+    SpendCheck::Allowed(checks)
+}
+
+// ---------------------------------------------------------------------------
+// Ledger (derived from the pending-confirmation stores)
+// ---------------------------------------------------------------------------
+
+pub fn spend_json(entries: &[SpendEntry]) -> Option<String> {
+    if entries.is_empty() {
+        return None;
+    }
+    serde_json::to_string(entries).ok()
+}
+
+/// Spend booked by rows of the pending-confirmation store (all chains).
+pub fn ledger_since(
+    conn: &rusqlite::Connection,
+    since_ms: u64,
+    exclude_id: Option<&str>,
+) -> Result<Vec<SpendEntry>, ErrorData> {
+    let mut out = Vec::new();
+    for (id, json_text) in crate::utils::pending_store::ledger_rows(conn, since_ms)? {
+        if exclude_id == Some(id.as_str()) {
+            continue;
+        }
+        if let Ok(entries) = serde_json::from_str::<Vec<SpendEntry>>(&json_text) {
+            out.extend(entries.into_iter().filter(|e| e.at_ms >= since_ms));
+        }
+    }
+    Ok(out)
+}
+
+pub fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Whether any limit is configured (a broken limits file counts, so the guard reports it).
+/// Lets confirm paths skip extra work such as a Sui dry run when there is nothing to enforce.
+pub fn limits_configured() -> bool {
+    load_limits().map_or(true, |l| !l.limits.limits.is_empty())
+}
+
+/// Check a pending transaction's spend against the configured limits and, if allowed, book it on
+/// pending row `id` before broadcast. The ledger read and the booking share one `BEGIN IMMEDIATE`
+/// transaction, so concurrent confirms (or servers sharing the data dir) cannot both pass a
+/// limit. The row's own earlier booking is not counted (a retry replaces it).
+pub fn reserve(
+    conn: &mut rusqlite::Connection,
+    limits: &[SpendLimit],
+    entries: &[SpendEntry],
+    id: &str,
+    now_ms: u64,
+) -> Result<SpendCheck, ErrorData> {
+    let Some(json_text) = spend_json(entries) else {
+        return Ok(SpendCheck::Allowed(Vec::new()));
+    };
+    let max_window = limits
+        .iter()
+        .filter_map(|l| parse_window_ms(&l.window).ok())
+        .max()
+        .unwrap_or(0);
+
+    let tx = conn
+        .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+        .map_err(|e| ledger_err("Failed to lock spend ledger", e))?;
+    let ledger = ledger_since(&tx, now_ms.saturating_sub(max_window), Some(id))?;
+    let check = evaluate(limits, &ledger, entries, now_ms);
+    if let SpendCheck::Allowed(_) = check {
+        crate::utils::pending_store::set_spend(&tx, id, &json_text)?;
+        tx.commit()
+            .map_err(|e| ledger_err("Failed to book spend", e))?;
+    }
+    Ok(check)
+}
+
+fn ledger_err(ctx: &str, e: rusqlite::Error) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: std::borrow::Cow::from(format!("{}: {}", ctx, e)),
+        data: None,
+    }
+}
+
+/// Spend booked on a pending row by [`reserve`]. Dropping it without [`SpendReservation::keep`]
+/// (a sign or send error, an early return) releases the booking.
+#[must_use]
+pub struct SpendReservation {
+    id: Option<String>,
+}
+
+impl SpendReservation {
+    /// The transaction was broadcast: its spend stays in the ledger.
+    pub fn keep(mut self) {
+        self.id = None;
+    }
+}
+
+impl Drop for SpendReservation {
+    fn drop(&mut self) {
+        let Some(id) = self.id.take() else {
+            return;
+        };
+        let released = crate::utils::pending_store::connect()
+            .and_then(|conn| crate::utils::pending_store::clear_spend(&conn, &id));
+        if let Err(e) = released {
+            tracing::warn!(id = %id, error = %e.message, "Failed to release booked spend");
+        }
+    }
+}
+
+/// Result of [`Web3McpServer::spend_limit_guard`].
+pub enum SpendGuard {
+    Reserved(SpendReservation),
+    /// The guard result the tool must return.
+    Blocked(Result<CallToolResult, ErrorData>),
+}
+
+impl Web3McpServer {
+    /// ERC20s the server can value without a `decimals` hint on the limit.
+    pub fn spend_known_evm_tokens(chain_id: u64) -> Vec<(String, &'static str, u8)> {
+        [("usdc", "USDC"), ("usdt", "USDT")]
+            .iter()
+            .filter_map(|(sym, label)| {
+                Self::resolve_evm_erc20_address(sym, chain_id)
+                    .map(|a| (a.to_lowercase(), *label, 6))
+            })
+            .collect()
+    }
+
+    /// Confirm-time spend-limit guard: books the spend on pending row `id` (see [`reserve`])
+    /// or returns the guard result. Call it right before signing, and `keep` the reservation
+    /// once the transaction is broadcast.
+    pub fn spend_limit_guard(context: &str, entries: &[SpendEntry], id: &str) -> SpendGuard {
+        if entries.is_empty() {
+            return SpendGuard::Reserved(SpendReservation { id: None });
+        }
+        let reserved = load_limits().and_then(|loaded| {
+            let mut conn = crate::utils::pending_store::connect()
+                .map_err(|e| format!("failed to open spend ledger: {}", e.message))?;
+            reserve(&mut conn, &loaded.limits.limits, entries, id, now_ms())
+                .map_err(|e| format!("failed to book spend: {}", e.message))
+        });
+        let blocked = match reserved {
+            Ok(SpendCheck::Allowed(_)) => {
+                return SpendGuard::Reserved(SpendReservation {
+                    id: Some(id.to_string()),
+                })
+            }
+            Ok(SpendCheck::Exceeded { message, data }) => {
+                let class = data
+                    .get("error_class")
+                    .and_then(Value::as_str)
+                    .unwrap_or("SPEND_LIMIT_EXCEEDED")
+                    .to_string();
+                Self::guard_result(
+                    context,
+                    &class,
+                    &message,
+                    false,
+                    Some("Wait for the window to roll over, lower the amount, or adjust the spend limits file"),
+                    None,
+                    Some(data),
+                )
+            }
+            Err(e) => Self::guard_result(
+                context,
+                "SPEND_LIMITS_INVALID",
+                "Spend limits could not be evaluated",
+                false,
+                Some("Fix the spend limits file (W3RT_SPEND_LIMITS or <data_dir>/policies/spend_limits.toml)"),
+                None,
+                Some(json!({ "error": e })),
+            ),
+        };
+        SpendGuard::Blocked(blocked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usdc_on_base(amount_ui: u64, at_ms: u64) -> SpendEntry {
+        SpendEntry {
+            chain: "evm".to_string(),
+            network: "base".to_string(),
+            chain_id: Some(8453),
+            wallet: "0xabc".to_string(),
+            token: "0x833589fcd6edb6e08f4c7c32d4f71b54bda02913".to_string(),
+            symbol: Some("USDC".to_string()),
+            amount_raw: (amount_ui * 1_000_000).to_string(),
+            decimals: Some(6),
+            at_ms,
+        }
+    }
+
+    #[test]
+    fn windows_parse() {
+        assert_eq!(parse_window_ms("1h").unwrap(), 3_600_000);
+        assert_eq!(parse_window_ms("24h").unwrap(), 86_400_000);
+        assert_eq!(parse_window_ms("90").unwrap(), 90_000);
+        assert!(parse_window_ms("0h").is_err());
+        assert!(parse_window_ms("1y").is_err());
+        assert!(parse_window_ms("60d").is_err());
+    }
+
+    #[test]
+    fn rolling_window_sums_ledger_per_wallet() {
+        let limits = parse_limits(
+            r#"
+            [[limits]]
+            id = "base-usdc-daily"
+            chain = "base"
+            token = "USDC"
+            max_amount = 500
+            window = "24h"
+            "#,
+            true,
+        )
+        .unwrap()
+        .limits;
+        let day = 86_400_000;
+        let now = 10 * day;
+        let ledger = vec![
+            usdc_on_base(300, now - day - 1), // outside the window
+            usdc_on_base(200, now - 3_600_000),
+            usdc_on_base(250, now - 60_000),
+        ];
+
+        match evaluate(&limits, &ledger, &[usdc_on_base(50, now)], now) {
+            SpendCheck::Allowed(checks) => assert_eq!(checks[0]["remaining_after"], json!(0.0)),
+            other => panic!("expected allowed, got {other:?}"),
+        }
+        match evaluate(&limits, &ledger, &[usdc_on_base(100, now)], now) {
+            SpendCheck::Exceeded { data, .. } => {
+                assert_eq!(data["used"], json!(450.0));
+                // Dropping the 200 spent an hour ago frees enough room.
+                assert_eq!(data["resets_in_ms"], json!(day - 3_600_000));
+            }
+            other => panic!("expected exceeded, got {other:?}"),
+        }
+
+        let mut other_wallet = usdc_on_base(100, now);
+        other_wallet.wallet = "0xdef".to_string();
+        assert!(matches!(
+            evaluate(&limits, &ledger, &[other_wallet], now),
+            SpendCheck::Allowed(_)
+        ));
+    }
+
+    #[test]
+    fn concurrent_reservations_cannot_both_pass_a_limit() {
+        use crate::utils::pending_store::{self, NewPending, PendingChain};
+
+        let limits = parse_limits(
+            "[[limits]]\nid = \"base-usdc\"\nchain = \"base\"\ntoken = \"USDC\"\nmax_amount = 500\nwindow = \"24h\"\n",
+            true,
+        )
+        .unwrap()
+        .limits;
+        let path = std::env::temp_dir().join(format!("w3rt_spend_reserve_{}.sqlite", now_ms()));
+        let open = || {
+            let mut conn = rusqlite::Connection::open(&path).unwrap();
+            conn.busy_timeout(std::time::Duration::from_secs(5))
+                .unwrap();
+            pending_store::apply_migrations(
+                &mut conn,
+                pending_store::COMPONENT,
+                pending_store::MIGRATIONS,
+            )
+            .unwrap();
+            conn
+        };
+        let conn = open();
+        for id in ["a", "b", "c"] {
+            pending_store::insert(
+                &conn,
+                &NewPending {
+                    id,
+                    chain: PendingChain::Evm,
+                    network: Some("8453"),
+                    created_at_ms: 1,
+                    expires_at_ms: u64::MAX / 2,
+                    tx_summary_hash: "h",
+                    source_tool: Some("test"),
+                    summary: None,
+                    payload: &json!({}),
+                },
+            )
+            .unwrap();
+        }
+
+        let now = now_ms();
+        let allowed: usize = std::thread::scope(|s| {
+            let handles: Vec<_> = ["a", "b"]
+                .into_iter()
+                .map(|id| {
+                    let (open, limits) = (&open, &limits);
+                    s.spawn(move || {
+                        let check =
+                            reserve(&mut open(), limits, &[usdc_on_base(300, now)], id, now)
+                                .unwrap();
+                        usize::from(matches!(check, SpendCheck::Allowed(_)))
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum()
+        });
+        assert_eq!(allowed, 1);
+
+        // A retry replaces the row's own booking; releasing it frees the window for others.
+        let booked = ["a", "b"]
+            .into_iter()
+            .find(|id| {
+                pending_store::get(&conn, id)
+                    .unwrap()
+                    .unwrap()
+                    .spend_json
+                    .is_some()
+            })
+            .unwrap();
+        let mut conn = conn;
+        let retry = reserve(&mut conn, &limits, &[usdc_on_base(500, now)], booked, now).unwrap();
+        assert!(matches!(retry, SpendCheck::Allowed(_)));
+        let other = reserve(&mut conn, &limits, &[usdc_on_base(1, now)], "c", now).unwrap();
+        assert!(matches!(other, SpendCheck::Exceeded { .. }));
+        pending_store::clear_spend(&conn, booked).unwrap();
+        let other = reserve(&mut conn, &limits, &[usdc_on_base(500, now)], "c", now).unwrap();
+        assert!(matches!(other, SpendCheck::Allowed(_)));
+
+        drop(conn);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn unknown_token_decimals_fail_closed() {
+        let limits = parse_limits(
+            r#"{"limits":[{"id":"any","chain":"solana","token":"MintX","max_amount":1,"window":"1h"}]}"#,
+            false,
+        )
+        .unwrap()
+        .limits;
+        let ix = (
+            SOLANA_TOKEN_PROGRAM.to_string(),
+            vec!["src".into(), "dst".into(), "owner".into()],
+            [vec![3u8], 5u64.to_le_bytes().to_vec()].concat(),
+        );
+        let entries =
+            solana_entries_from_instructions("mainnet", &[ix], Some(("MintX".into(), None)), 1);
+        assert_eq!(entries[0].wallet, "owner");
+        assert!(matches!(
+            evaluate(&limits, &[], &entries, 1),
+            SpendCheck::Exceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn extracts_spend_from_summaries_and_balance_changes() {
+        let swap = json!({"swap": {
+            "user_pubkey": "User1",
+            "input_mint": SOLANA_WSOL_MINT,
+            "input_decimals": 9,
+            "amount_in_base": "2500000000"
+        }});
+        let e = solana_entries_from_summary("mainnet", &swap, 7);
+        assert_eq!(e[0].token, NATIVE);
+        assert_eq!(e[0].amount_ui(None), Some(2.5));
+
+        let changes = json!([
+            {"owner": {"AddressOwner": "0xA1"}, "coinType": "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI", "amount": "-1500000000"},
+            {"owner": {"AddressOwner": "0xB2"}, "coinType": "0x2::sui::SUI", "amount": "1500000000"}
+        ]);
+        let e = sui_entries_from_balance_changes("mainnet", "0xa1", &changes, None, 7);
+        assert_eq!(e.len(), 1);
+        assert_eq!(e[0].symbol.as_deref(), Some("SUI"));
+        assert_eq!(e[0].amount_ui(None), Some(1.5));
+    }
+}
@@ -0,0 +1,421 @@
+//! Sui k-of-n multisig: address derivation, partial-signature checks and combining.
+//!
+//! Members are given as base64 `flag || public key` (the `sui keytool` format) with a weight;
+//! the multisig address is derived from the member set and threshold exactly as on chain, so
+//! the member order matters. Partial signatures are ordinary serialized Sui signatures over the
+//! transaction intent; the combined signature is the serialized `GenericSignature::MultiSig`
+//! that `Transaction::from_generic_sig_data` accepts.
+
+use crate::utils::signer::signature_invalid;
+use base64::engine::general_purpose::STANDARD as Base64Engine;
+use base64::Engine as _;
+use rmcp::model::{ErrorCode, ErrorData};
+use serde_json::{json, Value};
+use shared_crypto::intent::{Intent, IntentMessage};
+use std::borrow::Cow;
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{EncodeDecodeBase64, PublicKey, Signature, SuiSignature, ToFromBytes};
+use sui_types::multisig::{MultiSig, MultiSigPublicKey};
+use sui_types::signature::GenericSignature;
+use sui_types::transaction::{TransactionData, TransactionDataAPI};
+
+/// Verified multisig over one transaction.
+pub struct Checked {
+    pub multisig: MultiSig,
+    pub address: SuiAddress,
+    pub weight: u16,
+    pub threshold: u16,
+    /// `(member index, member address, weight)` of every signer, in member order.
+    pub signers: Vec<(usize, SuiAddress, u8)>,
+}
+
+impl Checked {
+    /// Base64 of the serialized `GenericSignature::MultiSig`.
+    pub fn signature_b64(&self) -> String {
+        Base64Engine.encode(GenericSignature::MultiSig(self.multisig.clone()).as_ref())
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "multisig_address": self.address.to_string(),
+            "weight": self.weight,
+            "threshold": self.threshold,
+            "signers": self
+                .signers
+                .iter()
+                .map(|(index, address, weight)| json!({
+                    "index": index,
+                    "address": address.to_string(),
+                    "weight": weight
+                }))
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+fn invalid(message: impl Into<String>, data: Value) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32602),
+        message: Cow::from(message.into()),
+        data: Some(data),
+    }
+}
+
+/// Multisig public key from `(base64 public key, weight)` members and a threshold.
+pub fn public_key(
+    members: &[(String, u8)],
+    threshold: u16,
+) -> Result<MultiSigPublicKey, ErrorData> {
+    let mut pks = Vec::with_capacity(members.len());
+    let mut weights = Vec::with_capacity(members.len());
+    for (index, (encoded, weight)) in members.iter().enumerate() {
+        let pk = PublicKey::decode_base64(encoded.trim()).map_err(|e| {
+            invalid(
+                format!("Invalid public key for member {}: {}", index, e),
+                json!({
+                    "index": index,
+                    "public_key": encoded,
+                    "hint": "use base64 flag || public key, as printed by `sui keytool list`"
+                }),
+            )
+        })?;
+        pks.push(pk);
+        weights.push(*weight);
+    }
+    let total: u16 = weights.iter().map(|w| u16::from(*w)).sum();
+    if threshold == 0 || threshold > total {
+        return Err(invalid(
+            format!(
+                "threshold must be between 1 and the total member weight ({})",
+                total
+            ),
+            json!({ "threshold": threshold, "total_weight": total }),
+        ));
+    }
+    MultiSigPublicKey::new(pks, weights, threshold).map_err(|e| {
+        invalid(
+            format!("Invalid multisig members: {}", e),
+            json!({ "members": members.len(), "threshold": threshold }),
+        )
+    })
+}
+
+/// Members as JSON: index, public key, derived single-key address and weight.
+pub fn members_json(multisig_pk: &MultiSigPublicKey) -> Vec<Value> {
+    multisig_pk
+        .pubkeys()
+        .iter()
+        .enumerate()
+        .map(|(index, (pk, weight))| {
+            json!({
+                "index": index,
+                "public_key": pk.encode_base64(),
+                "address": SuiAddress::from(pk).to_string(),
+                "weight": weight
+            })
+        })
+        .collect()
+}
+
+fn member_index(multisig_pk: &MultiSigPublicKey, pk: &PublicKey) -> Option<usize> {
+    multisig_pk
+        .pubkeys()
+        .iter()
+        .position(|(member, _)| member == pk)
+}
+
+fn intent_message(tx_data: &TransactionData) -> IntentMessage<TransactionData> {
+    IntentMessage::new(Intent::sui_transaction(), tx_data.clone())
+}
+
+/// Decode a partial signature and check it signs `tx_data` for one of the members;
+/// returns it with the member index.
+pub fn check_partial(
+    multisig_pk: &MultiSigPublicKey,
+    tx_data: &TransactionData,
+    signature_b64: &str,
+) -> Result<(usize, Signature), ErrorData> {
+    let bytes = Base64Engine
+        .decode(signature_b64.trim())
+        .map_err(|e| invalid(format!("Invalid base64 signature: {}", e), json!({})))?;
+    let sig = Signature::from_bytes(&bytes).map_err(|e| {
+        invalid(
+            format!("Invalid Sui signature: {}", e),
+            json!({ "signature": signature_b64 }),
+        )
+    })?;
+    let pk = sig.to_public_key().map_err(|e| {
+        invalid(
+            format!("Sui signature has no usable public key: {}", e),
+            json!({ "signature": signature_b64 }),
+        )
+    })?;
+    let index = member_index(multisig_pk, &pk).ok_or_else(|| {
+        signature_invalid(
+            "Signature is not from a multisig member",
+            json!({ "signed_by": SuiAddress::from(&pk).to_string() }),
+        )
+    })?;
+    sig.verify_secure(
+        &intent_message(tx_data),
+        SuiAddress::from(&pk),
+        sig.scheme(),
+    )
+    .map_err(|e| {
+        signature_invalid(
+            format!("Signature does not verify for this transaction: {}", e),
+            json!({ "index": index, "signed_by": SuiAddress::from(&pk).to_string() }),
+        )
+    })?;
+    Ok((index, sig))
+}
+
+/// Combine partial signatures (any order, one per member) into a multisig for `tx_data`.
+pub fn combine(
+    multisig_pk: MultiSigPublicKey,
+    tx_data: &TransactionData,
+    signatures: &[String],
+) -> Result<Checked, ErrorData> {
+    let mut partials: Vec<(usize, Signature)> = Vec::with_capacity(signatures.len());
+    for encoded in signatures {
+        let (index, sig) = check_partial(&multisig_pk, tx_data, encoded)?;
+        if partials.iter().any(|(i, _)| *i == index) {
+            return Err(invalid(
+                format!("Duplicate signature for member {}", index),
+                json!({ "index": index }),
+            ));
+        }
+        partials.push((index, sig));
+    }
+    // The bitmap is read in member order on chain, so keep the signatures in that order.
+    partials.sort_by_key(|(index, _)| *index);
+    let multisig = MultiSig::combine(
+        partials.into_iter().map(|(_, sig)| sig).collect(),
+        multisig_pk,
+    )
+    .map_err(|e| invalid(format!("Failed to combine signatures: {}", e), json!({})))?;
+    verify(&multisig, tx_data)
+}
+
+/// Decode a serialized multisig (base64 `GenericSignature::MultiSig` bytes).
+pub fn decode(multisig_b64: &str) -> Result<MultiSig, ErrorData> {
+    let bytes = Base64Engine
+        .decode(multisig_b64.trim())
+        .map_err(|e| invalid(format!("Invalid base64 multisig: {}", e), json!({})))?;
+    match GenericSignature::from_bytes(&bytes) {
+        Ok(GenericSignature::MultiSig(multisig)) => Ok(multisig),
+        Ok(_) => Err(invalid(
+            "Signature is not a Sui multisig",
+            json!({ "hint": "combine partial signatures with sui_multisig_combine" }),
+        )),
+        Err(e) => Err(invalid(format!("Invalid Sui multisig: {}", e), json!({}))),
+    }
+}
+
+/// Check every signature in `multisig` against `tx_data`, the signed weight against the
+/// threshold, and that the multisig address is the transaction sender.
+pub fn verify(multisig: &MultiSig, tx_data: &TransactionData) -> Result<Checked, ErrorData> {
+    let multisig_pk = multisig.get_pk();
+    let address = SuiAddress::from(multisig_pk);
+    let members = multisig_pk.pubkeys();
+    let indices = multisig
+        .get_indices()
+        .map_err(|e| invalid(format!("Invalid multisig bitmap: {}", e), json!({})))?;
+    if indices.len() != multisig.get_sigs().len() {
+        return Err(signature_invalid(
+            "Multisig bitmap does not match its signatures",
+            json!({ "multisig_address": address.to_string() }),
+        ));
+    }
+
+    let msg = intent_message(tx_data);
+    let mut signers = Vec::with_capacity(indices.len());
+    let mut weight: u16 = 0;
+    for (compressed, index) in multisig.get_sigs().iter().zip(indices) {
+        let index = usize::from(index);
+        let (pk, member_weight) = members.get(index).ok_or_else(|| {
+            signature_invalid(
+                format!("Multisig references unknown member {}", index),
+                json!({ "multisig_address": address.to_string() }),
+            )
+        })?;
+        let member = SuiAddress::from(pk);
+        let bytes = [&[pk.flag()][..], compressed.as_ref(), pk.as_ref()].concat();
+        let verified = Signature::from_bytes(&bytes)
+            .is_ok_and(|sig| sig.verify_secure(&msg, member, sig.scheme()).is_ok());
+        if !verified {
+            return Err(signature_invalid(
+                format!("Multisig signature of member {} does not verify", index),
+                json!({ "index": index, "address": member.to_string() }),
+            ));
+        }
+        weight += u16::from(*member_weight);
+        signers.push((index, member, *member_weight));
+    }
+
+    let threshold = *multisig_pk.threshold();
+    if weight < threshold {
+        return Err(signature_invalid(
+            format!(
+                "Signed weight {} is below the threshold {}",
+                weight, threshold
+            ),
+            json!({
+                "multisig_address": address.to_string(),
+                "weight": weight,
+                "threshold": threshold
+            }),
+        ));
+    }
+    if tx_data.sender() != address {
+        return Err(invalid(
+            format!(
+                "Transaction sender {} is not the multisig address {}",
+                tx_data.sender(),
+                address
+            ),
+            json!({
+                "sender": tx_data.sender().to_string(),
+                "multisig_address": address.to_string()
+            }),
+        ));
+    }
+
+    Ok(Checked {
+        multisig: multisig.clone(),
+        address,
+        weight,
+        threshold,
+        signers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::signer_backends::sui_ed25519_keypair;
+    use sui_types::base_types::{ObjectDigest, ObjectID, SequenceNumber};
+    use sui_types::crypto::SuiKeyPair;
+
+    fn keys() -> Vec<SuiKeyPair> {
+        (1u8..=3)
+            .map(|seed| sui_ed25519_keypair(&[seed; 32], "test").unwrap())
+            .collect()
+    }
+
+    /// 2-of-3 over the test keys, each with weight 1.
+    fn two_of_three(keys: &[SuiKeyPair]) -> MultiSigPublicKey {
+        let members: Vec<(String, u8)> = keys
+            .iter()
+            .map(|kp| (kp.public().encode_base64(), 1))
+            .collect();
+        public_key(&members, 2).unwrap()
+    }
+
+    fn transfer(sender: SuiAddress, amount: u64) -> TransactionData {
+        let gas = (
+            ObjectID::ZERO,
+            SequenceNumber::from_u64(1),
+            ObjectDigest::new([7; 32]),
+        );
+        TransactionData::new_transfer_sui(
+            SuiAddress::ZERO,
+            sender,
+            Some(amount),
+            gas,
+            10_000_000,
+            1_000,
+        )
+    }
+
+    fn sign(kp: &SuiKeyPair, tx_data: &TransactionData) -> String {
+        Base64Engine.encode(Signature::new_secure(&intent_message(tx_data), kp).as_ref())
+    }
+
+    fn error_class(err: ErrorData) -> Value {
+        err.data.unwrap_or_default()["error_class"].clone()
+    }
+
+    #[test]
+    fn two_of_three_round_trips_through_decode_and_verify() {
+        let keys = keys();
+        let multisig_pk = two_of_three(&keys);
+        let tx_data = transfer(SuiAddress::from(&multisig_pk), 5);
+
+        // Signatures arrive out of member order.
+        let sigs = vec![sign(&keys[2], &tx_data), sign(&keys[0], &tx_data)];
+        let checked = combine(multisig_pk.clone(), &tx_data, &sigs).unwrap();
+        assert_eq!(checked.address, SuiAddress::from(&multisig_pk));
+        assert_eq!((checked.weight, checked.threshold), (2, 2));
+        let indices: Vec<usize> = checked.signers.iter().map(|(i, _, _)| *i).collect();
+        assert_eq!(indices, vec![0, 2]);
+
+        let decoded = decode(&checked.signature_b64()).unwrap();
+        let reverified = verify(&decoded, &tx_data).unwrap();
+        assert_eq!(reverified.weight, 2);
+    }
+
+    #[test]
+    fn combine_rejects_weight_below_threshold() {
+        let keys = keys();
+        let multisig_pk = two_of_three(&keys);
+        let tx_data = transfer(SuiAddress::from(&multisig_pk), 5);
+
+        let err = combine(multisig_pk, &tx_data, &[sign(&keys[1], &tx_data)]).unwrap_err();
+        assert!(
+            err.message.contains("below the threshold"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn combine_rejects_a_duplicate_signer() {
+        let keys = keys();
+        let multisig_pk = two_of_three(&keys);
+        let tx_data = transfer(SuiAddress::from(&multisig_pk), 5);
+
+        let sig = sign(&keys[0], &tx_data);
+        let err = combine(multisig_pk, &tx_data, &[sig.clone(), sig]).unwrap_err();
+        assert!(
+            err.message.contains("Duplicate signature"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn verify_rejects_a_sender_other_than_the_multisig_address() {
+        let keys = keys();
+        let multisig_pk = two_of_three(&keys);
+        let other = SuiAddress::from(&keys[0].public());
+        let tx_data = transfer(other, 5);
+
+        let sigs = vec![sign(&keys[0], &tx_data), sign(&keys[1], &tx_data)];
+        let err = combine(multisig_pk, &tx_data, &sigs).unwrap_err();
+        assert!(
+            err.message.contains("is not the multisig address"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn signatures_over_another_transaction_do_not_verify() {
+        let keys = keys();
+        let multisig_pk = two_of_three(&keys);
+        let sender = SuiAddress::from(&multisig_pk);
+        let signed = transfer(sender, 5);
+        let tampered = transfer(sender, 5_000);
+
+        // A partial signature is checked against the transaction it is combined for...
+        let err = check_partial(&multisig_pk, &tampered, &sign(&keys[0], &signed)).unwrap_err();
+        assert_eq!(error_class(err), "SIGNATURE_INVALID");
+
+        // ...and a finished multisig no longer verifies once the transaction changes.
+        let sigs = vec![sign(&keys[0], &signed), sign(&keys[1], &signed)];
+        let checked = combine(multisig_pk, &signed, &sigs).unwrap();
+        let err = verify(&checked.multisig, &tampered).unwrap_err();
+        assert_eq!(error_class(err), "SIGNATURE_INVALID");
+    }
+}
@@ -0,0 +1,455 @@
+//! Sui sponsored transactions (gas station mode): sponsor policy and per-sender gas ledger.
+//!
+//! The sponsor is a Sui account of the signer keystore (`sui.sponsor.account`, a file
+//! `<signer.keystore_dir>/sui/<account>.json`). It co-signs only transactions that pass
+//! [`Policy::violations`]: every Move call matches `sui.sponsor.allowed_move_targets`, the gas
+//! coin is never used as an argument (it belongs to the sponsor), no publish / upgrade, the gas
+//! budget is at most `sui.sponsor.max_gas_budget`, and the sender stays within
+//! `sui.sponsor.sender_budget_mist` of sponsored gas per `sui.sponsor.budget_window_secs`.
+//!
+//! Sponsored gas is booked in the `sui_sponsor_ledger` table of `<data_dir>/pending.sqlite`:
+//! the whole gas budget when the sponsor signs, then the gas actually charged once the
+//! effects are known.
+
+use crate::utils::pending_store::{apply_migrations, Migration};
+use rmcp::model::{ErrorCode, ErrorData};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+
+pub const COMPONENT: &str = "sui_sponsor_ledger";
+pub const DEFAULT_MAX_GAS_BUDGET: u64 = 50_000_000;
+pub const DEFAULT_SENDER_BUDGET_MIST: u64 = 1_000_000_000;
+pub const DEFAULT_BUDGET_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    pub account: Option<String>,
+    pub max_gas_budget: u64,
+    pub sender_budget_mist: u64,
+    pub window_ms: u64,
+    /// `package::module::function` globs, addresses normalized.
+    pub allowed_move_targets: Vec<String>,
+}
+
+/// What a transaction does, as far as the policy cares.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxShape {
+    pub move_targets: Vec<String>,
+    pub uses_gas_coin: bool,
+    /// Commands the sponsor never pays for (`Publish`, `Upgrade`).
+    pub unsupported: Vec<String>,
+}
+
+fn u64_var(key: &str, default: u64) -> u64 {
+    crate::utils::config::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+impl Policy {
+    pub fn from_config() -> Self {
+        let allowed = crate::utils::config::var("SUI_SPONSOR_ALLOWED_MOVE_TARGETS")
+            .map(|v| crate::utils::tool_profiles::parse_globs(&v))
+            .unwrap_or_default();
+        Self {
+            account: crate::utils::config::var("SUI_SPONSOR_ACCOUNT")
+                .ok()
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty()),
+            max_gas_budget: u64_var("SUI_SPONSOR_MAX_GAS_BUDGET", DEFAULT_MAX_GAS_BUDGET),
+            sender_budget_mist: u64_var(
+                "SUI_SPONSOR_SENDER_BUDGET_MIST",
+                DEFAULT_SENDER_BUDGET_MIST,
+            ),
+            window_ms: u64_var("SUI_SPONSOR_BUDGET_WINDOW_SECS", DEFAULT_BUDGET_WINDOW_SECS)
+                .max(1)
+                .saturating_mul(1000),
+            allowed_move_targets: allowed.iter().map(|t| normalize_move_target(t)).collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "account": self.account,
+            "max_gas_budget": self.max_gas_budget,
+            "sender_budget_mist": self.sender_budget_mist,
+            "budget_window_secs": self.window_ms / 1000,
+            "allowed_move_targets": self.allowed_move_targets
+        })
+    }
+
+    pub fn move_target_allowed(&self, target: &str) -> bool {
+        let target = normalize_move_target(target);
+        self.allowed_move_targets
+            .iter()
+            .any(|pattern| crate::utils::tool_profiles::glob_match(pattern, &target))
+    }
+
+    /// Reasons the sponsor must not pay for a transaction of `shape` with `gas_budget`, given
+    /// what the sender already spent in the window. Empty means allowed.
+    pub fn violations(&self, shape: &TxShape, gas_budget: u64, sender_spent: u64) -> Vec<String> {
+        let mut out = Vec::new();
+        if shape.uses_gas_coin {
+            out.push(
+                "transaction uses the gas coin (the sponsor's SUI) as an argument".to_string(),
+            );
+        }
+        for command in &shape.unsupported {
+            out.push(format!("{} commands are never sponsored", command));
+        }
+        for target in &shape.move_targets {
+            if !self.move_target_allowed(target) {
+                out.push(format!(
+                    "Move call {} is not in allowed_move_targets",
+                    target
+                ));
+            }
+        }
+        if gas_budget > self.max_gas_budget {
+            out.push(format!(
+                "gas budget {} exceeds max_gas_budget {}",
+                gas_budget, self.max_gas_budget
+            ));
+        }
+        if sender_spent.saturating_add(gas_budget) > self.sender_budget_mist {
+            out.push(format!(
+                "sender budget exceeded: {} MIST sponsored in the window + {} > {}",
+                sender_spent, gas_budget, self.sender_budget_mist
+            ));
+        }
+        out
+    }
+}
+
+/// `0x0000…02::coin::join` -> `0x2::coin::join`; glob characters are kept.
+pub fn normalize_move_target(target: &str) -> String {
+    let target = target.trim();
+    match target.split_once("::") {
+        Some((address, rest)) if address != "*" => {
+            let hex = address.trim_start_matches("0x").trim_start_matches("0X");
+            let hex = hex.trim_start_matches('0').to_lowercase();
+            format!("0x{}::{}", if hex.is_empty() { "0" } else { &hex }, rest)
+        }
+        _ => target.to_string(),
+    }
+}
+
+/// `package::module::function`, each part a hex address / identifier or a glob.
+pub fn is_valid_move_target(target: &str) -> bool {
+    let parts: Vec<&str> = target.trim().split("::").collect();
+    let ident = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '*' | '?'))
+    };
+    match parts.as_slice() {
+        [address, module, function] => {
+            let hex = address.strip_prefix("0x").unwrap_or(address);
+            (*address == "*"
+                || (!hex.is_empty()
+                    && hex.len() <= 64
+                    && hex
+                        .chars()
+                        .all(|c| c.is_ascii_hexdigit() || matches!(c, '*' | '?'))))
+                && ident(module)
+                && ident(function)
+        }
+        _ => false,
+    }
+}
+
+// ---- Ledger --------------------------------------------------------------------------------
+
+fn db_err(ctx: &str, e: impl std::fmt::Display) -> ErrorData {
+    ErrorData {
+        code: ErrorCode(-32603),
+        message: Cow::from(format!("{}: {}", ctx, e)),
+        data: None,
+    }
+}
+
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "create_sui_sponsor_ledger",
+    up: m001_create_table,
+}];
+
+fn m001_create_table(tx: &rusqlite::Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sui_sponsor_ledger (
+           digest TEXT PRIMARY KEY,
+           sender TEXT NOT NULL,
+           network TEXT,
+           gas_mist INTEGER NOT NULL,
+           status TEXT NOT NULL,
+           created_ms INTEGER NOT NULL,
+           updated_ms INTEGER NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_sui_sponsor_sender ON sui_sponsor_ledger(sender, created_ms);",
+    )
+}
+
+/// Open (and migrate) the ledger at `path`.
+pub fn open_db(path: &std::path::Path) -> Result<rusqlite::Connection, ErrorData> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| db_err("Failed to create data dir", e))?;
+    }
+    let mut conn =
+        rusqlite::Connection::open(path).map_err(|e| db_err("Failed to open sqlite db", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| db_err("Failed to set sqlite busy_timeout", e))?;
+    apply_migrations(&mut conn, COMPONENT, MIGRATIONS)?;
+    Ok(conn)
+}
+
+pub fn connect() -> Result<rusqlite::Connection, ErrorData> {
+    open_db(&crate::utils::data_dir::pending_db_path())
+}
+
+/// Gas sponsored for `sender` since `since_ms` (reserved budgets count in full).
+pub fn spent_since(
+    conn: &rusqlite::Connection,
+    sender: &str,
+    since_ms: u64,
+) -> Result<u64, ErrorData> {
+    conn.query_row(
+        "SELECT COALESCE(SUM(gas_mist), 0) FROM sui_sponsor_ledger WHERE sender=?1 AND created_ms>=?2",
+        rusqlite::params![sender, since_ms as i64],
+        |row| row.get::<_, i64>(0),
+    )
+    .map(|n| n.max(0) as u64)
+    .map_err(|e| db_err("Failed to read sponsor ledger", e))
+}
+
+/// Outcome of [`reserve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reservation {
+    Booked,
+    /// The sender's window budget cannot cover `gas_budget`; nothing was written.
+    OverBudget {
+        spent: u64,
+    },
+}
+
+/// Book `gas_budget` for `digest` before the sponsor signs, if the sender's window budget still
+/// covers it. The budget check and the insert share one `BEGIN IMMEDIATE` transaction, so
+/// concurrent requests for one sender cannot both pass the check.
+pub fn reserve(
+    conn: &mut rusqlite::Connection,
+    policy: &Policy,
+    digest: &str,
+    sender: &str,
+    network: Option<&str>,
+    gas_budget: u64,
+    now_ms: u64,
+) -> Result<Reservation, ErrorData> {
+    let tx = conn
+        .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+        .map_err(|e| db_err("Failed to lock sponsor ledger", e))?;
+    let spent = spent_since(&tx, sender, now_ms.saturating_sub(policy.window_ms))?;
+    if spent.saturating_add(gas_budget) > policy.sender_budget_mist {
+        return Ok(Reservation::OverBudget { spent });
+    }
+    tx.execute(
+        "INSERT INTO sui_sponsor_ledger (digest, sender, network, gas_mist, status, created_ms, updated_ms)
+         VALUES (?1, ?2, ?3, ?4, 'reserved', ?5, ?5)",
+        rusqlite::params![digest, sender, network, gas_budget as i64, now_ms as i64],
+    )
+    .map_err(|e| {
+        if let rusqlite::Error::SqliteFailure(f, _) = &e {
+            if f.code == rusqlite::ErrorCode::ConstraintViolation {
+                return ErrorData {
+                    code: ErrorCode(-32602),
+                    message: Cow::from("This transaction was already sponsored"),
+                    data: Some(json!({ "digest": digest })),
+                };
+            }
+        }
+        db_err("Failed to write sponsor ledger", e)
+    })?;
+    tx.commit()
+        .map_err(|e| db_err("Failed to write sponsor ledger", e))?;
+    Ok(Reservation::Booked)
+}
+
+/// Replace the reserved budget with the gas the transaction was charged.
+pub fn settle(
+    conn: &rusqlite::Connection,
+    digest: &str,
+    gas_charged: u64,
+    now_ms: u64,
+) -> Result<(), ErrorData> {
+    conn.execute(
+        "UPDATE sui_sponsor_ledger SET gas_mist=?2, status='settled', updated_ms=?3 WHERE digest=?1",
+        rusqlite::params![digest, gas_charged as i64, now_ms as i64],
+    )
+    .map_err(|e| db_err("Failed to update sponsor ledger", e))?;
+    Ok(())
+}
+
+/// Drop a reservation whose transaction was never submitted. Not for submission errors: the
+/// transaction may still have executed and charged the sponsor.
+pub fn release(conn: &rusqlite::Connection, digest: &str) -> Result<(), ErrorData> {
+    conn.execute(
+        "DELETE FROM sui_sponsor_ledger WHERE digest=?1 AND status='reserved'",
+        [digest],
+    )
+    .map_err(|e| db_err("Failed to update sponsor ledger", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(targets: &[&str]) -> Policy {
+        Policy {
+            account: Some("sponsor".to_string()),
+            max_gas_budget: 50_000_000,
+            sender_budget_mist: 100_000_000,
+            window_ms: 86_400_000,
+            allowed_move_targets: targets.iter().map(|t| normalize_move_target(t)).collect(),
+        }
+    }
+
+    #[test]
+    fn move_targets_normalize_and_match() {
+        assert_eq!(
+            normalize_move_target(
+                "0x0000000000000000000000000000000000000000000000000000000000000002::coin::join"
+            ),
+            "0x2::coin::join"
+        );
+        assert_eq!(normalize_move_target("0xABC::m::f"), "0xabc::m::f");
+        let p = policy(&["0x2::coin::*", "0x00abc::onboarding::claim"]);
+        assert!(p.move_target_allowed(
+            "0x0000000000000000000000000000000000000000000000000000000000000002::coin::split"
+        ));
+        assert!(p.move_target_allowed("0xabc::onboarding::claim"));
+        assert!(!p.move_target_allowed("0xabc::onboarding::withdraw"));
+        assert!(!p.move_target_allowed("0x3::coin::join"));
+
+        assert!(is_valid_move_target("0x2::coin::*"));
+        assert!(is_valid_move_target("*::onboarding::claim"));
+        assert!(!is_valid_move_target("0x2::coin"));
+        assert!(!is_valid_move_target("0xzz::coin::join"));
+    }
+
+    #[test]
+    fn violations_cover_gas_coin_targets_and_budgets() {
+        let p = policy(&["0x2::coin::*"]);
+        let ok = TxShape {
+            move_targets: vec!["0x2::coin::join".to_string()],
+            ..TxShape::default()
+        };
+        assert!(p.violations(&ok, 10_000_000, 0).is_empty());
+        assert_eq!(p.violations(&ok, 60_000_000, 0).len(), 1);
+        assert_eq!(p.violations(&ok, 10_000_000, 95_000_000).len(), 1);
+
+        let bad = TxShape {
+            move_targets: vec!["0xabc::m::f".to_string()],
+            uses_gas_coin: true,
+            unsupported: vec!["Publish".to_string()],
+        };
+        assert_eq!(p.violations(&bad, 10_000_000, 0).len(), 3);
+    }
+
+    fn temp_db(tag: &str) -> (std::path::PathBuf, rusqlite::Connection) {
+        let dir = std::env::temp_dir().join(format!(
+            "web3mcp_sponsor_ledger_{}_{}_{}",
+            tag,
+            std::process::id(),
+            crate::utils::pending_store::now_ms()
+        ));
+        let conn = open_db(&dir.join("pending.sqlite")).unwrap();
+        (dir, conn)
+    }
+
+    #[test]
+    fn ledger_books_reserves_and_settles_per_sender() {
+        let (dir, mut conn) = temp_db("settle");
+        let p = policy(&[]);
+
+        let booked = Reservation::Booked;
+        assert_eq!(
+            reserve(
+                &mut conn,
+                &p,
+                "d1",
+                "0xa",
+                Some("testnet"),
+                20_000_000,
+                1_000
+            )
+            .unwrap(),
+            booked
+        );
+        assert_eq!(
+            reserve(
+                &mut conn,
+                &p,
+                "d2",
+                "0xa",
+                Some("testnet"),
+                30_000_000,
+                2_000
+            )
+            .unwrap(),
+            booked
+        );
+        assert_eq!(
+            reserve(&mut conn, &p, "d3", "0xb", None, 5_000_000, 2_000).unwrap(),
+            booked
+        );
+        assert!(reserve(&mut conn, &p, "d1", "0xa", None, 1, 3_000).is_err());
+        assert_eq!(spent_since(&conn, "0xa", 0).unwrap(), 50_000_000);
+        assert_eq!(
+            reserve(&mut conn, &p, "d4", "0xa", None, 60_000_000, 3_000).unwrap(),
+            Reservation::OverBudget { spent: 50_000_000 }
+        );
+
+        settle(&conn, "d1", 1_500_000, 3_000).unwrap();
+        release(&conn, "d2").unwrap();
+        assert_eq!(spent_since(&conn, "0xa", 0).unwrap(), 1_500_000);
+        assert_eq!(spent_since(&conn, "0xa", 1_500).unwrap(), 0);
+        assert_eq!(spent_since(&conn, "0xb", 0).unwrap(), 5_000_000);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn concurrent_reservations_never_overspend_the_sender_budget() {
+        let (dir, _conn) = temp_db("concurrent");
+        let path = dir.join("pending.sqlite");
+        let p = policy(&[]);
+
+        // 8 requests of 30M against a 100M budget: exactly 3 fit.
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let (path, p) = (path.clone(), p.clone());
+                std::thread::spawn(move || {
+                    let mut conn = open_db(&path).unwrap();
+                    reserve(
+                        &mut conn,
+                        &p,
+                        &format!("d{}", i),
+                        "0xa",
+                        None,
+                        30_000_000,
+                        1_000,
+                    )
+                    .unwrap()
+                })
+            })
+            .collect();
+        let booked = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|r| *r == Reservation::Booked)
+            .count();
+        assert_eq!(booked, 3);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -13,10 +13,10 @@ impl Web3McpServer {
     pub fn resolve_sui_coin_type(symbol: &str) -> Option<String> {
         let symbol = symbol.trim().to_lowercase();
         match symbol.as_str() {
-            "usdc" => std::env::var("SUI_USDC_COIN_TYPE")
+            "usdc" => crate::utils::config::var("SUI_USDC_COIN_TYPE")
                 .ok()
                 .or_else(|| Some(Self::builtin_sui_usdc_coin_type())),
-            "usdt" => std::env::var("SUI_USDT_COIN_TYPE")
+            "usdt" => crate::utils::config::var("SUI_USDT_COIN_TYPE")
                 .ok()
                 .or_else(Self::builtin_sui_usdt_coin_type),
             _ => None,
@@ -33,7 +33,7 @@ impl Web3McpServer {
         // Testnet: 0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC
         //
         // We pick based on SUI_NETWORK (mainnet|testnet|devnet) if present, otherwise infer from SUI_RPC_URL.
-        let network = std::env::var("SUI_NETWORK")
+        let network = crate::utils::config::var("SUI_NETWORK")
             .ok()
             .unwrap_or_default()
             .to_lowercase();
@@ -46,7 +46,7 @@ impl Web3McpServer {
             return "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC".to_string();
         }
 
-        let rpc = std::env::var("SUI_RPC_URL")
+        let rpc = crate::utils::config::var("SUI_RPC_URL")
             .ok()
             .unwrap_or_default()
             .to_lowercase();
@@ -118,7 +118,7 @@ impl Web3McpServer {
     pub fn resolve_evm_erc20_address(symbol: &str, chain_id: u64) -> Option<String> {
         let symbol_upper = symbol.trim().to_uppercase();
         let key = format!("EVM_{}_ADDRESS_{}", symbol_upper, chain_id);
-        if let Ok(v) = std::env::var(&key) {
+        if let Ok(v) = crate::utils::config::var(&key) {
             return Some(v);
         }
 
@@ -0,0 +1,422 @@
+//! Runtime tool-surface profiles.
+//!
+//! Every tool is compiled into the router; the active profile decides which ones `tools/list`
+//! shows and `tools/call` accepts. A profile is a list of allow globs and deny globs over tool
+//! names (`*` = any run of characters, `?` = one character); a tool is exposed when an allow
+//! glob matches and no deny glob does.
+//!
+//! The profile is picked by `--profile <name>`, else `WEB3MCP_TOOL_PROFILE` / `[tools] profile`,
+//! else [`default_profile_name`]. `WEB3MCP_TOOLS_ALLOW` / `WEB3MCP_TOOLS_DENY` (`[tools] allow`
+//! / `deny`) add globs on top of it, and `[tools.profiles.<name>]` defines custom profiles.
+
+use crate::utils::config::{Loaded, ToolProfileConfig};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+pub struct BuiltinProfile {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub allow: &'static [&'static str],
+    pub deny: &'static [&'static str],
+}
+
+/// Workflow entrypoints plus the pending-confirmation / config / metrics tools every profile
+/// needs.
+const PUBLIC_ALLOW: &[&str] = &[
+    "w3rt_*",
+    "system_config_show",
+    "system_metrics",
+    "system_*_pending_confirmation*",
+    "solana_confirm_transaction",
+];
+
+pub const BUILTIN_PROFILES: &[BuiltinProfile] = &[
+    BuiltinProfile {
+        name: "public",
+        description:
+            "Solana-first W3RT surface: workflow runs, pending confirmations, config, metrics",
+        allow: PUBLIC_ALLOW,
+        deny: &[],
+    },
+    BuiltinProfile {
+        name: "solana",
+        description: "Workflows plus every Solana and system tool",
+        allow: &["w3rt_*", "system_*", "solana_*"],
+        deny: &[],
+    },
+    BuiltinProfile {
+        name: "evm-trader",
+        description: "Workflows plus every EVM and system tool",
+        allow: &["w3rt_*", "system_*", "evm_*", "solana_confirm_transaction"],
+        deny: &[],
+    },
+    BuiltinProfile {
+        name: "sui-dev",
+        description: "Everything except the Solana and EVM toolboxes",
+        allow: &["*"],
+        deny: &["solana_*", "evm_*"],
+    },
+    BuiltinProfile {
+        name: "full",
+        description: "Every tool",
+        allow: &["*"],
+        deny: &[],
+    },
+];
+
+/// `public`, or `full` for builds with the deprecated `expose-advanced-tools` feature.
+pub fn default_profile_name() -> &'static str {
+    if cfg!(feature = "expose-advanced-tools") {
+        "full"
+    } else {
+        "public"
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ToolProfile {
+    pub name: String,
+    pub description: String,
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl ToolProfile {
+    pub fn builtin(name: &str) -> Option<Self> {
+        BUILTIN_PROFILES
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| Self {
+                name: p.name.to_string(),
+                description: p.description.to_string(),
+                allow: p.allow.iter().map(|s| s.to_string()).collect(),
+                deny: p.deny.iter().map(|s| s.to_string()).collect(),
+            })
+    }
+
+    pub fn allows(&self, tool: &str) -> bool {
+        self.allow.iter().any(|g| glob_match(g, tool))
+            && !self.deny.iter().any(|g| glob_match(g, tool))
+    }
+
+    /// Extra allow / deny globs (deny still wins over allow).
+    pub fn with_overrides(mut self, allow: Vec<String>, deny: Vec<String>) -> Self {
+        self.allow.extend(allow);
+        self.deny.extend(deny);
+        self
+    }
+}
+
+/// Glob match over the whole name: `*` is any run of characters, `?` exactly one.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let n: Vec<char> = name.chars().collect();
+    let (mut pi, mut ni) = (0, 0);
+    // Last `*` seen and the name position it currently covers up to.
+    let mut star: Option<(usize, usize)> = None;
+    while ni < n.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == n[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ni));
+            pi += 1;
+        } else if let Some((sp, sn)) = star {
+            pi = sp + 1;
+            ni = sn + 1;
+            star = Some((sp, sn + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+pub fn is_valid_glob(glob: &str) -> bool {
+    !glob.is_empty()
+        && glob
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '*' | '?'))
+}
+
+/// Comma-separated globs (the env var form of `[tools] allow` / `deny`).
+pub fn parse_globs(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-'))
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// A built-in profile or one of `custom` (custom names may not shadow built-ins).
+pub fn lookup(
+    name: &str,
+    custom: &BTreeMap<String, ToolProfileConfig>,
+) -> Result<ToolProfile, String> {
+    let name = normalize(name);
+    if let Some(p) = ToolProfile::builtin(&name) {
+        return Ok(p);
+    }
+    let Some(c) = custom.get(&name) else {
+        let mut known: Vec<String> = BUILTIN_PROFILES
+            .iter()
+            .map(|p| p.name.to_string())
+            .collect();
+        known.extend(custom.keys().cloned());
+        return Err(format!(
+            "unknown tool profile '{}' (known: {})",
+            name,
+            known.join("|")
+        ));
+    };
+    let base = match c.extends.as_deref().map(normalize) {
+        Some(base) => ToolProfile::builtin(&base).ok_or_else(|| {
+            format!(
+                "tool profile '{}' extends '{}', which is not a built-in profile",
+                name, base
+            )
+        })?,
+        None => ToolProfile::default(),
+    };
+    let profile = base.with_overrides(c.allow.clone(), c.deny.clone());
+    Ok(ToolProfile {
+        name,
+        description: c.description.clone().unwrap_or_default(),
+        ..profile
+    })
+}
+
+/// The profile to run with: `cli` (`--profile`), else config / env, else the default; plus the
+/// `WEB3MCP_TOOLS_ALLOW` / `WEB3MCP_TOOLS_DENY` globs.
+pub fn resolve(cli: Option<&str>) -> Result<ToolProfile, String> {
+    let name = cli
+        .map(str::to_string)
+        .filter(|s| !s.trim().is_empty())
+        .or_else(|| crate::utils::config::var("WEB3MCP_TOOL_PROFILE").ok())
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or_else(|| default_profile_name().to_string());
+    let profile = lookup(&name, &crate::utils::config::loaded().config.tools.profiles)?;
+    let globs = |key: &str| {
+        crate::utils::config::var(key)
+            .map(|v| parse_globs(&v))
+            .unwrap_or_default()
+    };
+    Ok(profile.with_overrides(globs("WEB3MCP_TOOLS_ALLOW"), globs("WEB3MCP_TOOLS_DENY")))
+}
+
+/// Problems with `[tools]` (the profile name and custom profiles), one line each.
+pub fn validate(loaded: &Loaded) -> Vec<String> {
+    let custom = &loaded.config.tools.profiles;
+    let mut problems = Vec::new();
+    for (name, c) in custom {
+        let key = format!("tools.profiles.{}", name);
+        if ToolProfile::builtin(name).is_some() {
+            problems.push(format!("{} (file): shadows a built-in profile", key));
+        } else if !is_valid_name(name) {
+            problems.push(format!(
+                "{} (file): profile names are lowercase letters, digits, _ and -",
+                key
+            ));
+        } else if let Err(e) = lookup(name, custom) {
+            problems.push(format!("{} (file): {}", key, e));
+        }
+        for g in c.allow.iter().chain(&c.deny) {
+            if !is_valid_glob(g) {
+                problems.push(format!(
+                    "{} (file): invalid glob '{}' (letters, digits, _ - * ?)",
+                    key, g
+                ));
+            }
+        }
+    }
+    let (name, source) = match std::env::var("WEB3MCP_TOOL_PROFILE") {
+        Ok(v) => (Some(v), "env:WEB3MCP_TOOL_PROFILE"),
+        Err(_) => (loaded.config.tools.profile.clone(), "file"),
+    };
+    if let Some(name) = name.filter(|n| !n.trim().is_empty()) {
+        if let Err(e) = lookup(&name, custom) {
+            problems.push(format!("tools.profile ({}): {}", source, e));
+        }
+    }
+    problems
+}
+
+static ACTIVE: OnceLock<(ToolProfile, Vec<String>)> = OnceLock::new();
+
+/// Record the profile the server runs with and the tools it exposes (first call wins).
+pub fn set_active(profile: ToolProfile, exposed: Vec<String>) {
+    let _ = ACTIVE.set((profile, exposed));
+}
+
+pub fn active() -> Option<&'static ToolProfile> {
+    ACTIVE.get().map(|(p, _)| p)
+}
+
+/// Active profile, its exposed tools and the built-in / custom profiles to choose from.
+pub fn active_json() -> Value {
+    let custom = &crate::utils::config::loaded().config.tools.profiles;
+    let mut available: Vec<Value> = BUILTIN_PROFILES
+        .iter()
+        .map(|p| json!({"name": p.name, "description": p.description, "builtin": true}))
+        .collect();
+    available.extend(
+        custom
+            .iter()
+            .map(|(name, c)| json!({"name": name, "description": c.description, "builtin": false})),
+    );
+    json!({
+        "active": ACTIVE.get().map(|(p, _)| json!(p)),
+        "exposed_tools": ACTIVE.get().map(|(_, tools)| tools.len()),
+        "default": default_profile_name(),
+        "available": available,
+        "how_to": "web3mcp --profile <name>, WEB3MCP_TOOL_PROFILE or [tools] profile in web3mcp.toml"
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn globs_match_whole_names() {
+        assert!(glob_match("*", "get_balance"));
+        assert!(glob_match("evm_*", "evm_get_balance"));
+        assert!(!glob_match("evm_*", "get_evm_balance"));
+        assert!(glob_match(
+            "*_pending_confirmation*",
+            "evm_list_pending_confirmations"
+        ));
+        assert!(glob_match("get_?oins", "get_coins"));
+        assert!(!glob_match("get_?oins", "get_all_coins"));
+        assert!(glob_match("a*b*c", "a_x_b_y_b_c"));
+        assert!(!glob_match("a*b*c", "a_x_b_y_c_d"));
+        assert!(glob_match("get_balance", "get_balance"));
+        assert!(!glob_match("get_balance", "get_balances"));
+    }
+
+    #[test]
+    fn builtin_profiles_filter_by_chain() {
+        let public = ToolProfile::builtin("public").unwrap();
+        for tool in [
+            "w3rt_run_workflow_v0",
+            "system_config_show",
+            "system_metrics",
+            "system_list_pending_confirmations",
+            "system_cleanup_pending_confirmations",
+            "solana_confirm_transaction",
+        ] {
+            assert!(public.allows(tool), "{}", tool);
+        }
+        for tool in ["solana_send_transaction", "evm_get_balance", "get_balance"] {
+            assert!(!public.allows(tool), "{}", tool);
+        }
+
+        let sui = ToolProfile::builtin("sui-dev").unwrap();
+        assert!(sui.allows("build_move_call"));
+        assert!(sui.allows("w3rt_get_run"));
+        assert!(!sui.allows("evm_get_balance"));
+        assert!(!sui.allows("solana_rpc_call"));
+
+        let evm = ToolProfile::builtin("evm-trader").unwrap();
+        assert!(evm.allows("evm_0x_quote"));
+        assert!(!evm.allows("solana_send_transaction"));
+        assert!(!evm.allows("build_move_call"));
+
+        assert!(ToolProfile::builtin("full").unwrap().allows("anything"));
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let p = ToolProfile::builtin("full")
+            .unwrap()
+            .with_overrides(vec!["evm_*".to_string()], vec!["*_execute_*".to_string()]);
+        assert!(p.allows("evm_get_balance"));
+        assert!(!p.allows("evm_execute_transfer_native"));
+    }
+
+    #[test]
+    fn custom_profiles_extend_builtins() {
+        let loaded = Loaded::from_toml(
+            None,
+            r#"
+            [tools]
+            profile = "ops"
+            allow = ["get_*"]
+            [tools.profiles.ops]
+            extends = "public"
+            allow = ["system_*"]
+            deny = ["system_debug_bundle"]
+            [tools.profiles.readonly]
+            allow = ["*"]
+            deny = ["*execute*", "*send*", "*sign*"]
+            "#,
+        );
+        assert_eq!(loaded.error, None);
+        assert_eq!(
+            loaded.file_value("WEB3MCP_TOOLS_ALLOW").as_deref(),
+            Some("get_*")
+        );
+        let custom = &loaded.config.tools.profiles;
+
+        let ops = lookup("OPS", custom).unwrap();
+        assert_eq!(ops.name, "ops");
+        assert!(ops.allows("w3rt_run_workflow_v0"));
+        assert!(ops.allows("system_healthcheck"));
+        assert!(!ops.allows("system_debug_bundle"));
+        assert!(!ops.allows("evm_get_balance"));
+
+        let ro = lookup("readonly", custom).unwrap();
+        assert!(ro.allows("get_balance"));
+        assert!(!ro.allows("execute_transfer_sui"));
+        assert!(!ro.allows("solana_send_transaction"));
+
+        assert!(lookup("nope", custom)
+            .unwrap_err()
+            .starts_with("unknown tool profile 'nope'"));
+        assert!(validate(&loaded).is_empty());
+    }
+
+    #[test]
+    fn bad_profiles_are_reported() {
+        let loaded = Loaded::from_toml(
+            None,
+            r#"
+            [tools]
+            profile = "missing"
+            [tools.profiles.full]
+            allow = ["*"]
+            [tools.profiles.mine]
+            extends = "other"
+            allow = ["evm_get balance"]
+            "#,
+        );
+        let problems = validate(&loaded);
+        let has = |prefix: &str| problems.iter().any(|p| p.starts_with(prefix));
+        if std::env::var("WEB3MCP_TOOL_PROFILE").is_err() {
+            assert!(has("tools.profile (file): unknown tool profile 'missing'"));
+        }
+        assert!(has(
+            "tools.profiles.full (file): shadows a built-in profile"
+        ));
+        assert!(has(
+            "tools.profiles.mine (file): tool profile 'mine' extends 'other'"
+        ));
+        assert!(has(
+            "tools.profiles.mine (file): invalid glob 'evm_get balance'"
+        ));
+    }
+}
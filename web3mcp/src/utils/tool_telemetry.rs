@@ -0,0 +1,133 @@
+use crate::Web3McpServer;
+use rmcp::model::{CallToolRequestParam, CallToolResult, ErrorData, RawContent};
+use rmcp::service::RequestContext;
+use rmcp::RoleServer;
+use serde_json::Value;
+use std::time::Instant;
+use tracing::{field, Instrument};
+
+impl Web3McpServer {
+    /// Dispatch a `tools/call` inside a `tool_call` span and record it in `utils/metrics.rs`.
+    /// Successful broadcasts also drop the cached balances (`utils/response_cache.rs`).
+    ///
+    /// Failures are labelled with the `error_class` the tool already returned, else with
+    /// `classify_error` over the error message.
+    pub async fn call_tool_instrumented(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        // Unknown names come from clients; keep them out of the metric labels.
+        let tool = if self.tool_router.has_route(&request.name) {
+            request.name.to_string()
+        } else {
+            "unknown".to_string()
+        };
+        let chain = Self::classify_error(&tool, "")["chain"]
+            .as_str()
+            .unwrap_or("system")
+            .to_string();
+        let network = crate::utils::metrics::network_label(
+            Self::network_argument(request.arguments.as_ref()).as_deref(),
+        );
+        let span = tracing::info_span!(
+            "tool_call",
+            tool = %tool,
+            chain = %chain,
+            network = %network,
+            session = self.session_id.as_deref().unwrap_or("stdio"),
+            latency_ms = field::Empty,
+            outcome = field::Empty,
+            error_class = field::Empty,
+        );
+
+        let started = Instant::now();
+        let tcc = rmcp::handler::server::tool::ToolCallContext::new(self, request, context);
+        let result = self.tool_router.call(tcc).instrument(span.clone()).await;
+        let elapsed = started.elapsed();
+
+        let error_class = Self::call_error_class(&tool, &result);
+        let latency_ms = elapsed.as_secs_f64() * 1000.0;
+        span.record("latency_ms", latency_ms);
+        span.record(
+            "outcome",
+            if error_class.is_some() { "error" } else { "ok" },
+        );
+        if let Some(class) = error_class.as_deref() {
+            span.record("error_class", class);
+            span.in_scope(|| tracing::warn!(latency_ms, error_class = class, "tool call failed"));
+        } else {
+            span.in_scope(|| tracing::info!(latency_ms, "tool call finished"));
+        }
+
+        if error_class.is_none() && crate::utils::response_cache::is_broadcast_tool(&tool) {
+            crate::utils::response_cache::invalidate(
+                crate::utils::response_cache::CacheCategory::Balance,
+            );
+        }
+
+        crate::utils::metrics::record(&crate::utils::metrics::CallSample {
+            tool: &tool,
+            chain: &chain,
+            network: &network,
+            error_class: error_class.as_deref(),
+            seconds: elapsed.as_secs_f64(),
+        });
+        result
+    }
+
+    /// `network`, else `chain_id` / `chain` of the call arguments.
+    fn network_argument(arguments: Option<&serde_json::Map<String, Value>>) -> Option<String> {
+        let args = arguments?;
+        ["network", "chain_id", "chain"]
+            .iter()
+            .find_map(|k| match args.get(*k)? {
+                Value::String(s) => Some(s.clone()),
+                Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+    }
+
+    /// `None` for a successful call (including `needs_confirmation` guard results).
+    fn call_error_class(tool: &str, result: &Result<CallToolResult, ErrorData>) -> Option<String> {
+        match result {
+            Ok(r) if r.is_error != Some(true) => None,
+            Ok(r) => {
+                let text: Vec<&str> = r
+                    .content
+                    .iter()
+                    .filter_map(|c| match &c.raw {
+                        RawContent::Text(t) => Some(t.text.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                Some(Self::classified(tool, &text.join("\n")))
+            }
+            Err(e) => {
+                let returned = e
+                    .data
+                    .as_ref()
+                    .and_then(|d| d.get("error_class"))
+                    .and_then(Value::as_str);
+                Some(match returned {
+                    Some(class) => class.to_string(),
+                    None => {
+                        let class = Self::classified(tool, &e.message);
+                        if class == "UNKNOWN" && e.code.0 == -32602 {
+                            "INVALID_PARAMS".to_string()
+                        } else {
+                            class
+                        }
+                    }
+                })
+            }
+        }
+    }
+
+    fn classified(tool: &str, message: &str) -> String {
+        Self::classify_error(tool, message)["error_class"]
+            .as_str()
+            .unwrap_or("UNKNOWN")
+            .to_string()
+    }
+}
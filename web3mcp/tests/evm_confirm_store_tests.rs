@@ -7,7 +7,9 @@ fn with_temp_cwd<F: FnOnce()>(f: F) {
     let tmp = tempfile::tempdir().expect("tempdir");
     let old = std::env::current_dir().expect("cwd");
     std::env::set_current_dir(tmp.path()).expect("set cwd");
+    std::env::set_var("WEB3MCP_DATA_DIR", tmp.path().join("data"));
     f();
+    std::env::remove_var("WEB3MCP_DATA_DIR");
     std::env::set_current_dir(old).expect("restore cwd");
 }
 
@@ -67,3 +69,73 @@ fn sqlite_migrations_are_idempotent_and_links_work() {
         assert_eq!(approve_row.swap_confirmation_id.as_deref(), Some(id_swap));
     });
 }
+
+#[test]
+fn legacy_cwd_store_is_imported_into_data_dir() {
+    with_temp_cwd(|| {
+        let cwd = std::env::current_dir().expect("cwd");
+        let tx = web3mcp::types::EvmTxRequest {
+            chain_id: 1,
+            from: "0x1111111111111111111111111111111111111111".to_string(),
+            to: "0x2222222222222222222222222222222222222222".to_string(),
+            value_wei: "0".to_string(),
+            nonce: None,
+            gas_limit: None,
+            max_fee_per_gas_wei: None,
+            max_priority_fee_per_gas_wei: None,
+            data_hex: Some("0x".to_string()),
+        };
+        let now = web3mcp::utils::evm_confirm_store::now_ms();
+        let hash = web3mcp::utils::evm_confirm_store::tx_summary_hash(&tx);
+
+        // Write a row where older versions kept the store (<cwd>/.data/pending.sqlite).
+        std::env::set_var("WEB3MCP_DATA_DIR", cwd.join(".data"));
+        web3mcp::utils::evm_confirm_store::insert_pending("legacy_1", &tx, now, now + 10000, &hash)
+            .expect("insert legacy");
+
+        std::env::set_var("WEB3MCP_DATA_DIR", cwd.join("data"));
+        let conn = web3mcp::utils::evm_confirm_store::connect().expect("connect");
+        let row = web3mcp::utils::evm_confirm_store::get_row(&conn, "legacy_1")
+            .expect("get legacy")
+            .expect("legacy row imported");
+        assert_eq!(row.tx_summary_hash, hash);
+        assert!(cwd.join("data").join("pending.sqlite").exists());
+        assert!(!cwd.join(".data").join("pending.sqlite").exists());
+        assert!(cwd.join(".data").join("pending.sqlite.migrated").exists());
+    });
+}
+
+#[test]
+fn pending_ids_minted_back_to_back_do_not_collide() {
+    with_temp_cwd(|| {
+        let tx = web3mcp::types::EvmTxRequest {
+            chain_id: 1,
+            from: "0x1111111111111111111111111111111111111111".to_string(),
+            to: "0x2222222222222222222222222222222222222222".to_string(),
+            value_wei: "0".to_string(),
+            nonce: None,
+            gas_limit: None,
+            max_fee_per_gas_wei: None,
+            max_priority_fee_per_gas_wei: None,
+            data_hex: Some("0x".to_string()),
+        };
+        let now = web3mcp::utils::evm_confirm_store::now_ms();
+        let hash = web3mcp::utils::evm_confirm_store::tx_summary_hash(&tx);
+
+        let first = web3mcp::utils::evm_confirm_store::new_pending_id("w3rt_transfer");
+        let second = web3mcp::utils::evm_confirm_store::new_pending_id("w3rt_transfer");
+        assert_ne!(first, second);
+        web3mcp::utils::evm_confirm_store::insert_pending(&first, &tx, now, now + 10000, &hash)
+            .expect("insert first");
+        web3mcp::utils::evm_confirm_store::insert_pending(&second, &tx, now, now + 10000, &hash)
+            .expect("insert second");
+
+        let conn = web3mcp::utils::evm_confirm_store::connect().expect("connect");
+        for id in [&first, &second] {
+            let row = web3mcp::utils::evm_confirm_store::get_row(&conn, id)
+                .expect("get row")
+                .expect("row exists");
+            assert_eq!(row.status, "pending");
+        }
+    });
+}